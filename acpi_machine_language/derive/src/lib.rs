@@ -34,6 +34,7 @@ pub fn derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let lender: proc_macro2::TokenStream = derive_lender(&derive_input);
     let char_from_self: proc_macro2::TokenStream = derive_char_from_self(&derive_input);
     let debug: proc_macro2::TokenStream = derive_debug(&derive_input);
+    let disassembler: proc_macro2::TokenStream = derive_disassembler(&derive_input);
     let first_reader: proc_macro2::TokenStream = derive_first_reader(&derive_input);
     let iter: proc_macro2::TokenStream = derive_reference_to_symbol_iterator(&derive_input);
     let length: proc_macro2::TokenStream = derive_with_length(&derive_input);
@@ -49,6 +50,7 @@ pub fn derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
         #lender
         #char_from_self
         #debug
+        #disassembler
         #first_reader
         #iter
         #length
@@ -83,6 +85,7 @@ impl From<RangeInclusive<u8>> for Encoding {
 struct TypeAttribute {
     defined_object_name: Option<Ident>,
     derive_debug: bool,
+    derive_disassembler: bool,
     derive_first_reader: bool,
     derive_lender: bool,
     derive_matcher: bool,
@@ -150,6 +153,44 @@ impl From<&DeriveInput> for TypeAttribute {
                 _ => true,
             }
         });
+        let derive_disassembler: bool = attrs.iter().all(|attribute| {
+            let Attribute {
+                pound_token: _,
+                style: _,
+                bracket_token: _,
+                meta,
+            } = attribute;
+            match meta {
+                Meta::List(MetaList {
+                    path,
+                    delimiter: _,
+                    tokens,
+                }) => {
+                    let Path {
+                        leading_colon: _,
+                        segments,
+                    } = path;
+                    let PathSegment {
+                        ident,
+                        arguments: _,
+                    } = segments.iter().last().unwrap();
+                    match ident.to_string().as_str() {
+                        "manual" => tokens
+                            .clone()
+                            .into_iter()
+                            .all(|token_tree| match token_tree {
+                                TokenTree::Ident(manual_arg) => {
+                                    let manual_arg: String = manual_arg.to_string();
+                                    !matches!(manual_arg.as_str(), "disassembler")
+                                }
+                                _ => true,
+                            }),
+                        _ => true,
+                    }
+                }
+                _ => true,
+            }
+        });
         let derive_first_reader: bool = attrs.iter().all(|attribute| {
             let Attribute {
                 pound_token: _,
@@ -672,6 +713,7 @@ impl From<&DeriveInput> for TypeAttribute {
         Self {
             defined_object_name,
             derive_debug,
+            derive_disassembler,
             derive_first_reader,
             derive_lender,
             derive_matcher,
@@ -770,7 +812,7 @@ fn derive_analyzer(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
         data: _,
     } = derive_input;
     quote! {
-        impl crate::acpi::machine_language::syntax::Analyzer for #ident {
+        impl crate::syntax::Analyzer for #ident {
         }
     }
 }
@@ -828,6 +870,7 @@ fn derive_debug(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
     let TypeAttribute {
         defined_object_name: _,
         derive_debug,
+        derive_disassembler: _,
         derive_first_reader: _,
         derive_lender: _,
         derive_matcher: _,
@@ -1026,6 +1069,243 @@ fn derive_debug(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
     }
 }
 
+fn derive_disassembler(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
+    let DeriveInput {
+        attrs: _,
+        vis: _,
+        ident,
+        generics: _,
+        data,
+    } = derive_input;
+    let TypeAttribute {
+        defined_object_name,
+        derive_debug: _,
+        derive_disassembler,
+        derive_first_reader: _,
+        derive_lender: _,
+        derive_matcher: _,
+        derive_path_getter: _,
+        derive_reader: _,
+        derive_reader_inside_method: _,
+        derive_reader_outside_method: _,
+        derive_string_from_self: _,
+        encoding,
+        flags,
+        has_field_list: _,
+        has_name_string: _,
+        matching_elements: _,
+        string,
+    } = derive_input.into();
+    let push_child = |field_name: &Ident, ty: &Type| -> proc_macro2::TokenStream {
+        match ty {
+            Type::Array(_) => quote! {
+                #field_name
+                    .as_slice()
+                    .iter()
+                    .for_each(|element| {
+                        children.push(element.disassemble());
+                    });
+            },
+            Type::Path(TypePath { qself: _, path }) => {
+                let Path {
+                    leading_colon: _,
+                    segments,
+                } = path;
+                let PathSegment {
+                    ident,
+                    arguments: _,
+                } = segments.iter().last().unwrap();
+                match ident.to_string().as_str() {
+                    "Option" => quote! {
+                        if let Some(element) = #field_name {
+                            children.push(element.disassemble());
+                        }
+                    },
+                    "Vec" => quote! {
+                        #field_name
+                            .iter()
+                            .for_each(|element| {
+                                children.push(element.disassemble());
+                            });
+                    },
+                    _ => quote! {
+                        children.push(#field_name.disassemble());
+                    },
+                }
+            }
+            _ => unimplemented!(),
+        }
+    };
+    let is_vec = |ty: &Type| -> bool {
+        match ty {
+            Type::Path(TypePath { qself: _, path }) => {
+                let Path {
+                    leading_colon: _,
+                    segments,
+                } = path;
+                let PathSegment {
+                    ident,
+                    arguments: _,
+                } = segments.iter().last().unwrap();
+                ident.to_string().as_str() == "Vec"
+            }
+            _ => false,
+        }
+    };
+    let disassemble: proc_macro2::TokenStream = if encoding.is_some() || flags {
+        quote! {
+            None
+        }
+    } else if string {
+        quote! {
+            Some(crate::disassembler::Asl::Term(self.into()))
+        }
+    } else {
+        match data {
+            Data::Enum(DataEnum {
+                enum_token: _,
+                brace_token: _,
+                variants,
+            }) => {
+                let push_patterns: Vec<proc_macro2::TokenStream> = variants
+                    .iter()
+                    .map(|variant| {
+                        let Variant {
+                            attrs: _,
+                            ident,
+                            fields,
+                            discriminant: _,
+                        } = variant;
+                        match fields {
+                            Fields::Unit => quote! {
+                                Self::#ident => {},
+                            },
+                            Fields::Unnamed(FieldsUnnamed {
+                                paren_token: _,
+                                unnamed,
+                            }) => {
+                                let (field_names, push_children): (
+                                    Vec<Ident>,
+                                    Vec<proc_macro2::TokenStream>,
+                                ) = unnamed
+                                    .iter()
+                                    .enumerate()
+                                    .map(|(index, field)| {
+                                        let Field {
+                                            attrs: _,
+                                            vis: _,
+                                            mutability: _,
+                                            ident: _,
+                                            colon_token: _,
+                                            ty,
+                                        } = field;
+                                        let field_name: Ident = format_ident!("field{}", index);
+                                        let push_child: proc_macro2::TokenStream =
+                                            push_child(&field_name, ty);
+                                        (field_name, push_child)
+                                    })
+                                    .unzip();
+                                quote! {
+                                    Self::#ident(#(#field_names),*) => {
+                                        #(#push_children)*
+                                    }
+                                }
+                            }
+                            _ => unimplemented!(),
+                        }
+                    })
+                    .collect();
+                quote! {
+                    let mut children: Vec<Option<crate::disassembler::Asl>> = Vec::new();
+                    match self {
+                        #(#push_patterns),*
+                    };
+                    crate::disassembler::Asl::sequence(children)
+                }
+            }
+            Data::Struct(DataStruct {
+                struct_token: _,
+                fields,
+                semi_token: _,
+            }) => match fields {
+                Fields::Unit => quote! {
+                    None
+                },
+                Fields::Unnamed(FieldsUnnamed {
+                    paren_token: _,
+                    unnamed,
+                }) => {
+                    let has_list: bool = unnamed.iter().any(|field| {
+                        let Field {
+                            attrs: _,
+                            vis: _,
+                            mutability: _,
+                            ident: _,
+                            colon_token: _,
+                            ty,
+                        } = field;
+                        is_vec(ty)
+                    });
+                    let (field_names, push_children): (Vec<Ident>, Vec<proc_macro2::TokenStream>) =
+                        unnamed
+                            .iter()
+                            .enumerate()
+                            .map(|(index, field)| {
+                                let Field {
+                                    attrs: _,
+                                    vis: _,
+                                    mutability: _,
+                                    ident: _,
+                                    colon_token: _,
+                                    ty,
+                                } = field;
+                                let field_name: Ident = format_ident!("field{}", index);
+                                let push_child: proc_macro2::TokenStream =
+                                    push_child(&field_name, ty);
+                                (field_name, push_child)
+                            })
+                            .unzip();
+                    let combine: proc_macro2::TokenStream = match defined_object_name {
+                        Some(defined_object_name) => quote! {
+                            crate::disassembler::Asl::defined_object(stringify!(#defined_object_name), children)
+                        },
+                        None => {
+                            if has_list {
+                                quote! {
+                                    crate::disassembler::Asl::list(children)
+                                }
+                            } else {
+                                quote! {
+                                    crate::disassembler::Asl::sequence(children)
+                                }
+                            }
+                        }
+                    };
+                    quote! {
+                        let Self(#(#field_names),*) = self;
+                        let mut children: Vec<Option<crate::disassembler::Asl>> = Vec::new();
+                        #(#push_children)*
+                        #combine
+                    }
+                }
+                _ => unimplemented!(),
+            },
+            _ => unimplemented!(),
+        }
+    };
+    if !derive_disassembler || flags {
+        quote! {}
+    } else {
+        quote! {
+            impl crate::syntax::Disassembler for #ident {
+                fn disassemble(&self) -> Option<crate::disassembler::Asl> {
+                    #disassemble
+                }
+            }
+        }
+    }
+}
+
 fn derive_first_reader(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
     let DeriveInput {
         attrs: _,
@@ -1037,6 +1317,7 @@ fn derive_first_reader(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
     let TypeAttribute {
         defined_object_name,
         derive_debug: _,
+        derive_disassembler: _,
         derive_first_reader,
         derive_lender: _,
         derive_matcher: _,
@@ -1055,14 +1336,16 @@ fn derive_first_reader(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
     if derive_first_reader {
         let first_read: proc_macro2::TokenStream = if flags {
             quote! {
-                assert!(Self::matches(aml), "aml = {:02x?}", aml);
+                if !Self::matches(aml) {
+                    return None;
+                }
                 match aml {
                     [symbol, aml @ ..] => {
                         let symbol: u8 = *symbol;
                         let symbol: Self = symbol.into();
-                        (symbol, aml)
+                        Some((symbol, aml))
                     },
-                    _ => unreachable!(),
+                    _ => None,
                 }
             }
         } else {
@@ -1085,8 +1368,8 @@ fn derive_first_reader(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
                                 Fields::Unit => quote! {
                                     if true {
                                         let symbol = Self::#ident;
-                                        let aml: &[u8] = &aml[symbol.length()..];
-                                        (symbol, aml)
+                                        let aml: &[u8] = aml.get(symbol.length()..)?;
+                                        Some((symbol, aml))
                                     }
                                 },
                                 Fields::Unnamed(FieldsUnnamed {
@@ -1187,7 +1470,7 @@ fn derive_first_reader(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
                                                                 .first()
                                                                 .unwrap() {
                                                                 GenericArgument::Type(element_type) => quote! {
-                                                                    let (#field_name, symbol_aml): (#element_type, &[u8]) = #element_type::first_read(symbol_aml, root, &current);
+                                                                    let (#field_name, symbol_aml): (#element_type, &[u8]) = #element_type::first_read(symbol_aml, root, &current)?;
                                                                     let #field_name: #ty = Box::new(#field_name);
                                                                 },
                                                                 _ => unimplemented!(),
@@ -1195,7 +1478,7 @@ fn derive_first_reader(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
                                                             _ => unimplemented!(),
                                                         }
                                                         _ => quote! {
-                                                            let (#field_name, symbol_aml): (#ty, &[u8]) = #ty::first_read(symbol_aml, root, &current);
+                                                            let (#field_name, symbol_aml): (#ty, &[u8]) = #ty::first_read(symbol_aml, root, &current)?;
                                                         },
                                                     }
                                                 },
@@ -1213,8 +1496,8 @@ fn derive_first_reader(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
                                             let symbol_aml: &[u8] = aml;
                                             #(#reads)*
                                             let symbol = Self::#ident(#(#field_names), *);
-                                            let aml: &[u8] = &aml[symbol.length()..];
-                                            (symbol, aml)
+                                            let aml: &[u8] = aml.get(symbol.length()..)?;
+                                            Some((symbol, aml))
                                         }
                                     }
                                 },
@@ -1224,7 +1507,7 @@ fn derive_first_reader(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
                         .collect();
                     quote! {
                         #(#read_patterns) else * else {
-                            panic!("aml = {:#x?}", aml)
+                            None
                         }
                     }
                 }
@@ -1235,8 +1518,8 @@ fn derive_first_reader(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
                 }) => match fields {
                     Fields::Unit => quote! {
                         let symbol = Self;
-                        let aml: &[u8] = &aml[symbol.length()..];
-                        (symbol, aml)
+                        let aml: &[u8] = aml.get(symbol.length()..)?;
+                        Some((symbol, aml))
                     },
                     Fields::Unnamed(FieldsUnnamed {
                         paren_token: _,
@@ -1268,7 +1551,7 @@ fn derive_first_reader(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
                                             #read
                                             (#field_name, symbol_aml)
                                         },
-                                        _ => unreachable!(),
+                                        _ => return None,
                                     };
                                 };
                                 let pack: proc_macro2::TokenStream = quote! {
@@ -1311,7 +1594,7 @@ fn derive_first_reader(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
                                                     }
                                                 } else {
                                                     quote! {
-                                                        let mut current: crate::acpi::machine_language::name::Path = current.clone();
+                                                        let mut current: crate::name::Path = current.clone();
                                                     }
                                                 },
                                                 _ => quote! {
@@ -1331,7 +1614,7 @@ fn derive_first_reader(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
                                                     quote! {
                                                         if index == 0 {
                                                             current += (&element).into();
-                                                            root.add_node(&current, crate::acpi::machine_language::name::Object::#defined_object_name);
+                                                            root.add_node(&current, crate::name::Object::#defined_object_name);
                                                         }
                                                     }
                                                 },
@@ -1341,12 +1624,12 @@ fn derive_first_reader(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
                                             quote! {
                                                 #mutable_current
                                                 let (elements, symbol_aml): (alloc::vec::Vec<#elem>, &[u8]) = (0..#len)
-                                                    .fold((alloc::vec::Vec::new(), symbol_aml), |(mut elements, symbol_aml), index| {
-                                                        let (element, symbol_aml): (#elem, &[u8]) = #elem::first_read(symbol_aml, root, &current);
+                                                    .try_fold((alloc::vec::Vec::new(), symbol_aml), |(mut elements, symbol_aml), index| {
+                                                        let (element, symbol_aml): (#elem, &[u8]) = #elem::first_read(symbol_aml, root, &current)?;
                                                         elements.push(element);
                                                         #add_node
-                                                        (elements, symbol_aml)
-                                                    });
+                                                        Some((elements, symbol_aml))
+                                                    })?;
                                                 let #field_name: #ty = elements
                                                     .try_into()
                                                     .unwrap();
@@ -1380,7 +1663,7 @@ fn derive_first_reader(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
                                                         .first()
                                                         .unwrap() {
                                                         GenericArgument::Type(element_type) => quote! {
-                                                            let (#field_name, symbol_aml): (#element_type, &[u8]) = #element_type::first_read(symbol_aml, root, &current);
+                                                            let (#field_name, symbol_aml): (#element_type, &[u8]) = #element_type::first_read(symbol_aml, root, &current)?;
                                                             let #field_name: #ty = Box::new(#field_name);
                                                         },
                                                         _ => unimplemented!(),
@@ -1389,16 +1672,16 @@ fn derive_first_reader(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
                                                 },
                                                 "NameString" => if has_field_list {
                                                     quote! {
-                                                        let (#field_name, symbol_aml): (#ty, &[u8]) = #ty::first_read(symbol_aml, root, &current);
+                                                        let (#field_name, symbol_aml): (#ty, &[u8]) = #ty::first_read(symbol_aml, root, &current)?;
                                                     }
                                                 } else {
                                                     let defined_object_name: &Ident = defined_object_name
                                                         .as_ref()
                                                         .unwrap();
                                                     quote! {
-                                                        let (#field_name, symbol_aml): (#ty, &[u8]) = #ty::first_read(symbol_aml, root, &current);
-                                                        let current: crate::acpi::machine_language::name::Path = current + (&#field_name).into();
-                                                        root.add_node(&current, crate::acpi::machine_language::name::Object::#defined_object_name);
+                                                        let (#field_name, symbol_aml): (#ty, &[u8]) = #ty::first_read(symbol_aml, root, &current)?;
+                                                        let current: crate::name::Path = current + (&#field_name).into();
+                                                        root.add_node(&current, crate::name::Object::#defined_object_name);
                                                     }
                                                 },
                                                 "Option" => match arguments {
@@ -1412,7 +1695,7 @@ fn derive_first_reader(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
                                                         .unwrap() {
                                                         GenericArgument::Type(element_type) => quote! {
                                                             let (#field_name, symbol_aml): (Option<#element_type>, &[u8]) = if #element_type::matches(symbol_aml) {
-                                                                let (#field_name, symbol_aml): (#element_type, &[u8]) = #element_type::first_read(symbol_aml, root, &current);
+                                                                let (#field_name, symbol_aml): (#element_type, &[u8]) = #element_type::first_read(symbol_aml, root, &current)?;
                                                                 (Some(#field_name), symbol_aml)
                                                             } else {
                                                                 (None, symbol_aml)
@@ -1424,11 +1707,11 @@ fn derive_first_reader(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
                                                 },
                                                 "PkgLength" => if index + 1 == unnamed.len() {
                                                     quote! {
-                                                        let #field_name: #ty = symbol_aml.into();
+                                                        let #field_name: #ty = #ty::new(symbol_aml)?;
                                                     }
                                                 } else {
                                                     quote! {
-                                                        let (#field_name, symbol_aml): (#ty, &[u8]) = #ty::first_read(symbol_aml, root, &current);
+                                                        let (#field_name, symbol_aml): (#ty, &[u8]) = #ty::first_read(symbol_aml, root, &current)?;
                                                     }
                                                 },
                                                 "Vec" => match arguments {
@@ -1443,8 +1726,8 @@ fn derive_first_reader(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
                                                         GenericArgument::Type(element_type) => {
                                                             let debug: proc_macro2::TokenStream = if debug {
                                                                 quote! {
-                                                                    crate::com2_println!("element = {:#x?}", element);
-                                                                    crate::com2_println!("remaining_aml = {:02x?}", &remaining_aml[0..core::cmp::min(10, remaining_aml.len())]);
+                                                                    crate::debug_println!("element = {:#x?}", element);
+                                                                    crate::debug_println!("remaining_aml = {:02x?}", &remaining_aml[0..core::cmp::min(10, remaining_aml.len())]);
                                                                 }
                                                             } else {
                                                                 quote! {
@@ -1458,7 +1741,7 @@ fn derive_first_reader(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
                                                                 } else {
                                                                     #element_type::matches(symbol_aml)
                                                                 } {
-                                                                    let (element, remaining_aml): (#element_type, &[u8]) = #element_type::first_read(symbol_aml, root, &current);
+                                                                    let (element, remaining_aml): (#element_type, &[u8]) = #element_type::first_read(symbol_aml, root, &current)?;
                                                                     #debug
                                                                     symbol_aml = remaining_aml;
                                                                     #field_name.push(element);
@@ -1470,7 +1753,7 @@ fn derive_first_reader(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
                                                     _ => unimplemented!(),
                                                 },
                                                 _ => quote! {
-                                                    let (#field_name, symbol_aml): (#ty, &[u8]) = #ty::first_read(symbol_aml, root, &current);
+                                                    let (#field_name, symbol_aml): (#ty, &[u8]) = #ty::first_read(symbol_aml, root, &current)?;
                                                 },
                                             }
                                         },
@@ -1479,7 +1762,9 @@ fn derive_first_reader(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
                                     let read: proc_macro2::TokenStream = if no_leftover {
                                         quote! {
                                             #read
-                                            assert!(symbol_aml.is_empty(), "symbol_aml = {:02x?}", symbol_aml);
+                                            if !symbol_aml.is_empty() {
+                                                return None;
+                                            }
                                         }
                                     } else {
                                         read
@@ -1496,12 +1781,14 @@ fn derive_first_reader(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
                                 }),
                         };
                         quote! {
-                            assert!(Self::matches(aml), "aml = {:#x?}", aml);
+                            if !Self::matches(aml) {
+                                return None;
+                            }
                             let symbol_aml: &[u8] = aml;
                             #(#read)*
                             let symbol = Self(#(#pack),*);
-                            let aml: &[u8] = &aml[symbol.length()..];
-                            (symbol, aml)
+                            let aml: &[u8] = aml.get(symbol.length()..)?;
+                            Some((symbol, aml))
                         }
                     }
                     _ => unimplemented!(),
@@ -1510,9 +1797,9 @@ fn derive_first_reader(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
             }
         };
         quote! {
-            impl crate::acpi::machine_language::syntax::FirstReader for #ident {
-                fn first_read<'a>(aml: &'a [u8], root: &mut crate::acpi::machine_language::name::Node, current: &crate::acpi::machine_language::name::Path) -> (Self, &'a [u8]) {
-                    let current: crate::acpi::machine_language::name::Path = current.clone();
+            impl crate::syntax::FirstReader for #ident {
+                fn first_read<'a>(aml: &'a [u8], root: &mut crate::name::Node, current: &crate::name::Path) -> Option<(Self, &'a [u8])> {
+                    let current: crate::name::Path = current.clone();
                     #first_read
                 }
             }
@@ -1533,6 +1820,7 @@ fn derive_lender(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
     let TypeAttribute {
         defined_object_name,
         derive_debug: _,
+        derive_disassembler: _,
         derive_first_reader: _,
         derive_lender,
         derive_matcher: _,
@@ -1552,7 +1840,7 @@ fn derive_lender(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
         let add_node: proc_macro2::TokenStream = if has_name_string && !has_field_list {
             match defined_object_name {
                 Some(defined_object_name) => quote! {
-                    let object = crate::acpi::machine_language::reference::Object::#defined_object_name(self);
+                    let object = crate::reference::Object::#defined_object_name(self);
                     root.add_node(&current, object);
                 },
                 None => quote! {},
@@ -1561,9 +1849,9 @@ fn derive_lender(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
             quote! {}
         };
         quote! {
-            impl crate::acpi::machine_language::syntax::Lender for #ident {
-                fn lend<'a>(&'a self, root: &mut crate::acpi::machine_language::reference::Node<'a>, current: &crate::acpi::machine_language::name::Path) {
-                    let current: crate::acpi::machine_language::name::Path = current.clone() + self
+            impl crate::syntax::Lender for #ident {
+                fn lend<'a>(&'a self, root: &mut crate::reference::Node<'a>, current: &crate::name::Path) {
+                    let current: crate::name::Path = current.clone() + self
                         .get_path()
                         .unwrap_or_default();
                     #add_node
@@ -1588,6 +1876,7 @@ fn derive_reference_to_symbol_iterator(derive_input: &DeriveInput) -> proc_macro
     let TypeAttribute {
         defined_object_name: _,
         derive_debug: _,
+        derive_disassembler: _,
         derive_first_reader: _,
         derive_lender: _,
         derive_matcher: _,
@@ -2024,19 +2313,19 @@ fn derive_reference_to_symbol_iterator(derive_input: &DeriveInput) -> proc_macro
         }
     };
     quote! {
-        impl crate::acpi::machine_language::syntax::ReferenceToSymbolIterator for #ident {
-            fn iter(&self) -> crate::acpi::machine_language::syntax::SymbolIterator<'_> {
-                let mut symbols: alloc::collections::vec_deque::VecDeque<&dyn crate::acpi::machine_language::syntax::Analyzer> = alloc::collections::vec_deque::VecDeque::new();
+        impl crate::syntax::ReferenceToSymbolIterator for #ident {
+            fn iter(&self) -> crate::syntax::SymbolIterator<'_> {
+                let mut symbols: alloc::collections::vec_deque::VecDeque<&dyn crate::syntax::Analyzer> = alloc::collections::vec_deque::VecDeque::new();
                 #push_symbols
-                crate::acpi::machine_language::syntax::SymbolIterator {
+                crate::syntax::SymbolIterator {
                     symbols,
                 }
             }
 
-            fn iter_mut(&mut self) -> crate::acpi::machine_language::syntax::MutSymbolIterator<'_> {
-                let mut symbols: alloc::collections::vec_deque::VecDeque<&mut dyn crate::acpi::machine_language::syntax::Analyzer> = alloc::collections::vec_deque::VecDeque::new();
+            fn iter_mut(&mut self) -> crate::syntax::MutSymbolIterator<'_> {
+                let mut symbols: alloc::collections::vec_deque::VecDeque<&mut dyn crate::syntax::Analyzer> = alloc::collections::vec_deque::VecDeque::new();
                 #push_mut_symbols
-                crate::acpi::machine_language::syntax::MutSymbolIterator {
+                crate::syntax::MutSymbolIterator {
                     symbols,
                 }
             }
@@ -2055,6 +2344,7 @@ fn derive_with_length(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
     let TypeAttribute {
         defined_object_name: _,
         derive_debug: _,
+        derive_disassembler: _,
         derive_first_reader: _,
         derive_lender: _,
         derive_matcher: _,
@@ -2231,7 +2521,7 @@ fn derive_with_length(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
         }
     };
     quote! {
-        impl crate::acpi::machine_language::syntax::WithLength for #ident {
+        impl crate::syntax::WithLength for #ident {
             fn length(&self) -> usize {
                 #length
             }
@@ -2250,6 +2540,7 @@ fn derive_matcher(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
     let TypeAttribute {
         defined_object_name: _,
         derive_debug: _,
+        derive_disassembler: _,
         derive_first_reader: _,
         derive_lender: _,
         derive_matcher,
@@ -2423,8 +2714,9 @@ fn derive_matcher(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
                                                         } else {
                                                             quote! {
                                                                 if #elem::matches(aml) {
-                                                                    let (_, aml): (#elem, &[u8]) = #elem::read(aml);
-                                                                    #matches
+                                                                    #elem::read(aml).is_some_and(|(_, aml): (#elem, &[u8])| {
+                                                                        #matches
+                                                                    })
                                                                 } else {
                                                                     false
                                                                 }
@@ -2471,8 +2763,9 @@ fn derive_matcher(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
                                                         } else {
                                                             quote! {
                                                                 if #element_type::matches(aml) {
-                                                                    let (_, aml): (#element_type, &[u8]) = #element_type::read(aml);
-                                                                    #matches
+                                                                    #element_type::read(aml).is_some_and(|(_, aml): (#element_type, &[u8])| {
+                                                                        #matches
+                                                                    })
                                                                 } else {
                                                                     false
                                                                 }
@@ -2514,8 +2807,9 @@ fn derive_matcher(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
                                                 } else {
                                                     quote! {
                                                         if #ty::matches(aml) {
-                                                            let (_, aml): (#ty, &[u8]) = #ty::read(aml);
-                                                            #matches
+                                                            #ty::read(aml).is_some_and(|(_, aml): (#ty, &[u8])| {
+                                                                #matches
+                                                            })
                                                         } else {
                                                             false
                                                         }
@@ -2537,7 +2831,7 @@ fn derive_matcher(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
     };
     if derive_matcher {
         quote! {
-            impl crate::acpi::machine_language::syntax::Matcher for #ident {
+            impl crate::syntax::Matcher for #ident {
                 fn matches(aml: &[u8]) -> bool {
                     #matches
                 }
@@ -2559,6 +2853,7 @@ fn derive_path_getter(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
     let TypeAttribute {
         defined_object_name: _,
         derive_debug: _,
+        derive_disassembler: _,
         derive_first_reader: _,
         derive_lender: _,
         derive_matcher: _,
@@ -2657,8 +2952,8 @@ fn derive_path_getter(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
             }
         };
         quote! {
-            impl crate::acpi::machine_language::syntax::PathGetter for #ident {
-                fn get_path(&self) -> Option<crate::acpi::machine_language::name::Path> {
+            impl crate::syntax::PathGetter for #ident {
+                fn get_path(&self) -> Option<crate::name::Path> {
                     #get_path
                 }
             }
@@ -2679,6 +2974,7 @@ fn derive_reader(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
     let TypeAttribute {
         defined_object_name: _,
         derive_debug: _,
+        derive_disassembler: _,
         derive_first_reader: _,
         derive_lender: _,
         derive_matcher: _,
@@ -2697,14 +2993,16 @@ fn derive_reader(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
     if derive_reader {
         let read: proc_macro2::TokenStream = if flags {
             quote! {
-                assert!(Self::matches(aml), "aml = {:02x?}", aml);
+                if !Self::matches(aml) {
+                    return None;
+                }
                 match aml {
                     [symbol, aml @ ..] => {
                         let symbol: u8 = *symbol;
                         let symbol: Self = symbol.into();
-                        (symbol, aml)
+                        Some((symbol, aml))
                     },
-                    _ => unreachable!(),
+                    _ => None,
                 }
             }
         } else {
@@ -2727,8 +3025,8 @@ fn derive_reader(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
                                 Fields::Unit => quote! {
                                     if true {
                                         let symbol = Self::#ident;
-                                        let aml: &[u8] = &aml[symbol.length()..];
-                                        (symbol, aml)
+                                        let aml: &[u8] = aml.get(symbol.length()..)?;
+                                        Some((symbol, aml))
                                     }
                                 },
                                 Fields::Unnamed(FieldsUnnamed {
@@ -2829,7 +3127,7 @@ fn derive_reader(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
                                                                 .first()
                                                                 .unwrap() {
                                                                 GenericArgument::Type(element_type) => quote! {
-                                                                    let (#field_name, symbol_aml): (#element_type, &[u8]) = #element_type::read(symbol_aml);
+                                                                    let (#field_name, symbol_aml): (#element_type, &[u8]) = #element_type::read(symbol_aml)?;
                                                                     let #field_name: #ty = Box::new(#field_name);
                                                                 },
                                                                 _ => unimplemented!(),
@@ -2837,7 +3135,7 @@ fn derive_reader(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
                                                             _ => unimplemented!(),
                                                         }
                                                         _ => quote! {
-                                                            let (#field_name, symbol_aml): (#ty, &[u8]) = #ty::read(symbol_aml);
+                                                            let (#field_name, symbol_aml): (#ty, &[u8]) = #ty::read(symbol_aml)?;
                                                         },
                                                     }
                                                 },
@@ -2855,8 +3153,8 @@ fn derive_reader(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
                                             let symbol_aml: &[u8] = aml;
                                             #(#reads)*
                                             let symbol = Self::#ident(#(#field_names), *);
-                                            let aml: &[u8] = &aml[symbol.length()..];
-                                            (symbol, aml)
+                                            let aml: &[u8] = aml.get(symbol.length()..)?;
+                                            Some((symbol, aml))
                                         }
                                     }
                                 },
@@ -2866,7 +3164,7 @@ fn derive_reader(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
                         .collect();
                     quote! {
                         #(#read_patterns) else * else {
-                            panic!("aml = {:#x?}", aml)
+                            None
                         }
                     }
                 }
@@ -2877,8 +3175,8 @@ fn derive_reader(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
                 }) => match fields {
                     Fields::Unit => quote! {
                         let symbol = Self;
-                        let aml: &[u8] = &aml[symbol.length()..];
-                        (symbol, aml)
+                        let aml: &[u8] = aml.get(symbol.length()..)?;
+                        Some((symbol, aml))
                     },
                     Fields::Unnamed(FieldsUnnamed {
                         paren_token: _,
@@ -2910,7 +3208,7 @@ fn derive_reader(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
                                             #read
                                             (#field_name, symbol_aml)
                                         },
-                                        _ => unreachable!(),
+                                        _ => return None,
                                     };
                                 };
                                 let pack: proc_macro2::TokenStream = quote! {
@@ -2945,11 +3243,11 @@ fn derive_reader(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
                                             len,
                                         }) => quote! {
                                             let (elements, symbol_aml): (alloc::vec::Vec<#elem>, &[u8]) = (0..#len)
-                                                .fold((alloc::vec::Vec::new(), symbol_aml), |(mut elements, symbol_aml), _| {
-                                                    let (element, symbol_aml): (#elem, &[u8]) = #elem::read(symbol_aml);
+                                                .try_fold((alloc::vec::Vec::new(), symbol_aml), |(mut elements, symbol_aml), _| {
+                                                    let (element, symbol_aml): (#elem, &[u8]) = #elem::read(symbol_aml)?;
                                                     elements.push(element);
-                                                    (elements, symbol_aml)
-                                                });
+                                                    Some((elements, symbol_aml))
+                                                })?;
                                             let #field_name: #ty = elements
                                                 .try_into()
                                                 .unwrap();
@@ -2982,7 +3280,7 @@ fn derive_reader(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
                                                         .first()
                                                         .unwrap() {
                                                         GenericArgument::Type(element_type) => quote! {
-                                                            let (#field_name, symbol_aml): (#element_type, &[u8]) = #element_type::read(symbol_aml);
+                                                            let (#field_name, symbol_aml): (#element_type, &[u8]) = #element_type::read(symbol_aml)?;
                                                             let #field_name: #ty = Box::new(#field_name);
                                                         },
                                                         _ => unimplemented!(),
@@ -3000,7 +3298,7 @@ fn derive_reader(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
                                                         .unwrap() {
                                                         GenericArgument::Type(element_type) => quote! {
                                                             let (#field_name, symbol_aml): (Option<#element_type>, &[u8]) = if #element_type::matches(symbol_aml) {
-                                                                let (#field_name, symbol_aml): (#element_type, &[u8]) = #element_type::read(symbol_aml);
+                                                                let (#field_name, symbol_aml): (#element_type, &[u8]) = #element_type::read(symbol_aml)?;
                                                                 (Some(#field_name), symbol_aml)
                                                             } else {
                                                                 (None, symbol_aml)
@@ -3012,11 +3310,11 @@ fn derive_reader(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
                                                 },
                                                 "PkgLength" => if index + 1 == unnamed.len() {
                                                     quote! {
-                                                        let #field_name: #ty = symbol_aml.into();
+                                                        let #field_name: #ty = #ty::new(symbol_aml)?;
                                                     }
                                                 } else {
                                                     quote! {
-                                                        let (#field_name, symbol_aml): (#ty, &[u8]) = #ty::read(symbol_aml);
+                                                        let (#field_name, symbol_aml): (#ty, &[u8]) = #ty::read(symbol_aml)?;
                                                     }
                                                 },
                                                 "Vec" => match arguments {
@@ -3031,8 +3329,8 @@ fn derive_reader(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
                                                         GenericArgument::Type(element_type) => {
                                                             let debug: proc_macro2::TokenStream = if debug {
                                                                 quote! {
-                                                                    crate::com2_println!("element = {:#x?}", element);
-                                                                    crate::com2_println!("remaining_aml = {:02x?}", &remaining_aml[0..core::cmp::min(10, remaining_aml.len())]);
+                                                                    crate::debug_println!("element = {:#x?}", element);
+                                                                    crate::debug_println!("remaining_aml = {:02x?}", &remaining_aml[0..core::cmp::min(10, remaining_aml.len())]);
                                                                 }
                                                             } else {
                                                                 quote! {
@@ -3046,7 +3344,7 @@ fn derive_reader(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
                                                                 } else {
                                                                     #element_type::matches(symbol_aml)
                                                                 } {
-                                                                    let (element, remaining_aml): (#element_type, &[u8]) = #element_type::read(symbol_aml);
+                                                                    let (element, remaining_aml): (#element_type, &[u8]) = #element_type::read(symbol_aml)?;
                                                                     #debug
                                                                     symbol_aml = remaining_aml;
                                                                     #field_name.push(element);
@@ -3058,7 +3356,7 @@ fn derive_reader(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
                                                     _ => unimplemented!(),
                                                 },
                                                 _ => quote! {
-                                                    let (#field_name, symbol_aml): (#ty, &[u8]) = #ty::read(symbol_aml);
+                                                    let (#field_name, symbol_aml): (#ty, &[u8]) = #ty::read(symbol_aml)?;
                                                 },
                                             }
                                         },
@@ -3067,7 +3365,9 @@ fn derive_reader(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
                                     let read: proc_macro2::TokenStream = if no_leftover {
                                         quote! {
                                             #read
-                                            assert!(symbol_aml.is_empty(), "symbol_aml = {:02x?}", symbol_aml);
+                                            if !symbol_aml.is_empty() {
+                                                return None;
+                                            }
                                         }
                                     } else {
                                         read
@@ -3084,12 +3384,14 @@ fn derive_reader(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
                                 }),
                         };
                         quote! {
-                            assert!(Self::matches(aml), "aml = {:#x?}", aml);
+                            if !Self::matches(aml) {
+                                return None;
+                            }
                             let symbol_aml: &[u8] = aml;
                             #(#read)*
                             let symbol = Self(#(#pack),*);
-                            let aml: &[u8] = &aml[symbol.length()..];
-                            (symbol, aml)
+                            let aml: &[u8] = aml.get(symbol.length()..)?;
+                            Some((symbol, aml))
                         }
                     }
                     _ => unimplemented!(),
@@ -3098,8 +3400,8 @@ fn derive_reader(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
             }
         };
         quote! {
-            impl crate::acpi::machine_language::syntax::Reader for #ident {
-                fn read(aml: &[u8]) -> (Self, &[u8]) {
+            impl crate::syntax::Reader for #ident {
+                fn read(aml: &[u8]) -> Option<(Self, &[u8])> {
                     #read
                 }
             }
@@ -3120,6 +3422,7 @@ fn derive_reader_inside_method(derive_input: &DeriveInput) -> proc_macro2::Token
     let TypeAttribute {
         defined_object_name,
         derive_debug: _,
+        derive_disassembler: _,
         derive_first_reader: _,
         derive_lender: _,
         derive_matcher: _,
@@ -3138,14 +3441,16 @@ fn derive_reader_inside_method(derive_input: &DeriveInput) -> proc_macro2::Token
     if derive_reader_inside_method {
         let read_inside_method: proc_macro2::TokenStream = if flags {
             quote! {
-                assert!(Self::matches(aml), "aml = {:02x?}", aml);
+                if !Self::matches(aml) {
+                    return None;
+                }
                 match aml {
                     [symbol, aml @ ..] => {
                         let symbol: u8 = *symbol;
                         let symbol: Self = symbol.into();
-                        (symbol, aml)
+                        Some((symbol, aml))
                     },
-                    _ => unreachable!(),
+                    _ => None,
                 }
             }
         } else {
@@ -3168,8 +3473,8 @@ fn derive_reader_inside_method(derive_input: &DeriveInput) -> proc_macro2::Token
                                 Fields::Unit => quote! {
                                     if true {
                                         let symbol = Self::#ident;
-                                        let aml: &[u8] = &aml[symbol.length()..];
-                                        (symbol, aml)
+                                        let aml: &[u8] = aml.get(symbol.length()..)?;
+                                        Some((symbol, aml))
                                     }
                                 },
                                 Fields::Unnamed(FieldsUnnamed {
@@ -3270,7 +3575,7 @@ fn derive_reader_inside_method(derive_input: &DeriveInput) -> proc_macro2::Token
                                                                 .first()
                                                                 .unwrap() {
                                                                 GenericArgument::Type(element_type) => quote! {
-                                                                    let (#field_name, symbol_aml): (#element_type, &[u8]) = #element_type::read_inside_method(symbol_aml, root, &current);
+                                                                    let (#field_name, symbol_aml): (#element_type, &[u8]) = #element_type::read_inside_method(symbol_aml, root, &current)?;
                                                                     let #field_name: #ty = Box::new(#field_name);
                                                                 },
                                                                 _ => unimplemented!(),
@@ -3278,7 +3583,7 @@ fn derive_reader_inside_method(derive_input: &DeriveInput) -> proc_macro2::Token
                                                             _ => unimplemented!(),
                                                         }
                                                         _ => quote! {
-                                                            let (#field_name, symbol_aml): (#ty, &[u8]) = #ty::read_inside_method(symbol_aml, root, &current);
+                                                            let (#field_name, symbol_aml): (#ty, &[u8]) = #ty::read_inside_method(symbol_aml, root, &current)?;
                                                         },
                                                     }
                                                 },
@@ -3296,8 +3601,8 @@ fn derive_reader_inside_method(derive_input: &DeriveInput) -> proc_macro2::Token
                                             let symbol_aml: &[u8] = aml;
                                             #(#reads)*
                                             let symbol = Self::#ident(#(#field_names), *);
-                                            let aml: &[u8] = &aml[symbol.length()..];
-                                            (symbol, aml)
+                                            let aml: &[u8] = aml.get(symbol.length()..)?;
+                                            Some((symbol, aml))
                                         }
                                     }
                                 },
@@ -3307,7 +3612,7 @@ fn derive_reader_inside_method(derive_input: &DeriveInput) -> proc_macro2::Token
                         .collect();
                     quote! {
                         #(#read_patterns) else * else {
-                            panic!("aml = {:#x?}", aml)
+                            None
                         }
                     }
                 }
@@ -3318,8 +3623,8 @@ fn derive_reader_inside_method(derive_input: &DeriveInput) -> proc_macro2::Token
                 }) => match fields {
                     Fields::Unit => quote! {
                         let symbol = Self;
-                        let aml: &[u8] = &aml[symbol.length()..];
-                        (symbol, aml)
+                        let aml: &[u8] = aml.get(symbol.length()..)?;
+                        Some((symbol, aml))
                     },
                     Fields::Unnamed(FieldsUnnamed {
                         paren_token: _,
@@ -3351,7 +3656,7 @@ fn derive_reader_inside_method(derive_input: &DeriveInput) -> proc_macro2::Token
                                             #read
                                             (#field_name, symbol_aml)
                                         },
-                                        _ => unreachable!(),
+                                        _ => return None,
                                     };
                                 };
                                 let pack: proc_macro2::TokenStream = quote! {
@@ -3394,7 +3699,7 @@ fn derive_reader_inside_method(derive_input: &DeriveInput) -> proc_macro2::Token
                                                     }
                                                 } else {
                                                     quote! {
-                                                        let mut current: crate::acpi::machine_language::name::Path = current.clone();
+                                                        let mut current: crate::name::Path = current.clone();
                                                     }
                                                 },
                                                 _ => quote! {
@@ -3414,7 +3719,7 @@ fn derive_reader_inside_method(derive_input: &DeriveInput) -> proc_macro2::Token
                                                     quote! {
                                                         if index == 0 {
                                                             current += (&element).into();
-                                                            root.add_node(&current, crate::acpi::machine_language::name::Object::#defined_object_name);
+                                                            root.add_node(&current, crate::name::Object::#defined_object_name);
                                                         }
                                                     }
                                                 },
@@ -3424,12 +3729,12 @@ fn derive_reader_inside_method(derive_input: &DeriveInput) -> proc_macro2::Token
                                             quote! {
                                                 #mutable_current
                                                 let (elements, symbol_aml): (alloc::vec::Vec<#elem>, &[u8]) = (0..#len)
-                                                    .fold((alloc::vec::Vec::new(), symbol_aml), |(mut elements, symbol_aml), index| {
-                                                        let (element, symbol_aml): (#elem, &[u8]) = #elem::read_inside_method(symbol_aml, root, &current);
+                                                    .try_fold((alloc::vec::Vec::new(), symbol_aml), |(mut elements, symbol_aml), index| {
+                                                        let (element, symbol_aml): (#elem, &[u8]) = #elem::read_inside_method(symbol_aml, root, &current)?;
                                                         elements.push(element);
                                                         #add_node
-                                                        (elements, symbol_aml)
-                                                    });
+                                                        Some((elements, symbol_aml))
+                                                    })?;
                                                 let #field_name: #ty = elements
                                                     .try_into()
                                                     .unwrap();
@@ -3463,7 +3768,7 @@ fn derive_reader_inside_method(derive_input: &DeriveInput) -> proc_macro2::Token
                                                         .first()
                                                         .unwrap() {
                                                         GenericArgument::Type(element_type) => quote! {
-                                                            let (#field_name, symbol_aml): (#element_type, &[u8]) = #element_type::read_inside_method(symbol_aml, root, &current);
+                                                            let (#field_name, symbol_aml): (#element_type, &[u8]) = #element_type::read_inside_method(symbol_aml, root, &current)?;
                                                             let #field_name: #ty = Box::new(#field_name);
                                                         },
                                                         _ => unimplemented!(),
@@ -3472,16 +3777,16 @@ fn derive_reader_inside_method(derive_input: &DeriveInput) -> proc_macro2::Token
                                                 },
                                                 "NameString" => if has_field_list {
                                                     quote! {
-                                                        let (#field_name, symbol_aml): (#ty, &[u8]) = #ty::read_inside_method(symbol_aml, root, &current);
+                                                        let (#field_name, symbol_aml): (#ty, &[u8]) = #ty::read_inside_method(symbol_aml, root, &current)?;
                                                     }
                                                 } else {
                                                     let defined_object_name: &Ident = defined_object_name
                                                         .as_ref()
                                                         .unwrap();
                                                     quote! {
-                                                        let (#field_name, symbol_aml): (#ty, &[u8]) = #ty::read_inside_method(symbol_aml, root, &current);
-                                                        let current: crate::acpi::machine_language::name::Path = current + (&#field_name).into();
-                                                        root.add_node(&current, crate::acpi::machine_language::name::Object::#defined_object_name);
+                                                        let (#field_name, symbol_aml): (#ty, &[u8]) = #ty::read_inside_method(symbol_aml, root, &current)?;
+                                                        let current: crate::name::Path = current + (&#field_name).into();
+                                                        root.add_node(&current, crate::name::Object::#defined_object_name);
                                                     }
                                                 },
                                                 "Option" => match arguments {
@@ -3495,7 +3800,7 @@ fn derive_reader_inside_method(derive_input: &DeriveInput) -> proc_macro2::Token
                                                         .unwrap() {
                                                         GenericArgument::Type(element_type) => quote! {
                                                             let (#field_name, symbol_aml): (Option<#element_type>, &[u8]) = if #element_type::matches(symbol_aml) {
-                                                                let (#field_name, symbol_aml): (#element_type, &[u8]) = #element_type::read_inside_method(symbol_aml, root, &current);
+                                                                let (#field_name, symbol_aml): (#element_type, &[u8]) = #element_type::read_inside_method(symbol_aml, root, &current)?;
                                                                 (Some(#field_name), symbol_aml)
                                                             } else {
                                                                 (None, symbol_aml)
@@ -3507,11 +3812,11 @@ fn derive_reader_inside_method(derive_input: &DeriveInput) -> proc_macro2::Token
                                                 },
                                                 "PkgLength" => if index + 1 == unnamed.len() {
                                                     quote! {
-                                                        let #field_name: #ty = symbol_aml.into();
+                                                        let #field_name: #ty = #ty::new(symbol_aml)?;
                                                     }
                                                 } else {
                                                     quote! {
-                                                        let (#field_name, symbol_aml): (#ty, &[u8]) = #ty::read_inside_method(symbol_aml, root, &current);
+                                                        let (#field_name, symbol_aml): (#ty, &[u8]) = #ty::read_inside_method(symbol_aml, root, &current)?;
                                                     }
                                                 },
                                                 "Vec" => match arguments {
@@ -3526,8 +3831,8 @@ fn derive_reader_inside_method(derive_input: &DeriveInput) -> proc_macro2::Token
                                                         GenericArgument::Type(element_type) => {
                                                             let debug: proc_macro2::TokenStream = if debug {
                                                                 quote! {
-                                                                    crate::com2_println!("element = {:#x?}", element);
-                                                                    crate::com2_println!("remaining_aml = {:02x?}", &remaining_aml[0..core::cmp::min(10, remaining_aml.len())]);
+                                                                    crate::debug_println!("element = {:#x?}", element);
+                                                                    crate::debug_println!("remaining_aml = {:02x?}", &remaining_aml[0..core::cmp::min(10, remaining_aml.len())]);
                                                                 }
                                                             } else {
                                                                 quote! {
@@ -3541,7 +3846,7 @@ fn derive_reader_inside_method(derive_input: &DeriveInput) -> proc_macro2::Token
                                                                 } else {
                                                                     #element_type::matches(symbol_aml)
                                                                 } {
-                                                                    let (element, remaining_aml): (#element_type, &[u8]) = #element_type::read_inside_method(symbol_aml, root, &current);
+                                                                    let (element, remaining_aml): (#element_type, &[u8]) = #element_type::read_inside_method(symbol_aml, root, &current)?;
                                                                     #debug
                                                                     symbol_aml = remaining_aml;
                                                                     #field_name.push(element);
@@ -3553,7 +3858,7 @@ fn derive_reader_inside_method(derive_input: &DeriveInput) -> proc_macro2::Token
                                                     _ => unimplemented!(),
                                                 },
                                                 _ => quote! {
-                                                    let (#field_name, symbol_aml): (#ty, &[u8]) = #ty::read_inside_method(symbol_aml, root, &current);
+                                                    let (#field_name, symbol_aml): (#ty, &[u8]) = #ty::read_inside_method(symbol_aml, root, &current)?;
                                                 },
                                             }
                                         },
@@ -3562,7 +3867,9 @@ fn derive_reader_inside_method(derive_input: &DeriveInput) -> proc_macro2::Token
                                     let read: proc_macro2::TokenStream = if no_leftover {
                                         quote! {
                                             #read
-                                            assert!(symbol_aml.is_empty(), "symbol_aml = {:02x?}", symbol_aml);
+                                            if !symbol_aml.is_empty() {
+                                                return None;
+                                            }
                                         }
                                     } else {
                                         read
@@ -3579,12 +3886,14 @@ fn derive_reader_inside_method(derive_input: &DeriveInput) -> proc_macro2::Token
                                 }),
                         };
                         quote! {
-                            assert!(Self::matches(aml), "aml = {:#x?}", aml);
+                            if !Self::matches(aml) {
+                                return None;
+                            }
                             let symbol_aml: &[u8] = aml;
                             #(#read)*
                             let symbol = Self(#(#pack),*);
-                            let aml: &[u8] = &aml[symbol.length()..];
-                            (symbol, aml)
+                            let aml: &[u8] = aml.get(symbol.length()..)?;
+                            Some((symbol, aml))
                         }
                     }
                     _ => unimplemented!(),
@@ -3593,9 +3902,9 @@ fn derive_reader_inside_method(derive_input: &DeriveInput) -> proc_macro2::Token
             }
         };
         quote! {
-            impl crate::acpi::machine_language::syntax::ReaderInsideMethod for #ident {
-                fn read_inside_method<'a>(aml: &'a [u8], root: &mut crate::acpi::machine_language::name::Node, current: &crate::acpi::machine_language::name::Path) -> (Self, &'a [u8]) {
-                    let current: crate::acpi::machine_language::name::Path = current.clone();
+            impl crate::syntax::ReaderInsideMethod for #ident {
+                fn read_inside_method<'a>(aml: &'a [u8], root: &mut crate::name::Node, current: &crate::name::Path) -> Option<(Self, &'a [u8])> {
+                    let current: crate::name::Path = current.clone();
                     #read_inside_method
                 }
             }
@@ -3616,6 +3925,7 @@ fn derive_reader_outside_method(derive_input: &DeriveInput) -> proc_macro2::Toke
     let TypeAttribute {
         defined_object_name: _,
         derive_debug: _,
+        derive_disassembler: _,
         derive_first_reader: _,
         derive_lender: _,
         derive_matcher: _,
@@ -3633,9 +3943,9 @@ fn derive_reader_outside_method(derive_input: &DeriveInput) -> proc_macro2::Toke
     } = derive_input.into();
     if derive_reader_outside_method {
         quote! {
-            impl crate::acpi::machine_language::syntax::ReaderOutsideMethod for #ident {
-                fn read_outside_method(&mut self, root: &mut crate::acpi::machine_language::name::Node, current: &crate::acpi::machine_language::name::Path) {
-                    let current: crate::acpi::machine_language::name::Path = current.clone() + self
+            impl crate::syntax::ReaderOutsideMethod for #ident {
+                fn read_outside_method(&mut self, root: &mut crate::name::Node, current: &crate::name::Path) {
+                    let current: crate::name::Path = current.clone() + self
                         .get_path()
                         .unwrap_or_default();
                     self.iter_mut()
@@ -3659,6 +3969,7 @@ fn derive_string_from_self(derive_input: &DeriveInput) -> proc_macro2::TokenStre
     let TypeAttribute {
         defined_object_name: _,
         derive_debug: _,
+        derive_disassembler: _,
         derive_first_reader: _,
         derive_lender: _,
         derive_matcher: _,
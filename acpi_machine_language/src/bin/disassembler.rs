@@ -0,0 +1,114 @@
+//! # AML Disassembler
+//! Print DSDTs and SSDTs given as files, such as ones dumped by `acpidump -b`, as ASL source on the host.
+//! The output is comparable with `iasl -d` of the same tables.
+//!
+//! ```sh
+//! cargo run --bin disassembler -- dsdt.dat ssdt1.dat
+//! ```
+
+use {
+    acpi_machine_language::{disassembler, interpreter, name, platform},
+    std::{env, fmt, fs, process},
+};
+
+static HOST: Host = Host;
+
+fn main() {
+    platform::set(&HOST);
+    let paths: Vec<String> = env::args().skip(1).collect();
+    if paths.is_empty() {
+        eprintln!("Usage: disassembler TABLE...");
+        process::exit(2);
+    }
+    let failed: usize = paths
+        .iter()
+        .filter(|path| {
+            let asl: Option<String> = fs::read(path)
+                .map_err(|error| eprintln!("Can't read {}: {}", path, error))
+                .ok()
+                .and_then(|table| {
+                    disassembler::disassemble_table(&table).or_else(|| {
+                        eprintln!("Can't disassemble {}", path);
+                        None
+                    })
+                });
+            if let Some(asl) = asl.as_ref() {
+                println!("{}", asl);
+            }
+            asl.is_none()
+        })
+        .count();
+    if failed != 0 {
+        process::exit(1);
+    }
+}
+
+/// # The platform of the disassembler
+/// Disassembling never evaluates AML, so only diagnostic messages reach the platform.
+struct Host;
+
+impl platform::Context for Host {
+    fn context_id(&self) -> usize {
+        0
+    }
+}
+
+impl platform::Debug for Host {
+    fn debug(&self, value: &interpreter::Value) {
+        eprintln!("AML DebugObj = {:#x?}", value);
+    }
+
+    fn log(&self, message: fmt::Arguments) {
+        eprintln!("{}", message);
+    }
+}
+
+impl platform::Firmware for Host {
+    fn acquire_global_lock(&self) -> bool {
+        unreachable!("The disassembler doesn't evaluate AML.")
+    }
+
+    fn release_global_lock(&self) {
+        unreachable!("The disassembler doesn't evaluate AML.")
+    }
+
+    fn find_table(&self, _signature: &str, _oem_id: &str, _oem_table_id: &str) -> Option<Vec<u8>> {
+        unreachable!("The disassembler doesn't evaluate AML.")
+    }
+}
+
+impl platform::Notifier for Host {
+    fn notify(&self, _object: name::Path, _value: u8) {
+        unreachable!("The disassembler doesn't evaluate AML.")
+    }
+}
+
+impl platform::OperationRegion for Host {
+    fn read(&self, _region_space: &interpreter::RegionSpace, _address: usize, _size: usize) -> u64 {
+        unreachable!("The disassembler doesn't evaluate AML.")
+    }
+
+    fn write(
+        &self,
+        _region_space: &interpreter::RegionSpace,
+        _address: usize,
+        _size: usize,
+        _value: u64,
+    ) {
+        unreachable!("The disassembler doesn't evaluate AML.")
+    }
+}
+
+impl platform::Timer for Host {
+    fn nanoseconds(&self) -> u64 {
+        unreachable!("The disassembler doesn't evaluate AML.")
+    }
+
+    fn sleep_milliseconds(&self, _milliseconds: usize) {
+        unreachable!("The disassembler doesn't evaluate AML.")
+    }
+
+    fn stall_microseconds(&self, _microseconds: usize) {
+        unreachable!("The disassembler doesn't evaluate AML.")
+    }
+}
@@ -0,0 +1,178 @@
+//! # Dynamically Loaded Definition Blocks
+//! `Load` and `LoadTable` parse a definition block into the namespace at run time and return a DDB handle, and `Unload` removes the definition block from the namespace.
+//! The syntax tree of a loaded definition block is never freed because a method in it may still be running when it is unloaded.
+//! ## References
+//! * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 19.6.76 Load (Load Definition Block)
+//! * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 19.6.77 LoadTable (Load Definition Block From XSDT)
+//! * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 19.6.144 Unload (Unload Definition Block)
+
+use {
+    super::{
+        debug_println, name, reference, spin,
+        syntax::{self, FirstReader, ReaderOutsideMethod},
+    },
+    alloc::{boxed::Box, collections::BTreeMap, vec::Vec},
+    core::{
+        str,
+        sync::atomic::{AtomicUsize, Ordering},
+    },
+};
+
+/// The size of the system description table header.
+pub(crate) const HEADER_SIZE: usize = 36;
+
+/// The number of loaded definition blocks, which lets lookups skip the lock while nothing is loaded.
+static LOADED: AtomicUsize = AtomicUsize::new(0);
+
+static STATE: spin::Lock<State> = spin::Lock::new(State::new());
+
+/// # Collect devices in loaded definition blocks
+pub fn get_device_paths() -> Vec<name::Path> {
+    STATE
+        .lock()
+        .definition_blocks
+        .values()
+        .flat_map(|definition_block| definition_block.reference_tree.get_local_device_paths())
+        .collect()
+}
+
+/// # Collect processors in loaded definition blocks
+pub fn get_processor_paths() -> Vec<name::Path> {
+    STATE
+        .lock()
+        .definition_blocks
+        .values()
+        .flat_map(|definition_block| definition_block.reference_tree.get_local_processor_paths())
+        .collect()
+}
+
+/// # Collect thermal zones in loaded definition blocks
+pub fn get_thermal_zone_paths() -> Vec<name::Path> {
+    STATE
+        .lock()
+        .definition_blocks
+        .values()
+        .flat_map(|definition_block| {
+            definition_block
+                .reference_tree
+                .get_local_thermal_zone_paths()
+        })
+        .collect()
+}
+
+/// # Collect objects at a path in loaded definition blocks
+/// Returns None if no loaded definition block has the path.
+pub fn get_objects(path: &name::Path) -> Option<Vec<reference::Object<'static>>> {
+    if LOADED.load(Ordering::Acquire) == 0 {
+        return None;
+    }
+    STATE
+        .lock()
+        .definition_blocks
+        .values()
+        .filter_map(|definition_block| definition_block.reference_tree.get_local_objects(path))
+        .fold(None, |objects, found| {
+            let mut objects: Vec<reference::Object<'static>> = objects.unwrap_or_default();
+            objects.extend(found.iter().cloned());
+            Some(objects)
+        })
+}
+
+/// # Load a table into the namespace under the parent path
+/// The table includes its system description table header.
+/// Returns the DDB handle of the loaded definition block.
+pub fn load(table: &[u8], parent: &name::Path) -> Option<u64> {
+    let table_size: Option<usize> = (HEADER_SIZE <= table.len())
+        .then(|| {
+            let length: [u8; 4] = table[4..8].try_into().unwrap();
+            u32::from_le_bytes(length) as usize
+        })
+        .filter(|table_size| {
+            (HEADER_SIZE..=table.len()).contains(table_size)
+                && table[..*table_size]
+                    .iter()
+                    .fold(0x00u8, |sum, byte| sum.wrapping_add(*byte))
+                    == 0
+        });
+    table_size
+        .or_else(|| {
+            debug_println!("Can't load an incorrect table under {:#x?}", parent);
+            None
+        })
+        .and_then(|table_size| {
+            let signature: &str = str::from_utf8(&table[0..4]).unwrap_or_default();
+            let definition_block: &[u8] = &table[HEADER_SIZE..table_size];
+            let mut semantic_tree = name::Node::default();
+            let (mut syntax_tree, _unread_definition_block): (syntax::TermList, &[u8]) =
+                syntax::TermList::first_read(definition_block, &mut semantic_tree, parent)
+                    .filter(|(_syntax_tree, unread_definition_block)| {
+                        unread_definition_block.is_empty()
+                    })
+                    .or_else(|| {
+                        debug_println!("Can't parse {:#x?} under {:#x?}", signature, parent);
+                        None
+                    })?;
+            syntax_tree.read_outside_method(&mut semantic_tree, parent);
+            let syntax_tree: &'static syntax::TermList = Box::leak(Box::new(syntax_tree));
+            let reference_tree = reference::Node::new(syntax_tree, parent);
+            let mut state: spin::Guard<State> = STATE.lock();
+            state.next_handle += 1;
+            let handle: u64 = state.next_handle;
+            debug_println!(
+                "Load {:#x?} under {:#x?} as DDB handle {:#x?}",
+                signature,
+                parent,
+                handle
+            );
+            state.definition_blocks.insert(
+                handle,
+                DefinitionBlock {
+                    parent: parent.clone(),
+                    reference_tree,
+                },
+            );
+            LOADED.fetch_add(1, Ordering::Release);
+            Some(handle)
+        })
+}
+
+/// # Unload a definition block
+/// Returns true if the DDB handle is not loaded.
+pub fn unload(handle: u64) -> bool {
+    match STATE.lock().definition_blocks.remove(&handle) {
+        Some(definition_block) => {
+            LOADED.fetch_sub(1, Ordering::Release);
+            debug_println!(
+                "Unload DDB handle {:#x?} under {:#x?}",
+                handle,
+                definition_block.parent
+            );
+            false
+        }
+        None => {
+            debug_println!("Can't unload DDB handle {:#x?} which is not loaded", handle);
+            true
+        }
+    }
+}
+
+struct DefinitionBlock {
+    parent: name::Path,
+    reference_tree: reference::Node<'static>,
+}
+
+struct State {
+    definition_blocks: BTreeMap<u64, DefinitionBlock>,
+    next_handle: u64,
+}
+
+impl State {
+    const fn new() -> Self {
+        let definition_blocks: BTreeMap<u64, DefinitionBlock> = BTreeMap::new();
+        let next_handle: u64 = 0;
+        Self {
+            definition_blocks,
+            next_handle,
+        }
+    }
+}
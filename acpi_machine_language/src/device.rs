@@ -0,0 +1,176 @@
+//! # ACPI Devices
+//! Enumerates `Device` objects in the namespace into a tree with their identification objects, so that drivers can bind to devices by their IDs.
+//! ## References
+//! * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 6.1 Device Identification Objects
+//! * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 6.3.7 _STA (Device Status)
+
+use {
+    super::{interpreter::Value, name, reference},
+    alloc::{format, string::String, vec::Vec},
+    bitfield_struct::bitfield,
+    core::iter,
+};
+
+#[derive(Debug)]
+pub struct Node {
+    path: name::Path,
+    hid: Option<String>,
+    cids: Vec<String>,
+    uid: Option<String>,
+    adr: Option<u64>,
+    status: Status,
+    children: Vec<Self>,
+}
+
+impl Node {
+    /// # Enumerate devices in the namespace
+    /// Returns the tree whose root is the root of the namespace.
+    /// Children of a device which is neither present nor functioning are not enumerated.
+    pub fn enumerate(root: &reference::Node) -> Self {
+        let device_paths: Vec<name::Path> = root.get_device_paths();
+        Self::new(root, name::Path::root(), &device_paths)
+    }
+
+    pub fn adr(&self) -> Option<u64> {
+        self.adr
+    }
+
+    pub fn children(&self) -> &[Self] {
+        &self.children
+    }
+
+    pub fn cids(&self) -> &[String] {
+        &self.cids
+    }
+
+    /// # Collect the device and its descendants in depth first order
+    pub fn devices(&self) -> Vec<&Self> {
+        iter::once(self)
+            .chain(self.children.iter().flat_map(|child| child.devices()))
+            .collect()
+    }
+
+    pub fn hid(&self) -> Option<&str> {
+        self.hid.as_deref()
+    }
+
+    /// # IDs to bind a driver to the device
+    /// `_HID` comes first and `_CID`s follow in order of preference.
+    pub fn ids(&self) -> impl Iterator<Item = &str> {
+        self.hid
+            .iter()
+            .chain(self.cids.iter())
+            .map(|id| id.as_str())
+    }
+
+    pub fn is_compatible(&self, id: &str) -> bool {
+        self.ids().any(|device_id| device_id == id)
+    }
+
+    pub fn path(&self) -> &name::Path {
+        &self.path
+    }
+
+    pub fn status(&self) -> Status {
+        self.status
+    }
+
+    pub fn uid(&self) -> Option<&str> {
+        self.uid.as_deref()
+    }
+
+    fn new(root: &reference::Node, path: name::Path, device_paths: &[name::Path]) -> Self {
+        let evaluate = |object: &str| root.evaluate(&(path.clone() + object.into()), Vec::new());
+        let hid: Option<String> = evaluate("_HID").as_ref().and_then(id);
+        let cids: Vec<String> = evaluate("_CID").map_or_else(Vec::new, |cid| match cid {
+            Value::Package(cids) => cids.iter().filter_map(id).collect(),
+            cid => id(&cid).into_iter().collect(),
+        });
+        let uid: Option<String> = evaluate("_UID").and_then(|uid| match uid {
+            Value::String(uid) => Some(uid),
+            uid => uid.get_integer().map(|uid| format!("{}", uid)),
+        });
+        let adr: Option<u64> = evaluate("_ADR").and_then(|adr| adr.get_integer());
+        let status: Status = evaluate("_STA")
+            .and_then(|sta| sta.get_integer())
+            .map_or_else(Status::absent_sta, |sta| (sta as u32).into());
+        let children: Vec<Self> = if status.present() || status.functioning() {
+            device_paths
+                .iter()
+                .filter(|device| parent(device, device_paths) == path)
+                .map(|device| Self::new(root, device.clone(), device_paths))
+                .collect()
+        } else {
+            Vec::new()
+        };
+        Self {
+            path,
+            hid,
+            cids,
+            uid,
+            adr,
+            status,
+            children,
+        }
+    }
+}
+
+/// # Device Status
+/// ## References
+/// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 6.3.7 _STA (Device Status)
+#[bitfield(u32)]
+pub struct Status {
+    pub present: bool,
+    pub enabled: bool,
+    pub shown: bool,
+    pub functioning: bool,
+    pub battery_present: bool,
+    #[bits(27)]
+    __: u32,
+}
+
+impl Status {
+    /// A device without `_STA` is assumed to be present, enabled, shown and functioning.
+    fn absent_sta() -> Self {
+        Self::new()
+            .with_present(true)
+            .with_enabled(true)
+            .with_shown(true)
+            .with_functioning(true)
+    }
+}
+
+/// # Decode an EISA ID
+/// The compressed manufacturer code and the product number are stored in big endian.
+/// ## References
+/// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 19.3.4 ASL Macros EISAID
+pub fn eisa_id(id: u32) -> String {
+    let id: u32 = id.swap_bytes();
+    let manufacturer: String = [10, 5, 0]
+        .into_iter()
+        .map(|shift| (b'@' + ((id >> (16 + shift)) & 0x1f) as u8) as char)
+        .collect();
+    let product: u16 = id as u16;
+    format!("{}{:04X}", manufacturer, product)
+}
+
+/// # Convert `_HID` or an element of `_CID` to a string
+fn id(id: &Value) -> Option<String> {
+    match id {
+        Value::String(id) => Some(id.clone()),
+        id => id.get_integer().map(|id| eisa_id(id as u32)),
+    }
+}
+
+/// # The nearest device above a device
+/// Returns the root if no device contains the device.
+fn parent(device: &name::Path, device_paths: &[name::Path]) -> name::Path {
+    let root: name::Path = name::Path::root();
+    iter::successors(Some(device.clone()), |path| {
+        let mut path: name::Path = path.clone();
+        path.pop_last_segment().map(|_| path)
+    })
+    .skip(1)
+    .find(|path| *path == root || device_paths.contains(path))
+    .unwrap_or(root)
+}
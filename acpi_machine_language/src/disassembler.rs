@@ -0,0 +1,183 @@
+//! # AML Disassembler
+//! Print a syntax tree as ASL source in the style of `iasl -d`.
+//! ## References
+//! * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 19 ACPI SOURCE LANGUAGE (ASL) REFERENCE
+
+use {
+    super::{
+        definition_block, name,
+        syntax::{self, Disassembler, FirstReader, ReaderOutsideMethod},
+    },
+    alloc::{format, string::String, vec::Vec},
+    core::str,
+};
+
+const INDENT: &str = "    ";
+
+/// # Disassemble a definition block
+pub fn disassemble(term_list: &syntax::TermList) -> String {
+    term_list
+        .disassemble()
+        .map(|asl| asl.lines().join("\n"))
+        .unwrap_or_default()
+}
+
+/// # Disassemble a table including its system description table header
+/// Returns `None` if the table is too short or its definition block can't be parsed.
+pub fn disassemble_table(table: &[u8]) -> Option<String> {
+    let header: &[u8] = table.get(..definition_block::HEADER_SIZE)?;
+    let length: [u8; 4] = header[4..8].try_into().unwrap();
+    let length: usize = u32::from_le_bytes(length) as usize;
+    let definition_block: &[u8] = table.get(definition_block::HEADER_SIZE..length)?;
+    let mut semantic_tree = name::Node::default();
+    let root_path = name::Path::root();
+    let (mut syntax_tree, _unread_definition_block): (syntax::TermList, &[u8]) =
+        syntax::TermList::first_read(definition_block, &mut semantic_tree, &root_path)
+            .filter(|(_syntax_tree, unread_definition_block)| unread_definition_block.is_empty())?;
+    syntax_tree.read_outside_method(&mut semantic_tree, &root_path);
+    let oem_revision: [u8; 4] = header[24..28].try_into().unwrap();
+    let definition_block: String = format!(
+        "DefinitionBlock (\"\", \"{}\", {}, \"{}\", \"{}\", {:#010x})",
+        str::from_utf8(&header[0..4]).ok()?,
+        header[8],
+        str::from_utf8(&header[10..16]).ok()?,
+        str::from_utf8(&header[16..24]).ok()?,
+        u32::from_le_bytes(oem_revision)
+    );
+    let lines: Vec<String> = disassemble(&syntax_tree)
+        .split('\n')
+        .map(String::from)
+        .collect();
+    Some(Asl::block(definition_block, lines).lines().join("\n"))
+}
+
+/// # ASL of a symbol
+pub enum Asl {
+    /// An argument or an expression, which spans lines if it has a block.
+    Term(String),
+    /// Statements in a block or elements of a list.
+    List(Vec<String>),
+}
+
+impl Asl {
+    /// # Block
+    /// Lines of the list are indented and enclosed in braces below the header.
+    pub fn block(header: String, lines: Vec<String>) -> Self {
+        let lines: Vec<String> = lines
+            .into_iter()
+            .map(|line| format!("{}{}", INDENT, line))
+            .collect();
+        let block: String = if lines.is_empty() {
+            format!("{}\n{{\n}}", header)
+        } else {
+            format!("{}\n{{\n{}\n}}", header, lines.join("\n"))
+        };
+        Self::Term(block)
+    }
+
+    /// # Defined object
+    /// Terms of the children are arguments and lists of the children are the block.
+    pub fn defined_object(name: &str, children: Vec<Option<Self>>) -> Option<Self> {
+        let name: &str = match name {
+            "FromBcd" => "FromBCD",
+            "Noop" => "NoOp",
+            "OpRegion" => "OperationRegion",
+            "ToBcd" => "ToBCD",
+            name => name,
+        };
+        let (arguments, block): (Vec<String>, Option<Vec<String>>) = children
+            .into_iter()
+            .flatten()
+            .fold((Vec::new(), None), |(mut arguments, block), child| {
+                let block: Option<Vec<String>> = match child {
+                    Self::Term(term) => {
+                        arguments.push(term);
+                        block
+                    }
+                    Self::List(lines) => {
+                        let mut block: Vec<String> = block.unwrap_or_default();
+                        block.extend(lines);
+                        Some(block)
+                    }
+                };
+                (arguments, block)
+            });
+        let header: String = if arguments.is_empty() {
+            name.into()
+        } else {
+            format!("{} ({})", name, arguments.join(", "))
+        };
+        Some(match block {
+            Some(block) => Self::block(header, block),
+            None => Self::Term(header),
+        })
+    }
+
+    /// # Elements separated by commas
+    pub fn elements(children: Vec<Option<Self>>) -> Option<Self> {
+        let elements: Vec<String> = children
+            .into_iter()
+            .flatten()
+            .map(|child| child.lines().join("\n"))
+            .collect();
+        let last: usize = elements.len().saturating_sub(1);
+        let lines: Vec<String> = elements
+            .into_iter()
+            .enumerate()
+            .flat_map(|(index, element)| {
+                let element: String = if index == last {
+                    element
+                } else {
+                    format!("{},", element)
+                };
+                element
+                    .split('\n')
+                    .map(String::from)
+                    .collect::<Vec<String>>()
+            })
+            .collect();
+        Some(Self::List(lines))
+    }
+
+    pub fn lines(&self) -> Vec<String> {
+        match self {
+            Self::Term(term) => term.split('\n').map(String::from).collect(),
+            Self::List(lines) => lines.clone(),
+        }
+    }
+
+    /// # Statements
+    /// Each child is a statement even if it is a term.
+    pub fn list(children: Vec<Option<Self>>) -> Option<Self> {
+        let lines: Vec<String> = children
+            .into_iter()
+            .flatten()
+            .flat_map(|child| child.lines())
+            .collect();
+        Some(Self::List(lines))
+    }
+
+    /// # Children in sequence
+    /// A single child is passed through and terms are joined as arguments.
+    pub fn sequence(children: Vec<Option<Self>>) -> Option<Self> {
+        let mut children: Vec<Self> = children.into_iter().flatten().collect();
+        match children.len() {
+            0 => None,
+            1 => children.pop(),
+            _ => {
+                if children.iter().all(|child| matches!(child, Self::Term(_))) {
+                    let terms: Vec<String> = children
+                        .into_iter()
+                        .filter_map(|child| match child {
+                            Self::Term(term) => Some(term),
+                            Self::List(_) => None,
+                        })
+                        .collect();
+                    Some(Self::Term(terms.join(", ")))
+                } else {
+                    Self::list(children.into_iter().map(Some).collect())
+                }
+            }
+        }
+    }
+}
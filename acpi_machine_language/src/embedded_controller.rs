@@ -0,0 +1,194 @@
+//! # ACPI Embedded Controller Interface
+//! The command protocol of the embedded controller over its command and data ports, which are accessed through the SystemIO region space of the platform.
+//! Finding the embedded controller and dispatching its queries to `_Qxx` methods are left to the platform.
+//! ## References
+//! * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 12 ACPI Embedded Controller Interface Specification
+
+use {
+    super::{debug_println, interpreter, name, platform, synchronization},
+    bitfield_struct::bitfield,
+};
+
+#[derive(Debug)]
+pub struct Controller {
+    command_port: u16,
+    data_port: u16,
+    global_lock: bool,
+}
+
+impl Controller {
+    /// # Burst Disable Embedded Controller
+    const BD_EC: u8 = 0x83;
+    /// # Burst Enable Embedded Controller
+    const BE_EC: u8 = 0x82;
+    /// # Burst Acknowledge Byte
+    const BURST_ACK: u8 = 0x90;
+    /// # Query Embedded Controller
+    const QR_EC: u8 = 0x84;
+    /// # Read Embedded Controller
+    const RD_EC: u8 = 0x80;
+    /// # Write Embedded Controller
+    const WR_EC: u8 = 0x81;
+    const TIMEOUT_MICROSECONDS: usize = 0x1000;
+
+    /// # An embedded controller at its ports
+    /// Accesses to it are serialized with the firmware through the global lock if `_GLK` requires it.
+    pub fn new(command_port: u16, data_port: u16, global_lock: bool) -> Self {
+        Self {
+            command_port,
+            data_port,
+            global_lock,
+        }
+    }
+
+    /// # Query the embedded controller
+    /// Returns the query value if an SCI event is pending.
+    pub fn query(&self) -> Option<u8> {
+        self.read_status()
+            .sci_evt()
+            .then(|| {
+                self.transaction(|| {
+                    self.write_command(Self::QR_EC);
+                    self.read_data()
+                })
+            })
+            .flatten()
+            .filter(|query| *query != 0)
+    }
+
+    /// # Read bytes at an address in the little endian
+    /// Multibyte accesses are done in burst mode so that the embedded controller serves them without interruptions.
+    pub fn read(&self, address: u8, length: usize) -> u64 {
+        self.transaction(|| {
+            let burst: bool = 1 < length && self.enable_burst();
+            let value: u64 = (0..length).rev().fold(0, |value, index| {
+                (value << u8::BITS) | (self.read_byte(address.wrapping_add(index as u8)) as u64)
+            });
+            if burst {
+                self.disable_burst();
+            }
+            value
+        })
+    }
+
+    /// # Write bytes at an address in the little endian
+    /// Multibyte accesses are done in burst mode so that the embedded controller serves them without interruptions.
+    pub fn write(&self, address: u8, length: usize, value: u64) {
+        self.transaction(|| {
+            let burst: bool = 1 < length && self.enable_burst();
+            (0..length).for_each(|index| {
+                let byte: u8 = (value >> (index * u8::BITS as usize)) as u8;
+                self.write_byte(address.wrapping_add(index as u8), byte);
+            });
+            if burst {
+                self.disable_burst();
+            }
+        });
+    }
+
+    fn disable_burst(&self) {
+        self.write_command(Self::BD_EC);
+        self.wait_input_buffer_empty();
+    }
+
+    fn enable_burst(&self) -> bool {
+        self.write_command(Self::BE_EC);
+        self.read_data() == Some(Self::BURST_ACK)
+    }
+
+    fn inb(port: u16) -> u8 {
+        platform::get().read(&interpreter::RegionSpace::SystemIo, port as usize, 1) as u8
+    }
+
+    fn outb(port: u16, value: u8) {
+        platform::get().write(
+            &interpreter::RegionSpace::SystemIo,
+            port as usize,
+            1,
+            value as u64,
+        );
+    }
+
+    fn read_byte(&self, address: u8) -> u8 {
+        self.write_command(Self::RD_EC);
+        self.write_data(address);
+        self.read_data().unwrap_or(0xff)
+    }
+
+    fn read_data(&self) -> Option<u8> {
+        self.wait(|status| status.obf())
+            .then(|| Self::inb(self.data_port))
+    }
+
+    fn read_status(&self) -> Status {
+        Self::inb(self.command_port).into()
+    }
+
+    fn transaction<F, T>(&self, transaction: F) -> T
+    where
+        F: FnOnce() -> T,
+    {
+        let global_lock: name::Path = synchronization::global_lock_path();
+        if self.global_lock {
+            synchronization::acquire_mutex(&global_lock, 0, synchronization::WAIT_FOREVER);
+        }
+        let result: T = transaction();
+        if self.global_lock {
+            synchronization::release_mutex(&global_lock);
+        }
+        result
+    }
+
+    fn wait<F>(&self, condition: F) -> bool
+    where
+        F: Fn(Status) -> bool,
+    {
+        let satisfied: bool = (0..Self::TIMEOUT_MICROSECONDS).any(|_| {
+            condition(self.read_status()) || {
+                platform::get().stall_microseconds(1);
+                false
+            }
+        });
+        if !satisfied {
+            debug_println!("The embedded controller timed out.");
+        }
+        satisfied
+    }
+
+    fn wait_input_buffer_empty(&self) -> bool {
+        self.wait(|status| !status.ibf())
+    }
+
+    fn write_byte(&self, address: u8, value: u8) {
+        self.write_command(Self::WR_EC);
+        self.write_data(address);
+        self.write_data(value);
+    }
+
+    fn write_command(&self, command: u8) {
+        if self.wait_input_buffer_empty() {
+            Self::outb(self.command_port, command);
+        }
+    }
+
+    fn write_data(&self, data: u8) {
+        if self.wait_input_buffer_empty() {
+            Self::outb(self.data_port, data);
+        }
+    }
+}
+
+/// # Embedded Controller Status Register
+/// ## References
+/// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 12.2.1 Embedded Controller Status, EC_SC (R)
+#[bitfield(u8)]
+struct Status {
+    obf: bool,
+    ibf: bool,
+    __: bool,
+    cmd: bool,
+    burst: bool,
+    sci_evt: bool,
+    smi_evt: bool,
+    __: bool,
+}
@@ -7,6 +7,11 @@ use {
     },
 };
 
+/// # The largest object which the interpreter creates
+/// Lengths and counts in AML come from the firmware, so a malformed table could otherwise exhaust the heap.
+/// A buffer or a string counts its bytes and a package counts its elements and their contents.
+pub const MAX_OBJECT_SIZE: usize = 0x100000;
+
 /// # AccessType of FieldFlags
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
@@ -30,7 +35,7 @@ impl AccessType {
             Self::DWord => 4,
             Self::QWord => 8,
             Self::Buffer => 1,
-            Self::Reserved => unimplemented!(),
+            Self::Reserved => 1,
         }
     }
 }
@@ -94,6 +99,8 @@ pub enum MatchOperator {
     Lt,
     Ge,
     Gt,
+    /// An operator above `MGT` matches nothing.
+    Reserved,
 }
 
 impl MatchOperator {
@@ -105,6 +112,7 @@ impl MatchOperator {
             Self::Lt => left < right,
             Self::Ge => left >= right,
             Self::Gt => left > right,
+            Self::Reserved => false,
         }
     }
 }
@@ -118,7 +126,7 @@ impl From<u8> for MatchOperator {
             3 => Self::Lt,
             4 => Self::Ge,
             5 => Self::Gt,
-            match_operator => unimplemented!("match_operator = {:#x?}", match_operator),
+            _ => Self::Reserved,
         }
     }
 }
@@ -174,17 +182,22 @@ impl From<&syntax::RegionSpace> for RegionSpace {
     }
 }
 
-#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+/// # Value
+/// Integers compare by their values regardless of their encodings, so `LEqual (Zero, 0x00)` is true.
+#[derive(Clone, Debug)]
 pub enum Value {
     Bool(bool),
     Buffer(Vec<u8>),
     Byte(u8),
     Char(char),
     DWord(u32),
+    DdbHandle(u64),
     One,
     Ones,
     Package(Vec<Self>),
     QWord(u64),
+    /// A name in a package, which refers to the named object.
+    Reference(name::Path),
     Revision,
     String(String),
     Word(u16),
@@ -192,32 +205,40 @@ pub enum Value {
 }
 
 impl Value {
-    pub fn concatenate(&self, other: &Self) -> Self {
+    pub fn concatenate(&self, other: &Self) -> Option<Self> {
         match (self, other) {
             (Self::Byte(low), Self::Byte(high)) => {
-                Self::Word((*low as u16) + ((*high as u16) << u8::BITS))
+                Some(Self::Word((*low as u16) + ((*high as u16) << u8::BITS)))
             }
             (Self::Word(low), Self::Word(high)) => {
-                Self::DWord((*low as u32) + ((*high as u32) << u16::BITS))
+                Some(Self::DWord((*low as u32) + ((*high as u32) << u16::BITS)))
             }
             (Self::DWord(low), Self::DWord(high)) => {
-                Self::QWord((*low as u64) + ((*high as u64) << u32::BITS))
+                Some(Self::QWord((*low as u64) + ((*high as u64) << u32::BITS)))
             }
             (Self::Buffer(first), Self::Buffer(second)) => {
-                Self::Buffer(first.iter().chain(second.iter()).cloned().collect())
+                Self::Buffer(first.iter().chain(second.iter()).cloned().collect()).limit()
             }
             (Self::Package(first), Self::Package(second)) => {
-                Self::Package(first.iter().chain(second.iter()).cloned().collect())
+                Self::Package(first.iter().chain(second.iter()).cloned().collect()).limit()
             }
             (Self::String(first), Self::String(second)) => {
-                Self::String(String::from(first) + second)
+                Self::String(String::from(first) + second).limit()
             }
-            (left, right) => unimplemented!("left = {:#x?}, right = {:#x?}", left, right),
+            (left, right) => left
+                .integer()
+                .zip(right.integer())
+                .and_then(|(left, right)| {
+                    Self::QWord(left)
+                        .to_buffer()
+                        .zip(Self::QWord(right).to_buffer())
+                })
+                .and_then(|(left, right)| left.concatenate(&right)),
         }
     }
 
     /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 19.6.54 From BCD (Convert BCD To Integer)
-    pub fn bcd2integer(&self) -> Self {
+    pub fn bcd2integer(&self) -> Option<Self> {
         match self {
             Self::Byte(byte) => {
                 let bits: Vec<bool> = (0..u8::BITS)
@@ -234,7 +255,7 @@ impl Value {
                     })
                     .rev()
                     .fold(0, |byte, digit| 10 * byte + digit);
-                Self::Byte(byte)
+                Some(Self::Byte(byte))
             }
             Self::Word(word) => {
                 let bits: Vec<bool> = (0..u16::BITS)
@@ -251,7 +272,7 @@ impl Value {
                     })
                     .rev()
                     .fold(0, |word, digit| 10 * word + digit);
-                Self::Word(word)
+                Some(Self::Word(word))
             }
             Self::DWord(dword) => {
                 let bits: Vec<bool> = (0..u32::BITS)
@@ -268,7 +289,7 @@ impl Value {
                     })
                     .rev()
                     .fold(0, |dword, digit| 10 * dword + digit);
-                Self::DWord(dword)
+                Some(Self::DWord(dword))
             }
             Self::QWord(qword) => {
                 let bits: Vec<bool> = (0..u64::BITS)
@@ -285,9 +306,9 @@ impl Value {
                     })
                     .rev()
                     .fold(0, |qword, digit| 10 * qword + digit);
-                Self::QWord(qword)
+                Some(Self::QWord(qword))
             }
-            value => unimplemented!("value = {:#x?}", value),
+            value => Self::QWord(value.integer()?).bcd2integer(),
         }
     }
 
@@ -330,6 +351,8 @@ impl Value {
                     .map(|byte| (*byte >> bit_index) & 1 != 0)
                     .ok_or(Some(bytes.len() * u8_bits))
             }
+            Self::DdbHandle(handle) => Self::QWord(*handle).get_bit(index),
+            Self::Reference(_) => Err(None),
             Self::One => Ok(index == 0),
             Self::Ones => Ok(true),
             Self::Package(package) => {
@@ -354,7 +377,7 @@ impl Value {
                     .map(|byte| (*byte >> bit_index) & 1 != 0)
                     .ok_or(Some(bytes.len() * u8_bits))
             }
-            Self::Revision => unimplemented!(),
+            Self::Revision => Err(None),
             Self::String(string) => {
                 let bytes: Vec<u8> = string.as_bytes().to_vec();
                 let byte_index: usize = index / u8_bits;
@@ -389,6 +412,10 @@ impl Value {
         }
     }
 
+    pub fn get_integer(&self) -> Option<u64> {
+        self.integer()
+    }
+
     /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 19.6.62 Index (Indexed Reference To Member Object)
     pub fn index(&self, index: &Self) -> Option<Self> {
         let index: usize = index.into();
@@ -400,37 +427,49 @@ impl Value {
         }
     }
 
-    /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 19.6.48 FindSetLeftBit (Find First Set Left Bit)
-    pub fn leftest_one_bit_shift(&self) -> Self {
+    /// # Replace an element of a buffer, a package or a string
+    pub fn replace(&self, index: &Self, element: Self) -> Option<Self> {
+        let index: usize = index.into();
         match self {
-            Self::Byte(byte) => Self::Byte(
-                (0..u8::BITS)
-                    .filter(|shift| byte >> shift != 0)
-                    .max()
-                    .map_or(0, |shift| (shift as u8) + 1),
-            ),
-            Self::Word(word) => Self::Byte(
-                (0..u16::BITS)
-                    .filter(|shift| word >> shift != 0)
-                    .max()
-                    .map_or(0, |shift| (shift as u8) + 1),
-            ),
-            Self::DWord(dword) => Self::Byte(
-                (0..u32::BITS)
-                    .filter(|shift| dword >> shift != 0)
-                    .max()
-                    .map_or(0, |shift| (shift as u8) + 1),
-            ),
-            Self::QWord(qword) => Self::Byte(
-                (0..u32::BITS)
-                    .filter(|shift| qword >> shift != 0)
-                    .max()
-                    .map_or(0, |shift| (shift as u8) + 1),
-            ),
-            value => unimplemented!("value = {:#x?}", value),
+            Self::Buffer(bytes) => (index < bytes.len()).then(|| {
+                let mut bytes: Vec<u8> = bytes.clone();
+                bytes[index] = (&element).into();
+                Self::Buffer(bytes)
+            }),
+            Self::Package(elements) => (index < elements.len())
+                .then(|| {
+                    let mut elements: Vec<Self> = elements.clone();
+                    elements[index] = element;
+                    Self::Package(elements)
+                })
+                .and_then(Self::limit),
+            _ => None,
         }
     }
 
+    /// # The value unless it's larger than `MAX_OBJECT_SIZE`
+    /// A package which contains itself doubles on every store, so the check bounds the size before it grows further.
+    pub fn limit(self) -> Option<Self> {
+        (self.footprint() <= MAX_OBJECT_SIZE).then_some(self)
+    }
+
+    fn footprint(&self) -> usize {
+        match self {
+            Self::Buffer(buffer) => buffer.len(),
+            Self::Package(package) => package.iter().fold(package.len(), |footprint, element| {
+                footprint.saturating_add(element.footprint())
+            }),
+            Self::String(string) => string.len(),
+            _ => 1,
+        }
+    }
+
+    /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 19.6.48 FindSetLeftBit (Find First Set Left Bit)
+    pub fn leftest_one_bit_shift(&self) -> Option<Self> {
+        self.integer()
+            .map(|integer| Self::Byte((u64::BITS - integer.leading_zeros()) as u8))
+    }
+
     /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 19.6.85 Mid (Extract Portion of Buffer or String)
     pub fn mid(&self, index: &Self, length: &Self) -> Option<Self> {
         let index: usize = index.into();
@@ -477,55 +516,33 @@ impl Value {
             Self::String(_) => Self::Byte(2),
             Self::Buffer(_) => Self::Byte(3),
             Self::Package(_) => Self::Byte(4),
-            value => unimplemented!("value = {:#x?}", value),
+            Self::DdbHandle(_) => Self::Byte(15),
+            _ => Self::Byte(0),
         }
     }
 
     /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 19.6.49 FindSetRightBit (Find First Set Right Bit)
-    pub fn rightest_one_bit_shift(&self) -> Self {
-        match self {
-            Self::Byte(byte) => Self::Byte(
-                (0..u8::BITS)
-                    .filter(|shift| byte << shift != 0)
-                    .max()
-                    .map_or(0, |shift| 8 - (shift as u8)),
-            ),
-            Self::Word(word) => Self::Byte(
-                (0..u16::BITS)
-                    .filter(|shift| word << shift != 0)
-                    .max()
-                    .map_or(0, |shift| 8 - (shift as u8)),
-            ),
-            Self::DWord(dword) => Self::Byte(
-                (0..u32::BITS)
-                    .filter(|shift| dword << shift != 0)
-                    .max()
-                    .map_or(0, |shift| 8 - (shift as u8)),
-            ),
-            Self::QWord(qword) => Self::Byte(
-                (0..u32::BITS)
-                    .filter(|shift| qword << shift != 0)
-                    .max()
-                    .map_or(0, |shift| 8 - (shift as u8)),
-            ),
-            value => unimplemented!("value = {:#x?}", value),
-        }
+    pub fn rightest_one_bit_shift(&self) -> Option<Self> {
+        self.integer().map(|integer| match integer {
+            0 => Self::Byte(0),
+            integer => Self::Byte((integer.trailing_zeros() + 1) as u8),
+        })
     }
 
     /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 19.6.124 SizeOf (Get Data Object Size)
-    pub fn size(&self) -> Self {
+    pub fn size(&self) -> Option<Self> {
         let size: usize = match self {
             Self::Buffer(buffer) => buffer.len(),
             Self::String(string) => string.len(),
             Self::Package(package) => package.len(),
-            value => unimplemented!("value = {:#x?}", value),
+            _ => return None,
         };
         let size: u64 = size as u64;
-        Self::QWord(size)
+        Some(Self::QWord(size))
     }
 
     /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 19.6.135 ToBCD (Convert Integer to BCD)
-    pub fn to_bcd(&self) -> Self {
+    pub fn to_bcd(&self) -> Option<Self> {
         match self {
             Self::Byte(byte) => {
                 let decimal_digit_iterator: DecimalDigitIterator = (*byte).into();
@@ -534,7 +551,7 @@ impl Value {
                     .into_iter()
                     .rev()
                     .fold(0, |word, digit| (word << 4) + (digit as u16));
-                Self::Word(word)
+                Some(Self::Word(word))
             }
             Self::Word(word) => {
                 let decimal_digit_iterator: DecimalDigitIterator = (*word).into();
@@ -543,7 +560,7 @@ impl Value {
                     .into_iter()
                     .rev()
                     .fold(0, |dword, digit| (dword << 4) + (digit as u32));
-                Self::DWord(dword)
+                Some(Self::DWord(dword))
             }
             Self::DWord(dword) => {
                 let decimal_digit_iterator: DecimalDigitIterator = (*dword).into();
@@ -552,7 +569,7 @@ impl Value {
                     .into_iter()
                     .rev()
                     .fold(0, |qword, digit| (qword << 4) + (digit as u64));
-                Self::QWord(qword)
+                Some(Self::QWord(qword))
             }
             Self::QWord(qword) => {
                 let decimal_digit_iterator: DecimalDigitIterator = (*qword).into();
@@ -561,99 +578,106 @@ impl Value {
                     .into_iter()
                     .rev()
                     .fold(0, |qword, digit| (qword << 4) + (digit as u64));
-                Self::QWord(qword)
+                Some(Self::QWord(qword))
             }
-            value => unimplemented!("value = {:#x?}", value),
+            value => Self::QWord(value.integer()?).to_bcd(),
         }
     }
 
     /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 19.6.136 ToBuffer (Conver Data to Buffer)
-    pub fn to_buffer(&self) -> Self {
+    pub fn to_buffer(&self) -> Option<Self> {
         match self {
             Self::Byte(byte) => {
                 let buffer: Vec<u8> = iter::once(*byte).collect();
-                Self::Buffer(buffer)
+                Some(Self::Buffer(buffer))
             }
             Self::Word(word) => {
                 let buffer: Vec<u8> = (0..mem::size_of::<u16>())
                     .map(|offset| (word >> (offset * (u8::BITS as usize))) as u8)
                     .collect();
-                Self::Buffer(buffer)
+                Some(Self::Buffer(buffer))
             }
             Self::DWord(dword) => {
                 let buffer: Vec<u8> = (0..mem::size_of::<u32>())
                     .map(|offset| (dword >> (offset * (u8::BITS as usize))) as u8)
                     .collect();
-                Self::Buffer(buffer)
+                Some(Self::Buffer(buffer))
             }
             Self::QWord(qword) => {
                 let buffer: Vec<u8> = (0..mem::size_of::<u64>())
                     .map(|offset| (qword >> (offset * (u8::BITS as usize))) as u8)
                     .collect();
-                Self::Buffer(buffer)
+                Some(Self::Buffer(buffer))
             }
-            Self::Buffer(buffer) => Self::Buffer(buffer.to_vec()),
+            Self::Buffer(buffer) => Some(Self::Buffer(buffer.to_vec())),
             Self::String(string) => {
                 let buffer: Vec<u8> = if string.is_empty() {
                     Vec::new()
                 } else {
                     string.bytes().chain(iter::once(0)).collect()
                 };
-                Self::Buffer(buffer)
+                Some(Self::Buffer(buffer))
             }
-            value => unimplemented!("value = {:#x?}", value),
+            Self::DdbHandle(handle) => Self::QWord(*handle).to_buffer(),
+            value => Self::QWord(value.integer()?).to_buffer(),
         }
     }
 
     /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 19.6.137 ToDecimalString (Convert Data to Decimal String)
-    pub fn to_decimal_string(&self) -> Self {
+    pub fn to_decimal_string(&self) -> Option<Self> {
         match self {
-            Self::Byte(byte) => Self::String(format!("{}", byte)),
-            Self::Word(word) => Self::String(format!("{}", word)),
-            Self::DWord(dword) => Self::String(format!("{}", dword)),
-            Self::QWord(qword) => Self::String(format!("{}", qword)),
+            Self::Byte(byte) => Some(Self::String(format!("{}", byte))),
+            Self::Word(word) => Some(Self::String(format!("{}", word))),
+            Self::DWord(dword) => Some(Self::String(format!("{}", dword))),
+            Self::QWord(qword) => Some(Self::String(format!("{}", qword))),
             Self::Buffer(buffer) => {
                 let bytes: Vec<String> = buffer.iter().map(|byte| format!("{}", byte)).collect();
                 let string: String = bytes.join(",");
-                Self::String(string)
+                Some(Self::String(string))
             }
-            Self::String(string) => Self::String(String::from(string)),
-            value => unimplemented!("value = {:#x?}", value),
+            Self::String(string) => Some(Self::String(String::from(string))),
+            Self::DdbHandle(handle) => Self::QWord(*handle).to_decimal_string(),
+            value => Self::QWord(value.integer()?).to_decimal_string(),
         }
     }
 
     /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 19.6.138 ToHexString (Convert Data to Hexadecimal String)
-    pub fn to_hex_string(&self) -> Self {
+    pub fn to_hex_string(&self) -> Option<Self> {
         match self {
-            Self::Byte(byte) => Self::String(format!("{:#04x}", byte)),
-            Self::Word(word) => Self::String(format!("{:#06x}", word)),
-            Self::DWord(dword) => Self::String(format!("{:#010x}", dword)),
-            Self::QWord(qword) => Self::String(format!("{:#018x}", qword)),
+            Self::Byte(byte) => Some(Self::String(format!("{:#04x}", byte))),
+            Self::Word(word) => Some(Self::String(format!("{:#06x}", word))),
+            Self::DWord(dword) => Some(Self::String(format!("{:#010x}", dword))),
+            Self::QWord(qword) => Some(Self::String(format!("{:#018x}", qword))),
             Self::Buffer(buffer) => {
                 let bytes: Vec<String> =
                     buffer.iter().map(|byte| format!("{:#04x}", byte)).collect();
                 let string: String = bytes.join(",");
-                Self::String(string)
+                Some(Self::String(string))
             }
-            Self::String(string) => Self::String(String::from(string)),
-            value => unimplemented!("value = {:#x?}", value),
+            Self::String(string) => Some(Self::String(String::from(string))),
+            Self::DdbHandle(handle) => Self::QWord(*handle).to_hex_string(),
+            value => Self::QWord(value.integer()?).to_hex_string(),
         }
     }
 
     /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 19.6.139 ToInteger (Convert Data to Integer)
-    pub fn to_integer(&self) -> Self {
+    pub fn to_integer(&self) -> Option<Self> {
         match self {
-            Self::Byte(byte) => Self::Byte(*byte),
-            Self::Word(word) => Self::Word(*word),
-            Self::DWord(dword) => Self::DWord(*dword),
-            Self::QWord(qword) => Self::QWord(*qword),
+            Self::Byte(byte) => Some(Self::Byte(*byte)),
+            Self::Word(word) => Some(Self::Word(*word)),
+            Self::DWord(dword) => Some(Self::DWord(*dword)),
+            Self::QWord(qword) => Some(Self::QWord(*qword)),
+            Self::DdbHandle(handle) => Some(Self::QWord(*handle)),
+            Self::Zero => Some(Self::Zero),
+            Self::One => Some(Self::One),
+            Self::Ones => Some(Self::Ones),
             Self::Buffer(buffer) => {
                 let qword: u64 = buffer
                     .iter()
                     .take(mem::size_of::<u64>())
                     .rev()
                     .fold(0, |qword, byte| (qword << u8::BITS) + (*byte as u64));
-                Self::QWord(qword)
+                Some(Self::QWord(qword))
             }
             Self::String(string) => {
                 let radix: u64 = if string.starts_with("0x") || string.starts_with("0X") {
@@ -666,10 +690,12 @@ impl Value {
                     .filter_map(|character| {
                         character.to_digit(radix as u32).map(|digit| digit as u64)
                     })
-                    .fold(0, |qword, digit| qword * radix + digit);
-                Self::QWord(qword)
+                    .fold(0, |qword: u64, digit| {
+                        qword.wrapping_mul(radix).wrapping_add(digit)
+                    });
+                Some(Self::QWord(qword))
             }
-            value => unimplemented!("Value = {:#x?}", value),
+            value => value.integer().map(Self::QWord),
         }
     }
 
@@ -689,15 +715,95 @@ impl Value {
             )
             .ok()
             .map(Self::String),
-            value => unimplemented!("Value = {:#x?}", value),
+            _ => None,
+        }
+    }
+
+    /// # The integer of an operand
+    /// A buffer or a string is converted implicitly and an object which can't be converted reads as zero.
+    fn implicit_integer(&self) -> u64 {
+        self.to_integer()
+            .as_ref()
+            .and_then(Self::integer)
+            .unwrap_or_default()
+    }
+
+    /// Whether an operator expecting integers converts the value implicitly.
+    /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 19.3.5.7 Data Type Conversion Rules
+    fn is_convertible_to_integer(&self) -> bool {
+        matches!(
+            self,
+            Self::Buffer(_)
+                | Self::Byte(_)
+                | Self::DWord(_)
+                | Self::One
+                | Self::Ones
+                | Self::QWord(_)
+                | Self::String(_)
+                | Self::Word(_)
+                | Self::Zero
+        )
+    }
+
+    /// The integer if the value is an integer.
+    fn integer(&self) -> Option<u64> {
+        match self {
+            Self::Bool(value) => Some(*value as u64),
+            Self::Byte(byte) => Some(*byte as u64),
+            Self::DWord(dword) => Some(*dword as u64),
+            Self::One => Some(1),
+            Self::Ones => Some(u64::MAX),
+            Self::QWord(qword) => Some(*qword),
+            Self::Word(word) => Some(*word as u64),
+            Self::Zero => Some(0),
+            _ => None,
+        }
+    }
+
+    /// # Apply an operator to the integers of two values
+    /// The result keeps the encoding of the operands if it fits in it, or is a QWord otherwise because AML integers are 64-bit.
+    /// It is `None` if either value is not an integer or the operator fails, such as a division by zero.
+    fn integer_operation(
+        &self,
+        other: &Self,
+        operator: fn(u64, u64) -> Option<u64>,
+    ) -> Option<Self> {
+        let (left, right): (Self, Self) = self.match_type(other)?;
+        let value: u64 = operator(left.integer()?, right.integer()?)?;
+        Some(match left {
+            Self::Byte(_) => u8::try_from(value).map_or(Self::QWord(value), Self::Byte),
+            Self::Word(_) => u16::try_from(value).map_or(Self::QWord(value), Self::Word),
+            Self::DWord(_) => u32::try_from(value).map_or(Self::QWord(value), Self::DWord),
+            _ => Self::QWord(value),
+        })
+    }
+
+    /// The order of kinds of values which are not integers.
+    fn kind_order(&self) -> u8 {
+        match self {
+            Self::Bool(_)
+            | Self::Byte(_)
+            | Self::DWord(_)
+            | Self::One
+            | Self::Ones
+            | Self::QWord(_)
+            | Self::Word(_)
+            | Self::Zero => 0,
+            Self::Buffer(_) => 1,
+            Self::Char(_) => 2,
+            Self::DdbHandle(_) => 3,
+            Self::Package(_) => 4,
+            Self::Reference(_) => 5,
+            Self::Revision => 6,
+            Self::String(_) => 7,
         }
     }
 
-    fn match_type(&self, other: &Self) -> (Self, Self) {
+    pub(crate) fn match_type(&self, other: &Self) -> Option<(Self, Self)> {
         match (self, other) {
-            (Self::Bool(left), Self::Bool(right)) => (Self::Bool(*left), Self::Bool(*right)),
+            (Self::Bool(left), Self::Bool(right)) => Some((Self::Bool(*left), Self::Bool(*right))),
             (Self::Buffer(left), Self::Buffer(right)) => {
-                (Self::Buffer(left.clone()), Self::Buffer(right.clone()))
+                Some((Self::Buffer(left.clone()), Self::Buffer(right.clone())))
             }
             (Self::Buffer(left), Self::String(right)) => {
                 let right: Vec<u8> = right
@@ -705,96 +811,117 @@ impl Value {
                     .chain(iter::repeat(0x00))
                     .take(left.len())
                     .collect();
-                (Self::Buffer(left.clone()), Self::Buffer(right))
+                Some((Self::Buffer(left.clone()), Self::Buffer(right)))
             }
-            (Self::Byte(left), Self::Byte(right)) => (Self::Byte(*left), Self::Byte(*right)),
+            (Self::Byte(left), Self::Byte(right)) => Some((Self::Byte(*left), Self::Byte(*right))),
             (Self::Byte(left), Self::DWord(right)) => {
-                (Self::DWord(*left as u32), Self::DWord(*right))
+                Some((Self::DWord(*left as u32), Self::DWord(*right)))
             }
-            (Self::Byte(left), Self::One) => (Self::Byte(*left), Self::Byte(0x01)),
-            (Self::Byte(left), Self::Ones) => (Self::Byte(*left), Self::Byte(0xff)),
+            (Self::Byte(left), Self::One) => Some((Self::Byte(*left), Self::Byte(0x01))),
+            (Self::Byte(left), Self::Ones) => Some((Self::Byte(*left), Self::Byte(0xff))),
             (Self::Byte(left), Self::QWord(right)) => {
-                (Self::QWord(*left as u64), Self::QWord(*right))
+                Some((Self::QWord(*left as u64), Self::QWord(*right)))
+            }
+            (Self::Byte(left), Self::Word(right)) => {
+                Some((Self::Word(*left as u16), Self::Word(*right)))
             }
-            (Self::Byte(left), Self::Word(right)) => (Self::Word(*left as u16), Self::Word(*right)),
-            (Self::Byte(left), Self::Zero) => (Self::Byte(*left), Self::Byte(0x00)),
-            (Self::Char(left), Self::Char(right)) => (Self::Char(*left), Self::Char(*right)),
+            (Self::Byte(left), Self::Zero) => Some((Self::Byte(*left), Self::Byte(0x00))),
+            (Self::Char(left), Self::Char(right)) => Some((Self::Char(*left), Self::Char(*right))),
             (Self::DWord(left), Self::Byte(right)) => {
-                (Self::DWord(*left), Self::DWord(*right as u32))
+                Some((Self::DWord(*left), Self::DWord(*right as u32)))
             }
-            (Self::DWord(left), Self::DWord(right)) => (Self::DWord(*left), Self::DWord(*right)),
-            (Self::DWord(left), Self::One) => (Self::DWord(*left), Self::DWord(0x00000001)),
-            (Self::DWord(left), Self::Ones) => (Self::DWord(*left), Self::DWord(0xffffffff)),
+            (Self::DWord(left), Self::DWord(right)) => {
+                Some((Self::DWord(*left), Self::DWord(*right)))
+            }
+            (Self::DWord(left), Self::One) => Some((Self::DWord(*left), Self::DWord(0x00000001))),
+            (Self::DWord(left), Self::Ones) => Some((Self::DWord(*left), Self::DWord(0xffffffff))),
             (Self::DWord(left), Self::QWord(right)) => {
-                (Self::QWord(*left as u64), Self::QWord(*right))
+                Some((Self::QWord(*left as u64), Self::QWord(*right)))
             }
             (Self::DWord(left), Self::Word(right)) => {
-                (Self::DWord(*left), Self::DWord(*right as u32))
+                Some((Self::DWord(*left), Self::DWord(*right as u32)))
             }
-            (Self::DWord(left), Self::Zero) => (Self::DWord(*left), Self::DWord(0x00000000)),
-            (Self::One, Self::Byte(right)) => (Self::Byte(0x01), Self::Byte(*right)),
-            (Self::One, Self::DWord(right)) => (Self::DWord(0x00000001), Self::DWord(*right)),
-            (Self::One, Self::One) => (Self::One, Self::One),
-            (Self::One, Self::Ones) => (Self::One, Self::Ones),
+            (Self::DWord(left), Self::Zero) => Some((Self::DWord(*left), Self::DWord(0x00000000))),
+            (Self::One, Self::Byte(right)) => Some((Self::Byte(0x01), Self::Byte(*right))),
+            (Self::One, Self::DWord(right)) => Some((Self::DWord(0x00000001), Self::DWord(*right))),
+            (Self::One, Self::One) => Some((Self::One, Self::One)),
+            (Self::One, Self::Ones) => Some((Self::One, Self::Ones)),
             (Self::One, Self::QWord(right)) => {
-                (Self::QWord(0x0000000000000001), Self::QWord(*right))
+                Some((Self::QWord(0x0000000000000001), Self::QWord(*right)))
+            }
+            (Self::One, Self::Word(right)) => Some((Self::Word(0x0001), Self::Word(*right))),
+            (Self::One, Self::Zero) => Some((Self::One, Self::Zero)),
+            (Self::Ones, Self::Byte(right)) => Some((Self::Byte(0xff), Self::Byte(*right))),
+            (Self::Ones, Self::DWord(right)) => {
+                Some((Self::DWord(0xffffffff), Self::DWord(*right)))
             }
-            (Self::One, Self::Word(right)) => (Self::Word(0x0001), Self::Word(*right)),
-            (Self::One, Self::Zero) => (Self::One, Self::Zero),
-            (Self::Ones, Self::Byte(right)) => (Self::Byte(0xff), Self::Byte(*right)),
-            (Self::Ones, Self::DWord(right)) => (Self::DWord(0xffffffff), Self::DWord(*right)),
-            (Self::Ones, Self::One) => (Self::Ones, Self::One),
-            (Self::Ones, Self::Ones) => (Self::Ones, Self::Ones),
+            (Self::Ones, Self::One) => Some((Self::Ones, Self::One)),
+            (Self::Ones, Self::Ones) => Some((Self::Ones, Self::Ones)),
             (Self::Ones, Self::QWord(right)) => {
-                (Self::QWord(0xffffffffffffffff), Self::QWord(*right))
+                Some((Self::QWord(0xffffffffffffffff), Self::QWord(*right)))
             }
-            (Self::Ones, Self::Word(right)) => (Self::Word(0xffff), Self::Word(*right)),
-            (Self::Ones, Self::Zero) => (Self::Ones, Self::Zero),
+            (Self::Ones, Self::Word(right)) => Some((Self::Word(0xffff), Self::Word(*right))),
+            (Self::Ones, Self::Zero) => Some((Self::Ones, Self::Zero)),
             (Self::Package(left), Self::Package(right)) => {
-                (Self::Package(left.clone()), Self::Package(right.clone()))
+                Some((Self::Package(left.clone()), Self::Package(right.clone())))
             }
             (Self::QWord(left), Self::Byte(right)) => {
-                (Self::QWord(*left), Self::QWord(*right as u64))
+                Some((Self::QWord(*left), Self::QWord(*right as u64)))
             }
             (Self::QWord(left), Self::DWord(right)) => {
-                (Self::QWord(*left), Self::QWord(*right as u64))
+                Some((Self::QWord(*left), Self::QWord(*right as u64)))
+            }
+            (Self::QWord(left), Self::One) => {
+                Some((Self::QWord(*left), Self::QWord(0x0000000000000001)))
             }
-            (Self::QWord(left), Self::One) => (Self::QWord(*left), Self::QWord(0x0000000000000001)),
             (Self::QWord(left), Self::Ones) => {
-                (Self::QWord(*left), Self::QWord(0xffffffffffffffff))
+                Some((Self::QWord(*left), Self::QWord(0xffffffffffffffff)))
+            }
+            (Self::QWord(left), Self::QWord(right)) => {
+                Some((Self::QWord(*left), Self::QWord(*right)))
             }
-            (Self::QWord(left), Self::QWord(right)) => (Self::QWord(*left), Self::QWord(*right)),
             (Self::QWord(left), Self::Word(right)) => {
-                (Self::QWord(*left), Self::QWord(*right as u64))
+                Some((Self::QWord(*left), Self::QWord(*right as u64)))
             }
             (Self::QWord(left), Self::Zero) => {
-                (Self::QWord(*left), Self::QWord(0x0000000000000000))
+                Some((Self::QWord(*left), Self::QWord(0x0000000000000000)))
             }
-            (Self::Revision, Self::Revision) => (Self::Revision, Self::Revision),
+            (Self::Revision, Self::Revision) => Some((Self::Revision, Self::Revision)),
             (Self::String(left), Self::String(right)) => {
-                (Self::String(left.clone()), Self::String(right.clone()))
+                Some((Self::String(left.clone()), Self::String(right.clone())))
+            }
+            (Self::Word(left), Self::Byte(right)) => {
+                Some((Self::Word(*left), Self::Word(*right as u16)))
             }
-            (Self::Word(left), Self::Byte(right)) => (Self::Word(*left), Self::Word(*right as u16)),
             (Self::Word(left), Self::DWord(right)) => {
-                (Self::DWord(*left as u32), Self::DWord(*right))
+                Some((Self::DWord(*left as u32), Self::DWord(*right)))
             }
-            (Self::Word(left), Self::One) => (Self::Word(*left), Self::Word(0x0001)),
-            (Self::Word(left), Self::Ones) => (Self::Word(*left), Self::Word(0xffff)),
+            (Self::Word(left), Self::One) => Some((Self::Word(*left), Self::Word(0x0001))),
+            (Self::Word(left), Self::Ones) => Some((Self::Word(*left), Self::Word(0xffff))),
             (Self::Word(left), Self::QWord(right)) => {
-                (Self::QWord(*left as u64), Self::QWord(*right))
+                Some((Self::QWord(*left as u64), Self::QWord(*right)))
+            }
+            (Self::Word(left), Self::Word(right)) => Some((Self::Word(*left), Self::Word(*right))),
+            (Self::Word(left), Self::Zero) => Some((Self::Word(*left), Self::Word(0x0000))),
+            (Self::Zero, Self::Byte(right)) => Some((Self::Byte(0x00), Self::Byte(*right))),
+            (Self::Zero, Self::DWord(right)) => {
+                Some((Self::DWord(0x00000000), Self::DWord(*right)))
             }
-            (Self::Word(left), Self::Word(right)) => (Self::Word(*left), Self::Word(*right)),
-            (Self::Word(left), Self::Zero) => (Self::Word(*left), Self::Word(0x0000)),
-            (Self::Zero, Self::Byte(right)) => (Self::Byte(0x00), Self::Byte(*right)),
-            (Self::Zero, Self::DWord(right)) => (Self::DWord(0x00000000), Self::DWord(*right)),
-            (Self::Zero, Self::One) => (Self::Zero, Self::One),
-            (Self::Zero, Self::Ones) => (Self::Zero, Self::Ones),
+            (Self::Zero, Self::One) => Some((Self::Zero, Self::One)),
+            (Self::Zero, Self::Ones) => Some((Self::Zero, Self::Ones)),
             (Self::Zero, Self::QWord(right)) => {
-                (Self::QWord(0x0000000000000000), Self::QWord(*right))
+                Some((Self::QWord(0x0000000000000000), Self::QWord(*right)))
+            }
+            (Self::Zero, Self::Word(right)) => Some((Self::Word(0x0000), Self::Word(*right))),
+            (Self::Zero, Self::Zero) => Some((Self::Zero, Self::Zero)),
+            (left, right)
+                if left.is_convertible_to_integer() && right.is_convertible_to_integer() =>
+            {
+                left.to_integer()
+                    .zip(right.to_integer())
+                    .and_then(|(left, right)| left.match_type(&right))
             }
-            (Self::Zero, Self::Word(right)) => (Self::Word(0x0000), Self::Word(*right)),
-            (Self::Zero, Self::Zero) => (Self::Zero, Self::Zero),
-            (left, right) => unimplemented!("left = {:#x?}, right = {:#x?}", left, right),
+            _ => None,
         }
     }
 }
@@ -806,114 +933,84 @@ impl<'a> Value {
 }
 
 impl Add for Value {
-    type Output = Self;
+    type Output = Option<Self>;
 
     fn add(self, other: Self) -> Self::Output {
-        match self.match_type(&other) {
-            (Self::Byte(left), Self::Byte(right)) => Self::Output::Byte(left + right),
-            (Self::Word(left), Self::Word(right)) => Self::Output::Word(left + right),
-            (Self::DWord(left), Self::DWord(right)) => Self::Output::DWord(left + right),
-            (Self::QWord(left), Self::QWord(right)) => Self::Output::QWord(left + right),
-            (Self::Zero, Self::Zero) => Self::Output::Zero,
-            (Self::Zero, Self::One) => Self::Output::One,
-            (Self::Zero, Self::Ones) => Self::Output::Ones,
-            (Self::One, Self::Zero) => Self::Output::One,
-            (Self::One, Self::One) => Self::Output::QWord(1 + 1),
-            (Self::Ones, Self::Zero) => Self::Output::Ones,
-            (left, right) => unimplemented!("left = {:#x?}, right = {:#x?}", left, right),
-        }
+        self.integer_operation(&other, |left, right| Some(left.wrapping_add(right)))
     }
 }
 
 impl BitAnd for Value {
-    type Output = Self;
+    type Output = Option<Self>;
 
     fn bitand(self, other: Self) -> Self::Output {
-        match self.match_type(&other) {
-            (Self::Byte(left), Self::Byte(right)) => Self::Output::Byte(left & right),
-            (Self::Word(left), Self::Word(right)) => Self::Output::Word(left & right),
-            (Self::DWord(left), Self::DWord(right)) => Self::Output::DWord(left & right),
-            (Self::QWord(left), Self::QWord(right)) => Self::Output::QWord(left & right),
-            (Self::Zero, Self::Zero) => Self::Output::Zero,
-            (Self::Zero, Self::One) => Self::Output::Zero,
-            (Self::Zero, Self::Ones) => Self::Output::Zero,
-            (Self::One, Self::Zero) => Self::Output::Zero,
-            (Self::One, Self::One) => Self::Output::One,
-            (Self::One, Self::Ones) => Self::Output::One,
-            (Self::Ones, Self::Zero) => Self::Output::Zero,
-            (Self::Ones, Self::One) => Self::Output::One,
-            (Self::Ones, Self::Ones) => Self::Output::Ones,
-            (left, right) => unimplemented!("left = {:#x?}, right = {:#x?}", left, right),
-        }
+        self.integer_operation(&other, |left, right| Some(left & right))
     }
 }
 
 impl BitOr for Value {
-    type Output = Self;
+    type Output = Option<Self>;
 
     fn bitor(self, other: Self) -> Self::Output {
-        match self.match_type(&other) {
-            (Self::Byte(left), Self::Byte(right)) => Self::Output::Byte(left | right),
-            (Self::Word(left), Self::Word(right)) => Self::Output::Word(left | right),
-            (Self::DWord(left), Self::DWord(right)) => Self::Output::DWord(left | right),
-            (Self::QWord(left), Self::QWord(right)) => Self::Output::QWord(left | right),
-            (Self::Zero, Self::Zero) => Self::Output::Zero,
-            (Self::Zero, Self::One) => Self::Output::One,
-            (Self::Zero, Self::Ones) => Self::Output::Ones,
-            (Self::One, Self::Zero) => Self::Output::One,
-            (Self::One, Self::One) => Self::Output::One,
-            (Self::One, Self::Ones) => Self::Output::Ones,
-            (Self::Ones, Self::Zero) => Self::Output::Ones,
-            (Self::Ones, Self::One) => Self::Output::Ones,
-            (Self::Ones, Self::Ones) => Self::Output::Ones,
-            (left, right) => unimplemented!("left = {:#x?}, right = {:#x?}", left, right),
-        }
+        self.integer_operation(&other, |left, right| Some(left | right))
     }
 }
 
 impl BitXor for Value {
-    type Output = Self;
+    type Output = Option<Self>;
 
     fn bitxor(self, other: Self) -> Self::Output {
-        match self.match_type(&other) {
-            (Self::Byte(left), Self::Byte(right)) => Self::Output::Byte(left ^ right),
-            (Self::Word(left), Self::Word(right)) => Self::Output::Word(left ^ right),
-            (Self::DWord(left), Self::DWord(right)) => Self::Output::DWord(left ^ right),
-            (Self::QWord(left), Self::QWord(right)) => Self::Output::QWord(left ^ right),
-            (Self::Zero, Self::Zero) => Self::Output::Zero,
-            (Self::Zero, Self::One) => Self::Output::One,
-            (Self::Zero, Self::Ones) => Self::Output::Ones,
-            (Self::One, Self::Zero) => Self::Output::One,
-            (Self::One, Self::One) => Self::Output::Zero,
-            (Self::One, Self::Ones) => Self::Output::QWord(1 ^ u64::MAX),
-            (Self::Ones, Self::Zero) => Self::Output::Ones,
-            (Self::Ones, Self::One) => Self::Output::QWord(u64::MAX ^ 1),
-            (Self::Ones, Self::Ones) => Self::Output::Zero,
-            (left, right) => unimplemented!("left = {:#x?}, right = {:#x?}", left, right),
-        }
+        self.integer_operation(&other, |left, right| Some(left ^ right))
     }
 }
 
 impl Div for Value {
-    type Output = Self;
+    type Output = Option<Self>;
 
     fn div(self, other: Self) -> Self::Output {
-        match self.match_type(&other) {
-            (Self::Byte(left), Self::Byte(right)) => Self::Output::Byte(left / right),
-            (Self::Word(left), Self::Word(right)) => Self::Output::Word(left / right),
-            (Self::DWord(left), Self::DWord(right)) => Self::Output::DWord(left / right),
-            (Self::QWord(left), Self::QWord(right)) => Self::Output::QWord(left / right),
-            (Self::Zero, Self::One) => Self::Output::Zero,
-            (Self::Zero, Self::Ones) => Self::Output::Zero,
-            (Self::One, Self::One) => Self::Output::One,
-            (Self::One, Self::Ones) => Self::Output::Zero,
-            (Self::Ones, Self::One) => Self::Output::Ones,
-            (Self::Ones, Self::Ones) => Self::Output::One,
-            (left, right) => unimplemented!("left = {:#x?}, right = {:#x?}", left, right),
+        self.integer_operation(&other, |left, right| left.checked_div(right))
+    }
+}
+
+impl Eq for Value {}
+
+impl Ord for Value {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        let integers: Option<(u64, u64)> = match (self, other) {
+            (Self::Bool(_), _) | (_, Self::Bool(_)) => self.integer().zip(other.integer()),
+            _ => self
+                .integer()
+                .zip(other.integer())
+                .and_then(|_| self.match_type(other))
+                .and_then(|(left, right)| left.integer().zip(right.integer())),
+        };
+        match integers {
+            Some((left, right)) => left.cmp(&right),
+            None => match (self, other) {
+                (Self::Buffer(left), Self::Buffer(right)) => left.cmp(right),
+                (Self::Char(left), Self::Char(right)) => left.cmp(right),
+                (Self::DdbHandle(left), Self::DdbHandle(right)) => left.cmp(right),
+                (Self::Package(left), Self::Package(right)) => left.cmp(right),
+                (Self::Reference(left), Self::Reference(right)) => left.cmp(right),
+                (Self::String(left), Self::String(right)) => left.cmp(right),
+                (left, right) => left.kind_order().cmp(&right.kind_order()),
+            },
         }
     }
 }
 
+impl PartialEq for Value {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == cmp::Ordering::Equal
+    }
+}
+
+impl PartialOrd for Value {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl From<String> for Value {
     fn from(value: String) -> Self {
         Self::String(value)
@@ -972,7 +1069,7 @@ impl From<&Value> for Vec<u8> {
     fn from(value: &Value) -> Self {
         match value {
             Value::Buffer(buffer) => buffer.clone(),
-            value => unimplemented!("value = {:#x?}", value),
+            value => value.to_buffer().as_ref().map_or_else(Vec::new, Self::from),
         }
     }
 }
@@ -985,11 +1082,13 @@ impl From<&Value> for bool {
             Value::Byte(byte) => *byte != 0,
             Value::Char(character) => (*character as u32) != 0,
             Value::DWord(dword) => *dword != 0,
+            Value::DdbHandle(_) => true,
+            Value::Reference(_) => true,
             Value::One => true,
             Value::Ones => true,
             Value::Package(package) => package.iter().any(|value| value.into()),
             Value::QWord(qword) => *qword != 0,
-            Value::Revision => unimplemented!(),
+            Value::Revision => true,
             Value::String(string) => !string.is_empty(),
             Value::Word(word) => *word != 0,
             Value::Zero => false,
@@ -1001,7 +1100,10 @@ impl From<&Value> for u8 {
     fn from(value: &Value) -> Self {
         match value {
             Value::Byte(byte) => *byte,
-            value => unimplemented!("value = {:#x?}", value),
+            Value::One => 1,
+            Value::Ones => Self::MAX,
+            Value::Zero => 0,
+            value => value.implicit_integer() as Self,
         }
     }
 }
@@ -1011,7 +1113,10 @@ impl From<&Value> for u16 {
         match value {
             Value::Byte(byte) => *byte as Self,
             Value::Word(word) => *word,
-            value => unimplemented!("value = {:#x?}", value),
+            Value::One => 1,
+            Value::Ones => Self::MAX,
+            Value::Zero => 0,
+            value => value.implicit_integer() as Self,
         }
     }
 }
@@ -1022,7 +1127,10 @@ impl From<&Value> for u32 {
             Value::Byte(byte) => *byte as Self,
             Value::Word(word) => *word as Self,
             Value::DWord(dword) => *dword,
-            value => unimplemented!("value = {:#x?}", value),
+            Value::One => 1,
+            Value::Ones => Self::MAX,
+            Value::Zero => 0,
+            value => value.implicit_integer() as Self,
         }
     }
 }
@@ -1034,7 +1142,11 @@ impl From<&Value> for u64 {
             Value::Word(word) => *word as Self,
             Value::DWord(dword) => *dword as Self,
             Value::QWord(qword) => *qword,
-            value => unimplemented!("value = {:#x?}", value),
+            Value::DdbHandle(handle) => *handle,
+            Value::One => 1,
+            Value::Ones => Self::MAX,
+            Value::Zero => 0,
+            value => value.implicit_integer() as Self,
         }
     }
 }
@@ -1043,153 +1155,183 @@ impl From<&Value> for usize {
     fn from(value: &Value) -> Self {
         match value {
             Value::Byte(byte) => *byte as Self,
+            Value::DdbHandle(handle) => *handle as Self,
             Value::DWord(dword) => *dword as Self,
             Value::One => 1,
             Value::Ones => usize::MAX,
             Value::QWord(qword) => *qword as Self,
             Value::Word(word) => *word as Self,
             Value::Zero => 0,
-            value => unimplemented!("value = {:#x?}", value),
+            value => value.implicit_integer() as Self,
         }
     }
 }
 
 impl Mul for Value {
-    type Output = Self;
+    type Output = Option<Self>;
 
     fn mul(self, other: Self) -> Self::Output {
-        match self.match_type(&other) {
-            (Self::Byte(left), Self::Byte(right)) => Self::Output::Byte(left * right),
-            (Self::Word(left), Self::Word(right)) => Self::Output::Word(left * right),
-            (Self::DWord(left), Self::DWord(right)) => Self::Output::DWord(left * right),
-            (Self::QWord(left), Self::QWord(right)) => Self::Output::QWord(left * right),
-            (Self::Zero, Self::Zero) => Self::Output::Zero,
-            (Self::Zero, Self::One) => Self::Output::Zero,
-            (Self::Zero, Self::Ones) => Self::Output::Zero,
-            (Self::One, Self::Zero) => Self::Output::Zero,
-            (Self::One, Self::One) => Self::Output::One,
-            (Self::One, Self::Ones) => Self::Output::Ones,
-            (Self::Ones, Self::Zero) => Self::Output::Zero,
-            (Self::Ones, Self::One) => Self::Output::Ones,
-            (left, right) => unimplemented!("left = {:#x?}, right = {:#x?}", left, right),
-        }
+        self.integer_operation(&other, |left, right| Some(left.wrapping_mul(right)))
     }
 }
 
 impl Not for Value {
-    type Output = Self;
+    type Output = Option<Self>;
 
     fn not(self) -> Self::Output {
         match self {
-            Self::Bool(value) => Self::Output::Bool(!value),
+            Self::Bool(value) => Some(Self::Bool(!value)),
             Self::Buffer(buffer) => {
-                Self::Output::Buffer(buffer.into_iter().map(|byte| !byte).collect())
-            }
-            Self::Byte(byte) => Self::Output::Byte(!byte),
-            Self::DWord(dword) => Self::Output::DWord(!dword),
-            Self::Package(package) => {
-                Self::Output::Package(package.into_iter().map(|element| !element).collect())
+                Some(Self::Buffer(buffer.into_iter().map(|byte| !byte).collect()))
             }
-            Self::QWord(qword) => Self::Output::QWord(!qword),
-            Self::Word(word) => Self::Output::Word(!word),
-            Self::Zero => Self::Output::Ones,
-            Self::One => Self::Output::QWord(!1),
-            Self::Ones => Self::Output::Zero,
-            value => unimplemented!("value = {:#x?}", value),
+            Self::Byte(byte) => Some(Self::Byte(!byte)),
+            Self::DWord(dword) => Some(Self::DWord(!dword)),
+            Self::Package(package) => package
+                .into_iter()
+                .map(|element| !element)
+                .collect::<Option<Vec<Self>>>()
+                .map(Self::Package),
+            Self::QWord(qword) => Some(Self::QWord(!qword)),
+            Self::Word(word) => Some(Self::Word(!word)),
+            Self::Zero => Some(Self::Ones),
+            Self::One => Some(Self::QWord(!1)),
+            Self::Ones => Some(Self::Zero),
+            _ => None,
         }
     }
 }
 
 impl Rem for Value {
-    type Output = Self;
+    type Output = Option<Self>;
 
     fn rem(self, other: Self) -> Self::Output {
-        match self.match_type(&other) {
-            (Self::Byte(left), Self::Byte(right)) => Self::Output::Byte(left % right),
-            (Self::Word(left), Self::Word(right)) => Self::Output::Word(left % right),
-            (Self::DWord(left), Self::DWord(right)) => Self::Output::DWord(left % right),
-            (Self::QWord(left), Self::QWord(right)) => Self::Output::QWord(left % right),
-            (Self::Zero, Self::One) => Self::Output::Zero,
-            (Self::Zero, Self::Ones) => Self::Output::Zero,
-            (Self::One, Self::One) => Self::Output::Zero,
-            (Self::One, Self::Ones) => Self::Output::One,
-            (Self::Ones, Self::One) => Self::Output::Zero,
-            (Self::Ones, Self::Ones) => Self::Output::Zero,
-            (left, right) => unimplemented!("left = {:#x?}, right = {:#x?}", left, right),
-        }
+        self.integer_operation(&other, |left, right| left.checked_rem(right))
     }
 }
 
 impl Shl for Value {
-    type Output = Self;
+    type Output = Option<Self>;
 
     fn shl(self, other: Self) -> Self::Output {
-        match self.match_type(&other) {
-            (Self::Byte(left), Self::Byte(right)) => Self::Output::Byte(left << right),
-            (Self::Word(left), Self::Word(right)) => Self::Output::Word(left << right),
-            (Self::DWord(left), Self::DWord(right)) => Self::Output::DWord(left << right),
-            (Self::QWord(left), Self::QWord(right)) => Self::Output::QWord(left << right),
-            (Self::Zero, Self::Zero) => Self::Output::Zero,
-            (Self::Zero, Self::One) => Self::Output::Zero,
-            (Self::Zero, Self::Ones) => Self::Output::Zero,
-            (Self::One, Self::Zero) => Self::Output::One,
-            (Self::One, Self::One) => Self::Output::QWord(1 << 1),
-            (Self::One, Self::Ones) => Self::Output::Zero,
-            (Self::Ones, Self::Zero) => Self::Output::Ones,
-            (Self::Ones, Self::One) => Self::Output::QWord(u64::MAX << 1),
-            (Self::Ones, Self::Ones) => Self::Output::Zero,
-            (left, right) => unimplemented!("left = {:#x?}, right = {:#x?}", left, right),
-        }
+        self.integer_operation(&other, |left, right| {
+            Some(
+                u32::try_from(right)
+                    .ok()
+                    .and_then(|right| left.checked_shl(right))
+                    .unwrap_or_default(),
+            )
+        })
     }
 }
 
 impl Shr for Value {
-    type Output = Self;
+    type Output = Option<Self>;
 
     fn shr(self, other: Self) -> Self::Output {
-        match self.match_type(&other) {
-            (Self::Byte(left), Self::Byte(right)) => Self::Output::Byte(left >> right),
-            (Self::Word(left), Self::Word(right)) => Self::Output::Word(left >> right),
-            (Self::DWord(left), Self::DWord(right)) => Self::Output::DWord(left >> right),
-            (Self::QWord(left), Self::QWord(right)) => Self::Output::QWord(left >> right),
-            (Self::Zero, Self::Zero) => Self::Output::Zero,
-            (Self::Zero, Self::One) => Self::Output::Zero,
-            (Self::Zero, Self::Ones) => Self::Output::Zero,
-            (Self::One, Self::Zero) => Self::Output::One,
-            (Self::One, Self::One) => Self::Output::Zero,
-            (Self::One, Self::Ones) => Self::Output::Zero,
-            (Self::Ones, Self::Zero) => Self::Output::Ones,
-            (Self::Ones, Self::One) => Self::Output::QWord(u64::MAX >> 1),
-            (Self::Ones, Self::Ones) => Self::Output::Zero,
-            (left, right) => unimplemented!("left = {:#x?}, right = {:#x?}", left, right),
-        }
+        self.integer_operation(&other, |left, right| {
+            Some(
+                u32::try_from(right)
+                    .ok()
+                    .and_then(|right| left.checked_shr(right))
+                    .unwrap_or_default(),
+            )
+        })
     }
 }
 
 impl Sub for Value {
-    type Output = Self;
+    type Output = Option<Self>;
 
     fn sub(self, other: Self) -> Self::Output {
-        match self.match_type(&other) {
-            (Self::Byte(left), Self::Byte(right)) => Self::Output::Byte(left - right),
-            (Self::Word(left), Self::Word(right)) => Self::Output::Word(left - right),
-            (Self::DWord(left), Self::DWord(right)) => Self::Output::DWord(left - right),
-            (Self::QWord(left), Self::QWord(right)) => Self::Output::QWord(left - right),
-            (Self::Zero, Self::Zero) => Self::Output::Zero,
-            (Self::One, Self::Zero) => Self::Output::One,
-            (Self::One, Self::One) => Self::Output::Zero,
-            (Self::Ones, Self::Zero) => Self::Output::Ones,
-            (Self::Ones, Self::One) => Self::Output::QWord(u64::MAX - 1),
-            (Self::Ones, Self::Ones) => Self::Output::Zero,
-            (left, right) => unimplemented!("left = {:#x?}, right = {:#x?}", left, right),
+        self.integer_operation(&other, |left, right| Some(left.wrapping_sub(right)))
+    }
+}
+
+/// # A buffer field created inside a method
+/// It refers to bits of its source buffer, so reading it reads the source and writing it writes the source back.
+/// ## References
+/// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 19.6.19 CreateField (Create Arbitrary Length Buffer Field)
+#[derive(Clone, Debug)]
+pub struct BufferField {
+    offset_in_bits: usize,
+    size_in_bits: usize,
+    source: syntax::SourceBuff,
+}
+
+impl BufferField {
+    pub fn new(source: syntax::SourceBuff, offset_in_bits: usize, size_in_bits: usize) -> Self {
+        Self {
+            offset_in_bits,
+            size_in_bits,
+            source,
+        }
+    }
+
+    /// # Extract the bits of the field from the source buffer
+    /// A field up to 64 bits is an integer and a longer one is a buffer.
+    pub fn read(&self, source: &Value) -> Value {
+        let source: Vec<u8> = source.into();
+        let bits: Vec<bool> = (self.offset_in_bits
+            ..self.offset_in_bits.saturating_add(self.size_in_bits))
+            .map(|index| {
+                source
+                    .get(index / (u8::BITS as usize))
+                    .is_some_and(|byte| (byte >> (index % (u8::BITS as usize))) & 1 != 0)
+            })
+            .collect();
+        let bytes: Vec<u8> = bits
+            .chunks(u8::BITS as usize)
+            .map(|bits| {
+                bits.iter()
+                    .rev()
+                    .fold(0, |byte, bit| (byte << 1) | (*bit as u8))
+            })
+            .collect();
+        let integer: u64 = bytes
+            .iter()
+            .rev()
+            .fold(0, |integer, byte| (integer << u8::BITS) | (*byte as u64));
+        match self.size_in_bits {
+            0..=8 => Value::Byte(integer as u8),
+            9..=16 => Value::Word(integer as u16),
+            17..=32 => Value::DWord(integer as u32),
+            33..=64 => Value::QWord(integer),
+            _ => Value::Buffer(bytes),
         }
     }
+
+    pub fn source(&self) -> &syntax::SourceBuff {
+        &self.source
+    }
+
+    /// # Put a value into the bits of the field in the source buffer
+    /// Bits of the field beyond the value are cleared and bits beyond the source buffer are dropped.
+    pub fn write(&self, source: &Value, value: &Value) -> Value {
+        let mut source: Vec<u8> = source.into();
+        let value: Vec<u8> = value.into();
+        (0..self.size_in_bits).for_each(|index| {
+            let bit: bool = value
+                .get(index / (u8::BITS as usize))
+                .is_some_and(|byte| (byte >> (index % (u8::BITS as usize))) & 1 != 0);
+            let index: usize = self.offset_in_bits.saturating_add(index);
+            if let Some(byte) = source.get_mut(index / (u8::BITS as usize)) {
+                let mask: u8 = 1 << (index % (u8::BITS as usize));
+                if bit {
+                    *byte |= mask;
+                } else {
+                    *byte &= !mask;
+                }
+            }
+        });
+        Value::Buffer(source)
+    }
 }
 
 #[derive(Clone, Debug, Default)]
 pub struct StackFrame {
     arguments: [Option<Value>; 0x07],
     broken: Vec<bool>,
+    buffer_fields: BTreeMap<name::Path, BufferField>,
     continued: Vec<bool>,
     locals: [Option<Value>; 0x08],
     named_locals: BTreeMap<name::Path, Value>,
@@ -1197,6 +1339,10 @@ pub struct StackFrame {
 }
 
 impl StackFrame {
+    pub fn add_buffer_field(&mut self, name: &name::Path, buffer_field: BufferField) {
+        self.buffer_fields.insert(name.clone(), buffer_field);
+    }
+
     pub fn add_named_local(&mut self, name: &name::Path, value: Value) {
         self.named_locals.insert(name.clone(), value);
     }
@@ -1213,6 +1359,10 @@ impl StackFrame {
         self.arguments[index].clone()
     }
 
+    pub fn buffer_field(&self, name: &name::Path) -> Option<BufferField> {
+        self.buffer_fields.get(name).cloned()
+    }
+
     pub fn read_local(&self, index: usize) -> Option<Value> {
         self.locals[index].clone()
     }
@@ -1229,6 +1379,7 @@ impl StackFrame {
         let Self {
             arguments,
             broken,
+            buffer_fields,
             continued,
             locals,
             named_locals,
@@ -1245,6 +1396,7 @@ impl StackFrame {
         Self {
             arguments,
             broken,
+            buffer_fields,
             continued,
             locals,
             named_locals,
@@ -1252,19 +1404,17 @@ impl StackFrame {
         }
     }
 
+    /// # Break out of the innermost loop
+    /// `Break` and `Continue` outside of a loop do nothing.
     pub fn r#break(&mut self) {
         if let Some(broken) = self.broken.last_mut() {
             *broken = true;
-        } else {
-            unreachable!();
         }
     }
 
     pub fn r#continue(&mut self) {
         if let Some(continued) = self.continued.last_mut() {
             *continued = true;
-        } else {
-            unreachable!();
         }
     }
 
@@ -1274,8 +1424,6 @@ impl StackFrame {
     }
 
     pub fn leave_loop(&mut self) {
-        assert!(!self.broken.is_empty());
-        assert!(!self.continued.is_empty());
         self.broken.pop();
         self.continued.pop();
     }
@@ -1283,8 +1431,6 @@ impl StackFrame {
     pub fn uncontinue(&mut self) {
         if let Some(continued) = self.continued.last_mut() {
             *continued = false;
-        } else {
-            unreachable!();
         }
     }
 
@@ -1300,7 +1446,10 @@ impl StackFrame {
 
     pub fn write_named_local(&mut self, name: &name::Path, value: Value) -> Option<Value> {
         self.named_locals.get_mut(name).map(|named_local| {
-            let (_named_local, value): (Value, Value) = named_local.match_type(&value);
+            // A value which can't be converted to the type of the named local replaces it.
+            let value: Value = named_local
+                .match_type(&value)
+                .map_or(value, |(_named_local, value)| value);
             *named_local = value.clone();
             value
         })
@@ -0,0 +1,196 @@
+//! # PCI Interrupt Routing
+//! `_PRT` of a PCI bridge maps each device and interrupt pin on its secondary bus to either a global system interrupt or an interrupt link device (`PNP0C0F`), whose interrupt is configured by `_CRS`, `_PRS` and `_SRS`.
+//! ## References
+//! * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 5.8.1 _PIC Method
+//! * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 6.2.13 _PRT (PCI Routing Table)
+
+use {
+    super::{
+        interpreter::Value,
+        name, reference,
+        resource::{self, Descriptor, InterruptFlags},
+    },
+    alloc::{vec, vec::Vec},
+};
+
+/// # Hardware ID of interrupt link devices
+pub const LINK_HARDWARE_ID: &str = "PNP0C0F";
+
+/// # Tell the firmware which interrupt model the OS uses
+/// `_PRT` may return different tables depending on the model, so this has to be done before routing interrupts.
+/// Returns false if the firmware has no `_PIC`.
+pub fn set_interrupt_model(root: &reference::Node, model: InterruptModel) -> bool {
+    let pic: name::Path = "\\_PIC".into();
+    root.get_method(&pic).is_some() && {
+        root.evaluate(&pic, vec![Value::Byte(model.into())]);
+        true
+    }
+}
+
+/// # Read `_PRT` of a PCI bridge
+pub fn table(root: &reference::Node, bridge: &name::Path) -> Option<Vec<Entry>> {
+    let prt: name::Path = bridge.clone() + "_PRT".into();
+    match root.evaluate(&prt, Vec::new())? {
+        Value::Package(entries) => Some(entries.iter().filter_map(Entry::new).collect()),
+        _ => None,
+    }
+}
+
+/// # Route an interrupt pin of a device on the secondary bus of a PCI bridge
+/// `pin` is 0 for INTA#, 1 for INTB#, 2 for INTC# and 3 for INTD#.
+/// An interrupt link device without an interrupt is configured with the first interrupt in `_PRS`.
+pub fn route(
+    root: &reference::Node,
+    bridge: &name::Path,
+    device: u8,
+    pin: u8,
+) -> Option<Interrupt> {
+    table(root, bridge)?
+        .into_iter()
+        .find(|entry| entry.device == device as u16 && entry.pin == pin)
+        .and_then(|entry| entry.interrupt(root))
+}
+
+/// # An entry of `_PRT`
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Entry {
+    /// The device number. `0xffff` in the high word of the address means all functions of the device.
+    pub device: u16,
+    pub pin: u8,
+    pub source: Source,
+}
+
+impl Entry {
+    /// # The interrupt of the entry
+    /// An interrupt link device without an interrupt is configured with the first interrupt in `_PRS`.
+    pub fn interrupt(&self, root: &reference::Node) -> Option<Interrupt> {
+        match &self.source {
+            Source::GlobalSystemInterrupt(global_system_interrupt) => Some(Interrupt {
+                global_system_interrupt: *global_system_interrupt,
+                flags: Interrupt::hardwired_flags(),
+            }),
+            Source::Link { device, index } => link(root, device, *index),
+        }
+    }
+
+    fn new(entry: &Value) -> Option<Self> {
+        let Value::Package(elements) = entry else {
+            return None;
+        };
+        let address: u64 = elements.first()?.get_integer()?;
+        let pin: u8 = elements.get(1)?.get_integer()? as u8;
+        let index: u32 = elements.get(3)?.get_integer()? as u32;
+        let source: Source = match elements.get(2)? {
+            Value::Reference(device) => Source::Link {
+                device: device.clone(),
+                index,
+            },
+            Value::String(device) => Source::Link {
+                device: device.as_str().into(),
+                index,
+            },
+            _ => Source::GlobalSystemInterrupt(index),
+        };
+        let device: u16 = (address >> u16::BITS) as u16;
+        Some(Self {
+            device,
+            pin,
+            source,
+        })
+    }
+}
+
+/// # A routed interrupt
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Interrupt {
+    pub global_system_interrupt: u32,
+    pub flags: InterruptFlags,
+}
+
+impl Interrupt {
+    /// A hardwired PCI interrupt is level triggered, active low and shareable.
+    fn hardwired_flags() -> InterruptFlags {
+        InterruptFlags {
+            edge_triggered: false,
+            active_low: true,
+            shared: true,
+            wake_capable: false,
+        }
+    }
+}
+
+/// # `_PIC` argument
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum InterruptModel {
+    Pic,
+    Apic,
+    Sapic,
+}
+
+impl From<InterruptModel> for u8 {
+    fn from(model: InterruptModel) -> Self {
+        match model {
+            InterruptModel::Pic => 0,
+            InterruptModel::Apic => 1,
+            InterruptModel::Sapic => 2,
+        }
+    }
+}
+
+/// # Source of an interrupt in `_PRT`
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Source {
+    /// The source is zero and the source index is the global system interrupt.
+    GlobalSystemInterrupt(u32),
+    /// The source index is the index of the interrupt descriptor in the resource template of the link device.
+    Link { device: name::Path, index: u32 },
+}
+
+/// # Get or configure the interrupt of a link device
+fn link(root: &reference::Node, device: &name::Path, index: u32) -> Option<Interrupt> {
+    let interrupt = |descriptors: &[Descriptor]| {
+        descriptors
+            .iter()
+            .filter(|descriptor| !descriptor.interrupts().is_empty())
+            .nth(index as usize)
+            .cloned()
+    };
+    let current: Option<Interrupt> = resource::current(root, device)
+        .as_deref()
+        .and_then(interrupt)
+        .and_then(|descriptor| descriptor.interrupts().first().copied())
+        .filter(|(global_system_interrupt, _)| *global_system_interrupt != 0)
+        .map(|(global_system_interrupt, flags)| Interrupt {
+            global_system_interrupt,
+            flags,
+        });
+    current.or_else(|| {
+        let descriptor: Descriptor = resource::possible(root, device)
+            .as_deref()
+            .and_then(interrupt)?;
+        let (global_system_interrupt, flags): (u32, InterruptFlags) =
+            descriptor.interrupts().first().copied()?;
+        let descriptor: Descriptor = match descriptor {
+            Descriptor::Irq { flags, .. } => Descriptor::Irq {
+                irqs: vec![global_system_interrupt as u8],
+                flags,
+            },
+            Descriptor::ExtendedInterrupt {
+                consumer,
+                flags,
+                resource_source,
+                ..
+            } => Descriptor::ExtendedInterrupt {
+                consumer,
+                flags,
+                interrupts: vec![global_system_interrupt],
+                resource_source,
+            },
+            descriptor => descriptor,
+        };
+        resource::set(root, device, &[descriptor]).then_some(Interrupt {
+            global_system_interrupt,
+            flags,
+        })
+    })
+}
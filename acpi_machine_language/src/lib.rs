@@ -0,0 +1,36 @@
+//! # ACPI Machine Lnaguage
+//! The interpreter doesn't touch any hardware by itself.
+//! It accesses operation regions, timers and the firmware through the [`platform`] registered by [`platform::set`], so it builds and runs on the host as well as in the kernel.
+//! ## References
+//! * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20 ACPI MACHINE LANGUAGE (AML) SPECIFICATION
+
+#![no_std]
+
+extern crate alloc;
+
+pub mod definition_block;
+pub mod device;
+pub mod disassembler;
+pub mod embedded_controller;
+pub mod interpreter;
+pub mod interrupt_routing;
+pub mod name;
+pub mod notification;
+pub mod platform;
+pub mod processor;
+pub mod reference;
+pub mod resource;
+pub mod synchronization;
+pub mod syntax;
+pub mod thermal_zone;
+
+mod spin;
+
+/// # Print a diagnostic message through the platform
+macro_rules! debug_println {
+    ($($argument:tt)*) => {
+        $crate::platform::get().log(format_args!($($argument)*))
+    };
+}
+
+pub(crate) use debug_println;
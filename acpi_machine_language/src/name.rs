@@ -38,7 +38,8 @@ impl Node {
                         }
                     }
                 }
-                Segment::Parent => unreachable!(),
+                // A path above the root names no object.
+                Segment::Parent => {}
                 Segment::Root => {
                     assert_eq!(self.name, Segment::Root);
                     self.add_node(&path, object);
@@ -65,7 +66,7 @@ impl Node {
                     .iter()
                     .find(|child| child.name == method_segment)
                     .and_then(|child| child.find_number_of_arguments(&method)),
-                Segment::Parent => unreachable!(),
+                Segment::Parent => None,
                 Segment::Root => {
                     assert_eq!(self.name, Segment::Root);
                     self.find_number_of_arguments(&method)
@@ -225,6 +226,15 @@ pub struct Path {
 }
 
 impl Path {
+    /// # A path in a string from AML, such as the root path of `LoadTable`
+    /// It is `None` if a segment is not a valid name.
+    pub fn parse(path: &str) -> Option<Self> {
+        path.split(['\\', '^', '.'])
+            .filter(|segment| !segment.is_empty())
+            .all(Segment::is_valid_name)
+            .then(|| path.into())
+    }
+
     pub fn is_empty(&self) -> bool {
         self.segments.is_empty()
     }
@@ -279,9 +289,10 @@ impl Add for Path {
     }
 }
 
+/// A path without the leading `\` is relative, so that `device + "_HID".into()` is the `_HID` object of the device.
 impl From<&str> for Path {
     fn from(path_str: &str) -> Self {
-        let mut path: Self = Self::root();
+        let mut path: Self = Self::default();
         let mut segment: String = String::new();
         path_str.chars().for_each(|character| match character {
             '\\' => {
@@ -300,6 +311,13 @@ impl From<&str> for Path {
                 path.push_segment(Segment::Parent);
                 segment = String::new();
             }
+            '.' => {
+                if !segment.is_empty() {
+                    let segment: Segment = segment.as_str().into();
+                    path.push_segment(segment);
+                }
+                segment = String::new();
+            }
             character => {
                 assert!(
                     character.is_ascii_digit()
@@ -438,25 +456,28 @@ impl fmt::Debug for Segment {
     }
 }
 
+impl Segment {
+    /// # Whether a string is a NameSeg without the trailing underscores
+    fn is_valid_name(name: &str) -> bool {
+        name.chars()
+            .enumerate()
+            .all(|(index, character)| match index {
+                0 => character.is_ascii_uppercase() || character == '_',
+                1..=3 => {
+                    character.is_ascii_digit() || character.is_ascii_uppercase() || character == '_'
+                }
+                _ => false,
+            })
+    }
+}
+
 impl From<&str> for Segment {
     fn from(segment: &str) -> Self {
         match segment {
             "\\" => Self::Root,
             "^" => Self::Parent,
             name => {
-                let valid: bool = name
-                    .chars()
-                    .enumerate()
-                    .all(|(index, character)| match index {
-                        0 => character.is_ascii_uppercase() || character == '_',
-                        1..=3 => {
-                            character.is_ascii_digit()
-                                || character.is_ascii_uppercase()
-                                || character == '_'
-                        }
-                        _ => false,
-                    });
-                assert!(valid);
+                assert!(Self::is_valid_name(name));
                 let name: String = String::from(name);
                 Self::Child { name }
             }
@@ -0,0 +1,73 @@
+//! # AML Notify
+//! `Notify(Object, NotificationValue)` passes the notification to the platform, and the platform calls handlers registered on the object later through [`dispatch`].
+//! ## References
+//! * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 5.6.6 Device Object Notifications
+//! * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 19.6.94 Notify (Notify Object of Event)
+
+use {
+    super::{name, platform, reference, spin},
+    alloc::vec::Vec,
+};
+
+/// A handler called with the notified object and the notification value.
+pub type Handler = fn(&name::Path, u8);
+
+static HANDLERS: spin::Lock<Vec<(name::Path, Handler)>> = spin::Lock::new(Vec::new());
+
+/// # Call handlers registered on the object
+/// The platform calls this outside of AML evaluation.
+pub fn dispatch(object: &name::Path, value: u8) {
+    let handlers: Vec<Handler> = HANDLERS
+        .lock()
+        .iter()
+        .filter(|(path, _handler)| path == object)
+        .map(|(_path, handler)| *handler)
+        .collect();
+    handlers
+        .into_iter()
+        .for_each(|handler| handler(object, value));
+}
+
+/// # Pass a notification to the platform
+pub fn notify(object: name::Path, value: u8) {
+    platform::get().notify(object, value);
+}
+
+/// # Register a handler on a namespace path
+pub fn register(object: &name::Path, handler: Handler) {
+    HANDLERS.lock().push((object.clone(), handler));
+}
+
+/// # Register a handler on the object which a name refers to
+/// The name is resolved in the same way as the object of `Notify`, so a relative name is searched upward from `current` and an alias is replaced by its original object.
+/// Returns the path of the object, or `None` if no object has the name.
+pub fn register_from_current(
+    root: &reference::Node,
+    current: &name::Path,
+    object: &name::Path,
+    handler: Handler,
+) -> Option<name::Path> {
+    let object: name::Path =
+        root.get_path_from_current(&name::AbsolutePath::new(current, object))?;
+    register(&object, handler);
+    Some(object)
+}
+
+/// # Unregister a handler from a namespace path
+pub fn unregister(object: &name::Path, handler: Handler) {
+    HANDLERS
+        .lock()
+        .retain(|(path, registered)| !(path == object && *registered == handler));
+}
+
+/// # Unregister a handler registered by [`register_from_current`]
+pub fn unregister_from_current(
+    root: &reference::Node,
+    current: &name::Path,
+    object: &name::Path,
+    handler: Handler,
+) {
+    if let Some(object) = root.get_path_from_current(&name::AbsolutePath::new(current, object)) {
+        unregister(&object, handler);
+    }
+}
@@ -0,0 +1,110 @@
+//! # Platform
+//! The interpreter reaches the hardware and the firmware only through these traits.
+//! The kernel registers an implementation accessing the real machine, and host tests register one backed by memory.
+
+use {
+    super::{interpreter, name, spin},
+    alloc::vec::Vec,
+    core::fmt,
+};
+
+static PLATFORM: spin::Lock<Option<&'static dyn Platform>> = spin::Lock::new(None);
+
+/// # Get the registered platform
+pub fn get() -> &'static dyn Platform {
+    PLATFORM
+        .lock()
+        .expect("The AML interpreter requires a platform registered by platform::set.")
+}
+
+/// # Register the platform
+/// The interpreter calls it from now on.
+pub fn set(platform: &'static dyn Platform) {
+    *PLATFORM.lock() = Some(platform);
+}
+
+/// # Context
+pub trait Context {
+    /// # Identify the context evaluating AML such as a processor or a thread
+    /// A mutex acquired in a context is owned by it and only the context can acquire it again or release it.
+    fn context_id(&self) -> usize;
+}
+
+/// # Debug
+/// ## References
+/// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 19.6.26 Debug (Debugging Output Object)
+pub trait Debug {
+    /// # Receive a value stored to the Debug object
+    fn debug(&self, value: &interpreter::Value);
+
+    /// # Print a diagnostic message of the interpreter
+    fn log(&self, message: fmt::Arguments);
+}
+
+/// # Firmware
+pub trait Firmware {
+    /// # Acquire the global lock shared with the firmware through the FACS
+    /// Returns true if the global lock is acquired.
+    fn acquire_global_lock(&self) -> bool;
+
+    /// # Release the global lock shared with the firmware through the FACS
+    fn release_global_lock(&self);
+
+    /// # Find a table in the XSDT
+    /// An empty OEM ID or OEM table ID matches any table.
+    /// The returned bytes include the system description table header.
+    fn find_table(&self, signature: &str, oem_id: &str, oem_table_id: &str) -> Option<Vec<u8>>;
+}
+
+/// # Notifier
+/// ## References
+/// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 19.6.94 Notify (Notify Object of Event)
+pub trait Notifier {
+    /// # Deliver a notification
+    /// The platform calls [`super::notification::dispatch`] later in its own context.
+    fn notify(&self, object: name::Path, value: u8);
+}
+
+/// # OperationRegion
+/// ## References
+/// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 19.6.100 OperationRegion (Declare Operation Region)
+pub trait OperationRegion {
+    /// # Read `size` bytes at `address` in the region space
+    /// The size is 1, 2, 4 or 8.
+    fn read(&self, region_space: &interpreter::RegionSpace, address: usize, size: usize) -> u64;
+
+    /// # Write `size` bytes at `address` in the region space
+    /// The size is 1, 2, 4 or 8.
+    fn write(
+        &self,
+        region_space: &interpreter::RegionSpace,
+        address: usize,
+        size: usize,
+        value: u64,
+    );
+}
+
+/// # Timer
+/// ## References
+/// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 19.6.126 Sleep (Milliseconds Sleep)
+/// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 19.6.127 Stall (Stall for a Short Time)
+/// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 19.6.134 Timer (Get 64-Bit Timer Value)
+pub trait Timer {
+    /// # Monotonic time in nanoseconds
+    fn nanoseconds(&self) -> u64;
+
+    /// # Sleep at least the milliseconds
+    fn sleep_milliseconds(&self, milliseconds: usize);
+
+    /// # Stall at least the microseconds
+    fn stall_microseconds(&self, microseconds: usize);
+}
+
+/// # Platform
+/// Anything implementing all the traits above is a platform.
+pub trait Platform: Context + Debug + Firmware + Notifier + OperationRegion + Sync + Timer {}
+
+impl<T> Platform for T where
+    T: Context + Debug + Firmware + Notifier + OperationRegion + Sync + Timer
+{
+}
@@ -0,0 +1,214 @@
+//! # ACPI Processors
+//! Enumerates processors declared by `Processor` objects or `ACPI0007` devices and reads their power states.
+//! ## References
+//! * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 8.4 Declaring Processors
+//! * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 8.4.1.1 _CST (C States)
+//! * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 8.4.5 Processor Performance Control
+
+use {
+    super::{device, interpreter::Value, name, reference, resource},
+    alloc::vec::Vec,
+};
+
+const HARDWARE_ID: &str = "ACPI0007";
+
+/// # The notification value when `_PPC` changes
+pub const PERFORMANCE_PRESENT_CAPABILITIES_CHANGED: u8 = 0x80;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Processor {
+    path: name::Path,
+    uid: u32,
+}
+
+impl Processor {
+    /// # Enumerate processors in the namespace
+    /// The UID of a `Processor` object is its `ProcessorID`, and the one of an `ACPI0007` device is its `_UID`.
+    /// Both are matched with the ACPI processor UID in the MADT.
+    pub fn enumerate(root: &reference::Node) -> Vec<Self> {
+        let objects = root.get_processor_paths().into_iter().filter_map(|path| {
+            root.get_processor(&path).map(|processor| Self {
+                uid: processor.proc_id() as u32,
+                path,
+            })
+        });
+        let devices: Vec<Self> = device::Node::enumerate(root)
+            .devices()
+            .into_iter()
+            .filter(|device| device.is_compatible(HARDWARE_ID))
+            .filter_map(|device| {
+                let uid: u32 = root
+                    .evaluate(&(device.path().clone() + "_UID".into()), Vec::new())?
+                    .get_integer()? as u32;
+                let path: name::Path = device.path().clone();
+                Some(Self { path, uid })
+            })
+            .collect();
+        objects.chain(devices).collect()
+    }
+
+    /// # Read `_CST`
+    /// Returns the C states in the order of the package, which is from the shallowest.
+    pub fn c_states(&self, root: &reference::Node) -> Option<Vec<CState>> {
+        let cst: Value = root.evaluate(&(self.path.clone() + "_CST".into()), Vec::new())?;
+        let Value::Package(cst) = cst else {
+            return None;
+        };
+        let (count, c_states): (&Value, &[Value]) = cst.split_first()?;
+        let count: usize = count.get_integer()? as usize;
+        c_states
+            .iter()
+            .take(count)
+            .map(CState::new)
+            .collect::<Option<Vec<CState>>>()
+    }
+
+    pub fn path(&self) -> &name::Path {
+        &self.path
+    }
+
+    /// # Read `_PCT` (Performance Control)
+    /// Returns the control register and the status register.
+    pub fn performance_control(&self, root: &reference::Node) -> Option<(Register, Register)> {
+        let pct: Value = root.evaluate(&(self.path.clone() + "_PCT".into()), Vec::new())?;
+        let Value::Package(pct) = pct else {
+            return None;
+        };
+        let [control, status] = pct.as_slice() else {
+            return None;
+        };
+        Some((Register::new(control)?, Register::new(status)?))
+    }
+
+    /// # Read `_PPC` (Performance Present Capabilities)
+    /// Returns the index of the highest performance state which is currently available.
+    pub fn performance_present_capabilities(&self, root: &reference::Node) -> Option<usize> {
+        root.evaluate(&(self.path.clone() + "_PPC".into()), Vec::new())
+            .and_then(|ppc| ppc.get_integer())
+            .map(|ppc| ppc as usize)
+    }
+
+    /// # Read `_PSS` (Performance Supported States)
+    /// Returns the performance states from the highest performance.
+    pub fn performance_states(&self, root: &reference::Node) -> Option<Vec<PState>> {
+        let pss: Value = root.evaluate(&(self.path.clone() + "_PSS".into()), Vec::new())?;
+        let Value::Package(pss) = pss else {
+            return None;
+        };
+        pss.iter().map(PState::new).collect::<Option<Vec<PState>>>()
+    }
+
+    pub fn uid(&self) -> u32 {
+        self.uid
+    }
+}
+
+/// # A C state in `_CST`
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CState {
+    pub register: Register,
+    /// 1 for C1, 2 for C2 and 3 for C3
+    pub state_type: u8,
+    /// Worst case latency in microseconds
+    pub latency: u16,
+    /// Average power consumption in milliwatts
+    pub power: u32,
+}
+
+impl CState {
+    fn new(c_state: &Value) -> Option<Self> {
+        let Value::Package(c_state) = c_state else {
+            return None;
+        };
+        let [register, state_type, latency, power] = c_state.as_slice() else {
+            return None;
+        };
+        let register: Register = Register::new(register)?;
+        let state_type: u8 = state_type.get_integer()? as u8;
+        let latency: u16 = latency.get_integer()? as u16;
+        let power: u32 = power.get_integer()? as u32;
+        Some(Self {
+            register,
+            state_type,
+            latency,
+            power,
+        })
+    }
+}
+
+/// # A performance state in `_PSS`
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PState {
+    /// Core frequency in MHz
+    pub core_frequency: u32,
+    /// Typical power dissipation in milliwatts
+    pub power: u32,
+    /// Worst case latency in microseconds to switch to this state
+    pub latency: u32,
+    /// Worst case latency in microseconds that bus masters are prevented from accessing memory
+    pub bus_master_latency: u32,
+    /// The value written to the control register to switch to this state
+    pub control: u64,
+    /// The value read from the status register in this state
+    pub status: u64,
+}
+
+impl PState {
+    fn new(p_state: &Value) -> Option<Self> {
+        let Value::Package(p_state) = p_state else {
+            return None;
+        };
+        let [core_frequency, power, latency, bus_master_latency, control, status] =
+            p_state.as_slice()
+        else {
+            return None;
+        };
+        Some(Self {
+            core_frequency: core_frequency.get_integer()? as u32,
+            power: power.get_integer()? as u32,
+            latency: latency.get_integer()? as u32,
+            bus_master_latency: bus_master_latency.get_integer()? as u32,
+            control: control.get_integer()?,
+            status: status.get_integer()?,
+        })
+    }
+}
+
+/// # A register to control the processor
+/// ## References
+/// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 6.4.3.7 Generic Register Descriptor
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Register {
+    pub address_space: u8,
+    pub bit_width: u8,
+    pub bit_offset: u8,
+    pub access_size: u8,
+    pub address: u64,
+}
+
+impl Register {
+    /// # Decode a resource template with a generic register descriptor
+    fn new(template: &Value) -> Option<Self> {
+        let Value::Buffer(template) = template else {
+            return None;
+        };
+        resource::decode(template)?
+            .into_iter()
+            .find_map(|descriptor| match descriptor {
+                resource::Descriptor::GenericRegister {
+                    address_space,
+                    bit_width,
+                    bit_offset,
+                    access_size,
+                    address,
+                } => Some(Self {
+                    address_space,
+                    bit_width,
+                    bit_offset,
+                    access_size,
+                    address,
+                }),
+                _ => None,
+            })
+    }
+}
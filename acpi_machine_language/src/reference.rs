@@ -3,8 +3,13 @@
 //! * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20 ACPI MACHINE LANGUAGE (AML) SPECIFICATION
 
 use {
-    super::{interpreter, name, syntax, syntax::Lender},
-    alloc::{format, string::String, vec::Vec},
+    super::{
+        definition_block,
+        interpreter::{self, Evaluator},
+        name, spin, syntax,
+        syntax::Lender,
+    },
+    alloc::{borrow::Cow, collections::btree_map::BTreeMap, format, string::String, vec::Vec},
     core::{fmt, ops::Range},
 };
 
@@ -12,9 +17,27 @@ pub struct Node<'a> {
     name: name::Segment,
     objects: Vec<Object<'a>>,
     children: Vec<Self>,
+    /// Values stored to named objects, which are immutable in the syntax tree.
+    values: spin::Lock<BTreeMap<name::Path, interpreter::Value>>,
 }
 
 impl<'a> Node<'a> {
+    /// # Lend objects in a syntax tree under the current path
+    pub fn new(term_list: &'a syntax::TermList, current: &name::Path) -> Self {
+        let name: name::Segment = name::Segment::Root;
+        let objects: Vec<Object<'a>> = Vec::default();
+        let children: Vec<Self> = Vec::default();
+        let values = spin::Lock::new(BTreeMap::new());
+        let mut node = Self {
+            name,
+            objects,
+            children,
+            values,
+        };
+        term_list.lend(&mut node, current);
+        node
+    }
+
     pub fn add_node(&mut self, path: &name::Path, object: Object<'a>) {
         let mut path: name::Path = path.clone();
         match path.pop_first_segment() {
@@ -27,17 +50,20 @@ impl<'a> Node<'a> {
                         None => {
                             let objects: Vec<Object<'a>> = Vec::default();
                             let children: Vec<Self> = Vec::default();
+                            let values = spin::Lock::new(BTreeMap::new());
                             let mut child = Self {
                                 name,
                                 objects,
                                 children,
+                                values,
                             };
                             child.add_node(&path, object);
                             self.children.push(child);
                         }
                     }
                 }
-                name::Segment::Parent => unreachable!(),
+                // A path above the root names no object.
+                name::Segment::Parent => {}
                 name::Segment::Root => {
                     assert_eq!(self.name, name::Segment::Root);
                     self.add_node(&path, object);
@@ -49,6 +75,116 @@ impl<'a> Node<'a> {
         }
     }
 
+    /// # Evaluate a named object or a method
+    pub fn evaluate(
+        &self,
+        path: &name::Path,
+        arguments: Vec<interpreter::Value>,
+    ) -> Option<interpreter::Value> {
+        self.get_name(path)
+            .and_then(|name| name.evaluate(&mut interpreter::StackFrame::default(), self, path))
+            .or_else(|| {
+                self.get_method(path).and_then(|method| {
+                    let mut stack_frame =
+                        interpreter::StackFrame::default().set_arguments(arguments);
+                    method.evaluate(&mut stack_frame, self, path)
+                })
+            })
+    }
+
+    /// # Collect devices including ones in loaded definition blocks
+    pub fn get_device_paths(&self) -> Vec<name::Path> {
+        let mut device_paths: Vec<name::Path> = self.get_local_device_paths();
+        definition_block::get_device_paths()
+            .into_iter()
+            .for_each(|device_path| {
+                if !device_paths.contains(&device_path) {
+                    device_paths.push(device_path);
+                }
+            });
+        device_paths
+    }
+
+    /// # Collect processors including ones in loaded definition blocks
+    pub fn get_processor_paths(&self) -> Vec<name::Path> {
+        let mut processor_paths: Vec<name::Path> = self.get_local_processor_paths();
+        definition_block::get_processor_paths()
+            .into_iter()
+            .for_each(|processor_path| {
+                if !processor_paths.contains(&processor_path) {
+                    processor_paths.push(processor_path);
+                }
+            });
+        processor_paths
+    }
+
+    /// # Collect thermal zones including ones in loaded definition blocks
+    pub fn get_thermal_zone_paths(&self) -> Vec<name::Path> {
+        let mut thermal_zone_paths: Vec<name::Path> = self.get_local_thermal_zone_paths();
+        definition_block::get_thermal_zone_paths()
+            .into_iter()
+            .for_each(|thermal_zone_path| {
+                if !thermal_zone_paths.contains(&thermal_zone_path) {
+                    thermal_zone_paths.push(thermal_zone_path);
+                }
+            });
+        thermal_zone_paths
+    }
+
+    /// # Collect devices only in this tree
+    pub fn get_local_device_paths(&self) -> Vec<name::Path> {
+        self.get_paths_under(&name::Path::root(), &|object| {
+            matches!(object, Object::Device(_))
+        })
+    }
+
+    /// # Collect processors only in this tree
+    pub fn get_local_processor_paths(&self) -> Vec<name::Path> {
+        self.get_paths_under(&name::Path::root(), &|object| {
+            matches!(object, Object::Processor(_))
+        })
+    }
+
+    /// # Collect thermal zones only in this tree
+    pub fn get_local_thermal_zone_paths(&self) -> Vec<name::Path> {
+        self.get_paths_under(&name::Path::root(), &|object| {
+            matches!(object, Object::ThermalZone(_))
+        })
+    }
+
+    pub fn get_event_from_current(
+        &self,
+        event: &name::AbsolutePath,
+    ) -> Option<(name::Path, &'a syntax::DefEvent)> {
+        self.get_objects_from_current(event)
+            .and_then(|(event_path, objects)| {
+                objects.iter().find_map(|object| match object {
+                    Object::Event(event) => Some((event_path.clone(), *event)),
+                    _ => None,
+                })
+            })
+    }
+
+    /// # Get objects at a path only in this tree
+    pub fn get_local_objects(&self, object: &name::Path) -> Option<&[Object<'a>]> {
+        let mut object: name::Path = object.clone();
+        match object.pop_first_segment() {
+            Some(name) => match name {
+                name::Segment::Child { name: _ } => self
+                    .children
+                    .iter()
+                    .find(|child| child.name == name)
+                    .and_then(|child| child.get_local_objects(&object)),
+                name::Segment::Parent => None,
+                name::Segment::Root => {
+                    assert_eq!(self.name, name::Segment::Root);
+                    self.get_local_objects(&object)
+                }
+            },
+            None => Some(&self.objects),
+        }
+    }
+
     pub fn get_method(&self, method: &name::Path) -> Option<&'a syntax::DefMethod> {
         let mut methods: Vec<&'a syntax::DefMethod> = self.get_methods(method);
         let method: Option<&'a syntax::DefMethod> = methods.pop();
@@ -75,6 +211,19 @@ impl<'a> Node<'a> {
             })
     }
 
+    pub fn get_mutex_from_current(
+        &self,
+        mutex: &name::AbsolutePath,
+    ) -> Option<(name::Path, &'a syntax::DefMutex)> {
+        self.get_objects_from_current(mutex)
+            .and_then(|(mutex_path, objects)| {
+                objects.iter().find_map(|object| match object {
+                    Object::Mutex(mutex) => Some((mutex_path.clone(), *mutex)),
+                    _ => None,
+                })
+            })
+    }
+
     pub fn get_name(&self, name: &name::Path) -> Option<&'a syntax::DefName> {
         let mut names: Vec<&'a syntax::DefName> = self.get_names(name);
         let name: Option<&'a syntax::DefName> = names.pop();
@@ -101,6 +250,38 @@ impl<'a> Node<'a> {
             })
     }
 
+    pub fn get_op_region_from_current(
+        &self,
+        op_region: &name::AbsolutePath,
+    ) -> Option<(name::Path, &'a syntax::DefOpRegion)> {
+        self.get_objects_from_current(op_region)
+            .and_then(|(op_region_path, objects)| {
+                objects.iter().find_map(|object| match object {
+                    Object::OpRegion(op_region) => Some((op_region_path.clone(), *op_region)),
+                    _ => None,
+                })
+            })
+    }
+
+    pub fn get_path_from_current(&self, object: &name::AbsolutePath) -> Option<name::Path> {
+        self.get_objects_from_current(object)
+            .map(|(object_path, _objects)| object_path)
+    }
+
+    pub fn get_processor(&self, processor: &name::Path) -> Option<&'a syntax::DefProcessor> {
+        self.get_objects(processor).and_then(|objects| {
+            objects.iter().find_map(|object| match object {
+                Object::Processor(processor) => Some(*processor),
+                _ => None,
+            })
+        })
+    }
+
+    /// # Read the value stored to a named object
+    pub fn read_name(&self, name: &name::Path) -> Option<interpreter::Value> {
+        self.values.lock().get(name).cloned()
+    }
+
     pub fn read_named_field(
         &self,
         stack_frame: &mut interpreter::StackFrame,
@@ -181,6 +362,50 @@ impl<'a> Node<'a> {
             })
     }
 
+    /// # Store a value to a named object
+    /// The value is converted to the type of the current value of the named object.
+    pub fn write_name(
+        &self,
+        value: interpreter::Value,
+        stack_frame: &mut interpreter::StackFrame,
+        root: &Node,
+        name: &name::AbsolutePath,
+    ) -> Option<interpreter::Value> {
+        self.get_name_from_current(name)
+            .and_then(|(name_path, name)| {
+                name.evaluate(stack_frame, root, &name_path)
+                    .map(|current| (name_path, current))
+            })
+            .map(|(name_path, current)| {
+                // A value which can't be converted to the type of the named object replaces it.
+                let value: interpreter::Value = current
+                    .match_type(&value)
+                    .map_or(value, |(_current, value)| value);
+                self.values.lock().insert(name_path, value.clone());
+                value
+            })
+    }
+
+    /// # Collect paths of nodes having an object which satisfies the predicate
+    fn get_paths_under(
+        &self,
+        parent: &name::Path,
+        predicate: &dyn Fn(&Object<'a>) -> bool,
+    ) -> Vec<name::Path> {
+        let segment: name::Path = (&self.name).into();
+        let current: name::Path = parent.clone() + segment;
+        let is_matched: bool = self.objects.iter().any(predicate);
+        is_matched
+            .then(|| current.clone())
+            .into_iter()
+            .chain(
+                self.children
+                    .iter()
+                    .flat_map(|child| child.get_paths_under(&current, predicate)),
+            )
+            .collect()
+    }
+
     fn get_methods(&self, method: &name::Path) -> Vec<&'a syntax::DefMethod> {
         match self.get_objects(method) {
             Some(objects) => objects
@@ -241,31 +466,28 @@ impl<'a> Node<'a> {
             })
     }
 
-    fn get_objects(&self, object: &name::Path) -> Option<&[Object<'a>]> {
-        let mut object: name::Path = object.clone();
-        match object.pop_first_segment() {
-            Some(name) => match name {
-                name::Segment::Child { name: _ } => self
-                    .children
-                    .iter()
-                    .find(|child| child.name == name)
-                    .and_then(|child| child.get_objects(&object)),
-                name::Segment::Parent => unreachable!(),
-                name::Segment::Root => {
-                    assert_eq!(self.name, name::Segment::Root);
-                    self.get_objects(&object)
-                }
-            },
-            None => Some(&self.objects),
+    /// Objects in loaded definition blocks follow objects in this tree.
+    /// The objects in this tree are borrowed unless a loaded definition block adds objects at the path.
+    fn get_objects(&self, object: &name::Path) -> Option<Cow<'_, [Object<'a>]>> {
+        let local_objects: Option<&[Object<'a>]> = self.get_local_objects(object);
+        let loaded_objects: Option<Vec<Object<'a>>> = definition_block::get_objects(object);
+        match (local_objects, loaded_objects) {
+            (local_objects, None) => local_objects.map(Cow::Borrowed),
+            (None, Some(loaded_objects)) => Some(Cow::Owned(loaded_objects)),
+            (Some(local_objects), Some(loaded_objects)) => {
+                let mut objects: Vec<Object<'a>> = local_objects.to_vec();
+                objects.extend(loaded_objects);
+                Some(Cow::Owned(objects))
+            }
         }
     }
 
     fn get_objects_from_current(
         &self,
         name: &name::AbsolutePath,
-    ) -> Option<(name::Path, &[Object<'a>])> {
+    ) -> Option<(name::Path, Cow<'_, [Object<'a>]>)> {
         let mut name: name::AbsolutePath = self.original_path(name);
-        name.find_map(|name| self.get_objects(&name).map(|object| (name, object)))
+        name.find_map(|name| self.get_objects(&name).map(|objects| (name, objects)))
     }
 
     fn original_path(&self, alias: &name::AbsolutePath) -> name::AbsolutePath {
@@ -275,9 +497,13 @@ impl<'a> Node<'a> {
         }
     }
 
+    /// # The original name of an alias
+    /// The original name is searched from the scope where the alias is defined.
     fn solve_alias(&self, alias: &name::Path) -> Option<name::AbsolutePath> {
+        let mut scope: name::Path = alias.clone();
+        scope.pop_last_segment();
         self.get_objects(alias)
-            .and_then(|objects| objects.iter().find_map(|object| object.solve_alias(alias)))
+            .and_then(|objects| objects.iter().find_map(|object| object.solve_alias(&scope)))
     }
 
     fn solve_alias_from_current(&self, alias: &name::AbsolutePath) -> Option<name::AbsolutePath> {
@@ -288,17 +514,7 @@ impl<'a> Node<'a> {
 
 impl<'a> From<&'a syntax::TermList> for Node<'a> {
     fn from(term_list: &'a syntax::TermList) -> Self {
-        let name: name::Segment = name::Segment::Root;
-        let objects: Vec<Object<'a>> = Vec::default();
-        let children: Vec<Self> = Vec::default();
-        let mut node = Self {
-            name,
-            objects,
-            children,
-        };
-        let current = name::Path::root();
-        term_list.lend(&mut node, &current);
-        node
+        Self::new(term_list, &name::Path::root())
     }
 }
 
@@ -308,6 +524,7 @@ impl fmt::Debug for Node<'_> {
             name,
             objects,
             children,
+            values: _,
         } = self;
         let name: String = name.into();
         let objects: Vec<&str> = objects.iter().map(|object| object.type_name()).collect();
@@ -320,6 +537,7 @@ impl fmt::Debug for Node<'_> {
     }
 }
 
+#[derive(Clone)]
 pub enum Object<'a> {
     Alias(&'a syntax::DefAlias),
     CreateBitField(&'a syntax::DefCreateBitField),
@@ -332,7 +550,6 @@ pub enum Object<'a> {
     Device(&'a syntax::DefDevice),
     Event(&'a syntax::DefEvent),
     External(&'a syntax::DefExternal),
-    Load(&'a syntax::DefLoad),
     Method(&'a syntax::DefMethod),
     Mutex(&'a syntax::DefMutex),
     Name(&'a syntax::DefName),
@@ -373,7 +590,6 @@ impl Object<'_> {
             Self::Device(_) => "Device",
             Self::Event(_) => "Event",
             Self::External(_) => "External",
-            Self::Load(_) => "Load",
             Self::Method(_) => "Method",
             Self::Mutex(_) => "Mutex",
             Self::Name(_) => "Name",
@@ -0,0 +1,847 @@
+//! # Resource Data Types
+//! `_CRS` and `_PRS` return a buffer of small and large resource descriptors terminated by an end tag, and `_SRS` takes a buffer in the same format.
+//! ## References
+//! * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 6.2.2 _CRS (Current Resource Settings)
+//! * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 6.2.12 _PRS (Possible Resource Settings)
+//! * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 6.2.16 _SRS (Set Resource Settings)
+//! * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 6.4 Resource Data Types for ACPI
+
+use {
+    super::{interpreter::Value, name, reference},
+    alloc::{string::String, vec, vec::Vec},
+    core::{iter, ops::Range, str},
+};
+
+/// # Decode the resources of `_CRS` of a device
+pub fn current(root: &reference::Node, device: &name::Path) -> Option<Vec<Descriptor>> {
+    evaluate(root, device, "_CRS").and_then(|template| decode(&template))
+}
+
+/// # Decode the resources of `_PRS` of a device
+pub fn possible(root: &reference::Node, device: &name::Path) -> Option<Vec<Descriptor>> {
+    evaluate(root, device, "_PRS").and_then(|template| decode(&template))
+}
+
+/// # Set resources of a device by `_SRS`
+/// Returns false if the device has no `_SRS`.
+pub fn set(root: &reference::Node, device: &name::Path, descriptors: &[Descriptor]) -> bool {
+    let srs: name::Path = device.clone() + "_SRS".into();
+    root.get_method(&srs).is_some() && {
+        root.evaluate(&srs, vec![Value::Buffer(encode(descriptors))]);
+        true
+    }
+}
+
+/// # Decode a resource template
+/// Returns `None` if a descriptor is truncated or malformed.
+/// A template without the end tag is accepted because some firmware omits it.
+pub fn decode(template: &[u8]) -> Option<Vec<Descriptor>> {
+    let mut descriptors: Vec<Descriptor> = Vec::new();
+    let mut template: &[u8] = template;
+    while let Some(tag) = template.first() {
+        if tag & LARGE == 0 {
+            let name: u8 = (tag >> 3) & 0x0f;
+            let length: usize = (tag & 0x07) as usize;
+            let data: &[u8] = template.get(1..1 + length)?;
+            if name == END_TAG {
+                break;
+            }
+            descriptors.push(Descriptor::decode_small(name, data)?);
+            template = &template[1 + length..];
+        } else {
+            let name: u8 = tag & !LARGE;
+            let length: usize = u16_at(template, 1)? as usize;
+            let data: &[u8] = template.get(3..3 + length)?;
+            descriptors.push(Descriptor::decode_large(name, data)?);
+            template = &template[3 + length..];
+        }
+    }
+    Some(descriptors)
+}
+
+/// # Encode a resource template
+/// The end tag has a zero checksum, which means that the template is treated as correct.
+pub fn encode(descriptors: &[Descriptor]) -> Vec<u8> {
+    descriptors
+        .iter()
+        .flat_map(|descriptor| {
+            let (large, name, data): (bool, u8, Vec<u8>) = descriptor.encode();
+            let header: Vec<u8> = if large {
+                iter::once(LARGE | name)
+                    .chain((data.len() as u16).to_le_bytes())
+                    .collect()
+            } else {
+                vec![(name << 3) | (data.len() as u8)]
+            };
+            header.into_iter().chain(data)
+        })
+        .chain([(END_TAG << 3) | 1, 0])
+        .collect()
+}
+
+const LARGE: u8 = 0x80;
+const END_TAG: u8 = 0x0f;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Descriptor {
+    /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 6.4.2.1 IRQ Descriptor
+    /// `flags` is `None` for `IRQNoFlags`, which means an edge triggered, active high and exclusive interrupt.
+    Irq {
+        irqs: Vec<u8>,
+        flags: Option<InterruptFlags>,
+    },
+    /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 6.4.2.2 DMA Descriptor
+    Dma {
+        channels: Vec<u8>,
+        speed: u8,
+        bus_master: bool,
+        transfer_type: u8,
+    },
+    /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 6.4.2.3 Start Dependent Functions Descriptor
+    StartDependentFunctions { priority: Option<u8> },
+    /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 6.4.2.4 End Dependent Functions Descriptor
+    EndDependentFunctions,
+    /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 6.4.2.5 I/O Port Descriptor
+    Io {
+        decode16: bool,
+        minimum: u16,
+        maximum: u16,
+        alignment: u8,
+        length: u8,
+    },
+    /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 6.4.2.6 Fixed Location I/O Port Descriptor
+    FixedIo { base: u16, length: u8 },
+    /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 6.4.2.7 Fixed DMA Descriptor
+    FixedDma {
+        request_line: u16,
+        channel: u16,
+        width: u8,
+    },
+    /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 6.4.3.7 Generic Register Descriptor
+    GenericRegister {
+        address_space: u8,
+        bit_width: u8,
+        bit_offset: u8,
+        access_size: u8,
+        address: u64,
+    },
+    /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 6.4.3.3 32-Bit Memory Range Descriptor
+    Memory32 {
+        writable: bool,
+        minimum: u32,
+        maximum: u32,
+        alignment: u32,
+        length: u32,
+    },
+    /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 6.4.3.4 32-Bit Fixed Memory Range Descriptor
+    Memory32Fixed {
+        writable: bool,
+        base: u32,
+        length: u32,
+    },
+    /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 6.4.3.5 Address Space Resource Descriptors
+    /// Word, DWord and QWord address space descriptors differ only in `width`.
+    Address {
+        width: AddressWidth,
+        resource_type: ResourceType,
+        consumer: bool,
+        subtractive_decode: bool,
+        minimum_fixed: bool,
+        maximum_fixed: bool,
+        type_specific_flags: u8,
+        granularity: u64,
+        minimum: u64,
+        maximum: u64,
+        translation_offset: u64,
+        length: u64,
+        resource_source: Option<ResourceSource>,
+    },
+    /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 6.4.3.6 Extended Interrupt Descriptor
+    ExtendedInterrupt {
+        consumer: bool,
+        flags: InterruptFlags,
+        interrupts: Vec<u32>,
+        resource_source: Option<ResourceSource>,
+    },
+    /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 6.4.3.8.1 GPIO Connection Descriptor
+    /// `interrupt` tells `GpioInt` from `GpioIo`, and `flags` are the interrupt and I/O flags of either.
+    Gpio {
+        revision: u8,
+        interrupt: bool,
+        consumer: bool,
+        flags: u16,
+        pin_configuration: u8,
+        output_drive_strength: u16,
+        debounce_timeout: u16,
+        pins: Vec<u16>,
+        resource_source: ResourceSource,
+        vendor_data: Vec<u8>,
+    },
+    /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 6.4.3.8.2 GenericSerialBus Connection Descriptors
+    SerialBus {
+        revision: u8,
+        bus: SerialBus,
+        slave_mode: bool,
+        consumer: bool,
+        shared: bool,
+        type_specific_flags: u16,
+        type_specific_revision: u8,
+        resource_source: ResourceSource,
+    },
+    /// A descriptor which is kept as it is, excluding its tag and length.
+    Other {
+        large: bool,
+        name: u8,
+        data: Vec<u8>,
+    },
+}
+
+impl Descriptor {
+    /// # Interrupts which the descriptor describes
+    pub fn interrupts(&self) -> Vec<(u32, InterruptFlags)> {
+        match self {
+            Self::Irq { irqs, flags } => irqs
+                .iter()
+                .map(|irq| (*irq as u32, flags.unwrap_or_else(InterruptFlags::no_flags)))
+                .collect(),
+            Self::ExtendedInterrupt {
+                flags, interrupts, ..
+            } => interrupts
+                .iter()
+                .map(|interrupt| (*interrupt, *flags))
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// # I/O ports which the descriptor describes
+    /// A range of an I/O port descriptor starts at its minimum base address.
+    pub fn io(&self) -> Option<Range<u64>> {
+        match self {
+            Self::Io {
+                minimum, length, ..
+            } => Some(*minimum as u64..*minimum as u64 + *length as u64),
+            Self::FixedIo { base, length } => Some(*base as u64..*base as u64 + *length as u64),
+            Self::Address {
+                resource_type: ResourceType::Io,
+                minimum,
+                length,
+                ..
+            } => Some(*minimum..*minimum + *length),
+            _ => None,
+        }
+    }
+
+    /// # Memory which the descriptor describes
+    /// A range of a memory range descriptor starts at its minimum base address.
+    pub fn memory(&self) -> Option<Range<u64>> {
+        match self {
+            Self::Memory32 {
+                minimum, length, ..
+            } => Some(*minimum as u64..*minimum as u64 + *length as u64),
+            Self::Memory32Fixed { base, length, .. } => {
+                Some(*base as u64..*base as u64 + *length as u64)
+            }
+            Self::Address {
+                resource_type: ResourceType::Memory,
+                minimum,
+                length,
+                ..
+            } => Some(*minimum..*minimum + *length),
+            _ => None,
+        }
+    }
+
+    fn decode_small(name: u8, data: &[u8]) -> Option<Self> {
+        match name {
+            0x04 => {
+                let mask: u16 = u16_at(data, 0)?;
+                let irqs: Vec<u8> = bits(mask as u64, u16::BITS);
+                let flags: Option<InterruptFlags> = data.get(2).map(|flags| InterruptFlags {
+                    edge_triggered: flags & 0x01 != 0,
+                    active_low: flags & 0x08 != 0,
+                    shared: flags & 0x10 != 0,
+                    wake_capable: flags & 0x20 != 0,
+                });
+                Some(Self::Irq { irqs, flags })
+            }
+            0x05 => {
+                let channels: Vec<u8> = bits(*data.first()? as u64, u8::BITS);
+                let flags: u8 = *data.get(1)?;
+                Some(Self::Dma {
+                    channels,
+                    speed: (flags >> 5) & 0x03,
+                    bus_master: flags & 0x04 != 0,
+                    transfer_type: flags & 0x03,
+                })
+            }
+            0x06 => Some(Self::StartDependentFunctions {
+                priority: data.first().copied(),
+            }),
+            0x07 => Some(Self::EndDependentFunctions),
+            0x08 => Some(Self::Io {
+                decode16: *data.first()? & 0x01 != 0,
+                minimum: u16_at(data, 1)?,
+                maximum: u16_at(data, 3)?,
+                alignment: *data.get(5)?,
+                length: *data.get(6)?,
+            }),
+            0x09 => Some(Self::FixedIo {
+                base: u16_at(data, 0)? & 0x03ff,
+                length: *data.get(2)?,
+            }),
+            0x0a => Some(Self::FixedDma {
+                request_line: u16_at(data, 0)?,
+                channel: u16_at(data, 2)?,
+                width: *data.get(4)?,
+            }),
+            name => Some(Self::Other {
+                large: false,
+                name,
+                data: data.to_vec(),
+            }),
+        }
+    }
+
+    fn decode_large(name: u8, data: &[u8]) -> Option<Self> {
+        match name {
+            0x02 => Some(Self::GenericRegister {
+                address_space: *data.first()?,
+                bit_width: *data.get(1)?,
+                bit_offset: *data.get(2)?,
+                access_size: *data.get(3)?,
+                address: u64_at(data, 4)?,
+            }),
+            0x05 => Some(Self::Memory32 {
+                writable: *data.first()? & 0x01 != 0,
+                minimum: u32_at(data, 1)?,
+                maximum: u32_at(data, 5)?,
+                alignment: u32_at(data, 9)?,
+                length: u32_at(data, 13)?,
+            }),
+            0x06 => Some(Self::Memory32Fixed {
+                writable: *data.first()? & 0x01 != 0,
+                base: u32_at(data, 1)?,
+                length: u32_at(data, 5)?,
+            }),
+            0x07 | 0x08 | 0x0a => {
+                let width: AddressWidth = match name {
+                    0x07 => AddressWidth::DWord,
+                    0x08 => AddressWidth::Word,
+                    _ => AddressWidth::QWord,
+                };
+                let size: usize = width.size();
+                let general_flags: u8 = *data.get(1)?;
+                let values: Vec<u64> = (0..5)
+                    .map(|index| uint_at(data, 3 + index * size, size))
+                    .collect::<Option<Vec<u64>>>()?;
+                Some(Self::Address {
+                    width,
+                    resource_type: (*data.first()?).into(),
+                    consumer: general_flags & 0x01 != 0,
+                    subtractive_decode: general_flags & 0x02 != 0,
+                    minimum_fixed: general_flags & 0x04 != 0,
+                    maximum_fixed: general_flags & 0x08 != 0,
+                    type_specific_flags: *data.get(2)?,
+                    granularity: values[0],
+                    minimum: values[1],
+                    maximum: values[2],
+                    translation_offset: values[3],
+                    length: values[4],
+                    resource_source: ResourceSource::decode(data.get(3 + 5 * size..)?),
+                })
+            }
+            0x09 => {
+                let flags: u8 = *data.first()?;
+                let count: usize = *data.get(1)? as usize;
+                let interrupts: Vec<u32> = (0..count)
+                    .map(|index| u32_at(data, 2 + 4 * index))
+                    .collect::<Option<Vec<u32>>>()?;
+                Some(Self::ExtendedInterrupt {
+                    consumer: flags & 0x01 != 0,
+                    flags: InterruptFlags {
+                        edge_triggered: flags & 0x02 != 0,
+                        active_low: flags & 0x04 != 0,
+                        shared: flags & 0x08 != 0,
+                        wake_capable: flags & 0x10 != 0,
+                    },
+                    interrupts,
+                    resource_source: ResourceSource::decode(data.get(2 + 4 * count..)?),
+                })
+            }
+            0x0c => {
+                // Offsets in a GPIO connection descriptor count from its tag.
+                let offset = |offset: usize| (u16_at(data, offset)? as usize).checked_sub(3);
+                let pin_table: usize = offset(11)?;
+                let resource_source_name: usize = offset(14)?;
+                let vendor_data: usize = offset(16)?;
+                let vendor_data_length: usize = u16_at(data, 18)? as usize;
+                let pins: Vec<u16> = data
+                    .get(pin_table..resource_source_name)?
+                    .chunks_exact(2)
+                    .map(|pin| u16::from_le_bytes([pin[0], pin[1]]))
+                    .collect();
+                Some(Self::Gpio {
+                    revision: *data.first()?,
+                    interrupt: *data.get(1)? == 0,
+                    consumer: u16_at(data, 2)? & 0x0001 != 0,
+                    flags: u16_at(data, 4)?,
+                    pin_configuration: *data.get(6)?,
+                    output_drive_strength: u16_at(data, 7)?,
+                    debounce_timeout: u16_at(data, 9)?,
+                    pins,
+                    resource_source: ResourceSource {
+                        index: *data.get(13)?,
+                        name: string(data.get(resource_source_name..vendor_data)?)?,
+                    },
+                    vendor_data: data
+                        .get(vendor_data..vendor_data + vendor_data_length)?
+                        .to_vec(),
+                })
+            }
+            0x0e => {
+                let general_flags: u8 = *data.get(3)?;
+                let type_data_length: usize = u16_at(data, 7)? as usize;
+                let type_data: &[u8] = data.get(9..9 + type_data_length)?;
+                let bus: SerialBus = match *data.get(2)? {
+                    SerialBus::I2C => SerialBus::I2c {
+                        connection_speed: u32_at(type_data, 0)?,
+                        slave_address: u16_at(type_data, 4)?,
+                        vendor_data: type_data.get(6..)?.to_vec(),
+                    },
+                    bus_type => SerialBus::Other {
+                        bus_type,
+                        data: type_data.to_vec(),
+                    },
+                };
+                Some(Self::SerialBus {
+                    revision: *data.first()?,
+                    bus,
+                    slave_mode: general_flags & 0x01 != 0,
+                    consumer: general_flags & 0x02 != 0,
+                    shared: general_flags & 0x04 != 0,
+                    type_specific_flags: u16_at(data, 4)?,
+                    type_specific_revision: *data.get(6)?,
+                    resource_source: ResourceSource {
+                        index: *data.get(1)?,
+                        name: string(&data[9 + type_data_length..])?,
+                    },
+                })
+            }
+            name => Some(Self::Other {
+                large: true,
+                name,
+                data: data.to_vec(),
+            }),
+        }
+    }
+
+    /// # Encode the descriptor
+    /// Returns whether the descriptor is large, its item name and its data following the length.
+    fn encode(&self) -> (bool, u8, Vec<u8>) {
+        match self {
+            Self::Irq { irqs, flags } => {
+                let mask: u16 = irqs.iter().fold(0, |mask, irq| mask | (1 << irq));
+                let flags: Option<u8> = flags.map(|flags| {
+                    (flags.edge_triggered as u8)
+                        | ((flags.active_low as u8) << 3)
+                        | ((flags.shared as u8) << 4)
+                        | ((flags.wake_capable as u8) << 5)
+                });
+                (
+                    false,
+                    0x04,
+                    mask.to_le_bytes().into_iter().chain(flags).collect(),
+                )
+            }
+            Self::Dma {
+                channels,
+                speed,
+                bus_master,
+                transfer_type,
+            } => {
+                let mask: u8 = channels
+                    .iter()
+                    .fold(0, |mask, channel| mask | (1 << channel));
+                let flags: u8 =
+                    ((speed & 0x03) << 5) | ((*bus_master as u8) << 2) | (transfer_type & 0x03);
+                (false, 0x05, vec![mask, flags])
+            }
+            Self::StartDependentFunctions { priority } => {
+                (false, 0x06, priority.iter().copied().collect())
+            }
+            Self::EndDependentFunctions => (false, 0x07, Vec::new()),
+            Self::Io {
+                decode16,
+                minimum,
+                maximum,
+                alignment,
+                length,
+            } => (
+                false,
+                0x08,
+                iter::once(*decode16 as u8)
+                    .chain(minimum.to_le_bytes())
+                    .chain(maximum.to_le_bytes())
+                    .chain([*alignment, *length])
+                    .collect(),
+            ),
+            Self::FixedIo { base, length } => (
+                false,
+                0x09,
+                base.to_le_bytes().into_iter().chain([*length]).collect(),
+            ),
+            Self::FixedDma {
+                request_line,
+                channel,
+                width,
+            } => (
+                false,
+                0x0a,
+                request_line
+                    .to_le_bytes()
+                    .into_iter()
+                    .chain(channel.to_le_bytes())
+                    .chain([*width])
+                    .collect(),
+            ),
+            Self::GenericRegister {
+                address_space,
+                bit_width,
+                bit_offset,
+                access_size,
+                address,
+            } => (
+                true,
+                0x02,
+                [*address_space, *bit_width, *bit_offset, *access_size]
+                    .into_iter()
+                    .chain(address.to_le_bytes())
+                    .collect(),
+            ),
+            Self::Memory32 {
+                writable,
+                minimum,
+                maximum,
+                alignment,
+                length,
+            } => (
+                true,
+                0x05,
+                iter::once(*writable as u8)
+                    .chain(minimum.to_le_bytes())
+                    .chain(maximum.to_le_bytes())
+                    .chain(alignment.to_le_bytes())
+                    .chain(length.to_le_bytes())
+                    .collect(),
+            ),
+            Self::Memory32Fixed {
+                writable,
+                base,
+                length,
+            } => (
+                true,
+                0x06,
+                iter::once(*writable as u8)
+                    .chain(base.to_le_bytes())
+                    .chain(length.to_le_bytes())
+                    .collect(),
+            ),
+            Self::Address {
+                width,
+                resource_type,
+                consumer,
+                subtractive_decode,
+                minimum_fixed,
+                maximum_fixed,
+                type_specific_flags,
+                granularity,
+                minimum,
+                maximum,
+                translation_offset,
+                length,
+                resource_source,
+            } => {
+                let name: u8 = match width {
+                    AddressWidth::Word => 0x08,
+                    AddressWidth::DWord => 0x07,
+                    AddressWidth::QWord => 0x0a,
+                };
+                let general_flags: u8 = (*consumer as u8)
+                    | ((*subtractive_decode as u8) << 1)
+                    | ((*minimum_fixed as u8) << 2)
+                    | ((*maximum_fixed as u8) << 3);
+                let data: Vec<u8> = [(*resource_type).into(), general_flags, *type_specific_flags]
+                    .into_iter()
+                    .chain(
+                        [
+                            *granularity,
+                            *minimum,
+                            *maximum,
+                            *translation_offset,
+                            *length,
+                        ]
+                        .into_iter()
+                        .flat_map(|value| value.to_le_bytes().into_iter().take(width.size())),
+                    )
+                    .chain(resource_source.iter().flat_map(ResourceSource::encode))
+                    .collect();
+                (true, name, data)
+            }
+            Self::ExtendedInterrupt {
+                consumer,
+                flags,
+                interrupts,
+                resource_source,
+            } => {
+                let flags: u8 = (*consumer as u8)
+                    | ((flags.edge_triggered as u8) << 1)
+                    | ((flags.active_low as u8) << 2)
+                    | ((flags.shared as u8) << 3)
+                    | ((flags.wake_capable as u8) << 4);
+                let data: Vec<u8> = [flags, interrupts.len() as u8]
+                    .into_iter()
+                    .chain(
+                        interrupts
+                            .iter()
+                            .flat_map(|interrupt| interrupt.to_le_bytes()),
+                    )
+                    .chain(resource_source.iter().flat_map(ResourceSource::encode))
+                    .collect();
+                (true, 0x09, data)
+            }
+            Self::Gpio {
+                revision,
+                interrupt,
+                consumer,
+                flags,
+                pin_configuration,
+                output_drive_strength,
+                debounce_timeout,
+                pins,
+                resource_source,
+                vendor_data,
+            } => {
+                let pin_table: u16 = 3 + 20;
+                let resource_source_name: u16 = pin_table + 2 * pins.len() as u16;
+                let vendor_data_offset: u16 =
+                    resource_source_name + resource_source.name.len() as u16 + 1;
+                let data: Vec<u8> = [*revision, !interrupt as u8]
+                    .into_iter()
+                    .chain((*consumer as u16).to_le_bytes())
+                    .chain(flags.to_le_bytes())
+                    .chain([*pin_configuration])
+                    .chain(output_drive_strength.to_le_bytes())
+                    .chain(debounce_timeout.to_le_bytes())
+                    .chain(pin_table.to_le_bytes())
+                    .chain([resource_source.index])
+                    .chain(resource_source_name.to_le_bytes())
+                    .chain(vendor_data_offset.to_le_bytes())
+                    .chain((vendor_data.len() as u16).to_le_bytes())
+                    .chain(pins.iter().flat_map(|pin| pin.to_le_bytes()))
+                    .chain(resource_source.name.bytes())
+                    .chain([0])
+                    .chain(vendor_data.iter().copied())
+                    .collect();
+                (true, 0x0c, data)
+            }
+            Self::SerialBus {
+                revision,
+                bus,
+                slave_mode,
+                consumer,
+                shared,
+                type_specific_flags,
+                type_specific_revision,
+                resource_source,
+            } => {
+                let (bus_type, type_data): (u8, Vec<u8>) = match bus {
+                    SerialBus::I2c {
+                        connection_speed,
+                        slave_address,
+                        vendor_data,
+                    } => (
+                        SerialBus::I2C,
+                        connection_speed
+                            .to_le_bytes()
+                            .into_iter()
+                            .chain(slave_address.to_le_bytes())
+                            .chain(vendor_data.iter().copied())
+                            .collect(),
+                    ),
+                    SerialBus::Other { bus_type, data } => (*bus_type, data.clone()),
+                };
+                let general_flags: u8 =
+                    (*slave_mode as u8) | ((*consumer as u8) << 1) | ((*shared as u8) << 2);
+                let data: Vec<u8> = [*revision, resource_source.index, bus_type, general_flags]
+                    .into_iter()
+                    .chain(type_specific_flags.to_le_bytes())
+                    .chain([*type_specific_revision])
+                    .chain((type_data.len() as u16).to_le_bytes())
+                    .chain(type_data)
+                    .chain(resource_source.name.bytes())
+                    .chain([0])
+                    .collect();
+                (true, 0x0e, data)
+            }
+            Self::Other { large, name, data } => (*large, *name, data.clone()),
+        }
+    }
+}
+
+/// # Width of an address space resource descriptor
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AddressWidth {
+    Word,
+    DWord,
+    QWord,
+}
+
+impl AddressWidth {
+    fn size(&self) -> usize {
+        match self {
+            Self::Word => 2,
+            Self::DWord => 4,
+            Self::QWord => 8,
+        }
+    }
+}
+
+/// # Interrupt flags of IRQ and extended interrupt descriptors
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct InterruptFlags {
+    pub edge_triggered: bool,
+    pub active_low: bool,
+    pub shared: bool,
+    pub wake_capable: bool,
+}
+
+impl InterruptFlags {
+    /// The flags of an IRQ descriptor without the information byte.
+    pub fn no_flags() -> Self {
+        Self {
+            edge_triggered: true,
+            ..Self::default()
+        }
+    }
+}
+
+/// # Resource type of an address space resource descriptor
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ResourceType {
+    Memory,
+    Io,
+    BusNumber,
+    Other(u8),
+}
+
+impl From<u8> for ResourceType {
+    fn from(resource_type: u8) -> Self {
+        match resource_type {
+            0 => Self::Memory,
+            1 => Self::Io,
+            2 => Self::BusNumber,
+            resource_type => Self::Other(resource_type),
+        }
+    }
+}
+
+impl From<ResourceType> for u8 {
+    fn from(resource_type: ResourceType) -> Self {
+        match resource_type {
+            ResourceType::Memory => 0,
+            ResourceType::Io => 1,
+            ResourceType::BusNumber => 2,
+            ResourceType::Other(resource_type) => resource_type,
+        }
+    }
+}
+
+/// # A device which produces the resource
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResourceSource {
+    pub index: u8,
+    pub name: String,
+}
+
+impl ResourceSource {
+    /// The optional resource source at the end of address space and extended interrupt descriptors.
+    fn decode(data: &[u8]) -> Option<Self> {
+        data.split_first().and_then(|(index, name)| {
+            string(name).map(|name| Self {
+                index: *index,
+                name,
+            })
+        })
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        iter::once(self.index)
+            .chain(self.name.bytes())
+            .chain([0])
+            .collect()
+    }
+}
+
+/// # Bus of a serial bus connection descriptor
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SerialBus {
+    /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 6.4.3.8.2.1 I2C Serial Bus Connection Resource Descriptor
+    I2c {
+        connection_speed: u32,
+        slave_address: u16,
+        vendor_data: Vec<u8>,
+    },
+    /// SPI, UART and others are kept as their type specific data.
+    Other { bus_type: u8, data: Vec<u8> },
+}
+
+impl SerialBus {
+    const I2C: u8 = 1;
+}
+
+/// Positions of set bits in a mask.
+fn bits(mask: u64, width: u32) -> Vec<u8> {
+    (0..width as u8)
+        .filter(|bit| mask & (1 << bit) != 0)
+        .collect()
+}
+
+/// A null terminated string.
+fn string(bytes: &[u8]) -> Option<String> {
+    let length: usize = bytes
+        .iter()
+        .position(|byte| *byte == 0)
+        .unwrap_or(bytes.len());
+    str::from_utf8(&bytes[..length]).ok().map(String::from)
+}
+
+fn evaluate(root: &reference::Node, device: &name::Path, object: &str) -> Option<Vec<u8>> {
+    let object: name::Path = device.clone() + object.into();
+    match root.evaluate(&object, Vec::new())? {
+        Value::Buffer(template) => Some(template),
+        _ => None,
+    }
+}
+
+fn u16_at(bytes: &[u8], offset: usize) -> Option<u16> {
+    uint_at(bytes, offset, 2).map(|value| value as u16)
+}
+
+fn u32_at(bytes: &[u8], offset: usize) -> Option<u32> {
+    uint_at(bytes, offset, 4).map(|value| value as u32)
+}
+
+fn u64_at(bytes: &[u8], offset: usize) -> Option<u64> {
+    uint_at(bytes, offset, 8)
+}
+
+/// A little endian integer of `size` bytes.
+fn uint_at(bytes: &[u8], offset: usize, size: usize) -> Option<u64> {
+    bytes.get(offset..offset + size).map(|bytes| {
+        bytes
+            .iter()
+            .rev()
+            .fold(0, |value, byte| (value << u8::BITS) | *byte as u64)
+    })
+}
@@ -0,0 +1,210 @@
+//! # AML Synchronization Objects
+//! A mutex is owned by the context which acquired it, and acquiring it again in the same context nests.
+//! Acquiring a mutex owned by another context waits until it is released or the timeout occurs.
+//! The global lock `\_GL` is shared with the firmware through the FACS too, so it is also acquired from the firmware after it is acquired from the other contexts.
+//! ## References
+//! * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 5.2.10.1 Global Lock
+//! * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 19.6.2 Acquire (Acquire a Mutex)
+//! * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 19.6.89 Mutex (Declare Synchronization/Mutex Object)
+//! * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 19.6.145 Wait (Wait for a Synchronization Event)
+
+use {
+    super::{debug_println, name, platform, spin},
+    alloc::{collections::BTreeMap, vec::Vec},
+};
+
+/// A timeout value meaning that Acquire and Wait never time out.
+pub const WAIT_FOREVER: u16 = 0xffff;
+
+static STATE: spin::Lock<State> = spin::Lock::new(State::new());
+
+/// # Acquire a mutex
+/// Returns true if the timeout occurred.
+pub fn acquire_mutex(mutex: &name::Path, sync_level: u8, timeout: u16) -> bool {
+    let owner: usize = platform::get().context_id();
+    {
+        let mut state: spin::Guard<State> = STATE.lock();
+        let current_sync_level: u8 = state.current_sync_level(owner);
+        let acquired: &mut Mutex = state
+            .mutexes
+            .entry(mutex.clone())
+            .or_insert_with(|| Mutex::new(sync_level));
+        if acquired.owner == Some(owner) {
+            acquired.depth += 1;
+            return false;
+        }
+        if sync_level < current_sync_level {
+            debug_println!(
+                "Can't acquire {:#x?} at sync level {:#x?} while holding sync level {:#x?}",
+                mutex,
+                sync_level,
+                current_sync_level
+            );
+            return true;
+        }
+    }
+    // The state isn't locked while waiting so that the owner can release the mutex.
+    if wait(timeout, || STATE.lock().claim(mutex, owner)) {
+        true
+    } else if *mutex == global_lock_path() && wait(timeout, acquire_firmware_global_lock) {
+        STATE.lock().unclaim(mutex);
+        true
+    } else {
+        false
+    }
+}
+
+/// # Path of the global lock
+pub fn global_lock_path() -> name::Path {
+    "\\_GL".into()
+}
+
+/// # Release a mutex
+/// Only the owner of the mutex can release it.
+pub fn release_mutex(mutex: &name::Path) {
+    let owner: usize = platform::get().context_id();
+    let released: bool = {
+        let mut state: spin::Guard<State> = STATE.lock();
+        match state.mutexes.get_mut(mutex) {
+            Some(acquired) if acquired.owner == Some(owner) => {
+                acquired.depth -= 1;
+                let released: bool = acquired.depth == 0;
+                if released {
+                    state.unclaim(mutex);
+                }
+                released
+            }
+            Some(acquired) if acquired.owner.is_some() => {
+                debug_println!("Can't release {:#x?} owned by another context", mutex);
+                false
+            }
+            _ => {
+                debug_println!("Can't release {:#x?} which is not acquired", mutex);
+                false
+            }
+        }
+    };
+    if released && *mutex == global_lock_path() {
+        release_firmware_global_lock();
+    }
+}
+
+/// # Reset an event
+pub fn reset_event(event: &name::Path) {
+    STATE.lock().events.insert(event.clone(), 0);
+}
+
+/// # Signal an event
+pub fn signal_event(event: &name::Path) {
+    *STATE.lock().events.entry(event.clone()).or_default() += 1;
+}
+
+/// # Wait for an event
+/// Returns true if the timeout occurred.
+pub fn wait_event(event: &name::Path, timeout: u16) -> bool {
+    wait(timeout, || {
+        let mut state: spin::Guard<State> = STATE.lock();
+        let signals: &mut usize = state.events.entry(event.clone()).or_default();
+        let signaled: bool = 0 < *signals;
+        if signaled {
+            *signals -= 1;
+        }
+        signaled
+    })
+}
+
+fn acquire_firmware_global_lock() -> bool {
+    platform::get().acquire_global_lock()
+}
+
+fn release_firmware_global_lock() {
+    platform::get().release_global_lock();
+}
+
+/// Returns true if the condition is not satisfied within the timeout in milliseconds.
+fn wait<F>(timeout: u16, mut condition: F) -> bool
+where
+    F: FnMut() -> bool,
+{
+    let mut elapsed: u16 = 0;
+    while !condition() {
+        if timeout != WAIT_FOREVER && timeout <= elapsed {
+            return true;
+        }
+        platform::get().sleep_milliseconds(1);
+        elapsed = elapsed.saturating_add(1);
+    }
+    false
+}
+
+#[derive(Debug)]
+struct Mutex {
+    depth: usize,
+    owner: Option<usize>,
+    sync_level: u8,
+}
+
+impl Mutex {
+    fn new(sync_level: u8) -> Self {
+        let depth: usize = 0;
+        let owner: Option<usize> = None;
+        Self {
+            depth,
+            owner,
+            sync_level,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct State {
+    events: BTreeMap<name::Path, usize>,
+    held: Vec<name::Path>,
+    mutexes: BTreeMap<name::Path, Mutex>,
+}
+
+impl State {
+    const fn new() -> Self {
+        let events: BTreeMap<name::Path, usize> = BTreeMap::new();
+        let held: Vec<name::Path> = Vec::new();
+        let mutexes: BTreeMap<name::Path, Mutex> = BTreeMap::new();
+        Self {
+            events,
+            held,
+            mutexes,
+        }
+    }
+
+    /// # Make a context own a mutex if no context owns it
+    /// Returns true if the context owns the mutex.
+    fn claim(&mut self, mutex: &name::Path, owner: usize) -> bool {
+        let acquired: &mut Mutex = self.mutexes.get_mut(mutex).unwrap();
+        let claimed: bool = acquired.owner.is_none();
+        if claimed {
+            acquired.owner = Some(owner);
+            acquired.depth = 1;
+            self.held.push(mutex.clone());
+        }
+        claimed
+    }
+
+    /// # The highest sync level of the mutexes owned by a context
+    fn current_sync_level(&self, owner: usize) -> u8 {
+        self.held
+            .iter()
+            .filter_map(|held| self.mutexes.get(held))
+            .filter(|mutex| mutex.owner == Some(owner))
+            .map(|mutex| mutex.sync_level)
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// # Make a mutex owned by no context
+    fn unclaim(&mut self, mutex: &name::Path) {
+        if let Some(acquired) = self.mutexes.get_mut(mutex) {
+            acquired.owner = None;
+            acquired.depth = 0;
+        }
+        self.held.retain(|held| held != mutex);
+    }
+}
@@ -4,14 +4,16 @@
 
 use {
     super::{
+        debug_println, definition_block, disassembler,
         interpreter::{self, Evaluator, Holder},
-        name, reference,
+        name, notification, platform, reference, synchronization,
     },
-    crate::{com2_println, pci, timer, x64},
     alloc::{
         boxed::Box,
         collections::{btree_map::BTreeMap, vec_deque::VecDeque},
+        format,
         string::String,
+        vec,
         vec::Vec,
     },
     bitfield_struct::bitfield,
@@ -19,7 +21,8 @@ use {
 };
 
 pub trait Analyzer:
-    FirstReader
+    Disassembler
+    + FirstReader
     + Lender
     + Matcher
     + PathGetter
@@ -31,12 +34,16 @@ pub trait Analyzer:
 {
 }
 
+pub trait Disassembler {
+    fn disassemble(&self) -> Option<disassembler::Asl>;
+}
+
 pub trait FirstReader {
     fn first_read<'a>(
         aml: &'a [u8],
         root: &mut name::Node,
         current: &name::Path,
-    ) -> (Self, &'a [u8])
+    ) -> Option<(Self, &'a [u8])>
     where
         Self: Sized;
 }
@@ -56,7 +63,7 @@ pub trait PathGetter {
 }
 
 pub trait Reader {
-    fn read(aml: &[u8]) -> (Self, &[u8])
+    fn read(aml: &[u8]) -> Option<(Self, &[u8])>
     where
         Self: Sized;
 }
@@ -66,7 +73,7 @@ pub trait ReaderInsideMethod {
         aml: &'a [u8],
         root: &mut name::Node,
         current: &name::Path,
-    ) -> (Self, &'a [u8])
+    ) -> Option<(Self, &'a [u8])>
     where
         Self: Sized;
 }
@@ -111,26 +118,45 @@ impl<'a> Iterator for MutSymbolIterator<'a> {
 /// # AccessAttrib
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct AccessAttrib(ByteData);
 
 /// # AccessField
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
+#[manual(disassembler)]
 pub struct AccessField(AccessFieldOp, AccessType, AccessAttrib);
 
+impl Disassembler for AccessField {
+    fn disassemble(&self) -> Option<disassembler::Asl> {
+        let Self(_access_field_op, access_type, access_attrib) = self;
+        let access_type: Option<disassembler::Asl> = access_type.disassemble();
+        let AccessAttrib(access_attrib) = access_attrib;
+        let access_attrib: u8 = access_attrib.into();
+        access_type.map(|access_type| {
+            let access_type: String = access_type.lines().join("");
+            let access_as: String = if access_attrib == 0 {
+                format!("AccessAs ({})", access_type)
+            } else {
+                format!("AccessAs ({}, {:#04x})", access_type, access_attrib)
+            };
+            disassembler::Asl::Term(access_as)
+        })
+    }
+}
+
 /// # AccessFieldOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x01]
 pub struct AccessFieldOp;
 
 /// # AccessType
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer)]
+#[derive(acpi_machine_language_derive::Analyzer)]
 #[bitfield(u8)]
 pub struct AccessType {
     #[bits(4)]
@@ -141,6 +167,13 @@ pub struct AccessType {
     access_attrib: u8,
 }
 
+impl Disassembler for AccessType {
+    fn disassemble(&self) -> Option<disassembler::Asl> {
+        let access_type: interpreter::AccessType = self.into();
+        Some(disassembler::Asl::Term(format!("{:?}Acc", access_type)))
+    }
+}
+
 impl AccessType {
     pub fn get_access_type(&self) -> u8 {
         self.access_type()
@@ -150,38 +183,46 @@ impl AccessType {
 /// # AcquireOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[matching_elements = 2]
 pub struct AcquireOp(ExtOpPrefix, AcquireOpSuffix);
 
 /// # AcquireOpSuffix
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x23]
 pub struct AcquireOpSuffix;
 
 /// # AddOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x72]
 pub struct AddOp;
 
 /// # AliasOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.1 Namespace Modifier Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x06]
 pub struct AliasOp;
 
 /// # AmlString
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.3 Data Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
+#[manual(disassembler)]
 #[string]
 pub struct AmlString(#[not_string] StringPrefix, AsciiCharList, NullChar);
 
+impl Disassembler for AmlString {
+    fn disassemble(&self) -> Option<disassembler::Asl> {
+        let string: String = self.into();
+        Some(disassembler::Asl::Term(format!("\"{}\"", string)))
+    }
+}
+
 impl Evaluator for AmlString {
     fn evaluate(
         &self,
@@ -196,18 +237,26 @@ impl Evaluator for AmlString {
 /// # AndOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x7b]
 pub struct AndOp;
 
 /// # Arg Objects Encoding
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.6.1 Arg Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
+#[manual(disassembler)]
 #[encoding_value_min = 0x68]
 #[encoding_value_max = 0x6e]
 pub struct ArgObj(u8);
 
+impl Disassembler for ArgObj {
+    fn disassemble(&self) -> Option<disassembler::Asl> {
+        let Self(index) = self;
+        Some(disassembler::Asl::Term(format!("Arg{}", index)))
+    }
+}
+
 impl Evaluator for ArgObj {
     fn evaluate(
         &self,
@@ -236,7 +285,7 @@ impl Holder for ArgObj {
 /// # ArgObject
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.3 Statement Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct ArgObject(TermArg);
 
 impl Evaluator for ArgObject {
@@ -254,7 +303,7 @@ impl Evaluator for ArgObject {
 /// # ArgumentCount
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct ArgumentCount(ByteData);
 
 impl From<&ArgumentCount> for usize {
@@ -267,7 +316,7 @@ impl From<&ArgumentCount> for usize {
 /// # AsciiChar
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.3 Data Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value_min = 0x01]
 #[encoding_value_max = 0x7f]
 pub struct AsciiChar(char);
@@ -275,7 +324,7 @@ pub struct AsciiChar(char);
 /// # AsciiCharList
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.3 Data Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[matching_elements = 0]
 #[string]
 pub struct AsciiCharList(Vec<AsciiChar>);
@@ -294,7 +343,7 @@ impl Evaluator for AsciiCharList {
 /// # AsciiUppercase
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.2 Name Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value_min = 0x41]
 #[encoding_value_max = 0x5a]
 pub struct AsciiUppercase(char);
@@ -302,42 +351,42 @@ pub struct AsciiUppercase(char);
 /// # AttribBytes
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x0b]
 pub struct AttribBytes;
 
 /// # AttribRawBytes
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x0e]
 pub struct AttribRawBytes;
 
 /// # AttribRawProcess
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x0f]
 pub struct AttribRawProcess;
 
 /// # BankFieldOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[matching_elements = 2]
 pub struct BankFieldOp(ExtOpPrefix, BankFieldOpSuffix);
 
 /// # BankFieldOpSuffix
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x87]
 pub struct BankFieldOpSuffix;
 
 /// # BankValue
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct BankValue(TermArg);
 
 impl Evaluator for BankValue {
@@ -355,7 +404,7 @@ impl Evaluator for BankValue {
 /// # BcdValue
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct BcdValue(TermArg);
 
 impl Evaluator for BcdValue {
@@ -373,7 +422,7 @@ impl Evaluator for BcdValue {
 /// # BitIndex
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct BitIndex(TermArg);
 
 impl Evaluator for BitIndex {
@@ -391,21 +440,21 @@ impl Evaluator for BitIndex {
 /// # BreakOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.3 Statement Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0xa5]
 pub struct BreakOp;
 
 /// # BreakPointOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.3 Statement Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0xcc]
 pub struct BreakPointOp;
 
 /// # BufData
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct BufData(TermArg);
 
 impl Evaluator for BufData {
@@ -423,7 +472,7 @@ impl Evaluator for BufData {
 /// # BuffPkgStrObj
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct BuffPkgStrObj(TermArg);
 
 impl Evaluator for BuffPkgStrObj {
@@ -438,17 +487,30 @@ impl Evaluator for BuffPkgStrObj {
     }
 }
 
+impl Holder for BuffPkgStrObj {
+    fn hold(
+        &self,
+        value: interpreter::Value,
+        stack_frame: &mut interpreter::StackFrame,
+        root: &reference::Node,
+        current: &name::Path,
+    ) -> interpreter::Value {
+        let Self(term_arg) = self;
+        term_arg.hold(value, stack_frame, root, current)
+    }
+}
+
 /// # BufferOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x11]
 pub struct BufferOp;
 
 /// # BufferSize
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct BufferSize(TermArg);
 
 impl Evaluator for BufferSize {
@@ -466,7 +528,7 @@ impl Evaluator for BufferSize {
 /// # ByteConst
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.3 Data Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct ByteConst(BytePrefix, ByteData);
 
 impl Evaluator for ByteConst {
@@ -484,11 +546,19 @@ impl Evaluator for ByteConst {
 /// # ByteData
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.3 Data Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
+#[manual(disassembler)]
 #[encoding_value_min = 0x00]
 #[encoding_value_max = 0xff]
 pub struct ByteData(u8);
 
+impl Disassembler for ByteData {
+    fn disassemble(&self) -> Option<disassembler::Asl> {
+        let Self(byte) = self;
+        Some(disassembler::Asl::Term(format!("{:#04x}", byte)))
+    }
+}
+
 impl From<&ByteData> for u8 {
     fn from(byte_data: &ByteData) -> Self {
         let ByteData(byte_data) = byte_data;
@@ -518,15 +588,48 @@ impl Evaluator for ByteData {
 /// # ByteIndex
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct ByteIndex(TermArg);
 
 /// # ByteList
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.3 Data Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
+#[manual(disassembler)]
 pub struct ByteList(Vec<ByteData>);
 
+impl Disassembler for ByteList {
+    fn disassemble(&self) -> Option<disassembler::Asl> {
+        let Self(byte_list) = self;
+        let lines: Vec<String> = byte_list
+            .chunks(8)
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .map(|byte_data| {
+                        let byte: u8 = byte_data.into();
+                        format!("{:#04x}", byte)
+                    })
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            })
+            .collect();
+        let last: usize = lines.len().saturating_sub(1);
+        let lines: Vec<String> = lines
+            .into_iter()
+            .enumerate()
+            .map(|(index, line)| {
+                if index == last {
+                    line
+                } else {
+                    format!("{},", line)
+                }
+            })
+            .collect();
+        Some(disassembler::Asl::List(lines))
+    }
+}
+
 impl From<&ByteList> for Vec<u8> {
     fn from(byte_list: &ByteList) -> Self {
         let ByteList(byte_list) = byte_list;
@@ -558,14 +661,14 @@ impl Evaluator for ByteList {
 /// # BytePrefix
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.3 Data Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x0a]
 pub struct BytePrefix;
 
 /// # ComputationalData
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.3 Data Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub enum ComputationalData {
     AmlString(AmlString),
     ByteConst(ByteConst),
@@ -600,41 +703,41 @@ impl Evaluator for ComputationalData {
 /// # ConcatOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x73]
 pub struct ConcatOp;
 
 /// # ConcatResOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x84]
 pub struct ConcatResOp;
 
 /// # CondRefOfOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[matching_elements = 2]
 pub struct CondRefOfOp(ExtOpPrefix, CondRefOfOpSuffix);
 
 /// # CondRefOfOpSuffix
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x12]
 pub struct CondRefOfOpSuffix;
 
 /// # ConnectField
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct ConnectField(ConnectFieldOp, ConnectFieldEnum);
 
 /// # ConnectFieldEnum
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub enum ConnectFieldEnum {
     NameString(NameString),
     BufData(BufData),
@@ -643,28 +746,28 @@ pub enum ConnectFieldEnum {
 /// # ConnectFieldOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x02]
 pub struct ConnectFieldOp;
 
 /// # ContinueOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.3 Statement Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x9f]
 pub struct ContinueOp;
 
 /// # CopyObjectOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x9d]
 pub struct CopyObjectOp;
 
 /// # ConstObj
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.3 Data Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub enum ConstObj {
     One(OneOp),
     Ones(OnesOp),
@@ -689,56 +792,56 @@ impl Evaluator for ConstObj {
 /// # CreateBitFieldOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x8d]
 pub struct CreateBitFieldOp;
 
 /// # CreateByteFieldOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x8c]
 pub struct CreateByteFieldOp;
 
 /// # CreateDWordFieldOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.3 Data Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x8a]
 pub struct CreateDWordFieldOp;
 
 /// # CreateFieldOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[matching_elements = 2]
 pub struct CreateFieldOp(ExtOpPrefix, CreateFieldOpSuffix);
 
 /// # CreateFieldOpSuffix
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x13]
 pub struct CreateFieldOpSuffix;
 
 /// # CreateQWordFieldOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x8f]
 pub struct CreateQWordFieldOp;
 
 /// # CreateWordFieldOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x8b]
 pub struct CreateWordFieldOp;
 
 /// # DWordConst
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.3 Data Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DWordConst(DWordPrefix, DWordData);
 
 impl Evaluator for DWordConst {
@@ -756,9 +859,26 @@ impl Evaluator for DWordConst {
 /// # DWordData
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.3 Data Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
+#[manual(disassembler)]
 pub struct DWordData([WordData; 2]);
 
+impl From<&DWordData> for u32 {
+    fn from(dword_data: &DWordData) -> Self {
+        let DWordData([low, high]) = dword_data;
+        let low: u16 = low.into();
+        let high: u16 = high.into();
+        (low as Self) | ((high as Self) << u16::BITS)
+    }
+}
+
+impl Disassembler for DWordData {
+    fn disassemble(&self) -> Option<disassembler::Asl> {
+        let dword: u32 = self.into();
+        Some(disassembler::Asl::Term(format!("{:#010x}", dword)))
+    }
+}
+
 impl Evaluator for DWordData {
     fn evaluate(
         &self,
@@ -769,21 +889,21 @@ impl Evaluator for DWordData {
         let Self([low, high]) = self;
         let low: Option<interpreter::Value> = low.evaluate(stack_frame, root, current);
         let high: Option<interpreter::Value> = high.evaluate(stack_frame, root, current);
-        low.zip(high).map(|(low, high)| low.concatenate(&high))
+        low.zip(high).and_then(|(low, high)| low.concatenate(&high))
     }
 }
 
 /// # DWordPrefix
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.3 Data Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x0c]
 pub struct DWordPrefix;
 
 /// # Data
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct Data(TermArg);
 
 impl Evaluator for Data {
@@ -801,7 +921,7 @@ impl Evaluator for Data {
 /// # DataObject
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.3 Data Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub enum DataObject {
     ComputationalData(ComputationalData),
     DefPackage(DefPackage),
@@ -830,7 +950,7 @@ impl Evaluator for DataObject {
 /// # DataRefObject
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.3 Data Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub enum DataRefObject {
     DataObject(DataObject),
     ObjReference(ObjReference),
@@ -845,9 +965,7 @@ impl Evaluator for DataRefObject {
     ) -> Option<interpreter::Value> {
         match self {
             Self::DataObject(data_object) => data_object.evaluate(stack_frame, root, current),
-            Self::ObjReference(obj_reference) => {
-                unimplemented!("obj_reference = {:#x?}", obj_reference)
-            }
+            Self::ObjReference(obj_reference) => obj_reference.evaluate(stack_frame, root, current),
         }
     }
 }
@@ -855,23 +973,58 @@ impl Evaluator for DataRefObject {
 /// # DataRegionOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[matching_elements = 2]
 pub struct DataRegionOp(ExtOpPrefix, DataRegionOpSuffix);
 
 /// # DataRegionOpSuffix
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x88]
 pub struct DataRegionOpSuffix;
 
+/// # DDBHandleObject
+/// ## References
+/// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.3 Statement Opcodes Encoding
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
+pub struct DdbHandleObject(SuperName);
+
+impl Evaluator for DdbHandleObject {
+    fn evaluate(
+        &self,
+        stack_frame: &mut interpreter::StackFrame,
+        root: &reference::Node,
+        current: &name::Path,
+    ) -> Option<interpreter::Value> {
+        let Self(super_name) = self;
+        match super_name {
+            SuperName::SimpleName(SimpleName::NameString(name_string)) => {
+                let name: name::Path = name_string.into();
+                let ddb_handle = name::AbsolutePath::new(current, &name);
+                stack_frame.read_named_local(&name).or_else(|| {
+                    root.get_name_from_current(&ddb_handle)
+                        .and_then(|(current, name)| name.evaluate(stack_frame, root, &current))
+                })
+            }
+            super_name => super_name.evaluate(stack_frame, root, current),
+        }
+    }
+}
+
 /// # DebugObj
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.6.3 Debug Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
+#[manual(disassembler)]
 pub struct DebugObj(DebugOp);
 
+impl Disassembler for DebugObj {
+    fn disassemble(&self) -> Option<disassembler::Asl> {
+        Some(disassembler::Asl::Term("Debug".into()))
+    }
+}
+
 impl Holder for DebugObj {
     fn hold(
         &self,
@@ -880,7 +1033,7 @@ impl Holder for DebugObj {
         _root: &reference::Node,
         _current: &name::Path,
     ) -> interpreter::Value {
-        com2_println!("AML DebugObj = {:#x?}", value);
+        platform::get().debug(&value);
         value
     }
 }
@@ -888,34 +1041,64 @@ impl Holder for DebugObj {
 /// # DebugOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.6.3 Debug Opects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[matching_elements = 2]
 pub struct DebugOp(ExtOpPrefix, DebugOpSuffix);
 
 /// # DebugOpSuffix
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.6.3 Debug Opects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x31]
 pub struct DebugOpSuffix;
 
 /// # DecrementOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x76]
 pub struct DecrementOp;
 
 /// # DefAcquire
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefAcquire(AcquireOp, MutexObject, Timeout);
 
+/// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 19.6.2 Acquire (Acquire a Mutex)
+impl Evaluator for DefAcquire {
+    fn evaluate(
+        &self,
+        stack_frame: &mut interpreter::StackFrame,
+        root: &reference::Node,
+        current: &name::Path,
+    ) -> Option<interpreter::Value> {
+        let Self(_acquire_op, mutex_object, timeout) = self;
+        let timeout: Option<u16> = timeout
+            .evaluate(stack_frame, root, current)
+            .as_ref()
+            .map(|timeout| timeout.into());
+        mutex_object
+            .solve(root, current)
+            .zip(timeout)
+            .map(|((mutex, sync_level), timeout)| {
+                synchronization::acquire_mutex(&mutex, sync_level, timeout)
+            })
+            .map(|timed_out| {
+                // True is Ones.
+                if timed_out {
+                    interpreter::Value::Ones
+                } else {
+                    interpreter::Value::Zero
+                }
+            })
+    }
+}
+
 /// # DefAdd
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefAdd(AddOp, [Operand; 2], Target);
 
 impl Evaluator for DefAdd {
@@ -929,14 +1112,15 @@ impl Evaluator for DefAdd {
         let left: Option<interpreter::Value> = left.evaluate(stack_frame, root, current);
         let right: Option<interpreter::Value> = right.evaluate(stack_frame, root, current);
         left.zip(right)
-            .map(|(left, right)| target.hold(left + right, stack_frame, root, current))
+            .and_then(|(left, right)| left + right)
+            .map(|value| target.hold(value, stack_frame, root, current))
     }
 }
 
 /// # DefAlias
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.1 Namespace Modifier Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[manual(first_reader, path_getter, reader_inside_method)]
 pub struct DefAlias(AliasOp, [NameString; 2]);
 
@@ -953,30 +1137,32 @@ impl FirstReader for DefAlias {
         aml: &'a [u8],
         root: &mut name::Node,
         current: &name::Path,
-    ) -> (Self, &'a [u8]) {
-        assert!(Self::matches(aml), "aml = {:#x?}", aml);
+    ) -> Option<(Self, &'a [u8])> {
+        if !Self::matches(aml) {
+            return None;
+        }
         let current: name::Path = current.clone();
         let symbol_aml: &[u8] = aml;
         let (alias_op, symbol_aml): (AliasOp, &[u8]) =
-            AliasOp::first_read(symbol_aml, root, &current);
+            AliasOp::first_read(symbol_aml, root, &current)?;
         let (original_name, symbol_aml): (NameString, &[u8]) =
-            NameString::first_read(symbol_aml, root, &current);
+            NameString::first_read(symbol_aml, root, &current)?;
         let (new_name, _symbol_aml): (NameString, &[u8]) =
-            NameString::first_read(symbol_aml, root, &current);
+            NameString::first_read(symbol_aml, root, &current)?;
         let original_path: name::Path = current.clone() + (&original_name).into();
         let new_path: name::Path = current.clone() + (&new_name).into();
         root.add_node(&new_path, name::Object::alias(&current, &original_path));
         let name_strings: [NameString; 2] = [original_name, new_name];
         let def_alias = Self(alias_op, name_strings);
-        let aml: &[u8] = &aml[def_alias.length()..];
-        (def_alias, aml)
+        let aml: &[u8] = aml.get(def_alias.length()..)?;
+        Some((def_alias, aml))
     }
 }
 
 impl PathGetter for DefAlias {
     fn get_path(&self) -> Option<name::Path> {
-        let Self(_alias_op, [original_name, _new_name]) = self;
-        Some(original_name.into())
+        let Self(_alias_op, [_original_name, new_name]) = self;
+        Some(new_name.into())
     }
 }
 
@@ -985,30 +1171,32 @@ impl ReaderInsideMethod for DefAlias {
         aml: &'a [u8],
         root: &mut name::Node,
         current: &name::Path,
-    ) -> (Self, &'a [u8]) {
-        assert!(Self::matches(aml), "aml = {:#x?}", aml);
+    ) -> Option<(Self, &'a [u8])> {
+        if !Self::matches(aml) {
+            return None;
+        }
         let current: name::Path = current.clone();
         let symbol_aml: &[u8] = aml;
         let (alias_op, symbol_aml): (AliasOp, &[u8]) =
-            AliasOp::read_inside_method(symbol_aml, root, &current);
+            AliasOp::read_inside_method(symbol_aml, root, &current)?;
         let (original_name, symbol_aml): (NameString, &[u8]) =
-            NameString::read_inside_method(symbol_aml, root, &current);
+            NameString::read_inside_method(symbol_aml, root, &current)?;
         let (new_name, _symbol_aml): (NameString, &[u8]) =
-            NameString::read_inside_method(symbol_aml, root, &current);
+            NameString::read_inside_method(symbol_aml, root, &current)?;
         let original_path: name::Path = current.clone() + (&original_name).into();
         let new_path: name::Path = current.clone() + (&new_name).into();
         root.add_node(&new_path, name::Object::alias(&current, &original_path));
         let name_strings: [NameString; 2] = [original_name, new_name];
         let def_alias = Self(alias_op, name_strings);
-        let aml: &[u8] = &aml[def_alias.length()..];
-        (def_alias, aml)
+        let aml: &[u8] = aml.get(def_alias.length()..)?;
+        Some((def_alias, aml))
     }
 }
 
 /// # DefAnd
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefAnd(AndOp, [Operand; 2], Target);
 
 impl Evaluator for DefAnd {
@@ -1022,14 +1210,15 @@ impl Evaluator for DefAnd {
         let left: Option<interpreter::Value> = left.evaluate(stack_frame, root, current);
         let right: Option<interpreter::Value> = right.evaluate(stack_frame, root, current);
         left.zip(right)
-            .map(|(left, right)| target.hold(left & right, stack_frame, root, current))
+            .and_then(|(left, right)| left & right)
+            .map(|value| target.hold(value, stack_frame, root, current))
     }
 }
 
 /// # DefBankField
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefBankField(
     BankFieldOp,
     PkgLength,
@@ -1042,7 +1231,7 @@ pub struct DefBankField(
 /// # DefBreak
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.3 Statement Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefBreak(BreakOp);
 
 impl Evaluator for DefBreak {
@@ -1060,7 +1249,7 @@ impl Evaluator for DefBreak {
 /// # DefBreakPoint
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.3 Statement Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefBreakPoint(BreakPointOp);
 
 impl Evaluator for DefBreakPoint {
@@ -1077,7 +1266,7 @@ impl Evaluator for DefBreakPoint {
 /// # DefBuffer
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefBuffer(BufferOp, PkgLength, BufferSize, #[no_leftover] ByteList);
 
 impl Evaluator for DefBuffer {
@@ -1091,12 +1280,11 @@ impl Evaluator for DefBuffer {
         let buffer_size: usize = buffer_size
             .evaluate(stack_frame, root, current)
             .as_ref()
-            .unwrap()
-            .into();
+            .map(|buffer_size| buffer_size.into())
+            .filter(|buffer_size| *buffer_size <= interpreter::MAX_OBJECT_SIZE)?;
         let byte_list: Vec<u8> = byte_list
             .evaluate(stack_frame, root, current)
-            .as_ref()
-            .unwrap()
+            .as_ref()?
             .into();
         let buffer: Vec<u8> = byte_list
             .into_iter()
@@ -1111,13 +1299,32 @@ impl Evaluator for DefBuffer {
 /// # DefCondRefOf
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefCondRefOf(CondRefOfOp, SuperName, Target);
 
+/// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 19.6.13 CondRefOf (Create Object Reference Conditionally)
+impl Evaluator for DefCondRefOf {
+    fn evaluate(
+        &self,
+        stack_frame: &mut interpreter::StackFrame,
+        root: &reference::Node,
+        current: &name::Path,
+    ) -> Option<interpreter::Value> {
+        let Self(_cond_ref_of_op, super_name, target) = self;
+        Some(match super_name.evaluate(stack_frame, root, current) {
+            Some(object) => {
+                target.hold(object, stack_frame, root, current);
+                interpreter::Value::Ones
+            }
+            None => interpreter::Value::Zero,
+        })
+    }
+}
+
 /// # DefConcat
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefConcat(ConcatOp, [Data; 2], Target);
 
 impl Evaluator for DefConcat {
@@ -1132,7 +1339,7 @@ impl Evaluator for DefConcat {
         let right: Option<interpreter::Value> = right.evaluate(stack_frame, root, current);
         let value: Option<interpreter::Value> = left
             .zip(right)
-            .map(|(left, right)| left.concatenate(&right));
+            .and_then(|(left, right)| left.concatenate(&right));
         value.map(|value| target.hold(value, stack_frame, root, current))
     }
 }
@@ -1140,7 +1347,7 @@ impl Evaluator for DefConcat {
 /// # DefConcatRes
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefConcatRes(ConcatResOp, [BufData; 2], Target);
 
 impl Evaluator for DefConcatRes {
@@ -1155,7 +1362,7 @@ impl Evaluator for DefConcatRes {
         let right: Option<interpreter::Value> = right.evaluate(stack_frame, root, current);
         let value: Option<interpreter::Value> = left
             .zip(right)
-            .map(|(left, right)| left.concatenate(&right));
+            .and_then(|(left, right)| left.concatenate(&right));
         value.map(|value| target.hold(value, stack_frame, root, current))
     }
 }
@@ -1163,7 +1370,7 @@ impl Evaluator for DefConcatRes {
 /// # DefContinue
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.3 Statement Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefContinue(ContinueOp);
 
 impl Evaluator for DefContinue {
@@ -1181,7 +1388,7 @@ impl Evaluator for DefContinue {
 /// # DefCopyObject
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefCopyObject(CopyObjectOp, TermArg, SimpleName);
 
 impl Evaluator for DefCopyObject {
@@ -1201,49 +1408,49 @@ impl Evaluator for DefCopyObject {
 /// # DefCreateBitField
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefCreateBitField(CreateBitFieldOp, SourceBuff, BitIndex, NameString);
 
 /// # DefCreateByteField
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefCreateByteField(CreateByteFieldOp, SourceBuff, ByteIndex, NameString);
 
 /// # DefCreateDWordField
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefCreateDWordField(CreateDWordFieldOp, SourceBuff, ByteIndex, NameString);
 
 /// # DefCreateField
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefCreateField(CreateFieldOp, SourceBuff, BitIndex, NumBits, NameString);
 
 /// # DefCreateQWordField
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefCreateQWordField(CreateQWordFieldOp, SourceBuff, ByteIndex, NameString);
 
 /// # DefCreateWordField
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefCreateWordField(CreateWordFieldOp, SourceBuff, ByteIndex, NameString);
 
 /// # DefDataRegion
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefDataRegion(DataRegionOp, NameString, [TermArg; 3]);
 
 /// # DefDecrement
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefDecrement(DecrementOp, SuperName);
 
 impl Evaluator for DefDecrement {
@@ -1256,16 +1463,15 @@ impl Evaluator for DefDecrement {
         let Self(_decrement_op, super_name) = self;
         super_name
             .evaluate(stack_frame, root, current)
-            .map(|value| {
-                super_name.hold(value - interpreter::Value::One, stack_frame, root, current)
-            })
+            .and_then(|value| value - interpreter::Value::One)
+            .map(|value| super_name.hold(value, stack_frame, root, current))
     }
 }
 
 /// # DefDerefOf
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefDerefOf(DerefOfOp, ObjReference);
 
 impl Evaluator for DefDerefOf {
@@ -1283,13 +1489,14 @@ impl Evaluator for DefDerefOf {
 /// # DefDevice
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefDevice(DeviceOp, PkgLength, NameString, #[no_leftover] TermList);
 
 /// # DefDivide
+/// Division by zero aborts the evaluation instead of panicking.
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefDivide(DivideOp, Dividend, Divisor, Remainder, Quotient);
 
 impl Evaluator for DefDivide {
@@ -1301,28 +1508,25 @@ impl Evaluator for DefDivide {
     ) -> Option<interpreter::Value> {
         let Self(_divide_op, dividend, divisor, remainder, quotient) = self;
         let dividend: Option<interpreter::Value> = dividend.evaluate(stack_frame, root, current);
-        let divisor: Option<interpreter::Value> = divisor.evaluate(stack_frame, root, current);
-        dividend.zip(divisor).map(|(dividend, divisor)| {
-            remainder.hold(
-                dividend.clone() % divisor.clone(),
-                stack_frame,
-                root,
-                current,
-            );
-            quotient.hold(
-                dividend.clone() / divisor.clone(),
-                stack_frame,
-                root,
-                current,
-            )
-        })
+        let divisor: Option<interpreter::Value> = divisor
+            .evaluate(stack_frame, root, current)
+            .filter(|divisor| divisor.into());
+        dividend
+            .zip(divisor)
+            .and_then(|(dividend, divisor)| {
+                (dividend.clone() % divisor.clone()).zip(dividend / divisor)
+            })
+            .map(|(remainder_value, quotient_value)| {
+                remainder.hold(remainder_value, stack_frame, root, current);
+                quotient.hold(quotient_value, stack_frame, root, current)
+            })
     }
 }
 
 /// # DefElse
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.3 Statement Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefElse(ElseOp, PkgLength, #[no_leftover] TermList);
 
 impl Evaluator for DefElse {
@@ -1340,13 +1544,13 @@ impl Evaluator for DefElse {
 /// # DefEvent
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefEvent(EventOp, NameString);
 
 /// # DefExternal
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[manual(first_reader, reader_inside_method)]
 pub struct DefExternal(ExternalOp, NameString, ObjectType, ArgumentCount);
 
@@ -1355,24 +1559,26 @@ impl FirstReader for DefExternal {
         aml: &'a [u8],
         root: &mut name::Node,
         current: &name::Path,
-    ) -> (Self, &'a [u8]) {
-        assert!(Self::matches(aml), "aml = {:#x?}", aml);
+    ) -> Option<(Self, &'a [u8])> {
+        if !Self::matches(aml) {
+            return None;
+        }
         let current: name::Path = current.clone();
         let symbol_aml: &[u8] = aml;
         let (external_op, symbol_aml): (ExternalOp, &[u8]) =
-            ExternalOp::first_read(symbol_aml, root, &current);
+            ExternalOp::first_read(symbol_aml, root, &current)?;
         let (name_string, symbol_aml): (NameString, &[u8]) =
-            NameString::first_read(symbol_aml, root, &current);
+            NameString::first_read(symbol_aml, root, &current)?;
         let (object_type, symbol_aml): (ObjectType, &[u8]) =
-            ObjectType::first_read(symbol_aml, root, &current);
+            ObjectType::first_read(symbol_aml, root, &current)?;
         let (argument_count, _symbol_aml): (ArgumentCount, &[u8]) =
-            ArgumentCount::first_read(symbol_aml, root, &current);
+            ArgumentCount::first_read(symbol_aml, root, &current)?;
         let current: name::Path = current.clone() + (&name_string).into();
         let number_of_arguments: usize = (&argument_count).into();
         root.add_node(&current, name::Object::external(number_of_arguments));
         let symbol = Self(external_op, name_string, object_type, argument_count);
-        let aml: &[u8] = &aml[symbol.length()..];
-        (symbol, aml)
+        let aml: &[u8] = aml.get(symbol.length()..)?;
+        Some((symbol, aml))
     }
 }
 
@@ -1381,31 +1587,33 @@ impl ReaderInsideMethod for DefExternal {
         aml: &'a [u8],
         root: &mut name::Node,
         current: &name::Path,
-    ) -> (Self, &'a [u8]) {
-        assert!(Self::matches(aml), "aml = {:#x?}", aml);
+    ) -> Option<(Self, &'a [u8])> {
+        if !Self::matches(aml) {
+            return None;
+        }
         let current: name::Path = current.clone();
         let symbol_aml: &[u8] = aml;
         let (external_op, symbol_aml): (ExternalOp, &[u8]) =
-            ExternalOp::read_inside_method(symbol_aml, root, &current);
+            ExternalOp::read_inside_method(symbol_aml, root, &current)?;
         let (name_string, symbol_aml): (NameString, &[u8]) =
-            NameString::read_inside_method(symbol_aml, root, &current);
+            NameString::read_inside_method(symbol_aml, root, &current)?;
         let (object_type, symbol_aml): (ObjectType, &[u8]) =
-            ObjectType::read_inside_method(symbol_aml, root, &current);
+            ObjectType::read_inside_method(symbol_aml, root, &current)?;
         let (argument_count, _symbol_aml): (ArgumentCount, &[u8]) =
-            ArgumentCount::read_inside_method(symbol_aml, root, &current);
+            ArgumentCount::read_inside_method(symbol_aml, root, &current)?;
         let current: name::Path = current.clone() + (&name_string).into();
         let number_of_arguments: usize = (&argument_count).into();
         root.add_node(&current, name::Object::external(number_of_arguments));
         let symbol = Self(external_op, name_string, object_type, argument_count);
-        let aml: &[u8] = &aml[symbol.length()..];
-        (symbol, aml)
+        let aml: &[u8] = aml.get(symbol.length()..)?;
+        Some((symbol, aml))
     }
 }
 
 /// # DefFatal
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.3 Statement Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefFatal(FatalOp, FatalType, FatalCode, FatalArg);
 
 impl Evaluator for DefFatal {
@@ -1417,22 +1625,23 @@ impl Evaluator for DefFatal {
     ) -> Option<interpreter::Value> {
         let Self(_fatal_op, fatal_type, fatal_code, fatal_arg) = self;
         if let Some(fatal_type) = fatal_type.evaluate(stack_frame, root, current) {
-            com2_println!("fatal_type = {:#x?}", fatal_type);
+            debug_println!("fatal_type = {:#x?}", fatal_type);
         }
         if let Some(fatal_code) = fatal_code.evaluate(stack_frame, root, current) {
-            com2_println!("fatal_code = {:#x?}", fatal_code);
+            debug_println!("fatal_code = {:#x?}", fatal_code);
         }
         if let Some(fatal_arg) = fatal_arg.evaluate(stack_frame, root, current) {
-            com2_println!("fatal_arg = {:#x?}", fatal_arg);
+            debug_println!("fatal_arg = {:#x?}", fatal_arg);
         }
-        panic!("AML interpreter panic!");
+        debug_println!("AML fatal error");
+        None
     }
 }
 
 /// # DefField
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[manual(lender)]
 pub struct DefField(
     FieldOp,
@@ -1481,7 +1690,7 @@ impl Lender for DefField {
 /// # DefFindSetLeftBit
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefFindSetLeftBit(FindSetLeftBitOp, Operand, Target);
 
 impl Evaluator for DefFindSetLeftBit {
@@ -1494,14 +1703,15 @@ impl Evaluator for DefFindSetLeftBit {
         let Self(_find_set_left_bit_op, operand, target) = self;
         operand
             .evaluate(stack_frame, root, current)
-            .map(|operand| target.hold(operand.leftest_one_bit_shift(), stack_frame, root, current))
+            .and_then(|operand| operand.leftest_one_bit_shift())
+            .map(|operand| target.hold(operand, stack_frame, root, current))
     }
 }
 
 /// # DefFindSetRightBit
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefFindSetRightBit(FindSetRightBitOp, Operand, Target);
 
 impl Evaluator for DefFindSetRightBit {
@@ -1512,16 +1722,17 @@ impl Evaluator for DefFindSetRightBit {
         current: &name::Path,
     ) -> Option<interpreter::Value> {
         let Self(_find_set_right_bit_op, operand, target) = self;
-        operand.evaluate(stack_frame, root, current).map(|operand| {
-            target.hold(operand.rightest_one_bit_shift(), stack_frame, root, current)
-        })
+        operand
+            .evaluate(stack_frame, root, current)
+            .and_then(|operand| operand.rightest_one_bit_shift())
+            .map(|operand| target.hold(operand, stack_frame, root, current))
     }
 }
 
 /// # DefFromBcd
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefFromBcd(FromBcdOp, BcdValue, Target);
 
 impl Evaluator for DefFromBcd {
@@ -1534,22 +1745,34 @@ impl Evaluator for DefFromBcd {
         let Self(_from_bcd_op, bcd_value, target) = self;
         bcd_value
             .evaluate(stack_frame, root, current)
-            .map(|bcd_value| target.hold(bcd_value.bcd2integer(), stack_frame, root, current))
+            .and_then(|bcd_value| bcd_value.bcd2integer())
+            .map(|value| target.hold(value, stack_frame, root, current))
     }
 }
 
 /// # DefIf
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.3 Statement Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefIf(IfOp, PkgLength, Predicate, #[no_leftover] TermList);
 
 /// # DefIfElse
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.3 Statement Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
+#[manual(disassembler)]
 pub struct DefIfElse(DefIf, Option<DefElse>);
 
+impl Disassembler for DefIfElse {
+    fn disassemble(&self) -> Option<disassembler::Asl> {
+        let Self(def_if, def_else) = self;
+        let def_else: Option<disassembler::Asl> = def_else
+            .as_ref()
+            .and_then(|def_else| def_else.disassemble());
+        disassembler::Asl::list(vec![def_if.disassemble(), def_else])
+    }
+}
+
 impl Evaluator for DefIfElse {
     fn evaluate(
         &self,
@@ -1574,7 +1797,7 @@ impl Evaluator for DefIfElse {
 /// # DefIncrement
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefIncrement(IncrementOp, SuperName);
 
 impl Evaluator for DefIncrement {
@@ -1587,16 +1810,15 @@ impl Evaluator for DefIncrement {
         let Self(_decrement_op, super_name) = self;
         super_name
             .evaluate(stack_frame, root, current)
-            .map(|value| {
-                super_name.hold(value + interpreter::Value::One, stack_frame, root, current)
-            })
+            .and_then(|value| value + interpreter::Value::One)
+            .map(|value| super_name.hold(value, stack_frame, root, current))
     }
 }
 
 /// # DefIndex
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefIndex(IndexOp, BuffPkgStrObj, IndexValue, Target);
 
 impl Evaluator for DefIndex {
@@ -1618,10 +1840,37 @@ impl Evaluator for DefIndex {
     }
 }
 
+impl Holder for DefIndex {
+    fn hold(
+        &self,
+        value: interpreter::Value,
+        stack_frame: &mut interpreter::StackFrame,
+        root: &reference::Node,
+        current: &name::Path,
+    ) -> interpreter::Value {
+        let Self(_index_op, buff_pkg_str_obj, index_value, _target) = self;
+        let index_value: Option<interpreter::Value> =
+            index_value.evaluate(stack_frame, root, current);
+        let replaced: Option<interpreter::Value> = buff_pkg_str_obj
+            .evaluate(stack_frame, root, current)
+            .zip(index_value)
+            .and_then(|(buff_pkg_str_obj, index_value)| {
+                buff_pkg_str_obj.replace(&index_value, value.clone())
+            });
+        match replaced {
+            Some(replaced) => {
+                buff_pkg_str_obj.hold(replaced, stack_frame, root, current);
+            }
+            None => debug_println!("Can't store to an index out of {:#x?}", current),
+        }
+        value
+    }
+}
+
 /// # DefIndexField
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefIndexField(
     IndexFieldOp,
     PkgLength,
@@ -1633,7 +1882,7 @@ pub struct DefIndexField(
 /// # DefLAnd
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefLAnd(LAndOp, [Operand; 2]);
 
 impl Evaluator for DefLAnd {
@@ -1654,7 +1903,7 @@ impl Evaluator for DefLAnd {
 /// # DefLEqual
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefLEqual(LEqualOp, [Operand; 2]);
 
 impl Evaluator for DefLEqual {
@@ -1675,7 +1924,7 @@ impl Evaluator for DefLEqual {
 /// # DefLGreater
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefLGreater(LGreaterOp, [Operand; 2]);
 
 impl Evaluator for DefLGreater {
@@ -1695,7 +1944,7 @@ impl Evaluator for DefLGreater {
 /// # DefLGreaterEqual
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefLGreaterEqual(LGreaterEqualOp, [Operand; 2]);
 
 impl Evaluator for DefLGreaterEqual {
@@ -1715,7 +1964,7 @@ impl Evaluator for DefLGreaterEqual {
 /// # DefLLess
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefLLess(LLessOp, [Operand; 2]);
 
 impl Evaluator for DefLLess {
@@ -1735,7 +1984,7 @@ impl Evaluator for DefLLess {
 /// # DefLLessEqual
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefLLessEqual(LLessEqualOp, [Operand; 2]);
 
 impl Evaluator for DefLLessEqual {
@@ -1755,7 +2004,7 @@ impl Evaluator for DefLLessEqual {
 /// # DefLNot
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefLNot(LNotOp, Operand);
 
 impl Evaluator for DefLNot {
@@ -1768,14 +2017,14 @@ impl Evaluator for DefLNot {
         let Self(_l_not_op, operand) = self;
         operand
             .evaluate(stack_frame, root, current)
-            .map(|operand| !operand)
+            .and_then(|operand| !operand)
     }
 }
 
 /// # DefLNotEqual
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefLNotEqual(LNotEqualOp, [Operand; 2]);
 
 impl Evaluator for DefLNotEqual {
@@ -1795,7 +2044,7 @@ impl Evaluator for DefLNotEqual {
 /// # DefLOr
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefLOr(LOrOp, [Operand; 2]);
 
 impl Evaluator for DefLOr {
@@ -1816,19 +2065,127 @@ impl Evaluator for DefLOr {
 /// # DefLoad
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
+#[manual(lender)]
 pub struct DefLoad(LoadOp, NameString, Target);
 
+/// The definition block is loaded under the scope of the method executing `Load`.
+/// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 19.6.76 Load (Load Definition Block)
+impl Evaluator for DefLoad {
+    fn evaluate(
+        &self,
+        stack_frame: &mut interpreter::StackFrame,
+        root: &reference::Node,
+        current: &name::Path,
+    ) -> Option<interpreter::Value> {
+        let Self(_load_op, name_string, target) = self;
+        let name: name::Path = name_string.into();
+        let object = name::AbsolutePath::new(current, &name);
+        let table: Option<Vec<u8>> = root
+            .get_op_region_from_current(&object)
+            .and_then(|(op_region_path, op_region)| {
+                op_region.read_bytes(stack_frame, root, &op_region_path)
+            })
+            .or_else(|| {
+                stack_frame
+                    .read_named_local(&name)
+                    .or_else(|| {
+                        root.get_name_from_current(&object)
+                            .and_then(|(current, name)| name.evaluate(stack_frame, root, &current))
+                    })
+                    .or_else(|| root.read_named_field(stack_frame, root, &object))
+                    .map(|table| (&table).into())
+            });
+        let mut scope: name::Path = current.clone();
+        scope.pop_last_segment();
+        let ddb_handle: interpreter::Value = table
+            .and_then(|table| definition_block::load(&table, &scope))
+            .map_or(interpreter::Value::Zero, interpreter::Value::DdbHandle);
+        Some(target.hold(ddb_handle, stack_frame, root, current))
+    }
+}
+
+/// `Load` refers to the object holding the definition block by its name and defines no object there.
+impl Lender for DefLoad {
+    fn lend<'a>(&'a self, _root: &mut reference::Node<'a>, _current: &name::Path) {}
+}
+
 /// # DefLoadTable
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefLoadTable(LoadTableOp, [TermArg; 6]);
 
+/// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 19.6.77 LoadTable (Load Definition Block From XSDT)
+impl Evaluator for DefLoadTable {
+    fn evaluate(
+        &self,
+        stack_frame: &mut interpreter::StackFrame,
+        root: &reference::Node,
+        current: &name::Path,
+    ) -> Option<interpreter::Value> {
+        let Self(
+            _load_table_op,
+            [signature, oem_id, oem_table_id, root_path, parameter_path, parameter_data],
+        ) = self;
+        let strings: Option<Vec<String>> =
+            [signature, oem_id, oem_table_id, root_path, parameter_path]
+                .into_iter()
+                .map(
+                    |term_arg| match term_arg.evaluate(stack_frame, root, current) {
+                        Some(interpreter::Value::String(string)) => Some(string),
+                        _ => None,
+                    },
+                )
+                .collect();
+        let parameter_data: Option<interpreter::Value> =
+            parameter_data.evaluate(stack_frame, root, current);
+        let ddb_handle: Option<u64> = strings.and_then(|strings| {
+            let [signature, oem_id, oem_table_id, root_path, parameter_path]: [String; 5] =
+                strings.try_into().unwrap();
+            let parent: name::Path = if root_path.is_empty() {
+                name::Path::root()
+            } else {
+                name::Path::root() + name::Path::parse(&root_path)?
+            };
+            let table: Option<Vec<u8>> =
+                platform::get().find_table(&signature, &oem_id, &oem_table_id);
+            table
+                .and_then(|table| definition_block::load(&table, &parent))
+                .inspect(|_ddb_handle| {
+                    if !parameter_path.is_empty() {
+                        let parameter: Option<name::Path> = name::Path::parse(&parameter_path);
+                        let stored: Option<interpreter::Value> = parameter
+                            .zip(parameter_data)
+                            .and_then(|(parameter, parameter_data)| {
+                                let parameter = name::AbsolutePath::new(&parent, &parameter);
+                                root.write_named_field(
+                                    parameter_data.clone(),
+                                    stack_frame,
+                                    root,
+                                    &parameter,
+                                )
+                                .or_else(|| {
+                                    root.write_name(parameter_data, stack_frame, root, &parameter)
+                                })
+                            });
+                        if stored.is_none() {
+                            debug_println!(
+                                "Can't store the parameter data to {:#x?}",
+                                parameter_path
+                            );
+                        }
+                    }
+                })
+        });
+        Some(ddb_handle.map_or(interpreter::Value::Zero, interpreter::Value::DdbHandle))
+    }
+}
+
 /// # DefMatch
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefMatch(
     MatchOp,
     SearchPkg,
@@ -1881,7 +2238,7 @@ impl Evaluator for DefMatch {
                 Some(operand1),
                 Some(start_index),
             ) => {
-                let search_pkg_size: interpreter::Value = search_pkg.size();
+                let search_pkg_size: interpreter::Value = search_pkg.size()?;
                 let search_pkg_size: usize = (&search_pkg_size).into();
                 let match_opcode0: interpreter::MatchOperator = (&match_opcode0).into();
                 let match_opcode1: interpreter::MatchOperator = (&match_opcode1).into();
@@ -1905,7 +2262,7 @@ impl Evaluator for DefMatch {
 /// # DefMethod
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[manual(first_reader, reader_inside_method)]
 pub struct DefMethod(
     MethodOp,
@@ -1920,24 +2277,28 @@ impl FirstReader for DefMethod {
         aml: &'a [u8],
         root: &mut name::Node,
         current: &name::Path,
-    ) -> (Self, &'a [u8]) {
-        assert!(Self::matches(aml), "aml = {:#x?}", aml);
+    ) -> Option<(Self, &'a [u8])> {
+        if !Self::matches(aml) {
+            return None;
+        }
         let current: name::Path = current.clone();
         let symbol_aml: &[u8] = aml;
         let (method_op, symbol_aml): (MethodOp, &[u8]) =
-            MethodOp::first_read(symbol_aml, root, &current);
+            MethodOp::first_read(symbol_aml, root, &current)?;
         let (pkg_length, symbol_aml): (PkgLength, &[u8]) =
-            PkgLength::first_read(symbol_aml, root, &current);
+            PkgLength::first_read(symbol_aml, root, &current)?;
         let (name_string, symbol_aml): (NameString, &[u8]) =
-            NameString::first_read(symbol_aml, root, &current);
+            NameString::first_read(symbol_aml, root, &current)?;
         let (method_flags, symbol_aml): (MethodFlags, &[u8]) =
-            MethodFlags::first_read(symbol_aml, root, &current);
+            MethodFlags::first_read(symbol_aml, root, &current)?;
         let current: name::Path = current.clone() + (&name_string).into();
         let number_of_arguments: usize = method_flags.arg_count() as usize;
         root.add_node(&current, name::Object::method(number_of_arguments));
         let (method_term_list, symbol_aml): (MethodTermList, &[u8]) =
-            MethodTermList::first_read(symbol_aml, root, &current);
-        assert!(symbol_aml.is_empty());
+            MethodTermList::first_read(symbol_aml, root, &current)?;
+        if !symbol_aml.is_empty() {
+            return None;
+        }
         let symbol = Self(
             method_op,
             pkg_length,
@@ -1945,8 +2306,8 @@ impl FirstReader for DefMethod {
             method_flags,
             method_term_list,
         );
-        let aml: &[u8] = &aml[symbol.length()..];
-        (symbol, aml)
+        let aml: &[u8] = aml.get(symbol.length()..)?;
+        Some((symbol, aml))
     }
 }
 
@@ -1955,24 +2316,28 @@ impl ReaderInsideMethod for DefMethod {
         aml: &'a [u8],
         root: &mut name::Node,
         current: &name::Path,
-    ) -> (Self, &'a [u8]) {
-        assert!(Self::matches(aml), "aml = {:#x?}", aml);
+    ) -> Option<(Self, &'a [u8])> {
+        if !Self::matches(aml) {
+            return None;
+        }
         let current: name::Path = current.clone();
         let symbol_aml: &[u8] = aml;
         let (method_op, symbol_aml): (MethodOp, &[u8]) =
-            MethodOp::read_inside_method(symbol_aml, root, &current);
+            MethodOp::read_inside_method(symbol_aml, root, &current)?;
         let (pkg_length, symbol_aml): (PkgLength, &[u8]) =
-            PkgLength::read_inside_method(symbol_aml, root, &current);
+            PkgLength::read_inside_method(symbol_aml, root, &current)?;
         let (name_string, symbol_aml): (NameString, &[u8]) =
-            NameString::read_inside_method(symbol_aml, root, &current);
+            NameString::read_inside_method(symbol_aml, root, &current)?;
         let (method_flags, symbol_aml): (MethodFlags, &[u8]) =
-            MethodFlags::read_inside_method(symbol_aml, root, &current);
+            MethodFlags::read_inside_method(symbol_aml, root, &current)?;
         let current: name::Path = current.clone() + (&name_string).into();
         let number_of_arguments: usize = method_flags.arg_count() as usize;
         root.add_node(&current, name::Object::method(number_of_arguments));
         let (method_term_list, symbol_aml): (MethodTermList, &[u8]) =
-            MethodTermList::read_inside_method(symbol_aml, root, &current);
-        assert!(symbol_aml.is_empty());
+            MethodTermList::read_inside_method(symbol_aml, root, &current)?;
+        if !symbol_aml.is_empty() {
+            return None;
+        }
         let symbol = Self(
             method_op,
             pkg_length,
@@ -1980,8 +2345,8 @@ impl ReaderInsideMethod for DefMethod {
             method_flags,
             method_term_list,
         );
-        let aml: &[u8] = &aml[symbol.length()..];
-        (symbol, aml)
+        let aml: &[u8] = aml.get(symbol.length()..)?;
+        Some((symbol, aml))
     }
 }
 
@@ -2000,7 +2365,7 @@ impl Evaluator for DefMethod {
 /// # DefMid
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefMid(MidOp, MidObj, [TermArg; 2], Target);
 
 impl Evaluator for DefMid {
@@ -2023,9 +2388,10 @@ impl Evaluator for DefMid {
 }
 
 /// # DefMod
+/// Division by zero aborts the evaluation instead of panicking.
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefMod(ModOp, Dividend, Divisor, Target);
 
 impl Evaluator for DefMod {
@@ -2037,17 +2403,20 @@ impl Evaluator for DefMod {
     ) -> Option<interpreter::Value> {
         let Self(_mod_op, dividend, divisor, target) = self;
         let dividend: Option<interpreter::Value> = dividend.evaluate(stack_frame, root, current);
-        let divisor: Option<interpreter::Value> = divisor.evaluate(stack_frame, root, current);
+        let divisor: Option<interpreter::Value> = divisor
+            .evaluate(stack_frame, root, current)
+            .filter(|divisor| divisor.into());
         dividend
             .zip(divisor)
-            .map(|(dividend, divisor)| target.hold(dividend % divisor, stack_frame, root, current))
+            .and_then(|(dividend, divisor)| dividend % divisor)
+            .map(|value| target.hold(value, stack_frame, root, current))
     }
 }
 
 /// # DefMultiply
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefMultiply(MultiplyOp, [Operand; 2], Target);
 
 impl Evaluator for DefMultiply {
@@ -2061,20 +2430,28 @@ impl Evaluator for DefMultiply {
         let left: Option<interpreter::Value> = left.evaluate(stack_frame, root, current);
         let right: Option<interpreter::Value> = right.evaluate(stack_frame, root, current);
         left.zip(right)
-            .map(|(left, right)| target.hold(left * right, stack_frame, root, current))
+            .and_then(|(left, right)| left * right)
+            .map(|value| target.hold(value, stack_frame, root, current))
     }
 }
 
 /// # DefMutex
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefMutex(MutexOp, NameString, SyncFlags);
 
+impl DefMutex {
+    pub fn sync_level(&self) -> u8 {
+        let Self(_mutex_op, _name_string, sync_flags) = self;
+        sync_flags.sync_level()
+    }
+}
+
 /// # DefNAnd
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefNAnd(NAndOp, [Operand; 2], Target);
 
 impl Evaluator for DefNAnd {
@@ -2088,14 +2465,15 @@ impl Evaluator for DefNAnd {
         let left: Option<interpreter::Value> = left.evaluate(stack_frame, root, current);
         let right: Option<interpreter::Value> = right.evaluate(stack_frame, root, current);
         left.zip(right)
-            .map(|(left, right)| target.hold(!(left & right), stack_frame, root, current))
+            .and_then(|(left, right)| (left & right).and_then(|value| !value))
+            .map(|value| target.hold(value, stack_frame, root, current))
     }
 }
 
 /// # DefNOr
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefNOr(NOrOp, [Operand; 2], Target);
 
 impl Evaluator for DefNOr {
@@ -2109,14 +2487,15 @@ impl Evaluator for DefNOr {
         let left: Option<interpreter::Value> = left.evaluate(stack_frame, root, current);
         let right: Option<interpreter::Value> = right.evaluate(stack_frame, root, current);
         left.zip(right)
-            .map(|(left, right)| target.hold(!(left | right), stack_frame, root, current))
+            .and_then(|(left, right)| (left | right).and_then(|value| !value))
+            .map(|value| target.hold(value, stack_frame, root, current))
     }
 }
 
 /// # DefName
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.1 Namespace Modifier Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefName(NameOp, NameString, DataRefObject);
 
 impl Evaluator for DefName {
@@ -2127,11 +2506,12 @@ impl Evaluator for DefName {
         current: &name::Path,
     ) -> Option<interpreter::Value> {
         let Self(_name_op, name_string, data_ref_object) = self;
+        let stored: Option<interpreter::Value> = root.read_name(current);
         let path: name::Path = name_string.into();
         let current: name::Path = current.clone() + path;
         let name: name::Path = name_string.into();
         let data_ref_object: Option<interpreter::Value> =
-            data_ref_object.evaluate(stack_frame, root, &current);
+            stored.or_else(|| data_ref_object.evaluate(stack_frame, root, &current));
         if let Some(data_ref_object) = data_ref_object.as_ref() {
             stack_frame.add_named_local(&name, data_ref_object.clone());
         }
@@ -2142,7 +2522,7 @@ impl Evaluator for DefName {
 /// # DefNoop
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.3 Statement Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefNoop(NoopOp);
 
 impl Evaluator for DefNoop {
@@ -2159,7 +2539,7 @@ impl Evaluator for DefNoop {
 /// # DefNot
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefNot(NotOp, Operand, Target);
 
 impl Evaluator for DefNot {
@@ -2172,23 +2552,119 @@ impl Evaluator for DefNot {
         let Self(_not_op, operand, target) = self;
         operand
             .evaluate(stack_frame, root, current)
-            .map(|operand| target.hold(!operand, stack_frame, root, current))
+            .and_then(|operand| !operand)
+            .map(|operand| target.hold(operand, stack_frame, root, current))
     }
 }
 
 /// # DefNotify
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.3 Statement Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefNotify(NotifyOp, NotifyObject, NotifyValue);
 
+/// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 19.6.94 Notify (Notify Object of Event)
+impl Evaluator for DefNotify {
+    fn evaluate(
+        &self,
+        stack_frame: &mut interpreter::StackFrame,
+        root: &reference::Node,
+        current: &name::Path,
+    ) -> Option<interpreter::Value> {
+        let Self(_notify_op, notify_object, notify_value) = self;
+        let object: Option<name::Path> = notify_object.solve(root, current);
+        let value: Option<u8> = notify_value
+            .evaluate(stack_frame, root, current)
+            .as_ref()
+            .map(|value| value.into());
+        if let Some((object, value)) = object.zip(value) {
+            notification::notify(object, value);
+        }
+        None
+    }
+}
+
 /// # DefOpRegion
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefOpRegion(OpRegionOp, NameString, RegionSpace, RegionOffset, RegionLen);
 
 impl DefOpRegion {
+    /// # Read whole bytes of a SystemMemory region
+    pub fn read_bytes(
+        &self,
+        stack_frame: &mut interpreter::StackFrame,
+        root: &reference::Node,
+        op_region_path: &name::Path,
+    ) -> Option<Vec<u8>> {
+        let Self(_op_region_op, _name_string, region_space, region_offset, region_len) = self;
+        match region_space.into() {
+            interpreter::RegionSpace::SystemMemory => {
+                let region_offset: Option<interpreter::Value> =
+                    region_offset.evaluate(stack_frame, root, op_region_path);
+                let region_len: Option<interpreter::Value> =
+                    region_len.evaluate(stack_frame, root, op_region_path);
+                region_offset
+                    .zip(region_len)
+                    .map(|(region_offset, region_len)| {
+                        let region_offset: usize = (&region_offset).into();
+                        let region_len: usize = (&region_len).into();
+                        (region_offset, region_len)
+                    })
+                    .filter(|(_region_offset, region_len)| {
+                        *region_len <= interpreter::MAX_OBJECT_SIZE
+                    })
+                    .map(|(region_offset, region_len)| {
+                        (region_offset..region_offset.saturating_add(region_len))
+                            .map(|address| {
+                                platform::get().read(
+                                    &interpreter::RegionSpace::SystemMemory,
+                                    address,
+                                    1,
+                                ) as u8
+                            })
+                            .collect()
+                    })
+            }
+            region_space => {
+                debug_println!("Can't read bytes from {:#x?}", region_space);
+                None
+            }
+        }
+    }
+
+    /// # Bytes accessed for bits of a field
+    /// Returns the first aligned byte with the first bit in it and the last aligned byte with the last bit in it.
+    /// It is `None` if the field is empty or goes beyond the region or `MAX_OBJECT_SIZE`.
+    fn aligned_bytes(
+        region_offset: usize,
+        region_len: usize,
+        offset_in_bits: usize,
+        size_in_bits: usize,
+        align_bytes: usize,
+    ) -> Option<(usize, usize, usize, usize)> {
+        const U8_BITS: usize = u8::BITS as usize;
+        let size_in_bits: usize = Some(size_in_bits).filter(|size_in_bits| {
+            (1..=interpreter::MAX_OBJECT_SIZE * U8_BITS).contains(size_in_bits)
+        })?;
+        let region_end: usize = region_offset.checked_add(region_len)?;
+        let first_byte: usize = region_offset.checked_add(offset_in_bits / U8_BITS)?;
+        let first_bit: usize = offset_in_bits % U8_BITS;
+        let last_bit: usize = first_bit + size_in_bits - 1;
+        let last_byte: usize = first_byte.checked_add(last_bit / U8_BITS)?;
+        let last_bit: usize = last_bit % U8_BITS;
+        let aligned_first_byte: usize = (first_byte / align_bytes) * align_bytes;
+        let aligned_last_byte: usize =
+            ((last_byte / align_bytes) * align_bytes).checked_add(align_bytes - 1)?;
+        (aligned_last_byte < region_end).then(|| {
+            let first_bit: usize = first_bit + (first_byte - aligned_first_byte) * U8_BITS;
+            let last_bit: usize =
+                last_bit + (last_byte + align_bytes - aligned_last_byte - 1) * U8_BITS;
+            (aligned_first_byte, first_bit, aligned_last_byte, last_bit)
+        })
+    }
+
     pub fn read_value(
         &self,
         stack_frame: &mut interpreter::StackFrame,
@@ -2212,21 +2688,25 @@ impl DefOpRegion {
         let align_bits: usize = align_bytes * U8_BITS;
         region_offset
             .zip(region_len)
-            .map(|(region_offset, region_len)| {
+            .and_then(|(region_offset, region_len)| {
                 let region_offset: usize = (&region_offset).into();
                 let region_len: usize = (&region_len).into();
-                let first_byte: usize = region_offset + offset_in_bits / U8_BITS;
-                let first_bit: usize = offset_in_bits % U8_BITS;
-                let last_bit: usize = first_bit + size_in_bits - 1;
-                let last_byte: usize = first_byte + last_bit / U8_BITS;
-                let last_bit: usize = last_bit % U8_BITS;
-                let aligned_first_byte: usize = (first_byte / align_bytes) * align_bytes;
-                let aligned_last_byte: usize =
-                    (last_byte / align_bytes) * align_bytes + align_bytes - 1;
-                assert!(aligned_last_byte < region_offset + region_len);
-                let first_bit: usize = first_bit + (first_byte - aligned_first_byte) * U8_BITS;
-                let last_bit: usize =
-                    last_bit + (last_byte + align_bytes - aligned_last_byte - 1) * U8_BITS;
+                let Some((aligned_first_byte, first_bit, aligned_last_byte, last_bit)) =
+                    Self::aligned_bytes(
+                        region_offset,
+                        region_len,
+                        offset_in_bits,
+                        size_in_bits,
+                        align_bytes,
+                    )
+                else {
+                    debug_println!(
+                        "Can't access bits {:#x?} of {:#x?}",
+                        offset_in_bits..offset_in_bits.saturating_add(size_in_bits),
+                        op_region_path
+                    );
+                    return None;
+                };
                 let bits: Vec<Vec<bool>> = (aligned_first_byte..=aligned_last_byte)
                     .step_by(align_bytes)
                     .map(|address| {
@@ -2241,97 +2721,14 @@ impl DefOpRegion {
                             } else {
                                 align_bits - 1
                             };
-                        match align_bytes {
-                            1 => {
-                                let read: u8 = match &region_space {
-                                    interpreter::RegionSpace::SystemMemory => {
-                                        let address: *const u8 = address as *const u8;
-                                        unsafe { address.read_volatile() }
-                                    }
-                                    interpreter::RegionSpace::SystemIo => {
-                                        x64::port::inb(address as u16)
-                                    }
-                                    interpreter::RegionSpace::SystemCmos => {
-                                        x64::cmos::read_u8(address as u8)
-                                    }
-                                    region_space => {
-                                        unimplemented!("reagion_space = {:#x?}", region_space)
-                                    }
-                                };
-                                (present_first_bit..=present_last_bit)
-                                    .map(|shift| (read >> shift) & 0x01 != 0)
-                                    .collect()
-                            }
-                            2 => {
-                                let read: u16 = match &region_space {
-                                    interpreter::RegionSpace::SystemMemory => {
-                                        let address: *const u16 = address as *const u16;
-                                        unsafe { address.read_volatile() }
-                                    }
-                                    interpreter::RegionSpace::SystemIo => {
-                                        x64::port::inw(address as u16)
-                                    }
-                                    interpreter::RegionSpace::SystemCmos => {
-                                        x64::cmos::read_u16(address as u8)
-                                    }
-                                    region_space => {
-                                        unimplemented!("reagion_space = {:#x?}", region_space)
-                                    }
-                                };
-                                (present_first_bit..=present_last_bit)
-                                    .map(|shift| (read >> shift) & 0x0001 != 0)
-                                    .collect()
-                            }
-                            4 => {
-                                let read: u32 = match &region_space {
-                                    interpreter::RegionSpace::SystemMemory => {
-                                        let address: *const u32 = address as *const u32;
-                                        unsafe { address.read_volatile() }
-                                    }
-                                    interpreter::RegionSpace::SystemIo => {
-                                        x64::port::inl(address as u16)
-                                    }
-                                    interpreter::RegionSpace::PciConfig => {
-                                        let bus: u8 =
-                                            ((address >> 0x30) & 0x00000000000000ff) as u8;
-                                        let device: u8 =
-                                            ((address >> 0x20) & 0x00000000000000ff) as u8;
-                                        let function: u8 =
-                                            ((address >> 0x10) & 0x00000000000000ff) as u8;
-                                        let offset: u8 = (address & 0x00000000000000ff) as u8;
-                                        pci::Address::create(bus, device, function, offset).read()
-                                    }
-                                    interpreter::RegionSpace::SystemCmos => {
-                                        x64::cmos::read_u32(address as u8)
-                                    }
-                                    region_space => {
-                                        unimplemented!("reagion_space = {:#x?}", region_space)
-                                    }
-                                };
-                                (present_first_bit..=present_last_bit)
-                                    .map(|shift| (read >> shift) & 0x00000001 != 0)
-                                    .collect()
-                            }
-                            8 => {
-                                let read: u64 = match &region_space {
-                                    interpreter::RegionSpace::SystemMemory => {
-                                        let address: *const u64 = address as *const u64;
-                                        unsafe { address.read_volatile() }
-                                    }
-                                    region_space => {
-                                        unimplemented!("reagion_space = {:#x?}", region_space)
-                                    }
-                                };
-                                (present_first_bit..=present_last_bit)
-                                    .map(|shift| (read >> shift) & 0x0000000000000001 != 0)
-                                    .collect()
-                            }
-                            _ => unreachable!(),
-                        }
+                        let read: u64 = platform::get().read(&region_space, address, align_bytes);
+                        (present_first_bit..=present_last_bit)
+                            .map(|shift| (read >> shift) & 0x0000000000000001 != 0)
+                            .collect()
                     })
                     .collect();
                 let bits: Vec<bool> = bits.into_iter().flatten().collect();
-                match bits.len() {
+                Some(match bits.len() {
                     1 => {
                         let bit: bool = bits.iter().any(|bit| *bit);
                         interpreter::Value::Bool(bit)
@@ -2367,7 +2764,7 @@ impl DefOpRegion {
                         interpreter::Value::QWord(qword)
                     }
                     length => {
-                        if length % U8_BITS == 0 {
+                        if length % U8_BITS == 0 || U64_BITS < length {
                             let bytes: Vec<u8> = bits
                                 .chunks(U8_BITS)
                                 .map(|byte| {
@@ -2388,10 +2785,14 @@ impl DefOpRegion {
                         } else if bits.iter().all(|bit| *bit) {
                             interpreter::Value::Ones
                         } else {
-                            unimplemented!()
+                            let qword: u64 = bits
+                                .iter()
+                                .rev()
+                                .fold(0, |qword, bit| (qword << 1) | *bit as u64);
+                            interpreter::Value::QWord(qword)
                         }
                     }
-                }
+                })
             })
     }
 
@@ -2405,9 +2806,6 @@ impl DefOpRegion {
         access_type: &interpreter::AccessType,
     ) -> Option<interpreter::Value> {
         const U8_BITS: usize = u8::BITS as usize;
-        const U16_BITS: usize = u16::BITS as usize;
-        const U32_BITS: usize = u32::BITS as usize;
-        const U64_BITS: usize = u64::BITS as usize;
         let Self(_op_region_op, _name_string, region_space, region_offset, region_len) = self;
         let Range { start, end } = bit_range;
         let offset_in_bits: usize = *start;
@@ -2421,21 +2819,25 @@ impl DefOpRegion {
         let align_bits: usize = align_bytes * U8_BITS;
         region_offset
             .zip(region_len)
-            .map(|(region_offset, region_len)| {
+            .and_then(|(region_offset, region_len)| {
                 let region_offset: usize = (&region_offset).into();
                 let region_len: usize = (&region_len).into();
-                let first_byte: usize = region_offset + offset_in_bits / U8_BITS;
-                let first_bit: usize = offset_in_bits % U8_BITS;
-                let last_bit: usize = first_bit + size_in_bits - 1;
-                let last_byte: usize = first_byte + last_bit / U8_BITS;
-                let last_bit: usize = last_bit % U8_BITS;
-                let aligned_first_byte: usize = (first_byte / align_bytes) * align_bytes;
-                let aligned_last_byte: usize =
-                    (last_byte / align_bytes) * align_bytes + align_bytes - 1;
-                assert!(aligned_last_byte < region_offset + region_len);
-                let first_bit: usize = first_bit + (first_byte - aligned_first_byte) * U8_BITS;
-                let last_bit: usize =
-                    last_bit + (last_byte + align_bytes - aligned_last_byte - 1) * U8_BITS;
+                let Some((aligned_first_byte, first_bit, aligned_last_byte, last_bit)) =
+                    Self::aligned_bytes(
+                        region_offset,
+                        region_len,
+                        offset_in_bits,
+                        size_in_bits,
+                        align_bytes,
+                    )
+                else {
+                    debug_println!(
+                        "Can't access bits {:#x?} of {:#x?}",
+                        offset_in_bits..offset_in_bits.saturating_add(size_in_bits),
+                        op_region_path
+                    );
+                    return None;
+                };
                 let mut bit_iterator: interpreter::BitIterator = (&value).into();
                 (aligned_first_byte..=aligned_last_byte)
                     .step_by(align_bytes)
@@ -2451,223 +2853,33 @@ impl DefOpRegion {
                             } else {
                                 align_bits - 1
                             };
-                        match align_bytes {
-                            1 => {
-                                let read: u8 = match &region_space {
-                                    interpreter::RegionSpace::SystemMemory => {
-                                        let address: *const u8 = address as *const u8;
-                                        unsafe { address.read_volatile() }
-                                    }
-                                    interpreter::RegionSpace::SystemIo => {
-                                        x64::port::inb(address as u16)
-                                    }
-                                    interpreter::RegionSpace::SystemCmos => {
-                                        x64::cmos::read_u8(address as u8)
-                                    }
-                                    region_space => {
-                                        unimplemented!("reagion_space = {:#x?}", region_space)
-                                    }
-                                };
-                                let written: Vec<bool> = (0..U8_BITS)
-                                    .map(|index| {
-                                        if (present_first_bit..=present_last_bit).contains(&index) {
-                                            bit_iterator
-                                                .next()
-                                                .unwrap_or((read >> index) & 0x01 != 0)
-                                        } else {
-                                            (read >> index) & 0x01 != 0
-                                        }
-                                    })
-                                    .collect();
-                                let written: u8 =
-                                    written.into_iter().rev().fold(0x00, |written, bit| {
-                                        (written << 1) | if bit { 0x01 } else { 0x00 }
-                                    });
-                                match &region_space {
-                                    interpreter::RegionSpace::SystemMemory => {
-                                        let address: *mut u8 = address as *mut u8;
-                                        unsafe {
-                                            address.write_volatile(written);
-                                        }
-                                    }
-                                    interpreter::RegionSpace::SystemIo => {
-                                        x64::port::outb(address as u16, written);
-                                    }
-                                    interpreter::RegionSpace::SystemCmos => {
-                                        x64::cmos::write_u8(address as u8, written);
-                                    }
-                                    region_space => {
-                                        unimplemented!("region_space = {:#x?}", region_space)
-                                    }
-                                };
-                            }
-                            2 => {
-                                let read: u16 = match &region_space {
-                                    interpreter::RegionSpace::SystemMemory => {
-                                        let address: *const u16 = address as *const u16;
-                                        unsafe { address.read_volatile() }
-                                    }
-                                    interpreter::RegionSpace::SystemIo => {
-                                        x64::port::inw(address as u16)
-                                    }
-                                    interpreter::RegionSpace::SystemCmos => {
-                                        x64::cmos::read_u16(address as u8)
-                                    }
-                                    region_space => {
-                                        unimplemented!("reagion_space = {:#x?}", region_space)
-                                    }
-                                };
-                                let written: Vec<bool> = (0..U16_BITS)
-                                    .map(|index| {
-                                        if (present_first_bit..=present_last_bit).contains(&index) {
-                                            bit_iterator
-                                                .next()
-                                                .unwrap_or((read >> index) & 0x0001 != 0)
-                                        } else {
-                                            (read >> index) & 0x0001 != 0
-                                        }
-                                    })
-                                    .collect();
-                                let written: u16 =
-                                    written.into_iter().rev().fold(0x0000, |written, bit| {
-                                        (written << 1) | if bit { 0x0001 } else { 0x0000 }
-                                    });
-                                match &region_space {
-                                    interpreter::RegionSpace::SystemMemory => {
-                                        let address: *mut u16 = address as *mut u16;
-                                        unsafe {
-                                            address.write_volatile(written);
-                                        }
-                                    }
-                                    interpreter::RegionSpace::SystemIo => {
-                                        x64::port::outw(address as u16, written);
-                                    }
-                                    interpreter::RegionSpace::SystemCmos => {
-                                        x64::cmos::write_u16(address as u8, written);
-                                    }
-                                    region_space => {
-                                        unimplemented!("region_space = {:#x?}", region_space)
-                                    }
-                                };
-                            }
-                            4 => {
-                                let read: u32 = match &region_space {
-                                    interpreter::RegionSpace::SystemMemory => {
-                                        let address: *const u32 = address as *const u32;
-                                        unsafe { address.read_volatile() }
-                                    }
-                                    interpreter::RegionSpace::SystemIo => {
-                                        x64::port::inl(address as u16)
-                                    }
-                                    interpreter::RegionSpace::PciConfig => {
-                                        let bus: u8 =
-                                            ((address >> 0x30) & 0x00000000000000ff) as u8;
-                                        let device: u8 =
-                                            ((address >> 0x20) & 0x00000000000000ff) as u8;
-                                        let function: u8 =
-                                            ((address >> 0x10) & 0x00000000000000ff) as u8;
-                                        let offset: u8 = (address & 0x00000000000000ff) as u8;
-                                        pci::Address::create(bus, device, function, offset).read()
-                                    }
-                                    interpreter::RegionSpace::SystemCmos => {
-                                        x64::cmos::read_u32(address as u8)
-                                    }
-                                    region_space => {
-                                        unimplemented!("reagion_space = {:#x?}", region_space)
-                                    }
-                                };
-                                let written: Vec<bool> = (0..U32_BITS)
-                                    .map(|index| {
-                                        if (present_first_bit..=present_last_bit).contains(&index) {
-                                            bit_iterator
-                                                .next()
-                                                .unwrap_or((read >> index) & 0x00000001 != 0)
-                                        } else {
-                                            (read >> index) & 0x00000001 != 0
-                                        }
-                                    })
-                                    .collect();
-                                let written: u32 =
-                                    written.into_iter().rev().fold(0x00000000, |written, bit| {
-                                        (written << 1) | if bit { 0x00000001 } else { 0x00000000 }
-                                    });
-                                match &region_space {
-                                    interpreter::RegionSpace::SystemMemory => {
-                                        let address: *mut u32 = address as *mut u32;
-                                        unsafe {
-                                            address.write_volatile(written);
-                                        }
-                                    }
-                                    interpreter::RegionSpace::SystemIo => {
-                                        x64::port::outl(address as u16, written);
-                                    }
-                                    interpreter::RegionSpace::PciConfig => {
-                                        let bus: u8 =
-                                            ((address >> 0x30) & 0x00000000000000ff) as u8;
-                                        let device: u8 =
-                                            ((address >> 0x20) & 0x00000000000000ff) as u8;
-                                        let function: u8 =
-                                            ((address >> 0x10) & 0x00000000000000ff) as u8;
-                                        let offset: u8 = (address & 0x00000000000000ff) as u8;
-                                        pci::Address::create(bus, device, function, offset)
-                                            .write(written);
-                                    }
-                                    interpreter::RegionSpace::SystemCmos => {
-                                        x64::cmos::write_u32(address as u8, written);
-                                    }
-                                    region_space => {
-                                        unimplemented!("region_space = {:#x?}", region_space)
-                                    }
-                                };
-                            }
-                            8 => {
-                                let read: u64 = match &region_space {
-                                    interpreter::RegionSpace::SystemMemory => {
-                                        let address: *const u64 = address as *const u64;
-                                        unsafe { address.read_volatile() }
-                                    }
-                                    region_space => {
-                                        unimplemented!("reagion_space = {:#x?}", region_space)
-                                    }
-                                };
-                                let written: Vec<bool> = (0..U64_BITS)
-                                    .map(|index| {
-                                        if (present_first_bit..=present_last_bit).contains(&index) {
-                                            bit_iterator.next().unwrap_or(
-                                                (read >> index) & 0x0000000000000001 != 0,
-                                            )
+                        let read: u64 = platform::get().read(&region_space, address, align_bytes);
+                        let written: Vec<bool> = (0..align_bits)
+                            .map(|index| {
+                                if (present_first_bit..=present_last_bit).contains(&index) {
+                                    bit_iterator
+                                        .next()
+                                        .unwrap_or((read >> index) & 0x0000000000000001 != 0)
+                                } else {
+                                    (read >> index) & 0x0000000000000001 != 0
+                                }
+                            })
+                            .collect();
+                        let written: u64 =
+                            written
+                                .into_iter()
+                                .rev()
+                                .fold(0x0000000000000000, |written, bit| {
+                                    (written << 1)
+                                        | if bit {
+                                            0x0000000000000001
                                         } else {
-                                            (read >> index) & 0x0000000000000001 != 0
+                                            0x0000000000000000
                                         }
-                                    })
-                                    .collect();
-                                let written: u64 = written.into_iter().rev().fold(
-                                    0x0000000000000000,
-                                    |written, bit| {
-                                        (written << 1)
-                                            | if bit {
-                                                0x0000000000000001
-                                            } else {
-                                                0x0000000000000000
-                                            }
-                                    },
-                                );
-                                match &region_space {
-                                    interpreter::RegionSpace::SystemMemory => {
-                                        let address: *mut u64 = address as *mut u64;
-                                        unsafe {
-                                            address.write_volatile(written);
-                                        }
-                                    }
-                                    region_space => {
-                                        unimplemented!("region_space = {:#x?}", region_space)
-                                    }
-                                };
-                            }
-                            _ => unreachable!(),
-                        }
+                                });
+                        platform::get().write(&region_space, address, align_bytes, written);
                     });
-                value
+                Some(value)
             })
     }
 }
@@ -2675,7 +2887,7 @@ impl DefOpRegion {
 /// # DefObjectType
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefObjectType(ObjectTypeOp, ObjectTypeEnum);
 
 impl Evaluator for DefObjectType {
@@ -2707,7 +2919,7 @@ impl Evaluator for DefObjectType {
 /// # DefOr
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefOr(OrOp, [Operand; 2], Target);
 
 impl Evaluator for DefOr {
@@ -2721,14 +2933,15 @@ impl Evaluator for DefOr {
         let left: Option<interpreter::Value> = left.evaluate(stack_frame, root, current);
         let right: Option<interpreter::Value> = right.evaluate(stack_frame, root, current);
         left.zip(right)
-            .map(|(left, right)| target.hold(left | right, stack_frame, root, current))
+            .and_then(|(left, right)| left | right)
+            .map(|value| target.hold(value, stack_frame, root, current))
     }
 }
 
 /// # DefPackage
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefPackage(
     PackageOp,
     PkgLength,
@@ -2743,15 +2956,28 @@ impl Evaluator for DefPackage {
         root: &reference::Node,
         current: &name::Path,
     ) -> Option<interpreter::Value> {
-        let Self(_package_op, _pkg_length, _num_elements, package_element_list) = self;
-        package_element_list.evaluate(stack_frame, root, current)
+        let Self(_package_op, _pkg_length, num_elements, package_element_list) = self;
+        let num_elements: usize = num_elements.into();
+        package_element_list
+            .evaluate(stack_frame, root, current)
+            .map(|package| match package {
+                interpreter::Value::Package(mut elements) => {
+                    // Elements without initial values are uninitialized, which reads as zero here.
+                    if elements.len() < num_elements {
+                        elements.resize(num_elements, interpreter::Value::Zero);
+                    }
+                    interpreter::Value::Package(elements)
+                }
+                package => package,
+            })
+            .and_then(interpreter::Value::limit)
     }
 }
 
 /// # DefPowerRes
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefPowerRes(
     PowerResOp,
     PkgLength,
@@ -2764,7 +2990,7 @@ pub struct DefPowerRes(
 /// # DefProcessor
 /// ## References
 /// * [Advanced Configuration and Power Interface Specification](https://uefi.org/sites/default/files/resources/ACPI_5_1release.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefProcessor(
     ProcessorOp,
     PkgLength,
@@ -2775,10 +3001,26 @@ pub struct DefProcessor(
     #[no_leftover] ObjectList,
 );
 
+impl DefProcessor {
+    pub fn proc_id(&self) -> u8 {
+        let Self(
+            _processor_op,
+            _pkg_length,
+            _name_string,
+            proc_id,
+            _pblk_addr,
+            _pblk_len,
+            _object_list,
+        ) = self;
+        let ProcId(proc_id) = proc_id;
+        proc_id.into()
+    }
+}
+
 /// # DefRefOf
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefRefOf(RefOfOp, Box<SuperName>);
 
 impl Evaluator for DefRefOf {
@@ -2796,19 +3038,51 @@ impl Evaluator for DefRefOf {
 /// # DefRelease
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.3 Statement Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefRelease(ReleaseOp, MutexObject);
 
+/// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 19.6.114 Release (Release a Mutex Synchronization Object)
+impl Evaluator for DefRelease {
+    fn evaluate(
+        &self,
+        _stack_frame: &mut interpreter::StackFrame,
+        root: &reference::Node,
+        current: &name::Path,
+    ) -> Option<interpreter::Value> {
+        let Self(_release_op, mutex_object) = self;
+        if let Some((mutex, _sync_level)) = mutex_object.solve(root, current) {
+            synchronization::release_mutex(&mutex);
+        }
+        None
+    }
+}
+
 /// # DefReset
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.3 Statement Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefReset(ResetOp, EventObject);
 
+/// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 19.6.115 Reset (Reset an Event Synchronization Object)
+impl Evaluator for DefReset {
+    fn evaluate(
+        &self,
+        _stack_frame: &mut interpreter::StackFrame,
+        root: &reference::Node,
+        current: &name::Path,
+    ) -> Option<interpreter::Value> {
+        let Self(_reset_op, event_object) = self;
+        if let Some(event) = event_object.solve(root, current) {
+            synchronization::reset_event(&event);
+        }
+        None
+    }
+}
+
 /// # DefReturn
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.3 Statement Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefReturn(ReturnOp, ArgObject);
 
 impl Evaluator for DefReturn {
@@ -2828,13 +3102,13 @@ impl Evaluator for DefReturn {
 /// # DefScope
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.1 Namespace Modifier Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefScope(ScopeOp, PkgLength, NameString, #[no_leftover] TermList);
 
 /// # DefShiftLeft
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefShiftLeft(ShiftLeftOp, Operand, ShiftCount, Target);
 
 impl Evaluator for DefShiftLeft {
@@ -2848,16 +3122,17 @@ impl Evaluator for DefShiftLeft {
         let operand: Option<interpreter::Value> = operand.evaluate(stack_frame, root, current);
         let shift_count: Option<interpreter::Value> =
             shift_count.evaluate(stack_frame, root, current);
-        operand.zip(shift_count).map(|(operand, shift_count)| {
-            target.hold(operand << shift_count, stack_frame, root, current)
-        })
+        operand
+            .zip(shift_count)
+            .and_then(|(operand, shift_count)| operand << shift_count)
+            .map(|value| target.hold(value, stack_frame, root, current))
     }
 }
 
 /// # DefShiftRight
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefShiftRight(ShiftRightOp, Operand, ShiftCount, Target);
 
 impl Evaluator for DefShiftRight {
@@ -2871,22 +3146,39 @@ impl Evaluator for DefShiftRight {
         let operand: Option<interpreter::Value> = operand.evaluate(stack_frame, root, current);
         let shift_count: Option<interpreter::Value> =
             shift_count.evaluate(stack_frame, root, current);
-        operand.zip(shift_count).map(|(operand, shift_count)| {
-            target.hold(operand >> shift_count, stack_frame, root, current)
-        })
+        operand
+            .zip(shift_count)
+            .and_then(|(operand, shift_count)| operand >> shift_count)
+            .map(|value| target.hold(value, stack_frame, root, current))
     }
 }
 
 /// # DefSignal
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.3 Statement Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefSignal(SignalOp, EventObject);
 
+/// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 19.6.123 Signal (Signal a Synchronization Event)
+impl Evaluator for DefSignal {
+    fn evaluate(
+        &self,
+        _stack_frame: &mut interpreter::StackFrame,
+        root: &reference::Node,
+        current: &name::Path,
+    ) -> Option<interpreter::Value> {
+        let Self(_signal_op, event_object) = self;
+        if let Some(event) = event_object.solve(root, current) {
+            synchronization::signal_event(&event);
+        }
+        None
+    }
+}
+
 /// # DefSizeOf
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefSizeOf(SizeOfOp, SuperName);
 
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 19.6.124 SizeOf (Get Data Object Size)
@@ -2900,14 +3192,14 @@ impl Evaluator for DefSizeOf {
         let Self(_size_of_op, super_name) = self;
         super_name
             .evaluate(stack_frame, root, current)
-            .map(|super_name| super_name.size())
+            .and_then(|super_name| super_name.size())
     }
 }
 
 /// # DefSleep
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.3 Statement Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefSleep(SleepOp, MsecTime);
 
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 19.6.125 Sleep (Milliseconds Sleep)
@@ -2924,7 +3216,7 @@ impl Evaluator for DefSleep {
             .as_ref()
             .map(|msec_time| msec_time.into())
         {
-            timer::acpi::wait_milliseconds(msec_time);
+            platform::get().sleep_milliseconds(msec_time);
         }
         None
     }
@@ -2933,7 +3225,7 @@ impl Evaluator for DefSleep {
 /// # DefStall
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.3 Statement Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefStall(StallOp, UsecTime);
 
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 19.6.127 Stall (Stall for a Short Time)
@@ -2950,7 +3242,7 @@ impl Evaluator for DefStall {
             .as_ref()
             .map(|usec_time| usec_time.into())
         {
-            timer::acpi::wait_microseconds(usec_time);
+            platform::get().stall_microseconds(usec_time);
         }
         None
     }
@@ -2959,7 +3251,7 @@ impl Evaluator for DefStall {
 /// # DefStore
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefStore(StoreOp, TermArg, SuperName);
 
 impl Evaluator for DefStore {
@@ -2979,7 +3271,7 @@ impl Evaluator for DefStore {
 /// # DefSubtract
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefSubtract(SubtractOp, [Operand; 2], Target);
 
 impl Evaluator for DefSubtract {
@@ -2993,14 +3285,15 @@ impl Evaluator for DefSubtract {
         let left: Option<interpreter::Value> = left.evaluate(stack_frame, root, current);
         let right: Option<interpreter::Value> = right.evaluate(stack_frame, root, current);
         left.zip(right)
-            .map(|(left, right)| target.hold(left - right, stack_frame, root, current))
+            .and_then(|(left, right)| left - right)
+            .map(|value| target.hold(value, stack_frame, root, current))
     }
 }
 
 /// # DefThermalZone
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefThermalZone(
     ThermalZoneOp,
     PkgLength,
@@ -3012,7 +3305,7 @@ pub struct DefThermalZone(
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 19.6.134 Timer (Get 64-Bit Timer Value)
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefTimer(TimerOp);
 
 impl Evaluator for DefTimer {
@@ -3022,7 +3315,7 @@ impl Evaluator for DefTimer {
         _root: &reference::Node,
         _current: &name::Path,
     ) -> Option<interpreter::Value> {
-        let nanoseconds: u64 = timer::acpi::nanoseconds() as u64;
+        let nanoseconds: u64 = platform::get().nanoseconds();
         Some(interpreter::Value::QWord(nanoseconds / 100))
     }
 }
@@ -3030,7 +3323,7 @@ impl Evaluator for DefTimer {
 /// # DefToBcd
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefToBcd(ToBcdOp, Operand, Target);
 
 impl Evaluator for DefToBcd {
@@ -3043,14 +3336,15 @@ impl Evaluator for DefToBcd {
         let Self(_to_bcd_op, operand, target) = self;
         operand
             .evaluate(stack_frame, root, current)
-            .map(|operand| target.hold(operand.to_bcd(), stack_frame, root, current))
+            .and_then(|operand| operand.to_bcd())
+            .map(|operand| target.hold(operand, stack_frame, root, current))
     }
 }
 
 /// # DefToBuffer
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefToBuffer(ToBufferOp, Operand, Target);
 
 impl Evaluator for DefToBuffer {
@@ -3063,14 +3357,15 @@ impl Evaluator for DefToBuffer {
         let Self(_to_buffer_op, operand, target) = self;
         operand
             .evaluate(stack_frame, root, current)
-            .map(|operand| target.hold(operand.to_buffer(), stack_frame, root, current))
+            .and_then(|operand| operand.to_buffer())
+            .map(|operand| target.hold(operand, stack_frame, root, current))
     }
 }
 
 /// # DefToDecimalString
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefToDecimalString(ToDecimalStringOp, Operand, Target);
 
 impl Evaluator for DefToDecimalString {
@@ -3083,14 +3378,15 @@ impl Evaluator for DefToDecimalString {
         let Self(_to_decimal_string_op, operand, target) = self;
         operand
             .evaluate(stack_frame, root, current)
-            .map(|operand| target.hold(operand.to_decimal_string(), stack_frame, root, current))
+            .and_then(|operand| operand.to_decimal_string())
+            .map(|operand| target.hold(operand, stack_frame, root, current))
     }
 }
 
 /// # DefToHexString
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefToHexString(ToHexStringOp, Operand, Target);
 
 impl Evaluator for DefToHexString {
@@ -3103,14 +3399,15 @@ impl Evaluator for DefToHexString {
         let Self(_to_hex_string_op, operand, target) = self;
         operand
             .evaluate(stack_frame, root, current)
-            .map(|operand| target.hold(operand.to_hex_string(), stack_frame, root, current))
+            .and_then(|operand| operand.to_hex_string())
+            .map(|operand| target.hold(operand, stack_frame, root, current))
     }
 }
 
 /// # DefToInteger
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefToInteger(ToIntegerOp, Operand, Target);
 
 impl Evaluator for DefToInteger {
@@ -3123,14 +3420,15 @@ impl Evaluator for DefToInteger {
         let Self(_to_integer_op, operand, target) = self;
         operand
             .evaluate(stack_frame, root, current)
-            .map(|operand| target.hold(operand.to_integer(), stack_frame, root, current))
+            .and_then(|operand| operand.to_integer())
+            .map(|operand| target.hold(operand, stack_frame, root, current))
     }
 }
 
 /// # DefToString
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefToString(ToStringOp, TermArg, LengthArg, Target);
 
 impl Evaluator for DefToString {
@@ -3150,10 +3448,35 @@ impl Evaluator for DefToString {
     }
 }
 
+/// # DefUnload
+/// ## References
+/// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.3 Statement Opcodes Encoding
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
+pub struct DefUnload(UnloadOp, DdbHandleObject);
+
+/// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 19.6.144 Unload (Unload Definition Block)
+impl Evaluator for DefUnload {
+    fn evaluate(
+        &self,
+        stack_frame: &mut interpreter::StackFrame,
+        root: &reference::Node,
+        current: &name::Path,
+    ) -> Option<interpreter::Value> {
+        let Self(_unload_op, ddb_handle_object) = self;
+        match ddb_handle_object.evaluate(stack_frame, root, current) {
+            Some(interpreter::Value::DdbHandle(ddb_handle)) => {
+                definition_block::unload(ddb_handle);
+            }
+            ddb_handle => debug_println!("Can't unload {:#x?}", ddb_handle),
+        }
+        None
+    }
+}
+
 /// # DefVarPackage
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefVarPackage(
     VarPackageOp,
     PkgLength,
@@ -3169,22 +3492,57 @@ impl Evaluator for DefVarPackage {
         current: &name::Path,
     ) -> Option<interpreter::Value> {
         let Self(_var_package_op, _pkg_length, _var_num_elements, package_element_list) = self;
-        package_element_list.evaluate(stack_frame, root, current)
+        package_element_list
+            .evaluate(stack_frame, root, current)
+            .and_then(interpreter::Value::limit)
     }
 }
 
 /// # DefWait
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefWait(WaitOp, EventObject, Operand);
 
+/// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 19.6.145 Wait (Wait for a Synchronization Event)
+impl Evaluator for DefWait {
+    fn evaluate(
+        &self,
+        stack_frame: &mut interpreter::StackFrame,
+        root: &reference::Node,
+        current: &name::Path,
+    ) -> Option<interpreter::Value> {
+        let Self(_wait_op, event_object, operand) = self;
+        let timeout: Option<u16> = operand
+            .evaluate(stack_frame, root, current)
+            .as_ref()
+            .map(|timeout| timeout.into());
+        event_object
+            .solve(root, current)
+            .zip(timeout)
+            .map(|(event, timeout)| synchronization::wait_event(&event, timeout))
+            .map(|timed_out| {
+                // True is Ones.
+                if timed_out {
+                    interpreter::Value::Ones
+                } else {
+                    interpreter::Value::Zero
+                }
+            })
+    }
+}
+
 /// # DefWhile
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.3 Statement Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefWhile(WhileOp, PkgLength, Predicate, #[no_leftover] TermList);
 
+impl DefWhile {
+    /// A While loop in broken firmware must not hang the kernel, so it is aborted after this many iterations like ACPICA does.
+    const MAX_ITERATIONS: usize = 0xffff;
+}
+
 impl Evaluator for DefWhile {
     fn evaluate(
         &self,
@@ -3194,15 +3552,20 @@ impl Evaluator for DefWhile {
     ) -> Option<interpreter::Value> {
         let Self(_while_op, _pkg_length, predicate, term_list) = self;
         stack_frame.enter_loop();
+        let mut iterations: usize = 0;
         while {
             let predicate: bool = predicate
                 .evaluate(stack_frame, root, current)
                 .map_or(false, |predicate| (&predicate).into());
             let broken: bool = stack_frame.is_broken();
-            predicate && !broken
+            predicate && !broken && iterations < Self::MAX_ITERATIONS
         } {
             term_list.evaluate(stack_frame, root, current);
             stack_frame.uncontinue();
+            iterations += 1;
+        }
+        if iterations == Self::MAX_ITERATIONS {
+            debug_println!("Abort a While loop in {:#x?}", current);
         }
         stack_frame.leave_loop();
         None
@@ -3212,7 +3575,7 @@ impl Evaluator for DefWhile {
 /// # DefXOr
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct DefXOr(XOrOp, [Operand; 2], Target);
 
 impl Evaluator for DefXOr {
@@ -3226,35 +3589,36 @@ impl Evaluator for DefXOr {
         let left: Option<interpreter::Value> = left.evaluate(stack_frame, root, current);
         let right: Option<interpreter::Value> = right.evaluate(stack_frame, root, current);
         left.zip(right)
-            .map(|(left, right)| target.hold(left ^ right, stack_frame, root, current))
+            .and_then(|(left, right)| left ^ right)
+            .map(|value| target.hold(value, stack_frame, root, current))
     }
 }
 
 /// # DerefOfOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x83]
 pub struct DerefOfOp;
 
 /// # DeviceOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[matching_elements = 2]
 pub struct DeviceOp(ExtOpPrefix, DeviceOpSuffix);
 
 /// # DeviceOpSuffix
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x82]
 pub struct DeviceOpSuffix;
 
 /// # DigitChar
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.2 Name Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value_min = 0x30]
 #[encoding_value_max = 0x39]
 pub struct DigitChar(char);
@@ -3262,14 +3626,14 @@ pub struct DigitChar(char);
 /// # DivideOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x78]
 pub struct DivideOp;
 
 /// # Dividend
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct Dividend(TermArg);
 
 impl Evaluator for Dividend {
@@ -3287,7 +3651,7 @@ impl Evaluator for Dividend {
 /// # Divisor
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct Divisor(TermArg);
 
 impl Evaluator for Divisor {
@@ -3305,7 +3669,7 @@ impl Evaluator for Divisor {
 /// # DualNamePath
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.2 Name Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[string]
 pub struct DualNamePath(
     #[not_string] DualNamePrefix,
@@ -3326,41 +3690,51 @@ impl From<&DualNamePath> for VecDeque<name::Segment> {
 /// # DualNamePrefix
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.2 Name Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x2e]
 pub struct DualNamePrefix;
 
 /// # ElseOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.3 Statement Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0xa1]
 pub struct ElseOp;
 
 /// # EventObject
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct EventObject(SuperName);
 
+impl EventObject {
+    pub fn solve(&self, root: &reference::Node, current: &name::Path) -> Option<name::Path> {
+        let Self(super_name) = self;
+        super_name
+            .solve(current)
+            .and_then(|event| root.get_event_from_current(&event))
+            .map(|(event, _def_event)| event)
+    }
+}
+
 /// # EventOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[matching_elements = 2]
 pub struct EventOp(ExtOpPrefix, EventOpSuffix);
 
 /// # EventOpSuffix
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x02]
 pub struct EventOpSuffix;
 
 /// # ExpressionOpcode
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub enum ExpressionOpcode {
     Acquire(DefAcquire),
     Add(DefAdd),
@@ -3426,14 +3800,14 @@ impl Evaluator for ExpressionOpcode {
         current: &name::Path,
     ) -> Option<interpreter::Value> {
         match self {
-            Self::Acquire(def_acquire) => unimplemented!("def_acquire = {:#x?}", def_acquire),
+            Self::Acquire(def_acquire) => def_acquire.evaluate(stack_frame, root, current),
             Self::Add(def_add) => def_add.evaluate(stack_frame, root, current),
             Self::And(def_and) => def_and.evaluate(stack_frame, root, current),
             Self::Buffer(def_buffer) => def_buffer.evaluate(stack_frame, root, current),
             Self::Concat(def_concat) => def_concat.evaluate(stack_frame, root, current),
             Self::ConcatRes(def_concat_res) => def_concat_res.evaluate(stack_frame, root, current),
             Self::CondRefOf(def_cond_ref_of) => {
-                unimplemented!("def_cond_ref_of = {:#x?}", def_cond_ref_of)
+                def_cond_ref_of.evaluate(stack_frame, root, current)
             }
             Self::CopyObject(def_copy_object) => {
                 def_copy_object.evaluate(stack_frame, root, current)
@@ -3465,10 +3839,8 @@ impl Evaluator for ExpressionOpcode {
                 def_l_not_equal.evaluate(stack_frame, root, current)
             }
             Self::LOr(def_l_or) => def_l_or.evaluate(stack_frame, root, current),
-            Self::Load(def_load) => unimplemented!("def_load = {:#x?}", def_load),
-            Self::LoadTable(def_load_table) => {
-                unimplemented!("def_load_table = {:#x?}", def_load_table)
-            }
+            Self::Load(def_load) => def_load.evaluate(stack_frame, root, current),
+            Self::LoadTable(def_load_table) => def_load_table.evaluate(stack_frame, root, current),
             Self::Match(def_match) => def_match.evaluate(stack_frame, root, current),
             Self::MethodInvocation(method_invocation) => {
                 method_invocation.evaluate(stack_frame, root, current)
@@ -3484,7 +3856,7 @@ impl Evaluator for ExpressionOpcode {
             }
             Self::Or(def_or) => def_or.evaluate(stack_frame, root, current),
             Self::Package(def_package) => def_package.evaluate(stack_frame, root, current),
-            Self::RefOf(def_ref_of) => unimplemented!("def_ref_of = {:#x?}", def_ref_of),
+            Self::RefOf(def_ref_of) => def_ref_of.evaluate(stack_frame, root, current),
             Self::ShiftLeft(def_shift_left) => def_shift_left.evaluate(stack_frame, root, current),
             Self::ShiftRight(def_shift_right) => {
                 def_shift_right.evaluate(stack_frame, root, current)
@@ -3506,7 +3878,7 @@ impl Evaluator for ExpressionOpcode {
             Self::VarPackage(def_var_package) => {
                 def_var_package.evaluate(stack_frame, root, current)
             }
-            Self::Wait(def_wait) => unimplemented!("def_wait = {:#x?}", def_wait),
+            Self::Wait(def_wait) => def_wait.evaluate(stack_frame, root, current),
             Self::XOr(def_x_or) => def_x_or.evaluate(stack_frame, root, current),
         }
     }
@@ -3515,14 +3887,14 @@ impl Evaluator for ExpressionOpcode {
 /// # ExtOpPrefix
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.3 Data Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x5b]
 pub struct ExtOpPrefix;
 
 /// # ExtendedAccessField
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub enum ExtendedAccessField {
     Bytes(AttribBytes),
     RawBytes(AttribRawBytes),
@@ -3532,14 +3904,14 @@ pub enum ExtendedAccessField {
 /// # ExternalOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x15]
 pub struct ExternalOp;
 
 /// # FieldElement
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub enum FieldElement {
     AccessField(AccessField),
     ConnectField(ConnectField),
@@ -3561,7 +3933,7 @@ impl FieldElement {
 /// # FataArg
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.3 Statement Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct FatalArg(TermArg);
 
 impl Evaluator for FatalArg {
@@ -3579,7 +3951,7 @@ impl Evaluator for FatalArg {
 /// # FatalCode
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.3 Statement Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct FatalCode(DWordData);
 
 impl Evaluator for FatalCode {
@@ -3597,21 +3969,21 @@ impl Evaluator for FatalCode {
 /// # FatalOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.3 Statement Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[matching_elements = 2]
 pub struct FatalOp(ExtOpPrefix, FatalOpSuffix);
 
 /// # FatalOpSuffix
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.3 Statement Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x32]
 pub struct FatalOpSuffix;
 
 /// # FatalType
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.3 Statement Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct FatalType(ByteData);
 
 impl Evaluator for FatalType {
@@ -3629,7 +4001,7 @@ impl Evaluator for FatalType {
 /// # FieldFlags
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer)]
+#[derive(acpi_machine_language_derive::Analyzer)]
 #[bitfield(u8)]
 pub struct FieldFlags {
     #[bits(4)]
@@ -3640,6 +4012,22 @@ pub struct FieldFlags {
     __: bool,
 }
 
+impl Disassembler for FieldFlags {
+    fn disassemble(&self) -> Option<disassembler::Asl> {
+        let access_type: interpreter::AccessType = self.into();
+        let lock_rule: &str = if self.lock_rule() { "Lock" } else { "NoLock" };
+        let update_rule: &str = match self.update_rule() {
+            0 => "Preserve",
+            1 => "WriteAsOnes",
+            _ => "WriteAsZeros",
+        };
+        Some(disassembler::Asl::Term(format!(
+            "{:?}Acc, {}, {}",
+            access_type, lock_rule, update_rule
+        )))
+    }
+}
+
 impl FieldFlags {
     pub fn get_access_type(&self) -> u8 {
         self.access_type()
@@ -3649,90 +4037,116 @@ impl FieldFlags {
 /// # FieldList
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
+#[manual(disassembler)]
 pub struct FieldList(Vec<FieldElement>);
 
+/// A reserved field ending at a byte boundary is an `Offset` like `iasl -d` prints.
+impl Disassembler for FieldList {
+    fn disassemble(&self) -> Option<disassembler::Asl> {
+        let Self(field_elements) = self;
+        let mut offset_in_bits: usize = 0;
+        disassembler::Asl::elements(
+            field_elements
+                .iter()
+                .map(|field_element| {
+                    offset_in_bits += field_element.bits();
+                    match field_element {
+                        FieldElement::Reserved(_) if offset_in_bits % (u8::BITS as usize) == 0 => {
+                            Some(disassembler::Asl::Term(format!(
+                                "Offset ({:#04x})",
+                                offset_in_bits / (u8::BITS as usize)
+                            )))
+                        }
+                        field_element => field_element.disassemble(),
+                    }
+                })
+                .collect(),
+        )
+    }
+}
+
 /// # FieldOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[matching_elements = 2]
 pub struct FieldOp(ExtOpPrefix, FieldOpSuffix);
 
 /// # FieldOpSuffix
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x81]
 pub struct FieldOpSuffix;
 
 /// # FindSetLeftBitOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x81]
 pub struct FindSetLeftBitOp;
 
 /// # FindSetRightBitOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x82]
 pub struct FindSetRightBitOp;
 
 /// # FromBcdOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[matching_elements = 2]
 pub struct FromBcdOp(ExtOpPrefix, FromBcdOpSuffix);
 
 /// # FromBcdOpSuffix
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x28]
 pub struct FromBcdOpSuffix;
 
 /// # IfOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.3 Statement Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0xa0]
 pub struct IfOp;
 
 /// # IncrementOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x75]
 pub struct IncrementOp;
 
 /// # IndexFieldOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[matching_elements = 2]
 pub struct IndexFieldOp(ExtOpPrefix, IndexFieldOpSuffix);
 
 /// # IndexFieldOpSuffix
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x86]
 pub struct IndexFieldOpSuffix;
 
 /// # IndexOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x88]
 pub struct IndexOp;
 
 /// # IndexValue
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct IndexValue(TermArg);
 
 impl Evaluator for IndexValue {
@@ -3750,70 +4164,70 @@ impl Evaluator for IndexValue {
 /// # LAndOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x90]
 pub struct LAndOp;
 
 /// # LEqualOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x93]
 pub struct LEqualOp;
 
 /// # LGreaterEqualOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[matching_elements = 2]
 pub struct LGreaterEqualOp(LNotOp, LLessOp);
 
 /// # LGreaterOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x94]
 pub struct LGreaterOp;
 
 /// # LLessEqualOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[matching_elements = 2]
 pub struct LLessEqualOp(LNotOp, LGreaterOp);
 
 /// # LLessOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x95]
 pub struct LLessOp;
 
 /// # LNotEqualOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[matching_elements = 2]
 pub struct LNotEqualOp(LNotOp, LEqualOp);
 
 /// # LNotOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x92]
 pub struct LNotOp;
 
 /// # LOrOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x91]
 pub struct LOrOp;
 
 /// # LengthArg
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct LengthArg(TermArg);
 
 impl Evaluator for LengthArg {
@@ -3831,35 +4245,35 @@ impl Evaluator for LengthArg {
 /// # LoadOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[matching_elements = 2]
 pub struct LoadOp(ExtOpPrefix, LoadOpSuffix);
 
 /// # LoadOpSuffix
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x20]
 pub struct LoadOpSuffix;
 
 /// # LoadTableOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[matching_elements = 2]
 pub struct LoadTableOp(ExtOpPrefix, LoadTableOpSuffix);
 
 /// # LoadTableOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x1f]
 pub struct LoadTableOpSuffix;
 
 /// # LeadNameChar
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.2 Name Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[string]
 pub enum LeadNameChar {
     AsciiUppercase(AsciiUppercase),
@@ -3869,11 +4283,19 @@ pub enum LeadNameChar {
 /// # Local Objects Encoding
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.6.2 Local Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
+#[manual(disassembler)]
 #[encoding_value_min = 0x60]
 #[encoding_value_max = 0x67]
 pub struct LocalObj(u8);
 
+impl Disassembler for LocalObj {
+    fn disassemble(&self) -> Option<disassembler::Asl> {
+        let Self(index) = self;
+        Some(disassembler::Asl::Term(format!("Local{}", index)))
+    }
+}
+
 impl Evaluator for LocalObj {
     fn evaluate(
         &self,
@@ -3902,14 +4324,14 @@ impl Holder for LocalObj {
 /// # MatchOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x89]
 pub struct MatchOp;
 
 /// # MatchOpcode
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct MatchOpcode(ByteData);
 
 impl Evaluator for MatchOpcode {
@@ -3927,7 +4349,7 @@ impl Evaluator for MatchOpcode {
 /// # MethodFlags
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer)]
+#[derive(acpi_machine_language_derive::Analyzer)]
 #[bitfield(u8)]
 pub struct MethodFlags {
     #[bits(3)]
@@ -3937,48 +4359,88 @@ pub struct MethodFlags {
     sync_level: u8,
 }
 
+impl Disassembler for MethodFlags {
+    fn disassemble(&self) -> Option<disassembler::Asl> {
+        let serialize_rule: &str = if self.serialize() {
+            "Serialized"
+        } else {
+            "NotSerialized"
+        };
+        let method_flags: String = if self.sync_level() == 0 {
+            format!("{}, {}", self.arg_count(), serialize_rule)
+        } else {
+            format!(
+                "{}, {}, {}",
+                self.arg_count(),
+                serialize_rule,
+                self.sync_level()
+            )
+        };
+        Some(disassembler::Asl::Term(method_flags))
+    }
+}
+
 /// # MethodInvocation
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5 Term Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
-#[manual(first_reader, reader, reader_inside_method)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
+#[manual(disassembler, first_reader, reader, reader_inside_method)]
 pub struct MethodInvocation(NameString, Vec<TermArg>);
 
+impl Disassembler for MethodInvocation {
+    fn disassemble(&self) -> Option<disassembler::Asl> {
+        let Self(name_string, term_args) = self;
+        let name_string: String = name_string.into();
+        let term_args: Vec<String> = term_args
+            .iter()
+            .filter_map(|term_arg| term_arg.disassemble())
+            .map(|term_arg| term_arg.lines().join("\n"))
+            .collect();
+        Some(disassembler::Asl::Term(format!(
+            "{} ({})",
+            name_string,
+            term_args.join(", ")
+        )))
+    }
+}
+
 impl FirstReader for MethodInvocation {
     fn first_read<'a>(
         aml: &'a [u8],
         root: &mut name::Node,
         current: &name::Path,
-    ) -> (Self, &'a [u8]) {
-        assert!(Self::matches(aml), "aml = {:#x?}", aml);
+    ) -> Option<(Self, &'a [u8])> {
+        if !Self::matches(aml) {
+            return None;
+        }
         let current: name::Path = current.clone();
         let symbol_aml: &[u8] = aml;
         let (name_string, symbol_aml): (NameString, &[u8]) =
-            NameString::first_read(symbol_aml, root, &current);
+            NameString::first_read(symbol_aml, root, &current)?;
         let method: name::Path = (&name_string).into();
         let method = name::AbsolutePath::new(&current, &method);
-        let number_of_arguments: usize =
-            root.find_number_of_arguments_from_current(&method).unwrap();
+        let number_of_arguments: usize = root.find_number_of_arguments_from_current(&method)?;
         let mut symbol_aml: &[u8] = symbol_aml;
         let mut term_args: Vec<TermArg> = Vec::new();
-        (0..number_of_arguments).for_each(|_| {
+        (0..number_of_arguments).try_for_each(|_| {
             let (term_arg, remaining_aml): (TermArg, &[u8]) =
-                TermArg::first_read(symbol_aml, root, &current);
+                TermArg::first_read(symbol_aml, root, &current)?;
             symbol_aml = remaining_aml;
             term_args.push(term_arg);
-        });
+            Some(())
+        })?;
         let method_invocation = Self(name_string, term_args);
-        let aml: &[u8] = &aml[method_invocation.length()..];
-        (method_invocation, aml)
+        let aml: &[u8] = aml.get(method_invocation.length()..)?;
+        Some((method_invocation, aml))
     }
 }
 
 impl Reader for MethodInvocation {
-    fn read(aml: &[u8]) -> (Self, &[u8]) {
-        let (name_string, aml): (NameString, &[u8]) = NameString::read(aml);
+    fn read(aml: &[u8]) -> Option<(Self, &[u8])> {
+        let (name_string, aml): (NameString, &[u8]) = NameString::read(aml)?;
         let term_args: Vec<TermArg> = Vec::new();
         let method_invocation = Self(name_string, term_args);
-        (method_invocation, aml)
+        Some((method_invocation, aml))
     }
 }
 
@@ -3987,12 +4449,14 @@ impl ReaderInsideMethod for MethodInvocation {
         aml: &'a [u8],
         root: &mut name::Node,
         current: &name::Path,
-    ) -> (Self, &'a [u8]) {
-        assert!(Self::matches(aml), "aml = {:#x?}", aml);
+    ) -> Option<(Self, &'a [u8])> {
+        if !Self::matches(aml) {
+            return None;
+        }
         let current: name::Path = current.clone();
         let symbol_aml: &[u8] = aml;
         let (name_string, symbol_aml): (NameString, &[u8]) =
-            NameString::read_inside_method(symbol_aml, root, &current);
+            NameString::read_inside_method(symbol_aml, root, &current)?;
         let method: name::Path = (&name_string).into();
         let method = name::AbsolutePath::new(&current, &method);
         let number_of_arguments: usize = root
@@ -4004,19 +4468,19 @@ impl ReaderInsideMethod for MethodInvocation {
                     let segment: String = (&segment).into();
                     method2number_of_arguments.get(segment.as_str()).cloned()
                 })
-            })
-            .unwrap();
+            })?;
         let mut symbol_aml: &[u8] = symbol_aml;
         let mut term_args: Vec<TermArg> = Vec::new();
-        (0..number_of_arguments).for_each(|_| {
+        (0..number_of_arguments).try_for_each(|_| {
             let (term_arg, remaining_aml): (TermArg, &[u8]) =
-                TermArg::read_inside_method(symbol_aml, root, &current);
+                TermArg::read_inside_method(symbol_aml, root, &current)?;
             symbol_aml = remaining_aml;
             term_args.push(term_arg);
-        });
+            Some(())
+        })?;
         let method_invocation = Self(name_string, term_args);
-        let aml: &[u8] = &aml[method_invocation.length()..];
-        (method_invocation, aml)
+        let aml: &[u8] = aml.get(method_invocation.length()..)?;
+        Some((method_invocation, aml))
     }
 }
 
@@ -4035,7 +4499,14 @@ impl Evaluator for MethodInvocation {
             .filter_map(|term_arg| term_arg.evaluate(stack_frame, root, current))
             .collect();
         stack_frame
-            .read_named_local(&relative_method_path)
+            .buffer_field(&relative_method_path)
+            .and_then(|buffer_field| {
+                buffer_field
+                    .source()
+                    .evaluate(stack_frame, root, current)
+                    .map(|source| buffer_field.read(&source))
+            })
+            .or_else(|| stack_frame.read_named_local(&relative_method_path))
             .or_else(|| {
                 root.get_name_from_current(&absolute_method_path)
                     .and_then(|(current, name)| name.evaluate(stack_frame, root, &current))
@@ -4055,7 +4526,7 @@ impl Evaluator for MethodInvocation {
 /// # MethodTermList
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[manual(reader_inside_method, reader_outside_method)]
 pub enum MethodTermList {
     Binary(ByteList),
@@ -4067,15 +4538,17 @@ impl ReaderInsideMethod for MethodTermList {
         aml: &'a [u8],
         root: &mut name::Node,
         current: &name::Path,
-    ) -> (Self, &'a [u8]) {
-        assert!(Self::matches(aml), "aml = {:#x?}", aml);
+    ) -> Option<(Self, &'a [u8])> {
+        if !Self::matches(aml) {
+            return None;
+        }
         let current: name::Path = current.clone();
         let symbol_aml: &[u8] = aml;
         let (term_list, _symbol_aml): (TermList, &[u8]) =
-            TermList::read_inside_method(symbol_aml, root, &current);
+            TermList::read_inside_method(symbol_aml, root, &current)?;
         let method_term_list = Self::SyntaxTree(term_list);
-        let aml: &[u8] = &aml[method_term_list.length()..];
-        (method_term_list, aml)
+        let aml: &[u8] = aml.get(method_term_list.length()..)?;
+        Some((method_term_list, aml))
     }
 }
 
@@ -4083,11 +4556,11 @@ impl ReaderOutsideMethod for MethodTermList {
     fn read_outside_method(&mut self, root: &mut name::Node, current: &name::Path) {
         let aml: Vec<u8> = match self {
             Self::Binary(byte_list) => (&*byte_list).into(),
-            Self::SyntaxTree(_) => unreachable!(),
+            Self::SyntaxTree(_) => return,
         };
-        let (term_list, aml): (TermList, &[u8]) = TermList::read_inside_method(&aml, root, current);
-        assert!(aml.is_empty());
-        *self = Self::SyntaxTree(term_list);
+        if let Some((term_list, [])) = TermList::read_inside_method(&aml, root, current) {
+            *self = Self::SyntaxTree(term_list);
+        }
     }
 }
 
@@ -4099,7 +4572,7 @@ impl Evaluator for MethodTermList {
         current: &name::Path,
     ) -> Option<interpreter::Value> {
         match self {
-            Self::Binary(_) => unreachable!(),
+            Self::Binary(_) => None,
             Self::SyntaxTree(term_list) => term_list.evaluate(stack_frame, root, current),
         }
     }
@@ -4108,14 +4581,14 @@ impl Evaluator for MethodTermList {
 /// # MethodOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x14]
 pub struct MethodOp;
 
 /// # MidObj
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct MidObj(TermArg);
 
 impl Evaluator for MidObj {
@@ -4133,21 +4606,21 @@ impl Evaluator for MidObj {
 /// # MidOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x9e]
 pub struct MidOp;
 
 /// # ModOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x85]
 pub struct ModOp;
 
 /// # MsecTime
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.3 Statement Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct MsecTime(TermArg);
 
 impl Evaluator for MsecTime {
@@ -4165,7 +4638,7 @@ impl Evaluator for MsecTime {
 /// # MultiNamePath
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.2 Name Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[manual(first_reader, reader, reader_inside_method)]
 #[string]
 pub struct MultiNamePath(
@@ -4179,26 +4652,29 @@ impl FirstReader for MultiNamePath {
         aml: &'a [u8],
         root: &mut name::Node,
         current: &name::Path,
-    ) -> (Self, &'a [u8]) {
-        assert!(Self::matches(aml), "aml = {:#x?}", aml);
+    ) -> Option<(Self, &'a [u8])> {
+        if !Self::matches(aml) {
+            return None;
+        }
         let current: name::Path = current.clone();
         let symbol_aml: &[u8] = aml;
         let (multi_name_prefix, symbol_aml): (MultiNamePrefix, &[u8]) =
-            MultiNamePrefix::first_read(symbol_aml, root, &current);
+            MultiNamePrefix::first_read(symbol_aml, root, &current)?;
         let (seg_count, symbol_aml): (SegCount, &[u8]) =
-            SegCount::first_read(symbol_aml, root, &current);
+            SegCount::first_read(symbol_aml, root, &current)?;
         let number_of_name_segs: usize = (&seg_count).into();
         let mut symbol_aml: &[u8] = symbol_aml;
         let mut name_segs: Vec<NameSeg> = Vec::new();
-        (0..number_of_name_segs).for_each(|_| {
+        (0..number_of_name_segs).try_for_each(|_| {
             let (name_seg, remaining_aml): (NameSeg, &[u8]) =
-                NameSeg::first_read(symbol_aml, root, &current);
+                NameSeg::first_read(symbol_aml, root, &current)?;
             symbol_aml = remaining_aml;
             name_segs.push(name_seg);
-        });
+            Some(())
+        })?;
         let symbol = Self(multi_name_prefix, seg_count, name_segs);
-        let aml: &[u8] = &aml[symbol.length()..];
-        (symbol, aml)
+        let aml: &[u8] = aml.get(symbol.length()..)?;
+        Some((symbol, aml))
     }
 }
 
@@ -4210,22 +4686,25 @@ impl From<&MultiNamePath> for VecDeque<name::Segment> {
 }
 
 impl Reader for MultiNamePath {
-    fn read(aml: &[u8]) -> (Self, &[u8]) {
-        assert!(Self::matches(aml), "aml = {:#x?}", aml);
+    fn read(aml: &[u8]) -> Option<(Self, &[u8])> {
+        if !Self::matches(aml) {
+            return None;
+        }
         let symbol_aml: &[u8] = aml;
         let (multi_name_prefix, symbol_aml): (MultiNamePrefix, &[u8]) =
-            MultiNamePrefix::read(symbol_aml);
-        let (seg_count, mut symbol_aml): (SegCount, &[u8]) = SegCount::read(symbol_aml);
+            MultiNamePrefix::read(symbol_aml)?;
+        let (seg_count, mut symbol_aml): (SegCount, &[u8]) = SegCount::read(symbol_aml)?;
         let number_of_name_segs: usize = (&seg_count).into();
         let mut name_segs: Vec<NameSeg> = Vec::new();
-        (0..number_of_name_segs).for_each(|_| {
-            let (name_seg, remaining_aml): (NameSeg, &[u8]) = NameSeg::read(symbol_aml);
+        (0..number_of_name_segs).try_for_each(|_| {
+            let (name_seg, remaining_aml): (NameSeg, &[u8]) = NameSeg::read(symbol_aml)?;
             symbol_aml = remaining_aml;
             name_segs.push(name_seg);
-        });
+            Some(())
+        })?;
         let symbol = Self(multi_name_prefix, seg_count, name_segs);
-        let aml: &[u8] = &aml[symbol.length()..];
-        (symbol, aml)
+        let aml: &[u8] = aml.get(symbol.length()..)?;
+        Some((symbol, aml))
     }
 }
 
@@ -4234,81 +4713,103 @@ impl ReaderInsideMethod for MultiNamePath {
         aml: &'a [u8],
         root: &mut name::Node,
         current: &name::Path,
-    ) -> (Self, &'a [u8]) {
-        assert!(Self::matches(aml), "aml = {:#x?}", aml);
+    ) -> Option<(Self, &'a [u8])> {
+        if !Self::matches(aml) {
+            return None;
+        }
         let current: name::Path = current.clone();
         let symbol_aml: &[u8] = aml;
         let (multi_name_prefix, symbol_aml): (MultiNamePrefix, &[u8]) =
-            MultiNamePrefix::read_inside_method(symbol_aml, root, &current);
+            MultiNamePrefix::read_inside_method(symbol_aml, root, &current)?;
         let (seg_count, symbol_aml): (SegCount, &[u8]) =
-            SegCount::read_inside_method(symbol_aml, root, &current);
+            SegCount::read_inside_method(symbol_aml, root, &current)?;
         let number_of_name_segs: usize = (&seg_count).into();
         let mut symbol_aml: &[u8] = symbol_aml;
         let mut name_segs: Vec<NameSeg> = Vec::new();
-        (0..number_of_name_segs).for_each(|_| {
+        (0..number_of_name_segs).try_for_each(|_| {
             let (name_seg, remaining_aml): (NameSeg, &[u8]) =
-                NameSeg::read_inside_method(symbol_aml, root, &current);
+                NameSeg::read_inside_method(symbol_aml, root, &current)?;
             symbol_aml = remaining_aml;
             name_segs.push(name_seg);
-        });
+            Some(())
+        })?;
         let symbol = Self(multi_name_prefix, seg_count, name_segs);
-        let aml: &[u8] = &aml[symbol.length()..];
-        (symbol, aml)
+        let aml: &[u8] = aml.get(symbol.length()..)?;
+        Some((symbol, aml))
     }
 }
 
 /// # MultiNamePrefix
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.2 Name Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x2f]
 pub struct MultiNamePrefix;
 
 /// # MultiplyOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x77]
 pub struct MultiplyOp;
 
 /// # MutexObject
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct MutexObject(SuperName);
 
+impl MutexObject {
+    /// Returns the path and the sync level of the mutex.
+    /// `\_GL` is predefined without any `DefMutex`.
+    pub fn solve(&self, root: &reference::Node, current: &name::Path) -> Option<(name::Path, u8)> {
+        let Self(super_name) = self;
+        super_name.solve(current).and_then(|mutex| {
+            root.get_mutex_from_current(&mutex)
+                .map(|(mutex, def_mutex)| (mutex, def_mutex.sync_level()))
+                .or_else(|| {
+                    let global_lock: name::Path = synchronization::global_lock_path();
+                    let mut mutex: name::AbsolutePath = mutex;
+                    mutex
+                        .any(|mutex| mutex == global_lock)
+                        .then_some((global_lock, 0))
+                })
+        })
+    }
+}
+
 /// # MutexOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[matching_elements = 2]
 pub struct MutexOp(ExtOpPrefix, MutexOpSuffix);
 
 /// # MutexOpSuffix
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x01]
 pub struct MutexOpSuffix;
 
 /// # NAndOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x7c]
 pub struct NAndOp;
 
 /// # NOrOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x7e]
 pub struct NOrOp;
 
 /// # NameChar
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.2 Name Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[string]
 pub enum NameChar {
     DigitChar(DigitChar),
@@ -4318,14 +4819,14 @@ pub enum NameChar {
 /// # NameOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.1 Namespace Modifier Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x08]
 pub struct NameOp;
 
 /// # NamePath
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.2 Name Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[string]
 pub enum NamePath {
     Dual(DualNamePath),
@@ -4348,14 +4849,14 @@ impl From<&NamePath> for VecDeque<name::Segment> {
 /// # NameSeg
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.2 Name Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[string]
 pub struct NameSeg(LeadNameChar, [NameChar; 3]);
 
 /// # NameSpaceModifierObj
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.1 Namespace Modifier Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub enum NameSpaceModifierObj {
     Alias(DefAlias),
     Name(DefName),
@@ -4370,9 +4871,8 @@ impl Evaluator for NameSpaceModifierObj {
         current: &name::Path,
     ) -> Option<interpreter::Value> {
         match self {
-            Self::Alias(def_alias) => unimplemented!("def_alias = {:#x?}", def_alias),
+            Self::Alias(_) | Self::Scope(_) => None,
             Self::Name(def_name) => def_name.evaluate(stack_frame, root, current),
-            Self::Scope(def_scope) => unimplemented!("def_scope = {:#x?}", def_scope),
         }
     }
 }
@@ -4380,7 +4880,7 @@ impl Evaluator for NameSpaceModifierObj {
 /// # NameString
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.2 Name Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[manual(matcher)]
 #[string]
 pub enum NameString {
@@ -4422,12 +4922,26 @@ impl Holder for NameString {
     ) -> interpreter::Value {
         let name: name::Path = self.into();
         stack_frame
-            .write_named_local(&name, value.clone())
+            .buffer_field(&name)
+            .and_then(|buffer_field| {
+                let source: interpreter::Value =
+                    buffer_field.source().evaluate(stack_frame, root, current)?;
+                let source: interpreter::Value = buffer_field.write(&source, &value);
+                buffer_field
+                    .source()
+                    .hold(source, stack_frame, root, current);
+                Some(value.clone())
+            })
+            .or_else(|| stack_frame.write_named_local(&name, value.clone()))
             .or_else(|| {
                 let named_field = name::AbsolutePath::new(current, &name);
-                root.write_named_field(value, stack_frame, root, &named_field)
+                root.write_named_field(value.clone(), stack_frame, root, &named_field)
+                    .or_else(|| root.write_name(value.clone(), stack_frame, root, &named_field))
+            })
+            .unwrap_or_else(|| {
+                debug_println!("Can't store to {:#x?} under {:#x?}", name, current);
+                value
             })
-            .unwrap()
     }
 }
 
@@ -4444,10 +4958,22 @@ impl Matcher for NameString {
 /// # NamedField
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
-#[manual(first_reader, lender, path_getter, reader_inside_method)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
+#[manual(disassembler, first_reader, lender, path_getter, reader_inside_method)]
 pub struct NamedField(NameSeg, PkgLength);
 
+impl Disassembler for NamedField {
+    fn disassemble(&self) -> Option<disassembler::Asl> {
+        let Self(name_seg, pkg_length) = self;
+        let name_seg: String = name_seg.into();
+        Some(disassembler::Asl::Term(format!(
+            "{}, {}",
+            name_seg,
+            pkg_length.pkg_length()
+        )))
+    }
+}
+
 impl NamedField {
     pub fn bits(&self) -> usize {
         let Self(_name_seg, pkg_length) = self;
@@ -4460,19 +4986,21 @@ impl FirstReader for NamedField {
         aml: &'a [u8],
         root: &mut name::Node,
         current: &name::Path,
-    ) -> (Self, &'a [u8]) {
-        assert!(Self::matches(aml), "aml = {:#x?}", aml);
+    ) -> Option<(Self, &'a [u8])> {
+        if !Self::matches(aml) {
+            return None;
+        }
         let current: name::Path = current.clone();
         let symbol_aml: &[u8] = aml;
         let (name_seg, symbol_aml): (NameSeg, &[u8]) =
-            NameSeg::first_read(symbol_aml, root, &current);
+            NameSeg::first_read(symbol_aml, root, &current)?;
         let path: name::Path = (&name_seg).into();
         let current: name::Path = current.clone() + path;
         root.add_node(&current, name::Object::NamedField);
-        let pkg_length: PkgLength = symbol_aml.into();
+        let pkg_length: PkgLength = PkgLength::new(symbol_aml)?;
         let named_field = Self(name_seg, pkg_length);
-        let aml: &[u8] = &aml[named_field.length()..];
-        (named_field, aml)
+        let aml: &[u8] = aml.get(named_field.length()..)?;
+        Some((named_field, aml))
     }
 }
 
@@ -4495,26 +5023,28 @@ impl ReaderInsideMethod for NamedField {
         aml: &'a [u8],
         root: &mut name::Node,
         current: &name::Path,
-    ) -> (Self, &'a [u8]) {
-        assert!(Self::matches(aml), "aml = {:#x?}", aml);
+    ) -> Option<(Self, &'a [u8])> {
+        if !Self::matches(aml) {
+            return None;
+        }
         let current: name::Path = current.clone();
         let symbol_aml: &[u8] = aml;
         let (name_seg, symbol_aml): (NameSeg, &[u8]) =
-            NameSeg::read_inside_method(symbol_aml, root, &current);
+            NameSeg::read_inside_method(symbol_aml, root, &current)?;
         let path: name::Path = (&name_seg).into();
         let current: name::Path = current.clone() + path;
         root.add_node(&current, name::Object::NamedField);
-        let pkg_length: PkgLength = symbol_aml.into();
+        let pkg_length: PkgLength = PkgLength::new(symbol_aml)?;
         let named_field = Self(name_seg, pkg_length);
-        let aml: &[u8] = &aml[named_field.length()..];
-        (named_field, aml)
+        let aml: &[u8] = aml.get(named_field.length()..)?;
+        Some((named_field, aml))
     }
 }
 
 /// # NamedObj
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub enum NamedObj {
     BankField(DefBankField),
     CreateBitField(DefCreateBitField),
@@ -4537,51 +5067,132 @@ pub enum NamedObj {
     ThermalZone(DefThermalZone),
 }
 
+/// Buffer fields created inside a method belong to the stack frame of the method.
 impl Evaluator for NamedObj {
     fn evaluate(
         &self,
-        _stack_frame: &mut interpreter::StackFrame,
-        _root: &reference::Node,
-        _current: &name::Path,
+        stack_frame: &mut interpreter::StackFrame,
+        root: &reference::Node,
+        current: &name::Path,
     ) -> Option<interpreter::Value> {
-        match self {
-            Self::Field(_) | Self::OpRegion(_) => None,
-            named_obj => unimplemented!("named_obj = {:#x?}", named_obj),
+        let u8_bits: usize = u8::BITS as usize;
+        let buffer_field: Option<(&SourceBuff, Option<usize>, usize, &NameString)> = match self {
+            Self::CreateBitField(DefCreateBitField(_, source_buff, bit_index, name_string)) => {
+                let bit_index: Option<usize> = bit_index
+                    .evaluate(stack_frame, root, current)
+                    .map(|bit_index| (&bit_index).into());
+                Some((source_buff, bit_index, 1, name_string))
+            }
+            Self::CreateByteField(DefCreateByteField(
+                _,
+                source_buff,
+                ByteIndex(byte_index),
+                name_string,
+            )) => {
+                let bit_index: Option<usize> = byte_index
+                    .evaluate(stack_frame, root, current)
+                    .map(|byte_index| u8_bits.saturating_mul(usize::from(&byte_index)));
+                Some((source_buff, bit_index, u8_bits, name_string))
+            }
+            Self::CreateDWordField(DefCreateDWordField(
+                _,
+                source_buff,
+                ByteIndex(byte_index),
+                name_string,
+            )) => {
+                let bit_index: Option<usize> = byte_index
+                    .evaluate(stack_frame, root, current)
+                    .map(|byte_index| u8_bits.saturating_mul(usize::from(&byte_index)));
+                Some((source_buff, bit_index, u32::BITS as usize, name_string))
+            }
+            Self::CreateField(DefCreateField(_, source_buff, bit_index, num_bits, name_string)) => {
+                let bit_index: Option<usize> = bit_index
+                    .evaluate(stack_frame, root, current)
+                    .map(|bit_index| (&bit_index).into());
+                let num_bits: usize = num_bits
+                    .evaluate(stack_frame, root, current)
+                    .map_or(0, |num_bits| (&num_bits).into());
+                Some((source_buff, bit_index, num_bits, name_string))
+            }
+            Self::CreateQWordField(DefCreateQWordField(
+                _,
+                source_buff,
+                ByteIndex(byte_index),
+                name_string,
+            )) => {
+                let bit_index: Option<usize> = byte_index
+                    .evaluate(stack_frame, root, current)
+                    .map(|byte_index| u8_bits.saturating_mul(usize::from(&byte_index)));
+                Some((source_buff, bit_index, u64::BITS as usize, name_string))
+            }
+            Self::CreateWordField(DefCreateWordField(
+                _,
+                source_buff,
+                ByteIndex(byte_index),
+                name_string,
+            )) => {
+                let bit_index: Option<usize> = byte_index
+                    .evaluate(stack_frame, root, current)
+                    .map(|byte_index| u8_bits.saturating_mul(usize::from(&byte_index)));
+                Some((source_buff, bit_index, u16::BITS as usize, name_string))
+            }
+            Self::Event(_) | Self::Field(_) | Self::Mutex(_) | Self::OpRegion(_) => None,
+            _ => None,
+        };
+        if let Some((source_buff, Some(bit_index), num_bits, name_string)) =
+            buffer_field.filter(|(_source_buff, _bit_index, num_bits, _name_string)| {
+                *num_bits <= interpreter::MAX_OBJECT_SIZE * u8_bits
+            })
+        {
+            let name: name::Path = name_string.into();
+            let buffer_field =
+                interpreter::BufferField::new(source_buff.clone(), bit_index, num_bits);
+            stack_frame.add_buffer_field(&name, buffer_field);
         }
+        None
     }
 }
 
 /// # NoopOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.3 Statement Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0xa3]
 pub struct NoopOp;
 
 /// # NotOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x80]
 pub struct NotOp;
 
 /// # NotifyObject
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.3 Statement Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct NotifyObject(SuperName);
 
+impl NotifyObject {
+    pub fn solve(&self, root: &reference::Node, current: &name::Path) -> Option<name::Path> {
+        let Self(super_name) = self;
+        super_name
+            .solve(current)
+            .and_then(|object| root.get_path_from_current(&object))
+    }
+}
+
 /// # NotifyOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.3 Statement Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x86]
 pub struct NotifyOp;
 
 /// # NotifyValue
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.3 Statement Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct NotifyValue(TermArg);
 
 impl Evaluator for NotifyValue {
@@ -4599,21 +5210,21 @@ impl Evaluator for NotifyValue {
 /// # NullChar
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.3 Data Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x00]
 pub struct NullChar(char);
 
 /// # NullName
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.2 Name Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x00]
 pub struct NullName(char);
 
 /// # NumBits
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct NumBits(TermArg);
 
 impl Evaluator for NumBits {
@@ -4630,13 +5241,20 @@ impl Evaluator for NumBits {
 /// # NumElements
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct NumElements(ByteData);
 
+impl From<&NumElements> for usize {
+    fn from(num_elements: &NumElements) -> Self {
+        let NumElements(byte_data) = num_elements;
+        byte_data.into()
+    }
+}
+
 /// # ObjReference
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct ObjReference(TermArg);
 
 impl Evaluator for ObjReference {
@@ -4654,7 +5272,7 @@ impl Evaluator for ObjReference {
 /// # Object
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5 Term Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub enum Object {
     NameSpaceModifierObj(NameSpaceModifierObj),
     NamedObj(NamedObj),
@@ -4679,19 +5297,19 @@ impl Evaluator for Object {
 /// # ObjectList
 /// ## References
 /// * [Advanced Configuration and Power Interface Specification](https://uefi.org/sites/default/files/resources/ACPI_5_1release.pdf) 20.2.5 Term Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct ObjectList(Vec<Object>);
 
 /// # ObjectType
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct ObjectType(ByteData);
 
 /// # ObjectTypeEnum
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub enum ObjectTypeEnum {
     SimpleName(SimpleName),
     DebugObj(DebugObj),
@@ -4703,17 +5321,24 @@ pub enum ObjectTypeEnum {
 /// # ObjectTypeOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x8e]
 pub struct ObjectTypeOp;
 
 /// # OneOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.3 Data Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
+#[manual(disassembler)]
 #[encoding_value = 0x01]
 pub struct OneOp;
 
+impl Disassembler for OneOp {
+    fn disassemble(&self) -> Option<disassembler::Asl> {
+        Some(disassembler::Asl::Term("One".into()))
+    }
+}
+
 impl Evaluator for OneOp {
     fn evaluate(
         &self,
@@ -4728,10 +5353,17 @@ impl Evaluator for OneOp {
 /// # OnesOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.3 Data Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
+#[manual(disassembler)]
 #[encoding_value = 0xff]
 pub struct OnesOp;
 
+impl Disassembler for OnesOp {
+    fn disassemble(&self) -> Option<disassembler::Asl> {
+        Some(disassembler::Asl::Term("Ones".into()))
+    }
+}
+
 impl Evaluator for OnesOp {
     fn evaluate(
         &self,
@@ -4746,21 +5378,21 @@ impl Evaluator for OnesOp {
 /// # OpRegionOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[matching_elements = 2]
 pub struct OpRegionOp(ExtOpPrefix, OpRegionOpSuffix);
 
 /// # OpRegionOpSuffix
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x80]
 pub struct OpRegionOpSuffix;
 
 /// # Operand
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct Operand(TermArg);
 
 impl Evaluator for Operand {
@@ -4778,14 +5410,14 @@ impl Evaluator for Operand {
 /// # OrOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x7d]
 pub struct OrOp;
 
 /// # PackageElement
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub enum PackageElement {
     NameString(NameString),
     DataRefObject(DataRefObject),
@@ -4799,7 +5431,14 @@ impl Evaluator for PackageElement {
         current: &name::Path,
     ) -> Option<interpreter::Value> {
         match self {
-            Self::NameString(name_string) => unimplemented!("name_string = {:#x?}", name_string),
+            Self::NameString(name_string) => {
+                let name: name::Path = name_string.into();
+                let object = name::AbsolutePath::new(current, &name);
+                let object: name::Path = root
+                    .get_path_from_current(&object)
+                    .unwrap_or_else(|| current.clone() + name);
+                Some(interpreter::Value::Reference(object))
+            }
             Self::DataRefObject(data_ref_object) => {
                 data_ref_object.evaluate(stack_frame, root, current)
             }
@@ -4810,9 +5449,22 @@ impl Evaluator for PackageElement {
 /// # PackageElementList
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
+#[manual(disassembler)]
 pub struct PackageElementList(Vec<PackageElement>);
 
+impl Disassembler for PackageElementList {
+    fn disassemble(&self) -> Option<disassembler::Asl> {
+        let Self(package_elements) = self;
+        disassembler::Asl::elements(
+            package_elements
+                .iter()
+                .map(|package_element| package_element.disassemble())
+                .collect(),
+        )
+    }
+}
+
 impl Evaluator for PackageElementList {
     fn evaluate(
         &self,
@@ -4833,33 +5485,33 @@ impl Evaluator for PackageElementList {
 /// # PackageOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x12]
 pub struct PackageOp;
 
 /// # ParentPrefixChar
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.2 Name Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x5e]
 pub struct ParentPrefixChar(char);
 
 /// # PblkAddr
 /// ## References
 /// * [Advanced Configuration and Power Interface Specification](https://uefi.org/sites/default/files/resources/ACPI_5_1release.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct PblkAddr(DWordData);
 
 /// # PblkLen
 /// ## References
 /// * [Advanced Configuration and Power Interface Specification](https://uefi.org/sites/default/files/resources/ACPI_5_1release.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct PblkLen(ByteData);
 
 /// # PkgLeadByte
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.4 Package Length Encoding
-#[derive(acpi_machine_language::Analyzer)]
+#[derive(acpi_machine_language_derive::Analyzer)]
 #[bitfield(u8)]
 pub struct PkgLeadByte {
     #[bits(6)]
@@ -4868,6 +5520,12 @@ pub struct PkgLeadByte {
     byte_data_count: u8,
 }
 
+impl Disassembler for PkgLeadByte {
+    fn disassemble(&self) -> Option<disassembler::Asl> {
+        None
+    }
+}
+
 impl PkgLeadByte {
     pub fn byte_data_length(&self) -> usize {
         self.byte_data_count() as usize
@@ -4881,11 +5539,36 @@ impl PkgLeadByte {
 /// # PkgLength
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.4 Package Length Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
-#[manual(debug, first_reader, reader, reader_inside_method)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
+#[manual(debug, disassembler, first_reader, reader, reader_inside_method)]
 pub struct PkgLength(PkgLeadByte, Vec<ByteData>);
 
+impl Disassembler for PkgLength {
+    fn disassemble(&self) -> Option<disassembler::Asl> {
+        None
+    }
+}
+
 impl PkgLength {
+    pub fn new(aml: &[u8]) -> Option<Self> {
+        if !Self::matches(aml) {
+            return None;
+        }
+        let symbol_aml: &[u8] = aml;
+        let (pkg_lead_byte, symbol_aml): (PkgLeadByte, &[u8]) = PkgLeadByte::read(symbol_aml)?;
+        let (_symbol_aml, byte_data): (&[u8], Vec<ByteData>) =
+            (0..pkg_lead_byte.byte_data_length()).try_fold(
+                (symbol_aml, Vec::new()),
+                |(symbol_aml, mut byte_data), _| {
+                    let (new_byte_data, symbol_aml): (ByteData, &[u8]) =
+                        ByteData::read(symbol_aml)?;
+                    byte_data.push(new_byte_data);
+                    Some((symbol_aml, byte_data))
+                },
+            )?;
+        Some(Self(pkg_lead_byte, byte_data))
+    }
+
     pub fn pkg_length(&self) -> usize {
         let Self(pkg_lead_byte, byte_data) = self;
         (byte_data.iter().rev().fold(0, |length, byte_data| {
@@ -4910,36 +5593,18 @@ impl FirstReader for PkgLength {
         aml: &'a [u8],
         _root: &mut name::Node,
         _current: &name::Path,
-    ) -> (Self, &'a [u8]) {
-        let pkg_length: Self = aml.into();
-        let aml: &[u8] = &aml[pkg_length.length()..pkg_length.pkg_length()];
-        (pkg_length, aml)
-    }
-}
-
-impl From<&[u8]> for PkgLength {
-    fn from(aml: &[u8]) -> Self {
-        assert!(Self::matches(aml), "aml = {:#x?}", aml);
-        let symbol_aml: &[u8] = aml;
-        let (pkg_lead_byte, symbol_aml): (PkgLeadByte, &[u8]) = PkgLeadByte::read(symbol_aml);
-        let (_symbol_aml, byte_data): (&[u8], Vec<ByteData>) =
-            (0..pkg_lead_byte.byte_data_length()).fold(
-                (symbol_aml, Vec::new()),
-                |(symbol_aml, mut byte_data), _| {
-                    let (new_byte_data, symbol_aml): (ByteData, &[u8]) = ByteData::read(symbol_aml);
-                    byte_data.push(new_byte_data);
-                    (symbol_aml, byte_data)
-                },
-            );
-        Self(pkg_lead_byte, byte_data)
+    ) -> Option<(Self, &'a [u8])> {
+        let pkg_length: Self = Self::new(aml)?;
+        let aml: &[u8] = aml.get(pkg_length.length()..pkg_length.pkg_length())?;
+        Some((pkg_length, aml))
     }
 }
 
 impl Reader for PkgLength {
-    fn read(aml: &[u8]) -> (Self, &[u8]) {
-        let pkg_length: Self = aml.into();
-        let aml: &[u8] = &aml[pkg_length.length()..pkg_length.pkg_length()];
-        (pkg_length, aml)
+    fn read(aml: &[u8]) -> Option<(Self, &[u8])> {
+        let pkg_length: Self = Self::new(aml)?;
+        let aml: &[u8] = aml.get(pkg_length.length()..pkg_length.pkg_length())?;
+        Some((pkg_length, aml))
     }
 }
 
@@ -4948,31 +5613,31 @@ impl ReaderInsideMethod for PkgLength {
         aml: &'a [u8],
         _root: &mut name::Node,
         _current: &name::Path,
-    ) -> (Self, &'a [u8]) {
-        let pkg_length: Self = aml.into();
-        let aml: &[u8] = &aml[pkg_length.length()..pkg_length.pkg_length()];
-        (pkg_length, aml)
+    ) -> Option<(Self, &'a [u8])> {
+        let pkg_length: Self = Self::new(aml)?;
+        let aml: &[u8] = aml.get(pkg_length.length()..pkg_length.pkg_length())?;
+        Some((pkg_length, aml))
     }
 }
 
 /// # PowerResOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[matching_elements = 2]
 pub struct PowerResOp(ExtOpPrefix, PowerResOpSuffix);
 
 /// # PowerResOpSuffix
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x84]
 pub struct PowerResOpSuffix;
 
 /// # Predicate
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.3 Statement Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct Predicate(TermArg);
 
 impl Evaluator for Predicate {
@@ -4990,7 +5655,7 @@ impl Evaluator for Predicate {
 /// # PrefixPath
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.2 Name Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[matching_elements = 0]
 #[string]
 pub struct PrefixPath(Vec<ParentPrefixChar>);
@@ -5008,27 +5673,27 @@ impl From<&PrefixPath> for VecDeque<name::Segment> {
 /// # ProcessorOp
 /// ## References
 /// * [Advanced Configuration and Power Interface Specification](https://uefi.org/sites/default/files/resources/ACPI_5_1release.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[matching_elements = 2]
 pub struct ProcessorOp(ExtOpPrefix, ProcessorOpSuffix);
 
 /// # ProcessorOpSuffix
 /// ## References
 /// * [Advanced Configuration and Power Interface Specification](https://uefi.org/sites/default/files/resources/ACPI_5_1release.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x83]
 pub struct ProcessorOpSuffix;
 
 /// # ProcId
 /// ## References
 /// * [Advanced Configuration and Power Interface Specification](https://uefi.org/sites/default/files/resources/ACPI_5_1release.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct ProcId(ByteData);
 
 /// # QWordConst
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.3 Data Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct QWordConst(QWordPrefix, QWordData);
 
 impl Evaluator for QWordConst {
@@ -5046,9 +5711,26 @@ impl Evaluator for QWordConst {
 /// # QWordData
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.3 Data Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
+#[manual(disassembler)]
 pub struct QWordData([DWordData; 2]);
 
+impl From<&QWordData> for u64 {
+    fn from(qword_data: &QWordData) -> Self {
+        let QWordData([low, high]) = qword_data;
+        let low: u32 = low.into();
+        let high: u32 = high.into();
+        (low as Self) | ((high as Self) << u32::BITS)
+    }
+}
+
+impl Disassembler for QWordData {
+    fn disassemble(&self) -> Option<disassembler::Asl> {
+        let qword: u64 = self.into();
+        Some(disassembler::Asl::Term(format!("{:#018x}", qword)))
+    }
+}
+
 impl Evaluator for QWordData {
     fn evaluate(
         &self,
@@ -5059,21 +5741,21 @@ impl Evaluator for QWordData {
         let Self([low, high]) = self;
         let low: Option<interpreter::Value> = low.evaluate(stack_frame, root, current);
         let high: Option<interpreter::Value> = high.evaluate(stack_frame, root, current);
-        low.zip(high).map(|(low, high)| low.concatenate(&high))
+        low.zip(high).and_then(|(low, high)| low.concatenate(&high))
     }
 }
 
 /// # QWordPrefix
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.3 Data Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x0e]
 pub struct QWordPrefix;
 
 /// # Quotient
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct Quotient(Target);
 
 impl Holder for Quotient {
@@ -5092,24 +5774,39 @@ impl Holder for Quotient {
 /// # ReferenceTypeOpcode
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub enum ReferenceTypeOpcode {
     DefIndex(DefIndex),
     DerefOf(DefDerefOf),
     RefOf(DefRefOf),
 }
 
+impl Evaluator for ReferenceTypeOpcode {
+    fn evaluate(
+        &self,
+        stack_frame: &mut interpreter::StackFrame,
+        root: &reference::Node,
+        current: &name::Path,
+    ) -> Option<interpreter::Value> {
+        match self {
+            Self::DefIndex(def_index) => def_index.evaluate(stack_frame, root, current),
+            Self::DerefOf(def_deref_of) => def_deref_of.evaluate(stack_frame, root, current),
+            Self::RefOf(def_ref_of) => def_ref_of.evaluate(stack_frame, root, current),
+        }
+    }
+}
+
 /// # RefOfOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x71]
 pub struct RefOfOp;
 
 /// # RegionLen
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct RegionLen(TermArg);
 
 impl Evaluator for RegionLen {
@@ -5127,7 +5824,7 @@ impl Evaluator for RegionLen {
 /// # RegionOffset
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct RegionOffset(TermArg);
 
 impl Evaluator for RegionOffset {
@@ -5145,11 +5842,34 @@ impl Evaluator for RegionOffset {
 /// # RegionSpace
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
+#[manual(disassembler)]
 #[encoding_value_min = 0x00]
 #[encoding_value_max = 0xff]
 pub struct RegionSpace(u8);
 
+impl Disassembler for RegionSpace {
+    fn disassemble(&self) -> Option<disassembler::Asl> {
+        let region_space: &str = match self.into() {
+            interpreter::RegionSpace::SystemMemory => "SystemMemory",
+            interpreter::RegionSpace::SystemIo => "SystemIO",
+            interpreter::RegionSpace::PciConfig => "PCI_Config",
+            interpreter::RegionSpace::EmbeddedControl => "EmbeddedControl",
+            interpreter::RegionSpace::SmBus => "SMBus",
+            interpreter::RegionSpace::SystemCmos => "SystemCMOS",
+            interpreter::RegionSpace::PciBarTarget => "PciBarTarget",
+            interpreter::RegionSpace::Ipmi => "IPMI",
+            interpreter::RegionSpace::GeneralPurposeIo => "GeneralPurposeIo",
+            interpreter::RegionSpace::GenericSerialBus => "GenericSerialBus",
+            interpreter::RegionSpace::Pcc => "PCC",
+            interpreter::RegionSpace::OemDefined(region_space) => {
+                return Some(disassembler::Asl::Term(format!("{:#04x}", region_space)));
+            }
+        };
+        Some(disassembler::Asl::Term(region_space.into()))
+    }
+}
+
 impl RegionSpace {
     pub fn get(&self) -> u8 {
         let Self(byte) = self;
@@ -5160,21 +5880,21 @@ impl RegionSpace {
 /// # ReleaseOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.3 Statement Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[matching_elements = 2]
 pub struct ReleaseOp(ExtOpPrefix, ReleaseOpSuffix);
 
 /// # ReleaseOpSuffix
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.3 Statement Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x27]
 pub struct ReleaseOpSuffix;
 
 /// # Remainder
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct Remainder(Target);
 
 impl Holder for Remainder {
@@ -5193,9 +5913,20 @@ impl Holder for Remainder {
 /// # ReservedField
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
+#[manual(disassembler)]
 pub struct ReservedField(ReservedFieldOp, PkgLength);
 
+impl Disassembler for ReservedField {
+    fn disassemble(&self) -> Option<disassembler::Asl> {
+        let Self(_reserved_field_op, pkg_length) = self;
+        Some(disassembler::Asl::Term(format!(
+            ", {}",
+            pkg_length.pkg_length()
+        )))
+    }
+}
+
 impl ReservedField {
     pub fn bits(&self) -> usize {
         let Self(_reserved_field_op, pkg_length) = self;
@@ -5206,44 +5937,51 @@ impl ReservedField {
 /// # ReservedFieldOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x00]
 pub struct ReservedFieldOp;
 
 /// # ResetOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.3 Statement Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[matching_elements = 2]
 pub struct ResetOp(ExtOpPrefix, ResetOpSuffix);
 
 /// # ResetOpSuffix
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.3 Statement Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x26]
 pub struct ResetOpSuffix;
 
 /// # ResourceOrder
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct ResourceOrder(WordData);
 
 /// # ReturnOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.3 Statement Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0xa4]
 pub struct ReturnOp;
 
 /// # RevisionOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.3 Data Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
+#[manual(disassembler)]
 #[matching_elements = 2]
 pub struct RevisionOp(ExtOpPrefix, RevisionOpSuffix);
 
+impl Disassembler for RevisionOp {
+    fn disassemble(&self) -> Option<disassembler::Asl> {
+        Some(disassembler::Asl::Term("Revision".into()))
+    }
+}
+
 impl Evaluator for RevisionOp {
     fn evaluate(
         &self,
@@ -5258,28 +5996,28 @@ impl Evaluator for RevisionOp {
 /// # RevisionOpSuffix
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.3 Data Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x30]
 pub struct RevisionOpSuffix;
 
 /// # RootChar
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.2 Name Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x5c]
 pub struct RootChar(char);
 
 /// # ScopeOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.1 Namespace Modifier Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x10]
 pub struct ScopeOp;
 
 /// # SearchPkg
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct SearchPkg(TermArg);
 
 impl Evaluator for SearchPkg {
@@ -5297,7 +6035,7 @@ impl Evaluator for SearchPkg {
 /// # SegCount
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.2 Name Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct SegCount(ByteData);
 
 impl From<&SegCount> for usize {
@@ -5310,7 +6048,7 @@ impl From<&SegCount> for usize {
 /// # ShiftCount
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.2 Name Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct ShiftCount(TermArg);
 
 impl Evaluator for ShiftCount {
@@ -5328,41 +6066,42 @@ impl Evaluator for ShiftCount {
 /// # ShiftLeftOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.2 Name Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x79]
 pub struct ShiftLeftOp;
 
 /// # ShiftRightOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.2 Name Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x7a]
 pub struct ShiftRightOp;
 
 /// # SignalOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.3 Statement Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[matching_elements = 2]
 pub struct SignalOp(ExtOpPrefix, SignalOpSuffix);
 
 /// # SignalOpSuffix
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.3 Statement Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x24]
 pub struct SignalOpSuffix;
 
 /// # SimpleName
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.2 Name Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub enum SimpleName {
     NameString(NameString),
     ArgObj(ArgObj),
     LocalObj(LocalObj),
 }
 
+/// A name evaluates to the value of a data object or a reference to any other object, such as a device, and to `None` if the object doesn't exist.
 impl Evaluator for SimpleName {
     fn evaluate(
         &self,
@@ -5371,7 +6110,28 @@ impl Evaluator for SimpleName {
         current: &name::Path,
     ) -> Option<interpreter::Value> {
         match self {
-            Self::NameString(name_string) => unimplemented!("name_string = {:#x?}", name_string),
+            Self::NameString(name_string) => {
+                let name: name::Path = name_string.into();
+                let object = name::AbsolutePath::new(current, &name);
+                stack_frame
+                    .buffer_field(&name)
+                    .and_then(|buffer_field| {
+                        buffer_field
+                            .source()
+                            .evaluate(stack_frame, root, current)
+                            .map(|source| buffer_field.read(&source))
+                    })
+                    .or_else(|| stack_frame.read_named_local(&name))
+                    .or_else(|| {
+                        root.get_name_from_current(&object)
+                            .and_then(|(current, name)| name.evaluate(stack_frame, root, &current))
+                    })
+                    .or_else(|| root.read_named_field(stack_frame, root, &object))
+                    .or_else(|| {
+                        root.get_path_from_current(&object)
+                            .map(interpreter::Value::Reference)
+                    })
+            }
             Self::ArgObj(arg_obj) => arg_obj.evaluate(stack_frame, root, current),
             Self::LocalObj(local_obj) => local_obj.evaluate(stack_frame, root, current),
         }
@@ -5397,28 +6157,28 @@ impl Holder for SimpleName {
 /// # SizeOfOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x87]
 pub struct SizeOfOp;
 
 /// # SleepOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.3 Statement Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[matching_elements = 2]
 pub struct SleepOp(ExtOpPrefix, SleepOpSuffix);
 
 /// # SleepOpSuffix
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.3 Statement Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x22]
 pub struct SleepOpSuffix;
 
 /// # SourceBuff
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct SourceBuff(TermArg);
 
 impl Evaluator for SourceBuff {
@@ -5433,10 +6193,23 @@ impl Evaluator for SourceBuff {
     }
 }
 
+impl Holder for SourceBuff {
+    fn hold(
+        &self,
+        value: interpreter::Value,
+        stack_frame: &mut interpreter::StackFrame,
+        root: &reference::Node,
+        current: &name::Path,
+    ) -> interpreter::Value {
+        let Self(term_arg) = self;
+        term_arg.hold(value, stack_frame, root, current)
+    }
+}
+
 /// # StatementOpcode
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.3 Statement Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub enum StatementOpcode {
     Break(DefBreak),
     BreakPoint(DefBreakPoint),
@@ -5451,6 +6224,7 @@ pub enum StatementOpcode {
     Signal(DefSignal),
     Sleep(DefSleep),
     Stall(DefStall),
+    Unload(DefUnload),
     While(DefWhile),
 }
 
@@ -5470,13 +6244,14 @@ impl Evaluator for StatementOpcode {
             Self::Fatal(def_fatal) => def_fatal.evaluate(stack_frame, root, current),
             Self::IfElse(def_if_else) => def_if_else.evaluate(stack_frame, root, current),
             Self::Noop(def_noop) => def_noop.evaluate(stack_frame, root, current),
-            Self::Notify(def_notify) => unimplemented!("def_notify = {:#x?}", def_notify),
-            Self::Release(def_release) => unimplemented!("def_release = {:#x?}", def_release),
-            Self::Reset(def_reset) => unimplemented!("def_reset = {:#x?}", def_reset),
+            Self::Notify(def_notify) => def_notify.evaluate(stack_frame, root, current),
+            Self::Release(def_release) => def_release.evaluate(stack_frame, root, current),
+            Self::Reset(def_reset) => def_reset.evaluate(stack_frame, root, current),
             Self::Return(def_return) => def_return.evaluate(stack_frame, root, current),
-            Self::Signal(def_signal) => unimplemented!("def_signal = {:#x?}", def_signal),
+            Self::Signal(def_signal) => def_signal.evaluate(stack_frame, root, current),
             Self::Sleep(def_sleep) => def_sleep.evaluate(stack_frame, root, current),
             Self::Stall(def_stall) => def_stall.evaluate(stack_frame, root, current),
+            Self::Unload(def_unload) => def_unload.evaluate(stack_frame, root, current),
             Self::While(def_while) => def_while.evaluate(stack_frame, root, current),
         }
     }
@@ -5485,21 +6260,21 @@ impl Evaluator for StatementOpcode {
 /// # StallOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.3 Statement Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[matching_elements = 2]
 pub struct StallOp(ExtOpPrefix, StallOpSuffix);
 
 /// # StallOpSuffix
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.3 Statement Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x21]
 pub struct StallOpSuffix;
 
 /// # StartIndex
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct StartIndex(TermArg);
 
 impl Evaluator for StartIndex {
@@ -5517,28 +6292,28 @@ impl Evaluator for StartIndex {
 /// # StoreOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x70]
 pub struct StoreOp;
 
 /// # StringPrefix
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.3 Data Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x0d]
 pub struct StringPrefix;
 
 /// # SubtractOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x74]
 pub struct SubtractOp;
 
 /// # SuperName
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.2 Name Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub enum SuperName {
     DebugObj(DebugObj),
     ReferenceTypeOpcode(ReferenceTypeOpcode),
@@ -5553,15 +6328,27 @@ impl Evaluator for SuperName {
         current: &name::Path,
     ) -> Option<interpreter::Value> {
         match self {
-            Self::DebugObj(debug_obj) => unreachable!("debug_obj = {:#x?}", debug_obj),
+            Self::DebugObj(_debug_obj) => None,
             Self::ReferenceTypeOpcode(reference_type_opcode) => {
-                unimplemented!("reference_type_opcode = {:#x?}", reference_type_opcode)
+                reference_type_opcode.evaluate(stack_frame, root, current)
             }
             Self::SimpleName(simple_name) => simple_name.evaluate(stack_frame, root, current),
         }
     }
 }
 
+impl SuperName {
+    pub fn solve(&self, current: &name::Path) -> Option<name::AbsolutePath> {
+        match self {
+            Self::SimpleName(SimpleName::NameString(name_string)) => {
+                let name: name::Path = name_string.into();
+                Some(name::AbsolutePath::new(current, &name))
+            }
+            _ => None,
+        }
+    }
+}
+
 impl Holder for SuperName {
     fn hold(
         &self,
@@ -5572,8 +6359,15 @@ impl Holder for SuperName {
     ) -> interpreter::Value {
         match self {
             Self::DebugObj(debug_obj) => debug_obj.hold(value, stack_frame, root, current),
-            Self::ReferenceTypeOpcode(reference_type_opcode) => {
-                unimplemented!("reference_type_opcode = {:#x?}", reference_type_opcode)
+            Self::ReferenceTypeOpcode(ReferenceTypeOpcode::DefIndex(def_index)) => {
+                def_index.hold(value, stack_frame, root, current)
+            }
+            Self::ReferenceTypeOpcode(ReferenceTypeOpcode::RefOf(DefRefOf(_, super_name))) => {
+                super_name.hold(value, stack_frame, root, current)
+            }
+            Self::ReferenceTypeOpcode(ReferenceTypeOpcode::DerefOf(def_deref_of)) => {
+                debug_println!("Can't store to {:#x?}", def_deref_of);
+                value
             }
             Self::SimpleName(simple_name) => simple_name.hold(value, stack_frame, root, current),
         }
@@ -5583,7 +6377,7 @@ impl Holder for SuperName {
 /// # SyncFlags
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer)]
+#[derive(acpi_machine_language_derive::Analyzer)]
 #[bitfield(u8)]
 pub struct SyncFlags {
     #[bits(4)]
@@ -5592,16 +6386,25 @@ pub struct SyncFlags {
     __: u8,
 }
 
+impl Disassembler for SyncFlags {
+    fn disassemble(&self) -> Option<disassembler::Asl> {
+        Some(disassembler::Asl::Term(format!(
+            "{:#04x}",
+            self.sync_level()
+        )))
+    }
+}
+
 /// # SystemLevel
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct SystemLevel(ByteData);
 
 /// # Target
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.2 Name Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub enum Target {
     NullName(NullName),
     SuperName(Box<SuperName>),
@@ -5625,7 +6428,7 @@ impl Holder for Target {
 /// # TermArg
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5 Term Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub enum TermArg {
     ExpressionOpcode(Box<ExpressionOpcode>),
     DataObject(Box<DataObject>),
@@ -5651,10 +6454,38 @@ impl Evaluator for TermArg {
     }
 }
 
+/// A term argument is stored to only if it names an object, which is an argument, a local, a named object or an element picked by `Index`.
+impl Holder for TermArg {
+    fn hold(
+        &self,
+        value: interpreter::Value,
+        stack_frame: &mut interpreter::StackFrame,
+        root: &reference::Node,
+        current: &name::Path,
+    ) -> interpreter::Value {
+        match self {
+            Self::ArgObj(arg_obj) => arg_obj.hold(value, stack_frame, root, current),
+            Self::LocalObj(local_obj) => local_obj.hold(value, stack_frame, root, current),
+            Self::ExpressionOpcode(expression_opcode) => match expression_opcode.as_ref() {
+                ExpressionOpcode::MethodInvocation(MethodInvocation(name_string, term_args))
+                    if term_args.is_empty() =>
+                {
+                    name_string.hold(value, stack_frame, root, current)
+                }
+                ExpressionOpcode::Index(def_index) => {
+                    def_index.hold(value, stack_frame, root, current)
+                }
+                _ => value,
+            },
+            _ => value,
+        }
+    }
+}
+
 /// # TermList
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5 Term Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct TermList(#[no_leftover] Vec<TermObj>);
 
 impl Evaluator for TermList {
@@ -5680,7 +6511,7 @@ impl Evaluator for TermList {
 /// # TermObj
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5 Term Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub enum TermObj {
     ExpressionOpcode(ExpressionOpcode),
     Object(Object),
@@ -5709,97 +6540,123 @@ impl Evaluator for TermObj {
 /// # ThermalZoneOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[matching_elements = 2]
 pub struct ThermalZoneOp(ExtOpPrefix, ThermalZoneOpSuffix);
 
 /// # ThermalZoneOpSuffix
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.2 Named Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x85]
 pub struct ThermalZoneOpSuffix;
 
 /// # Timeout
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct Timeout(WordData);
 
+impl Evaluator for Timeout {
+    fn evaluate(
+        &self,
+        stack_frame: &mut interpreter::StackFrame,
+        root: &reference::Node,
+        current: &name::Path,
+    ) -> Option<interpreter::Value> {
+        let Self(word_data) = self;
+        word_data.evaluate(stack_frame, root, current)
+    }
+}
+
 /// # TimerOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[matching_elements = 2]
 pub struct TimerOp(ExtOpPrefix, TimerOpSuffix);
 
 /// # TimerOpSuffix
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x33]
 pub struct TimerOpSuffix;
 
 /// # ToBcdOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[matching_elements = 2]
 pub struct ToBcdOp(ExtOpPrefix, ToBdcOpSuffix);
 
 /// # ToBcdOpSuffix
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x29]
 pub struct ToBdcOpSuffix;
 
 /// # ToBufferOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x96]
 pub struct ToBufferOp;
 
 /// # ToDecimalStringOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x97]
 pub struct ToDecimalStringOp;
 
 /// # ToHexStringOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x98]
 pub struct ToHexStringOp;
 
 /// # ToIntegerOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x99]
 pub struct ToIntegerOp;
 
 /// # ToStringOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x9c]
 pub struct ToStringOp;
 
 /// # Underscore
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.2 Name Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x5f]
 pub struct Underscore(char);
 
+/// # UnloadOp
+/// ## References
+/// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.3 Statement Opcodes Encoding
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
+#[matching_elements = 2]
+pub struct UnloadOp(ExtOpPrefix, UnloadOpSuffix);
+
+/// # UnloadOpSuffix
+/// ## References
+/// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.3 Statement Opcodes Encoding
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
+#[encoding_value = 0x2a]
+pub struct UnloadOpSuffix;
+
 /// # UsecTime
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.3 Statement Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct UsecTime(TermArg);
 
 impl Evaluator for UsecTime {
@@ -5817,14 +6674,14 @@ impl Evaluator for UsecTime {
 /// # VarPackageOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x13]
 pub struct VarPackageOp;
 
 /// # VarNumElements
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct VarNumElements(TermArg);
 
 impl Evaluator for VarNumElements {
@@ -5842,28 +6699,28 @@ impl Evaluator for VarNumElements {
 /// # WaitOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.3 Statement Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[matching_elements = 2]
 pub struct WaitOp(ExtOpPrefix, WaitOpSuffix);
 
 /// # WaitOpSuffix
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.3 Statement Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x25]
 pub struct WaitOpSuffix;
 
 /// # WhileOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.3 Statement Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0xa2]
 pub struct WhileOp;
 
 /// # WordConst
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.3 Data Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 pub struct WordConst(WordPrefix, WordData);
 
 impl Evaluator for WordConst {
@@ -5881,9 +6738,26 @@ impl Evaluator for WordConst {
 /// # WordData
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.3 Data Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
+#[manual(disassembler)]
 pub struct WordData([ByteData; 2]);
 
+impl From<&WordData> for u16 {
+    fn from(word_data: &WordData) -> Self {
+        let WordData([low, high]) = word_data;
+        let low: u8 = low.into();
+        let high: u8 = high.into();
+        (low as Self) | ((high as Self) << u8::BITS)
+    }
+}
+
+impl Disassembler for WordData {
+    fn disassemble(&self) -> Option<disassembler::Asl> {
+        let word: u16 = self.into();
+        Some(disassembler::Asl::Term(format!("{:#06x}", word)))
+    }
+}
+
 impl Evaluator for WordData {
     fn evaluate(
         &self,
@@ -5894,31 +6768,38 @@ impl Evaluator for WordData {
         let Self([low, high]) = self;
         let low: Option<interpreter::Value> = low.evaluate(stack_frame, root, current);
         let high: Option<interpreter::Value> = high.evaluate(stack_frame, root, current);
-        low.zip(high).map(|(low, high)| low.concatenate(&high))
+        low.zip(high).and_then(|(low, high)| low.concatenate(&high))
     }
 }
 
 /// # WordPrefix
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.3 Data Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x0b]
 pub struct WordPrefix;
 
 /// # XOrOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.5.4 Expression Opcodes Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
 #[encoding_value = 0x7f]
 pub struct XOrOp;
 
 /// # ZeroOp
 /// ## References
 /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20.2.3 Data Objects Encoding
-#[derive(acpi_machine_language::Analyzer, Clone)]
+#[derive(acpi_machine_language_derive::Analyzer, Clone)]
+#[manual(disassembler)]
 #[encoding_value = 0x00]
 pub struct ZeroOp;
 
+impl Disassembler for ZeroOp {
+    fn disassemble(&self) -> Option<disassembler::Asl> {
+        Some(disassembler::Asl::Term("Zero".into()))
+    }
+}
+
 impl Evaluator for ZeroOp {
     fn evaluate(
         &self,
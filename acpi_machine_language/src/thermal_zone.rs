@@ -0,0 +1,105 @@
+//! # ACPI Thermal Zones
+//! Enumerates `ThermalZone` objects in the namespace and reads their temperatures and trip points.
+//! ## References
+//! * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 11 Thermal Management
+
+use {
+    super::{name, reference},
+    alloc::vec::Vec,
+    core::fmt,
+};
+
+/// # Notification that the temperature changed
+pub const TEMPERATURE_CHANGED: u8 = 0x80;
+/// # Notification that the trip points changed
+pub const TRIP_POINTS_CHANGED: u8 = 0x81;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Zone {
+    path: name::Path,
+}
+
+impl Zone {
+    /// # Enumerate thermal zones in the namespace
+    pub fn enumerate(root: &reference::Node) -> Vec<Self> {
+        root.get_thermal_zone_paths()
+            .into_iter()
+            .map(|path| Self { path })
+            .collect()
+    }
+
+    /// # Read `_CRT` (Critical Temperature)
+    pub fn critical(&self, root: &reference::Node) -> Option<Temperature> {
+        self.temperature_of(root, "_CRT")
+    }
+
+    /// # Read `_HOT` (Hot Temperature)
+    pub fn hot(&self, root: &reference::Node) -> Option<Temperature> {
+        self.temperature_of(root, "_HOT")
+    }
+
+    pub fn path(&self) -> &name::Path {
+        &self.path
+    }
+
+    /// # Read `_TZP` (Thermal Zone Polling) in tenths of seconds
+    /// 0 means that the platform notifies temperature changes and the zone doesn't need polling.
+    pub fn polling_period(&self, root: &reference::Node) -> Option<u64> {
+        self.evaluate(root, "_TZP")
+    }
+
+    /// # Read `_TMP` (Temperature)
+    pub fn temperature(&self, root: &reference::Node) -> Option<Temperature> {
+        self.temperature_of(root, "_TMP")
+    }
+
+    fn evaluate(&self, root: &reference::Node, object: &str) -> Option<u64> {
+        root.evaluate(&(self.path.clone() + object.into()), Vec::new())
+            .and_then(|value| value.get_integer())
+    }
+
+    fn temperature_of(&self, root: &reference::Node, object: &str) -> Option<Temperature> {
+        self.evaluate(root, object).map(Temperature::from)
+    }
+}
+
+/// # Temperature in tenths of Kelvin
+/// ## References
+/// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 11.4.21 _TMP (Temperature)
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Temperature(u64);
+
+impl Temperature {
+    const ZERO_CELSIUS: i64 = 2732;
+
+    /// # Temperature in tenths of degrees Celsius
+    pub fn deci_celsius(self) -> i64 {
+        self.0 as i64 - Self::ZERO_CELSIUS
+    }
+
+    /// # Temperature in tenths of Kelvin
+    pub fn deci_kelvin(self) -> u64 {
+        self.0
+    }
+}
+
+impl fmt::Display for Temperature {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let deci_celsius: i64 = self.deci_celsius();
+        let sign: &str = if deci_celsius < 0 { "-" } else { "" };
+        let deci_celsius: u64 = deci_celsius.unsigned_abs();
+        write!(
+            formatter,
+            "{}{}.{} C",
+            sign,
+            deci_celsius / 10,
+            deci_celsius % 10
+        )
+    }
+}
+
+impl From<u64> for Temperature {
+    fn from(deci_kelvin: u64) -> Self {
+        Self(deci_kelvin)
+    }
+}
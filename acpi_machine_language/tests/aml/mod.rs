@@ -0,0 +1,474 @@
+//! # AML builder
+//! Encodes ASL terms into AML in the same way as `hw/acpi/aml-build.c` of QEMU, so that tests can describe tables in a form close to their disassembly.
+//! ## References
+//! * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20 ACPI MACHINE LANGUAGE (AML) SPECIFICATION
+
+#![allow(dead_code)]
+
+use acpi_machine_language::{
+    name,
+    syntax::{self, FirstReader, ReaderOutsideMethod},
+};
+
+const EXT_OP_PREFIX: u8 = 0x5b;
+
+/// # The size of the header preceding the definition block of a table
+pub const HEADER_SIZE: usize = 36;
+
+/// # Acquire (Mutex, Timeout)
+pub fn acquire(mutex: &str, timeout: u16) -> Vec<u8> {
+    let mut aml: Vec<u8> = vec![EXT_OP_PREFIX, 0x23];
+    aml.extend(name_string(mutex));
+    aml.extend(timeout.to_le_bytes());
+    aml
+}
+
+/// # Add (Addend1, Addend2, Result)
+pub fn add(addend1: Vec<u8>, addend2: Vec<u8>, result: Vec<u8>) -> Vec<u8> {
+    binary(0x72, addend1, addend2, result)
+}
+
+/// # Alias (SourceObject, AliasObject)
+pub fn alias(source: &str, alias: &str) -> Vec<u8> {
+    let mut aml: Vec<u8> = vec![0x06];
+    aml.extend(name_string(source));
+    aml.extend(name_string(alias));
+    aml
+}
+
+/// # And (Source1, Source2, Result)
+pub fn and(source1: Vec<u8>, source2: Vec<u8>, result: Vec<u8>) -> Vec<u8> {
+    binary(0x7b, source1, source2, result)
+}
+
+/// # ArgN
+pub fn arg(index: u8) -> Vec<u8> {
+    assert!(index < 7);
+    vec![0x68 + index]
+}
+
+/// # Buffer (BufferSize) {ByteList}
+pub fn buffer(bytes: &[u8]) -> Vec<u8> {
+    let mut body: Vec<u8> = integer(bytes.len() as u64);
+    body.extend(bytes);
+    with_pkg_length(&[0x11], body)
+}
+
+/// # DefinitionBlock
+/// Returns the whole table including its header with a correct checksum.
+pub fn definition_block(
+    signature: &str,
+    revision: u8,
+    oem_id: &str,
+    oem_table_id: &str,
+    oem_revision: u32,
+    terms: Vec<Vec<u8>>,
+) -> Vec<u8> {
+    let terms: Vec<u8> = terms.concat();
+    let length: u32 = (HEADER_SIZE + terms.len()) as u32;
+    let mut table: Vec<u8> = Vec::new();
+    table.extend(padded(signature, 4, b' '));
+    table.extend(length.to_le_bytes());
+    table.push(revision);
+    table.push(0);
+    table.extend(padded(oem_id, 6, b' '));
+    table.extend(padded(oem_table_id, 8, b' '));
+    table.extend(oem_revision.to_le_bytes());
+    table.extend(padded("BXPC", 4, b' '));
+    table.extend(1u32.to_le_bytes());
+    table.extend(terms);
+    let sum: u8 = table
+        .iter()
+        .fold(0x00u8, |sum, byte| sum.wrapping_add(*byte));
+    table[9] = sum.wrapping_neg();
+    table
+}
+
+/// # Device (DeviceName) {TermList}
+pub fn device(name: &str, terms: Vec<Vec<u8>>) -> Vec<u8> {
+    let mut body: Vec<u8> = name_string(name);
+    body.extend(terms.concat());
+    with_pkg_length(&[EXT_OP_PREFIX, 0x82], body)
+}
+
+/// # EisaId (EisaIdString)
+pub fn eisa_id(id: &str) -> Vec<u8> {
+    let id: &[u8] = id.as_bytes();
+    assert_eq!(id.len(), 7);
+    let compressed: u32 = id[..3].iter().fold(0, |compressed, character| {
+        (compressed << 5) | ((*character - 0x40) as u32)
+    });
+    let product: u32 = u32::from_str_radix(std::str::from_utf8(&id[3..]).unwrap(), 16).unwrap();
+    let id: u32 = (compressed << 16) | product;
+    let mut aml: Vec<u8> = vec![0x0c];
+    aml.extend(id.to_be_bytes());
+    aml
+}
+
+/// # Else {TermList}
+pub fn else_(terms: Vec<Vec<u8>>) -> Vec<u8> {
+    with_pkg_length(&[0xa1], terms.concat())
+}
+
+/// # Event (EventName)
+pub fn event(name: &str) -> Vec<u8> {
+    let mut aml: Vec<u8> = vec![EXT_OP_PREFIX, 0x02];
+    aml.extend(name_string(name));
+    aml
+}
+
+/// # Field (RegionName, AccessType, LockRule, UpdateRule) {FieldUnitList}
+/// A field unit without a name is reserved.
+pub fn field(region: &str, flags: u8, units: &[(&str, usize)]) -> Vec<u8> {
+    let mut body: Vec<u8> = name_string(region);
+    body.push(flags);
+    units.iter().for_each(|(name, bits)| {
+        if name.is_empty() {
+            body.push(0x00);
+        } else {
+            body.extend(name_segment(name));
+        }
+        body.extend(field_length(*bits));
+    });
+    with_pkg_length(&[EXT_OP_PREFIX, 0x81], body)
+}
+
+/// # If (Predicate) {TermList}
+pub fn if_(predicate: Vec<u8>, terms: Vec<Vec<u8>>) -> Vec<u8> {
+    let mut body: Vec<u8> = predicate;
+    body.extend(terms.concat());
+    with_pkg_length(&[0xa0], body)
+}
+
+/// # Increment (Addend)
+pub fn increment(addend: Vec<u8>) -> Vec<u8> {
+    let mut aml: Vec<u8> = vec![0x75];
+    aml.extend(addend);
+    aml
+}
+
+/// # Index (Source, Index, Destination)
+pub fn index(source: Vec<u8>, index: Vec<u8>, destination: Vec<u8>) -> Vec<u8> {
+    binary(0x88, source, index, destination)
+}
+
+/// # Integer
+/// The shortest encoding as iasl does.
+pub fn integer(value: u64) -> Vec<u8> {
+    match value {
+        0 => vec![0x00],
+        1 => vec![0x01],
+        value if value == u64::MAX => vec![0xff],
+        value if value <= u8::MAX as u64 => vec![0x0a, value as u8],
+        value if value <= u16::MAX as u64 => {
+            let mut aml: Vec<u8> = vec![0x0b];
+            aml.extend((value as u16).to_le_bytes());
+            aml
+        }
+        value if value <= u32::MAX as u64 => {
+            let mut aml: Vec<u8> = vec![0x0c];
+            aml.extend((value as u32).to_le_bytes());
+            aml
+        }
+        value => {
+            let mut aml: Vec<u8> = vec![0x0e];
+            aml.extend(value.to_le_bytes());
+            aml
+        }
+    }
+}
+
+/// # LEqual (Source1, Source2)
+pub fn lequal(source1: Vec<u8>, source2: Vec<u8>) -> Vec<u8> {
+    let mut aml: Vec<u8> = vec![0x93];
+    aml.extend(source1);
+    aml.extend(source2);
+    aml
+}
+
+/// # LLess (Source1, Source2)
+pub fn lless(source1: Vec<u8>, source2: Vec<u8>) -> Vec<u8> {
+    let mut aml: Vec<u8> = vec![0x95];
+    aml.extend(source1);
+    aml.extend(source2);
+    aml
+}
+
+/// # Load (Object, DDBHandle)
+pub fn load(object: &str, ddb_handle: Vec<u8>) -> Vec<u8> {
+    let mut aml: Vec<u8> = vec![EXT_OP_PREFIX, 0x20];
+    aml.extend(name_string(object));
+    aml.extend(ddb_handle);
+    aml
+}
+
+/// # LoadTable (SignatureString, OEMIDString, OEMTableIDString, RootPathString, ParameterPathString, ParameterData)
+pub fn load_table(strings: [&str; 5], parameter_data: Vec<u8>) -> Vec<u8> {
+    let mut aml: Vec<u8> = vec![EXT_OP_PREFIX, 0x1f];
+    strings
+        .into_iter()
+        .for_each(|string_| aml.extend(string(string_)));
+    aml.extend(parameter_data);
+    aml
+}
+
+/// # LocalN
+pub fn local(index: u8) -> Vec<u8> {
+    assert!(index < 8);
+    vec![0x60 + index]
+}
+
+/// # Method (MethodName, NumArgs, SerializeRule) {TermList}
+pub fn method(name: &str, arguments: u8, serialized: bool, terms: Vec<Vec<u8>>) -> Vec<u8> {
+    let mut body: Vec<u8> = name_string(name);
+    body.push(arguments | if serialized { 0x08 } else { 0x00 });
+    body.extend(terms.concat());
+    with_pkg_length(&[0x14], body)
+}
+
+/// # Mutex (MutexName, SyncLevel)
+pub fn mutex(name: &str, sync_level: u8) -> Vec<u8> {
+    let mut aml: Vec<u8> = vec![EXT_OP_PREFIX, 0x01];
+    aml.extend(name_string(name));
+    aml.push(sync_level);
+    aml
+}
+
+/// # Name (ObjectName, Object)
+pub fn name(name: &str, object: Vec<u8>) -> Vec<u8> {
+    let mut aml: Vec<u8> = vec![0x08];
+    aml.extend(name_string(name));
+    aml.extend(object);
+    aml
+}
+
+/// # NameString
+/// Dots separate name segments, and a leading backslash or carets are prefixes.
+pub fn name_string(path: &str) -> Vec<u8> {
+    let prefix_length: usize = path
+        .find(|character| character != '\\' && character != '^')
+        .unwrap_or(path.len());
+    let (prefix, path): (&str, &str) = path.split_at(prefix_length);
+    let segments: Vec<Vec<u8>> = if path.is_empty() {
+        Vec::new()
+    } else {
+        path.split('.').map(name_segment).collect()
+    };
+    let mut aml: Vec<u8> = prefix.as_bytes().to_vec();
+    match segments.len() {
+        0 => aml.push(0x00),
+        1 => {}
+        2 => aml.push(0x2e),
+        length => aml.extend([0x2f, length as u8]),
+    }
+    aml.extend(segments.concat());
+    aml
+}
+
+/// # Notify (Object, NotificationValue)
+pub fn notify(object: &str, value: Vec<u8>) -> Vec<u8> {
+    let mut aml: Vec<u8> = vec![0x86];
+    aml.extend(name_string(object));
+    aml.extend(value);
+    aml
+}
+
+/// # OperationRegion (RegionName, RegionSpace, Offset, Length)
+pub fn operation_region(name: &str, region_space: u8, offset: u64, length: u64) -> Vec<u8> {
+    let mut aml: Vec<u8> = vec![EXT_OP_PREFIX, 0x80];
+    aml.extend(name_string(name));
+    aml.push(region_space);
+    aml.extend(integer(offset));
+    aml.extend(integer(length));
+    aml
+}
+
+/// # Or (Source1, Source2, Result)
+pub fn or(source1: Vec<u8>, source2: Vec<u8>, result: Vec<u8>) -> Vec<u8> {
+    binary(0x7d, source1, source2, result)
+}
+
+/// # Package (NumElements) {PackageList}
+/// A name string element refers to a named object.
+pub fn package(elements: Vec<Vec<u8>>) -> Vec<u8> {
+    let mut body: Vec<u8> = vec![elements.len() as u8];
+    body.extend(elements.concat());
+    with_pkg_length(&[0x12], body)
+}
+
+/// # Package (NumElements) {}
+pub fn empty_package(length: u8) -> Vec<u8> {
+    with_pkg_length(&[0x12], vec![length])
+}
+
+/// # Parse the definition block of a table
+pub fn parse(table: &[u8]) -> syntax::TermList {
+    let definition_block: &[u8] = &table[HEADER_SIZE..];
+    let mut semantic_tree = name::Node::default();
+    let root_path = name::Path::root();
+    let (mut syntax_tree, unread_definition_block): (syntax::TermList, &[u8]) =
+        syntax::TermList::first_read(definition_block, &mut semantic_tree, &root_path).unwrap();
+    assert!(unread_definition_block.is_empty());
+    syntax_tree.read_outside_method(&mut semantic_tree, &root_path);
+    syntax_tree
+}
+
+/// # Release (Mutex)
+pub fn release(mutex: &str) -> Vec<u8> {
+    let mut aml: Vec<u8> = vec![EXT_OP_PREFIX, 0x27];
+    aml.extend(name_string(mutex));
+    aml
+}
+
+/// # Processor (ProcessorName, ProcessorID, PBlockAddress, PblockLength) {ObjectList}
+pub fn processor(
+    name: &str,
+    id: u8,
+    pblk_address: u32,
+    pblk_length: u8,
+    terms: Vec<Vec<u8>>,
+) -> Vec<u8> {
+    let mut body: Vec<u8> = name_string(name);
+    body.push(id);
+    body.extend(pblk_address.to_le_bytes());
+    body.push(pblk_length);
+    body.extend(terms.concat());
+    with_pkg_length(&[EXT_OP_PREFIX, 0x83], body)
+}
+
+/// # Reset (SyncObject)
+pub fn reset(event: &str) -> Vec<u8> {
+    let mut aml: Vec<u8> = vec![EXT_OP_PREFIX, 0x26];
+    aml.extend(name_string(event));
+    aml
+}
+
+/// # Return (Arg)
+pub fn return_(arg: Vec<u8>) -> Vec<u8> {
+    let mut aml: Vec<u8> = vec![0xa4];
+    aml.extend(arg);
+    aml
+}
+
+/// # Scope (Location) {TermList}
+pub fn scope(location: &str, terms: Vec<Vec<u8>>) -> Vec<u8> {
+    let mut body: Vec<u8> = name_string(location);
+    body.extend(terms.concat());
+    with_pkg_length(&[0x10], body)
+}
+
+/// # ShiftLeft (Source, ShiftCount, Result)
+pub fn shift_left(source: Vec<u8>, shift_count: Vec<u8>, result: Vec<u8>) -> Vec<u8> {
+    binary(0x79, source, shift_count, result)
+}
+
+/// # ShiftRight (Source, ShiftCount, Result)
+pub fn shift_right(source: Vec<u8>, shift_count: Vec<u8>, result: Vec<u8>) -> Vec<u8> {
+    binary(0x7a, source, shift_count, result)
+}
+
+/// # Signal (SyncObject)
+pub fn signal(event: &str) -> Vec<u8> {
+    let mut aml: Vec<u8> = vec![EXT_OP_PREFIX, 0x24];
+    aml.extend(name_string(event));
+    aml
+}
+
+/// # Store (Source, Destination)
+pub fn store(source: Vec<u8>, destination: Vec<u8>) -> Vec<u8> {
+    let mut aml: Vec<u8> = vec![0x70];
+    aml.extend(source);
+    aml.extend(destination);
+    aml
+}
+
+/// # String
+pub fn string(string: &str) -> Vec<u8> {
+    let mut aml: Vec<u8> = vec![0x0d];
+    aml.extend(string.as_bytes());
+    aml.push(0x00);
+    aml
+}
+
+/// # ThermalZone (ThermalZoneName) {TermList}
+pub fn thermal_zone(name: &str, terms: Vec<Vec<u8>>) -> Vec<u8> {
+    let mut body: Vec<u8> = name_string(name);
+    body.extend(terms.concat());
+    with_pkg_length(&[EXT_OP_PREFIX, 0x85], body)
+}
+
+/// # Wait (SyncObject, TimeoutValue)
+pub fn wait(event: &str, timeout: Vec<u8>) -> Vec<u8> {
+    let mut aml: Vec<u8> = vec![EXT_OP_PREFIX, 0x25];
+    aml.extend(name_string(event));
+    aml.extend(timeout);
+    aml
+}
+
+/// # While (Predicate) {TermList}
+pub fn while_(predicate: Vec<u8>, terms: Vec<Vec<u8>>) -> Vec<u8> {
+    let mut body: Vec<u8> = predicate;
+    body.extend(terms.concat());
+    with_pkg_length(&[0xa2], body)
+}
+
+/// # Target which discards the result
+pub fn zero_target() -> Vec<u8> {
+    vec![0x00]
+}
+
+fn binary(opcode: u8, operand1: Vec<u8>, operand2: Vec<u8>, target: Vec<u8>) -> Vec<u8> {
+    let mut aml: Vec<u8> = vec![opcode];
+    aml.extend(operand1);
+    aml.extend(operand2);
+    aml.extend(target);
+    aml
+}
+
+fn field_length(bits: usize) -> Vec<u8> {
+    encoded_length(bits)
+}
+
+fn name_segment(segment: &str) -> Vec<u8> {
+    assert!(!segment.is_empty() && segment.len() <= 4);
+    padded(segment, 4, b'_')
+}
+
+fn padded(string: &str, length: usize, padding: u8) -> Vec<u8> {
+    let mut bytes: Vec<u8> = string.as_bytes().to_vec();
+    assert!(bytes.len() <= length);
+    bytes.resize(length, padding);
+    bytes
+}
+
+/// Encodes a length in the PkgLength format.
+fn encoded_length(length: usize) -> Vec<u8> {
+    if length < 0x40 {
+        vec![length as u8]
+    } else {
+        let following: usize = (1..=3)
+            .find(|following| length < 1 << (4 + 8 * following))
+            .unwrap();
+        let mut bytes: Vec<u8> = vec![((following as u8) << 6) | (length & 0x0f) as u8];
+        bytes.extend((0..following).map(|index| (length >> (4 + 8 * index)) as u8));
+        bytes
+    }
+}
+
+/// Encodes a PkgLength of the body, which counts the PkgLength itself.
+fn pkg_length(body_length: usize) -> Vec<u8> {
+    let size: usize = (1..=4)
+        .find(|size| {
+            let length: usize = body_length + size;
+            encoded_length(length).len() == *size
+        })
+        .unwrap();
+    encoded_length(body_length + size)
+}
+
+fn with_pkg_length(opcode: &[u8], body: Vec<u8>) -> Vec<u8> {
+    let mut aml: Vec<u8> = opcode.to_vec();
+    aml.extend(pkg_length(body.len()));
+    aml.extend(body);
+    aml
+}
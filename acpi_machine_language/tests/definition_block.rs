@@ -0,0 +1,98 @@
+//! # Dynamically loaded definition blocks
+//! Methods in the DSDT below load SSDTs with `Load` and `LoadTable`, and the tests check where the loaded objects appear in the namespace.
+//! The loaded definition blocks are global, so each test loads tables with its own names.
+
+mod aml;
+mod platform;
+
+use acpi_machine_language::{interpreter::Value, reference, syntax};
+
+/// # An SSDT defining a relative name
+fn ssdt(oem_table_id: &str, name: &str, value: u64) -> Vec<u8> {
+    aml::definition_block(
+        "SSDT",
+        2,
+        "KERNEL",
+        oem_table_id,
+        1,
+        vec![aml::name(name, aml::integer(value))],
+    )
+}
+
+fn dsdt() -> Vec<u8> {
+    aml::definition_block(
+        "DSDT",
+        2,
+        "KERNEL",
+        "LOAD",
+        1,
+        vec![aml::scope(
+            "\\_SB",
+            vec![
+                aml::device(
+                    "DEV0",
+                    vec![
+                        aml::name("TBL0", aml::buffer(&ssdt("LOAD0", "LDN0", 0x2a))),
+                        aml::method(
+                            "LOAD",
+                            0,
+                            false,
+                            vec![
+                                aml::load("TBL0", aml::local(0)),
+                                aml::return_(aml::local(0)),
+                            ],
+                        ),
+                    ],
+                ),
+                aml::method(
+                    "LDTB",
+                    0,
+                    false,
+                    vec![aml::return_(aml::load_table(
+                        ["SSDT", "KERNEL", "LOAD1", "\\_SB", "LDN1"],
+                        aml::integer(0x55),
+                    ))],
+                ),
+            ],
+        )],
+    )
+}
+
+fn integer(value: &Value) -> u64 {
+    value.into()
+}
+
+#[test]
+fn load_under_scope() {
+    platform::initialize();
+    let dsdt: Vec<u8> = dsdt();
+    let dsdt: syntax::TermList = aml::parse(&dsdt);
+    let root = reference::Node::from(&dsdt);
+    let handle: Option<Value> = root.evaluate(&"\\_SB.DEV0.LOAD".into(), Vec::new());
+    assert!(matches!(handle, Some(Value::DdbHandle(_))));
+    assert_ne!(handle.as_ref().map(integer), Some(0));
+    assert_eq!(
+        root.evaluate(&"\\_SB.DEV0.LDN0".into(), Vec::new())
+            .as_ref()
+            .map(integer),
+        Some(0x2a)
+    );
+    assert!(root.evaluate(&"\\LDN0".into(), Vec::new()).is_none());
+}
+
+#[test]
+fn load_table_stores_parameter_to_name() {
+    let host = platform::initialize();
+    host.add_table(ssdt("LOAD1", "LDN1", 0));
+    let dsdt: Vec<u8> = dsdt();
+    let dsdt: syntax::TermList = aml::parse(&dsdt);
+    let root = reference::Node::from(&dsdt);
+    let handle: Option<Value> = root.evaluate(&"\\_SB.LDTB".into(), Vec::new());
+    assert!(matches!(handle, Some(Value::DdbHandle(_))));
+    assert_eq!(
+        root.evaluate(&"\\_SB.LDN1".into(), Vec::new())
+            .as_ref()
+            .map(integer),
+        Some(0x55)
+    );
+}
@@ -0,0 +1,247 @@
+//! # Embedded controller
+//! A scripted model of an embedded controller sits behind I/O ports of the host platform.
+//! It serves the commands of the ACPI embedded controller interface from its RAM and records every port access, so the tests check both the values and the protocol.
+//! Each test has its own data and command ports because the tests share the platform.
+
+mod aml;
+mod platform;
+
+use {
+    acpi_machine_language::{
+        embedded_controller::Controller, interpreter::Value, reference, syntax,
+    },
+    std::{
+        collections::VecDeque,
+        sync::{Arc, Mutex},
+    },
+};
+
+/// # A port access seen by the model
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Access {
+    ReadData(u8),
+    ReadStatus(u8),
+    WriteCommand(u8),
+    WriteData(u8),
+}
+
+#[derive(Default)]
+enum State {
+    #[default]
+    Idle,
+    ReadAddress,
+    WriteAddress,
+    WriteValue(u8),
+}
+
+#[derive(Default)]
+struct Model {
+    accesses: Vec<Access>,
+    burst: bool,
+    output: Option<u8>,
+    queries: VecDeque<u8>,
+    ram: Vec<u8>,
+    state: State,
+}
+
+impl Model {
+    fn new() -> Arc<Mutex<Self>> {
+        let ram: Vec<u8> = (0..=u8::MAX).collect();
+        Arc::new(Mutex::new(Self {
+            ram,
+            ..Self::default()
+        }))
+    }
+
+    /// # The commands written to the command port
+    fn commands(&self) -> Vec<u8> {
+        self.accesses
+            .iter()
+            .filter_map(|access| match access {
+                Access::WriteCommand(command) => Some(*command),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn status(&self) -> u8 {
+        let obf: u8 = self.output.is_some() as u8;
+        let burst: u8 = (self.burst as u8) << 4;
+        let sci_evt: u8 = (!self.queries.is_empty() as u8) << 5;
+        obf | burst | sci_evt
+    }
+}
+
+/// # The model shared between the platform and a test
+struct Shared {
+    command_port: u16,
+    model: Arc<Mutex<Model>>,
+}
+
+impl Shared {
+    /// # Put a model behind a data port and the command port following it
+    fn attach(host: &platform::Host, data_port: u16, command_port: u16) -> Arc<Mutex<Model>> {
+        let model: Arc<Mutex<Model>> = Model::new();
+        host.attach_io_device(
+            data_port as usize..=command_port as usize,
+            Box::new(Self {
+                command_port,
+                model: model.clone(),
+            }),
+        );
+        model
+    }
+}
+
+impl platform::IoDevice for Shared {
+    fn read(&mut self, port: usize) -> u8 {
+        let mut model = self.model.lock().unwrap();
+        if port == self.command_port as usize {
+            let status: u8 = model.status();
+            model.accesses.push(Access::ReadStatus(status));
+            status
+        } else {
+            let data: u8 = model.output.take().unwrap_or(0xff);
+            model.accesses.push(Access::ReadData(data));
+            data
+        }
+    }
+
+    fn write(&mut self, port: usize, value: u8) {
+        let mut model = self.model.lock().unwrap();
+        if port == self.command_port as usize {
+            model.accesses.push(Access::WriteCommand(value));
+            match value {
+                0x80 => model.state = State::ReadAddress,
+                0x81 => model.state = State::WriteAddress,
+                0x82 => {
+                    model.burst = true;
+                    model.output = Some(0x90);
+                }
+                0x83 => model.burst = false,
+                0x84 => model.output = Some(model.queries.pop_front().unwrap_or_default()),
+                command => panic!("Unknown command {:#x?}", command),
+            }
+        } else {
+            model.accesses.push(Access::WriteData(value));
+            match model.state {
+                State::Idle => panic!("Unexpected data {:#x?}", value),
+                State::ReadAddress => {
+                    model.output = Some(model.ram[value as usize]);
+                    model.state = State::Idle;
+                }
+                State::WriteAddress => model.state = State::WriteValue(value),
+                State::WriteValue(address) => {
+                    model.ram[address as usize] = value;
+                    model.state = State::Idle;
+                }
+            }
+        }
+    }
+}
+
+/// # A DSDT accessing the embedded controller RAM through fields
+fn dsdt() -> Vec<u8> {
+    aml::definition_block(
+        "DSDT",
+        2,
+        "KERNEL",
+        "EC",
+        1,
+        vec![aml::scope(
+            "\\_SB",
+            vec![aml::device(
+                "EC0",
+                vec![
+                    aml::name("_HID", aml::eisa_id("PNP0C09")),
+                    aml::operation_region("ECOR", 0x03, 0x00, 0x100),
+                    aml::field(
+                        "ECOR",
+                        0x01,
+                        &[("", 0x10 * 8), ("TMP0", 8), ("", 8), ("BAT0", 16)],
+                    ),
+                    aml::method(
+                        "RTMP",
+                        0,
+                        false,
+                        vec![aml::return_(aml::name_string("TMP0"))],
+                    ),
+                    aml::method(
+                        "RBAT",
+                        0,
+                        false,
+                        vec![aml::return_(aml::name_string("BAT0"))],
+                    ),
+                    aml::method(
+                        "WBAT",
+                        1,
+                        false,
+                        vec![aml::store(aml::arg(0), aml::name_string("BAT0"))],
+                    ),
+                ],
+            )],
+        )],
+    )
+}
+
+fn integer(value: &Value) -> u64 {
+    let value: usize = value.into();
+    value as u64
+}
+
+#[test]
+fn protocol() {
+    let host = platform::initialize();
+    let model: Arc<Mutex<Model>> = Shared::attach(host, 0x62, 0x66);
+    let controller = Controller::new(0x66, 0x62, false);
+    // A single byte is read without burst mode.
+    assert_eq!(controller.read(0x20, 1), 0x20);
+    assert_eq!(
+        model.lock().unwrap().accesses,
+        [
+            Access::ReadStatus(0x00),
+            Access::WriteCommand(0x80),
+            Access::ReadStatus(0x00),
+            Access::WriteData(0x20),
+            Access::ReadStatus(0x01),
+            Access::ReadData(0x20),
+        ]
+    );
+    // Multiple bytes are read in burst mode in the little endian.
+    model.lock().unwrap().accesses.clear();
+    assert_eq!(controller.read(0x30, 2), 0x3130);
+    assert_eq!(model.lock().unwrap().commands(), [0x82, 0x80, 0x80, 0x83]);
+    assert!(!model.lock().unwrap().burst);
+    controller.write(0x40, 2, 0xbeef);
+    assert_eq!(model.lock().unwrap().ram[0x40..0x42], [0xef, 0xbe]);
+    // A query is read only while an SCI event is pending.
+    assert_eq!(controller.query(), None);
+    model.lock().unwrap().queries.push_back(0x12);
+    assert_eq!(controller.query(), Some(0x12));
+    assert_eq!(controller.query(), None);
+}
+
+#[test]
+fn embedded_control_fields() {
+    let host = platform::initialize();
+    let model: Arc<Mutex<Model>> = Shared::attach(host, 0x68, 0x6c);
+    host.attach_embedded_controller(Controller::new(0x6c, 0x68, false));
+    let dsdt: Vec<u8> = dsdt();
+    let dsdt: syntax::TermList = aml::parse(&dsdt);
+    let root = reference::Node::from(&dsdt);
+    model.lock().unwrap().ram[0x10] = 0x2a;
+    assert_eq!(
+        root.evaluate(&"\\_SB.EC0.RTMP".into(), Vec::new())
+            .as_ref()
+            .map(integer),
+        Some(0x2a)
+    );
+    root.evaluate(&"\\_SB.EC0.WBAT".into(), vec![Value::Word(0x1234)]);
+    assert_eq!(model.lock().unwrap().ram[0x12..0x14], [0x34, 0x12]);
+    assert_eq!(
+        root.evaluate(&"\\_SB.EC0.RBAT".into(), Vec::new())
+            .as_ref()
+            .map(integer),
+        Some(0x1234)
+    );
+}
@@ -0,0 +1,233 @@
+//! # Fuzzing
+//! A malformed table must neither crash nor hang the parser and the interpreter.
+//! The harness parses a table with `TermList::first_read` and `read_outside_method`, lends it to a `reference::Node` and evaluates the usual objects of every device, with operation regions backed by the memory of the host platform.
+//!
+//! `corpus` and `regressions` run the harness on the seed corpus in `tests/corpus`, the tables in `tests/tables` and the inputs in `tests/regressions` which crashed once.
+//! `mutate` is the fuzzer itself, which is ignored by default because it takes time.
+//!
+//! ```sh
+//! AML_FUZZ_ITERATIONS=100000 AML_FUZZ_SEED=1 cargo test --test fuzz -- --ignored --nocapture
+//! ```
+//!
+//! Each input which panics or hangs is written to `target/fuzz`.
+//! Once the cause is fixed, the input goes to `tests/regressions`.
+
+mod aml;
+mod platform;
+
+use {
+    acpi_machine_language::{
+        interpreter::Value,
+        name, reference,
+        syntax::{self, FirstReader, ReaderOutsideMethod},
+    },
+    std::{
+        env, fs, panic,
+        path::{Path, PathBuf},
+        sync::mpsc,
+        thread,
+        time::Duration,
+    },
+};
+
+/// # Objects evaluated under every device
+const DEVICE_OBJECTS: [&str; 10] = [
+    "_ADR", "_CRS", "_HID", "_INI", "_PRS", "_PRT", "_PS0", "_PS3", "_STA", "_UID",
+];
+
+/// # Objects evaluated under the root
+const ROOT_OBJECTS: [&str; 6] = ["\\_S0", "\\_S3", "\\_S4", "\\_S5", "\\_PTS", "\\_WAK"];
+
+/// # An input which hangs the harness longer than this is reported
+const TIMEOUT: Duration = Duration::from_secs(60);
+
+/// # Bytes which the mutator inserts
+/// Prefixes and opcodes which introduce a package length or a name are the most likely to confuse the parser.
+const INTERESTING_BYTES: [u8; 16] = [
+    0x00, 0x01, 0x0a, 0x0b, 0x0c, 0x0e, 0x10, 0x12, 0x14, 0x2e, 0x2f, 0x5b, 0x5c, 0x70, 0xa2, 0xff,
+];
+
+/// # Parse and evaluate a table
+fn harness(table: &[u8]) {
+    let Some(definition_block) = table.get(aml::HEADER_SIZE..) else {
+        return;
+    };
+    let mut semantic_tree = name::Node::default();
+    let root_path = name::Path::root();
+    let Some((mut syntax_tree, _unread_definition_block)) =
+        syntax::TermList::first_read(definition_block, &mut semantic_tree, &root_path)
+    else {
+        return;
+    };
+    syntax_tree.read_outside_method(&mut semantic_tree, &root_path);
+    let root = reference::Node::from(&syntax_tree);
+    root.get_local_device_paths()
+        .into_iter()
+        .flat_map(|device| {
+            DEVICE_OBJECTS
+                .into_iter()
+                .map(move |object| device.clone() + object.into())
+        })
+        .chain(ROOT_OBJECTS.into_iter().map(name::Path::from))
+        .for_each(|object| {
+            root.evaluate(&object, vec![Value::Zero]);
+        });
+}
+
+/// # Run the harness on a thread
+/// Returns the panic message or `None` if the harness finishes.
+fn run(table: &[u8]) -> Option<String> {
+    let (sender, receiver) = mpsc::channel();
+    let table: Vec<u8> = table.to_vec();
+    thread::spawn(move || {
+        let message: Option<String> =
+            panic::catch_unwind(|| harness(&table))
+                .err()
+                .map(|payload| {
+                    payload
+                        .downcast_ref::<String>()
+                        .cloned()
+                        .or_else(|| {
+                            payload
+                                .downcast_ref::<&str>()
+                                .map(|message| message.to_string())
+                        })
+                        .unwrap_or_default()
+                });
+        sender.send(message).ok();
+    });
+    receiver
+        .recv_timeout(TIMEOUT)
+        .unwrap_or_else(|_| Some(format!("hang over {:?}", TIMEOUT)))
+}
+
+/// # Tables in a directory
+fn tables(directory: &str) -> Vec<(PathBuf, Vec<u8>)> {
+    let directory: PathBuf = Path::new(env!("CARGO_MANIFEST_DIR")).join(directory);
+    let mut tables: Vec<(PathBuf, Vec<u8>)> = fs::read_dir(directory)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "aml"))
+        .map(|path| {
+            let table: Vec<u8> = fs::read(&path).unwrap();
+            (path, table)
+        })
+        .collect();
+    tables.sort();
+    tables
+}
+
+/// # Check that the harness finishes on every table in directories
+fn replay(directories: &[&str]) {
+    platform::initialize();
+    let failures: Vec<String> = directories
+        .iter()
+        .flat_map(|directory| tables(directory))
+        .filter_map(|(path, table)| run(&table).map(|message| format!("{:?}: {}", path, message)))
+        .collect();
+    assert!(failures.is_empty(), "{:#?}", failures);
+}
+
+/// # A mutational fuzzer
+/// A xorshift generator keeps runs reproducible from a seed.
+struct Mutator {
+    state: u64,
+}
+
+impl Mutator {
+    fn new(seed: u64) -> Self {
+        let state: u64 = seed.wrapping_mul(0x9e3779b97f4a7c15).max(1);
+        Self { state }
+    }
+
+    fn next(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+
+    fn below(&mut self, bound: usize) -> usize {
+        (self.next() % bound.max(1) as u64) as usize
+    }
+
+    /// # Derive an input from the seeds
+    /// The header is kept so that mutations hit the definition block.
+    fn mutate(&mut self, seeds: &[Vec<u8>]) -> Vec<u8> {
+        let mut table: Vec<u8> = seeds[self.below(seeds.len())].clone();
+        (0..1 + self.below(4)).for_each(|_| {
+            if table.len() <= aml::HEADER_SIZE {
+                return;
+            }
+            let position: usize = aml::HEADER_SIZE + self.below(table.len() - aml::HEADER_SIZE);
+            match self.below(6) {
+                0 => table[position] = self.next() as u8,
+                1 => table[position] ^= 1 << self.below(u8::BITS as usize),
+                2 => {
+                    let byte: u8 = INTERESTING_BYTES[self.below(INTERESTING_BYTES.len())];
+                    table.insert(position, byte);
+                }
+                3 => {
+                    let end: usize = table.len().min(position + 1 + self.below(8));
+                    table.drain(position..end);
+                }
+                4 => {
+                    let seed: &Vec<u8> = &seeds[self.below(seeds.len())];
+                    let start: usize = aml::HEADER_SIZE + self.below(seed.len() - aml::HEADER_SIZE);
+                    let end: usize = seed.len().min(start + 1 + self.below(16));
+                    table.splice(position..position, seed[start..end].iter().copied());
+                }
+                _ => table.truncate(position),
+            }
+        });
+        table
+    }
+}
+
+#[test]
+fn corpus() {
+    replay(&["tests/corpus", "tests/tables"]);
+}
+
+#[test]
+fn regressions() {
+    replay(&["tests/regressions"]);
+}
+
+#[test]
+#[ignore]
+fn mutate() {
+    platform::initialize();
+    panic::set_hook(Box::new(|_| {}));
+    let iterations: usize = env::var("AML_FUZZ_ITERATIONS")
+        .ok()
+        .and_then(|iterations| iterations.parse().ok())
+        .unwrap_or(10000);
+    let seed: u64 = env::var("AML_FUZZ_SEED")
+        .ok()
+        .and_then(|seed| seed.parse().ok())
+        .unwrap_or(0x2545f4914f6cdd1d);
+    let seeds: Vec<Vec<u8>> = tables("tests/corpus")
+        .into_iter()
+        .chain(tables("tests/tables"))
+        .map(|(_path, table)| table)
+        .filter(|table| aml::HEADER_SIZE < table.len())
+        .collect();
+    let crashes: PathBuf = Path::new(env!("CARGO_MANIFEST_DIR")).join("target/fuzz");
+    let mut mutator = Mutator::new(seed);
+    let failures: Vec<(PathBuf, String)> = (0..iterations)
+        .filter_map(|iteration| {
+            let table: Vec<u8> = mutator.mutate(&seeds);
+            run(&table).map(|message| {
+                fs::create_dir_all(&crashes).unwrap();
+                let path: PathBuf = crashes.join(format!("{:x}-{}.aml", seed, iteration));
+                fs::write(&path, &table).unwrap();
+                (path, message)
+            })
+        })
+        .collect();
+    let _ = panic::take_hook();
+    assert!(failures.is_empty(), "{:#?}", failures);
+}
@@ -0,0 +1,47 @@
+//! # Values of the interpreter
+//! The operators are checked on values in the encodings which the parser produces for integers.
+
+use acpi_machine_language::interpreter::{Value, MAX_OBJECT_SIZE};
+
+#[test]
+fn integers_compare_by_value() {
+    assert_eq!(Value::Zero, Value::Byte(0x00));
+    assert_eq!(Value::One, Value::QWord(0x01));
+    assert_eq!(Value::Ones, Value::QWord(u64::MAX));
+    assert_eq!(Value::Word(0x0100), Value::DWord(0x00000100));
+    assert!(Value::Byte(0xff) < Value::Word(0x0100));
+    assert_ne!(Value::Byte(0x00), Value::Buffer(vec![0x00]));
+}
+
+#[test]
+fn shifts_are_64_bit() {
+    assert_eq!(
+        Value::Byte(0x01) << Value::Byte(16),
+        Some(Value::QWord(0x10000))
+    );
+    assert_eq!(
+        Value::DWord(0x01) << Value::Byte(63),
+        Some(Value::QWord(1 << 63))
+    );
+    assert_eq!(Value::Byte(0x01) << Value::Byte(64), Some(Value::Zero));
+    assert_eq!(Value::Byte(0x80) >> Value::Byte(7), Some(Value::One));
+    assert_eq!(Value::QWord(u64::MAX) >> Value::Byte(64), Some(Value::Zero));
+}
+
+#[test]
+fn invalid_operands_have_no_result() {
+    assert_eq!(Value::Byte(0x01) / Value::Zero, None);
+    assert_eq!(Value::Byte(0x01) % Value::Zero, None);
+    assert_eq!(Value::Zero + Value::Package(vec![]), None);
+    assert_eq!(Value::QWord(u64::MAX) + Value::One, Some(Value::Zero));
+    assert_ne!(Value::Zero, Value::Package(vec![]));
+}
+
+#[test]
+fn objects_are_limited_in_size() {
+    let largest = Value::Buffer(vec![0x00; MAX_OBJECT_SIZE]);
+    assert!(largest.clone().limit().is_some());
+    assert_eq!(largest.concatenate(&Value::Buffer(vec![0x00])), None);
+    let package = Value::Package(vec![Value::Buffer(vec![0x00; MAX_OBJECT_SIZE / 2]); 2]);
+    assert_eq!(package.concatenate(&package), None);
+}
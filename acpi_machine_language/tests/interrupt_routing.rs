@@ -0,0 +1,200 @@
+//! # PCI interrupt routing
+//! The DSDT below routes slot 1 to a global system interrupt and slot 2 to an interrupt link device in the APIC mode, and routes both slots to the link device in the PIC mode like QEMU q35.
+
+mod aml;
+mod platform;
+
+use acpi_machine_language::{
+    interrupt_routing::{self, Entry, Interrupt, InterruptModel, Source},
+    name, reference,
+    resource::{self, Descriptor, InterruptFlags},
+    syntax,
+};
+
+/// # `Interrupt (ResourceConsumer, Level, ActiveLow, Shared) {interrupts}`
+fn link_interrupt(interrupts: Vec<u32>) -> Descriptor {
+    Descriptor::ExtendedInterrupt {
+        consumer: true,
+        flags: InterruptFlags {
+            edge_triggered: false,
+            active_low: true,
+            shared: true,
+            wake_capable: false,
+        },
+        interrupts,
+        resource_source: None,
+    }
+}
+
+/// # A _PRT entry
+fn route(slot: u64, pin: u64, source: Vec<u8>, index: u64) -> Vec<u8> {
+    aml::package(vec![
+        aml::integer((slot << 16) | 0xffff),
+        aml::integer(pin),
+        source,
+        aml::integer(index),
+    ])
+}
+
+fn dsdt() -> Vec<u8> {
+    aml::definition_block(
+        "DSDT",
+        2,
+        "KERNEL",
+        "ROUTING",
+        1,
+        vec![
+            aml::scope(
+                "\\",
+                vec![
+                    aml::name("PICF", aml::integer(0)),
+                    aml::method(
+                        "_PIC",
+                        1,
+                        false,
+                        vec![aml::store(aml::arg(0), aml::name_string("PICF"))],
+                    ),
+                ],
+            ),
+            aml::scope(
+                "\\_SB",
+                vec![
+                    aml::device(
+                        "PCI0",
+                        vec![
+                            aml::name("_HID", aml::eisa_id("PNP0A08")),
+                            aml::name(
+                                "PRTP",
+                                aml::package(vec![
+                                    route(1, 0, aml::name_string("\\_SB.LNKA"), 0),
+                                    route(2, 0, aml::name_string("\\_SB.LNKA"), 0),
+                                ]),
+                            ),
+                            aml::name(
+                                "PRTA",
+                                aml::package(vec![
+                                    route(1, 0, aml::integer(0), 16),
+                                    route(2, 0, aml::name_string("\\_SB.LNKA"), 0),
+                                ]),
+                            ),
+                            aml::method(
+                                "_PRT",
+                                0,
+                                false,
+                                vec![
+                                    aml::if_(
+                                        aml::lequal(aml::name_string("PICF"), aml::integer(0)),
+                                        vec![aml::return_(aml::name_string("PRTP"))],
+                                    ),
+                                    aml::else_(vec![aml::return_(aml::name_string("PRTA"))]),
+                                ],
+                            ),
+                        ],
+                    ),
+                    aml::device(
+                        "LNKA",
+                        vec![
+                            aml::name("_HID", aml::eisa_id("PNP0C0F")),
+                            aml::name("_UID", aml::integer(0)),
+                            aml::name(
+                                "RSRC",
+                                aml::buffer(&resource::encode(&[link_interrupt(vec![0])])),
+                            ),
+                            aml::method(
+                                "_CRS",
+                                0,
+                                false,
+                                vec![aml::return_(aml::name_string("RSRC"))],
+                            ),
+                            aml::method(
+                                "_PRS",
+                                0,
+                                false,
+                                vec![aml::return_(aml::buffer(&resource::encode(&[
+                                    link_interrupt(vec![5, 10, 11]),
+                                ])))],
+                            ),
+                            aml::method(
+                                "_SRS",
+                                1,
+                                false,
+                                vec![aml::store(aml::arg(0), aml::name_string("RSRC"))],
+                            ),
+                        ],
+                    ),
+                ],
+            ),
+        ],
+    )
+}
+
+#[test]
+fn interrupt_model() {
+    platform::initialize();
+    let dsdt: Vec<u8> = dsdt();
+    let dsdt: syntax::TermList = aml::parse(&dsdt);
+    let root = reference::Node::from(&dsdt);
+    let pci0: name::Path = "\\_SB.PCI0".into();
+    let link = Source::Link {
+        device: "\\_SB.LNKA".into(),
+        index: 0,
+    };
+    let table: Vec<Entry> = interrupt_routing::table(&root, &pci0).unwrap();
+    assert_eq!(table[0].source, link);
+    assert!(interrupt_routing::set_interrupt_model(
+        &root,
+        InterruptModel::Apic
+    ));
+    let table: Vec<Entry> = interrupt_routing::table(&root, &pci0).unwrap();
+    assert_eq!(
+        table,
+        [
+            Entry {
+                device: 1,
+                pin: 0,
+                source: Source::GlobalSystemInterrupt(16),
+            },
+            Entry {
+                device: 2,
+                pin: 0,
+                source: link,
+            },
+        ]
+    );
+}
+
+#[test]
+fn link_and_hardwired_routes() {
+    platform::initialize();
+    let dsdt: Vec<u8> = dsdt();
+    let dsdt: syntax::TermList = aml::parse(&dsdt);
+    let root = reference::Node::from(&dsdt);
+    let pci0: name::Path = "\\_SB.PCI0".into();
+    let lnka: name::Path = "\\_SB.LNKA".into();
+    let level_active_low = InterruptFlags {
+        edge_triggered: false,
+        active_low: true,
+        shared: true,
+        wake_capable: false,
+    };
+    interrupt_routing::set_interrupt_model(&root, InterruptModel::Apic);
+    assert_eq!(
+        interrupt_routing::route(&root, &pci0, 1, 0),
+        Some(Interrupt {
+            global_system_interrupt: 16,
+            flags: level_active_low,
+        })
+    );
+    assert_eq!(interrupt_routing::route(&root, &pci0, 1, 1), None);
+    // The link device has no interrupt yet, so the first possible one is set.
+    let interrupt = Some(Interrupt {
+        global_system_interrupt: 5,
+        flags: level_active_low,
+    });
+    assert_eq!(interrupt_routing::route(&root, &pci0, 2, 0), interrupt);
+    assert_eq!(
+        resource::current(&root, &lnka).unwrap(),
+        [link_interrupt(vec![5])]
+    );
+    assert_eq!(interrupt_routing::route(&root, &pci0, 2, 0), interrupt);
+}
@@ -0,0 +1,76 @@
+//! # Notifications
+//! The DSDT below notifies the power button from a method of the embedded controller, and has an alias of the power button.
+
+mod aml;
+mod platform;
+
+use {
+    acpi_machine_language::{name, notification, reference, syntax},
+    std::sync::atomic::{AtomicUsize, Ordering},
+};
+
+static NOTIFICATIONS: AtomicUsize = AtomicUsize::new(0);
+
+fn dsdt() -> Vec<u8> {
+    aml::definition_block(
+        "DSDT",
+        2,
+        "KERNEL",
+        "NOTIFY",
+        1,
+        vec![aml::scope(
+            "\\_SB",
+            vec![
+                aml::device("PWRB", vec![aml::name("_HID", aml::eisa_id("PNP0C0C"))]),
+                aml::alias("PWRB", "PBTN"),
+                aml::device(
+                    "EC0",
+                    vec![
+                        aml::name("_HID", aml::eisa_id("PNP0C09")),
+                        aml::method(
+                            "_Q01",
+                            0,
+                            false,
+                            vec![aml::notify("PWRB", aml::integer(0x80))],
+                        ),
+                    ],
+                ),
+            ],
+        )],
+    )
+}
+
+fn handle(object: &name::Path, value: u8) {
+    assert_eq!(*object, "\\_SB.PWRB".into());
+    assert_eq!(value, 0x80);
+    NOTIFICATIONS.fetch_add(1, Ordering::SeqCst);
+}
+
+#[test]
+fn register_alias_from_current() {
+    let host = platform::initialize();
+    let dsdt: Vec<u8> = dsdt();
+    let dsdt: syntax::TermList = aml::parse(&dsdt);
+    let root = reference::Node::from(&dsdt);
+    let embedded_controller: name::Path = "\\_SB.EC0".into();
+    assert_eq!(
+        notification::register_from_current(&root, &embedded_controller, &"PBTN".into(), handle),
+        Some("\\_SB.PWRB".into())
+    );
+    root.evaluate(&"\\_SB.EC0._Q01".into(), Vec::new());
+    host.notifications()
+        .into_iter()
+        .filter(|(object, _value)| *object == "\\_SB.PWRB".into())
+        .for_each(|(object, value)| notification::dispatch(&object, value));
+    assert_eq!(NOTIFICATIONS.load(Ordering::SeqCst), 1);
+    notification::unregister_from_current(&root, &embedded_controller, &"PBTN".into(), handle);
+    notification::dispatch(&"\\_SB.PWRB".into(), 0x80);
+    assert_eq!(NOTIFICATIONS.load(Ordering::SeqCst), 1);
+    assert!(notification::register_from_current(
+        &root,
+        &embedded_controller,
+        &"NONE".into(),
+        handle
+    )
+    .is_none());
+}
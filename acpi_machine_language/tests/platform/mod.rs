@@ -0,0 +1,272 @@
+//! # Host platform
+//! Operation regions are backed by memory, so AML touching hardware registers runs without side effects.
+
+#![allow(dead_code)]
+
+use {
+    acpi_machine_language::{embedded_controller, interpreter, name, platform},
+    std::{
+        collections::{hash_map::DefaultHasher, BTreeMap},
+        fmt,
+        hash::{Hash, Hasher},
+        ops::RangeInclusive,
+        sync::{Mutex, Once},
+        thread,
+        time::{Duration, Instant},
+    },
+};
+
+static HOST: Host = Host::new();
+static INITIALIZATION: Once = Once::new();
+
+/// # Register the host platform
+pub fn initialize() -> &'static Host {
+    INITIALIZATION.call_once(|| platform::set(&HOST));
+    &HOST
+}
+
+/// # A device behind I/O ports
+pub trait IoDevice: Send {
+    fn read(&mut self, port: usize) -> u8;
+
+    fn write(&mut self, port: usize, value: u8);
+}
+
+/// # A device and its ports
+type IoDeviceAttachment = (RangeInclusive<usize>, Box<dyn IoDevice>);
+
+/// # Owner of the global lock in the FACS
+#[derive(Debug, PartialEq)]
+enum GlobalLock {
+    Free,
+    Owned,
+    OwnedByFirmware,
+}
+
+pub struct Host {
+    debug: Mutex<Vec<interpreter::Value>>,
+    embedded_controller: Mutex<Option<embedded_controller::Controller>>,
+    global_lock: Mutex<GlobalLock>,
+    io_devices: Mutex<Vec<IoDeviceAttachment>>,
+    notifications: Mutex<Vec<(name::Path, u8)>>,
+    regions: Mutex<BTreeMap<(String, usize), u8>>,
+    start: Mutex<Option<Instant>>,
+    tables: Mutex<Vec<Vec<u8>>>,
+}
+
+impl Host {
+    const fn new() -> Self {
+        Self {
+            debug: Mutex::new(Vec::new()),
+            embedded_controller: Mutex::new(None),
+            global_lock: Mutex::new(GlobalLock::Free),
+            io_devices: Mutex::new(Vec::new()),
+            notifications: Mutex::new(Vec::new()),
+            regions: Mutex::new(BTreeMap::new()),
+            start: Mutex::new(None),
+            tables: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// # Values stored to the Debug object
+    pub fn debug_values(&self) -> Vec<interpreter::Value> {
+        self.debug.lock().unwrap().clone()
+    }
+
+    /// # Route EmbeddedControl regions to an embedded controller
+    pub fn attach_embedded_controller(&self, controller: embedded_controller::Controller) {
+        *self.embedded_controller.lock().unwrap() = Some(controller);
+    }
+
+    /// # Whether the interpreter owns the global lock in the FACS
+    pub fn owns_global_lock(&self) -> bool {
+        *self.global_lock.lock().unwrap() == GlobalLock::Owned
+    }
+
+    /// # Make the firmware own the global lock or release it
+    /// The interpreter can't acquire the global lock while the firmware owns it.
+    pub fn set_firmware_global_lock(&self, owned: bool) {
+        *self.global_lock.lock().unwrap() = if owned {
+            GlobalLock::OwnedByFirmware
+        } else {
+            GlobalLock::Free
+        };
+    }
+
+    /// # Put a device behind I/O ports
+    /// Byte accesses to the ports in the SystemIO region space reach the device instead of the memory.
+    pub fn attach_io_device(&self, ports: RangeInclusive<usize>, device: Box<dyn IoDevice>) {
+        self.io_devices.lock().unwrap().push((ports, device));
+    }
+
+    /// # Add a table which `LoadTable` finds
+    pub fn add_table(&self, table: Vec<u8>) {
+        self.tables.lock().unwrap().push(table);
+    }
+
+    /// # Notifications delivered to the platform
+    pub fn notifications(&self) -> Vec<(name::Path, u8)> {
+        self.notifications.lock().unwrap().clone()
+    }
+
+    /// # Read a byte of an operation region
+    pub fn peek(&self, region_space: &interpreter::RegionSpace, address: usize) -> u8 {
+        self.regions
+            .lock()
+            .unwrap()
+            .get(&(format!("{:?}", region_space), address))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// # Write a byte of an operation region
+    pub fn poke(&self, region_space: &interpreter::RegionSpace, address: usize, value: u8) {
+        self.regions
+            .lock()
+            .unwrap()
+            .insert((format!("{:?}", region_space), address), value);
+    }
+}
+
+impl platform::Context for Host {
+    fn context_id(&self) -> usize {
+        let mut hasher = DefaultHasher::new();
+        thread::current().id().hash(&mut hasher);
+        hasher.finish() as usize
+    }
+}
+
+impl platform::Debug for Host {
+    fn debug(&self, value: &interpreter::Value) {
+        self.debug.lock().unwrap().push(value.clone());
+    }
+
+    fn log(&self, message: fmt::Arguments) {
+        println!("{}", message);
+    }
+}
+
+impl platform::Firmware for Host {
+    fn acquire_global_lock(&self) -> bool {
+        let mut global_lock = self.global_lock.lock().unwrap();
+        let acquired: bool = *global_lock == GlobalLock::Free;
+        if acquired {
+            *global_lock = GlobalLock::Owned;
+        }
+        acquired
+    }
+
+    fn release_global_lock(&self) {
+        let mut global_lock = self.global_lock.lock().unwrap();
+        assert_eq!(*global_lock, GlobalLock::Owned);
+        *global_lock = GlobalLock::Free;
+    }
+
+    fn find_table(&self, signature: &str, oem_id: &str, oem_table_id: &str) -> Option<Vec<u8>> {
+        let padding: &[char] = &[' ', '\0'];
+        self.tables
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|table| {
+                let field = |range: std::ops::Range<usize>| {
+                    String::from_utf8_lossy(&table[range])
+                        .trim_end_matches(padding)
+                        .to_string()
+                };
+                field(0..4) == signature
+                    && (oem_id.is_empty() || field(10..16) == oem_id)
+                    && (oem_table_id.is_empty() || field(16..24) == oem_table_id)
+            })
+            .cloned()
+    }
+}
+
+impl platform::Notifier for Host {
+    fn notify(&self, object: name::Path, value: u8) {
+        self.notifications.lock().unwrap().push((object, value));
+    }
+}
+
+impl platform::OperationRegion for Host {
+    fn read(&self, region_space: &interpreter::RegionSpace, address: usize, size: usize) -> u64 {
+        match region_space {
+            interpreter::RegionSpace::EmbeddedControl => {
+                if let Some(controller) = self.embedded_controller.lock().unwrap().as_ref() {
+                    return controller.read(address as u8, size);
+                }
+            }
+            interpreter::RegionSpace::SystemIo if size == 1 => {
+                if let Some((_ports, device)) = self
+                    .io_devices
+                    .lock()
+                    .unwrap()
+                    .iter_mut()
+                    .find(|(ports, _device)| ports.contains(&address))
+                {
+                    return device.read(address) as u64;
+                }
+            }
+            _ => {}
+        }
+        (0..size).rev().fold(0, |value, offset| {
+            (value << u8::BITS) | self.peek(region_space, address + offset) as u64
+        })
+    }
+
+    fn write(
+        &self,
+        region_space: &interpreter::RegionSpace,
+        address: usize,
+        size: usize,
+        value: u64,
+    ) {
+        match region_space {
+            interpreter::RegionSpace::EmbeddedControl => {
+                if let Some(controller) = self.embedded_controller.lock().unwrap().as_ref() {
+                    controller.write(address as u8, size, value);
+                    return;
+                }
+            }
+            interpreter::RegionSpace::SystemIo if size == 1 => {
+                if let Some((_ports, device)) = self
+                    .io_devices
+                    .lock()
+                    .unwrap()
+                    .iter_mut()
+                    .find(|(ports, _device)| ports.contains(&address))
+                {
+                    device.write(address, value as u8);
+                    return;
+                }
+            }
+            _ => {}
+        }
+        (0..size).for_each(|offset| {
+            self.poke(
+                region_space,
+                address + offset,
+                (value >> (offset * u8::BITS as usize)) as u8,
+            )
+        });
+    }
+}
+
+impl platform::Timer for Host {
+    fn nanoseconds(&self) -> u64 {
+        self.start
+            .lock()
+            .unwrap()
+            .get_or_insert_with(Instant::now)
+            .elapsed()
+            .as_nanos() as u64
+    }
+
+    fn sleep_milliseconds(&self, milliseconds: usize) {
+        thread::sleep(Duration::from_millis(milliseconds as u64));
+    }
+
+    fn stall_microseconds(&self, microseconds: usize) {
+        thread::sleep(Duration::from_micros(microseconds as u64));
+    }
+}
@@ -0,0 +1,202 @@
+//! # Processors
+//! The DSDT below declares a `Processor` object with `_CST` having an MWAIT based C1 and an I/O port based C2, and `_PCT`, `_PSS` and `_PPC` controlled by FFixedHW.
+//! It also declares an `ACPI0007` device without them.
+
+mod aml;
+mod platform;
+
+use acpi_machine_language::{
+    name,
+    processor::{CState, PState, Processor, Register},
+    reference, syntax,
+};
+
+const SYSTEM_IO: u8 = 0x01;
+const FUNCTIONAL_FIXED_HARDWARE: u8 = 0x7f;
+
+/// # `ResourceTemplate () {Register (AddressSpaceKeyword, RegisterBitWidth, RegisterBitOffset, RegisterAddress, AccessSize)}`
+fn register(register: Register) -> Vec<u8> {
+    let mut template: Vec<u8> = vec![0x82, 0x0c, 0x00];
+    template.extend([
+        register.address_space,
+        register.bit_width,
+        register.bit_offset,
+        register.access_size,
+    ]);
+    template.extend(register.address.to_le_bytes());
+    template.extend([0x79, 0x00]);
+    aml::buffer(&template)
+}
+
+/// # A _CST entry
+fn c_state(c_state: &CState) -> Vec<u8> {
+    aml::package(vec![
+        register(c_state.register),
+        aml::integer(c_state.state_type as u64),
+        aml::integer(c_state.latency as u64),
+        aml::integer(c_state.power as u64),
+    ])
+}
+
+fn c_states() -> Vec<CState> {
+    vec![
+        CState {
+            register: Register {
+                address_space: FUNCTIONAL_FIXED_HARDWARE,
+                bit_width: 0x01,
+                bit_offset: 0x02,
+                access_size: 0x01,
+                address: 0x00,
+            },
+            state_type: 1,
+            latency: 1,
+            power: 1000,
+        },
+        CState {
+            register: Register {
+                address_space: SYSTEM_IO,
+                bit_width: 0x08,
+                bit_offset: 0x00,
+                access_size: 0x00,
+                address: 0x414,
+            },
+            state_type: 2,
+            latency: 100,
+            power: 500,
+        },
+    ]
+}
+
+/// # A _PSS entry
+fn p_state(p_state: &PState) -> Vec<u8> {
+    aml::package(vec![
+        aml::integer(p_state.core_frequency as u64),
+        aml::integer(p_state.power as u64),
+        aml::integer(p_state.latency as u64),
+        aml::integer(p_state.bus_master_latency as u64),
+        aml::integer(p_state.control),
+        aml::integer(p_state.status),
+    ])
+}
+
+fn p_states() -> Vec<PState> {
+    [
+        (3000, 35000, 0x1e00),
+        (2000, 20000, 0x1400),
+        (800, 6000, 0x0800),
+    ]
+    .into_iter()
+    .map(|(core_frequency, power, control)| PState {
+        core_frequency,
+        power,
+        latency: 10,
+        bus_master_latency: 10,
+        control,
+        status: control,
+    })
+    .collect()
+}
+
+fn performance_register() -> Register {
+    Register {
+        address_space: FUNCTIONAL_FIXED_HARDWARE,
+        bit_width: 0x00,
+        bit_offset: 0x00,
+        access_size: 0x00,
+        address: 0x00,
+    }
+}
+
+fn dsdt() -> Vec<u8> {
+    let c_states: Vec<CState> = c_states();
+    let mut cst: Vec<Vec<u8>> = vec![aml::integer(c_states.len() as u64)];
+    cst.extend(c_states.iter().map(c_state));
+    aml::definition_block(
+        "DSDT",
+        2,
+        "KERNEL",
+        "CSTATE",
+        1,
+        vec![
+            aml::scope(
+                "\\_PR",
+                vec![aml::processor(
+                    "CPU0",
+                    0x00,
+                    0x410,
+                    0x06,
+                    vec![
+                        aml::method("_CST", 0, false, vec![aml::return_(aml::package(cst))]),
+                        aml::name(
+                            "_PCT",
+                            aml::package(vec![
+                                register(performance_register()),
+                                register(performance_register()),
+                            ]),
+                        ),
+                        aml::name(
+                            "_PSS",
+                            aml::package(p_states().iter().map(p_state).collect()),
+                        ),
+                        aml::method("_PPC", 0, false, vec![aml::return_(aml::integer(1))]),
+                    ],
+                )],
+            ),
+            aml::scope(
+                "\\_SB",
+                vec![aml::device(
+                    "CPU1",
+                    vec![
+                        aml::name("_HID", aml::string("ACPI0007")),
+                        aml::name("_UID", aml::integer(1)),
+                    ],
+                )],
+            ),
+        ],
+    )
+}
+
+#[test]
+fn enumerate() {
+    platform::initialize();
+    let dsdt: Vec<u8> = dsdt();
+    let dsdt: syntax::TermList = aml::parse(&dsdt);
+    let root = reference::Node::from(&dsdt);
+    let processors: Vec<(name::Path, u32)> = Processor::enumerate(&root)
+        .iter()
+        .map(|processor| (processor.path().clone(), processor.uid()))
+        .collect();
+    assert_eq!(
+        processors,
+        [("\\_PR.CPU0".into(), 0), ("\\_SB.CPU1".into(), 1)]
+    );
+}
+
+#[test]
+fn cst() {
+    platform::initialize();
+    let dsdt: Vec<u8> = dsdt();
+    let dsdt: syntax::TermList = aml::parse(&dsdt);
+    let root = reference::Node::from(&dsdt);
+    let [cpu0, cpu1] = Processor::enumerate(&root).try_into().unwrap();
+    assert_eq!(cpu0.c_states(&root), Some(c_states()));
+    assert_eq!(cpu1.c_states(&root), None);
+}
+
+#[test]
+fn performance() {
+    platform::initialize();
+    let dsdt: Vec<u8> = dsdt();
+    let dsdt: syntax::TermList = aml::parse(&dsdt);
+    let root = reference::Node::from(&dsdt);
+    let [cpu0, cpu1] = Processor::enumerate(&root).try_into().unwrap();
+    assert_eq!(
+        cpu0.performance_control(&root),
+        Some((performance_register(), performance_register()))
+    );
+    assert_eq!(cpu0.performance_states(&root), Some(p_states()));
+    assert_eq!(cpu0.performance_present_capabilities(&root), Some(1));
+    assert_eq!(cpu1.performance_control(&root), None);
+    assert_eq!(cpu1.performance_states(&root), None);
+    assert_eq!(cpu1.performance_present_capabilities(&root), None);
+}
@@ -0,0 +1,640 @@
+//! # Tables of QEMU and OVMF
+//! `tests/tables` holds tables dumped from machines by `acpidump -b`, such as the DSDT which QEMU i440fx provides when booted with OVMF, and `_S5`, `_PRT` and the devices are checked against it.
+//! Every table put there is parsed too, and every DSDT there must have `_S5`.
+//! The DSDT and the SSDT built below with the AML builder keep the objects of the i440fx DSDT and the processor SSDT which the kernel depends on, to check the interpreter on what the dumped tables don't cover.
+//! `iasl_round_trip` compiles the disassembly with `iasl`, which is ignored by default because it needs `iasl` on the host.
+//!
+//! ```sh
+//! cargo test --test qemu -- --ignored
+//! ```
+
+mod aml;
+mod platform;
+
+use {
+    acpi_machine_language::{
+        definition_block, device, disassembler,
+        interpreter::{RegionSpace, Value},
+        name, reference, syntax,
+    },
+    std::{
+        fs,
+        path::{Path, PathBuf},
+        process::{Command, Output},
+    },
+};
+
+const SYSTEM_IO: u8 = 0x01;
+const PCI_CONFIG: u8 = 0x02;
+const BYTE_ACC_NO_LOCK_PRESERVE: u8 = 0x01;
+
+/// # Convert an integer value
+fn integer(value: &Value) -> usize {
+    value.into()
+}
+
+/// # Convert a package of integers and references
+fn routes(prt: &Value) -> Vec<(usize, usize, String, usize)> {
+    match prt {
+        Value::Package(routes) => routes
+            .iter()
+            .map(|route| match route {
+                Value::Package(route) => {
+                    let source: String = match &route[2] {
+                        Value::Reference(source) => format!("{:?}", source),
+                        source => format!("{:#x}", integer(source)),
+                    };
+                    (
+                        integer(&route[0]),
+                        integer(&route[1]),
+                        source,
+                        integer(&route[3]),
+                    )
+                }
+                route => panic!("route = {:#x?}", route),
+            })
+            .collect(),
+        prt => panic!("prt = {:#x?}", prt),
+    }
+}
+
+/// # Convert a sleep state package
+fn sleep_types(package: &Value) -> Vec<usize> {
+    match package {
+        Value::Package(package) => package.iter().map(integer).collect(),
+        package => panic!("package = {:#x?}", package),
+    }
+}
+
+/// # Routes which `_PRT` of QEMU i440fx returns
+/// Pin A of slot 1 is routed to `LNKS` for the power management controller and the other pins rotate `LNKA` to `LNKD`.
+fn i440fx_routes() -> Vec<(usize, usize, String, usize)> {
+    (0..0x80usize)
+        .map(|pin| {
+            let slot: usize = pin >> 2;
+            let link: &str = match (pin + slot) & 3 {
+                0 => "LNKD",
+                1 if pin == 4 => "LNKS",
+                1 => "LNKA",
+                2 => "LNKB",
+                _ => "LNKC",
+            };
+            ((slot << 16) | 0xffff, pin & 3, format!("\\_SB.{}", link), 0)
+        })
+        .collect()
+}
+
+/// # A link device
+fn link(name: &str, hid: &str, uid: u64) -> Vec<u8> {
+    aml::device(
+        name,
+        vec![
+            aml::name("_HID", aml::eisa_id(hid)),
+            aml::name("_UID", aml::integer(uid)),
+            aml::method("_STA", 0, false, vec![aml::return_(aml::integer(0x0b))]),
+        ],
+    )
+}
+
+/// # i440fx DSDT
+/// `_PRT` builds its routes in a loop like `build_prt` of QEMU.
+fn i440fx_dsdt() -> Vec<u8> {
+    let pin: fn() -> Vec<u8> = || aml::local(1);
+    let slot: fn() -> Vec<u8> = || aml::local(2);
+    let link_index: fn() -> Vec<u8> = || aml::local(3);
+    let route_entry: fn() -> Vec<u8> = || aml::local(4);
+    let initialize_route = |link: &str, index: u64| {
+        aml::if_(
+            aml::lequal(link_index(), aml::integer(index)),
+            vec![aml::store(
+                aml::package(vec![
+                    aml::integer(0),
+                    aml::integer(0),
+                    aml::name_string(link),
+                    aml::integer(0),
+                ]),
+                route_entry(),
+            )],
+        )
+    };
+    let prt: Vec<u8> = aml::method(
+        "_PRT",
+        0,
+        false,
+        vec![
+            aml::store(aml::empty_package(0x80), aml::local(0)),
+            aml::store(aml::integer(0), pin()),
+            aml::while_(
+                aml::lless(pin(), aml::integer(0x80)),
+                vec![
+                    aml::store(
+                        aml::shift_right(pin(), aml::integer(2), aml::zero_target()),
+                        slot(),
+                    ),
+                    aml::store(
+                        aml::and(
+                            aml::add(pin(), slot(), aml::zero_target()),
+                            aml::integer(3),
+                            aml::zero_target(),
+                        ),
+                        link_index(),
+                    ),
+                    initialize_route("LNKD", 0),
+                    aml::if_(
+                        aml::lequal(link_index(), aml::integer(1)),
+                        vec![
+                            aml::if_(
+                                aml::lequal(pin(), aml::integer(4)),
+                                vec![aml::store(
+                                    aml::package(vec![
+                                        aml::integer(0),
+                                        aml::integer(0),
+                                        aml::name_string("LNKS"),
+                                        aml::integer(0),
+                                    ]),
+                                    route_entry(),
+                                )],
+                            ),
+                            aml::else_(vec![aml::store(
+                                aml::package(vec![
+                                    aml::integer(0),
+                                    aml::integer(0),
+                                    aml::name_string("LNKA"),
+                                    aml::integer(0),
+                                ]),
+                                route_entry(),
+                            )]),
+                        ],
+                    ),
+                    initialize_route("LNKB", 2),
+                    initialize_route("LNKC", 3),
+                    aml::store(
+                        aml::or(
+                            aml::shift_left(slot(), aml::integer(16), aml::zero_target()),
+                            aml::integer(0xffff),
+                            aml::zero_target(),
+                        ),
+                        aml::index(route_entry(), aml::integer(0), aml::zero_target()),
+                    ),
+                    aml::store(
+                        aml::and(pin(), aml::integer(3), aml::zero_target()),
+                        aml::index(route_entry(), aml::integer(1), aml::zero_target()),
+                    ),
+                    aml::store(
+                        route_entry(),
+                        aml::index(aml::local(0), pin(), aml::zero_target()),
+                    ),
+                    aml::increment(pin()),
+                ],
+            ),
+            aml::return_(aml::local(0)),
+        ],
+    );
+    aml::definition_block(
+        "DSDT",
+        1,
+        "BOCHS",
+        "BXPC",
+        1,
+        vec![
+            aml::scope(
+                "\\",
+                vec![
+                    aml::operation_region("DBG", SYSTEM_IO, 0x0402, 1),
+                    aml::field("DBG", BYTE_ACC_NO_LOCK_PRESERVE, &[("DBGB", 8)]),
+                    aml::method(
+                        "DBUG",
+                        1,
+                        false,
+                        vec![aml::store(aml::arg(0), aml::name_string("DBGB"))],
+                    ),
+                ],
+            ),
+            aml::scope(
+                "\\_SB",
+                vec![aml::device(
+                    "PCI0",
+                    vec![
+                        aml::name("_HID", aml::eisa_id("PNP0A03")),
+                        aml::name("_ADR", aml::integer(0)),
+                        aml::name("_UID", aml::integer(0)),
+                    ],
+                )],
+            ),
+            aml::name(
+                "\\_S3",
+                aml::package(vec![
+                    aml::integer(1),
+                    aml::integer(1),
+                    aml::integer(0),
+                    aml::integer(0),
+                ]),
+            ),
+            aml::name(
+                "\\_S4",
+                aml::package(vec![
+                    aml::integer(2),
+                    aml::integer(2),
+                    aml::integer(0),
+                    aml::integer(0),
+                ]),
+            ),
+            aml::name(
+                "\\_S5",
+                aml::package(vec![
+                    aml::integer(0),
+                    aml::integer(0),
+                    aml::integer(0),
+                    aml::integer(0),
+                ]),
+            ),
+            aml::scope(
+                "\\_SB.PCI0",
+                vec![aml::device(
+                    "ISA",
+                    vec![
+                        aml::name("_ADR", aml::integer(0x00010000)),
+                        aml::operation_region("P40C", PCI_CONFIG, 0x60, 4),
+                    ],
+                )],
+            ),
+            aml::scope(
+                "\\_SB",
+                vec![
+                    aml::scope("PCI0", vec![prt]),
+                    aml::field(
+                        "PCI0.ISA.P40C",
+                        BYTE_ACC_NO_LOCK_PRESERVE,
+                        &[("PRQ0", 8), ("PRQ1", 8), ("PRQ2", 8), ("PRQ3", 8)],
+                    ),
+                    link("LNKA", "PNP0C0F", 0),
+                    link("LNKB", "PNP0C0F", 1),
+                    link("LNKC", "PNP0C0F", 2),
+                    link("LNKD", "PNP0C0F", 3),
+                    link("LNKS", "PNP0C0F", 4),
+                ],
+            ),
+        ],
+    )
+}
+
+/// # SSDT of processors
+fn cpu_ssdt() -> Vec<u8> {
+    aml::definition_block(
+        "SSDT",
+        1,
+        "BOCHS",
+        "CPUSSDT",
+        1,
+        vec![aml::scope(
+            "\\_SB",
+            vec![aml::device(
+                "CPUS",
+                vec![
+                    aml::name("_HID", aml::string("ACPI0010")),
+                    aml::name("_CID", aml::eisa_id("PNP0A05")),
+                    aml::device(
+                        "C000",
+                        vec![
+                            aml::name("_HID", aml::string("ACPI0007")),
+                            aml::name("_UID", aml::integer(0)),
+                            aml::method("_STA", 0, false, vec![aml::return_(aml::integer(0x0f))]),
+                        ],
+                    ),
+                ],
+            )],
+        )],
+    )
+}
+
+#[test]
+fn i440fx_s5() {
+    platform::initialize();
+    let dsdt: Vec<u8> = i440fx_dsdt();
+    let dsdt: syntax::TermList = aml::parse(&dsdt);
+    let root = reference::Node::from(&dsdt);
+    let s3: Value = root.evaluate(&"\\_S3".into(), Vec::new()).unwrap();
+    let s4: Value = root.evaluate(&"\\_S4".into(), Vec::new()).unwrap();
+    let s5: Value = root.evaluate(&"\\_S5".into(), Vec::new()).unwrap();
+    assert_eq!(sleep_types(&s3), vec![1, 1, 0, 0]);
+    assert_eq!(sleep_types(&s4), vec![2, 2, 0, 0]);
+    assert_eq!(sleep_types(&s5), vec![0, 0, 0, 0]);
+}
+
+#[test]
+fn i440fx_prt() {
+    platform::initialize();
+    let dsdt: Vec<u8> = i440fx_dsdt();
+    let dsdt: syntax::TermList = aml::parse(&dsdt);
+    let root = reference::Node::from(&dsdt);
+    let prt: Value = root
+        .evaluate(&"\\_SB.PCI0._PRT".into(), Vec::new())
+        .unwrap();
+    assert_eq!(routes(&prt), i440fx_routes());
+}
+
+#[test]
+fn i440fx_operation_region() {
+    let host: &platform::Host = platform::initialize();
+    let dsdt: Vec<u8> = i440fx_dsdt();
+    let dsdt: syntax::TermList = aml::parse(&dsdt);
+    let root = reference::Node::from(&dsdt);
+    root.evaluate(&"\\DBUG".into(), vec![Value::Byte(0x0a)]);
+    assert_eq!(host.peek(&RegionSpace::SystemIo, 0x0402), 0x0a);
+}
+
+#[test]
+fn cpu_ssdt_devices() {
+    platform::initialize();
+    let ssdt: Vec<u8> = cpu_ssdt();
+    let ssdt: syntax::TermList = aml::parse(&ssdt);
+    let root = reference::Node::from(&ssdt);
+    let devices = device::Node::enumerate(&root);
+    let [cpus] = devices.children() else {
+        panic!("devices = {:#x?}", devices);
+    };
+    assert_eq!(cpus.ids().collect::<Vec<&str>>(), ["ACPI0010", "PNP0A05"]);
+    let [processor] = cpus.children() else {
+        panic!("cpus = {:#x?}", cpus);
+    };
+    assert_eq!(processor.hid(), Some("ACPI0007"));
+    assert!(processor.status().present() && processor.status().functioning());
+}
+
+#[test]
+fn ssdt_load_and_unload() {
+    platform::initialize();
+    let dsdt: Vec<u8> = i440fx_dsdt();
+    let dsdt: syntax::TermList = aml::parse(&dsdt);
+    let root = reference::Node::from(&dsdt);
+    let sta: name::Path = "\\_SB.CPUS.C000._STA".into();
+    assert!(root.evaluate(&sta, Vec::new()).is_none());
+    let handle: u64 = definition_block::load(&cpu_ssdt(), &name::Path::root()).unwrap();
+    assert_eq!(
+        root.evaluate(&sta, Vec::new()).map(|sta| integer(&sta)),
+        Some(0x0f)
+    );
+    assert!(!definition_block::unload(handle));
+    assert!(root.evaluate(&sta, Vec::new()).is_none());
+}
+
+#[test]
+fn disassemble() {
+    platform::initialize();
+    let dsdt: Vec<u8> = i440fx_dsdt();
+    let dsdt: syntax::TermList = aml::parse(&dsdt);
+    let asl: String = disassembler::disassemble(&dsdt);
+    assert!(asl.contains("OperationRegion (DBG, SystemIO, 0x0402, One)"));
+    assert!(asl.contains("Method (_PRT, 0, NotSerialized)"));
+}
+
+/// # Compare the disassembly with iasl
+/// `iasl` compiles the disassembly of each table and the compiled table must disassemble into the same ASL.
+#[test]
+#[ignore]
+fn iasl_round_trip() {
+    platform::initialize();
+    let directory: PathBuf = Path::new(env!("CARGO_TARGET_TMPDIR")).join("iasl");
+    fs::create_dir_all(&directory).unwrap();
+    [("i440fx", i440fx_dsdt()), ("cpu", cpu_ssdt())]
+        .into_iter()
+        .for_each(|(name, table)| {
+            let asl: String = disassembler::disassemble_table(&table).unwrap();
+            let prefix: PathBuf = directory.join(name);
+            let source: PathBuf = prefix.with_extension("dsl");
+            fs::write(&source, &asl).unwrap();
+            let output: Output = Command::new("iasl")
+                .arg("-p")
+                .arg(&prefix)
+                .arg(&source)
+                .output()
+                .expect("iasl_round_trip requires iasl");
+            assert!(
+                output.status.success(),
+                "iasl can't compile {:?}\n{}",
+                source,
+                String::from_utf8_lossy(&output.stdout)
+            );
+            let compiled: Vec<u8> = fs::read(prefix.with_extension("aml")).unwrap();
+            assert_eq!(
+                disassembler::disassemble_table(&compiled),
+                Some(asl),
+                "{:?}",
+                source
+            );
+        });
+}
+
+/// # Tables dumped from a machine
+/// Every table in `tests/tables` must parse, a DSDT must have `_S5` and at least one DSDT must be there.
+#[test]
+fn dumped_tables() {
+    platform::initialize();
+    let directory: PathBuf = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/tables");
+    let paths: Vec<PathBuf> = fs::read_dir(&directory)
+        .unwrap_or_else(|error| panic!("Can't read {:?}: {}", directory, error))
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "aml"))
+        .collect();
+    let dsdts: usize = paths
+        .iter()
+        .filter(|path| {
+            let table: Vec<u8> = fs::read(path).unwrap();
+            let term_list: syntax::TermList = aml::parse(&table);
+            let is_dsdt: bool = table.starts_with(b"DSDT");
+            if is_dsdt {
+                let root = reference::Node::from(&term_list);
+                assert!(
+                    root.evaluate(&"\\_S5".into(), Vec::new()).is_some(),
+                    "{:?} has no _S5",
+                    path
+                );
+            }
+            is_dsdt
+        })
+        .count();
+    assert_ne!(dsdts, 0, "{:?} has no DSDT", directory);
+}
+
+/// # The DSDT dumped from QEMU i440fx booted with OVMF
+/// It builds `_PRT` at run time by storing into elements of packages.
+fn ovmf_dsdt() -> Vec<u8> {
+    fs::read(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/tables/qemu-i440fx-ovmf-dsdt.aml"))
+        .unwrap()
+}
+
+#[test]
+fn ovmf_s5() {
+    platform::initialize();
+    let dsdt: Vec<u8> = ovmf_dsdt();
+    let dsdt: syntax::TermList = aml::parse(&dsdt);
+    let root = reference::Node::from(&dsdt);
+    let s3: Value = root.evaluate(&"\\_S3".into(), Vec::new()).unwrap();
+    let s4: Value = root.evaluate(&"\\_S4".into(), Vec::new()).unwrap();
+    let s5: Value = root.evaluate(&"\\_S5".into(), Vec::new()).unwrap();
+    assert_eq!(sleep_types(&s3), vec![1, 1, 0, 0]);
+    assert_eq!(sleep_types(&s4), vec![2, 2, 0, 0]);
+    assert_eq!(sleep_types(&s5), vec![0, 0, 0, 0]);
+}
+
+#[test]
+fn ovmf_prt() {
+    platform::initialize();
+    let dsdt: Vec<u8> = ovmf_dsdt();
+    let dsdt: syntax::TermList = aml::parse(&dsdt);
+    let root = reference::Node::from(&dsdt);
+    let prt: Value = root
+        .evaluate(&"\\_SB.PCI0._PRT".into(), Vec::new())
+        .unwrap();
+    assert_eq!(routes(&prt), i440fx_routes());
+}
+
+/// # Resources of a link device
+/// `_SRS` and `_CRS` go through buffer fields created inside the methods.
+#[test]
+fn ovmf_link_resources() {
+    platform::initialize();
+    let dsdt: Vec<u8> = ovmf_dsdt();
+    let dsdt: syntax::TermList = aml::parse(&dsdt);
+    let root = reference::Node::from(&dsdt);
+    let mut resource: Vec<u8> = match root.evaluate(&"\\_SB.LNKA._CRS".into(), Vec::new()) {
+        Some(Value::Buffer(resource)) => resource,
+        crs => panic!("_CRS = {:#x?}", crs),
+    };
+    resource[5..9].copy_from_slice(&0x0bu32.to_le_bytes());
+    root.evaluate(&"\\_SB.LNKA._SRS".into(), vec![Value::Buffer(resource)]);
+    match root.evaluate(&"\\_SB.LNKA._CRS".into(), Vec::new()) {
+        Some(Value::Buffer(resource)) => assert_eq!(resource[5..9], 0x0bu32.to_le_bytes()),
+        crs => panic!("_CRS = {:#x?}", crs),
+    }
+}
+
+#[test]
+fn ovmf_devices() {
+    platform::initialize();
+    let dsdt: Vec<u8> = ovmf_dsdt();
+    let dsdt: syntax::TermList = aml::parse(&dsdt);
+    let root = reference::Node::from(&dsdt);
+    let devices = device::Node::enumerate(&root);
+    assert_eq!(devices.devices().len(), 55);
+    let find = |path: &str| -> &device::Node {
+        let path: name::Path = path.into();
+        devices
+            .devices()
+            .into_iter()
+            .find(|device| device.path() == &path)
+            .unwrap_or_else(|| panic!("{:#x?} isn't enumerated", path))
+    };
+    let pci0: &device::Node = find("\\_SB.PCI0");
+    assert_eq!(pci0.hid(), Some("PNP0A03"));
+    assert_eq!(pci0.uid(), Some("0"));
+    assert_eq!(u32::from(pci0.status()), 0x0f);
+    [
+        ("\\_SB.PCI0.S08.KBD", "PNP0303"),
+        ("\\_SB.PCI0.S08.MOU", "PNP0F13"),
+        ("\\_SB.PCI0.S08.FDC0", "PNP0700"),
+        ("\\_SB.PCI0.S08.LPT1", "PNP0400"),
+        ("\\_SB.PCI0.S08.COM1", "PNP0501"),
+        ("\\_SB.PCI0.S08.COM2", "PNP0501"),
+        ("\\_SB.PCI0.S08.RTC", "PNP0B00"),
+        ("\\_SB.CPUS", "ACPI0010"),
+    ]
+    .into_iter()
+    .for_each(|(path, hid)| {
+        let device: &device::Node = find(path);
+        assert_eq!(device.hid(), Some(hid), "{}", path);
+        assert_eq!(u32::from(device.status()), 0x0f, "{}", path);
+    });
+    let links: Vec<&device::Node> = devices
+        .children()
+        .iter()
+        .filter(|device| device.hid() == Some("PNP0C0F"))
+        .collect();
+    assert_eq!(links.len(), 5);
+    assert!(links.iter().all(|link| u32::from(link.status()) == 0x0b));
+    // HPET is absent because the platform reads zero from its registers.
+    let hpet: &device::Node = find("\\_SB.HPET");
+    assert_eq!(hpet.hid(), Some("PNP0103"));
+    assert_eq!(u32::from(hpet.status()), 0x00);
+}
+
+/// # Declarations and field units in ASL
+/// Each item is the keyword and the declared name, or a field unit, which `iasl -d` and the disassembler print alike.
+fn outline(asl: &str) -> Vec<String> {
+    const DECLARATIONS: [&str; 17] = [
+        "CreateBitField",
+        "CreateByteField",
+        "CreateDWordField",
+        "CreateField",
+        "CreateQWordField",
+        "CreateWordField",
+        "Device",
+        "Event",
+        "Field",
+        "IndexField",
+        "Method",
+        "Mutex",
+        "Name",
+        "OperationRegion",
+        "PowerResource",
+        "Processor",
+        "Scope",
+    ];
+    asl.lines()
+        .map(|line| {
+            line.split("//")
+                .next()
+                .unwrap()
+                .trim()
+                .trim_end_matches(',')
+        })
+        .filter_map(|line| match line.split_once(" (") {
+            Some((keyword, arguments)) if DECLARATIONS.contains(&keyword) => {
+                let (arguments, _): (&str, &str) = arguments.rsplit_once(')')?;
+                let name: &str = if keyword.starts_with("Create") {
+                    arguments.rsplit(',').next().unwrap()
+                } else {
+                    arguments.split(',').next().unwrap()
+                };
+                Some(format!("{} {}", keyword, name.trim()))
+            }
+            Some(("Offset", _)) => Some(line.to_string()),
+            Some(_) => None,
+            None => {
+                let (name, bits): (&str, &str) = line.split_once(',')?;
+                (name
+                    .chars()
+                    .all(|character| character.is_ascii_alphanumeric() || character == '_')
+                    && bits.trim().parse::<usize>().is_ok())
+                .then(|| format!("{}, {}", name, bits.trim()))
+            }
+        })
+        .collect()
+}
+
+/// # Disassembly of the DSDT dumped from QEMU i440fx booted with OVMF
+/// `tests/tables/qemu-i440fx-ovmf-dsdt.dsl` is the output of `iasl -d` 20200925 for the table.
+#[test]
+fn disassembled_i440fx_dsdt() {
+    platform::initialize();
+    let directory: PathBuf = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/tables");
+    let dsdt: Vec<u8> = ovmf_dsdt();
+    let iasl: String = fs::read_to_string(directory.join("qemu-i440fx-ovmf-dsdt.dsl")).unwrap();
+    let asl: String = disassembler::disassemble_table(&dsdt).unwrap();
+    assert_eq!(outline(&asl), outline(&iasl));
+}
+
+/// # Seed corpus of the fuzzer
+/// `tests/corpus` holds the tables above, so a change of them has to be copied there.
+#[test]
+fn seed_corpus() {
+    let directory: PathBuf = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/corpus");
+    [
+        ("cpu-ssdt.aml", cpu_ssdt()),
+        ("i440fx-dsdt.aml", i440fx_dsdt()),
+    ]
+    .into_iter()
+    .for_each(|(file, table)| {
+        assert!(fs::read(directory.join(file)).unwrap() == table, "{}", file);
+    });
+}
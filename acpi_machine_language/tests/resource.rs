@@ -0,0 +1,238 @@
+//! # Resource templates
+//! The templates below are what `iasl` compiles from the ASL in the comments.
+
+mod aml;
+mod platform;
+
+use acpi_machine_language::{
+    name, reference,
+    resource::{
+        self, AddressWidth, Descriptor, InterruptFlags, ResourceSource, ResourceType, SerialBus,
+    },
+    syntax,
+};
+
+const SYSTEM_MEMORY: u8 = 0x00;
+const BYTE_ACC_NO_LOCK_PRESERVE: u8 = 0x01;
+
+/// ```asl
+/// IO (Decode16, 0x03F8, 0x03F8, 0x00, 0x08)
+/// IRQNoFlags () {4}
+/// ```
+const COM1: [u8; 13] = [
+    0x47, 0x01, 0xf8, 0x03, 0xf8, 0x03, 0x00, 0x08, 0x22, 0x10, 0x00, 0x79, 0x00,
+];
+
+/// ```asl
+/// WordBusNumber (ResourceProducer, MinFixed, MaxFixed, PosDecode, 0x0000, 0x0000, 0x00FF, 0x0000, 0x0100)
+/// DWordMemory (ResourceProducer, PosDecode, MinFixed, MaxFixed, Cacheable, ReadWrite, 0x00000000, 0x000A0000, 0x000BFFFF, 0x00000000, 0x00020000)
+/// Memory32Fixed (ReadWrite, 0xFED00000, 0x00000400)
+/// Interrupt (ResourceConsumer, Level, ActiveHigh, Exclusive) {9}
+/// ```
+const HOST_BRIDGE: [u8; 65] = [
+    0x88, 0x0d, 0x00, 0x02, 0x0c, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x00, 0x00, 0x00, 0x00, 0x01,
+    0x87, 0x17, 0x00, 0x00, 0x0c, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0a, 0x00, 0xff, 0xff,
+    0x0b, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x86, 0x09, 0x00, 0x01, 0x00, 0x00,
+    0xd0, 0xfe, 0x00, 0x04, 0x00, 0x00, 0x89, 0x06, 0x00, 0x01, 0x01, 0x09, 0x00, 0x00, 0x00, 0x79,
+    0x00,
+];
+
+/// ```asl
+/// I2cSerialBusV2 (0x001A, ControllerInitiated, 400000, AddressingMode7Bit, "\\_SB.I2C1", 0x00, ResourceConsumer, , Exclusive)
+/// ```
+const CODEC: [u8; 30] = [
+    0x8e, 0x19, 0x00, 0x02, 0x00, 0x01, 0x02, 0x00, 0x00, 0x01, 0x06, 0x00, 0x80, 0x1a, 0x06, 0x00,
+    0x1a, 0x00, 0x5c, 0x5f, 0x53, 0x42, 0x2e, 0x49, 0x32, 0x43, 0x31, 0x00, 0x79, 0x00,
+];
+
+#[test]
+fn small_descriptors() {
+    let descriptors: Vec<Descriptor> = resource::decode(&COM1).unwrap();
+    assert_eq!(
+        descriptors,
+        [
+            Descriptor::Io {
+                decode16: true,
+                minimum: 0x03f8,
+                maximum: 0x03f8,
+                alignment: 0x00,
+                length: 0x08,
+            },
+            Descriptor::Irq {
+                irqs: vec![4],
+                flags: None,
+            },
+        ]
+    );
+    assert_eq!(descriptors[0].io(), Some(0x03f8..0x0400));
+    assert_eq!(
+        descriptors[1].interrupts(),
+        [(4, InterruptFlags::no_flags())]
+    );
+    assert_eq!(resource::encode(&descriptors), COM1);
+}
+
+#[test]
+fn large_descriptors() {
+    let descriptors: Vec<Descriptor> = resource::decode(&HOST_BRIDGE).unwrap();
+    assert_eq!(
+        descriptors[0],
+        Descriptor::Address {
+            width: AddressWidth::Word,
+            resource_type: ResourceType::BusNumber,
+            consumer: false,
+            subtractive_decode: false,
+            minimum_fixed: true,
+            maximum_fixed: true,
+            type_specific_flags: 0x00,
+            granularity: 0x0000,
+            minimum: 0x0000,
+            maximum: 0x00ff,
+            translation_offset: 0x0000,
+            length: 0x0100,
+            resource_source: None,
+        }
+    );
+    assert_eq!(descriptors[1].memory(), Some(0x000a0000..0x000c0000));
+    assert_eq!(descriptors[2].memory(), Some(0xfed00000..0xfed00400));
+    assert_eq!(
+        descriptors[3],
+        Descriptor::ExtendedInterrupt {
+            consumer: true,
+            flags: InterruptFlags::default(),
+            interrupts: vec![9],
+            resource_source: None,
+        }
+    );
+    assert_eq!(resource::encode(&descriptors), HOST_BRIDGE);
+}
+
+#[test]
+fn serial_bus() {
+    let descriptors: Vec<Descriptor> = resource::decode(&CODEC).unwrap();
+    assert_eq!(
+        descriptors,
+        [Descriptor::SerialBus {
+            revision: 2,
+            bus: SerialBus::I2c {
+                connection_speed: 400000,
+                slave_address: 0x1a,
+                vendor_data: Vec::new(),
+            },
+            slave_mode: false,
+            consumer: true,
+            shared: false,
+            type_specific_flags: 0x0000,
+            type_specific_revision: 1,
+            resource_source: ResourceSource {
+                index: 0,
+                name: String::from("\\_SB.I2C1"),
+            },
+        }]
+    );
+    assert_eq!(resource::encode(&descriptors), CODEC);
+}
+
+#[test]
+fn gpio() {
+    let gpio_int = Descriptor::Gpio {
+        revision: 1,
+        interrupt: true,
+        consumer: true,
+        flags: 0x0019,
+        pin_configuration: 1,
+        output_drive_strength: 0,
+        debounce_timeout: 0,
+        pins: vec![18, 19],
+        resource_source: ResourceSource {
+            index: 0,
+            name: String::from("\\_SB.GPO0"),
+        },
+        vendor_data: vec![0xde, 0xad],
+    };
+    let template: Vec<u8> = resource::encode(&[gpio_int.clone()]);
+    // The pin table follows the fixed part of 23 bytes.
+    assert_eq!(&template[14..16], &[23, 0]);
+    assert_eq!(resource::decode(&template).unwrap(), [gpio_int]);
+}
+
+#[test]
+fn truncated_template() {
+    assert!(resource::decode(&COM1[..5]).is_none());
+    assert!(resource::decode(&HOST_BRIDGE[..20]).is_none());
+    assert_eq!(resource::decode(&COM1[..11]).unwrap().len(), 2);
+}
+
+/// # A device whose `_SRS` writes the IRQ mask to an operation region and `_CRS` reads it back
+#[test]
+fn set_resources() {
+    platform::initialize();
+    let irq_mask: fn(u64) -> Vec<u8> =
+        |offset| aml::index(aml::arg(0), aml::integer(offset), aml::zero_target());
+    let dsdt: Vec<u8> = aml::definition_block(
+        "DSDT",
+        2,
+        "KERNEL",
+        "RESOURCE",
+        1,
+        vec![aml::scope(
+            "\\_SB",
+            vec![aml::device(
+                "COM1",
+                vec![
+                    aml::name("_HID", aml::eisa_id("PNP0501")),
+                    aml::name("RSRC", aml::buffer(&COM1)),
+                    aml::operation_region("IRQR", SYSTEM_MEMORY, 0x1000, 2),
+                    aml::field(
+                        "IRQR",
+                        BYTE_ACC_NO_LOCK_PRESERVE,
+                        &[("IRQL", 8), ("IRQH", 8)],
+                    ),
+                    aml::method(
+                        "_CRS",
+                        0,
+                        false,
+                        vec![
+                            aml::store(aml::name_string("RSRC"), aml::local(0)),
+                            aml::store(
+                                aml::name_string("IRQL"),
+                                aml::index(aml::local(0), aml::integer(9), aml::zero_target()),
+                            ),
+                            aml::store(
+                                aml::name_string("IRQH"),
+                                aml::index(aml::local(0), aml::integer(10), aml::zero_target()),
+                            ),
+                            aml::return_(aml::local(0)),
+                        ],
+                    ),
+                    aml::method(
+                        "_PRS",
+                        0,
+                        false,
+                        vec![aml::return_(aml::name_string("RSRC"))],
+                    ),
+                    aml::method(
+                        "_SRS",
+                        1,
+                        false,
+                        vec![
+                            aml::store(irq_mask(9), aml::name_string("IRQL")),
+                            aml::store(irq_mask(10), aml::name_string("IRQH")),
+                        ],
+                    ),
+                ],
+            )],
+        )],
+    );
+    let dsdt: syntax::TermList = aml::parse(&dsdt);
+    let root = reference::Node::from(&dsdt);
+    let com1: name::Path = "\\_SB.COM1".into();
+    let mut descriptors: Vec<Descriptor> = resource::possible(&root, &com1).unwrap();
+    descriptors[1] = Descriptor::Irq {
+        irqs: vec![3],
+        flags: None,
+    };
+    assert!(resource::set(&root, &com1, &descriptors));
+    assert_eq!(resource::current(&root, &com1).unwrap(), descriptors);
+    assert!(!resource::set(&root, &"\\_SB".into(), &descriptors));
+}
@@ -0,0 +1,180 @@
+//! # Synchronization
+//! Each test thread is a context of the host platform, so a mutex acquired by a thread is owned by it.
+//! The DSDT below acquires and releases mutexes and the global lock, and signals, waits for and resets an event from methods.
+
+mod aml;
+mod platform;
+
+use {
+    acpi_machine_language::{interpreter::Value, name, reference, synchronization, syntax},
+    std::thread,
+};
+
+fn dsdt() -> Vec<u8> {
+    let acquire = |method: &str, mutex: &str, timeout: u16| {
+        aml::method(
+            method,
+            0,
+            false,
+            vec![aml::return_(aml::acquire(mutex, timeout))],
+        )
+    };
+    let release =
+        |method: &str, mutex: &str| aml::method(method, 0, false, vec![aml::release(mutex)]);
+    aml::definition_block(
+        "DSDT",
+        2,
+        "KERNEL",
+        "SYNC",
+        1,
+        vec![
+            aml::mutex("MTXH", 0x02),
+            aml::mutex("MTXL", 0x01),
+            aml::mutex("MTXT", 0x00),
+            aml::event("EVT0"),
+            acquire("ACQH", "MTXH", synchronization::WAIT_FOREVER),
+            acquire("ACQL", "MTXL", synchronization::WAIT_FOREVER),
+            release("RELH", "MTXH"),
+            release("RELL", "MTXL"),
+            acquire("ACQT", "MTXT", 0x000a),
+            release("RELT", "MTXT"),
+            acquire("ACGL", "\\_GL", 0x000a),
+            release("RLGL", "\\_GL"),
+            aml::method("SGNL", 0, false, vec![aml::signal("EVT0")]),
+            aml::method("RSET", 0, false, vec![aml::reset("EVT0")]),
+            aml::method(
+                "WAIT",
+                0,
+                false,
+                vec![aml::return_(aml::wait("EVT0", aml::integer(0x0a)))],
+            ),
+        ],
+    )
+}
+
+/// # Evaluate a method of the DSDT in a context
+fn evaluate(method: &str) -> Option<Value> {
+    let dsdt: Vec<u8> = dsdt();
+    let dsdt: syntax::TermList = aml::parse(&dsdt);
+    let root = reference::Node::from(&dsdt);
+    root.evaluate(&method.into(), Vec::new())
+}
+
+#[test]
+fn nested_acquire_by_owner() {
+    platform::initialize();
+    let mutex: name::Path = "\\MTX0".into();
+    assert!(!synchronization::acquire_mutex(&mutex, 0, 0));
+    assert!(!synchronization::acquire_mutex(&mutex, 0, 0));
+    synchronization::release_mutex(&mutex);
+    synchronization::release_mutex(&mutex);
+    assert!(!synchronization::acquire_mutex(&mutex, 0, 0));
+    synchronization::release_mutex(&mutex);
+}
+
+#[test]
+fn mutual_exclusion() {
+    platform::initialize();
+    let mutex: name::Path = "\\MTX1".into();
+    assert!(!synchronization::acquire_mutex(&mutex, 0, 0));
+    let other: name::Path = mutex.clone();
+    let timed_out: bool = thread::spawn(move || synchronization::acquire_mutex(&other, 0, 10))
+        .join()
+        .unwrap();
+    assert!(timed_out);
+    // A context which doesn't own the mutex can't release it.
+    let other: name::Path = mutex.clone();
+    thread::spawn(move || synchronization::release_mutex(&other))
+        .join()
+        .unwrap();
+    let other: name::Path = mutex.clone();
+    assert!(
+        thread::spawn(move || synchronization::acquire_mutex(&other, 0, 0))
+            .join()
+            .unwrap()
+    );
+    synchronization::release_mutex(&mutex);
+    let other: name::Path = mutex.clone();
+    let timed_out: bool = thread::spawn(move || {
+        let timed_out: bool = synchronization::acquire_mutex(&other, 0, 10);
+        synchronization::release_mutex(&other);
+        timed_out
+    })
+    .join()
+    .unwrap();
+    assert!(!timed_out);
+}
+
+/// # Acquiring a mutex of a lower sync level than a held one
+/// Acquire returns Ones as if the timeout occurred, and the mutex stays free.
+#[test]
+fn sync_level_violation() {
+    platform::initialize();
+    assert_eq!(evaluate("\\ACQH"), Some(Value::Zero));
+    assert_eq!(evaluate("\\ACQL"), Some(Value::Ones));
+    evaluate("\\RELH");
+    assert_eq!(evaluate("\\ACQL"), Some(Value::Zero));
+    assert_eq!(evaluate("\\ACQH"), Some(Value::Zero));
+    evaluate("\\RELH");
+    evaluate("\\RELL");
+}
+
+#[test]
+fn acquire_timeout() {
+    platform::initialize();
+    assert_eq!(evaluate("\\ACQT"), Some(Value::Zero));
+    let timed_out: Option<Value> = thread::spawn(|| evaluate("\\ACQT")).join().unwrap();
+    assert_eq!(timed_out, Some(Value::Ones));
+    evaluate("\\RELT");
+    let acquired: Option<Value> = thread::spawn(|| {
+        let acquired: Option<Value> = evaluate("\\ACQT");
+        evaluate("\\RELT");
+        acquired
+    })
+    .join()
+    .unwrap();
+    assert_eq!(acquired, Some(Value::Zero));
+}
+
+#[test]
+fn event() {
+    platform::initialize();
+    assert_eq!(evaluate("\\WAIT"), Some(Value::Ones));
+    evaluate("\\SGNL");
+    assert_eq!(evaluate("\\WAIT"), Some(Value::Zero));
+    assert_eq!(evaluate("\\WAIT"), Some(Value::Ones));
+    // Each signal satisfies one wait until the event is reset.
+    evaluate("\\SGNL");
+    evaluate("\\SGNL");
+    assert_eq!(evaluate("\\WAIT"), Some(Value::Zero));
+    evaluate("\\RSET");
+    assert_eq!(evaluate("\\WAIT"), Some(Value::Ones));
+    // A signal from a context satisfies a wait in another context.
+    evaluate("\\SGNL");
+    let waited: Option<Value> = thread::spawn(|| evaluate("\\WAIT")).join().unwrap();
+    assert_eq!(waited, Some(Value::Zero));
+}
+
+/// # The global lock
+/// `\_GL` is acquired from the firmware through the platform after it is acquired from the other contexts.
+#[test]
+fn global_lock() {
+    let host: &platform::Host = platform::initialize();
+    assert_eq!(evaluate("\\ACGL"), Some(Value::Zero));
+    assert!(host.owns_global_lock());
+    evaluate("\\RLGL");
+    assert!(!host.owns_global_lock());
+    host.set_firmware_global_lock(true);
+    assert_eq!(evaluate("\\ACGL"), Some(Value::Ones));
+    assert!(!host.owns_global_lock());
+    host.set_firmware_global_lock(false);
+    let acquired: Option<Value> = thread::spawn(|| {
+        let acquired: Option<Value> = evaluate("\\ACGL");
+        evaluate("\\RLGL");
+        acquired
+    })
+    .join()
+    .unwrap();
+    assert_eq!(acquired, Some(Value::Zero));
+    assert!(!host.owns_global_lock());
+}
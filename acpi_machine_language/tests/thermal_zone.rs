@@ -0,0 +1,91 @@
+//! # Thermal zones
+//! The DSDT below has a thermal zone under `\_TZ` with all trip points and polling, and another one under `\_SB` which only reports its temperature.
+
+mod aml;
+mod platform;
+
+use acpi_machine_language::{
+    name, reference, syntax,
+    thermal_zone::{Temperature, Zone},
+};
+
+fn dsdt() -> Vec<u8> {
+    aml::definition_block(
+        "DSDT",
+        2,
+        "KERNEL",
+        "THERMAL",
+        1,
+        vec![
+            aml::scope(
+                "\\_TZ",
+                vec![
+                    aml::name("TMPV", aml::integer(3002)),
+                    aml::thermal_zone(
+                        "TZ00",
+                        vec![
+                            aml::method(
+                                "_TMP",
+                                0,
+                                false,
+                                vec![aml::return_(aml::name_string("TMPV"))],
+                            ),
+                            aml::name("_CRT", aml::integer(3732)),
+                            aml::name("_HOT", aml::integer(3632)),
+                            aml::name("_TZP", aml::integer(50)),
+                        ],
+                    ),
+                ],
+            ),
+            aml::scope(
+                "\\_SB",
+                vec![
+                    aml::device("PCI0", vec![aml::name("_ADR", aml::integer(0))]),
+                    aml::thermal_zone("TZ01", vec![aml::name("_TMP", aml::integer(2632))]),
+                ],
+            ),
+        ],
+    )
+}
+
+#[test]
+fn enumerate() {
+    platform::initialize();
+    let dsdt: Vec<u8> = dsdt();
+    let dsdt: syntax::TermList = aml::parse(&dsdt);
+    let root = reference::Node::from(&dsdt);
+    let zones: Vec<name::Path> = Zone::enumerate(&root)
+        .iter()
+        .map(|zone| zone.path().clone())
+        .collect();
+    assert_eq!(zones, ["\\_TZ.TZ00".into(), "\\_SB.TZ01".into()]);
+    assert!(!root
+        .get_local_device_paths()
+        .iter()
+        .any(|device| zones.contains(device)));
+}
+
+#[test]
+fn trip_points() {
+    platform::initialize();
+    let dsdt: Vec<u8> = dsdt();
+    let dsdt: syntax::TermList = aml::parse(&dsdt);
+    let root = reference::Node::from(&dsdt);
+    let [tz00, tz01] = Zone::enumerate(&root).try_into().unwrap();
+    assert_eq!(tz00.temperature(&root), Some(Temperature::from(3002)));
+    assert_eq!(tz00.critical(&root), Some(Temperature::from(3732)));
+    assert_eq!(tz00.hot(&root), Some(Temperature::from(3632)));
+    assert_eq!(tz00.polling_period(&root), Some(50));
+    assert_eq!(tz01.temperature(&root), Some(Temperature::from(2632)));
+    assert_eq!(tz01.critical(&root), None);
+    assert_eq!(tz01.hot(&root), None);
+    assert_eq!(tz01.polling_period(&root), None);
+}
+
+#[test]
+fn display() {
+    assert_eq!(Temperature::from(3002).to_string(), "27.0 C");
+    assert_eq!(Temperature::from(3732).deci_celsius(), 1000);
+    assert_eq!(Temperature::from(2632).to_string(), "-10.0 C");
+    assert_eq!(Temperature::from(2727).to_string(), "-0.5 C");
+}
@@ -0,0 +1,57 @@
+use std::{env, fs, fs::File, io::Write, path::Path};
+
+struct Vendor {
+    id: u16,
+    name: String,
+    devices: Vec<(u16, String)>,
+}
+
+fn main() {
+    let pci_ids = "pci.ids";
+    println!("cargo:rerun-if-changed={}", pci_ids);
+    let pci_ids: String = fs::read_to_string(pci_ids).unwrap();
+    let mut vendors: Vec<Vendor> = Vec::new();
+    for line in pci_ids.lines() {
+        if line.starts_with('#') || line.trim().is_empty() || line.starts_with("\t\t") {
+            continue;
+        }
+        if line.starts_with('C') {
+            // The device classes follow the vendors.
+            break;
+        }
+        let (id, name): (&str, &str) = line.trim_start().split_once("  ").unwrap();
+        let id: u16 = u16::from_str_radix(id, 16).unwrap();
+        let name: String = name.to_string();
+        if line.starts_with('\t') {
+            vendors.last_mut().unwrap().devices.push((id, name));
+        } else {
+            let devices: Vec<(u16, String)> = Vec::new();
+            vendors.push(Vendor { id, name, devices });
+        }
+    }
+    vendors.sort_by_key(|vendor| vendor.id);
+    vendors
+        .iter_mut()
+        .for_each(|vendor| vendor.devices.sort_by_key(|(id, _name)| *id));
+    let out_dir: String = env::var("OUT_DIR").unwrap();
+    let ids = Path::new(&out_dir).join("pci_ids.rs");
+    let mut ids = File::create(ids).unwrap();
+    writeln!(&mut ids, "static VENDORS: &[Vendor] = &[").unwrap();
+    for vendor in vendors.iter() {
+        writeln!(&mut ids, "    Vendor {{").unwrap();
+        writeln!(&mut ids, "        id: {:#06x},", vendor.id).unwrap();
+        writeln!(&mut ids, "        name: {:?},", vendor.name).unwrap();
+        writeln!(&mut ids, "        devices: &[").unwrap();
+        for (id, name) in vendor.devices.iter() {
+            writeln!(
+                &mut ids,
+                "            Device {{ id: {:#06x}, name: {:?} }},",
+                id, name
+            )
+            .unwrap();
+        }
+        writeln!(&mut ids, "        ],").unwrap();
+        writeln!(&mut ids, "    }},").unwrap();
+    }
+    writeln!(&mut ids, "];").unwrap();
+}
@@ -4,8 +4,12 @@
 
 pub mod boot_graphics_resource;
 pub mod debug_port;
+pub mod device;
 pub mod differentiated_system_description;
 pub mod direct_memory_access_remapping;
+pub mod embedded_controller;
+pub mod embedded_controller_boot_resources;
+pub mod event;
 pub mod extended_system_description;
 pub mod firmware_acpi_control;
 pub mod firmware_performance_data;
@@ -18,9 +22,12 @@ pub mod memory_mapped_configuration;
 pub mod multiple_apic_description;
 pub mod root_system_description;
 pub mod secondary_system_description;
+pub mod sleep;
 pub mod static_resource_affinity;
 pub mod system_description;
+pub mod thermal;
 pub mod trusted_platform_module;
+pub mod watchdog;
 pub mod watchdog_action;
 pub mod windows_acpi_emulated_devices;
 pub mod windows_smm_security_mitigations;
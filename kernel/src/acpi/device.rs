@@ -0,0 +1,56 @@
+//! # ACPI Device Drivers
+//! Devices in the namespace are bound to drivers by `_HID` and `_CID`.
+//! ## References
+//! * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 6.1 Device Identification Objects
+
+mod ac_adapter;
+mod keyboard;
+mod power_button;
+mod serial;
+
+use {
+    super::machine_language::{self, device},
+    crate::com2_println,
+};
+
+static DRIVERS: [&dyn Driver; 4] = [
+    &ac_adapter::AcAdapter,
+    &keyboard::Keyboard,
+    &power_button::PowerButton,
+    &serial::Serial,
+];
+
+pub trait Driver: Sync {
+    /// # Start handling a device
+    fn bind(&self, device: &device::Node);
+    /// # IDs of devices the driver handles
+    fn ids(&self) -> &[&str];
+    fn name(&self) -> &str;
+}
+
+/// # Enumerate devices and bind drivers to them
+/// `_HID` is matched before `_CID`s, and a device which is not present is not bound.
+pub fn initialize() {
+    let devices = device::Node::enumerate(machine_language::root());
+    devices
+        .devices()
+        .into_iter()
+        .filter(|device| device.status().present())
+        .for_each(|device| {
+            let driver: Option<&dyn Driver> = device.ids().find_map(|id| {
+                DRIVERS
+                    .iter()
+                    .find(|driver| driver.ids().contains(&id))
+                    .copied()
+            });
+            if let Some(driver) = driver {
+                com2_println!(
+                    "{} binds {:?} (_HID {})",
+                    driver.name(),
+                    device.path(),
+                    device.hid().unwrap_or("none")
+                );
+                driver.bind(device);
+            }
+        });
+}
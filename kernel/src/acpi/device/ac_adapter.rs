@@ -0,0 +1,56 @@
+//! # AC Adapter
+//! ## References
+//! * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 10.3 AC Adapters and Power Source Objects
+
+use {
+    super::{device, machine_language, Driver},
+    crate::com2_println,
+    alloc::vec::Vec,
+    core::sync::atomic::{AtomicBool, Ordering},
+};
+
+/// # Notification that the power source status changed
+const STATUS_CHANGED: u8 = 0x80;
+
+static ONLINE: AtomicBool = AtomicBool::new(true);
+
+/// # Whether the system runs on the AC adapter
+#[allow(dead_code)]
+pub fn is_online() -> bool {
+    ONLINE.load(Ordering::Relaxed)
+}
+
+pub struct AcAdapter;
+
+impl Driver for AcAdapter {
+    fn bind(&self, device: &device::Node) {
+        update(device.path());
+        machine_language::notification::register(device.path(), notify);
+    }
+
+    fn ids(&self) -> &[&str] {
+        &["ACPI0003"]
+    }
+
+    fn name(&self) -> &str {
+        "AC adapter"
+    }
+}
+
+fn notify(device: &machine_language::name::Path, value: u8) {
+    if value == STATUS_CHANGED {
+        update(device);
+    }
+}
+
+/// # Read `_PSR` (Power Source)
+fn update(device: &machine_language::name::Path) {
+    let psr: machine_language::name::Path = device.clone() + "_PSR".into();
+    if let Some(online) = machine_language::root()
+        .evaluate(&psr, Vec::new())
+        .map(|psr| (&psr).into())
+    {
+        ONLINE.store(online, Ordering::Relaxed);
+        com2_println!("AC adapter online = {}", online);
+    }
+}
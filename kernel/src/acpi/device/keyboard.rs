@@ -0,0 +1,22 @@
+//! # PS/2 Keyboard
+
+use {
+    super::{device, Driver},
+    crate::com2_println,
+};
+
+pub struct Keyboard;
+
+impl Driver for Keyboard {
+    fn bind(&self, device: &device::Node) {
+        com2_println!("PS/2 keyboard {:#x?}", device.path());
+    }
+
+    fn ids(&self) -> &[&str] {
+        &["PNP0303"]
+    }
+
+    fn name(&self) -> &str {
+        "keyboard"
+    }
+}
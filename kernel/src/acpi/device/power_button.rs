@@ -0,0 +1,34 @@
+//! # Control Method Power Button
+//! ## References
+//! * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 4.8.2.2.1.2 Control Method Power Button
+
+use {
+    super::{device, machine_language, Driver},
+    crate::{acpi::event, com2_println},
+};
+
+/// # Notification that the power button is pressed
+const PRESSED: u8 = 0x80;
+
+pub struct PowerButton;
+
+impl Driver for PowerButton {
+    fn bind(&self, device: &device::Node) {
+        machine_language::notification::register(device.path(), notify);
+    }
+
+    fn ids(&self) -> &[&str] {
+        &["PNP0C0C"]
+    }
+
+    fn name(&self) -> &str {
+        "power button"
+    }
+}
+
+fn notify(device: &machine_language::name::Path, value: u8) {
+    if value == PRESSED {
+        com2_println!("The power button {:#x?} is pressed.", device);
+        event::press_power_button();
+    }
+}
@@ -0,0 +1,31 @@
+//! # 16550 Compatible Serial Port
+
+use {
+    super::{device, Driver},
+    crate::{acpi::machine_language, com2_println},
+    alloc::vec::Vec,
+};
+
+pub struct Serial;
+
+impl Driver for Serial {
+    /// `_UID` of a serial port is the COM port number counted from 1 on most firmware.
+    fn bind(&self, device: &device::Node) {
+        let resources: Option<Vec<machine_language::resource::Descriptor>> =
+            machine_language::resource::current(machine_language::root(), device.path());
+        com2_println!(
+            "COM{} {:#x?} {:#x?}",
+            device.uid().unwrap_or("?"),
+            device.path(),
+            resources
+        );
+    }
+
+    fn ids(&self) -> &[&str] {
+        &["PNP0500", "PNP0501"]
+    }
+
+    fn name(&self) -> &str {
+        "serial"
+    }
+}
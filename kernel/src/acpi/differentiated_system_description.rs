@@ -21,6 +21,10 @@ impl Table {
         unsafe { slice::from_raw_parts(definition_block, definition_block_size) }
     }
 
+    pub fn header(&self) -> &system_description::Header {
+        &self.header
+    }
+
     pub fn is_correct(&self) -> bool {
         self.header.is_correct()
     }
@@ -1,8 +1,8 @@
 mod acpi_namespace_device_declaration;
 mod hardware_static_affinity;
-mod hardware_unit_definition;
+pub mod hardware_unit_definition;
 mod other;
-mod reserved_memory_region;
+pub mod reserved_memory_region;
 mod root_port_ats_capability;
 mod soc_integrated;
 
@@ -25,10 +25,38 @@ pub struct Table {
 }
 
 impl Table {
+    /// # DMA Remapping Hardware Unit Definitions
+    pub fn hardware_units(&self) -> impl Iterator<Item = &hardware_unit_definition::Structure> {
+        self.iter().filter_map(|structure| match structure {
+            Structure::Drhd(structure) => Some(structure),
+            _ => None,
+        })
+    }
+
+    /// # The maximum DMA physical addressability in bits
+    pub fn host_address_width(&self) -> u8 {
+        self.host_address_width + 1
+    }
+
     pub fn is_correct(&self) -> bool {
         self.header.is_correct()
     }
 
+    /// # Reserved Memory Regions which have to be identity mapped for their devices
+    pub fn reserved_memory_regions(
+        &self,
+    ) -> impl Iterator<Item = &reserved_memory_region::Structure> {
+        self.iter().filter_map(|structure| match structure {
+            Structure::Rmrr(structure) => Some(structure),
+            _ => None,
+        })
+    }
+
+    pub fn supports_interrupt_remapping(&self) -> bool {
+        let flags: Flags = self.flags;
+        flags.intr_remap()
+    }
+
     fn bytes(&self) -> &[u8] {
         let table: *const Self = self as *const Self;
         let table: *const Self = unsafe { table.add(1) };
@@ -21,10 +21,28 @@ pub struct Structure {
 }
 
 impl Structure {
+    /// # Whether the unit remaps all the devices in its segment which no other unit reports
+    pub fn includes_pci_all(&self) -> bool {
+        let flags: Flags = self.flags;
+        flags.include_pci_all()
+    }
+
     pub fn length(&self) -> usize {
         self.length as usize
     }
 
+    pub fn register_base_address(&self) -> usize {
+        self.register_base_address as usize
+    }
+
+    pub fn scopes(&self) -> Scopes<'_> {
+        self.into()
+    }
+
+    pub fn segment_number(&self) -> u16 {
+        self.segment_number
+    }
+
     fn bytes(&self) -> &[u8] {
         let structure: *const Self = self as *const Self;
         let first_byte: *const Self = unsafe { structure.add(1) };
@@ -32,10 +50,6 @@ impl Structure {
         let size: usize = self.length() - size_of::<Self>();
         unsafe { slice::from_raw_parts(first_byte, size) }
     }
-
-    fn iter(&self) -> Scopes<'_> {
-        self.into()
-    }
 }
 
 impl fmt::Debug for Structure {
@@ -46,7 +60,7 @@ impl fmt::Debug for Structure {
         let size: u8 = self.size;
         let segment_number: u16 = self.segment_number;
         let register_base_address: u64 = self.register_base_address;
-        let scopes: Vec<&scope::Structure> = self.iter().collect();
+        let scopes: Vec<&scope::Structure> = self.scopes().collect();
         formatter
             .debug_struct("Structure")
             .field("structure_type", &structure_type)
@@ -17,6 +17,20 @@ pub struct Structure {
 }
 
 impl Structure {
+    /// # The enumeration ID of an I/O APIC, an HPET or an ACPI namespace device
+    pub fn enumeration_id(&self) -> u8 {
+        self.enumeration_id
+    }
+
+    /// # Device and function numbers from the start bus to the device
+    pub fn path_entries(&self) -> impl Iterator<Item = (u8, u8)> + '_ {
+        self.path().iter().map(|path_entry| {
+            let device: u8 = *path_entry as u8;
+            let function: u8 = (*path_entry >> u8::BITS) as u8;
+            (device, function)
+        })
+    }
+
     pub fn scan(bytes: &[u8]) -> Option<(&Self, &[u8])> {
         bytes.first().map(|structure| {
             let structure: *const u8 = structure as *const u8;
@@ -27,6 +41,14 @@ impl Structure {
         })
     }
 
+    pub fn scope_type(&self) -> Type {
+        self.structure_type.into()
+    }
+
+    pub fn start_bus_number(&self) -> u8 {
+        self.start_bus_number
+    }
+
     fn length(&self) -> usize {
         self.length as usize
     }
@@ -69,3 +91,29 @@ struct Flags {
     #[bits(3)]
     __: u8,
 }
+
+/// # Device Scope Entry Type
+/// ## References
+/// * [Intel Virtualization Technology for Directed I/O](https://software.intel.com/content/dam/develop/external/us/en/documents-tps/vt-directed-io-spec.pdf) 8.3.1 Device Scope Structure
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Type {
+    PciEndpoint,
+    PciSubHierarchy,
+    IoApic,
+    Hpet,
+    AcpiNamespaceDevice,
+    Reserved(u8),
+}
+
+impl From<u8> for Type {
+    fn from(scope_type: u8) -> Self {
+        match scope_type {
+            0x01 => Self::PciEndpoint,
+            0x02 => Self::PciSubHierarchy,
+            0x03 => Self::IoApic,
+            0x04 => Self::Hpet,
+            0x05 => Self::AcpiNamespaceDevice,
+            scope_type => Self::Reserved(scope_type),
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use {
     super::hardware_unit_definition,
     alloc::vec::Vec,
-    core::{fmt, mem::size_of, slice},
+    core::{fmt, mem::size_of, ops::RangeInclusive, slice},
 };
 
 /// # Reserved Memory Region Reporting Structure
@@ -30,9 +30,19 @@ impl Structure {
         self.length as usize
     }
 
-    fn iter(&self) -> hardware_unit_definition::Scopes<'_> {
+    pub fn range(&self) -> RangeInclusive<usize> {
+        let base_address: usize = self.base_address as usize;
+        let limit_address: usize = self.limit_address as usize;
+        base_address..=limit_address
+    }
+
+    pub fn scopes(&self) -> hardware_unit_definition::Scopes<'_> {
         self.into()
     }
+
+    pub fn segment_number(&self) -> u16 {
+        self.segment_number
+    }
 }
 
 impl fmt::Debug for Structure {
@@ -42,7 +52,7 @@ impl fmt::Debug for Structure {
         let segment_number: u16 = self.segment_number;
         let base_address: u64 = self.base_address;
         let limit_address: u64 = self.limit_address;
-        let scopes: Vec<&hardware_unit_definition::scope::Structure> = self.iter().collect();
+        let scopes: Vec<&hardware_unit_definition::scope::Structure> = self.scopes().collect();
         formatter
             .debug_struct("Structure")
             .field("structure_type", &structure_type)
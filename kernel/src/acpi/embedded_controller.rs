@@ -0,0 +1,178 @@
+//! # ACPI Embedded Controller
+//! The command protocol is implemented by the interpreter crate, and this module finds the embedded controller and dispatches its queries.
+//! ## References
+//! * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 12 ACPI Embedded Controller Interface Specification
+
+use {
+    super::machine_language::{self, interpreter::Value},
+    crate::{com2_println, sync::spin, Argument},
+    alloc::{format, string::String, vec, vec::Vec},
+};
+
+const HARDWARE_ID: &str = "PNP0C09";
+
+static CONTROLLER: spin::Lock<Option<Controller>> = spin::Lock::new(None);
+
+/// # GPE number of the embedded controller SCI
+pub fn gpe() -> Option<u8> {
+    CONTROLLER
+        .lock()
+        .as_ref()
+        .and_then(|controller| controller.gpe)
+}
+
+/// # Find the embedded controller
+/// The ECDT is preferred because it is available before the namespace is loaded.
+/// Otherwise, the namespace is searched for a `PNP0C09` device.
+pub fn initialize() {
+    let root: &machine_language::reference::Node = machine_language::root();
+    let controller: Option<Controller> = Argument::get()
+        .efi_system_table()
+        .rsdp()
+        .xsdt()
+        .ecdt()
+        .map(|ecdt| {
+            let path: machine_language::name::Path = ecdt.ec_id().into();
+            let global_lock: bool = Controller::evaluate_global_lock(root, &path);
+            Controller {
+                gpe: Some(ecdt.gpe_bit()),
+                interface: machine_language::embedded_controller::Controller::new(
+                    ecdt.command_port(),
+                    ecdt.data_port(),
+                    global_lock,
+                ),
+                path,
+            }
+        })
+        .or_else(|| {
+            machine_language::device::Node::enumerate(root)
+                .devices()
+                .into_iter()
+                .find(|device| device.is_compatible(HARDWARE_ID))
+                .and_then(|device| Controller::from_namespace(root, device.path().clone()))
+        });
+    com2_println!("embedded controller = {:#x?}", controller);
+    let path: Option<machine_language::name::Path> = controller
+        .as_ref()
+        .map(|controller| controller.path.clone());
+    *CONTROLLER.lock() = controller;
+    if let Some(path) = path {
+        // Tell AML that the EmbeddedControl operation region handler is available.
+        let reg: machine_language::name::Path = path + "_REG".into();
+        root.evaluate(&reg, vec![Value::Byte(0x03), Value::One]);
+    }
+}
+
+/// # Handle SCI events of the embedded controller
+/// Each pending query value `xx` is dispatched to the `_Qxx` method of the embedded controller.
+pub fn process_queries(root: &machine_language::reference::Node) {
+    while let Some((path, query)) = {
+        let controller: spin::Guard<Option<Controller>> = CONTROLLER.lock();
+        controller.as_ref().and_then(|controller| {
+            controller
+                .interface
+                .query()
+                .map(|query| (controller.path.clone(), query))
+        })
+    } {
+        let method: String = format!("_Q{:02X}", query);
+        let method: machine_language::name::Path = path + method.as_str().into();
+        root.evaluate(&method, Vec::new());
+    }
+}
+
+pub fn read_u8(address: u8) -> u8 {
+    read_bytes(address, 1) as u8
+}
+
+pub fn read_u16(address: u8) -> u16 {
+    read_bytes(address, 2) as u16
+}
+
+pub fn read_u32(address: u8) -> u32 {
+    read_bytes(address, 4) as u32
+}
+
+pub fn read_u64(address: u8) -> u64 {
+    read_bytes(address, 8)
+}
+
+pub fn write_u8(address: u8, value: u8) {
+    write_bytes(address, 1, value as u64);
+}
+
+pub fn write_u16(address: u8, value: u16) {
+    write_bytes(address, 2, value as u64);
+}
+
+pub fn write_u32(address: u8, value: u32) {
+    write_bytes(address, 4, value as u64);
+}
+
+pub fn write_u64(address: u8, value: u64) {
+    write_bytes(address, 8, value);
+}
+
+fn read_bytes(address: u8, length: usize) -> u64 {
+    CONTROLLER.lock().as_ref().map_or(u64::MAX, |controller| {
+        controller.interface.read(address, length)
+    })
+}
+
+fn write_bytes(address: u8, length: usize, value: u64) {
+    if let Some(controller) = CONTROLLER.lock().as_ref() {
+        controller.interface.write(address, length, value);
+    }
+}
+
+#[derive(Debug)]
+struct Controller {
+    gpe: Option<u8>,
+    interface: machine_language::embedded_controller::Controller,
+    path: machine_language::name::Path,
+}
+
+impl Controller {
+    fn evaluate_global_lock(
+        root: &machine_language::reference::Node,
+        path: &machine_language::name::Path,
+    ) -> bool {
+        let glk: machine_language::name::Path = path.clone() + "_GLK".into();
+        root.evaluate(&glk, Vec::new())
+            .map_or(false, |glk| (&glk).into())
+    }
+
+    /// # Find ports from `_CRS` of the `PNP0C09` device
+    /// The first I/O port descriptor is the data port and the second one is the command port.
+    /// ## References
+    /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 6.4.2.5 I/O Port Descriptor
+    /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 6.4.2.6 Fixed Location I/O Port Descriptor
+    fn from_namespace(
+        root: &machine_language::reference::Node,
+        path: machine_language::name::Path,
+    ) -> Option<Self> {
+        let ports: Vec<u16> = machine_language::resource::current(root, &path)?
+            .iter()
+            .filter_map(|descriptor| descriptor.io())
+            .map(|ports| ports.start as u16)
+            .collect();
+        let gpe: machine_language::name::Path = path.clone() + "_GPE".into();
+        let gpe: Option<u8> = root
+            .evaluate(&gpe, Vec::new())
+            .as_ref()
+            .and_then(|gpe| gpe.get_byte());
+        let global_lock: bool = Self::evaluate_global_lock(root, &path);
+        ports
+            .first()
+            .zip(ports.get(1))
+            .map(|(data_port, command_port)| Self {
+                gpe,
+                interface: machine_language::embedded_controller::Controller::new(
+                    *command_port,
+                    *data_port,
+                    global_lock,
+                ),
+                path,
+            })
+    }
+}
@@ -0,0 +1,68 @@
+use {
+    super::{generic_address, system_description},
+    core::{fmt, mem::size_of, slice, str},
+};
+
+/// # ECDT
+/// ## References
+/// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 5.2.16 Embedded Controller Boot Resources Table (ECDT)
+#[repr(packed)]
+pub struct Table {
+    header: system_description::Header,
+    ec_control: generic_address::Structure,
+    ec_data: generic_address::Structure,
+    uid: u32,
+    gpe_bit: u8,
+}
+
+impl Table {
+    pub fn command_port(&self) -> u16 {
+        let ec_control: generic_address::Structure = self.ec_control;
+        ec_control.address() as u16
+    }
+
+    pub fn data_port(&self) -> u16 {
+        let ec_data: generic_address::Structure = self.ec_data;
+        ec_data.address() as u16
+    }
+
+    pub fn ec_id(&self) -> &str {
+        let table: *const Self = self as *const Self;
+        let table: usize = table as usize;
+        let ec_id: usize = table + size_of::<Self>();
+        let ec_id: *const u8 = ec_id as *const u8;
+        let ec_id_size: usize = self.header.table_size() - size_of::<Self>();
+        let ec_id: &[u8] = unsafe { slice::from_raw_parts(ec_id, ec_id_size) };
+        let ec_id_size: usize = ec_id
+            .iter()
+            .position(|byte| *byte == 0)
+            .unwrap_or(ec_id_size);
+        str::from_utf8(&ec_id[..ec_id_size]).unwrap()
+    }
+
+    pub fn gpe_bit(&self) -> u8 {
+        self.gpe_bit
+    }
+
+    pub fn is_correct(&self) -> bool {
+        self.header.is_correct()
+    }
+}
+
+impl fmt::Debug for Table {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let header: system_description::Header = self.header;
+        let ec_control: generic_address::Structure = self.ec_control;
+        let ec_data: generic_address::Structure = self.ec_data;
+        let uid: u32 = self.uid;
+        formatter
+            .debug_struct("Table")
+            .field("header", &header)
+            .field("ec_control", &ec_control)
+            .field("ec_data", &ec_data)
+            .field("uid", &uid)
+            .field("gpe_bit", &self.gpe_bit)
+            .field("ec_id", &self.ec_id())
+            .finish()
+    }
+}
@@ -0,0 +1,218 @@
+//! # ACPI Events
+//! Fixed events in the PM1 status registers and general-purpose events in the GPE blocks raise the SCI.
+//! The SCI handler takes pending events from the hardware, and the kernel loop processes them.
+//! ## References
+//! * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 4.8.3.1 PM1 Event Grouping
+//! * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 4.8.4.1 General-Purpose Event Register Blocks
+//! * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 5.6.4 General-Purpose Event Handling
+
+use {
+    super::{
+        embedded_controller,
+        fixed_acpi_description::{self, gpe, pm1},
+        machine_language, multiple_apic_description, thermal,
+    },
+    crate::{com2_println, interrupt, Argument},
+    alloc::{format, string::String, vec::Vec},
+    core::sync::atomic::{AtomicBool, Ordering},
+};
+
+static POWER_BUTTON: AtomicBool = AtomicBool::new(false);
+static SLEEP_BUTTON: AtomicBool = AtomicBool::new(false);
+
+/// # Enable the ACPI mode and the SCI
+/// GPEs are enabled if they have `_Lxx` or `_Exx` methods or belong to the embedded controller.
+pub fn initialize(local_apic_id: u8) {
+    let fadt: &mut fixed_acpi_description::Table = Argument::get()
+        .efi_system_table_mut()
+        .rsdp_mut()
+        .xsdt_mut()
+        .fadt_mut();
+    if !fadt.enable_acpi_mode() {
+        com2_println!("The firmware doesn't enter the ACPI mode.");
+        return;
+    }
+    let root: &machine_language::reference::Node = machine_language::root();
+    let embedded_controller_gpe: Option<u16> = embedded_controller::gpe().map(u16::from);
+    fadt.gpe_blocks().iter().for_each(|block| {
+        block.gpes().for_each(|gpe| {
+            block.enable(gpe, false);
+            block.clear_status(gpe);
+            let enable: bool = handler(root, gpe).is_some() || embedded_controller_gpe == Some(gpe);
+            block.enable(gpe, enable);
+        })
+    });
+    fadt.enable_fixed_events();
+    let sci_int: u16 = fadt.sci_int();
+    let madt: &mut multiple_apic_description::Table = Argument::get()
+        .efi_system_table_mut()
+        .rsdp_mut()
+        .xsdt_mut()
+        .madt_mut();
+    // The SCI is a sharable, level triggered and active low interrupt unless it is overridden.
+    let (global_system_interrupt, polarity, trigger_mode): (
+        u32,
+        interrupt::apic::Polarity,
+        interrupt::apic::TriggerMode,
+    ) = madt.interrupt_source_override(sci_int as u8).map_or(
+        (
+            sci_int as u32,
+            interrupt::apic::Polarity::ActiveLow,
+            interrupt::apic::TriggerMode::Level,
+        ),
+        |interrupt_source_override| {
+            (
+                interrupt_source_override.global_system_interrupt(),
+                interrupt_source_override
+                    .polarity()
+                    .unwrap_or(interrupt::apic::Polarity::ActiveLow),
+                interrupt_source_override
+                    .trigger_mode()
+                    .unwrap_or(interrupt::apic::TriggerMode::Level),
+            )
+        },
+    );
+    let io_apic = madt.io_apic_mut();
+    let irq: u32 = global_system_interrupt - io_apic.global_system_interrupt_base();
+    io_apic.registers_mut().redirect_with_signal(
+        irq as u8,
+        local_apic_id,
+        interrupt::SCI_INTERRUPT,
+        polarity,
+        trigger_mode,
+    );
+}
+
+/// # Disable GPEs so that no SCI interrupts the teardown
+/// The fixed power button stays enabled to wake the system from S5.
+pub fn finalize() {
+    Argument::get()
+        .efi_system_table()
+        .rsdp()
+        .xsdt()
+        .fadt()
+        .gpe_blocks()
+        .iter()
+        .for_each(|block| block.gpes().for_each(|gpe| block.enable(gpe, false)));
+}
+
+/// # Whether the power button has been pressed
+pub fn is_power_button_pressed() -> bool {
+    POWER_BUTTON.load(Ordering::Acquire)
+}
+
+/// # Request a graceful shutdown
+/// Called for the fixed power button and the control method power button.
+pub fn press_power_button() {
+    POWER_BUTTON.store(true, Ordering::Release);
+}
+
+/// # Whether the sleep button has been pressed since the last call
+pub fn take_sleep_button() -> bool {
+    SLEEP_BUTTON.swap(false, Ordering::AcqRel)
+}
+
+/// # Request a suspend
+pub fn press_sleep_button() {
+    SLEEP_BUTTON.store(true, Ordering::Release);
+}
+
+/// # Events taken in the SCI handler
+#[derive(Debug)]
+pub struct Sci {
+    fixed_events: pm1::status::Register,
+    gpes: Vec<u16>,
+}
+
+impl Sci {
+    /// # Take pending events from the hardware
+    /// Fixed events are cleared, and pending GPEs are disabled until they are processed so that a level triggered GPE doesn't raise the SCI again.
+    pub fn take() -> Self {
+        let fadt: &mut fixed_acpi_description::Table = Argument::get()
+            .efi_system_table_mut()
+            .rsdp_mut()
+            .xsdt_mut()
+            .fadt_mut();
+        let fixed_events: pm1::status::Register = fadt.take_fixed_events();
+        let gpes: Vec<u16> = fadt
+            .gpe_blocks()
+            .iter()
+            .flat_map(|block| {
+                let gpes: Vec<u16> = block.pending();
+                gpes.iter().for_each(|gpe| block.enable(*gpe, false));
+                gpes
+            })
+            .collect();
+        Self { fixed_events, gpes }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fixed_events.is_empty() && self.gpes.is_empty()
+    }
+
+    /// # Process the events
+    /// An edge triggered GPE is cleared before its method is evaluated, and a level triggered one is cleared after.
+    pub fn process(self) {
+        let Self { fixed_events, gpes } = self;
+        if fixed_events.power_button() {
+            com2_println!("The power button is pressed.");
+            press_power_button();
+        }
+        if fixed_events.sleep_button() {
+            com2_println!("The sleep button is pressed.");
+            press_sleep_button();
+        }
+        if fixed_events.rtc() {
+            com2_println!("The RTC alarm fired.");
+            thermal::alarm();
+        }
+        let root: &machine_language::reference::Node = machine_language::root();
+        let blocks: Vec<gpe::Block> = Argument::get()
+            .efi_system_table()
+            .rsdp()
+            .xsdt()
+            .fadt()
+            .gpe_blocks();
+        let embedded_controller_gpe: Option<u16> = embedded_controller::gpe().map(u16::from);
+        gpes.into_iter().for_each(|gpe| {
+            if let Some(block) = blocks.iter().find(|block| block.gpes().contains(&gpe)) {
+                match handler(root, gpe) {
+                    Some((method, level_triggered)) => {
+                        if !level_triggered {
+                            block.clear_status(gpe);
+                        }
+                        root.evaluate(&method, Vec::new());
+                        if level_triggered {
+                            block.clear_status(gpe);
+                        }
+                    }
+                    None => {
+                        block.clear_status(gpe);
+                        if embedded_controller_gpe == Some(gpe) {
+                            embedded_controller::process_queries(root);
+                        } else {
+                            com2_println!("GPE {:#x} has no handler.", gpe);
+                        }
+                    }
+                }
+                block.enable(gpe, true);
+            }
+        });
+    }
+}
+
+/// # Find the `_Lxx` or `_Exx` method of a GPE
+/// Returns the method and whether the GPE is level triggered.
+fn handler(
+    root: &machine_language::reference::Node,
+    gpe: u16,
+) -> Option<(machine_language::name::Path, bool)> {
+    [('L', true), ('E', false)]
+        .into_iter()
+        .find_map(|(prefix, level_triggered)| {
+            let method: String = format!("\\_GPE._{}{:02X}", prefix, gpe);
+            let method: machine_language::name::Path = method.as_str().into();
+            root.get_method(&method)
+                .map(|_method| (method, level_triggered))
+        })
+}
@@ -1,7 +1,9 @@
 use {
     super::{
-        fixed_acpi_description, high_precision_event_timer, multiple_apic_description,
-        secondary_system_description, system_description,
+        direct_memory_access_remapping, embedded_controller_boot_resources, fixed_acpi_description,
+        high_precision_event_timer, low_power_idle, memory_mapped_configuration,
+        multiple_apic_description, secondary_system_description, system_description,
+        watchdog_action,
     },
     alloc::vec::Vec,
     core::{fmt, mem::size_of, slice},
@@ -16,6 +18,27 @@ pub struct Table {
 }
 
 impl Table {
+    pub fn dmar(&self) -> Option<&direct_memory_access_remapping::Table> {
+        self.bytes()
+            .chunks(size_of::<usize>())
+            .find_map(|entry_address_bytes| {
+                let entry: usize = entry_address_bytes
+                    .iter()
+                    .rev()
+                    .fold(0usize, |entry_address, byte| {
+                        (entry_address << u8::BITS) + (*byte as usize)
+                    });
+                let header: *const system_description::Header =
+                    entry as *const system_description::Header;
+                let header: &system_description::Header = unsafe { &*header };
+                (header.signature() == "DMAR").then(|| {
+                    let table: *const direct_memory_access_remapping::Table =
+                        entry as *const direct_memory_access_remapping::Table;
+                    unsafe { &*table }
+                })
+            })
+    }
+
     pub fn entries(&self) -> Vec<system_description::Table<'_>> {
         self.bytes()
             .chunks(size_of::<usize>())
@@ -34,6 +57,27 @@ impl Table {
             .collect()
     }
 
+    pub fn ecdt(&self) -> Option<&embedded_controller_boot_resources::Table> {
+        self.bytes()
+            .chunks(size_of::<usize>())
+            .find_map(|entry_address_bytes| {
+                let entry: usize = entry_address_bytes
+                    .iter()
+                    .rev()
+                    .fold(0usize, |entry_address, byte| {
+                        (entry_address << u8::BITS) + (*byte as usize)
+                    });
+                let header: *const system_description::Header =
+                    entry as *const system_description::Header;
+                let header: &system_description::Header = unsafe { &*header };
+                (header.signature() == "ECDT").then(|| {
+                    let table: *const embedded_controller_boot_resources::Table =
+                        entry as *const embedded_controller_boot_resources::Table;
+                    unsafe { &*table }
+                })
+            })
+    }
+
     pub fn fadt(&self) -> &fixed_acpi_description::Table {
         self.bytes()
             .chunks(size_of::<usize>())
@@ -78,6 +122,36 @@ impl Table {
             .unwrap()
     }
 
+    /// # Find a table by its signature, OEM ID and OEM table ID
+    /// An empty OEM ID or OEM table ID matches any table.
+    pub fn find_table(
+        &self,
+        signature: &str,
+        oem_id: &str,
+        oem_table_id: &str,
+    ) -> Option<&system_description::Header> {
+        let padding: &[char] = &[' ', '\0'];
+        self.bytes()
+            .chunks(size_of::<usize>())
+            .map(|entry_address_bytes| {
+                let entry: usize = entry_address_bytes
+                    .iter()
+                    .rev()
+                    .fold(0usize, |entry_address, byte| {
+                        (entry_address << u8::BITS) + (*byte as usize)
+                    });
+                let header: *const system_description::Header =
+                    entry as *const system_description::Header;
+                unsafe { &*header }
+            })
+            .find(|header| {
+                header.signature() == signature
+                    && (oem_id.is_empty() || header.oemid().trim_end_matches(padding) == oem_id)
+                    && (oem_table_id.is_empty()
+                        || header.oem_table_id().trim_end_matches(padding) == oem_table_id)
+            })
+    }
+
     pub fn hpet(&self) -> &high_precision_event_timer::Table {
         self.bytes()
             .chunks(size_of::<usize>())
@@ -122,6 +196,26 @@ impl Table {
             .unwrap()
     }
 
+    pub fn lpit(&self) -> Option<&low_power_idle::Table> {
+        self.bytes()
+            .chunks(size_of::<usize>())
+            .find_map(|entry_address_bytes| {
+                let entry: usize = entry_address_bytes
+                    .iter()
+                    .rev()
+                    .fold(0usize, |entry_address, byte| {
+                        (entry_address << u8::BITS) + (*byte as usize)
+                    });
+                let header: *const system_description::Header =
+                    entry as *const system_description::Header;
+                let header: &system_description::Header = unsafe { &*header };
+                (header.signature() == "LPIT").then(|| {
+                    let table: *const low_power_idle::Table = entry as *const low_power_idle::Table;
+                    unsafe { &*table }
+                })
+            })
+    }
+
     pub fn is_correct(&self) -> bool {
         self.header.is_correct() && self.entries().iter().all(|entry| entry.is_correct())
     }
@@ -170,6 +264,27 @@ impl Table {
             .unwrap()
     }
 
+    pub fn mcfg(&self) -> Option<&memory_mapped_configuration::Table> {
+        self.bytes()
+            .chunks(size_of::<usize>())
+            .find_map(|entry_address_bytes| {
+                let entry: usize = entry_address_bytes
+                    .iter()
+                    .rev()
+                    .fold(0usize, |entry_address, byte| {
+                        (entry_address << u8::BITS) + (*byte as usize)
+                    });
+                let header: *const system_description::Header =
+                    entry as *const system_description::Header;
+                let header: &system_description::Header = unsafe { &*header };
+                (header.signature() == "MCFG").then(|| {
+                    let table: *const memory_mapped_configuration::Table =
+                        entry as *const memory_mapped_configuration::Table;
+                    unsafe { &*table }
+                })
+            })
+    }
+
     pub fn ssdt(&self) -> &secondary_system_description::Table {
         self.bytes()
             .chunks(size_of::<usize>())
@@ -192,6 +307,27 @@ impl Table {
             .unwrap()
     }
 
+    pub fn wdat(&self) -> Option<&watchdog_action::Table> {
+        self.bytes()
+            .chunks(size_of::<usize>())
+            .find_map(|entry_address_bytes| {
+                let entry: usize = entry_address_bytes
+                    .iter()
+                    .rev()
+                    .fold(0usize, |entry_address, byte| {
+                        (entry_address << u8::BITS) + (*byte as usize)
+                    });
+                let header: *const system_description::Header =
+                    entry as *const system_description::Header;
+                let header: &system_description::Header = unsafe { &*header };
+                (header.signature() == "WDAT").then(|| {
+                    let table: *const watchdog_action::Table =
+                        entry as *const watchdog_action::Table;
+                    unsafe { &*table }
+                })
+            })
+    }
+
     fn bytes(&self) -> &[u8] {
         let table: *const Self = self as *const Self;
         let table: usize = table as usize;
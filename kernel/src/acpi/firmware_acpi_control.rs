@@ -1,6 +1,9 @@
 use {
     bitfield_struct::bitfield,
-    core::{fmt, str},
+    core::{
+        fmt, ptr, str,
+        sync::atomic::{AtomicU32, Ordering},
+    },
 };
 
 /// # FACS
@@ -18,6 +21,54 @@ pub struct Structure {
 }
 
 impl Structure {
+    /// # Try to acquire the global lock
+    /// Returns true if the lock is acquired.
+    /// Otherwise, the pending bit is set and the firmware raises GBL_STS when it releases the lock.
+    /// ## References
+    /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 5.2.10.1.1 Global Lock Usage Example
+    pub fn acquire_global_lock(&self) -> bool {
+        let global_lock: &AtomicU32 = self.global_lock_atomic();
+        let previous: u32 = global_lock
+            .fetch_update(Ordering::Acquire, Ordering::Relaxed, |global_lock| {
+                let global_lock: GlobalLock = global_lock.into();
+                let owned: bool = global_lock.owned();
+                Some(global_lock.with_owned(true).with_pending(owned).into())
+            })
+            .unwrap();
+        let previous: GlobalLock = previous.into();
+        !previous.owned()
+    }
+
+    /// # Release the global lock
+    /// Returns true if the firmware is waiting for the lock.
+    /// In this case, the caller has to set GBL_RLS in the PM1 control register.
+    /// ## References
+    /// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 5.2.10.1.1 Global Lock Usage Example
+    pub fn release_global_lock(&self) -> bool {
+        let global_lock: &AtomicU32 = self.global_lock_atomic();
+        let previous: u32 = global_lock
+            .fetch_update(Ordering::Release, Ordering::Relaxed, |global_lock| {
+                let global_lock: GlobalLock = global_lock.into();
+                Some(global_lock.with_owned(false).with_pending(false).into())
+            })
+            .unwrap();
+        let previous: GlobalLock = previous.into();
+        previous.pending()
+    }
+
+    /// # Set the firmware waking vector
+    /// X_Firmware_Waking_Vector is cleared because it takes precedence over the 32-bit vector entered in the real mode.
+    pub fn set_firmware_waking_vector(&mut self, firmware_waking_vector: u32) {
+        self.firmware_waking_vendor = firmware_waking_vector;
+        self.x_firmware_waking_vendor = 0;
+    }
+
+    fn global_lock_atomic(&self) -> &AtomicU32 {
+        let global_lock: *const GlobalLock = ptr::addr_of!(self.global_lock);
+        let global_lock: *mut u32 = global_lock as *mut u32;
+        unsafe { AtomicU32::from_ptr(global_lock) }
+    }
+
     fn signature(&self) -> &str {
         str::from_utf8(self.signature.as_slice()).unwrap()
     }
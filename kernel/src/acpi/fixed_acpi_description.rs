@@ -1,17 +1,10 @@
+pub mod gpe;
 pub mod pm1;
 
 use {
-    super::{
-        firmware_acpi_control, generic_address,
-        machine_language::{
-            self,
-            interpreter::Evaluator,
-            syntax::{FirstReader, ReaderOutsideMethod},
-        },
-        system_description,
-    },
-    crate::{io, x64},
-    alloc::vec,
+    super::{firmware_acpi_control, generic_address, machine_language, sleep, system_description},
+    crate::{io, timer, x64},
+    alloc::vec::Vec,
     bitfield_struct::bitfield,
     core::{fmt, mem},
 };
@@ -83,6 +76,161 @@ pub struct Table {
 }
 
 impl Table {
+    /// # Try to acquire the global lock
+    /// Returns true if the lock is acquired or there is no FACS to share the lock with the firmware.
+    pub fn acquire_global_lock(&self) -> bool {
+        self.firmware_ctrl()
+            .map_or(true, |firmware_ctrl| firmware_ctrl.acquire_global_lock())
+    }
+
+    /// # Release the global lock
+    /// If the firmware is waiting for the lock, GBL_RLS is set to notify it.
+    pub fn release_global_lock(&mut self) {
+        let pending: bool = self
+            .firmware_ctrl()
+            .map_or(false, |firmware_ctrl| firmware_ctrl.release_global_lock());
+        if pending {
+            if let Some(pm1a_control) = self.read_pm1a_control() {
+                self.write_pm1a_control(pm1a_control.release_global_lock());
+            }
+        }
+    }
+
+    /// # Enable the ACPI mode
+    /// Writing ACPI_ENABLE to SMI_CMD makes the firmware hand over the power management hardware and set SCI_EN.
+    /// Returns false if SCI_EN is not set in time.
+    pub fn enable_acpi_mode(&mut self) -> bool {
+        const TIMEOUT_MILLISECONDS: usize = 0x100;
+        let is_sci_enabled = |fadt: &Self| {
+            fadt.read_pm1a_control()
+                .map_or(false, |pm1a_control| pm1a_control.is_sci_enabled())
+        };
+        if is_sci_enabled(self) {
+            return true;
+        }
+        if self.smi_cmd == 0 || self.acpi_enable == 0 {
+            return false;
+        }
+        x64::port::outb(self.smi_cmd as u16, self.acpi_enable);
+        (0..TIMEOUT_MILLISECONDS).any(|_| {
+            is_sci_enabled(self) || {
+                timer::acpi::wait_milliseconds(1);
+                false
+            }
+        })
+    }
+
+    /// # Enable the power button, sleep button and RTC fixed events
+    /// A button which is a control method device by the FADT flags is left to its driver.
+    pub fn enable_fixed_events(&mut self) {
+        let flags: Flags = self.flags;
+        let power_button: bool = !flags.pwr_button();
+        let sleep_button: bool = !flags.slp_button();
+        let rtc: bool = !flags.fix_rtc();
+        if let Some(pm1a_enable) = self.read_pm1a_enable() {
+            self.write_pm1a_enable(pm1a_enable.enable_fixed_events(
+                power_button,
+                sleep_button,
+                rtc,
+            ));
+        }
+        if let Some(pm1b_enable) = self.read_pm1b_enable() {
+            self.write_pm1b_enable(pm1b_enable.enable_fixed_events(
+                power_button,
+                sleep_button,
+                rtc,
+            ));
+        }
+    }
+
+    /// # GPE0 and GPE1 blocks
+    /// X_GPE0_BLK and X_GPE1_BLK are preferred to GPE0_BLK and GPE1_BLK if they are not zero.
+    /// GPE numbers in the GPE1 block start from GPE1_BASE.
+    pub fn gpe_blocks(&self) -> Vec<gpe::Block> {
+        [
+            (self.x_gpe0_blk().or(self.gpe0_blk()), self.gpe0_blk_len, 0),
+            (
+                self.x_gpe1_blk().or(self.gpe1_blk()),
+                self.gpe1_blk_len,
+                self.gpe1_base,
+            ),
+        ]
+        .into_iter()
+        .filter(|(_address, length, _base)| *length != 0)
+        .filter_map(|(address, length, base)| {
+            address.map(|address| gpe::Block::new(address, length, base as u16))
+        })
+        .collect()
+    }
+
+    /// # Reset the system through RESET_REG
+    /// Returns false if the reset register is not supported.
+    pub fn reset(&self) -> bool {
+        let flags: Flags = self.flags;
+        let supported: bool = Self::reser_value_offset() < self.header.table_size()
+            && flags.reser_reg_sup()
+            && !self.reset_reg.is_null();
+        if supported {
+            let mut reset_reg: generic_address::Structure = self.reset_reg;
+            reset_reg.write_byte(self.reser_value);
+        }
+        supported
+    }
+
+    /// # The I/O port of PM1a_STS to read BM_STS
+    /// Only a PM1 event block in the system I/O space is returned because application processors read it as well.
+    pub fn bus_master_status_port(&self) -> Option<u16> {
+        self.x_pm1a_evt_blk()
+            .or(self.pm1a_evt_blk())
+            .filter(|pm1a_evt_blk| {
+                pm1a_evt_blk.space_id() == generic_address::SpaceId::SystemIoSpace
+            })
+            .map(|pm1a_evt_blk| pm1a_evt_blk.address() as u16)
+    }
+
+    /// # The I/O port of PM2_CNT to write ARB_DIS
+    pub fn arbiter_port(&self) -> Option<u16> {
+        let pm2_cnt_blk: Option<generic_address::Structure> = (Self::pm2_cnt_blk_offset()
+            < self.header.table_size())
+        .then_some(self.pm2_cnt_blk)
+        .filter(|pm2_cnt_blk| *pm2_cnt_blk != 0 && self.pm2_cnt_len != 0)
+        .map(|pm2_cnt_blk| generic_address::Structure::system_io(pm2_cnt_blk as u16, 1));
+        let x_pm2_cnt_blk: Option<generic_address::Structure> = (Self::x_pm2_cnt_blk_offset()
+            < self.header.table_size())
+        .then_some(self.x_pm2_cnt_blk)
+        .filter(|x_pm2_cnt_blk| x_pm2_cnt_blk.address() != 0);
+        x_pm2_cnt_blk
+            .or(pm2_cnt_blk)
+            .filter(|pm2_cnt_blk| pm2_cnt_blk.space_id() == generic_address::SpaceId::SystemIoSpace)
+            .map(|pm2_cnt_blk| pm2_cnt_blk.address() as u16)
+    }
+
+    pub fn sci_int(&self) -> u16 {
+        self.sci_int
+    }
+
+    /// # Take enabled fixed events
+    /// The status bits of the taken events are cleared.
+    pub fn take_fixed_events(&mut self) -> pm1::status::Register {
+        let pm1a_status: Option<pm1::status::Register> = self
+            .read_pm1a_status()
+            .zip(self.read_pm1a_enable())
+            .map(|(status, enable)| status.enabled(enable));
+        let pm1b_status: Option<pm1::status::Register> = self
+            .read_pm1b_status()
+            .zip(self.read_pm1b_enable())
+            .map(|(status, enable)| status.enabled(enable));
+        if let Some(pm1a_status) = pm1a_status {
+            self.write_pm1a_status(pm1a_status);
+        }
+        if let Some(pm1b_status) = pm1b_status {
+            self.write_pm1b_status(pm1b_status);
+        }
+        let pm1a_status: u16 = pm1a_status.map_or(0, u16::from);
+        let pm1b_status: u16 = pm1b_status.map_or(0, u16::from);
+        (pm1a_status | pm1b_status).into()
+    }
+
     pub fn timer(&self) -> Option<io::Mapped> {
         (self.pm_tmr_len == 4).then(|| {
             let self_address: *const Self = self as *const Self;
@@ -113,66 +261,52 @@ impl Table {
         self.header.is_correct() && self.dsdt().map_or(true, |dsdt| dsdt.is_correct())
     }
 
+    /// # Enter S5
     pub fn shutdown(&mut self) {
-        let dsdt: system_description::Table = self.dsdt().unwrap();
-        let dsdt: &[u8] = dsdt.definition_block();
-        let mut semantic_tree = machine_language::name::Node::default();
-        let root_path = machine_language::name::Path::root();
-        let (mut syntax_tree, unread_dsdt): (machine_language::syntax::TermList, &[u8]) =
-            machine_language::syntax::TermList::first_read(dsdt, &mut semantic_tree, &root_path);
-        assert!(unread_dsdt.is_empty());
-        syntax_tree.read_outside_method(&mut semantic_tree, &root_path);
-        let reference_tree: machine_language::reference::Node = (&syntax_tree).into();
-        let stack_frame = machine_language::interpreter::StackFrame::default()
-            .set_arguments(vec![machine_language::interpreter::Value::Byte(0x05)]);
-        let tts_path: machine_language::name::Path = "\\_TTS".into();
-        let tts: Option<&machine_language::syntax::DefMethod> =
-            reference_tree.get_method(&tts_path);
-        if let Some(tts) = tts {
-            tts.evaluate(&mut stack_frame.clone(), &reference_tree, &tts_path);
+        let root: &machine_language::reference::Node = machine_language::root();
+        if let Some(sleep_type) = sleep::prepare(root, sleep::State::S5) {
+            self.enter_sleep_state(sleep_type);
         }
-        let pts_path: machine_language::name::Path = "\\_PTS".into();
-        let pts: Option<&machine_language::syntax::DefMethod> =
-            reference_tree.get_method(&pts_path);
-        if let Some(pts) = pts {
-            pts.evaluate(&mut stack_frame.clone(), &reference_tree, &pts_path);
+        loop {
+            x64::pause();
         }
-        let pm1a_control: Option<pm1::control::Register> = self.read_pm1a_control();
-        let pm1b_control: Option<pm1::control::Register> = self.read_pm1b_control();
-        let s5: machine_language::name::Path = "\\_S5".into();
-        let s5: Option<&machine_language::syntax::DefName> = reference_tree.get_name(&s5);
-        let s5: Option<machine_language::interpreter::Value> = s5.and_then(|s5| {
-            s5.evaluate(
-                &mut machine_language::interpreter::StackFrame::default(),
-                &reference_tree,
-                &root_path,
-            )
-        });
-        let pm1a_cnt_slp_typ: Option<u8> = s5
-            .as_ref()
-            .and_then(|s5| s5.index(&machine_language::interpreter::Value::QWord(0)))
-            .and_then(|pm1a_cnt_slp_typ| pm1a_cnt_slp_typ.get_byte());
-        let pm1b_cnt_slp_typ: Option<u8> = s5
-            .as_ref()
-            .and_then(|s5| s5.index(&machine_language::interpreter::Value::QWord(1)))
-            .and_then(|pm1b_cnt_slp_typ| pm1b_cnt_slp_typ.get_byte());
-        if let Some((pm1a_control, pm1a_cnt_slp_typ)) = pm1a_control.zip(pm1a_cnt_slp_typ) {
-            self.write_pm1a_control(pm1a_control.sleep(pm1a_cnt_slp_typ));
+    }
+
+    /// # Enter a sleeping state
+    /// WAK_STS is cleared and the caches are flushed before SLP_TYP and SLP_EN are written.
+    /// Returns when WAK_STS is set, that is, when the hardware doesn't sleep or wakes without losing the processor context.
+    pub fn enter_sleep_state(&mut self, sleep_type: sleep::Type) {
+        self.write_pm1a_status(pm1::status::Register::clear_wake());
+        self.write_pm1b_status(pm1::status::Register::clear_wake());
+        x64::wbinvd();
+        if let Some(pm1a_control) = self.read_pm1a_control() {
+            self.write_pm1a_control(pm1a_control.sleep(sleep_type.pm1a));
         }
-        if let Some((pm1b_control, pm1b_cnt_slp_typ)) = pm1b_control.zip(pm1b_cnt_slp_typ) {
-            self.write_pm1b_control(pm1b_control.sleep(pm1b_cnt_slp_typ));
+        if let Some(pm1b_control) = self.read_pm1b_control() {
+            self.write_pm1b_control(pm1b_control.sleep(sleep_type.pm1b));
         }
-        loop {
+        while !self
+            .read_pm1a_status()
+            .map_or(false, |pm1a_status| pm1a_status.is_woken())
+        {
             x64::pause();
         }
     }
 
+    /// # Set the address which the firmware jumps to in the real mode on waking
+    /// Returns false if there is no FACS.
+    pub fn set_firmware_waking_vector(&mut self, firmware_waking_vector: u32) -> bool {
+        self.firmware_ctrl_mut()
+            .map(|firmware_ctrl| firmware_ctrl.set_firmware_waking_vector(firmware_waking_vector))
+            .is_some()
+    }
+
     pub fn timer_bits(&self) -> usize {
         let flags: Flags = self.flags;
         flags.timer_bits()
     }
 
-    fn dsdt(&self) -> Option<system_description::Table> {
+    pub fn dsdt(&self) -> Option<system_description::Table> {
         let dsdt: Option<usize> = (44 <= self.header.table_size()).then_some(self.dsdt as usize);
         let x_dsdt: Option<usize> =
             (148 <= self.header.table_size()).then_some(self.x_dsdt as usize);
@@ -189,20 +323,45 @@ impl Table {
     }
 
     fn firmware_ctrl(&self) -> Option<&firmware_acpi_control::Structure> {
+        self.firmware_ctrl_address().map(|firmware_ctrl| {
+            let firmware_ctrl: *const firmware_acpi_control::Structure =
+                firmware_ctrl as *const firmware_acpi_control::Structure;
+            unsafe { &*firmware_ctrl }
+        })
+    }
+
+    fn firmware_ctrl_address(&self) -> Option<usize> {
         let firmware_ctrl: Option<usize> =
             (40 <= self.header.table_size()).then_some(self.firmware_ctrl as usize);
         let x_firmware_ctrl: Option<usize> =
             (140 <= self.header.table_size()).then_some(self.x_firmware_ctrl as usize);
         firmware_ctrl
-            .iter()
-            .chain(x_firmware_ctrl.iter())
+            .into_iter()
+            .chain(x_firmware_ctrl)
             .max()
-            .filter(|firmware_ctrl| **firmware_ctrl != 0)
-            .map(|firmware_ctrl| {
-                let firmware_ctrl: *const firmware_acpi_control::Structure =
-                    (*firmware_ctrl) as *const firmware_acpi_control::Structure;
-                unsafe { &*firmware_ctrl }
-            })
+            .filter(|firmware_ctrl| *firmware_ctrl != 0)
+    }
+
+    fn firmware_ctrl_mut(&mut self) -> Option<&mut firmware_acpi_control::Structure> {
+        self.firmware_ctrl_address().map(|firmware_ctrl| {
+            let firmware_ctrl: *mut firmware_acpi_control::Structure =
+                firmware_ctrl as *mut firmware_acpi_control::Structure;
+            unsafe { &mut *firmware_ctrl }
+        })
+    }
+
+    fn gpe0_blk(&self) -> Option<generic_address::Structure> {
+        (Self::gpe0_blk_offset() < self.header.table_size())
+            .then_some(self.gpe0_blk)
+            .filter(|gpe0_blk| *gpe0_blk != 0)
+            .map(|gpe0_blk| generic_address::Structure::system_io(gpe0_blk as u16, 1))
+    }
+
+    fn gpe1_blk(&self) -> Option<generic_address::Structure> {
+        (Self::gpe1_blk_offset() < self.header.table_size())
+            .then_some(self.gpe1_blk)
+            .filter(|gpe1_blk| *gpe1_blk != 0)
+            .map(|gpe1_blk| generic_address::Structure::system_io(gpe1_blk as u16, 1))
     }
 
     fn pm1a_cnt_blk(&self) -> Option<generic_address::Structure> {
@@ -257,7 +416,6 @@ impl Table {
             .map(|pm1b_cnt_blk| pm1b_cnt_blk.read_word().into())
     }
 
-    #[allow(dead_code)]
     fn read_pm1a_enable(&self) -> Option<pm1::enable::Register> {
         self.x_pm1a_evt_blk()
             .or(self.pm1a_evt_blk())
@@ -269,7 +427,6 @@ impl Table {
             })
     }
 
-    #[allow(dead_code)]
     fn read_pm1b_enable(&self) -> Option<pm1::enable::Register> {
         self.x_pm1b_evt_blk()
             .or(self.pm1b_evt_blk())
@@ -281,14 +438,12 @@ impl Table {
             })
     }
 
-    #[allow(dead_code)]
     fn read_pm1a_status(&self) -> Option<pm1::status::Register> {
         self.x_pm1a_evt_blk()
             .or(self.pm1a_evt_blk())
             .map(|pm1a_evt_blk| pm1a_evt_blk.read_word().into())
     }
 
-    #[allow(dead_code)]
     fn read_pm1b_status(&self) -> Option<pm1::status::Register> {
         self.x_pm1b_evt_blk()
             .or(self.pm1b_evt_blk())
@@ -307,6 +462,48 @@ impl Table {
         }
     }
 
+    fn write_pm1a_enable(&mut self, pm1a_en: pm1::enable::Register) {
+        if let Some(pm1a_evt_blk) = self.x_pm1a_evt_blk().or(self.pm1a_evt_blk()) {
+            pm1a_evt_blk
+                .add((self.pm1_evt_len as usize) / 2)
+                .write_word(pm1a_en.into());
+        }
+    }
+
+    fn write_pm1b_enable(&mut self, pm1b_en: pm1::enable::Register) {
+        if let Some(pm1b_evt_blk) = self.x_pm1b_evt_blk().or(self.pm1b_evt_blk()) {
+            pm1b_evt_blk
+                .add((self.pm1_evt_len as usize) / 2)
+                .write_word(pm1b_en.into());
+        }
+    }
+
+    /// Status bits are cleared by writing 1.
+    fn write_pm1a_status(&mut self, pm1a_sts: pm1::status::Register) {
+        if let Some(mut pm1a_evt_blk) = self.x_pm1a_evt_blk().or(self.pm1a_evt_blk()) {
+            pm1a_evt_blk.write_word(pm1a_sts.into());
+        }
+    }
+
+    /// Status bits are cleared by writing 1.
+    fn write_pm1b_status(&mut self, pm1b_sts: pm1::status::Register) {
+        if let Some(mut pm1b_evt_blk) = self.x_pm1b_evt_blk().or(self.pm1b_evt_blk()) {
+            pm1b_evt_blk.write_word(pm1b_sts.into());
+        }
+    }
+
+    fn x_gpe0_blk(&self) -> Option<generic_address::Structure> {
+        (Self::x_gpe0_blk_offset() < self.header.table_size())
+            .then_some(self.x_gpe0_blk)
+            .filter(|x_gpe0_blk| x_gpe0_blk.address() != 0)
+    }
+
+    fn x_gpe1_blk(&self) -> Option<generic_address::Structure> {
+        (Self::x_gpe1_blk_offset() < self.header.table_size())
+            .then_some(self.x_gpe1_blk)
+            .filter(|x_gpe1_blk| x_gpe1_blk.address() != 0)
+    }
+
     fn x_pm1a_cnt_blk(&self) -> Option<generic_address::Structure> {
         (Self::x_pm1a_cnt_blk_offset() < self.header.table_size())
             .then_some(self.x_pm1a_cnt_blk)
@@ -0,0 +1,74 @@
+use {super::super::generic_address, alloc::vec::Vec, core::ops::Range};
+
+/// # General-Purpose Event Register Block
+/// The first half of the block is the status registers and the second half is the enable registers.
+/// Each register is accessed a byte at a time in the address space of the block.
+/// ## References
+/// * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 4.8.4.1 General-Purpose Event Register Blocks
+#[derive(Clone, Copy, Debug)]
+pub struct Block {
+    address: generic_address::Structure,
+    length: u8,
+    base: u16,
+}
+
+impl Block {
+    pub fn new(address: generic_address::Structure, length: u8, base: u16) -> Self {
+        Self {
+            address,
+            length,
+            base,
+        }
+    }
+
+    /// # Status bits are cleared by writing 1
+    pub fn clear_status(&self, gpe: u16) {
+        let (index, mask): (u16, u8) = self.register(gpe);
+        self.status_register(index).write_byte(mask);
+    }
+
+    pub fn enable(&self, gpe: u16, enable: bool) {
+        let (index, mask): (u16, u8) = self.register(gpe);
+        let mut register: generic_address::Structure = self.enable_register(index);
+        let enables: u8 = register.read_byte();
+        let enables: u8 = if enable {
+            enables | mask
+        } else {
+            enables & !mask
+        };
+        register.write_byte(enables);
+    }
+
+    pub fn gpes(&self) -> Range<u16> {
+        let registers: u16 = (self.length / 2) as u16;
+        self.base..self.base + registers * (u8::BITS as u16)
+    }
+
+    /// # GPEs whose status and enable bits are both set
+    pub fn pending(&self) -> Vec<u16> {
+        self.gpes()
+            .filter(|gpe| {
+                let (index, mask): (u16, u8) = self.register(*gpe);
+                let status: u8 = self.status_register(index).read_byte();
+                let enable: u8 = self.enable_register(index).read_byte();
+                status & enable & mask != 0
+            })
+            .collect()
+    }
+
+    fn enable_register(&self, index: u16) -> generic_address::Structure {
+        self.address
+            .byte((self.length / 2) as usize + index as usize)
+    }
+
+    fn register(&self, gpe: u16) -> (u16, u8) {
+        let bit: u16 = gpe - self.base;
+        let index: u16 = bit / (u8::BITS as u16);
+        let mask: u8 = 1 << (bit % (u8::BITS as u16));
+        (index, mask)
+    }
+
+    fn status_register(&self, index: u16) -> generic_address::Structure {
+        self.address.byte(index as usize)
+    }
+}
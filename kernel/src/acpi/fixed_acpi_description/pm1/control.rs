@@ -19,6 +19,14 @@ pub struct Register {
 }
 
 impl Register {
+    pub fn is_sci_enabled(&self) -> bool {
+        self.sci_en()
+    }
+
+    pub fn release_global_lock(self) -> Self {
+        self.with_gbl_rls(true)
+    }
+
     pub fn sleep(self, slp_typx: u8) -> Self {
         self.with_slp_typx(slp_typx).with_slp_en(true)
     }
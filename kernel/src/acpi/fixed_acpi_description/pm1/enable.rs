@@ -19,3 +19,11 @@ pub struct Register {
     pciexp_wake_dis: bool,
     __: bool,
 }
+
+impl Register {
+    pub fn enable_fixed_events(self, power_button: bool, sleep_button: bool, rtc: bool) -> Self {
+        self.with_pwrbtn_en(power_button)
+            .with_slpbtn_en(sleep_button)
+            .with_rtc_en(rtc)
+    }
+}
@@ -1,4 +1,4 @@
-use bitfield_struct::bitfield;
+use {super::enable, bitfield_struct::bitfield};
 
 /// # PM1 Status Register
 /// ## References
@@ -21,3 +21,43 @@ pub struct Register {
     pciexp_wake_sts: bool,
     wak_sts: bool,
 }
+
+impl Register {
+    /// # A value which clears WAK_STS by writing it
+    pub fn clear_wake() -> Self {
+        Self::new().with_wak_sts(true)
+    }
+
+    /// # Fixed events whose enable bits are set
+    /// The bits of the status register and the enable register are at the same positions.
+    pub fn enabled(self, enable: enable::Register) -> Self {
+        let fixed_events: u16 = Self::new()
+            .with_pwrbtn_sts(true)
+            .with_slpbtn_sts(true)
+            .with_rtc_sts(true)
+            .into();
+        let status: u16 = self.into();
+        let enable: u16 = enable.into();
+        (status & enable & fixed_events).into()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        u16::from(*self) == 0
+    }
+
+    pub fn is_woken(&self) -> bool {
+        self.wak_sts()
+    }
+
+    pub fn power_button(&self) -> bool {
+        self.pwrbtn_sts()
+    }
+
+    pub fn rtc(&self) -> bool {
+        self.rtc_sts()
+    }
+
+    pub fn sleep_button(&self) -> bool {
+        self.slpbtn_sts()
+    }
+}
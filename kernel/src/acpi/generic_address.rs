@@ -1,4 +1,12 @@
-use {crate::x64::port, core::mem};
+use {
+    crate::{pci, x64::port},
+    core::mem,
+};
+
+#[derive(Debug)]
+pub enum Error {
+    InvalidAccessSize(usize),
+}
 
 /// # Generic Address Structure
 /// ## References
@@ -14,8 +22,13 @@ pub struct Structure {
 }
 
 impl Structure {
+    /// # Access size in bytes
+    /// Access size 0 is undefined for legacy reasons, so the register bit width is used instead.
     pub fn access_size(&self) -> usize {
-        1 << (self.access_size - 1)
+        match self.access_size {
+            0 => (self.register_bit_width as usize).div_ceil(u8::BITS as usize),
+            access_size => 1 << (access_size - 1),
+        }
     }
 
     pub fn add(self, offset: usize) -> Self {
@@ -40,6 +53,25 @@ impl Structure {
         self.address as usize
     }
 
+    /// # The byte register at an offset in the same address space
+    pub fn byte(self, offset: usize) -> Self {
+        let Self {
+            address_space_id,
+            address,
+            ..
+        } = self.add(offset);
+        let register_bit_width: u8 = u8::BITS as u8;
+        let register_bit_offset: u8 = 0;
+        let access_size: u8 = 1;
+        Self {
+            address_space_id,
+            register_bit_width,
+            register_bit_offset,
+            access_size,
+            address,
+        }
+    }
+
     pub fn get<T>(&self) -> &T {
         let address_space_id: SpaceId = self.address_space_id.into();
         assert_eq!(address_space_id, SpaceId::SystemMemorySpace);
@@ -60,6 +92,41 @@ impl Structure {
         self.address == 0
     }
 
+    pub fn register_bit_offset(&self) -> u8 {
+        self.register_bit_offset
+    }
+
+    pub fn register_bit_width(&self) -> u8 {
+        self.register_bit_width
+    }
+
+    pub fn space_id(&self) -> SpaceId {
+        self.address_space_id.into()
+    }
+
+    /// # The PCI configuration address on bus 0 and the bit offset of the byte in the dword
+    /// The address has the device in bits 32 to 47, the function in bits 16 to 31 and the register in bits 0 to 15.
+    fn pci_address(&self) -> (pci::Address, u32) {
+        let device: u8 = (self.address >> 32) as u8;
+        let function: u8 = (self.address >> 16) as u8;
+        let register: u8 = self.address as u8;
+        let aligned_register: u8 = register & !(mem::size_of::<u32>() as u8 - 1);
+        let shift: u32 = u8::BITS * ((register - aligned_register) as u32);
+        let address = pci::Address::create(0, device, function, aligned_register);
+        (address, shift)
+    }
+
+    /// # Read the register in its access size
+    pub fn read(&self) -> Result<u64, Error> {
+        match self.access_size() {
+            1 => Ok(self.read_byte() as u64),
+            2 => Ok(self.read_word() as u64),
+            4 => Ok(self.read_dword() as u64),
+            8 => Ok(self.read_qword()),
+            access_size => Err(Error::InvalidAccessSize(access_size)),
+        }
+    }
+
     pub fn read_byte(&self) -> u8 {
         assert_eq!(self.access_size(), 1);
         match self.address_space_id.into() {
@@ -143,6 +210,18 @@ impl Structure {
         }
     }
 
+    /// # Write the register in its access size
+    pub fn write(&mut self, data: u64) -> Result<(), Error> {
+        match self.access_size() {
+            1 => self.write_byte(data as u8),
+            2 => self.write_word(data as u16),
+            4 => self.write_dword(data as u32),
+            8 => self.write_qword(data),
+            access_size => return Err(Error::InvalidAccessSize(access_size)),
+        }
+        Ok(())
+    }
+
     pub fn write_byte(&mut self, data: u8) {
         assert_eq!(self.access_size(), 1);
         match self.address_space_id.into() {
@@ -157,6 +236,12 @@ impl Structure {
                 let port: u16 = self.address as u16;
                 port::outb(port, data);
             }
+            SpaceId::PciConfigurationSpace => {
+                let (address, shift): (pci::Address, u32) = self.pci_address();
+                let mask: u32 = (u8::MAX as u32) << shift;
+                let dword: u32 = (address.read() & !mask) | ((data as u32) << shift);
+                address.write(dword);
+            }
             _ => unimplemented!(),
         }
     }
@@ -1,4 +1,4 @@
-mod native_c_state_instruction;
+pub mod native_c_state_instruction;
 mod other;
 
 use {
@@ -20,6 +20,18 @@ impl Table {
         self.header.is_correct()
     }
 
+    /// # Native C-State instruction based LPI structures which are not disabled
+    pub fn native_c_states(&self) -> Vec<&native_c_state_instruction::Structure> {
+        self.iter()
+            .filter_map(|state_structure| match state_structure {
+                StateStructure::NativeCStateInstruction(structure) => {
+                    (!structure.is_disabled()).then_some(structure)
+                }
+                StateStructure::Other(_) => None,
+            })
+            .collect()
+    }
+
     fn bytes(&self) -> &[u8] {
         let table: *const Self = self as *const Self;
         let table: *const Self = unsafe { table.add(1) };
@@ -12,13 +12,9 @@ pub struct Structure {
     #[allow(dead_code)]
     unique_id: u16,
     __: u16,
-    #[allow(dead_code)]
     flags: Flags,
-    #[allow(dead_code)]
     entry_trigger: generic_address::Structure,
-    #[allow(dead_code)]
     residency: u32,
-    #[allow(dead_code)]
     latency: u32,
     #[allow(dead_code)]
     residency_counter: generic_address::Structure,
@@ -27,9 +23,29 @@ pub struct Structure {
 }
 
 impl Structure {
+    /// # The register to enter the state
+    pub fn entry_trigger(&self) -> generic_address::Structure {
+        self.entry_trigger
+    }
+
+    pub fn is_disabled(&self) -> bool {
+        let flags: Flags = self.flags;
+        flags.disabled()
+    }
+
+    /// # Worst case exit latency in microseconds
+    pub fn latency(&self) -> u32 {
+        self.latency
+    }
+
     pub fn length(&self) -> usize {
         self.length as usize
     }
+
+    /// # Minimum residency in microseconds to save power
+    pub fn residency(&self) -> u32 {
+        self.residency
+    }
 }
 
 /// # Flags Field
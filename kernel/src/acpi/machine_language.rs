@@ -1,8 +1,276 @@
-//! # ACPI Machine Lnaguage
+//! # ACPI Machine Language
+//! The interpreter is the `acpi_machine_language` crate, which builds on the host too.
+//! This module plugs the kernel into it as the platform.
 //! ## References
 //! * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 20 ACPI MACHINE LANGUAGE (AML) SPECIFICATION
 
-pub mod interpreter;
-pub mod name;
-pub mod reference;
-pub mod syntax;
+pub use acpi_machine_language::*;
+
+use {
+    crate::{acpi, com2_println, interrupt, pci, timer, x64, Argument},
+    alloc::{boxed::Box, vec::Vec},
+    core::{cell::OnceCell, fmt},
+    syntax::{FirstReader, ReaderOutsideMethod},
+};
+
+static KERNEL: Kernel = Kernel;
+
+static mut ROOT: OnceCell<reference::Node<'static>> = OnceCell::new();
+
+/// # Register the kernel as the platform of the interpreter and load the namespace
+/// The syntax tree of the DSDT is never freed like the ones of dynamically loaded definition blocks, and SSDTs are loaded into the namespace as definition blocks.
+/// If the DSDT can't be read, the namespace starts empty so that evaluations fail instead of panicking, and the error is returned.
+pub fn initialize() -> Result<(), Error> {
+    platform::set(&KERNEL);
+    let xsdt: &acpi::extended_system_description::Table =
+        Argument::get().efi_system_table().rsdp().xsdt();
+    let root_path = name::Path::root();
+    let (syntax_tree, result): (syntax::TermList, Result<(), Error>) = match xsdt
+        .fadt()
+        .dsdt()
+        .ok_or(Error::NoDsdt)
+        .and_then(|dsdt| read(dsdt.definition_block(), &root_path))
+    {
+        Ok(syntax_tree) => (syntax_tree, Ok(())),
+        Err(error) => (read(&[], &root_path).unwrap(), Err(error)),
+    };
+    let syntax_tree: &'static syntax::TermList = Box::leak(Box::new(syntax_tree));
+    unsafe { ROOT.set(syntax_tree.into()) }.ok().unwrap();
+    xsdt.entries()
+        .into_iter()
+        .filter(|table| matches!(table, acpi::system_description::Table::Ssdt(_)))
+        .for_each(|ssdt| {
+            let table: &[u8] = (&ssdt).into();
+            if definition_block::load(table, &root_path).is_none() {
+                com2_println!("Can't load an SSDT.");
+            }
+        });
+    result
+}
+
+/// # The root of the namespace
+pub fn root() -> &'static reference::Node<'static> {
+    unsafe { ROOT.get().unwrap() }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    NoDsdt,
+    Syntax,
+    UnreadBytes(usize),
+}
+
+struct Kernel;
+
+impl platform::Context for Kernel {
+    fn context_id(&self) -> usize {
+        x64::msr::ia32::ApicBase::get()
+            .map_or(0, |apic_base| apic_base.registers().apic_id() as usize)
+    }
+}
+
+impl platform::Debug for Kernel {
+    fn debug(&self, value: &interpreter::Value) {
+        com2_println!("AML DebugObj = {:#x?}", value);
+    }
+
+    fn log(&self, message: fmt::Arguments) {
+        com2_println!("{}", message);
+    }
+}
+
+impl platform::Firmware for Kernel {
+    fn acquire_global_lock(&self) -> bool {
+        Argument::get()
+            .efi_system_table()
+            .rsdp()
+            .xsdt()
+            .fadt()
+            .acquire_global_lock()
+    }
+
+    fn release_global_lock(&self) {
+        Argument::get()
+            .efi_system_table_mut()
+            .rsdp_mut()
+            .xsdt_mut()
+            .fadt_mut()
+            .release_global_lock();
+    }
+
+    fn find_table(&self, signature: &str, oem_id: &str, oem_table_id: &str) -> Option<Vec<u8>> {
+        Argument::get()
+            .efi_system_table()
+            .rsdp()
+            .xsdt()
+            .find_table(signature, oem_id, oem_table_id)
+            .map(|header| {
+                let table: &[u8] = header.into();
+                table.to_vec()
+            })
+    }
+}
+
+impl platform::Notifier for Kernel {
+    fn notify(&self, object: name::Path, value: u8) {
+        interrupt::Event::push(interrupt::Event::acpi_notify(object, value));
+    }
+}
+
+impl platform::OperationRegion for Kernel {
+    fn read(&self, region_space: &interpreter::RegionSpace, address: usize, size: usize) -> u64 {
+        match (region_space, size) {
+            (interpreter::RegionSpace::SystemMemory, 1) => {
+                let address: *const u8 = address as *const u8;
+                unsafe { address.read_volatile() as u64 }
+            }
+            (interpreter::RegionSpace::SystemMemory, 2) => {
+                let address: *const u16 = address as *const u16;
+                unsafe { address.read_volatile() as u64 }
+            }
+            (interpreter::RegionSpace::SystemMemory, 4) => {
+                let address: *const u32 = address as *const u32;
+                unsafe { address.read_volatile() as u64 }
+            }
+            (interpreter::RegionSpace::SystemMemory, 8) => {
+                let address: *const u64 = address as *const u64;
+                unsafe { address.read_volatile() }
+            }
+            (interpreter::RegionSpace::SystemIo, 1) => x64::port::inb(address as u16) as u64,
+            (interpreter::RegionSpace::SystemIo, 2) => x64::port::inw(address as u16) as u64,
+            (interpreter::RegionSpace::SystemIo, 4) => x64::port::inl(address as u16) as u64,
+            (interpreter::RegionSpace::PciConfig, 4) => {
+                let (bus, device, function, offset): (u8, u8, u8, u16) =
+                    pci_config_address(address);
+                pci::FunctionAddress::new(0, bus, device, function).read(offset) as u64
+            }
+            (interpreter::RegionSpace::SystemCmos, 1) => x64::cmos::read_u8(address as u8) as u64,
+            (interpreter::RegionSpace::SystemCmos, 2) => x64::cmos::read_u16(address as u8) as u64,
+            (interpreter::RegionSpace::SystemCmos, 4) => x64::cmos::read_u32(address as u8) as u64,
+            (interpreter::RegionSpace::EmbeddedControl, 1) => {
+                acpi::embedded_controller::read_u8(address as u8) as u64
+            }
+            (interpreter::RegionSpace::EmbeddedControl, 2) => {
+                acpi::embedded_controller::read_u16(address as u8) as u64
+            }
+            (interpreter::RegionSpace::EmbeddedControl, 4) => {
+                acpi::embedded_controller::read_u32(address as u8) as u64
+            }
+            (interpreter::RegionSpace::EmbeddedControl, 8) => {
+                acpi::embedded_controller::read_u64(address as u8)
+            }
+            (region_space, size) => {
+                unimplemented!("region_space = {:#x?}, size = {:#x?}", region_space, size)
+            }
+        }
+    }
+
+    fn write(
+        &self,
+        region_space: &interpreter::RegionSpace,
+        address: usize,
+        size: usize,
+        value: u64,
+    ) {
+        match (region_space, size) {
+            (interpreter::RegionSpace::SystemMemory, 1) => {
+                let address: *mut u8 = address as *mut u8;
+                unsafe {
+                    address.write_volatile(value as u8);
+                }
+            }
+            (interpreter::RegionSpace::SystemMemory, 2) => {
+                let address: *mut u16 = address as *mut u16;
+                unsafe {
+                    address.write_volatile(value as u16);
+                }
+            }
+            (interpreter::RegionSpace::SystemMemory, 4) => {
+                let address: *mut u32 = address as *mut u32;
+                unsafe {
+                    address.write_volatile(value as u32);
+                }
+            }
+            (interpreter::RegionSpace::SystemMemory, 8) => {
+                let address: *mut u64 = address as *mut u64;
+                unsafe {
+                    address.write_volatile(value);
+                }
+            }
+            (interpreter::RegionSpace::SystemIo, 1) => {
+                x64::port::outb(address as u16, value as u8);
+            }
+            (interpreter::RegionSpace::SystemIo, 2) => {
+                x64::port::outw(address as u16, value as u16);
+            }
+            (interpreter::RegionSpace::SystemIo, 4) => {
+                x64::port::outl(address as u16, value as u32);
+            }
+            (interpreter::RegionSpace::PciConfig, 4) => {
+                let (bus, device, function, offset): (u8, u8, u8, u16) =
+                    pci_config_address(address);
+                pci::FunctionAddress::new(0, bus, device, function).write(offset, value as u32);
+            }
+            (interpreter::RegionSpace::SystemCmos, 1) => {
+                x64::cmos::write_u8(address as u8, value as u8);
+            }
+            (interpreter::RegionSpace::SystemCmos, 2) => {
+                x64::cmos::write_u16(address as u8, value as u16);
+            }
+            (interpreter::RegionSpace::SystemCmos, 4) => {
+                x64::cmos::write_u32(address as u8, value as u32);
+            }
+            (interpreter::RegionSpace::EmbeddedControl, 1) => {
+                acpi::embedded_controller::write_u8(address as u8, value as u8);
+            }
+            (interpreter::RegionSpace::EmbeddedControl, 2) => {
+                acpi::embedded_controller::write_u16(address as u8, value as u16);
+            }
+            (interpreter::RegionSpace::EmbeddedControl, 4) => {
+                acpi::embedded_controller::write_u32(address as u8, value as u32);
+            }
+            (interpreter::RegionSpace::EmbeddedControl, 8) => {
+                acpi::embedded_controller::write_u64(address as u8, value);
+            }
+            (region_space, size) => {
+                unimplemented!("region_space = {:#x?}, size = {:#x?}", region_space, size)
+            }
+        }
+    }
+}
+
+impl platform::Timer for Kernel {
+    fn nanoseconds(&self) -> u64 {
+        timer::acpi::nanoseconds() as u64
+    }
+
+    fn sleep_milliseconds(&self, milliseconds: usize) {
+        timer::acpi::wait_milliseconds(milliseconds);
+    }
+
+    fn stall_microseconds(&self, microseconds: usize) {
+        timer::acpi::wait_microseconds(microseconds);
+    }
+}
+
+/// # Read a definition block into a syntax tree
+fn read(definition_block: &[u8], root_path: &name::Path) -> Result<syntax::TermList, Error> {
+    let mut semantic_tree = name::Node::default();
+    let (mut syntax_tree, unread_definition_block): (syntax::TermList, &[u8]) =
+        syntax::TermList::first_read(definition_block, &mut semantic_tree, root_path)
+            .ok_or(Error::Syntax)?;
+    if !unread_definition_block.is_empty() {
+        return Err(Error::UnreadBytes(unread_definition_block.len()));
+    }
+    syntax_tree.read_outside_method(&mut semantic_tree, root_path);
+    Ok(syntax_tree)
+}
+
+/// # Split an address in the PCI_Config region space into bus, device, function and offset
+fn pci_config_address(address: usize) -> (u8, u8, u8, u16) {
+    let bus: u8 = ((address >> 0x30) & 0x00000000000000ff) as u8;
+    let device: u8 = ((address >> 0x20) & 0x00000000000000ff) as u8;
+    let function: u8 = ((address >> 0x10) & 0x00000000000000ff) as u8;
+    let offset: u16 = (address & 0x000000000000ffff) as u16;
+    (bus, device, function, offset)
+}
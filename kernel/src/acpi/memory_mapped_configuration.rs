@@ -1,6 +1,6 @@
 use {
     super::system_description,
-    core::{fmt, mem::size_of, slice},
+    core::{fmt, mem::size_of, ops::RangeInclusive, slice},
 };
 
 /// # Memory Mapped Configuration Table
@@ -13,11 +13,18 @@ pub struct Table {
 }
 
 impl Table {
+    /// # The ECAM address of the configuration space of a function
+    pub fn address(&self, segment: u16, bus: u8, device: u8, function: u8) -> Option<usize> {
+        self.configuration_space_base_address_allocations()
+            .iter()
+            .find_map(|allocation| allocation.address(segment, bus, device, function))
+    }
+
     pub fn is_correct(&self) -> bool {
         self.header.is_correct()
     }
 
-    fn configuration_space_base_address_allocations(
+    pub fn configuration_space_base_address_allocations(
         &self,
     ) -> &[ConfigurationSpaceBaseAddressAllocation] {
         let address: *const Self = self as *const Self;
@@ -48,14 +55,34 @@ impl fmt::Debug for Table {
 /// * [PCI Express](https://wiki.osdev.org/PCI_Express)
 #[derive(Debug)]
 #[repr(packed)]
-struct ConfigurationSpaceBaseAddressAllocation {
-    #[allow(dead_code)]
+pub struct ConfigurationSpaceBaseAddressAllocation {
     base_address: u64,
-    #[allow(dead_code)]
     pci_segment_group_number: u16,
-    #[allow(dead_code)]
     start_pci_bus_number: u8,
-    #[allow(dead_code)]
     end_pci_bus_number: u8,
     __: u32,
 }
+
+impl ConfigurationSpaceBaseAddressAllocation {
+    /// # The ECAM address of the configuration space of a function
+    /// The base address corresponds to bus 0 even if the start bus is not 0.
+    /// ## References
+    /// * [PCI Express Base Specification Revision 5.0 Version 1.0](https://picture.iczhiku.com/resource/eetop/SYkDTqhOLhpUTnMx.pdf) 7.2.2 PCI Express Enhanced Configuration Access Mechanism (ECAM) Table 7-1 Enhanced Configuration Address Mapping
+    pub fn address(&self, segment: u16, bus: u8, device: u8, function: u8) -> Option<usize> {
+        (segment == self.pci_segment_group_number() && self.bus_range().contains(&bus)).then(|| {
+            let base_address: u64 = self.base_address;
+            (base_address as usize)
+                + ((bus as usize) << 20)
+                + ((device as usize) << 15)
+                + ((function as usize) << 12)
+        })
+    }
+
+    pub fn bus_range(&self) -> RangeInclusive<u8> {
+        self.start_pci_bus_number..=self.end_pci_bus_number
+    }
+
+    pub fn pci_segment_group_number(&self) -> u16 {
+        self.pci_segment_group_number
+    }
+}
@@ -55,6 +55,44 @@ impl Table {
         unsafe { &mut *io_apic }
     }
 
+    /// # Find the I/O APIC whose redirection table has the global system interrupt
+    pub fn io_apic_mut_for(
+        &mut self,
+        global_system_interrupt: u32,
+    ) -> Option<&mut io_apic::Structure> {
+        let bytes: &mut [u8] = self.bytes_mut();
+        let mut index: usize = 0;
+        while index + 1 < bytes.len() && bytes[index + 1] != 0 {
+            let length: usize = bytes[index + 1] as usize;
+            if bytes[index] == 0x01 {
+                let io_apic: &mut u8 = &mut bytes[index];
+                let io_apic: *mut u8 = io_apic as *mut u8;
+                let io_apic: *mut io_apic::Structure = io_apic as *mut io_apic::Structure;
+                let io_apic: &mut io_apic::Structure = unsafe { &mut *io_apic };
+                if io_apic.has(global_system_interrupt) {
+                    return Some(io_apic);
+                }
+            }
+            index += length;
+        }
+        None
+    }
+
+    /// # Find the override of an ISA IRQ
+    pub fn interrupt_source_override(
+        &self,
+        irq: u8,
+    ) -> Option<&interrupt_source_override::Structure> {
+        self.iter().find_map(|structure| match structure {
+            InterruptControllerStructure::InterruptSourceOverride(structure)
+                if structure.source() == irq =>
+            {
+                Some(structure)
+            }
+            _ => None,
+        })
+    }
+
     pub fn is_correct(&self) -> bool {
         self.header.is_correct()
     }
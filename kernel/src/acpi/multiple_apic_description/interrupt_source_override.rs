@@ -1,4 +1,4 @@
-use bitfield_struct::bitfield;
+use {crate::interrupt, bitfield_struct::bitfield};
 
 /// # Interrupt Source Override Structure
 /// ## References
@@ -11,18 +11,45 @@ pub struct Structure {
     length: u8,
     #[allow(dead_code)]
     bus: u8,
-    #[allow(dead_code)]
     source: u8,
-    #[allow(dead_code)]
     global_system_interrupt: u32,
-    #[allow(dead_code)]
     flags: Flags,
 }
 
 impl Structure {
+    pub fn global_system_interrupt(&self) -> u32 {
+        self.global_system_interrupt
+    }
+
     pub fn length(&self) -> usize {
         self.length as usize
     }
+
+    /// # Polarity of the interrupt
+    /// Returns `None` if the polarity conforms to the specifications of the bus.
+    pub fn polarity(&self) -> Option<interrupt::apic::Polarity> {
+        let flags: Flags = self.flags;
+        match flags.polarity() {
+            0b01 => Some(interrupt::apic::Polarity::ActiveHigh),
+            0b11 => Some(interrupt::apic::Polarity::ActiveLow),
+            _ => None,
+        }
+    }
+
+    pub fn source(&self) -> u8 {
+        self.source
+    }
+
+    /// # Trigger mode of the interrupt
+    /// Returns `None` if the trigger mode conforms to the specifications of the bus.
+    pub fn trigger_mode(&self) -> Option<interrupt::apic::TriggerMode> {
+        let flags: Flags = self.flags;
+        match flags.trigger_mode() {
+            0b01 => Some(interrupt::apic::TriggerMode::Edge),
+            0b11 => Some(interrupt::apic::TriggerMode::Level),
+            _ => None,
+        }
+    }
 }
 
 /// # MPS INTI Flags
@@ -14,6 +14,17 @@ pub struct Structure {
 }
 
 impl Structure {
+    pub fn global_system_interrupt_base(&self) -> u32 {
+        self.global_system_interrupt_base
+    }
+
+    /// # Whether the redirection table has the global system interrupt
+    pub fn has(&mut self, global_system_interrupt: u32) -> bool {
+        let base: u32 = self.global_system_interrupt_base;
+        let length: u32 = self.registers_mut().version().redirection_table_length() as u32;
+        (base..base + length).contains(&global_system_interrupt)
+    }
+
     pub fn length(&self) -> usize {
         self.length as usize
     }
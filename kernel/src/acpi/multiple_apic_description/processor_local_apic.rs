@@ -9,7 +9,6 @@ pub struct Structure {
     #[allow(dead_code)]
     structure_type: u8,
     length: u8,
-    #[allow(dead_code)]
     acpi_processor_uid: u8,
     apic_id: u8,
     #[allow(dead_code)]
@@ -17,6 +16,11 @@ pub struct Structure {
 }
 
 impl Structure {
+    /// # The UID of the processor object in the namespace
+    pub fn acpi_processor_uid(&self) -> u8 {
+        self.acpi_processor_uid
+    }
+
     pub fn apic_id(&self) -> u8 {
         self.apic_id
     }
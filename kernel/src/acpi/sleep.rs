@@ -0,0 +1,172 @@
+//! # Sleeping and Waking
+//! ## References
+//! * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 7.4 OEM-Supplied System-Level Control Methods
+//! * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 16 Waking and Sleeping
+
+pub mod context;
+
+use {
+    super::{event, fixed_acpi_description, machine_language, watchdog},
+    crate::{com2_println, interrupt, iommu, memory, processor, task, timer, x64, Argument},
+    alloc::{format, string::String, vec, vec::Vec},
+};
+
+/// # Sleeping States
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum State {
+    S0,
+    S3,
+    S5,
+}
+
+impl State {
+    /// # Read `\_Sx`
+    pub fn sleep_type(self, root: &machine_language::reference::Node) -> Option<Type> {
+        let path: String = format!("\\_S{}", u8::from(self));
+        let path: machine_language::name::Path = path.as_str().into();
+        let package: machine_language::interpreter::Value = root.evaluate(&path, Vec::new())?;
+        let slp_typ = |index: u64| {
+            package
+                .index(&machine_language::interpreter::Value::QWord(index))
+                .and_then(|slp_typ| slp_typ.get_integer())
+                .map(|slp_typ| slp_typ as u8)
+        };
+        let pm1a: u8 = slp_typ(0)?;
+        let pm1b: u8 = slp_typ(1).unwrap_or_default();
+        Some(Type { pm1a, pm1b })
+    }
+
+    /// # Evaluate a system-level control method with the state as its argument
+    fn notify(self, root: &machine_language::reference::Node, method: &str) {
+        let method: machine_language::name::Path = method.into();
+        let state: machine_language::interpreter::Value =
+            machine_language::interpreter::Value::Byte(self.into());
+        if root.get_method(&method).is_some() {
+            root.evaluate(&method, vec![state]);
+        }
+    }
+}
+
+impl From<State> for u8 {
+    fn from(state: State) -> Self {
+        match state {
+            State::S0 => 0,
+            State::S3 => 3,
+            State::S5 => 5,
+        }
+    }
+}
+
+/// # SLP_TYPa and SLP_TYPb of a sleeping state
+/// It is passed through `context::sleep`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(C)]
+pub struct Type {
+    pub pm1a: u8,
+    pub pm1b: u8,
+}
+
+/// # Prepare to enter a sleeping state
+/// `_TTS` and `_PTS` are evaluated only if the state is supported.
+pub fn prepare(root: &machine_language::reference::Node, state: State) -> Option<Type> {
+    let sleep_type: Type = state.sleep_type(root)?;
+    state.notify(root, "\\_TTS");
+    state.notify(root, "\\_PTS");
+    Some(sleep_type)
+}
+
+/// # Suspend to RAM
+/// The application processors are stopped and the context of the bootstrap processor is saved.
+/// The firmware jumps to the boot loader of the application processors on waking, which brings the bootstrap processor to the long mode and calls `context::resume`, and `context::sleep` returns.
+/// After waking, the IOMMUs are restored before the I/O APIC, the interrupt controllers, the timers and ACPI events are initialized again, `_WAK` is evaluated and the application processors boot again.
+pub fn suspend(local_apic_id: u8) {
+    let root: &machine_language::reference::Node = machine_language::root();
+    if State::S3.sleep_type(root).is_none() {
+        com2_println!("S3 is not supported.");
+        return;
+    }
+    let hpet: &timer::hpet::Registers = Argument::get()
+        .efi_system_table()
+        .rsdp()
+        .xsdt()
+        .hpet()
+        .registers();
+    let mut ia32_apic_base = x64::msr::ia32::ApicBase::get().unwrap();
+    processor::Manager::park(ia32_apic_base.registers_mut(), hpet);
+    let watchdog_is_running: bool = watchdog::is_running();
+    watchdog::stop();
+    let sleep_type: Type = prepare(root, State::S3).unwrap();
+    task::Controller::get_current_mut().unwrap().cli();
+    let io_apic_redirection_table_entries: Vec<interrupt::apic::io::redirection::table::Entry> =
+        Argument::get()
+            .efi_system_table_mut()
+            .rsdp_mut()
+            .xsdt_mut()
+            .madt_mut()
+            .io_apic_mut()
+            .registers_mut()
+            .redirection_table_entries();
+    let wake_stack: Vec<u8> = vec![0; memory::page::SIZE];
+    let wake_stack_floor: usize = wake_stack.as_ptr_range().end as usize & !0xf;
+    let cr3: u64 = Argument::get().paging().cr3().into();
+    let boot_loader: &mut processor::boot::Loader = Argument::get().processor_boot_loader_mut();
+    boot_loader.initialize_for_wake(
+        cr3,
+        context::resume as usize,
+        wake_stack_floor,
+        local_apic_id,
+    );
+    let fadt: &mut fixed_acpi_description::Table = Argument::get()
+        .efi_system_table_mut()
+        .rsdp_mut()
+        .xsdt_mut()
+        .fadt_mut();
+    if !fadt.set_firmware_waking_vector(boot_loader.entry_point() as u32) {
+        com2_println!("There is no FACS to set the waking vector.");
+    } else if !unsafe { context::sleep(enter, &sleep_type) } {
+        com2_println!("The firmware returned without entering S3.");
+    }
+    com2_println!("{}", boot_loader.log());
+    // The saved redirection table entries may be in the remappable format.
+    iommu::resume();
+    Argument::get()
+        .efi_system_table_mut()
+        .rsdp_mut()
+        .xsdt_mut()
+        .madt_mut()
+        .io_apic_mut()
+        .registers_mut()
+        .set_redirection_table_entries(&io_apic_redirection_table_entries);
+    let local_apic_registers: &mut interrupt::apic::local::Registers =
+        interrupt::apic::local::Registers::initialize(&mut ia32_apic_base);
+    timer::pit::initialize(local_apic_id);
+    timer::rtc::initialize(local_apic_id);
+    let hpet: &timer::hpet::Registers = timer::hpet::Registers::initialize(local_apic_id);
+    local_apic_registers.initialize_apic(hpet);
+    task::Controller::get_current_mut().unwrap().sti();
+    event::initialize(local_apic_id);
+    wake(root, State::S3);
+    processor::Manager::resume(local_apic_id, local_apic_registers, hpet);
+    if watchdog_is_running {
+        watchdog::start();
+    }
+    com2_println!("Resumed from S3.");
+}
+
+/// # Enter a sleeping state
+/// `context::sleep` calls this function after saving the processor context.
+extern "C" fn enter(sleep_type: &Type) {
+    com2_println!("Suspending to RAM.");
+    Argument::get()
+        .efi_system_table_mut()
+        .rsdp_mut()
+        .xsdt_mut()
+        .fadt_mut()
+        .enter_sleep_state(*sleep_type);
+}
+
+/// # Notify the firmware of waking
+fn wake(root: &machine_language::reference::Node, state: State) {
+    state.notify(root, "\\_WAK");
+    State::S0.notify(root, "\\_TTS");
+}
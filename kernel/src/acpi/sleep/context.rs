@@ -0,0 +1,240 @@
+//! # Processor Context
+//! The processor loses its state in S3.
+//! `sleep` stores the registers which are not reinitialized by the waking trampoline and calls the function entering the sleeping state.
+//! `resume` restores them and returns from `sleep` in place of the function, so that Rust code never sees a function returning twice.
+//! ## References
+//! * [Intel 64 and IA-32 Architectures Software Developer's Manual December 2023](https://www.intel.com/content/www/us/en/developer/articles/technical/intel-sdm.html) Vol.3A 10.1 Initialization Overview
+
+use {super::Type, core::mem};
+
+static mut CONTEXT: Context = unsafe { mem::zeroed() };
+
+/// # Saved registers
+/// The offsets of the fields are hard coded in `sleep` and `resume`.
+#[repr(C)]
+struct Context {
+    #[allow(dead_code)]
+    rbx: u64, // 0x00
+    #[allow(dead_code)]
+    rbp: u64, // 0x08
+    #[allow(dead_code)]
+    r12: u64, // 0x10
+    #[allow(dead_code)]
+    r13: u64, // 0x18
+    #[allow(dead_code)]
+    r14: u64, // 0x20
+    #[allow(dead_code)]
+    r15: u64, // 0x28
+    #[allow(dead_code)]
+    rsp: u64, // 0x30
+    #[allow(dead_code)]
+    rip: u64, // 0x38
+    #[allow(dead_code)]
+    rflags: u64, // 0x40
+    #[allow(dead_code)]
+    cr0: u64, // 0x48
+    #[allow(dead_code)]
+    cr3: u64, // 0x50
+    #[allow(dead_code)]
+    cr4: u64, // 0x58
+    #[allow(dead_code)]
+    gdtr: [u64; 2], // 0x60
+    #[allow(dead_code)]
+    idtr: [u64; 2], // 0x70
+    #[allow(dead_code)]
+    ia32_efer: u64, // 0x80
+    #[allow(dead_code)]
+    ia32_star: u64, // 0x88
+    #[allow(dead_code)]
+    ia32_lstar: u64, // 0x90
+    #[allow(dead_code)]
+    ia32_fmask: u64, // 0x98
+    #[allow(dead_code)]
+    ia32_fs_base: u64, // 0xa0
+    #[allow(dead_code)]
+    ia32_gs_base: u64, // 0xa8
+    #[allow(dead_code)]
+    ia32_kernel_gs_base: u64, // 0xb0
+    #[allow(dead_code)]
+    cs: u16, // 0xb8
+    #[allow(dead_code)]
+    ds: u16, // 0xba
+    #[allow(dead_code)]
+    es: u16, // 0xbc
+    #[allow(dead_code)]
+    fs: u16, // 0xbe
+    #[allow(dead_code)]
+    gs: u16, // 0xc0
+    #[allow(dead_code)]
+    ss: u16, // 0xc2
+    #[allow(dead_code)]
+    tr: u16, // 0xc4
+    #[allow(dead_code)]
+    fpu_control_word: u16, // 0xc6
+    #[allow(dead_code)]
+    mxcsr: u32, // 0xc8
+}
+
+/// # Save the processor context and enter a sleeping state
+/// `enter` is called with `sleep_type` on the saved stack.
+/// Returns false if `enter` returns without sleeping and true if `resume` restores the context after waking.
+#[naked_function::naked]
+pub unsafe extern "C" fn sleep(enter: extern "C" fn(&Type), sleep_type: &Type) -> bool {
+    asm!(
+        "mov r10, rdi",
+        "mov r11, rsi",
+        "lea rdi, [rip + {context}]",
+        "mov [rdi + 0x00], rbx",
+        "mov [rdi + 0x08], rbp",
+        "mov [rdi + 0x10], r12",
+        "mov [rdi + 0x18], r13",
+        "mov [rdi + 0x20], r14",
+        "mov [rdi + 0x28], r15",
+        "mov [rdi + 0x30], rsp", // The stack pointer at 2 below
+        "lea rax, [rip + 2f]",
+        "mov [rdi + 0x38], rax",
+        "pushfq",
+        "pop qword ptr [rdi + 0x40]",
+        "mov rax, cr0",
+        "mov [rdi + 0x48], rax",
+        "mov rax, cr3",
+        "mov [rdi + 0x50], rax",
+        "mov rax, cr4",
+        "mov [rdi + 0x58], rax",
+        "sgdt [rdi + 0x60]",
+        "sidt [rdi + 0x70]",
+        "mov ecx, 0xc0000080", // IA32_EFER
+        "rdmsr",
+        "mov [rdi + 0x80], eax",
+        "mov [rdi + 0x84], edx",
+        "mov ecx, 0xc0000081", // IA32_STAR
+        "rdmsr",
+        "mov [rdi + 0x88], eax",
+        "mov [rdi + 0x8c], edx",
+        "mov ecx, 0xc0000082", // IA32_LSTAR
+        "rdmsr",
+        "mov [rdi + 0x90], eax",
+        "mov [rdi + 0x94], edx",
+        "mov ecx, 0xc0000084", // IA32_FMASK
+        "rdmsr",
+        "mov [rdi + 0x98], eax",
+        "mov [rdi + 0x9c], edx",
+        "mov ecx, 0xc0000100", // IA32_FS_BASE
+        "rdmsr",
+        "mov [rdi + 0xa0], eax",
+        "mov [rdi + 0xa4], edx",
+        "mov ecx, 0xc0000101", // IA32_GS_BASE
+        "rdmsr",
+        "mov [rdi + 0xa8], eax",
+        "mov [rdi + 0xac], edx",
+        "mov ecx, 0xc0000102", // IA32_KERNEL_GS_BASE
+        "rdmsr",
+        "mov [rdi + 0xb0], eax",
+        "mov [rdi + 0xb4], edx",
+        "mov word ptr [rdi + 0xb8], cs",
+        "mov word ptr [rdi + 0xba], ds",
+        "mov word ptr [rdi + 0xbc], es",
+        "mov word ptr [rdi + 0xbe], fs",
+        "mov word ptr [rdi + 0xc0], gs",
+        "mov word ptr [rdi + 0xc2], ss",
+        "str word ptr [rdi + 0xc4]",
+        "fnstcw [rdi + 0xc6]",
+        "stmxcsr [rdi + 0xc8]",
+        // Align the stack to 16 bytes at the call.
+        "sub rsp, 0x08",
+        "mov rdi, r11",
+        "call r10",
+        "add rsp, 0x08",
+        "xor eax, eax",
+        "ret",
+        // `resume` jumps here with the saved stack pointer.
+        "2:",
+        "ret",
+        context = sym CONTEXT,
+    );
+}
+
+/// # Restore the processor context
+/// The waking trampoline calls this function in the long mode with the saved CR3, and it returns true from `sleep`.
+#[naked_function::naked]
+pub unsafe extern "C" fn resume() -> ! {
+    asm!(
+        "lea rdi, [rip + {context}]",
+        "mov rax, [rdi + 0x58]",
+        "mov cr4, rax",
+        "mov rax, [rdi + 0x50]",
+        "mov cr3, rax",
+        "mov rax, [rdi + 0x48]",
+        "mov cr0, rax",
+        "mov ecx, 0xc0000080", // IA32_EFER
+        "mov eax, [rdi + 0x80]",
+        "mov edx, [rdi + 0x84]",
+        "wrmsr",
+        "mov ecx, 0xc0000081", // IA32_STAR
+        "mov eax, [rdi + 0x88]",
+        "mov edx, [rdi + 0x8c]",
+        "wrmsr",
+        "mov ecx, 0xc0000082", // IA32_LSTAR
+        "mov eax, [rdi + 0x90]",
+        "mov edx, [rdi + 0x94]",
+        "wrmsr",
+        "mov ecx, 0xc0000084", // IA32_FMASK
+        "mov eax, [rdi + 0x98]",
+        "mov edx, [rdi + 0x9c]",
+        "wrmsr",
+        "lgdt [rdi + 0x60]",
+        "lidt [rdi + 0x70]",
+        "mov ax, [rdi + 0xba]",
+        "mov ds, ax",
+        "mov ax, [rdi + 0xbc]",
+        "mov es, ax",
+        "mov ax, [rdi + 0xbe]",
+        "mov fs, ax",
+        "mov ax, [rdi + 0xc0]",
+        "mov gs, ax",
+        "mov ax, [rdi + 0xc2]",
+        "mov ss, ax",
+        // Loading FS and GS overwrites their bases.
+        "mov ecx, 0xc0000100", // IA32_FS_BASE
+        "mov eax, [rdi + 0xa0]",
+        "mov edx, [rdi + 0xa4]",
+        "wrmsr",
+        "mov ecx, 0xc0000101", // IA32_GS_BASE
+        "mov eax, [rdi + 0xa8]",
+        "mov edx, [rdi + 0xac]",
+        "wrmsr",
+        "mov ecx, 0xc0000102", // IA32_KERNEL_GS_BASE
+        "mov eax, [rdi + 0xb0]",
+        "mov edx, [rdi + 0xb4]",
+        "wrmsr",
+        "movzx rax, word ptr [rdi + 0xb8]",
+        "lea rcx, [rip + 2f]",
+        "push rax",
+        "push rcx",
+        "retfq",
+        "2:",
+        // LTR faults on a busy TSS descriptor, so clear its busy bit.
+        "movzx eax, word ptr [rdi + 0xc4]",
+        "test eax, eax",
+        "jz 3f",
+        "and eax, 0xfff8",
+        "mov rcx, [rdi + 0x62]",
+        "and byte ptr [rcx + rax + 0x05], 0xfd",
+        "ltr word ptr [rdi + 0xc4]",
+        "3:",
+        "fldcw [rdi + 0xc6]",
+        "ldmxcsr [rdi + 0xc8]",
+        "mov rbx, [rdi + 0x00]",
+        "mov rbp, [rdi + 0x08]",
+        "mov r12, [rdi + 0x10]",
+        "mov r13, [rdi + 0x18]",
+        "mov r14, [rdi + 0x20]",
+        "mov r15, [rdi + 0x28]",
+        "mov rsp, [rdi + 0x30]",
+        "push qword ptr [rdi + 0x40]",
+        "popfq",
+        "mov eax, 1",
+        "jmp qword ptr [rdi + 0x38]",
+        context = sym CONTEXT,
+    );
+}
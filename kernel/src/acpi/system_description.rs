@@ -1,11 +1,12 @@
 use {
     super::{
         boot_graphics_resource, debug_port, differentiated_system_description,
-        direct_memory_access_remapping, extended_system_description, firmware_performance_data,
-        fixed_acpi_description, high_precision_event_timer, low_power_idle,
-        memory_mapped_configuration, multiple_apic_description, root_system_description,
-        secondary_system_description, static_resource_affinity, trusted_platform_module,
-        watchdog_action, windows_acpi_emulated_devices, windows_smm_security_mitigations,
+        direct_memory_access_remapping, embedded_controller_boot_resources,
+        extended_system_description, firmware_performance_data, fixed_acpi_description,
+        high_precision_event_timer, low_power_idle, memory_mapped_configuration,
+        multiple_apic_description, root_system_description, secondary_system_description,
+        static_resource_affinity, trusted_platform_module, watchdog_action,
+        windows_acpi_emulated_devices, windows_smm_security_mitigations,
     },
     core::{fmt, slice, str},
 };
@@ -36,6 +37,14 @@ impl Header {
             == 0
     }
 
+    pub fn oem_revision(&self) -> u32 {
+        self.oem_revision
+    }
+
+    pub fn revision(&self) -> u8 {
+        self.revision
+    }
+
     pub fn signature(&self) -> &str {
         str::from_utf8(self.signature.as_slice()).unwrap()
     }
@@ -48,11 +57,11 @@ impl Header {
         str::from_utf8(self.creater_id.as_slice()).unwrap()
     }
 
-    fn oemid(&self) -> &str {
+    pub fn oemid(&self) -> &str {
         str::from_utf8(self.oemid.as_slice()).unwrap()
     }
 
-    fn oem_table_id(&self) -> &str {
+    pub fn oem_table_id(&self) -> &str {
         str::from_utf8(self.oem_table_id.as_slice()).unwrap()
     }
 }
@@ -96,6 +105,7 @@ pub enum Table<'a> {
     Dbgp(&'a debug_port::Table),
     Dmar(&'a direct_memory_access_remapping::Table),
     Dsdt(&'a differentiated_system_description::Table),
+    Ecdt(&'a embedded_controller_boot_resources::Table),
     Fadt(&'a fixed_acpi_description::Table),
     Fpdt(&'a firmware_performance_data::Table),
     Hpet(&'a high_precision_event_timer::Table),
@@ -114,6 +124,15 @@ pub enum Table<'a> {
 }
 
 impl Table<'_> {
+    /// # Disassemble a DSDT or an SSDT into ASL
+    /// Returns `None` if the table is not a DSDT or an SSDT or can't be parsed.
+    #[cfg(feature = "disassemble")]
+    pub fn disassemble(&self) -> Option<alloc::string::String> {
+        matches!(self, Self::Dsdt(_) | Self::Ssdt(_))
+            .then(|| super::machine_language::disassembler::disassemble_table(self.into()))
+            .flatten()
+    }
+
     pub fn definition_block(&self) -> &[u8] {
         match self {
             Self::Bgrt(_table) => unimplemented!(),
@@ -121,6 +140,7 @@ impl Table<'_> {
             Self::Dbgp(_table) => unimplemented!(),
             Self::Dmar(_table) => unimplemented!(),
             Self::Dsdt(table) => table.definition_block(),
+            Self::Ecdt(_table) => unimplemented!(),
             Self::Fadt(_table) => unimplemented!(),
             Self::Fpdt(_table) => unimplemented!(),
             Self::Hpet(_table) => unimplemented!(),
@@ -146,6 +166,7 @@ impl Table<'_> {
             Self::Dbgp(table) => table.is_correct(),
             Self::Dmar(table) => table.is_correct(),
             Self::Dsdt(table) => table.is_correct(),
+            Self::Ecdt(table) => table.is_correct(),
             Self::Fadt(table) => table.is_correct(),
             Self::Fpdt(table) => table.is_correct(),
             Self::Hpet(table) => table.is_correct(),
@@ -208,6 +229,13 @@ impl<'a> From<&'a Header> for Table<'a> {
                 let table: &differentiated_system_description::Table = unsafe { &*table };
                 Self::Dsdt(table)
             }
+            "ECDT" => {
+                let header: *const Header = header as *const Header;
+                let table: *const embedded_controller_boot_resources::Table =
+                    header as *const embedded_controller_boot_resources::Table;
+                let table: &embedded_controller_boot_resources::Table = unsafe { &*table };
+                Self::Ecdt(table)
+            }
             "FACP" => {
                 let header: *const Header = header as *const Header;
                 let table: *const fixed_acpi_description::Table =
@@ -312,6 +340,7 @@ impl<'a> From<&'a Table<'a>> for &'a [u8] {
             Table::Dbgp(_table) => unimplemented!(),
             Table::Dmar(_table) => unimplemented!(),
             Table::Dsdt(table) => (*table).into(),
+            Table::Ecdt(_table) => unimplemented!(),
             Table::Fadt(_table) => unimplemented!(),
             Table::Fpdt(_table) => unimplemented!(),
             Table::Hpet(_table) => unimplemented!(),
@@ -321,7 +350,7 @@ impl<'a> From<&'a Table<'a>> for &'a [u8] {
             Table::Other(_table) => unimplemented!(),
             Table::Rsdt(_table) => unimplemented!(),
             Table::Srat(_table) => unimplemented!(),
-            Table::Ssdt(_table) => unimplemented!(),
+            Table::Ssdt(table) => (*table).into(),
             Table::Tpm2(_table) => unimplemented!(),
             Table::Waet(_table) => unimplemented!(),
             Table::Wdat(_table) => unimplemented!(),
@@ -0,0 +1,199 @@
+//! # Thermal Management
+//! Thermal zones are polled at their `_TZP` intervals on HPET events and updated on thermal notifications.
+//! Reaching `_CRT` requests an orderly shutdown, and reaching `_HOT` requests a suspend because S4 is not supported.
+//! The RTC alarm wakes the system after a cooling period to read the zones again.
+//! ## References
+//! * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 11.1.5 Critical Shutdown
+//! * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 11.4 Thermal Objects
+
+use {
+    super::{
+        event,
+        machine_language::{
+            self,
+            thermal_zone::{self, Temperature},
+        },
+        sleep,
+    },
+    crate::{com2_println, sync::spin, timer},
+    alloc::vec::Vec,
+    core::sync::atomic::{AtomicBool, Ordering},
+};
+
+/// Polling period in tenths of seconds of a zone without `_TZP`
+const DEFAULT_POLLING_PERIOD: u64 = 100;
+/// Seconds to stay in S3 after reaching `_HOT`
+const COOLING_PERIOD: u32 = 60;
+/// Period of HPET events in tenths of seconds
+const TICK: u64 = (timer::hpet::PERIOD_MILLISECONDS / 100) as u64;
+
+static CRITICAL: AtomicBool = AtomicBool::new(false);
+static ZONES: spin::Lock<Vec<Zone>> = spin::Lock::new(Vec::new());
+
+/// # Find thermal zones and read them first
+pub fn initialize() {
+    let root: &machine_language::reference::Node = machine_language::root();
+    let mut zones: Vec<Zone> = thermal_zone::Zone::enumerate(root)
+        .into_iter()
+        .map(Zone::new)
+        .collect();
+    zones.iter_mut().for_each(|zone| {
+        machine_language::notification::register(zone.zone.path(), notify);
+        zone.update();
+    });
+    com2_println!("thermal zones = {:#x?}", zones);
+    *ZONES.lock() = zones;
+}
+
+/// # Read all thermal zones again
+/// Called on the RTC fixed event, which the alarm set on reaching `_HOT` raises on waking.
+/// A zone still hot requests another suspend.
+pub fn alarm() {
+    timer::rtc::disable_alarm();
+    ZONES.lock().iter_mut().for_each(|zone| {
+        zone.is_hot = false;
+        zone.update();
+    });
+}
+
+/// # Whether a thermal zone has reached its critical temperature
+pub fn is_critical() -> bool {
+    CRITICAL.load(Ordering::Acquire)
+}
+
+/// # The last readings of the thermal zones
+#[allow(dead_code)]
+pub fn readings() -> Vec<Reading> {
+    ZONES.lock().iter().map(Reading::from).collect()
+}
+
+/// # Poll thermal zones whose polling periods have elapsed
+/// Called every HPET event.
+pub fn tick() {
+    ZONES
+        .lock()
+        .iter_mut()
+        .filter(|zone| zone.polling_period != 0)
+        .for_each(|zone| {
+            zone.elapsed += TICK;
+            if zone.polling_period <= zone.elapsed {
+                zone.elapsed = 0;
+                zone.update();
+            }
+        });
+}
+
+/// # A reading of a thermal zone
+#[allow(dead_code)]
+#[derive(Clone, Debug)]
+pub struct Reading {
+    pub path: machine_language::name::Path,
+    pub temperature: Option<Temperature>,
+    pub critical: Option<Temperature>,
+    pub hot: Option<Temperature>,
+}
+
+impl From<&Zone> for Reading {
+    fn from(zone: &Zone) -> Self {
+        Self {
+            path: zone.zone.path().clone(),
+            temperature: zone.temperature,
+            critical: zone.critical,
+            hot: zone.hot,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Zone {
+    zone: thermal_zone::Zone,
+    critical: Option<Temperature>,
+    hot: Option<Temperature>,
+    /// In tenths of seconds
+    polling_period: u64,
+    /// In tenths of seconds
+    elapsed: u64,
+    temperature: Option<Temperature>,
+    is_hot: bool,
+}
+
+impl Zone {
+    fn new(zone: thermal_zone::Zone) -> Self {
+        let mut zone = Self {
+            zone,
+            critical: None,
+            hot: None,
+            polling_period: 0,
+            elapsed: 0,
+            temperature: None,
+            is_hot: false,
+        };
+        zone.read_trip_points();
+        zone
+    }
+
+    /// # Read `_CRT`, `_HOT` and `_TZP`
+    fn read_trip_points(&mut self) {
+        let root: &machine_language::reference::Node = machine_language::root();
+        self.critical = self.zone.critical(root);
+        self.hot = self.zone.hot(root);
+        self.polling_period = self
+            .zone
+            .polling_period(root)
+            .unwrap_or(DEFAULT_POLLING_PERIOD);
+    }
+
+    /// # Read `_TMP` and enforce the thresholds
+    fn update(&mut self) {
+        let root: &machine_language::reference::Node = machine_language::root();
+        self.temperature = self.zone.temperature(root);
+        let Some(temperature) = self.temperature else {
+            com2_println!("{:?} has no temperature.", self.zone.path());
+            return;
+        };
+        com2_println!("{:?} = {}", self.zone.path(), temperature);
+        if self
+            .critical
+            .is_some_and(|critical| critical <= temperature)
+        {
+            com2_println!(
+                "{:?} reaches the critical temperature {}.",
+                self.zone.path(),
+                self.critical.unwrap()
+            );
+            CRITICAL.store(true, Ordering::Release);
+        }
+        let is_hot: bool = self.hot.is_some_and(|hot| hot <= temperature);
+        if is_hot && !self.is_hot {
+            com2_println!(
+                "{:?} reaches the hot temperature {}.",
+                self.zone.path(),
+                self.hot.unwrap()
+            );
+            if sleep::State::S3.sleep_type(root).is_some() {
+                timer::rtc::set_alarm(COOLING_PERIOD);
+                event::press_sleep_button();
+            } else {
+                CRITICAL.store(true, Ordering::Release);
+            }
+        }
+        self.is_hot = is_hot;
+    }
+}
+
+fn notify(path: &machine_language::name::Path, value: u8) {
+    if let Some(zone) = ZONES
+        .lock()
+        .iter_mut()
+        .find(|zone| zone.zone.path() == path)
+    {
+        match value {
+            thermal_zone::TEMPERATURE_CHANGED => zone.update(),
+            thermal_zone::TRIP_POINTS_CHANGED => {
+                zone.read_trip_points();
+                zone.update();
+            }
+            value => com2_println!("Unknown thermal notification {:#x?}.", value),
+        }
+    }
+}
@@ -0,0 +1,86 @@
+//! # Hardware Watchdog
+//! The watchdog described by the WDAT is started with a countdown of `TIMEOUT` and reset on every HPET event, so the machine reboots if the kernel loop hangs.
+//! ## References
+//! * [Hardware Watchdog Timers Design Specification](https://download.microsoft.com/download/a/f/7/af7777e5-7dcd-4800-8a0a-b18336565f5b/hardwarewdtspec.doc)
+
+use {
+    super::watchdog_action::{self, instruction::Action},
+    crate::{com2_println, Argument},
+    core::sync::atomic::{AtomicBool, Ordering},
+};
+
+/// Countdown period in milliseconds
+const TIMEOUT: u32 = 30000;
+
+static RUNNING: AtomicBool = AtomicBool::new(false);
+
+/// # Start the watchdog if the WDAT enables it
+pub fn initialize() {
+    match wdat() {
+        Some(wdat) if wdat.is_enabled() => start(),
+        Some(_) => com2_println!("The watchdog is disabled."),
+        None => com2_println!("There is no WDAT."),
+    }
+}
+
+/// # Whether the watchdog is counting down
+pub fn is_running() -> bool {
+    RUNNING.load(Ordering::Acquire)
+}
+
+/// # Set the countdown period and start the watchdog
+/// The watchdog reboots the machine on expiration if the WDAT supports choosing the action.
+pub fn start() {
+    let Some(wdat) = wdat().filter(|wdat| wdat.is_enabled()) else {
+        return;
+    };
+    let countdown: u32 = wdat.countdown(TIMEOUT);
+    match [
+        Action::SetCountdownPeriod,
+        Action::SetReboot,
+        Action::SetRunningState,
+        Action::Reset,
+    ]
+    .into_iter()
+    .try_for_each(|action| wdat.run(action, countdown).map(|_| ()))
+    .and_then(|()| wdat.run(Action::QueryRunningState, countdown))
+    {
+        Ok(running) => {
+            let running: bool = running.map_or(true, |running| running != 0);
+            com2_println!(
+                "The watchdog is started with countdown {:#x?}. running = {}",
+                countdown,
+                running
+            );
+            RUNNING.store(running, Ordering::Release);
+        }
+        Err(error) => com2_println!("Can't start the watchdog. error = {:#x?}", error),
+    }
+}
+
+/// # Stop the watchdog
+pub fn stop() {
+    let Some(wdat) = wdat() else {
+        return;
+    };
+    if RUNNING.swap(false, Ordering::AcqRel) {
+        match wdat.run(Action::SetStoppedState, 0) {
+            Ok(_) => com2_println!("The watchdog is stopped."),
+            Err(error) => com2_println!("Can't stop the watchdog. error = {:#x?}", error),
+        }
+    }
+}
+
+/// # Reset the countdown
+/// Called every HPET event.
+pub fn tick() {
+    if let Some(wdat) = wdat().filter(|_| is_running()) {
+        if let Err(error) = wdat.run(Action::Reset, wdat.countdown(TIMEOUT)) {
+            com2_println!("Can't reset the watchdog. error = {:#x?}", error);
+        }
+    }
+}
+
+fn wdat() -> Option<&'static watchdog_action::Table> {
+    Argument::get().efi_system_table().rsdp().xsdt().wdat()
+}
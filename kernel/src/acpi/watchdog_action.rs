@@ -1,7 +1,7 @@
-mod instruction;
+pub mod instruction;
 
 use {
-    super::system_description,
+    super::{generic_address, system_description},
     bitfield_struct::bitfield,
     core::{fmt, mem::size_of, slice},
 };
@@ -29,10 +29,42 @@ pub struct Table {
 }
 
 impl Table {
+    /// # The countdown value for a period in milliseconds
+    /// The value is clamped between the minimum and the maximum counts.
+    pub fn countdown(&self, milliseconds: u32) -> u32 {
+        let timer_period: u32 = self.timer_period;
+        let maximum_count: u32 = self.maximum_count;
+        let minimum_count: u32 = self.minimum_count;
+        (milliseconds / timer_period.max(1)).clamp(minimum_count, maximum_count.max(minimum_count))
+    }
+
     pub fn is_correct(&self) -> bool {
         self.header.is_correct()
     }
 
+    pub fn is_enabled(&self) -> bool {
+        let flags: Flags = self.flags;
+        flags.enabled()
+    }
+
+    /// # Run the instructions of an action in the order of the table
+    /// Returns `None` if the table doesn't have the action, and otherwise the result of the last read instruction or 0.
+    /// An instruction with an invalid register stops the action.
+    pub fn run(
+        &self,
+        action: instruction::Action,
+        countdown: u32,
+    ) -> Result<Option<u32>, generic_address::Error> {
+        self.instruction_entries()
+            .iter()
+            .filter(|entry| entry.action() == action)
+            .try_fold(None, |result, entry| {
+                Ok(Some(
+                    entry.execute(countdown)?.unwrap_or(result.unwrap_or(0)),
+                ))
+            })
+    }
+
     fn instruction_entries(&self) -> &[instruction::Entry] {
         let table: *const Self = self as *const Self;
         let instruction_entries: *const Self = unsafe { table.add(1) };
@@ -6,15 +6,127 @@ use super::super::generic_address;
 #[derive(Debug)]
 #[repr(packed)]
 pub struct Entry {
-    #[allow(dead_code)]
     action: u8,
-    #[allow(dead_code)]
     flags: u8,
     __: u16,
-    #[allow(dead_code)]
     register_region: generic_address::Structure,
-    #[allow(dead_code)]
     value: u32,
-    #[allow(dead_code)]
     mask: u32,
 }
+
+impl Entry {
+    pub fn action(&self) -> Action {
+        self.action.into()
+    }
+
+    /// # Execute the instruction
+    /// Returns the result of a read instruction, which is 1 if `READ_VALUE` matches the value.
+    pub fn execute(&self, countdown: u32) -> Result<Option<u32>, generic_address::Error> {
+        let mut register: generic_address::Structure = self.register_region;
+        let bit_offset: u8 = register.register_bit_offset();
+        let mask: u64 = self.mask as u64;
+        let value: u64 = self.value as u64;
+        let instruction: Instruction = self.instruction();
+        match instruction {
+            Instruction::ReadValue => Ok(Some(
+                ((register.read()? >> bit_offset) & mask == value) as u32,
+            )),
+            Instruction::ReadCountdown => {
+                Ok(Some(((register.read()? >> bit_offset) & mask) as u32))
+            }
+            Instruction::WriteValue | Instruction::WriteCountdown => {
+                let data: u64 = if instruction == Instruction::WriteValue {
+                    value
+                } else {
+                    countdown as u64
+                };
+                let data: u64 = (data & mask) << bit_offset;
+                let data: u64 = if self.preserves_register() {
+                    data | (register.read()? & !(mask << bit_offset))
+                } else {
+                    data
+                };
+                register.write(data)?;
+                Ok(None)
+            }
+            Instruction::Reserved(_) => Ok(None),
+        }
+    }
+
+    fn instruction(&self) -> Instruction {
+        (self.flags & 0x07).into()
+    }
+
+    fn preserves_register(&self) -> bool {
+        self.flags & 0x80 != 0
+    }
+}
+
+/// # Watchdog Action
+/// ## References
+/// * [Hardware Watchdog Timers Design Specification](https://download.microsoft.com/download/a/f/7/af7777e5-7dcd-4800-8a0a-b18336565f5b/hardwarewdtspec.doc) Table 4 Watchdog Actions
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Action {
+    Reset,
+    QueryCurrentCountdownPeriod,
+    QueryCountdownPeriod,
+    SetCountdownPeriod,
+    QueryRunningState,
+    SetRunningState,
+    QueryStoppedState,
+    SetStoppedState,
+    QueryReboot,
+    SetReboot,
+    QueryShutdown,
+    SetShutdown,
+    QueryWatchdogStatus,
+    SetWatchdogStatus,
+    Reserved(u8),
+}
+
+impl From<u8> for Action {
+    fn from(action: u8) -> Self {
+        match action {
+            0x01 => Self::Reset,
+            0x04 => Self::QueryCurrentCountdownPeriod,
+            0x05 => Self::QueryCountdownPeriod,
+            0x06 => Self::SetCountdownPeriod,
+            0x08 => Self::QueryRunningState,
+            0x09 => Self::SetRunningState,
+            0x0a => Self::QueryStoppedState,
+            0x0b => Self::SetStoppedState,
+            0x10 => Self::QueryReboot,
+            0x11 => Self::SetReboot,
+            0x12 => Self::QueryShutdown,
+            0x13 => Self::SetShutdown,
+            0x20 => Self::QueryWatchdogStatus,
+            0x21 => Self::SetWatchdogStatus,
+            action => Self::Reserved(action),
+        }
+    }
+}
+
+/// # Watchdog Instruction
+/// ## References
+/// * [Hardware Watchdog Timers Design Specification](https://download.microsoft.com/download/a/f/7/af7777e5-7dcd-4800-8a0a-b18336565f5b/hardwarewdtspec.doc) Table 5 Watchdog Instructions
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Instruction {
+    ReadValue,
+    ReadCountdown,
+    WriteValue,
+    WriteCountdown,
+    Reserved(u8),
+}
+
+impl From<u8> for Instruction {
+    fn from(instruction: u8) -> Self {
+        match instruction {
+            0x00 => Self::ReadValue,
+            0x01 => Self::ReadCountdown,
+            0x02 => Self::WriteValue,
+            0x03 => Self::WriteCountdown,
+            instruction => Self::Reserved(instruction),
+        }
+    }
+}
@@ -27,7 +27,7 @@ pub use {
     guid::Guid,
     handle::Handle,
     protocol::{font::Font, graphics_output, simple_text},
-    runtime_services::RuntimeServices,
+    runtime_services::{ResetType, RuntimeServices},
     status::Status,
     system_table::SystemTable,
     table_header::TableHeader,
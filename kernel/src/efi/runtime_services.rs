@@ -24,13 +24,18 @@ pub struct RuntimeServices {
 }
 
 impl RuntimeServices {
-    pub fn shutdown(&self) {
-        let reset_type = ResetType::Shutdown;
+    /// # Reset the system
+    /// Doesn't return unless the firmware fails to reset the system.
+    pub fn reset(&self, reset_type: ResetType) {
         let status = Status::SUCCESS;
         let data_size: usize = 0;
         let data: Void = VOID;
         (self.reset_system)(reset_type, status, data_size, &data);
     }
+
+    pub fn shutdown(&self) {
+        self.reset(ResetType::Shutdown);
+    }
 }
 
 /// # GetVariable
@@ -130,12 +135,10 @@ type ResetSystem = extern "efiapi" fn(
 /// # EFI_RESET_TYPE
 /// ## References
 /// * [UEFI Specification Version 2.9](https://uefi.org/sites/default/files/resources/UEFI_Spec_2_9_2021_03_18.pdf) 8.5.1 Reset System
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug)]
 #[repr(C)]
-enum ResetType {
-    #[allow(dead_code)]
+pub enum ResetType {
     Cold,
-    #[allow(dead_code)]
     Warm,
     Shutdown,
     #[allow(dead_code)]
@@ -1,7 +1,7 @@
 use {
     super::{
-        char16, configuration, memory, simple_text, BootServices, Guid, Handle, RuntimeServices,
-        Status, TableHeader, Void,
+        char16, configuration, memory, simple_text, BootServices, Guid, Handle, ResetType,
+        RuntimeServices, Status, TableHeader, Void,
     },
     crate::{acpi, x64},
     alloc::vec::Vec,
@@ -86,6 +86,12 @@ impl SystemTable<'_> {
         self.configuration_tables.rsdp_mut()
     }
 
+    /// # Reset the system by ResetSystem
+    /// Doesn't return unless the firmware fails to reset the system.
+    pub fn reset(&self, reset_type: ResetType) {
+        self.runtime_services.reset(reset_type);
+    }
+
     pub fn shutdown(&self) -> ! {
         self.runtime_services.shutdown();
         loop {
@@ -5,24 +5,36 @@ pub mod non_maskable;
 pub use descriptor::Descriptor;
 
 use {
-    crate::{com2_println, memory, processor, task, timer, x64},
+    crate::{acpi, com2_println, iommu, memory, pci, processor, task, timer, x64},
     alloc::collections::VecDeque,
 };
 
 static mut EVENTS: VecDeque<Event> = VecDeque::new();
 
+#[allow(clippy::large_enum_variant)]
 pub enum Event {
+    AcpiNotify {
+        object: acpi::machine_language::name::Path,
+        value: u8,
+    },
+    Aer,
     ApicTimer,
     Hpet,
     Interprocessor {
         sender_local_apic_id: u8,
         message: processor::message::Content,
     },
+    Iommu,
     Pit,
     Rtc,
+    Sci(acpi::event::Sci),
 }
 
 impl Event {
+    pub fn acpi_notify(object: acpi::machine_language::name::Path, value: u8) -> Self {
+        Self::AcpiNotify { object, value }
+    }
+
     pub fn interprocessor(
         controller: &processor::Controller,
         message: processor::message::Content,
@@ -43,14 +55,29 @@ impl Event {
 
     pub fn process(self) {
         match self {
+            Self::AcpiNotify { object, value } => {
+                com2_println!(
+                    "ACPI notify event. object = {:#x?}, value = {:#x?}",
+                    object,
+                    value
+                );
+                acpi::machine_language::notification::dispatch(&object, value);
+            }
+            Self::Aer => {
+                com2_println!("AER event.");
+                pci::aer::handle();
+            }
             Self::ApicTimer => com2_println!("APIC timer event."),
             Self::Hpet => {
                 com2_println!("HPET event.");
+                acpi::thermal::tick();
+                acpi::watchdog::tick();
                 processor::Controller::get_mut_all()
                     .filter(|processor| processor.is_initialized())
                     .for_each(|processor| {
                         processor.send(processor::message::Content::HpetInterrupt)
                     });
+                processor::performance::tick();
             }
             Self::Interprocessor {
                 sender_local_apic_id,
@@ -61,6 +88,10 @@ impl Event {
                     .unwrap();
                 message.process(processor);
             }
+            Self::Iommu => {
+                com2_println!("IOMMU event.");
+                iommu::handle_faults();
+            }
             Self::Pit => {
                 com2_println!("PIT event.");
                 processor::Controller::get_mut_all()
@@ -77,6 +108,10 @@ impl Event {
                         processor.send(processor::message::Content::RtcInterrupt)
                     });
             }
+            Self::Sci(sci) => {
+                com2_println!("SCI event. {:#x?}", sci);
+                sci.process();
+            }
         }
     }
 
@@ -93,7 +128,10 @@ pub const APIC_TIMER_INTERRUPT: u8 = 0x98;
 pub const HPET_INTERRUPT: u8 = 0x22;
 pub const PIT_INTERRUPT: u8 = 0x20;
 pub const RTC_INTERRUPT: u8 = 0x28;
+pub const SCI_INTERRUPT: u8 = 0x29;
 pub const INTERPROCESSOR_INTERRUPT: u8 = 0x99;
+pub const AER_INTERRUPT: u8 = 0x9a;
+pub const IOMMU_INTERRUPT: u8 = 0x9b;
 pub const SPURIOUS_INTERRUPT: u8 = 0x9f;
 
 pub enum Handler {
@@ -559,8 +597,8 @@ pub fn register_handlers(idt: &mut descriptor::Table) {
         1, // int 0x97 IRQ 0x77
         1, // int 0x98 APIC timer interrupt
         1, // int 0x99 Interprocessor interrupt
-        1, // int 0x9a
-        1, // int 0x9b
+        1, // int 0x9a PCI Express Advanced Error Reporting interrupt
+        1, // int 0x9b IOMMU fault event interrupt
         1, // int 0x9c
         1, // int 0x9d
         1, // int 0x9e
@@ -1372,14 +1410,19 @@ extern "x86-interrupt" fn handler_0x28(_stack_frame: StackFrame) {
     }
 }
 
-/// # IRQ 0x09
-extern "x86-interrupt" fn handler_0x29(stack_frame: StackFrame) {
-    let interrupt_number: u8 = 0x29;
+/// # IRQ 0x09 SCI
+extern "x86-interrupt" fn handler_0x29(_stack_frame: StackFrame) {
     if let Some(current_task) = task::Controller::get_current_mut() {
         current_task.start_interrupt();
     }
-    com2_println!("interrupt_number = {:#x?}", interrupt_number);
-    com2_println!("stack_frame = {:#x?}", stack_frame);
+    let sci = acpi::event::Sci::take();
+    x64::msr::ia32::ApicBase::get()
+        .unwrap()
+        .registers_mut()
+        .end_interruption();
+    if !sci.is_empty() {
+        Event::push(Event::Sci(sci));
+    }
     if let Some(current_task) = task::Controller::get_current_mut() {
         current_task.end_interrupt();
     }
@@ -2846,25 +2889,31 @@ extern "x86-interrupt" fn handler_0x99(_stack_frame: StackFrame) {
     }
 }
 
-extern "x86-interrupt" fn handler_0x9a(stack_frame: StackFrame) {
-    let interrupt_number: u8 = 0x9a;
+/// # PCI Express Advanced Error Reporting interrupt
+extern "x86-interrupt" fn handler_0x9a(_stack_frame: StackFrame) {
     if let Some(current_task) = task::Controller::get_current_mut() {
         current_task.start_interrupt();
     }
-    com2_println!("interrupt_number = {:#x?}", interrupt_number);
-    com2_println!("stack_frame = {:#x?}", stack_frame);
+    x64::msr::ia32::ApicBase::get()
+        .unwrap()
+        .registers_mut()
+        .end_interruption();
+    Event::push(Event::Aer);
     if let Some(current_task) = task::Controller::get_current_mut() {
         current_task.end_interrupt();
     }
 }
 
-extern "x86-interrupt" fn handler_0x9b(stack_frame: StackFrame) {
-    let interrupt_number: u8 = 0x9b;
+/// # IOMMU fault event interrupt
+extern "x86-interrupt" fn handler_0x9b(_stack_frame: StackFrame) {
     if let Some(current_task) = task::Controller::get_current_mut() {
         current_task.start_interrupt();
     }
-    com2_println!("interrupt_number = {:#x?}", interrupt_number);
-    com2_println!("stack_frame = {:#x?}", stack_frame);
+    x64::msr::ia32::ApicBase::get()
+        .unwrap()
+        .registers_mut()
+        .end_interruption();
+    Event::push(Event::Iommu);
     if let Some(current_task) = task::Controller::get_current_mut() {
         current_task.end_interrupt();
     }
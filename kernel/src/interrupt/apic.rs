@@ -79,11 +79,38 @@ impl From<DeliveryStatus> for bool {
     }
 }
 
+/// # Polarity
+/// ## References
+/// * [Intel 64 and IA-32 Architectures Software Developer's Manual December 2023](https://www.intel.com/content/www/us/en/developer/articles/technical/intel-sdm.html) 3.11.5.1 Figure 11-8. Local Vector Table (LVT)
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Polarity {
+    ActiveHigh,
+    ActiveLow,
+}
+
+impl From<bool> for Polarity {
+    fn from(polarity: bool) -> Self {
+        match polarity {
+            false => Self::ActiveHigh,
+            true => Self::ActiveLow,
+        }
+    }
+}
+
+impl From<Polarity> for bool {
+    fn from(polarity: Polarity) -> Self {
+        match polarity {
+            Polarity::ActiveHigh => false,
+            Polarity::ActiveLow => true,
+        }
+    }
+}
+
 /// # Trigger Mode
 /// ## References
 /// * [Intel 64 and IA-32 Architectures Software Developer's Manual December 2023](https://www.intel.com/content/www/us/en/developer/articles/technical/intel-sdm.html) 3.11.5.1 Figure 11-8. Local Vector Table (LVT)
 /// * [Intel 64 and IA-32 Architectures Software Developer's Manual December 2023](https://www.intel.com/content/www/us/en/developer/articles/technical/intel-sdm.html) 3.11.6.1 Figure 11-12. Interrupt Command Register (ICR)
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum TriggerMode {
     Edge,
     Level,
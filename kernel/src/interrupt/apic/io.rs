@@ -5,7 +5,12 @@ pub mod index;
 pub mod redirection;
 pub mod version;
 
-use {alloc::vec::Vec, core::fmt};
+use {
+    super::{Polarity, TriggerMode},
+    crate::iommu,
+    alloc::vec::Vec,
+    core::fmt,
+};
 
 /// # Advanced Programmable Interrupt Controller (APIC) Registers
 /// ## References
@@ -42,6 +47,27 @@ impl Registers {
             .redirection_table_entry(irq)
             .unwrap()
             .with_redirection(local_apic_id, interrupt_number);
+        let redirection_table_entry: redirection::table::Entry =
+            iommu::remap_io_apic(self.identification().id(), redirection_table_entry);
+        self.set_redirection_table_entry(irq, redirection_table_entry);
+    }
+
+    /// # Redirect a level triggered or active low interrupt such as a PCI interrupt
+    pub fn redirect_with_signal(
+        &mut self,
+        irq: u8,
+        local_apic_id: u8,
+        interrupt_number: u8,
+        polarity: Polarity,
+        trigger_mode: TriggerMode,
+    ) {
+        let redirection_table_entry: redirection::table::Entry = self
+            .redirection_table_entry(irq)
+            .unwrap()
+            .with_redirection(local_apic_id, interrupt_number)
+            .with_signal(polarity, trigger_mode);
+        let redirection_table_entry: redirection::table::Entry =
+            iommu::remap_io_apic(self.identification().id(), redirection_table_entry);
         self.set_redirection_table_entry(irq, redirection_table_entry);
     }
 
@@ -57,6 +83,19 @@ impl Registers {
         })
     }
 
+    /// # Restore redirection table entries saved by `redirection_table_entries`
+    pub fn set_redirection_table_entries(
+        &mut self,
+        redirection_table_entries: &[redirection::table::Entry],
+    ) {
+        redirection_table_entries
+            .iter()
+            .enumerate()
+            .for_each(|(irq, redirection_table_entry)| {
+                self.set_redirection_table_entry(irq as u8, *redirection_table_entry)
+            });
+    }
+
     pub fn version(&mut self) -> version::Register {
         self.get_u32(1).into()
     }
@@ -14,3 +14,9 @@ pub struct Register {
     #[bits(4)]
     __: u8,
 }
+
+impl Register {
+    pub fn id(&self) -> u8 {
+        self.apic_identification()
+    }
+}
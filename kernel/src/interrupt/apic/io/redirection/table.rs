@@ -1,4 +1,7 @@
-use {super::super::super::DeliveryMode, bitfield_struct::bitfield};
+use {
+    super::super::super::{DeliveryMode, Polarity, TriggerMode},
+    bitfield_struct::bitfield,
+};
 
 /// # Redirection Table Entry
 /// ## References
@@ -22,10 +25,27 @@ pub struct Entry {
 }
 
 impl Entry {
+    pub fn interrupt_number(&self) -> u8 {
+        self.vector()
+    }
+
     pub fn is_enabled(&self) -> bool {
         !self.mask()
     }
 
+    pub fn is_level_triggered(&self) -> bool {
+        self.trigger_mode()
+    }
+
+    /// # Whether the entry is in the remappable format of an IOMMU
+    pub fn is_remappable(&self) -> bool {
+        self.extended_destination_id() & 1 != 0
+    }
+
+    pub fn local_apic_id(&self) -> u8 {
+        self.destination_id()
+    }
+
     pub fn with_redirection(self, local_apic_id: u8, interrupt_number: u8) -> Self {
         self.with_vector(interrupt_number)
             .with_delivery_mode(DeliveryMode::Fixed.into())
@@ -37,4 +57,20 @@ impl Entry {
             .with_extended_destination_id(0)
             .with_destination_id(local_apic_id)
     }
+
+    pub fn with_signal(self, polarity: Polarity, trigger_mode: TriggerMode) -> Self {
+        self.with_polarity(polarity.into())
+            .with_trigger_mode(trigger_mode.into())
+    }
+
+    /// # Convert the entry into the remappable format
+    /// The destination is replaced by the index of the interrupt remapping table entry.
+    /// ## References
+    /// * [Intel Virtualization Technology for Directed I/O](https://software.intel.com/content/dam/develop/external/us/en/documents-tps/vt-directed-io-spec.pdf) 5.1.5.1 I/OxAPIC Programming
+    pub fn with_remapping(self, index: u16) -> Self {
+        self.with_delivery_mode(0)
+            .with_destination_mode(index & 0x8000 != 0)
+            .with_extended_destination_id(1 | ((index as u8) << 1))
+            .with_destination_id((index >> 7) as u8)
+    }
 }
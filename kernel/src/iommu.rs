@@ -0,0 +1,289 @@
+//! # Intel VT-d IOMMU
+//! Every DMA Remapping Hardware Unit in the DMAR translates the DMA of the PCI functions in its Device Scopes, or of all the other functions in its segment if it includes all PCI devices.
+//! Each function is put in its own domain whose second-level page table maps only the Reserved Memory Regions of the function and the buffers which its driver passes to `dma_map`, so any other DMA faults.
+//! The functions behind a PCI Express to PCI bridge share a domain because the bridge may issue their DMA with its own requester ID.
+//! The faults are reported through the fault event MSI and logged with the function and the reason.
+//! If the units support interrupt remapping, MSIs and the I/O APIC redirection table entries are in the remappable format, and interrupts in the compatibility format are blocked.
+//! The redirection table entries are rewritten before the units start remapping interrupts.
+//! Interrupts are remapped in the xAPIC interrupt mode because the local APICs are in xAPIC mode, so the destinations are 8-bit APIC IDs.
+//! ## References
+//! * [Intel Virtualization Technology for Directed I/O](https://software.intel.com/content/dam/develop/external/us/en/documents-tps/vt-directed-io-spec.pdf) 3 DMA Remapping
+//! * [Intel Virtualization Technology for Directed I/O](https://software.intel.com/content/dam/develop/external/us/en/documents-tps/vt-directed-io-spec.pdf) 5 Interrupt Remapping
+//! * [PCI Express to PCI/PCI-X Bridge Specification Revision 1.0](https://picture.iczhiku.com/resource/eetop/wYIEDKFRorpoPvvV.pdf) 2.3 Transaction Forwarding
+
+mod context;
+mod domain;
+mod interrupt_remapping;
+mod invalidation_queue;
+mod register;
+mod root;
+mod scope;
+mod second_level;
+mod unit;
+
+use {
+    crate::{
+        com2_println,
+        interrupt::apic::io::{self, redirection},
+        pci::{
+            self,
+            capability::{self, msi::message, pci_express},
+        },
+        sync::spin,
+        x64, Argument,
+    },
+    alloc::vec::Vec,
+    core::{mem, ops::Range},
+    scope::Scope,
+    unit::Unit,
+};
+
+static UNITS: spin::Lock<Vec<Unit>> = spin::Lock::new(Vec::new());
+
+/// # Start translating DMA of the enumerated functions
+/// The faults are reported to the local APIC.
+pub fn initialize(configuration: &pci::Configuration, local_apic_id: u8) {
+    let Some(dmar) = Argument::get().efi_system_table().rsdp().xsdt().dmar() else {
+        com2_println!("There is no DMAR.");
+        return;
+    };
+    com2_println!(
+        "The DMAR reports host address width {}.",
+        dmar.host_address_width()
+    );
+    let mut units: Vec<Unit> = dmar
+        .hardware_units()
+        .filter_map(|structure| {
+            Unit::new(dmar, structure, local_apic_id)
+                .map_err(|error| {
+                    com2_println!(
+                        "The IOMMU at {:#x?} is unusable. {:?}",
+                        structure.register_base_address(),
+                        error
+                    )
+                })
+                .ok()
+        })
+        .collect();
+    let functions: Vec<pci::FunctionAddress> = configuration
+        .functions()
+        .map(|function| function.address())
+        .collect();
+    functions.iter().for_each(|function| {
+        if let Some(unit) = unit_mut(&mut units, *function) {
+            if let Err(error) = unit.attach(*function, requester_ids(configuration, *function)) {
+                com2_println!("PCI {} can't be in a domain. {:?}", function, error);
+            }
+        }
+    });
+    dmar.reserved_memory_regions().for_each(|region| {
+        let scopes: Vec<Scope> = region
+            .scopes()
+            .filter_map(|scope| Scope::new(region.segment_number(), scope))
+            .collect();
+        functions
+            .iter()
+            .filter(|function| scopes.iter().any(|scope| scope.contains(**function)))
+            .for_each(|function| {
+                if let Some(unit) = units.iter_mut().find(|unit| unit.has(*function)) {
+                    unit.map_identity(*function, region.range());
+                }
+            });
+    });
+    *UNITS.lock() = units;
+    enable();
+}
+
+/// # Map a buffer for DMA of a function
+/// It returns the IOVA which the function accesses the buffer at, and the last IOVA of the buffer doesn't exceed the DMA mask of the function.
+/// The physical address is returned as is if no IOMMU translates the DMA of the function.
+pub fn dma_map(
+    function: pci::FunctionAddress,
+    paddr: usize,
+    size: usize,
+    dma_mask: u64,
+) -> Result<u64, Error> {
+    let paddr: u64 = paddr as u64;
+    match UNITS.lock().iter_mut().find(|unit| unit.has(function)) {
+        Some(unit) => unit.map(function, paddr, size, dma_mask),
+        None => (paddr + size as u64 - 1 <= dma_mask)
+            .then_some(paddr)
+            .ok_or(Error::AboveDmaMask),
+    }
+}
+
+/// # Unmap a buffer mapped by `dma_map`
+pub fn dma_unmap(function: pci::FunctionAddress, iova: u64, size: usize) {
+    if let Some(unit) = UNITS.lock().iter_mut().find(|unit| unit.has(function)) {
+        unit.unmap(function, iova, size);
+    }
+}
+
+/// # MSI message of an interrupt from a function to a local APIC
+/// The message is in the remappable format if a unit remaps the interrupts of the function.
+/// Otherwise, the message is in the compatibility format.
+pub fn msi_message(
+    function: pci::FunctionAddress,
+    destination: u8,
+    interrupt_number: u8,
+) -> (u64, u32) {
+    UNITS
+        .lock()
+        .iter_mut()
+        .find(|unit| unit.has(function) && unit.remaps_interrupts())
+        .and_then(|unit| unit.msi_message(function, destination, interrupt_number))
+        .unwrap_or_else(|| {
+            (
+                message::address(destination),
+                message::data(interrupt_number),
+            )
+        })
+}
+
+/// # Remap a redirection table entry of an I/O APIC
+/// The entry is returned as is if no unit remaps the interrupts of the I/O APIC.
+pub fn remap_io_apic(
+    io_apic_id: u8,
+    entry: redirection::table::Entry,
+) -> redirection::table::Entry {
+    if !entry.is_enabled() || entry.is_remappable() {
+        return entry;
+    }
+    let mut units = UNITS.lock();
+    let index: Option<usize> = units
+        .iter()
+        .position(|unit| unit.has_io_apic(io_apic_id))
+        .or_else(|| {
+            units
+                .iter()
+                .position(|unit| unit.includes_pci_all() && unit.segment_number() == 0)
+        })
+        .filter(|index| units[*index].remaps_interrupts());
+    let Some(index) = index else {
+        return entry;
+    };
+    units[index]
+        .remap_io_apic(io_apic_id, entry)
+        .unwrap_or_else(|| {
+            com2_println!(
+                "An interrupt {:#x?} of the I/O APIC {} can't be remapped.",
+                entry.interrupt_number(),
+                io_apic_id
+            );
+            entry
+        })
+}
+
+/// # Handle the faults recorded by the units
+/// It is called on the IOMMU fault event interrupt.
+pub fn handle_faults() {
+    UNITS.lock().iter().for_each(Unit::handle_faults);
+}
+
+/// # Restore the units after sleep
+/// It is called before the saved I/O APIC redirection table entries are restored, which are in the remappable format.
+pub fn resume() {
+    enable();
+}
+
+#[derive(Debug)]
+pub enum Error {
+    /// The buffer can't be reached by the DMA mask of the function which no IOMMU translates.
+    AboveDmaMask,
+    InvalidationTimeout,
+    NoDomain,
+    NoIova,
+    UnsupportedAddressWidth,
+}
+
+/// # Start the units
+/// The enabled I/O APIC redirection table entries are rewritten in the remappable format after the interrupt remapping tables are pointed and before interrupt remapping is enabled.
+fn enable() {
+    UNITS.lock().iter_mut().for_each(Unit::enable);
+    remap_io_apic_entries();
+    UNITS
+        .lock()
+        .iter_mut()
+        .for_each(Unit::enable_interrupt_remapping);
+}
+
+/// # The physical address of a translation structure
+fn paddr<T>(structure: &T) -> u64 {
+    Argument::get().paging().vaddr2paddr(structure).unwrap() as u64
+}
+
+/// # Write back a translation structure modified by the processor
+/// A unit which doesn't snoop the processor caches reads the structure from the memory.
+/// ## References
+/// * [Intel Virtualization Technology for Directed I/O](https://software.intel.com/content/dam/develop/external/us/en/documents-tps/vt-directed-io-spec.pdf) 11.4.3 Extended Capability Register
+fn write_back<T: ?Sized>(structure: &T, coherent: bool) {
+    const CACHE_LINE: usize = 0x40;
+    if !coherent {
+        let start: usize = structure as *const T as *const u8 as usize;
+        let end: usize = start + mem::size_of_val(structure);
+        let lines: Range<usize> = start / CACHE_LINE..end.div_ceil(CACHE_LINE);
+        lines.for_each(|line| x64::clflush(line * CACHE_LINE));
+        x64::mfence();
+    }
+}
+
+/// # The requester IDs which a function issues DMA with
+/// A PCI Express to PCI bridge takes the ownership of the transactions from its secondary bus with the requester ID of the device 0 and the function 0 on the secondary bus, and a PCI bridge or a PCI to PCI Express bridge takes them with its own requester ID.
+fn requester_ids(configuration: &pci::Configuration, function: pci::FunctionAddress) -> Vec<u16> {
+    let segment_number: u16 = function.segment_number();
+    let mut requester_ids: Vec<u16> = Vec::from([function.requester_id()]);
+    let mut bus_number: u8 = function.bus_number();
+    while let Some(bridge) = configuration
+        .upstream_bridge(segment_number, bus_number)
+        .map(|bridge| bridge.address())
+        .filter(|bridge| bridge.bus_number() < bus_number)
+    {
+        let pci_express: Option<pci_express::capability::Register> = bridge
+            .capability(capability::Id::PciExpress)
+            .map(|pci_express| ((bridge.read(pci_express) >> u16::BITS) as u16).into());
+        let alias: Option<u16> = match pci_express {
+            Some(pci_express) if pci_express.is_pci_express_to_pci_bridge() => {
+                Some(pci::FunctionAddress::new(segment_number, bus_number, 0, 0).requester_id())
+            }
+            Some(pci_express) if !pci_express.is_pci_to_pci_express_bridge() => None,
+            _ => Some(bridge.requester_id()),
+        };
+        if let Some(alias) = alias.filter(|alias| !requester_ids.contains(alias)) {
+            requester_ids.push(alias);
+        }
+        bus_number = bridge.bus_number();
+    }
+    requester_ids
+}
+
+/// # The unit translating DMA of a function
+/// A unit reporting the function in its Device Scopes is preferred to a unit including all PCI devices.
+fn unit_mut(units: &mut [Unit], function: pci::FunctionAddress) -> Option<&mut Unit> {
+    let index: usize = units
+        .iter()
+        .position(|unit| unit.contains(function))
+        .or_else(|| {
+            units.iter().position(|unit| {
+                unit.includes_pci_all() && unit.segment_number() == function.segment_number()
+            })
+        })?;
+    units.get_mut(index)
+}
+
+/// # Rewrite the enabled I/O APIC redirection table entries in the remappable format
+fn remap_io_apic_entries() {
+    let io_apic: &mut io::Registers = Argument::get()
+        .efi_system_table_mut()
+        .rsdp_mut()
+        .xsdt_mut()
+        .madt_mut()
+        .io_apic_mut()
+        .registers_mut();
+    let io_apic_id: u8 = io_apic.identification().id();
+    let entries: Vec<redirection::table::Entry> = io_apic
+        .redirection_table_entries()
+        .into_iter()
+        .map(|entry| remap_io_apic(io_apic_id, entry))
+        .collect();
+    io_apic.set_redirection_table_entries(&entries);
+}
@@ -0,0 +1,92 @@
+use {super::write_back, bitfield_struct::bitfield, core::ptr};
+
+/// # Context Table
+/// The context entry of each device and function on a bus assigns a domain to the function.
+/// ## References
+/// * [Intel Virtualization Technology for Directed I/O](https://software.intel.com/content/dam/develop/external/us/en/documents-tps/vt-directed-io-spec.pdf) 9.3 Context Entry
+#[repr(align(4096))]
+pub struct Table {
+    entries: [Entry; 0x100],
+}
+
+impl Table {
+    /// # Translate the DMA of a function through the second-level page table of a domain
+    pub fn set(
+        &mut self,
+        device: u8,
+        function: u8,
+        domain_id: u16,
+        page_table_levels: usize,
+        page_table: u64,
+        coherent: bool,
+    ) {
+        let index: usize = ((device as usize) << 3) | (function as usize);
+        let upper: Upper = Upper::new()
+            .with_address_width((page_table_levels - 2) as u8)
+            .with_domain_identifier(domain_id);
+        let lower: Lower = Lower::new()
+            .with_present(true)
+            .with_translation_type(Lower::UNTRANSLATED_REQUESTS)
+            .with_second_level_page_translation_pointer(
+                page_table >> Lower::SECOND_LEVEL_PAGE_TRANSLATION_POINTER_OFFSET,
+            );
+        let entry: &mut Entry = &mut self.entries[index];
+        // The present bit is written last.
+        unsafe {
+            ptr::write_volatile(&mut entry.upper, upper);
+            ptr::write_volatile(&mut entry.lower, lower);
+        }
+        write_back(entry, coherent);
+    }
+}
+
+impl Default for Table {
+    fn default() -> Self {
+        let entries: [Entry; 0x100] = [Entry::default(); 0x100];
+        Self { entries }
+    }
+}
+
+#[derive(Clone, Copy)]
+#[repr(C)]
+struct Entry {
+    lower: Lower,
+    upper: Upper,
+}
+
+impl Default for Entry {
+    fn default() -> Self {
+        let lower: Lower = Lower::new();
+        let upper: Upper = Upper::new();
+        Self { lower, upper }
+    }
+}
+
+#[bitfield(u64)]
+struct Lower {
+    present: bool,
+    fault_processing_disable: bool,
+    #[bits(2)]
+    translation_type: u8,
+    #[bits(8)]
+    __: u8,
+    #[bits(52)]
+    second_level_page_translation_pointer: u64,
+}
+
+impl Lower {
+    /// Untranslated requests are translated by the second-level page table.
+    const UNTRANSLATED_REQUESTS: u8 = 0b00;
+}
+
+#[bitfield(u64)]
+struct Upper {
+    #[bits(3)]
+    address_width: u8,
+    #[bits(4)]
+    __: u8,
+    __: bool,
+    domain_identifier: u16,
+    #[bits(40)]
+    __: u64,
+}
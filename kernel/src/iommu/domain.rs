@@ -0,0 +1,128 @@
+use {
+    super::second_level,
+    alloc::vec::Vec,
+    core::ops::{Range, RangeInclusive},
+};
+
+/// # Domain
+/// The DMA of the functions in a domain is translated through the same second-level page table.
+/// IOVAs are allocated upward above 4 GiB, or below 4 GiB for a DMA mask which can't reach them, and are not reused after they are unmapped.
+/// The IOVAs below 4 GiB skip the identity mapped ranges and the interrupt address range.
+/// ## References
+/// * [Intel Virtualization Technology for Directed I/O](https://software.intel.com/content/dam/develop/external/us/en/documents-tps/vt-directed-io-spec.pdf) 3.2 Domains and Address Translation
+/// * [Intel Virtualization Technology for Directed I/O](https://software.intel.com/content/dam/develop/external/us/en/documents-tps/vt-directed-io-spec.pdf) 3.15 Handling Requests to Interrupt Address Range
+pub struct Domain {
+    coherent: bool,
+    id: u16,
+    iovas: Range<u64>,
+    levels: usize,
+    low_iovas: Range<u64>,
+    page_table: second_level::Table,
+    reserved: Vec<Range<u64>>,
+}
+
+impl Domain {
+    /// DMA to the interrupt address range is an interrupt request.
+    const INTERRUPT_ADDRESSES: Range<u64> = 0xfee00000..0xfef00000;
+    /// The first MiB is left to legacy devices.
+    const LOW_IOVAS: Range<u64> = 0x100000..(1 << 32);
+
+    pub fn new(id: u16, levels: usize, iovas: Range<u64>, coherent: bool) -> Self {
+        let page_table = second_level::Table::new(coherent);
+        let reserved: Vec<Range<u64>> = Vec::from([Self::INTERRUPT_ADDRESSES]);
+        Self {
+            coherent,
+            id,
+            iovas,
+            levels,
+            low_iovas: Self::LOW_IOVAS,
+            page_table,
+            reserved,
+        }
+    }
+
+    /// # Allocate IOVAs for a buffer
+    /// The IOVA keeps the offset of the buffer in its page, and the last IOVA of the buffer doesn't exceed the DMA mask.
+    pub fn allocate(&mut self, paddr: u64, size: usize, dma_mask: u64) -> Option<u64> {
+        let pages: Range<u64> = Self::pages(paddr, size);
+        let length: u64 = pages.end - pages.start;
+        let limit: u64 = dma_mask.saturating_add(1);
+        let start: u64 = Self::allocate_in(&mut self.iovas, &[], length, limit)
+            .or_else(|| Self::allocate_in(&mut self.low_iovas, &self.reserved, length, limit))?;
+        Some(start + (paddr - pages.start))
+    }
+
+    pub fn id(&self) -> u16 {
+        self.id
+    }
+
+    /// # Map the IOVAs of a buffer to its physical addresses
+    pub fn map(&mut self, iova: u64, paddr: u64, size: usize) {
+        let iova: u64 = iova - paddr % second_level::Table::PAGE_SIZE;
+        Self::pages(paddr, size)
+            .step_by(second_level::Table::PAGE_SIZE as usize)
+            .enumerate()
+            .for_each(|(index, paddr)| {
+                let iova: u64 = iova + (index as u64) * second_level::Table::PAGE_SIZE;
+                self.page_table.map(self.levels, iova, paddr, self.coherent);
+            });
+    }
+
+    /// # Map a physical address range to the same IOVAs
+    /// The range is not allocated for buffers.
+    pub fn map_identity(&mut self, range: RangeInclusive<usize>) {
+        let paddr: u64 = *range.start() as u64;
+        let size: usize = range.end() + 1 - range.start();
+        self.reserved.push(Self::pages(paddr, size));
+        self.map(paddr, paddr, size);
+    }
+
+    pub fn levels(&self) -> usize {
+        self.levels
+    }
+
+    pub fn page_table(&self) -> u64 {
+        self.page_table.paddr()
+    }
+
+    pub fn unmap(&mut self, iova: u64, size: usize) {
+        Self::pages(iova, size)
+            .step_by(second_level::Table::PAGE_SIZE as usize)
+            .for_each(|iova| self.page_table.unmap(self.levels, iova, self.coherent));
+    }
+
+    /// # Allocate IOVAs from the bottom of a range
+    /// The allocation moves above a reserved range which it overlaps, and it fails if it exceeds the limit.
+    fn allocate_in(
+        iovas: &mut Range<u64>,
+        reserved: &[Range<u64>],
+        length: u64,
+        limit: u64,
+    ) -> Option<u64> {
+        let limit: u64 = iovas.end.min(limit);
+        let mut start: u64 = iovas.start;
+        loop {
+            let end: u64 = start.checked_add(length).filter(|end| *end <= limit)?;
+            match reserved
+                .iter()
+                .filter(|reserved| reserved.start < end && start < reserved.end)
+                .map(|reserved| reserved.end)
+                .max()
+            {
+                Some(reserved_end) => start = reserved_end,
+                None => {
+                    iovas.start = end;
+                    return Some(start);
+                }
+            }
+        }
+    }
+
+    /// # The pages containing a buffer
+    fn pages(address: u64, size: usize) -> Range<u64> {
+        let page_size: u64 = second_level::Table::PAGE_SIZE;
+        let start: u64 = address - address % page_size;
+        let end: u64 = (address + size as u64).div_ceil(page_size) * page_size;
+        start..end
+    }
+}
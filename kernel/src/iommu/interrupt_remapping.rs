@@ -0,0 +1,140 @@
+use {super::write_back, bitfield_struct::bitfield, core::ptr};
+
+/// # MSI Message in the remappable format
+/// The subhandle in the data is added to the handle in the address, so the data is 0.
+/// ## References
+/// * [Intel Virtualization Technology for Directed I/O](https://software.intel.com/content/dam/develop/external/us/en/documents-tps/vt-directed-io-spec.pdf) 5.1.2.2 Interrupt Requests in Remappable Format
+pub fn message(handle: u16) -> (u64, u32) {
+    let handle: u64 = handle as u64;
+    let interrupt_format: u64 = 1 << 4;
+    let subhandle_valid: u64 = 1 << 3;
+    let address: u64 = 0xfee00000
+        | ((handle & 0x7fff) << 5)
+        | interrupt_format
+        | subhandle_valid
+        | ((handle >> 15) << 2);
+    let data: u32 = 0;
+    (address, data)
+}
+
+/// # Interrupt Remapping Table
+/// ## References
+/// * [Intel Virtualization Technology for Directed I/O](https://software.intel.com/content/dam/develop/external/us/en/documents-tps/vt-directed-io-spec.pdf) 9.9 Interrupt Remapping Table Entry (IRTE) for Remapped Interrupts
+#[repr(align(4096))]
+pub struct Table {
+    entries: [Entry; Table::ENTRIES],
+}
+
+impl Table {
+    pub const ENTRIES: usize = 0x100;
+
+    /// # Put an entry in the table
+    /// The handle of the same entry is returned if it is already in the table, so an interrupt programmed again doesn't consume another entry.
+    pub fn allocate(&mut self, entry: Entry, coherent: bool) -> Option<u16> {
+        let index: usize = self
+            .entries
+            .iter()
+            .position(|allocated| allocated.is_present() && allocated.is(&entry))
+            .or_else(|| {
+                self.entries
+                    .iter()
+                    .position(|allocated| !allocated.is_present())
+            })?;
+        let allocated: &mut Entry = &mut self.entries[index];
+        // The present bit is written last.
+        unsafe {
+            ptr::write_volatile(&mut allocated.upper, entry.upper);
+            ptr::write_volatile(&mut allocated.lower, entry.lower);
+        }
+        write_back(allocated, coherent);
+        Some(index as u16)
+    }
+}
+
+impl Default for Table {
+    fn default() -> Self {
+        let entries: [Entry; Table::ENTRIES] = [Entry::default(); Table::ENTRIES];
+        Self { entries }
+    }
+}
+
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct Entry {
+    lower: Lower,
+    upper: Upper,
+}
+
+impl Entry {
+    const SOURCE_VALIDATION_REQUESTER_ID: u8 = 0b01;
+
+    /// # A fixed interrupt to a local APIC
+    /// The destination is the xAPIC ID because the table is used in the xAPIC interrupt mode.
+    /// Only the requester identified by `source` can send the interrupt if it is known.
+    pub fn new(
+        destination: u8,
+        interrupt_number: u8,
+        level_triggered: bool,
+        source: Option<u16>,
+    ) -> Self {
+        let destination_id: u32 = (destination as u32) << 8;
+        let lower: Lower = Lower::new()
+            .with_present(true)
+            .with_trigger_mode(level_triggered)
+            .with_vector(interrupt_number)
+            .with_destination_id(destination_id);
+        let upper: Upper = source.map_or(Upper::new(), |source| {
+            Upper::new()
+                .with_source_identifier(source)
+                .with_source_validation_type(Self::SOURCE_VALIDATION_REQUESTER_ID)
+        });
+        Self { lower, upper }
+    }
+
+    fn is(&self, other: &Self) -> bool {
+        u64::from(self.lower) == u64::from(other.lower)
+            && u64::from(self.upper) == u64::from(other.upper)
+    }
+
+    fn is_present(&self) -> bool {
+        self.lower.present()
+    }
+}
+
+impl Default for Entry {
+    fn default() -> Self {
+        let lower: Lower = Lower::new();
+        let upper: Upper = Upper::new();
+        Self { lower, upper }
+    }
+}
+
+#[bitfield(u64)]
+struct Lower {
+    present: bool,
+    fault_processing_disable: bool,
+    destination_mode: bool,
+    redirection_hint: bool,
+    trigger_mode: bool,
+    #[bits(3)]
+    delivery_mode: u8,
+    #[bits(4)]
+    available: u8,
+    #[bits(3)]
+    __: u8,
+    irte_mode: bool,
+    vector: u8,
+    __: u8,
+    destination_id: u32,
+}
+
+#[bitfield(u64)]
+struct Upper {
+    source_identifier: u16,
+    #[bits(2)]
+    source_id_qualifier: u8,
+    #[bits(2)]
+    source_validation_type: u8,
+    #[bits(44)]
+    __: u64,
+}
@@ -0,0 +1,168 @@
+use {
+    super::{
+        paddr,
+        register::{invalidation_queue_address, Registers},
+        write_back, Error,
+    },
+    crate::timer,
+    alloc::boxed::Box,
+    core::ptr,
+};
+
+/// # Invalidation Queue
+/// Each invalidation is followed by an invalidation wait descriptor, and the queue is waited until the hardware writes the status or the wait times out.
+/// The descriptors are written back and the status is read from the memory for a unit which doesn't snoop the processor caches.
+/// ## References
+/// * [Intel Virtualization Technology for Directed I/O](https://software.intel.com/content/dam/develop/external/us/en/documents-tps/vt-directed-io-spec.pdf) 6.5.2 Queued Invalidation Interface
+pub struct Queue {
+    coherent: bool,
+    descriptors: Box<Descriptors>,
+    status: Box<u32>,
+    tail: usize,
+}
+
+impl Queue {
+    const DESCRIPTORS: usize = 0x100;
+    const STATUS_DONE: u32 = 1;
+    const TIMEOUT_MICROSECONDS: usize = 1000000;
+    const POLLING_INTERVAL_MICROSECONDS: usize = 10;
+
+    pub fn new(coherent: bool) -> Self {
+        let descriptors: Box<Descriptors> = Box::default();
+        let status: Box<u32> = Box::default();
+        let tail: usize = 0;
+        Self {
+            coherent,
+            descriptors,
+            status,
+            tail,
+        }
+    }
+
+    /// # The Invalidation Queue Address Register pointing the queue
+    /// The hardware starts from the head of the queue, so the tail is reset too.
+    pub fn address(&mut self) -> invalidation_queue_address::Register {
+        self.tail = 0;
+        invalidation_queue_address::Register::page(paddr(self.descriptors.as_ref()))
+    }
+
+    /// # Submit an invalidation and wait for its completion
+    pub fn invalidate(
+        &mut self,
+        registers: &Registers,
+        descriptor: Descriptor,
+    ) -> Result<(), Error> {
+        let coherent: bool = self.coherent;
+        let status: *mut u32 = self.status.as_mut() as *mut u32;
+        unsafe { ptr::write_volatile(status, 0) };
+        write_back(self.status.as_ref(), coherent);
+        let wait = Descriptor::wait(paddr(self.status.as_ref()), Self::STATUS_DONE);
+        [descriptor, wait].into_iter().for_each(|descriptor| {
+            self.descriptors.set(self.tail, descriptor, coherent);
+            self.tail = (self.tail + 1) % Self::DESCRIPTORS;
+        });
+        registers.set_invalidation_queue_tail(self.tail);
+        (0..Self::TIMEOUT_MICROSECONDS / Self::POLLING_INTERVAL_MICROSECONDS)
+            .any(|_| {
+                // The line is flushed so that the status written by the unit is read from the memory.
+                write_back(self.status.as_ref(), coherent);
+                let done: bool = unsafe { ptr::read_volatile(status) } == Self::STATUS_DONE;
+                if !done {
+                    timer::acpi::wait_microseconds(Self::POLLING_INTERVAL_MICROSECONDS);
+                }
+                done
+            })
+            .then_some(())
+            .ok_or(Error::InvalidationTimeout)
+    }
+}
+
+/// # Invalidation Descriptor
+/// ## References
+/// * [Intel Virtualization Technology for Directed I/O](https://software.intel.com/content/dam/develop/external/us/en/documents-tps/vt-directed-io-spec.pdf) 6.5.2 Queued Invalidation Interface
+#[derive(Clone, Copy, Default)]
+#[repr(C)]
+pub struct Descriptor {
+    lower: u64,
+    upper: u64,
+}
+
+impl Descriptor {
+    const CONTEXT_CACHE_INVALIDATE: u64 = 0x1;
+    const IOTLB_INVALIDATE: u64 = 0x2;
+    const INTERRUPT_ENTRY_CACHE_INVALIDATE: u64 = 0x4;
+    const INVALIDATION_WAIT: u64 = 0x5;
+    const GLOBAL_INVALIDATION: u64 = 0b01 << 4;
+    const DOMAIN_SELECTIVE_INVALIDATION: u64 = 0b10 << 4;
+    const INDEX_SELECTIVE_INVALIDATION: u64 = 1 << 4;
+    const STATUS_WRITE: u64 = 1 << 5;
+
+    /// # Invalidate all context cache entries
+    pub fn context_cache_global_invalidation() -> Self {
+        Self {
+            lower: Self::CONTEXT_CACHE_INVALIDATE | Self::GLOBAL_INVALIDATION,
+            upper: 0,
+        }
+    }
+
+    /// # Invalidate the interrupt entry cache of an interrupt remapping table entry
+    pub fn interrupt_entry_cache_index_invalidation(index: u16) -> Self {
+        Self {
+            lower: Self::INTERRUPT_ENTRY_CACHE_INVALIDATE
+                | Self::INDEX_SELECTIVE_INVALIDATION
+                | ((index as u64) << 32),
+            upper: 0,
+        }
+    }
+
+    /// # Invalidate all interrupt entry cache entries
+    pub fn interrupt_entry_cache_global_invalidation() -> Self {
+        Self {
+            lower: Self::INTERRUPT_ENTRY_CACHE_INVALIDATE,
+            upper: 0,
+        }
+    }
+
+    /// # Invalidate the IOTLB entries of a domain
+    pub fn iotlb_domain_selective_invalidation(domain_id: u16) -> Self {
+        Self {
+            lower: Self::IOTLB_INVALIDATE
+                | Self::DOMAIN_SELECTIVE_INVALIDATION
+                | ((domain_id as u64) << 16),
+            upper: 0,
+        }
+    }
+
+    /// # Invalidate all IOTLB entries
+    pub fn iotlb_global_invalidation() -> Self {
+        Self {
+            lower: Self::IOTLB_INVALIDATE | Self::GLOBAL_INVALIDATION,
+            upper: 0,
+        }
+    }
+
+    /// # Write a status after the preceding descriptors are completed
+    fn wait(status_address: u64, status_data: u32) -> Self {
+        Self {
+            lower: Self::INVALIDATION_WAIT | Self::STATUS_WRITE | ((status_data as u64) << 32),
+            upper: status_address,
+        }
+    }
+}
+
+#[repr(align(4096))]
+struct Descriptors([Descriptor; Queue::DESCRIPTORS]);
+
+impl Descriptors {
+    fn set(&mut self, index: usize, descriptor: Descriptor, coherent: bool) {
+        let Self(descriptors) = self;
+        unsafe { ptr::write_volatile(&mut descriptors[index], descriptor) };
+        write_back(&descriptors[index], coherent);
+    }
+}
+
+impl Default for Descriptors {
+    fn default() -> Self {
+        Self([Descriptor::default(); Queue::DESCRIPTORS])
+    }
+}
@@ -0,0 +1,191 @@
+pub mod capability;
+pub mod context_command;
+pub mod extended_capability;
+pub mod fault_event_control;
+pub mod fault_recording;
+pub mod fault_status;
+pub mod global_command;
+pub mod global_status;
+pub mod interrupt_remapping_table_address;
+pub mod invalidation_queue_address;
+pub mod iotlb_invalidate;
+pub mod root_table_address;
+
+use {
+    crate::x64,
+    core::{fmt, mem::size_of},
+};
+
+/// # Remapping Hardware Registers
+/// The registers are accessed by their physical addresses.
+/// ## References
+/// * [Intel Virtualization Technology for Directed I/O](https://software.intel.com/content/dam/develop/external/us/en/documents-tps/vt-directed-io-spec.pdf) 11.4 Register Descriptions
+#[derive(Clone, Copy)]
+pub struct Registers {
+    base: usize,
+}
+
+impl Registers {
+    const CAPABILITY: usize = 0x08;
+    const EXTENDED_CAPABILITY: usize = 0x10;
+    const GLOBAL_COMMAND: usize = 0x18;
+    const GLOBAL_STATUS: usize = 0x1c;
+    const ROOT_TABLE_ADDRESS: usize = 0x20;
+    const CONTEXT_COMMAND: usize = 0x28;
+    const FAULT_STATUS: usize = 0x34;
+    const FAULT_EVENT_CONTROL: usize = 0x38;
+    const FAULT_EVENT_DATA: usize = 0x3c;
+    const FAULT_EVENT_ADDRESS: usize = 0x40;
+    const FAULT_EVENT_UPPER_ADDRESS: usize = 0x44;
+    const INVALIDATION_QUEUE_TAIL: usize = 0x88;
+    const INVALIDATION_QUEUE_ADDRESS: usize = 0x90;
+    const INTERRUPT_REMAPPING_TABLE_ADDRESS: usize = 0xb8;
+    /// The IOTLB Invalidate Register follows the Invalidate Address Register.
+    const IOTLB_INVALIDATE: usize = 0x08;
+    const FAULT_RECORDING_REGISTER_SIZE: usize = 2 * size_of::<u64>();
+
+    pub fn new(base: usize) -> Self {
+        Self { base }
+    }
+
+    pub fn capability(&self) -> capability::Register {
+        self.read::<u64>(Self::CAPABILITY).into()
+    }
+
+    /// # Clear a fault in a Fault Recording Register
+    pub fn clear_fault(&self, index: usize) {
+        let register: usize = self.fault_recording_register(index) + size_of::<u64>();
+        self.write(register, u64::from(fault_recording::Register::clear()));
+    }
+
+    /// # Clear the overflow and the errors in the Fault Status Register
+    pub fn clear_fault_status(&self) {
+        let fault_status: u32 = self.read(Self::FAULT_STATUS);
+        self.write(Self::FAULT_STATUS, fault_status);
+    }
+
+    pub fn extended_capability(&self) -> extended_capability::Register {
+        self.read::<u64>(Self::EXTENDED_CAPABILITY).into()
+    }
+
+    /// # Read a Fault Recording Register
+    /// It returns the page address and the upper quadword.
+    pub fn fault(&self, index: usize) -> (u64, fault_recording::Register) {
+        let register: usize = self.fault_recording_register(index);
+        let fault_info: u64 = self.read(register);
+        let fault: u64 = self.read(register + size_of::<u64>());
+        (fault_info & !0xfff, fault.into())
+    }
+
+    pub fn fault_status(&self) -> fault_status::Register {
+        self.read::<u32>(Self::FAULT_STATUS).into()
+    }
+
+    pub fn global_status(&self) -> global_status::Register {
+        self.read::<u32>(Self::GLOBAL_STATUS).into()
+    }
+
+    /// # Invalidate the context cache and wait for the completion
+    pub fn invalidate_context_cache(&self, command: context_command::Register) {
+        self.write(Self::CONTEXT_COMMAND, u64::from(command));
+        while context_command::Register::from(self.read::<u64>(Self::CONTEXT_COMMAND))
+            .is_invalidating()
+        {
+            x64::pause();
+        }
+    }
+
+    /// # Invalidate the IOTLB and wait for the completion
+    pub fn invalidate_iotlb(&self, command: iotlb_invalidate::Register) {
+        let register: usize = self.extended_capability().iotlb_registers() + Self::IOTLB_INVALIDATE;
+        self.write(register, u64::from(command));
+        while iotlb_invalidate::Register::from(self.read::<u64>(register)).is_invalidating() {
+            x64::pause();
+        }
+    }
+
+    /// # Issue a global command and wait for the status reflecting it
+    pub fn run(
+        &self,
+        command: impl FnOnce(global_command::Register) -> global_command::Register,
+        is_completed: impl Fn(&global_status::Register) -> bool,
+    ) {
+        let current: global_command::Register = self.global_status().into();
+        self.write(Self::GLOBAL_COMMAND, u32::from(command(current)));
+        while !is_completed(&self.global_status()) {
+            x64::pause();
+        }
+    }
+
+    /// # Send fault events as an MSI
+    pub fn set_fault_event(&self, address: u64, data: u32) {
+        self.write(Self::FAULT_EVENT_DATA, data);
+        self.write(Self::FAULT_EVENT_ADDRESS, address as u32);
+        self.write(
+            Self::FAULT_EVENT_UPPER_ADDRESS,
+            (address >> u32::BITS) as u32,
+        );
+        let fault_event_control: fault_event_control::Register =
+            self.read::<u32>(Self::FAULT_EVENT_CONTROL).into();
+        self.write(
+            Self::FAULT_EVENT_CONTROL,
+            u32::from(fault_event_control.with_interrupt(true)),
+        );
+    }
+
+    pub fn set_interrupt_remapping_table_address(
+        &self,
+        interrupt_remapping_table_address: interrupt_remapping_table_address::Register,
+    ) {
+        self.write(
+            Self::INTERRUPT_REMAPPING_TABLE_ADDRESS,
+            u64::from(interrupt_remapping_table_address),
+        );
+    }
+
+    pub fn set_invalidation_queue_address(
+        &self,
+        invalidation_queue_address: invalidation_queue_address::Register,
+    ) {
+        self.write(
+            Self::INVALIDATION_QUEUE_ADDRESS,
+            u64::from(invalidation_queue_address),
+        );
+    }
+
+    /// # Set the Invalidation Queue Tail Register to the index of the next descriptor
+    pub fn set_invalidation_queue_tail(&self, index: usize) {
+        self.write(Self::INVALIDATION_QUEUE_TAIL, (index as u64) << 4);
+    }
+
+    pub fn set_root_table_address(&self, root_table_address: root_table_address::Register) {
+        self.write(Self::ROOT_TABLE_ADDRESS, u64::from(root_table_address));
+    }
+
+    fn fault_recording_register(&self, index: usize) -> usize {
+        self.capability().fault_recording_registers() + index * Self::FAULT_RECORDING_REGISTER_SIZE
+    }
+
+    fn read<T>(&self, register: usize) -> T {
+        let register: *const T = (self.base + register) as *const T;
+        unsafe { register.read_volatile() }
+    }
+
+    fn write<T>(&self, register: usize, value: T) {
+        let register: *mut T = (self.base + register) as *mut T;
+        unsafe { register.write_volatile(value) }
+    }
+}
+
+impl fmt::Debug for Registers {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("Registers")
+            .field("base", &self.base)
+            .field("capability", &self.capability())
+            .field("extended_capability", &self.extended_capability())
+            .field("global_status", &self.global_status())
+            .field("fault_status", &self.fault_status())
+            .finish()
+    }
+}
@@ -0,0 +1,77 @@
+use bitfield_struct::bitfield;
+
+/// # Capability Register
+/// ## References
+/// * [Intel Virtualization Technology for Directed I/O](https://software.intel.com/content/dam/develop/external/us/en/documents-tps/vt-directed-io-spec.pdf) 11.4.2 Capability Register
+#[bitfield(u64)]
+pub struct Register {
+    #[bits(3)]
+    number_of_domains_supported: u8,
+    advanced_fault_logging: bool,
+    required_write_buffer_flushing: bool,
+    protected_low_memory_region: bool,
+    protected_high_memory_region: bool,
+    caching_mode: bool,
+    #[bits(5)]
+    supported_adjusted_guest_address_widths: u8,
+    #[bits(3)]
+    __: u8,
+    #[bits(6)]
+    maximum_guest_address_width: u8,
+    zero_length_read: bool,
+    __: bool,
+    #[bits(10)]
+    fault_recording_register_offset: u16,
+    #[bits(4)]
+    second_level_large_page_support: u8,
+    __: bool,
+    page_selective_invalidation: bool,
+    number_of_fault_recording_registers: u8,
+    #[bits(6)]
+    maximum_address_mask_value: u8,
+    write_draining: bool,
+    read_draining: bool,
+    first_level_1gib_page_support: bool,
+    #[bits(2)]
+    __: u8,
+    posted_interrupts_support: bool,
+    first_level_5level_paging_support: bool,
+    #[bits(3)]
+    __: u8,
+}
+
+impl Register {
+    /// # The maximum width of DMA addresses which are translated
+    pub fn address_width(&self) -> usize {
+        self.maximum_guest_address_width() as usize + 1
+    }
+
+    /// # The offset of the first Fault Recording Register from the register base
+    pub fn fault_recording_registers(&self) -> usize {
+        16 * (self.fault_recording_register_offset() as usize)
+    }
+
+    pub fn fault_recording_registers_len(&self) -> usize {
+        self.number_of_fault_recording_registers() as usize + 1
+    }
+
+    pub fn number_of_domains(&self) -> usize {
+        1 << (4 + 2 * self.number_of_domains_supported())
+    }
+
+    /// # The number of levels of second-level page tables
+    /// 4-level paging translating 48-bit addresses is preferred to 3-level paging translating 39-bit addresses.
+    pub fn page_table_levels(&self) -> Option<usize> {
+        let supported_adjusted_guest_address_widths: u8 =
+            self.supported_adjusted_guest_address_widths();
+        [(4, 2), (3, 1)]
+            .into_iter()
+            .find(|(_levels, bit)| supported_adjusted_guest_address_widths & (1 << bit) != 0)
+            .map(|(levels, _bit)| levels)
+    }
+
+    /// # Whether the write buffer has to be flushed after the translation structures are modified
+    pub fn requires_write_buffer_flushing(&self) -> bool {
+        self.required_write_buffer_flushing()
+    }
+}
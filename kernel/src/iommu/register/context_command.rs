@@ -0,0 +1,34 @@
+use bitfield_struct::bitfield;
+
+/// # Context Command Register
+/// ## References
+/// * [Intel Virtualization Technology for Directed I/O](https://software.intel.com/content/dam/develop/external/us/en/documents-tps/vt-directed-io-spec.pdf) 11.4.7 Context Command Register
+#[bitfield(u64)]
+pub struct Register {
+    domain_id: u16,
+    source_id: u16,
+    #[bits(2)]
+    function_mask: u8,
+    #[bits(25)]
+    __: u32,
+    #[bits(2)]
+    context_actual_invalidation_granularity: u8,
+    #[bits(2)]
+    context_invalidation_request_granularity: u8,
+    invalidate_context_cache: bool,
+}
+
+impl Register {
+    const GLOBAL_INVALIDATION: u8 = 0b01;
+
+    /// # Invalidate all context cache entries
+    pub fn global_invalidation() -> Self {
+        Self::new()
+            .with_invalidate_context_cache(true)
+            .with_context_invalidation_request_granularity(Self::GLOBAL_INVALIDATION)
+    }
+
+    pub fn is_invalidating(&self) -> bool {
+        self.invalidate_context_cache()
+    }
+}
@@ -0,0 +1,44 @@
+use bitfield_struct::bitfield;
+
+/// # Extended Capability Register
+/// ## References
+/// * [Intel Virtualization Technology for Directed I/O](https://software.intel.com/content/dam/develop/external/us/en/documents-tps/vt-directed-io-spec.pdf) 11.4.3 Extended Capability Register
+#[bitfield(u64)]
+pub struct Register {
+    page_walk_coherency: bool,
+    queued_invalidation_support: bool,
+    device_tlb_support: bool,
+    interrupt_remapping_support: bool,
+    extended_interrupt_mode: bool,
+    __: bool,
+    pass_through: bool,
+    snoop_control: bool,
+    #[bits(10)]
+    iotlb_register_offset: u16,
+    #[bits(2)]
+    __: u8,
+    #[bits(4)]
+    maximum_handle_mask_value: u8,
+    #[bits(40)]
+    __: u64,
+}
+
+impl Register {
+    /// # Whether the hardware snoops the processor caches on accessing the translation structures
+    pub fn is_coherent(&self) -> bool {
+        self.page_walk_coherency()
+    }
+
+    /// # The offset of the IOTLB registers from the register base
+    pub fn iotlb_registers(&self) -> usize {
+        16 * (self.iotlb_register_offset() as usize)
+    }
+
+    pub fn supports_interrupt_remapping(&self) -> bool {
+        self.interrupt_remapping_support()
+    }
+
+    pub fn supports_queued_invalidation(&self) -> bool {
+        self.queued_invalidation_support()
+    }
+}
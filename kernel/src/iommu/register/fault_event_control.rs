@@ -0,0 +1,18 @@
+use bitfield_struct::bitfield;
+
+/// # Fault Event Control Register
+/// ## References
+/// * [Intel Virtualization Technology for Directed I/O](https://software.intel.com/content/dam/develop/external/us/en/documents-tps/vt-directed-io-spec.pdf) 11.4.9.2 Fault Event Control Register
+#[bitfield(u32)]
+pub struct Register {
+    #[bits(30)]
+    __: u32,
+    interrupt_pending: bool,
+    interrupt_mask: bool,
+}
+
+impl Register {
+    pub fn with_interrupt(self, enable: bool) -> Self {
+        self.with_interrupt_mask(!enable)
+    }
+}
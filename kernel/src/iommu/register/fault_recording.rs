@@ -0,0 +1,89 @@
+use bitfield_struct::bitfield;
+
+/// # Upper Quadword of Fault Recording Register
+/// The lower quadword is the Fault Info, the page address of the faulted request.
+/// ## References
+/// * [Intel Virtualization Technology for Directed I/O](https://software.intel.com/content/dam/develop/external/us/en/documents-tps/vt-directed-io-spec.pdf) 11.4.14 Fault Recording Registers
+#[bitfield(u64)]
+pub struct Register {
+    source_identifier: u16,
+    #[bits(12)]
+    __: u16,
+    type2: bool,
+    privilege_mode_requested: bool,
+    execute_permission_requested: bool,
+    pasid_present: bool,
+    fault_reason: u8,
+    #[bits(20)]
+    pasid_value: u32,
+    #[bits(2)]
+    address_type: u8,
+    type1: bool,
+    fault: bool,
+}
+
+impl Register {
+    /// # The value clearing the fault
+    pub fn clear() -> Self {
+        Self::new().with_fault(true)
+    }
+
+    pub fn is_fault(&self) -> bool {
+        self.fault()
+    }
+
+    pub fn is_read(&self) -> bool {
+        !self.type2() && self.type1()
+    }
+
+    pub fn reason(&self) -> Reason {
+        self.fault_reason().into()
+    }
+
+    /// # The Requester ID of the faulted request
+    pub fn requester_id(&self) -> u16 {
+        self.source_identifier()
+    }
+}
+
+/// # Non-recoverable Fault Reason
+/// ## References
+/// * [Intel Virtualization Technology for Directed I/O](https://software.intel.com/content/dam/develop/external/us/en/documents-tps/vt-directed-io-spec.pdf) 7.1.3 Fault Conditions and Remapping Hardware Behavior for Various Requests
+#[derive(Debug)]
+pub enum Reason {
+    RootEntryNotPresent,
+    ContextEntryNotPresent,
+    InvalidContextEntry,
+    AddressBeyondMaximumGuestAddressWidth,
+    WriteNotPermitted,
+    ReadNotPermitted,
+    PageTableEntryAccessError,
+    RootTableAccessError,
+    ContextTableAccessError,
+    ReservedFieldInRootEntry,
+    ReservedFieldInContextEntry,
+    ReservedFieldInPageTableEntry,
+    BlockedByTranslationType,
+    Other(u8),
+}
+
+impl From<u8> for Reason {
+    fn from(reason: u8) -> Self {
+        match reason {
+            0x01 => Self::RootEntryNotPresent,
+            0x02 => Self::ContextEntryNotPresent,
+            0x03 => Self::InvalidContextEntry,
+            0x04 => Self::AddressBeyondMaximumGuestAddressWidth,
+            0x05 => Self::WriteNotPermitted,
+            0x06 => Self::ReadNotPermitted,
+            0x07 => Self::PageTableEntryAccessError,
+            0x08 => Self::RootTableAccessError,
+            0x09 => Self::ContextTableAccessError,
+            0x0a => Self::ReservedFieldInRootEntry,
+            0x0b => Self::ReservedFieldInContextEntry,
+            0x0c => Self::ReservedFieldInPageTableEntry,
+            0x0d => Self::BlockedByTranslationType,
+            reason => Self::Other(reason),
+        }
+    }
+}
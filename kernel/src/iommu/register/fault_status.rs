@@ -0,0 +1,33 @@
+use bitfield_struct::bitfield;
+
+/// # Fault Status Register
+/// ## References
+/// * [Intel Virtualization Technology for Directed I/O](https://software.intel.com/content/dam/develop/external/us/en/documents-tps/vt-directed-io-spec.pdf) 11.4.9.1 Fault Status Register
+#[bitfield(u32)]
+pub struct Register {
+    primary_fault_overflow: bool,
+    primary_pending_fault: bool,
+    advanced_fault_overflow: bool,
+    advanced_pending_fault: bool,
+    invalidation_queue_error: bool,
+    invalidation_completion_error: bool,
+    invalidation_timeout_error: bool,
+    __: bool,
+    fault_record_index: u8,
+    __: u16,
+}
+
+impl Register {
+    /// # The index of the first Fault Recording Register with a fault
+    pub fn first_fault(&self) -> usize {
+        self.fault_record_index() as usize
+    }
+
+    pub fn has_overflowed(&self) -> bool {
+        self.primary_fault_overflow()
+    }
+
+    pub fn has_pending_faults(&self) -> bool {
+        self.primary_pending_fault()
+    }
+}
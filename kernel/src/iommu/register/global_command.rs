@@ -0,0 +1,53 @@
+use {super::global_status, bitfield_struct::bitfield};
+
+/// # Global Command Register
+/// ## References
+/// * [Intel Virtualization Technology for Directed I/O](https://software.intel.com/content/dam/develop/external/us/en/documents-tps/vt-directed-io-spec.pdf) 11.4.4 Global Command Register
+#[bitfield(u32)]
+pub struct Register {
+    #[bits(23)]
+    __: u32,
+    compatibility_format_interrupt: bool,
+    set_interrupt_remap_table_pointer: bool,
+    interrupt_remapping_enable: bool,
+    queued_invalidation_enable: bool,
+    write_buffer_flush: bool,
+    enable_advanced_fault_logging: bool,
+    set_fault_log: bool,
+    set_root_table_pointer: bool,
+    translation_enable: bool,
+}
+
+impl Register {
+    pub fn flush_write_buffer(self) -> Self {
+        self.with_write_buffer_flush(true)
+    }
+
+    pub fn latch_interrupt_remapping_table_pointer(self) -> Self {
+        self.with_set_interrupt_remap_table_pointer(true)
+    }
+
+    pub fn latch_root_table_pointer(self) -> Self {
+        self.with_set_root_table_pointer(true)
+    }
+
+    pub fn with_interrupt_remapping(self, enable: bool) -> Self {
+        self.with_interrupt_remapping_enable(enable)
+    }
+
+    pub fn with_queued_invalidation(self, enable: bool) -> Self {
+        self.with_queued_invalidation_enable(enable)
+    }
+
+    pub fn with_translation(self, enable: bool) -> Self {
+        self.with_translation_enable(enable)
+    }
+}
+
+/// # The command keeping the current state
+/// The one-shot bits are cleared so that they are not triggered again.
+impl From<global_status::Register> for Register {
+    fn from(status: global_status::Register) -> Self {
+        (u32::from(status) & 0x96ffffff).into()
+    }
+}
@@ -0,0 +1,45 @@
+use bitfield_struct::bitfield;
+
+/// # Global Status Register
+/// ## References
+/// * [Intel Virtualization Technology for Directed I/O](https://software.intel.com/content/dam/develop/external/us/en/documents-tps/vt-directed-io-spec.pdf) 11.4.5 Global Status Register
+#[bitfield(u32)]
+pub struct Register {
+    #[bits(23)]
+    __: u32,
+    compatibility_format_interrupt_status: bool,
+    interrupt_remapping_table_pointer_status: bool,
+    interrupt_remapping_enable_status: bool,
+    queued_invalidation_enable_status: bool,
+    write_buffer_flush_status: bool,
+    advanced_fault_logging_status: bool,
+    fault_log_status: bool,
+    root_table_pointer_status: bool,
+    translation_enable_status: bool,
+}
+
+impl Register {
+    pub fn is_flushing_write_buffer(&self) -> bool {
+        self.write_buffer_flush_status()
+    }
+
+    pub fn is_interrupt_remapping_enabled(&self) -> bool {
+        self.interrupt_remapping_enable_status()
+    }
+
+    pub fn is_interrupt_remapping_table_pointer_set(&self) -> bool {
+        self.interrupt_remapping_table_pointer_status()
+    }
+
+    pub fn is_queued_invalidation_enabled(&self) -> bool {
+        self.queued_invalidation_enable_status()
+    }
+
+    pub fn is_root_table_pointer_set(&self) -> bool {
+        self.root_table_pointer_status()
+    }
+
+    pub fn is_translation_enabled(&self) -> bool {
+        self.translation_enable_status()
+    }
+}
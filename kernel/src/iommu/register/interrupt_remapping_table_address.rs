@@ -0,0 +1,28 @@
+use bitfield_struct::bitfield;
+
+/// # Interrupt Remapping Table Address Register
+/// ## References
+/// * [Intel Virtualization Technology for Directed I/O](https://software.intel.com/content/dam/develop/external/us/en/documents-tps/vt-directed-io-spec.pdf) 11.4.11.1 Interrupt Remapping Table Address Register
+#[bitfield(u64)]
+pub struct Register {
+    #[bits(4)]
+    size: u8,
+    #[bits(7)]
+    __: u8,
+    extended_interrupt_mode_enable: bool,
+    #[bits(52)]
+    interrupt_remapping_table_address: u64,
+}
+
+impl Register {
+    /// # A table of `entries` entries
+    /// The table is used in the xAPIC interrupt mode, so the destination IDs are xAPIC IDs.
+    pub fn create(interrupt_remapping_table: u64, entries: usize) -> Self {
+        Self::new()
+            .with_size((entries.trailing_zeros() - 1) as u8)
+            .with_extended_interrupt_mode_enable(false)
+            .with_interrupt_remapping_table_address(
+                interrupt_remapping_table >> Self::INTERRUPT_REMAPPING_TABLE_ADDRESS_OFFSET,
+            )
+    }
+}
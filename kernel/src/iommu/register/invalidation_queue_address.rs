@@ -0,0 +1,24 @@
+use bitfield_struct::bitfield;
+
+/// # Invalidation Queue Address Register
+/// ## References
+/// * [Intel Virtualization Technology for Directed I/O](https://software.intel.com/content/dam/develop/external/us/en/documents-tps/vt-directed-io-spec.pdf) 11.4.10.3 Invalidation Queue Address Register
+#[bitfield(u64)]
+pub struct Register {
+    #[bits(3)]
+    queue_size: u8,
+    #[bits(8)]
+    __: u8,
+    descriptor_width: bool,
+    #[bits(52)]
+    invalidation_queue_base_address: u64,
+}
+
+impl Register {
+    /// # A queue of 256 128-bit descriptors in a page
+    pub fn page(invalidation_queue: u64) -> Self {
+        Self::new().with_invalidation_queue_base_address(
+            invalidation_queue >> Self::INVALIDATION_QUEUE_BASE_ADDRESS_OFFSET,
+        )
+    }
+}
@@ -0,0 +1,45 @@
+use bitfield_struct::bitfield;
+
+/// # IOTLB Invalidate Register
+/// ## References
+/// * [Intel Virtualization Technology for Directed I/O](https://software.intel.com/content/dam/develop/external/us/en/documents-tps/vt-directed-io-spec.pdf) 11.4.8.1 IOTLB Invalidate Register
+#[bitfield(u64)]
+pub struct Register {
+    __: u32,
+    domain_id: u16,
+    drain_writes: bool,
+    drain_reads: bool,
+    #[bits(7)]
+    __: u8,
+    #[bits(2)]
+    iotlb_actual_invalidation_granularity: u8,
+    __: bool,
+    #[bits(2)]
+    iotlb_invalidation_request_granularity: u8,
+    __: bool,
+    invalidate_iotlb: bool,
+}
+
+impl Register {
+    const GLOBAL_INVALIDATION: u8 = 0b01;
+    const DOMAIN_SELECTIVE_INVALIDATION: u8 = 0b10;
+
+    /// # Invalidate the IOTLB entries of a domain
+    pub fn domain_selective_invalidation(domain_id: u16) -> Self {
+        Self::new()
+            .with_invalidate_iotlb(true)
+            .with_iotlb_invalidation_request_granularity(Self::DOMAIN_SELECTIVE_INVALIDATION)
+            .with_domain_id(domain_id)
+    }
+
+    /// # Invalidate all IOTLB entries
+    pub fn global_invalidation() -> Self {
+        Self::new()
+            .with_invalidate_iotlb(true)
+            .with_iotlb_invalidation_request_granularity(Self::GLOBAL_INVALIDATION)
+    }
+
+    pub fn is_invalidating(&self) -> bool {
+        self.invalidate_iotlb()
+    }
+}
@@ -0,0 +1,21 @@
+use bitfield_struct::bitfield;
+
+/// # Root Table Address Register
+/// ## References
+/// * [Intel Virtualization Technology for Directed I/O](https://software.intel.com/content/dam/develop/external/us/en/documents-tps/vt-directed-io-spec.pdf) 11.4.6 Root Table Address Register
+#[bitfield(u64)]
+pub struct Register {
+    #[bits(10)]
+    __: u16,
+    #[bits(2)]
+    translation_table_mode: u8,
+    #[bits(52)]
+    root_table_address: u64,
+}
+
+impl Register {
+    /// # A legacy mode root table
+    pub fn legacy(root_table: u64) -> Self {
+        Self::new().with_root_table_address(root_table >> Self::ROOT_TABLE_ADDRESS_OFFSET)
+    }
+}
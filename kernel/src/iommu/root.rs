@@ -0,0 +1,56 @@
+use {super::write_back, bitfield_struct::bitfield, core::ptr};
+
+/// # Root Table
+/// The root entry of each bus points to the context table of the bus.
+/// ## References
+/// * [Intel Virtualization Technology for Directed I/O](https://software.intel.com/content/dam/develop/external/us/en/documents-tps/vt-directed-io-spec.pdf) 9.1 Root Entry
+#[repr(align(4096))]
+pub struct Table {
+    entries: [Entry; 0x100],
+}
+
+impl Table {
+    /// # Point the context table of a bus
+    pub fn set(&mut self, bus: u8, context_table: u64, coherent: bool) {
+        let entry = Entry {
+            lower: Lower::new()
+                .with_present(true)
+                .with_context_table_pointer(context_table >> Lower::CONTEXT_TABLE_POINTER_OFFSET),
+            upper: 0,
+        };
+        let entry_in_table: &mut Entry = &mut self.entries[bus as usize];
+        unsafe { ptr::write_volatile(entry_in_table, entry) };
+        write_back(entry_in_table, coherent);
+    }
+}
+
+impl Default for Table {
+    fn default() -> Self {
+        let entries: [Entry; 0x100] = [Entry::default(); 0x100];
+        Self { entries }
+    }
+}
+
+#[derive(Clone, Copy)]
+#[repr(C)]
+struct Entry {
+    lower: Lower,
+    upper: u64,
+}
+
+impl Default for Entry {
+    fn default() -> Self {
+        let lower: Lower = Lower::new();
+        let upper: u64 = 0;
+        Self { lower, upper }
+    }
+}
+
+#[bitfield(u64)]
+struct Lower {
+    present: bool,
+    #[bits(11)]
+    __: u16,
+    #[bits(52)]
+    context_table_pointer: u64,
+}
@@ -0,0 +1,73 @@
+use {
+    crate::{acpi::direct_memory_access_remapping::hardware_unit_definition::scope, pci},
+    alloc::vec::Vec,
+    core::ops::RangeInclusive,
+};
+
+/// # PCI Functions in a Device Scope
+/// The path in the scope is followed through the bridges configured now.
+/// ## References
+/// * [Intel Virtualization Technology for Directed I/O](https://software.intel.com/content/dam/develop/external/us/en/documents-tps/vt-directed-io-spec.pdf) 8.3.1 Device Scope Structure
+#[derive(Clone, Debug)]
+pub enum Scope {
+    Function(pci::FunctionAddress),
+    /// The I/O APIC sends its interrupts as the function.
+    IoApic {
+        id: u8,
+        function: pci::FunctionAddress,
+    },
+    SubHierarchy {
+        bridge: pci::FunctionAddress,
+        buses: Option<RangeInclusive<u8>>,
+    },
+}
+
+impl Scope {
+    pub fn new(segment: u16, structure: &scope::Structure) -> Option<Self> {
+        let path: Vec<(u8, u8)> = structure.path_entries().collect();
+        let ((device, function), bridges) = path.split_last()?;
+        let bus: u8 =
+            bridges
+                .iter()
+                .try_fold(structure.start_bus_number(), |bus, (device, function)| {
+                    pci::FunctionAddress::new(segment, bus, *device, *function)
+                        .secondary_buses()
+                        .map(|buses| *buses.start())
+                })?;
+        let function = pci::FunctionAddress::new(segment, bus, *device, *function);
+        match structure.scope_type() {
+            scope::Type::IoApic => Some(Self::IoApic {
+                id: structure.enumeration_id(),
+                function,
+            }),
+            scope::Type::PciEndpoint => Some(Self::Function(function)),
+            scope::Type::PciSubHierarchy => Some(Self::SubHierarchy {
+                bridge: function,
+                buses: function.secondary_buses(),
+            }),
+            _ => None,
+        }
+    }
+
+    pub fn contains(&self, function: pci::FunctionAddress) -> bool {
+        match self {
+            Self::Function(scope) => *scope == function,
+            Self::IoApic { .. } => false,
+            Self::SubHierarchy { bridge, buses } => {
+                *bridge == function
+                    || (bridge.segment_number() == function.segment_number()
+                        && buses
+                            .as_ref()
+                            .is_some_and(|buses| buses.contains(&function.bus_number())))
+            }
+        }
+    }
+
+    /// # The function which an I/O APIC sends its interrupts as
+    pub fn io_apic(&self, io_apic_id: u8) -> Option<pci::FunctionAddress> {
+        match self {
+            Self::IoApic { id, function } if *id == io_apic_id => Some(*function),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use {
+    super::{paddr, write_back},
+    alloc::{boxed::Box, collections::BTreeMap},
+    bitfield_struct::bitfield,
+    core::ptr,
+};
+
+/// # Second-Level Paging Structure
+/// A table keeps its lower tables to find them by an IOVA because the hardware knows only their physical addresses.
+/// ## References
+/// * [Intel Virtualization Technology for Directed I/O](https://software.intel.com/content/dam/develop/external/us/en/documents-tps/vt-directed-io-spec.pdf) 9.8 Second-Level Paging Entries
+pub struct Table {
+    entries: Box<Entries>,
+    tables: BTreeMap<usize, Table>,
+}
+
+impl Table {
+    pub const PAGE_SIZE: u64 = 1 << Self::PAGE_SHIFT;
+    const ENTRIES: usize = 0x200;
+    const PAGE_SHIFT: usize = 12;
+
+    /// # An empty table
+    /// The cleared entries are written back for a unit which doesn't snoop the processor caches.
+    pub fn new(coherent: bool) -> Self {
+        let entries: Box<Entries> = Box::default();
+        write_back(entries.as_ref(), coherent);
+        let tables: BTreeMap<usize, Table> = BTreeMap::new();
+        Self { entries, tables }
+    }
+
+    /// # Map a page at a level
+    /// The last level is 1.
+    pub fn map(&mut self, level: usize, iova: u64, paddr: u64, coherent: bool) {
+        let index: usize = Self::index(level, iova);
+        if level == 1 {
+            let entry: Entry = Entry::new()
+                .with_read(true)
+                .with_write(true)
+                .with_address(paddr >> Self::PAGE_SHIFT);
+            self.entries.set(index, entry, coherent);
+        } else {
+            let table: &mut Table = self
+                .tables
+                .entry(index)
+                .or_insert_with(|| Self::new(coherent));
+            let entry: Entry = Entry::new()
+                .with_read(true)
+                .with_write(true)
+                .with_address(table.paddr() >> Self::PAGE_SHIFT);
+            table.map(level - 1, iova, paddr, coherent);
+            self.entries.set(index, entry, coherent);
+        }
+    }
+
+    pub fn paddr(&self) -> u64 {
+        paddr(self.entries.as_ref())
+    }
+
+    /// # Unmap a page at a level
+    /// The lower tables are kept for the next mapping.
+    pub fn unmap(&mut self, level: usize, iova: u64, coherent: bool) {
+        let index: usize = Self::index(level, iova);
+        if level == 1 {
+            self.entries.set(index, Entry::new(), coherent);
+        } else if let Some(table) = self.tables.get_mut(&index) {
+            table.unmap(level - 1, iova, coherent);
+        }
+    }
+
+    fn index(level: usize, iova: u64) -> usize {
+        let shift: usize = Self::PAGE_SHIFT + 9 * (level - 1);
+        ((iova >> shift) as usize) % Self::ENTRIES
+    }
+}
+
+#[repr(align(4096))]
+struct Entries([Entry; Table::ENTRIES]);
+
+impl Entries {
+    fn set(&mut self, index: usize, entry: Entry, coherent: bool) {
+        let Self(entries) = self;
+        unsafe { ptr::write_volatile(&mut entries[index], entry) };
+        write_back(&entries[index], coherent);
+    }
+}
+
+impl Default for Entries {
+    fn default() -> Self {
+        Self([Entry::new(); Table::ENTRIES])
+    }
+}
+
+#[bitfield(u64)]
+struct Entry {
+    read: bool,
+    write: bool,
+    execute: bool,
+    #[bits(3)]
+    extended_memory_type: u8,
+    ignore_pat: bool,
+    page_size: bool,
+    #[bits(4)]
+    __: u8,
+    #[bits(40)]
+    address: u64,
+    #[bits(12)]
+    __: u16,
+}
@@ -0,0 +1,526 @@
+use {
+    super::{
+        context,
+        domain::Domain,
+        interrupt_remapping,
+        invalidation_queue::{self, Descriptor},
+        paddr,
+        register::{
+            context_command, fault_recording, fault_status, interrupt_remapping_table_address,
+            iotlb_invalidate, root_table_address, Registers,
+        },
+        root,
+        scope::Scope,
+        write_back, Error,
+    },
+    crate::{
+        acpi::direct_memory_access_remapping::{self, hardware_unit_definition},
+        com2_println,
+        interrupt::{self, apic::io::redirection},
+        pci::{self, capability::msi::message},
+    },
+    alloc::{boxed::Box, collections::BTreeMap, vec::Vec},
+    core::ops::{Range, RangeInclusive},
+};
+
+/// # DMA Remapping Hardware Unit
+/// Each function in the scope of the unit is in its own domain, except that the functions whose requester IDs alias each other share a domain.
+/// The translation structures are written back entry by entry if the unit doesn't snoop the processor caches.
+/// The interrupts of the functions and the I/O APICs in the scope are remapped if the unit and the DMAR support interrupt remapping.
+/// ## References
+/// * [Intel Virtualization Technology for Directed I/O](https://software.intel.com/content/dam/develop/external/us/en/documents-tps/vt-directed-io-spec.pdf) 8.3 DMA Remapping Hardware Unit Definition Structure
+pub struct Unit {
+    coherent: bool,
+    context_tables: BTreeMap<u8, Box<context::Table>>,
+    domains: Vec<Domain>,
+    /// The index of the domain of each function
+    functions: BTreeMap<pci::FunctionAddress, usize>,
+    includes_pci_all: bool,
+    interrupt_remapping_table: Option<Box<interrupt_remapping::Table>>,
+    invalidation_queue: Option<invalidation_queue::Queue>,
+    levels: usize,
+    local_apic_id: u8,
+    registers: Registers,
+    /// The index of the domain of each requester ID in the context tables
+    requesters: BTreeMap<u16, usize>,
+    root_table: Box<root::Table>,
+    scopes: Vec<Scope>,
+    segment_number: u16,
+}
+
+impl Unit {
+    /// IOVAs start above the Reserved Memory Regions which are below 4 GiB.
+    const IOVA_START: u64 = 1 << 32;
+
+    /// # Read a unit in the DMAR
+    /// The faults are reported to the local APIC.
+    /// Interrupt remapping needs the invalidation queue because the interrupt entry cache can't be invalidated through the registers.
+    pub fn new(
+        dmar: &direct_memory_access_remapping::Table,
+        structure: &hardware_unit_definition::Structure,
+        local_apic_id: u8,
+    ) -> Result<Self, Error> {
+        let registers = Registers::new(structure.register_base_address());
+        let levels: usize = registers
+            .capability()
+            .page_table_levels()
+            .ok_or(Error::UnsupportedAddressWidth)?;
+        let segment_number: u16 = structure.segment_number();
+        let scopes: Vec<Scope> = structure
+            .scopes()
+            .filter_map(|scope| Scope::new(segment_number, scope))
+            .collect();
+        let extended_capability = registers.extended_capability();
+        let coherent: bool = extended_capability.is_coherent();
+        let remaps_interrupts: bool = dmar.supports_interrupt_remapping()
+            && extended_capability.supports_interrupt_remapping()
+            && extended_capability.supports_queued_invalidation();
+        let interrupt_remapping_table: Option<Box<interrupt_remapping::Table>> = remaps_interrupts
+            .then(|| {
+                let interrupt_remapping_table: Box<interrupt_remapping::Table> = Box::default();
+                write_back(interrupt_remapping_table.as_ref(), coherent);
+                interrupt_remapping_table
+            });
+        let invalidation_queue: Option<invalidation_queue::Queue> =
+            remaps_interrupts.then(|| invalidation_queue::Queue::new(coherent));
+        let root_table: Box<root::Table> = Box::default();
+        write_back(root_table.as_ref(), coherent);
+        Ok(Self {
+            coherent,
+            context_tables: BTreeMap::new(),
+            domains: Vec::new(),
+            functions: BTreeMap::new(),
+            includes_pci_all: structure.includes_pci_all(),
+            interrupt_remapping_table,
+            invalidation_queue,
+            levels,
+            local_apic_id,
+            registers,
+            requesters: BTreeMap::new(),
+            root_table,
+            scopes,
+            segment_number,
+        })
+    }
+
+    /// # Put a function in a domain
+    /// The function joins the domain of a function which shares any of the requester IDs, or it is put in a new domain.
+    pub fn attach(
+        &mut self,
+        function: pci::FunctionAddress,
+        requester_ids: Vec<u16>,
+    ) -> Result<(), Error> {
+        if self.functions.contains_key(&function) {
+            return Ok(());
+        }
+        let index: usize = match requester_ids
+            .iter()
+            .find_map(|requester_id| self.requesters.get(requester_id))
+        {
+            Some(index) => *index,
+            None => {
+                // Domain 0 is reserved in the caching mode.
+                let id: u16 = (self.domains.len() + 1)
+                    .try_into()
+                    .ok()
+                    .filter(|id| (*id as usize) < self.registers.capability().number_of_domains())
+                    .ok_or(Error::NoDomain)?;
+                self.domains
+                    .push(Domain::new(id, self.levels, self.iovas(), self.coherent));
+                self.domains.len() - 1
+            }
+        };
+        for requester_id in requester_ids {
+            if !self.requesters.contains_key(&requester_id) {
+                self.set_context(requester_id, index);
+            }
+        }
+        self.functions.insert(function, index);
+        Ok(())
+    }
+
+    /// # Whether a Device Scope of the unit contains a function
+    pub fn contains(&self, function: pci::FunctionAddress) -> bool {
+        self.scopes.iter().any(|scope| scope.contains(function))
+    }
+
+    /// # Start translating DMA and point the interrupt remapping table
+    /// It is also called to restore the unit after sleep.
+    /// Interrupt remapping is enabled by `enable_interrupt_remapping` after the I/O APIC redirection table entries are rewritten.
+    pub fn enable(&mut self) {
+        let status = self.registers.global_status();
+        if status.is_translation_enabled() {
+            self.registers.run(
+                |command| command.with_translation(false),
+                |status| !status.is_translation_enabled(),
+            );
+        }
+        if status.is_interrupt_remapping_enabled() {
+            self.registers.run(
+                |command| command.with_interrupt_remapping(false),
+                |status| !status.is_interrupt_remapping_enabled(),
+            );
+        }
+        if status.is_queued_invalidation_enabled() {
+            self.registers.run(
+                |command| command.with_queued_invalidation(false),
+                |status| !status.is_queued_invalidation_enabled(),
+            );
+        }
+        self.registers
+            .set_root_table_address(root_table_address::Register::legacy(paddr(
+                self.root_table.as_ref(),
+            )));
+        self.registers.run(
+            |command| command.latch_root_table_pointer(),
+            |status| status.is_root_table_pointer_set(),
+        );
+        self.flush_write_buffer();
+        if let Some(invalidation_queue) = self.invalidation_queue.as_mut() {
+            self.registers.set_invalidation_queue_tail(0);
+            self.registers
+                .set_invalidation_queue_address(invalidation_queue.address());
+            self.registers.run(
+                |command| command.with_queued_invalidation(true),
+                |status| status.is_queued_invalidation_enabled(),
+            );
+        }
+        if let Err(error) = self
+            .invalidate_context_cache()
+            .and_then(|()| self.invalidate_iotlb(None))
+        {
+            com2_println!(
+                "The IOMMU at {:#x?} can't invalidate the caches. {:?}",
+                self.registers,
+                error
+            );
+        }
+        if let Some(interrupt_remapping_table) = self.interrupt_remapping_table.as_ref() {
+            self.registers.set_interrupt_remapping_table_address(
+                interrupt_remapping_table_address::Register::create(
+                    paddr(interrupt_remapping_table.as_ref()),
+                    interrupt_remapping::Table::ENTRIES,
+                ),
+            );
+            self.registers.run(
+                |command| command.latch_interrupt_remapping_table_pointer(),
+                |status| status.is_interrupt_remapping_table_pointer_set(),
+            );
+            if let Err(error) = self.invalidate_interrupt_entry_cache(None) {
+                com2_println!(
+                    "The IOMMU at {:#x?} can't invalidate the interrupt entry cache. {:?}",
+                    self.registers,
+                    error
+                );
+            }
+        }
+        self.registers.set_fault_event(
+            message::address(self.local_apic_id),
+            message::data(interrupt::IOMMU_INTERRUPT),
+        );
+        self.registers.run(
+            |command| command.with_translation(true),
+            |status| status.is_translation_enabled(),
+        );
+        com2_println!(
+            "The IOMMU at {:#x?} translates DMA of {} functions in {} domains.",
+            self.registers,
+            self.functions.len(),
+            self.domains.len()
+        );
+    }
+
+    /// # Start remapping interrupts
+    pub fn enable_interrupt_remapping(&mut self) {
+        if self.remaps_interrupts() {
+            self.registers.run(
+                |command| command.with_interrupt_remapping(true),
+                |status| status.is_interrupt_remapping_enabled(),
+            );
+            com2_println!(
+                "The IOMMU at {:#x?} remaps interrupts in xAPIC interrupt mode.",
+                self.registers
+            );
+        }
+    }
+
+    /// # Log and clear the recorded faults
+    pub fn handle_faults(&self) {
+        let registers: &Registers = &self.registers;
+        let status: fault_status::Register = registers.fault_status();
+        if status.has_pending_faults() {
+            let length: usize = registers.capability().fault_recording_registers_len();
+            (0..length)
+                .map(|index| (status.first_fault() + index) % length)
+                .map(|index| (index, registers.fault(index)))
+                .take_while(|(_index, (_address, fault))| fault.is_fault())
+                .for_each(|(index, (address, fault))| {
+                    self.report(address, &fault);
+                    registers.clear_fault(index);
+                });
+        }
+        if status.has_overflowed() {
+            com2_println!("The IOMMU lost faults.");
+        }
+        registers.clear_fault_status();
+    }
+
+    /// # Whether a function is in a domain of the unit
+    pub fn has(&self, function: pci::FunctionAddress) -> bool {
+        self.functions.contains_key(&function)
+    }
+
+    pub fn includes_pci_all(&self) -> bool {
+        self.includes_pci_all
+    }
+
+    /// # Whether an I/O APIC is in a Device Scope of the unit
+    pub fn has_io_apic(&self, io_apic_id: u8) -> bool {
+        self.scopes
+            .iter()
+            .any(|scope| scope.io_apic(io_apic_id).is_some())
+    }
+
+    /// # Map a physical address range to the same IOVAs for a function
+    pub fn map_identity(&mut self, function: pci::FunctionAddress, range: RangeInclusive<usize>) {
+        if let Some(domain) = self.domain_mut(function) {
+            domain.map_identity(range);
+            let id: u16 = domain.id();
+            if let Err(error) = self.flush(id) {
+                com2_println!(
+                    "The IOMMU can't map the RMRR of PCI {}. {:?}",
+                    function,
+                    error
+                );
+            }
+        }
+    }
+
+    /// # Map a buffer for DMA of a function
+    pub fn map(
+        &mut self,
+        function: pci::FunctionAddress,
+        paddr: u64,
+        size: usize,
+        dma_mask: u64,
+    ) -> Result<u64, Error> {
+        let domain: &mut Domain = self.domain_mut(function).ok_or(Error::NoDomain)?;
+        let iova: u64 = domain
+            .allocate(paddr, size, dma_mask)
+            .ok_or(Error::NoIova)?;
+        domain.map(iova, paddr, size);
+        let id: u16 = domain.id();
+        self.flush(id)?;
+        Ok(iova)
+    }
+
+    /// # Remap an interrupt of a function to a local APIC
+    /// It returns the MSI message in the remappable format.
+    pub fn msi_message(
+        &mut self,
+        function: pci::FunctionAddress,
+        destination: u8,
+        interrupt_number: u8,
+    ) -> Option<(u64, u32)> {
+        self.remap(
+            Some(function.requester_id()),
+            destination,
+            interrupt_number,
+            false,
+        )
+        .map(interrupt_remapping::message)
+    }
+
+    /// # Remap a redirection table entry of an I/O APIC
+    /// The interrupt is validated with the requester ID of the I/O APIC if it is in a Device Scope.
+    pub fn remap_io_apic(
+        &mut self,
+        io_apic_id: u8,
+        entry: redirection::table::Entry,
+    ) -> Option<redirection::table::Entry> {
+        let source: Option<u16> = self
+            .scopes
+            .iter()
+            .find_map(|scope| scope.io_apic(io_apic_id))
+            .map(|function| function.requester_id());
+        self.remap(
+            source,
+            entry.local_apic_id(),
+            entry.interrupt_number(),
+            entry.is_level_triggered(),
+        )
+        .map(|handle| entry.with_remapping(handle))
+    }
+
+    pub fn remaps_interrupts(&self) -> bool {
+        self.interrupt_remapping_table.is_some()
+    }
+
+    pub fn segment_number(&self) -> u16 {
+        self.segment_number
+    }
+
+    pub fn unmap(&mut self, function: pci::FunctionAddress, iova: u64, size: usize) {
+        if let Some(domain) = self.domain_mut(function) {
+            domain.unmap(iova, size);
+            let id: u16 = domain.id();
+            if let Err(error) = self.flush(id) {
+                com2_println!(
+                    "The IOMMU can't unmap {:#x} of PCI {}. {:?}",
+                    iova,
+                    function,
+                    error
+                );
+            }
+        }
+    }
+
+    fn domain_mut(&mut self, function: pci::FunctionAddress) -> Option<&mut Domain> {
+        let index: usize = *self.functions.get(&function)?;
+        self.domains.get_mut(index)
+    }
+
+    /// # Make the hardware see the modified page table of a domain
+    fn flush(&mut self, domain_id: u16) -> Result<(), Error> {
+        self.flush_write_buffer();
+        self.invalidate_iotlb(Some(domain_id))
+    }
+
+    /// # Drain the write buffer of the unit
+    /// The modified entries are already written back to the memory.
+    fn flush_write_buffer(&self) {
+        if self.registers.capability().requires_write_buffer_flushing() {
+            self.registers.run(
+                |command| command.flush_write_buffer(),
+                |status| !status.is_flushing_write_buffer(),
+            );
+        }
+    }
+
+    fn invalidate_context_cache(&mut self) -> Result<(), Error> {
+        match self.invalidation_queue.as_mut() {
+            Some(invalidation_queue) => invalidation_queue.invalidate(
+                &self.registers,
+                Descriptor::context_cache_global_invalidation(),
+            ),
+            None => {
+                self.registers
+                    .invalidate_context_cache(context_command::Register::global_invalidation());
+                Ok(())
+            }
+        }
+    }
+
+    /// # Invalidate the interrupt entry cache of an interrupt remapping table entry, or of all entries
+    fn invalidate_interrupt_entry_cache(&mut self, index: Option<u16>) -> Result<(), Error> {
+        match self.invalidation_queue.as_mut() {
+            Some(invalidation_queue) => {
+                let descriptor: Descriptor = index.map_or_else(
+                    Descriptor::interrupt_entry_cache_global_invalidation,
+                    Descriptor::interrupt_entry_cache_index_invalidation,
+                );
+                invalidation_queue.invalidate(&self.registers, descriptor)
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// # Invalidate the IOTLB of a domain, or of all domains
+    /// The registers can't be used while the invalidation queue is enabled.
+    fn invalidate_iotlb(&mut self, domain_id: Option<u16>) -> Result<(), Error> {
+        match self.invalidation_queue.as_mut() {
+            Some(invalidation_queue) => invalidation_queue.invalidate(
+                &self.registers,
+                domain_id.map_or_else(
+                    Descriptor::iotlb_global_invalidation,
+                    Descriptor::iotlb_domain_selective_invalidation,
+                ),
+            ),
+            None => {
+                self.registers.invalidate_iotlb(domain_id.map_or_else(
+                    iotlb_invalidate::Register::global_invalidation,
+                    iotlb_invalidate::Register::domain_selective_invalidation,
+                ));
+                Ok(())
+            }
+        }
+    }
+
+    /// # The IOVAs translated by the unit
+    fn iovas(&self) -> Range<u64> {
+        let address_width: usize = (12 + 9 * self.levels)
+            .min(self.registers.capability().address_width())
+            .min(u64::BITS as usize - 1);
+        Self::IOVA_START..(1 << address_width)
+    }
+
+    /// # Put an interrupt in the interrupt remapping table
+    /// It returns the handle of the entry.
+    fn remap(
+        &mut self,
+        source: Option<u16>,
+        destination: u8,
+        interrupt_number: u8,
+        level_triggered: bool,
+    ) -> Option<u16> {
+        let entry =
+            interrupt_remapping::Entry::new(destination, interrupt_number, level_triggered, source);
+        let handle: u16 = self
+            .interrupt_remapping_table
+            .as_mut()?
+            .allocate(entry, self.coherent)?;
+        self.invalidate_interrupt_entry_cache(Some(handle))
+            .map_err(|error| {
+                com2_println!(
+                    "The IOMMU can't remap an interrupt {:#x?}. {:?}",
+                    interrupt_number,
+                    error
+                )
+            })
+            .ok()?;
+        Some(handle)
+    }
+
+    /// # Point a requester ID to a domain in the context table of its bus
+    fn set_context(&mut self, requester_id: u16, index: usize) {
+        let coherent: bool = self.coherent;
+        let domain: &Domain = &self.domains[index];
+        let bus: u8 = (requester_id >> 8) as u8;
+        let context_table: &mut Box<context::Table> =
+            self.context_tables.entry(bus).or_insert_with(|| {
+                let context_table: Box<context::Table> = Box::default();
+                write_back(context_table.as_ref(), coherent);
+                self.root_table
+                    .set(bus, paddr(context_table.as_ref()), coherent);
+                context_table
+            });
+        context_table.set(
+            ((requester_id >> 3) & 0x1f) as u8,
+            (requester_id & 0x7) as u8,
+            domain.id(),
+            domain.levels(),
+            domain.page_table(),
+            coherent,
+        );
+        self.requesters.insert(requester_id, index);
+    }
+
+    fn report(&self, address: u64, fault: &fault_recording::Register) {
+        let function =
+            pci::FunctionAddress::from_requester_id(self.segment_number, fault.requester_id());
+        let (vendor, device): (pci::lspci::Vendor, pci::lspci::Device) = pci::lspci::name(function);
+        com2_println!(
+            "PCI {} {} {} {} {:#x} is blocked by the IOMMU. {:?}",
+            function,
+            vendor,
+            device,
+            if fault.is_read() {
+                "reading"
+            } else {
+                "writing"
+            },
+            address,
+            fault.reason()
+        );
+    }
+}
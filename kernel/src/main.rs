@@ -12,8 +12,10 @@ mod efi;
 mod elf;
 mod interrupt;
 mod io;
+mod iommu;
 mod memory;
 mod pci;
+mod power;
 mod processor;
 mod rs232c;
 mod sync;
@@ -61,6 +63,10 @@ fn main(argument: &'static mut Argument<'static>) {
     task::Controller::get_current_mut().unwrap().sti();
     // Check RSDP.
     assert!(Argument::get().efi_system_table().rsdp().is_correct());
+    // Plug the kernel into the AML interpreter.
+    if let Err(error) = acpi::machine_language::initialize() {
+        com2_println!("Can't load the ACPI namespace. error = {:#x?}", error);
+    }
     // Set APIC.
     let mut ia32_apic_base = x64::msr::ia32::ApicBase::get().unwrap();
     let local_apic_registers = interrupt::apic::local::Registers::initialize(&mut ia32_apic_base);
@@ -75,17 +81,95 @@ fn main(argument: &'static mut Argument<'static>) {
     local_apic_registers.initialize_apic(hpet);
     // Boot application processors.
     processor::Manager::initialize(local_apic_id, local_apic_registers, heap_size, hpet);
+    // Find the embedded controller.
+    acpi::embedded_controller::initialize();
+    // Bind drivers to ACPI devices.
+    acpi::device::initialize();
+    // Handle ACPI events.
+    acpi::event::initialize(local_apic_id);
+    // Read idle states.
+    processor::idle::initialize(local_apic_id);
+    // Read performance states.
+    processor::performance::initialize(local_apic_id);
+    // Monitor thermal zones.
+    acpi::thermal::initialize();
+    // Disassemble the DSDT and SSDTs.
+    #[cfg(feature = "disassemble")]
+    {
+        let xsdt: &acpi::extended_system_description::Table =
+            Argument::get().efi_system_table().rsdp().xsdt();
+        xsdt.fadt()
+            .dsdt()
+            .into_iter()
+            .chain(xsdt.entries())
+            .filter_map(|table| table.disassemble())
+            .for_each(|asl| com2_println!("{}", asl));
+    }
+    // Assign PCI bus numbers and resources.
+    let conflicts = pci::resource::assign();
+    com2_println!("PCI resource conflicts = {:#x?}", conflicts);
     // Enumerate PCI devices.
-    let mut pci = pci::Configuration::read();
-    pci.reset();
-    com2_println!("pci = {:#x?}", pci);
+    let pci = pci::Configuration::read();
+    // Translate DMA.
+    iommu::initialize(&pci, local_apic_id);
+    // Route PCI interrupts.
+    pci::interrupt_routing::initialize(&pci);
+    // Bind PCI drivers.
+    pci::driver::initialize(&pci);
+    com2_println!(
+        "{}",
+        pci::lspci::Listing::new(&pci, pci::lspci::Verbosity::Verbose)
+    );
+    pci.functions()
+        .filter_map(|function| {
+            let pin: pci::interrupt_routing::Pin = function
+                .function()
+                .header()
+                .interrupt_pin()
+                .try_into()
+                .ok()?;
+            let route: Option<pci::interrupt_routing::Route> =
+                pci::interrupt_routing::route(function.address());
+            Some((
+                function.segment_number(),
+                function.bus_number(),
+                function.device_number(),
+                function.function_number(),
+                pin,
+                route,
+            ))
+        })
+        .for_each(|(segment, bus, device, function, pin, route)| {
+            com2_println!(
+                "PCI {:04x}:{:02x}:{:02x}.{:x} {:?} -> {:#x?}",
+                segment,
+                bus,
+                device,
+                function,
+                pin,
+                route
+            )
+        });
+    // Suspend to RAM once to test waking.
+    #[cfg(feature = "suspend_test")]
+    acpi::event::press_sleep_button();
+    // Start the watchdog.
+    acpi::watchdog::initialize();
     // Kernel loop.
     let mut shutdown: bool = false;
     let mut loop_counter: usize = 0;
     while !shutdown {
         match interrupt::Event::pop() {
             Some(event) => event.process(),
-            None => x64::hlt(),
+            None => processor::idle::idle(),
+        }
+        if acpi::event::take_sleep_button() {
+            let pci_states = pci::power::save_all(&pci);
+            pci::driver::suspend(&pci);
+            acpi::sleep::suspend(local_apic_id);
+            pci::resource::restore();
+            pci::power::restore_all(&pci_states);
+            pci::driver::resume(&pci);
         }
         loop_counter +=
             if processor::Controller::get_all().all(|processor| processor.is_initialized()) {
@@ -93,27 +177,26 @@ fn main(argument: &'static mut Argument<'static>) {
             } else {
                 0
             };
-        shutdown = 0x100 <= loop_counter;
+        shutdown = (!cfg!(feature = "aer_test") && 0x100 <= loop_counter)
+            || acpi::event::is_power_button_pressed()
+            || acpi::thermal::is_critical();
+    }
+    // Report idle residencies.
+    com2_println!("idle governor = {:#x?}", processor::idle::governor());
+    processor::Controller::get_all().for_each(|processor| {
+        com2_println!(
+            "idle governor of processor {:#x?} = {:#x?}",
+            processor.local_apic_id(),
+            processor.idle_governor()
+        )
+    });
+    if cfg!(feature = "reboot_test") {
+        // Reboot to test the reset methods.
+        power::reboot(&pci, power::Kind::Warm)
+    } else {
+        // Shut down.
+        power::shutdown(&pci)
     }
-    // Stop RTC interruptions.
-    timer::rtc::disable_periodic_interrupt();
-    // Stop HPET.
-    timer::hpet::Registers::finalize();
-    // Stop APIC interruptions.
-    local_apic_registers.disable_periodic_interrupt();
-    // Disable all interruptions.
-    task::Controller::get_current_mut().unwrap().cli();
-    // Print AP log.
-    processor::Manager::finalize();
-    // Shutdown.
-    com2_println!("Shutting down.");
-    Argument::get()
-        .efi_system_table_mut()
-        .rsdp_mut()
-        .xsdt_mut()
-        .fadt_mut()
-        .shutdown();
-    unreachable!();
 }
 
 /// # A panic handler of the kernel
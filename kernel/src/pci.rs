@@ -2,26 +2,34 @@
 //! ## References
 //! * [PCI Express Base Specification Revision 5.0 Version 1.0](https://picture.iczhiku.com/resource/eetop/SYkDTqhOLhpUTnMx.pdf)
 
+pub mod aer;
 pub mod base;
 pub mod bist;
 pub mod bridge_control;
 pub mod capability;
 pub mod class;
 pub mod command;
+pub mod driver;
 pub mod expansion_rom_base_address;
+pub mod extended_capability;
 pub mod header_type;
+pub mod ids;
+pub mod interrupt_routing;
+pub mod lspci;
+pub mod power;
+pub mod resource;
 pub mod secondary_status;
 pub mod status;
 pub mod xhc;
 
 use {
-    crate::x64,
+    crate::{x64, Argument},
     alloc::{
         collections::{btree_map::BTreeMap, btree_set::BTreeSet},
         vec::Vec,
     },
     bitfield_struct::bitfield,
-    core::{fmt, mem, ops},
+    core::{fmt, iter, mem, ops},
 };
 
 /// # CFGADR - Configuration Address Register
@@ -85,45 +93,308 @@ impl Address {
     }
 }
 
+/// # Configuration Space Address of a Function
+/// The whole 4 KiB configuration space of any segment is accessed through the ECAM windows in the MCFG.
+/// Without a window, CFGADR reaches the first 256 bytes on segment 0.
+/// ## References
+/// * [PCI Express Base Specification Revision 5.0 Version 1.0](https://picture.iczhiku.com/resource/eetop/SYkDTqhOLhpUTnMx.pdf) 7.2.2 PCI Express Enhanced Configuration Access Mechanism (ECAM)
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct FunctionAddress {
+    segment: u16,
+    bus: u8,
+    device: u8,
+    function: u8,
+}
+
+impl FunctionAddress {
+    const COMMAND_AND_STATUS: u16 = 0x04;
+    const HEADER_TYPE: u16 = 0x0c;
+    const BUS_NUMBERS: u16 = 0x18;
+    const CAPABILITIES_POINTER: u16 = 0x34;
+    const LEGACY_SPACE_SIZE: u16 = 0x100;
+    /// The capabilities list is followed at most this many times in case it loops.
+    const MAX_CAPABILITIES: usize = 48;
+
+    pub fn new(segment: u16, bus: u8, device: u8, function: u8) -> Self {
+        Self {
+            segment,
+            bus,
+            device,
+            function,
+        }
+    }
+
+    /// # The function identified by a Requester ID in a segment
+    pub fn from_requester_id(segment: u16, requester_id: u16) -> Self {
+        let bus: u8 = (requester_id >> 8) as u8;
+        let device: u8 = ((requester_id >> 3) & 0x1f) as u8;
+        let function: u8 = (requester_id & 0x7) as u8;
+        Self::new(segment, bus, device, function)
+    }
+
+    /// # The functions which are present on a bus
+    pub fn bus(segment: u16, bus: u8) -> Vec<Self> {
+        Address::device_range()
+            .map(|device| Self::new(segment, bus, device, 0))
+            .filter(|function| function.is_present())
+            .flat_map(|function| {
+                let device: u8 = function.device;
+                let functions: ops::RangeInclusive<u8> =
+                    if function.header_type().is_multi_function_device() {
+                        Address::function_range()
+                    } else {
+                        0..=0
+                    };
+                functions.map(move |function| Self::new(segment, bus, device, function))
+            })
+            .filter(|function| function.is_present())
+            .collect()
+    }
+
+    /// # Find a capability in the current configuration space
+    /// The enumerated configuration may be stale after a reset.
+    pub fn capability(self, id: capability::Id) -> Option<u16> {
+        let has_capabilities_list: bool = self.read(Self::COMMAND_AND_STATUS) & 0x00100000 != 0;
+        if !has_capabilities_list {
+            return None;
+        }
+        let mut pointer: u16 = (self.read(Self::CAPABILITIES_POINTER) & 0xfc) as u16;
+        (0..Self::MAX_CAPABILITIES)
+            .map_while(|_| {
+                (pointer != 0).then(|| {
+                    let current: u16 = pointer;
+                    let header: u16 = self.read(current) as u16;
+                    pointer = (header >> u8::BITS) & 0xfc;
+                    (current, capability::Id::from(header as u8))
+                })
+            })
+            .find(|(_pointer, found)| *found == id)
+            .map(|(pointer, _found)| pointer)
+    }
+
+    /// # Find an extended capability in the current configuration space
+    pub fn extended_capability(self, id: extended_capability::Id) -> Option<u16> {
+        let mut pointer: u16 = extended_capability::FIRST_POINTER;
+        iter::from_fn(|| {
+            let header: u32 = self.read(pointer);
+            (extended_capability::FIRST_POINTER <= pointer && header != 0 && header != u32::MAX)
+                .then(|| {
+                    let current: u16 = pointer;
+                    let header: extended_capability::Header = header.into();
+                    // A capability never points backward, which stops a broken list.
+                    pointer = Some(header.next_pointer())
+                        .filter(|next_pointer| current < *next_pointer)
+                        .unwrap_or(0);
+                    (current, header.id())
+                })
+        })
+        .find(|(_pointer, found)| *found == id)
+        .map(|(pointer, _found)| pointer)
+    }
+
+    /// # Read the current header type
+    pub fn header_type(self) -> header_type::Register {
+        ((self.read(Self::HEADER_TYPE) >> 16) as u8).into()
+    }
+
+    pub fn bus_number(self) -> u8 {
+        self.bus
+    }
+
+    pub fn is_present(self) -> bool {
+        self.read(0) & 0xffff != 0xffff
+    }
+
+    /// # Read a dword of the configuration space
+    /// All bits are set if the register is not reachable.
+    pub fn read(self, register: u16) -> u32 {
+        assert_eq!((register as usize) % mem::size_of::<u32>(), 0);
+        match self.ecam() {
+            Some(ecam) => {
+                let register: *const u32 = (ecam + register as usize) as *const u32;
+                unsafe { register.read_volatile() }
+            }
+            None if self.segment == 0 && register < Self::LEGACY_SPACE_SIZE => {
+                Address::create(self.bus, self.device, self.function, register as u8).read()
+            }
+            None => u32::MAX,
+        }
+    }
+
+    /// # The Requester ID of the function in transactions it initiates
+    pub fn requester_id(self) -> u16 {
+        ((self.bus as u16) << 8) | ((self.device as u16) << 3) | (self.function as u16)
+    }
+
+    /// # The buses under a bridge
+    /// It is `None` if the function is not a bridge or its secondary bus is not numbered yet.
+    pub fn secondary_buses(self) -> Option<ops::RangeInclusive<u8>> {
+        matches!(self.header_type().into(), header_type::Type::One)
+            .then(|| self.read(Self::BUS_NUMBERS))
+            .map(|bus_numbers| ((bus_numbers >> 8) as u8, (bus_numbers >> 16) as u8))
+            .filter(|(secondary_bus_number, _)| *secondary_bus_number != 0)
+            .map(|(secondary_bus_number, subordinate_bus_number)| {
+                secondary_bus_number..=subordinate_bus_number
+            })
+    }
+
+    pub fn segment_number(self) -> u16 {
+        self.segment
+    }
+
+    /// # Write a dword of the configuration space
+    /// The data is discarded if the register is not reachable.
+    pub fn write(self, register: u16, data: u32) {
+        assert_eq!((register as usize) % mem::size_of::<u32>(), 0);
+        match self.ecam() {
+            Some(ecam) => {
+                let register: *mut u32 = (ecam + register as usize) as *mut u32;
+                unsafe { register.write_volatile(data) }
+            }
+            None if self.segment == 0 && register < Self::LEGACY_SPACE_SIZE => {
+                Address::create(self.bus, self.device, self.function, register as u8).write(data)
+            }
+            None => {}
+        }
+    }
+
+    /// # The address of the configuration space in the ECAM window
+    pub fn ecam(self) -> Option<usize> {
+        Argument::get()
+            .efi_system_table()
+            .rsdp()
+            .xsdt()
+            .mcfg()?
+            .address(self.segment, self.bus, self.device, self.function)
+    }
+}
+
+impl fmt::Display for FunctionAddress {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "{:04x}:{:02x}:{:02x}.{:x}",
+            self.segment, self.bus, self.device, self.function
+        )
+    }
+}
+
 /// # PCI
 /// ## References
 /// * [PCI Express Base Specification Revision 5.0 Version 1.0](https://picture.iczhiku.com/resource/eetop/SYkDTqhOLhpUTnMx.pdf)
 #[derive(Default)]
 pub struct Configuration {
-    buses: BTreeMap<u8, Bus>,
+    segments: BTreeMap<u16, Segment>,
 }
 
 impl Configuration {
+    /// # Enumerate functions
+    /// Segment 0 is scanned from the host bridge at 00:00.0, and the other segments in the MCFG are scanned from their first buses.
     pub fn read() -> Self {
-        let buses: BTreeMap<u8, Bus> = BTreeMap::new();
-        let mut pci = Self { buses };
+        let segments: BTreeMap<u16, Segment> = BTreeMap::new();
+        let mut pci = Self { segments };
+        let segment_number: u16 = 0;
         let bus_number: u8 = 0;
         let device_number: u8 = 0;
         let function_number: u8 = 0;
-        pci.scan(bus_number, device_number, function_number);
+        pci.scan(segment_number, bus_number, device_number, function_number);
+        if let Some(mcfg) = Argument::get().efi_system_table().rsdp().xsdt().mcfg() {
+            mcfg.configuration_space_base_address_allocations()
+                .iter()
+                .filter(|allocation| allocation.pci_segment_group_number() != 0)
+                .for_each(|allocation| {
+                    let segment_number: u16 = allocation.pci_segment_group_number();
+                    let bus_number: u8 = *allocation.bus_range().start();
+                    Address::device_range().for_each(|device_number| {
+                        pci.scan(segment_number, bus_number, device_number, function_number)
+                    });
+                });
+        }
         pci
     }
 
-    pub fn reset(&mut self) {
-        self.buses.values_mut().for_each(|bus| bus.reset())
+    pub fn functions(&self) -> impl Iterator<Item = FunctionWithAddress<'_>> {
+        self.segments.iter().flat_map(|(segment_number, segment)| {
+            segment.buses.iter().flat_map(move |(bus_number, bus)| {
+                bus.devices.iter().flat_map(move |(device_number, device)| {
+                    device
+                        .functions
+                        .iter()
+                        .map(move |(function_number, function)| {
+                            FunctionWithAddress::new(
+                                *segment_number,
+                                *bus_number,
+                                *device_number,
+                                *function_number,
+                                function,
+                            )
+                        })
+                })
+            })
+        })
+    }
+
+    /// # Find a function
+    pub fn function(&self, address: FunctionAddress) -> Option<FunctionWithAddress<'_>> {
+        self.functions()
+            .find(|function| function.address() == address)
     }
 
-    fn add(&mut self, bus_number: u8, device_number: u8, function_number: u8, function: Function) {
-        self.buses
+    fn add(
+        &mut self,
+        segment_number: u16,
+        bus_number: u8,
+        device_number: u8,
+        function_number: u8,
+        function: Function,
+    ) {
+        self.segments
+            .entry(segment_number)
+            .or_default()
+            .buses
             .entry(bus_number)
             .or_default()
             .add(device_number, function_number, function);
     }
 
-    fn has(&self, bus_number: u8, device_number: u8, function_number: u8) -> bool {
-        self.buses
-            .get(&bus_number)
+    fn has(
+        &self,
+        segment_number: u16,
+        bus_number: u8,
+        device_number: u8,
+        function_number: u8,
+    ) -> bool {
+        self.segments
+            .get(&segment_number)
+            .and_then(|segment| segment.buses.get(&bus_number))
             .map_or(false, |bus| bus.has(device_number, function_number))
     }
 
-    fn scan(&mut self, bus_number: u8, device_number: u8, function_number: u8) {
-        if !self.has(bus_number, device_number, function_number) {
-            if let Some(function) = Function::read(bus_number, device_number, function_number) {
+    /// # Find the PCI to PCI bridge whose secondary bus is the bus
+    pub fn upstream_bridge(
+        &self,
+        segment_number: u16,
+        bus_number: u8,
+    ) -> Option<FunctionWithAddress<'_>> {
+        self.functions()
+            .filter(|function| function.segment_number == segment_number)
+            .find(|function| match function.function.header() {
+                Header::Type1(type1) => type1.secondary_bus_number == bus_number,
+                Header::Type0(_) => false,
+            })
+    }
+
+    fn scan(
+        &mut self,
+        segment_number: u16,
+        bus_number: u8,
+        device_number: u8,
+        function_number: u8,
+    ) {
+        if !self.has(segment_number, bus_number, device_number, function_number) {
+            if let Some(function) =
+                Function::read(segment_number, bus_number, device_number, function_number)
+            {
                 let mut next_addresses: BTreeSet<(u8, u8, u8)> = BTreeSet::new();
                 match function.header().class_code() {
                     class::Code::HostBridge => {
@@ -155,10 +426,16 @@ impl Configuration {
                             .map(|function_number| (bus_number, device_number, function_number)),
                     );
                 }
-                self.add(bus_number, device_number, function_number, function);
+                self.add(
+                    segment_number,
+                    bus_number,
+                    device_number,
+                    function_number,
+                    function,
+                );
                 next_addresses.into_iter().for_each(
                     |(bus_number, device_number, function_number)| {
-                        self.scan(bus_number, device_number, function_number)
+                        self.scan(segment_number, bus_number, device_number, function_number)
                     },
                 );
             }
@@ -170,11 +447,54 @@ impl fmt::Debug for Configuration {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
         formatter
             .debug_map()
-            .entries(
-                self.buses
-                    .iter()
-                    .map(|(bus_number, bus)| (*bus_number, BusWithAddress::new(*bus_number, bus))),
-            )
+            .entries(self.segments.iter().map(|(segment_number, segment)| {
+                (
+                    *segment_number,
+                    SegmentWithAddress::new(*segment_number, segment),
+                )
+            }))
+            .finish()
+    }
+}
+
+/// # PCI Segment Group
+/// ## References
+/// * [PCI Express Base Specification Revision 5.0 Version 1.0](https://picture.iczhiku.com/resource/eetop/SYkDTqhOLhpUTnMx.pdf)
+#[derive(Default)]
+pub struct Segment {
+    buses: BTreeMap<u8, Bus>,
+}
+
+pub struct SegmentWithAddress<'a> {
+    segment_number: u16,
+    segment: &'a Segment,
+}
+
+impl<'a> SegmentWithAddress<'a> {
+    fn new(segment_number: u16, segment: &'a Segment) -> Self {
+        Self {
+            segment_number,
+            segment,
+        }
+    }
+}
+
+impl fmt::Debug for SegmentWithAddress<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_map()
+            .entries({
+                let Self {
+                    segment_number,
+                    segment: Segment { buses },
+                } = self;
+                buses.iter().map(|(bus_number, bus)| {
+                    (
+                        *bus_number,
+                        BusWithAddress::new(*segment_number, *bus_number, bus),
+                    )
+                })
+            })
             .finish()
     }
 }
@@ -200,20 +520,21 @@ impl Bus {
             .get(&device_number)
             .map_or(false, |device| device.has(function_number))
     }
-
-    fn reset(&mut self) {
-        self.devices.values_mut().for_each(|device| device.reset());
-    }
 }
 
 pub struct BusWithAddress<'a> {
+    segment_number: u16,
     bus_number: u8,
     bus: &'a Bus,
 }
 
 impl<'a> BusWithAddress<'a> {
-    fn new(bus_number: u8, bus: &'a Bus) -> Self {
-        Self { bus_number, bus }
+    fn new(segment_number: u16, bus_number: u8, bus: &'a Bus) -> Self {
+        Self {
+            segment_number,
+            bus_number,
+            bus,
+        }
     }
 }
 
@@ -223,13 +544,19 @@ impl fmt::Debug for BusWithAddress<'_> {
             .debug_map()
             .entries({
                 let Self {
+                    segment_number,
                     bus_number,
                     bus: Bus { devices },
                 } = self;
                 devices.iter().map(|(device_number, device)| {
                     (
                         *device_number,
-                        DeviceWithAddress::new(*bus_number, *device_number, device),
+                        DeviceWithAddress::new(
+                            *segment_number,
+                            *bus_number,
+                            *device_number,
+                            device,
+                        ),
                     )
                 })
             })
@@ -253,23 +580,19 @@ impl Device {
     fn has(&self, function_number: u8) -> bool {
         self.functions.get(&function_number).is_some()
     }
-
-    fn reset(&mut self) {
-        self.functions
-            .values_mut()
-            .for_each(|device| device.reset());
-    }
 }
 
 pub struct DeviceWithAddress<'a> {
+    segment_number: u16,
     bus_number: u8,
     device_number: u8,
     device: &'a Device,
 }
 
 impl<'a> DeviceWithAddress<'a> {
-    fn new(bus_number: u8, device_number: u8, device: &'a Device) -> Self {
+    fn new(segment_number: u16, bus_number: u8, device_number: u8, device: &'a Device) -> Self {
         Self {
+            segment_number,
             bus_number,
             device_number,
             device,
@@ -283,6 +606,7 @@ impl fmt::Debug for DeviceWithAddress<'_> {
             .debug_map()
             .entries({
                 let Self {
+                    segment_number,
                     bus_number,
                     device_number,
                     device: Device { functions },
@@ -291,6 +615,7 @@ impl fmt::Debug for DeviceWithAddress<'_> {
                     (
                         *function_number,
                         FunctionWithAddress::new(
+                            *segment_number,
                             *bus_number,
                             *device_number,
                             *function_number,
@@ -304,6 +629,7 @@ impl fmt::Debug for DeviceWithAddress<'_> {
 }
 
 /// # PCI Function
+/// The first 256 bytes of the configuration space are kept, and the extended capabilities are read on demand through `FunctionAddress`.
 /// ## References
 /// * [PCI Express Base Specification Revision 5.0 Version 1.0](https://picture.iczhiku.com/resource/eetop/SYkDTqhOLhpUTnMx.pdf) 7.5.1.1 Type 0/1 Common Configuration Space Figure 7-4 Common Configuration Space Header
 pub struct Function {
@@ -318,27 +644,21 @@ impl Function {
         self.into()
     }
 
-    pub fn read(bus: u8, device: u8, function: u8) -> Option<Self> {
-        let space: Vec<u32> = (u8::MIN..=u8::MAX)
-            .filter(|register| register % 4 == 0)
-            .map(|register| Address::create(bus, device, function, register).read())
-            .collect();
-        let space: [u32; Self::LENGTH] = space.try_into().unwrap();
-        let vendor_id: u16 = (space[0] & 0x0000ffff) as u16;
-        (vendor_id != 0xffff).then_some(Self { space })
-    }
-
-    pub fn reset(&self) {
-        if self.header().class_code() == class::Code::UsbXhc {
-            let xhc: Result<xhc::Registers, ()> = self.try_into();
-            if let Ok(mut xhc) = xhc {
-                xhc.reset();
-            }
-        }
+    pub fn read(segment: u16, bus: u8, device: u8, function: u8) -> Option<Self> {
+        let address = FunctionAddress::new(segment, bus, device, function);
+        let vendor_id: u16 = (address.read(0) & 0x0000ffff) as u16;
+        (vendor_id != 0xffff).then(|| {
+            let space: Vec<u32> = (0..Self::LENGTH)
+                .map(|index| address.read((index * mem::size_of::<u32>()) as u16))
+                .collect();
+            let space: [u32; Self::LENGTH] = space.try_into().unwrap();
+            Self { space }
+        })
     }
 }
 
 pub struct FunctionWithAddress<'a> {
+    segment_number: u16,
     bus_number: u8,
     device_number: u8,
     function_number: u8,
@@ -346,6 +666,15 @@ pub struct FunctionWithAddress<'a> {
 }
 
 impl<'a> FunctionWithAddress<'a> {
+    pub fn address(&self) -> FunctionAddress {
+        FunctionAddress::new(
+            self.segment_number,
+            self.bus_number,
+            self.device_number,
+            self.function_number,
+        )
+    }
+
     pub fn function(&'a self) -> &'a Function {
         self.function
     }
@@ -354,32 +683,48 @@ impl<'a> FunctionWithAddress<'a> {
         self.bus_number
     }
 
+    pub fn capabilities(&self) -> capability::Headers<'_> {
+        self.into()
+    }
+
     pub fn device_number(&self) -> u8 {
         self.device_number
     }
 
+    pub fn extended_capabilities(&self) -> extended_capability::Headers {
+        self.address().into()
+    }
+
     pub fn function_number(&self) -> u8 {
         self.function_number
     }
 
-    fn new(bus_number: u8, device_number: u8, function_number: u8, function: &'a Function) -> Self {
+    pub fn segment_number(&self) -> u16 {
+        self.segment_number
+    }
+
+    fn new(
+        segment_number: u16,
+        bus_number: u8,
+        device_number: u8,
+        function_number: u8,
+        function: &'a Function,
+    ) -> Self {
         Self {
+            segment_number,
             bus_number,
             device_number,
             function_number,
             function,
         }
     }
-
-    fn msi_capabilities(&self) -> capability::Headers<'_> {
-        self.into()
-    }
 }
 
 impl fmt::Debug for FunctionWithAddress<'_> {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut debug_struct: fmt::DebugStruct = formatter.debug_struct("Function");
-        let capabilities: capability::Headers = self.msi_capabilities();
+        let capabilities: capability::Headers = self.capabilities();
+        let extended_capabilities: extended_capability::Headers = self.extended_capabilities();
         match self.function.header() {
             Header::Type0(type0) => {
                 let vendor_id: u16 = type0.vendor_id;
@@ -420,6 +765,7 @@ impl fmt::Debug for FunctionWithAddress<'_> {
                     .field("subsystem_id", &subsystem_id)
                     .field("expansion_rom_base_address", &expansion_rom_base_address)
                     .field("capabilities", &capabilities)
+                    .field("extended_capabilities", &extended_capabilities)
                     .field("interrupt_line", &interrupt_line)
                     .field("interrupt_pin", &interrupt_pin)
                     .field("min_gnt", &min_gnt)
@@ -494,6 +840,7 @@ impl fmt::Debug for FunctionWithAddress<'_> {
                     .field("io_base_upper_16bits", &io_base_upper_16bits)
                     .field("io_limit_upper_16bits", &io_limit_upper_16bits)
                     .field("capabilities", &capabilities)
+                    .field("extended_capabilities", &extended_capabilities)
                     .field("expantion_rom_base_address", &expantion_rom_base_address)
                     .field("interrupt_line", &interrupt_line)
                     .field("interrupt_pin", &interrupt_pin)
@@ -523,6 +870,19 @@ impl Header<'_> {
         }
     }
 
+    /// # Primary, Secondary and Subordinate Bus Numbers
+    /// Only a bridge has bus numbers in its header.
+    pub fn bus_numbers(&self) -> Option<(u8, u8, u8)> {
+        match self {
+            Self::Type0(_) => None,
+            Self::Type1(type1) => Some((
+                type1.primary_bus_number,
+                type1.secondary_bus_number,
+                type1.subordinate_bus_number,
+            )),
+        }
+    }
+
     pub fn capabilities_pointer(&self) -> u8 {
         let capabilities_pointer: u8 = match self {
             Self::Type0(type0) => type0.capabilities_pointer,
@@ -540,6 +900,27 @@ impl Header<'_> {
         .into()
     }
 
+    pub fn class_register(&self) -> class::Register {
+        match self {
+            Self::Type0(type0) => type0.class_code.clone(),
+            Self::Type1(type1) => type1.class_code.clone(),
+        }
+    }
+
+    pub fn command(&self) -> command::Register {
+        match self {
+            Self::Type0(type0) => type0.command,
+            Self::Type1(type1) => type1.command,
+        }
+    }
+
+    pub fn device_id(&self) -> u16 {
+        match self {
+            Self::Type0(type0) => type0.device_id,
+            Self::Type1(type1) => type1.device_id,
+        }
+    }
+
     pub fn header_type(&self) -> header_type::Register {
         match self {
             Self::Type0(type0) => type0.header_type,
@@ -547,6 +928,54 @@ impl Header<'_> {
         }
     }
 
+    pub fn expansion_rom_base_address(&self) -> expansion_rom_base_address::Register {
+        match self {
+            Self::Type0(type0) => type0.expansion_rom_base_address,
+            Self::Type1(type1) => type1.expantion_rom_base_address,
+        }
+    }
+
+    pub fn interrupt_line(&self) -> u8 {
+        match self {
+            Self::Type0(type0) => type0.interrupt_line,
+            Self::Type1(type1) => type1.interrupt_line,
+        }
+    }
+
+    /// # Interrupt Pin
+    /// 1 to 4 mean INTA# to INTD# and 0 means that the function uses no legacy interrupt.
+    pub fn interrupt_pin(&self) -> u8 {
+        match self {
+            Self::Type0(type0) => type0.interrupt_pin,
+            Self::Type1(type1) => type1.interrupt_pin,
+        }
+    }
+
+    pub fn revision_id(&self) -> u8 {
+        match self {
+            Self::Type0(type0) => type0.revision_id,
+            Self::Type1(type1) => type1.revision_id,
+        }
+    }
+
+    /// # Subsystem ID
+    /// A bridge has no subsystem ID in its header.
+    pub fn subsystem_id(&self) -> Option<u16> {
+        match self {
+            Self::Type0(type0) => Some(type0.subsystem_id),
+            Self::Type1(_) => None,
+        }
+    }
+
+    /// # Subsystem Vendor ID
+    /// A bridge has no subsystem vendor ID in its header.
+    pub fn subsystem_vendor_id(&self) -> Option<u16> {
+        match self {
+            Self::Type0(type0) => Some(type0.subsystem_vendor_id),
+            Self::Type1(_) => None,
+        }
+    }
+
     pub fn vendor_id(&self) -> u16 {
         match self {
             Self::Type0(type0) => type0.vendor_id,
@@ -0,0 +1,394 @@
+//! # PCI Express Advanced Error Reporting
+//! A root port with the Advanced Error Reporting capability collects the error messages from the functions under it and interrupts the processor through MSI-X, MSI or its INTx pin.
+//! The functions under the root port send the error messages because error reporting is enabled in their PCI Express Device Control registers.
+//! An error is decoded from the Advanced Error Reporting capability of the function in the Error Source Identification register and logged with the name of the function.
+//! A fatal error is recovered from by Secondary Bus Reset of the root port, which resets the link.
+//! ## References
+//! * [PCI Express Base Specification Revision 5.0 Version 1.0](https://picture.iczhiku.com/resource/eetop/SYkDTqhOLhpUTnMx.pdf) 6.2 Error Signaling and Logging
+//! * [PCI Express Base Specification Revision 5.0 Version 1.0](https://picture.iczhiku.com/resource/eetop/SYkDTqhOLhpUTnMx.pdf) 7.8.4 Advanced Error Reporting Extended Capability
+
+use {
+    super::{
+        capability::{
+            self,
+            msi::message,
+            msi_x,
+            pci_express::{device, root},
+        },
+        command,
+        extended_capability::{self, advanced_error_reporting},
+        interrupt_routing, lspci, power, FunctionAddress, FunctionWithAddress,
+    },
+    crate::{com2_println, interrupt, iommu, sync::spin},
+    alloc::vec::Vec,
+};
+
+const COMMAND: u16 = 0x04;
+
+// Registers in the PCI Express capability
+const DEVICE_CONTROL_AND_STATUS: u16 = 0x08;
+const ROOT_CONTROL: u16 = 0x1c;
+
+// Registers in the Advanced Error Reporting capability
+const UNCORRECTABLE_ERROR_STATUS: u16 = 0x04;
+const UNCORRECTABLE_ERROR_SEVERITY: u16 = 0x0c;
+const CORRECTABLE_ERROR_STATUS: u16 = 0x10;
+const HEADER_LOG: u16 = 0x1c;
+const ROOT_ERROR_COMMAND: u16 = 0x2c;
+const ROOT_ERROR_STATUS: u16 = 0x30;
+const ERROR_SOURCE_IDENTIFICATION: u16 = 0x34;
+
+static ROOT_PORTS: spin::Lock<Vec<RootPort>> = spin::Lock::new(Vec::new());
+
+/// # Start handling the errors under a root port
+/// The interrupt is sent to the local APIC.
+pub fn enable(function: &FunctionWithAddress, local_apic_id: u8) -> Result<(), Error> {
+    let address: FunctionAddress = function.address();
+    let pci_express: u16 = address
+        .capability(capability::Id::PciExpress)
+        .ok_or(Error::NoPciExpress)?;
+    let advanced_error_reporting: u16 = address
+        .extended_capability(extended_capability::Id::AdvancedErrorReporting)
+        .ok_or(Error::NoAdvancedErrorReporting)?;
+    let root_port = RootPort {
+        address,
+        advanced_error_reporting,
+    };
+    root_port.set_reporting(false);
+    root_port.clear();
+    enable_interrupt(function, local_apic_id)?;
+    // The errors are handled by the interrupt instead of System Error.
+    let root_control: root::control::Register =
+        (address.read(pci_express + ROOT_CONTROL) as u16).into();
+    address.write(
+        pci_express + ROOT_CONTROL,
+        u16::from(root_control.with_system_errors(false)) as u32,
+    );
+    root_port
+        .functions()
+        .into_iter()
+        .for_each(|function| set_error_reporting(function, true));
+    root_port.set_reporting(true);
+    ROOT_PORTS.lock().push(root_port);
+    com2_println!("PCI {} reports errors.", address);
+    Ok(())
+}
+
+/// # Stop handling the errors under a root port
+pub fn disable(address: FunctionAddress) {
+    let root_port: Option<RootPort> = {
+        let mut root_ports = ROOT_PORTS.lock();
+        root_ports
+            .iter()
+            .position(|root_port| root_port.address == address)
+            .map(|index| root_ports.remove(index))
+    };
+    if let Some(root_port) = root_port {
+        root_port.set_reporting(false);
+        root_port
+            .functions()
+            .into_iter()
+            .for_each(|function| set_error_reporting(function, false));
+        disable_interrupt(address);
+    }
+}
+
+/// # Handle the error messages received by the root ports
+/// It is called on the Advanced Error Reporting interrupt.
+pub fn handle() {
+    // Recovery waits for the functions, so the lock isn't held.
+    let root_ports: Vec<RootPort> = ROOT_PORTS.lock().clone();
+    root_ports.iter().for_each(RootPort::handle);
+}
+
+#[derive(Debug)]
+pub enum Error {
+    /// The root port has neither MSI nor MSI-X, and its INTx pin isn't routed to an I/O APIC.
+    InterruptUnavailable,
+    NoAdvancedErrorReporting,
+    NoPciExpress,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct RootPort {
+    address: FunctionAddress,
+    advanced_error_reporting: u16,
+}
+
+impl RootPort {
+    /// # Clear the error status of the root port
+    fn clear(&self) {
+        [
+            UNCORRECTABLE_ERROR_STATUS,
+            CORRECTABLE_ERROR_STATUS,
+            ROOT_ERROR_STATUS,
+        ]
+        .into_iter()
+        .map(|register| self.advanced_error_reporting + register)
+        .for_each(|register| self.address.write(register, self.address.read(register)));
+        clear_device_status(self.address);
+    }
+
+    /// # The functions in the hierarchy of the root port including itself
+    fn functions(&self) -> Vec<FunctionAddress> {
+        let segment: u16 = self.address.segment;
+        [self.address]
+            .into_iter()
+            .chain(
+                self.address
+                    .secondary_buses()
+                    .into_iter()
+                    .flatten()
+                    .flat_map(|bus| FunctionAddress::bus(segment, bus)),
+            )
+            .collect()
+    }
+
+    fn handle(&self) {
+        let root_error_status: u32 = self
+            .address
+            .read(self.advanced_error_reporting + ROOT_ERROR_STATUS);
+        let status: advanced_error_reporting::RootErrorStatus = root_error_status.into();
+        let error_sources: u32 = self
+            .address
+            .read(self.advanced_error_reporting + ERROR_SOURCE_IDENTIFICATION);
+        // The status is cleared before the sources are handled so that a following error interrupts again.
+        self.address.write(
+            self.advanced_error_reporting + ROOT_ERROR_STATUS,
+            root_error_status,
+        );
+        if status.is_correctable_received() {
+            let source: FunctionAddress = self.source(error_sources as u16);
+            if status.is_multiple_correctable_received() {
+                com2_println!("PCI {} received multiple correctable errors.", self.address);
+            }
+            report_correctable(source);
+        }
+        if status.is_uncorrectable_received() {
+            let source: FunctionAddress = self.source((error_sources >> u16::BITS) as u16);
+            if status.is_multiple_uncorrectable_received() {
+                com2_println!(
+                    "PCI {} received multiple uncorrectable errors.",
+                    self.address
+                );
+            }
+            let fatal: bool = status.is_fatal_received();
+            report_uncorrectable(source, fatal);
+            if fatal {
+                self.recover();
+            }
+        }
+    }
+
+    /// # Reset the link under the root port
+    fn recover(&self) {
+        com2_println!("PCI {} resets its link.", self.address);
+        match power::reset_secondary_bus(self.address) {
+            Ok(()) => com2_println!("PCI {} recovered from the fatal error.", self.address),
+            Err(error) => com2_println!(
+                "PCI {} can't recover from the fatal error. {:?}",
+                self.address,
+                error
+            ),
+        }
+    }
+
+    /// # Enable or disable the interrupt on error messages
+    fn set_reporting(&self, enable: bool) {
+        let register: u16 = self.advanced_error_reporting + ROOT_ERROR_COMMAND;
+        let root_error_command: advanced_error_reporting::RootErrorCommand =
+            self.address.read(register).into();
+        self.address
+            .write(register, root_error_command.with_reporting(enable).into());
+    }
+
+    /// # The function identified by a Requester ID
+    fn source(&self, requester_id: u16) -> FunctionAddress {
+        FunctionAddress::from_requester_id(self.address.segment, requester_id)
+    }
+}
+
+/// # Clear the error detected bits in the PCI Express Device Status register
+fn clear_device_status(function: FunctionAddress) {
+    if let Some(pci_express) = function.capability(capability::Id::PciExpress) {
+        let register: u16 = pci_express + DEVICE_CONTROL_AND_STATUS;
+        let device_control_and_status: u32 = function.read(register);
+        function.write(register, device_control_and_status & 0x000fffff);
+    }
+}
+
+fn disable_interrupt(function: FunctionAddress) {
+    if let Some(msi_x) = function.capability(capability::Id::MsiX) {
+        let message_control_and_header: u32 = function.read(msi_x);
+        let message_control: msi_x::MessageControl =
+            ((message_control_and_header >> u16::BITS) as u16).into();
+        write_message_control(
+            function,
+            msi_x,
+            message_control_and_header,
+            message_control.with_enabled(false).into(),
+        );
+    } else if let Some(msi) = function.capability(capability::Id::Msi) {
+        let message_control_and_header: u32 = function.read(msi);
+        let message_control: message::Control =
+            ((message_control_and_header >> u16::BITS) as u16).into();
+        write_message_control(
+            function,
+            msi,
+            message_control_and_header,
+            message_control.with_enabled(false).into(),
+        );
+    } else {
+        let command: command::Register = (function.read(COMMAND) as u16).into();
+        function.write(COMMAND, u16::from(command.with_interrupt(false)) as u32);
+    }
+}
+
+/// # Send the interrupts of a function to a local APIC
+/// MSI-X is preferred to MSI, and every MSI-X entry has the same message because the Advanced Error Interrupt Message Number may select any of them.
+fn enable_interrupt(function: &FunctionWithAddress, local_apic_id: u8) -> Result<(), Error> {
+    let address: FunctionAddress = function.address();
+    let (message_address, message_data): (u64, u32) =
+        iommu::msi_message(address, local_apic_id, interrupt::AER_INTERRUPT);
+    if let Some(msi_x) = address.capability(capability::Id::MsiX) {
+        if let capability::Structure::MsiX(structure) =
+            capability::Structure::new(function, msi_x as u8)
+        {
+            let structure: &msi_x::Structure = structure.structure();
+            (0..structure.table_length()).for_each(|index| {
+                let entry = msi_x::table::Entry::new(message_address, message_data);
+                structure.write_table(function.function(), index, entry);
+            });
+        }
+        let message_control_and_header: u32 = address.read(msi_x);
+        let message_control: msi_x::MessageControl =
+            ((message_control_and_header >> u16::BITS) as u16).into();
+        write_message_control(
+            address,
+            msi_x,
+            message_control_and_header,
+            message_control.with_enabled(true).into(),
+        );
+    } else if let Some(msi) = address.capability(capability::Id::Msi) {
+        let message_control_and_header: u32 = address.read(msi);
+        let message_control: message::Control =
+            ((message_control_and_header >> u16::BITS) as u16).into();
+        address.write(msi + 4, message_address as u32);
+        let message_data_register: u16 = if message_control.is_64bit() {
+            address.write(msi + 8, (message_address >> u32::BITS) as u32);
+            msi + 0x0c
+        } else {
+            msi + 8
+        };
+        address.write(message_data_register, message_data);
+        write_message_control(
+            address,
+            msi,
+            message_control_and_header,
+            message_control.with_enabled(true).into(),
+        );
+    } else {
+        // The root port asserts its INTx pin instead.
+        let redirected: bool = interrupt_routing::route(address)
+            .is_some_and(|route| route.redirect(local_apic_id, interrupt::AER_INTERRUPT));
+        if !redirected {
+            return Err(Error::InterruptUnavailable);
+        }
+        let command: command::Register = (address.read(COMMAND) as u16).into();
+        address.write(COMMAND, u16::from(command.with_interrupt(true)) as u32);
+        return Ok(());
+    }
+    let command: command::Register = (address.read(COMMAND) as u16).into();
+    address.write(COMMAND, u16::from(command.with_bus_master(true)) as u32);
+    Ok(())
+}
+
+fn report_correctable(source: FunctionAddress) {
+    let (vendor, device): (lspci::Vendor, lspci::Device) = lspci::name(source);
+    match source.extended_capability(extended_capability::Id::AdvancedErrorReporting) {
+        Some(advanced_error_reporting) => {
+            let register: u16 = advanced_error_reporting + CORRECTABLE_ERROR_STATUS;
+            let status: u32 = source.read(register);
+            source.write(register, status);
+            let status: advanced_error_reporting::CorrectableError = status.into();
+            com2_println!(
+                "PCI {} {} {} reports correctable errors {:?}.",
+                source,
+                vendor,
+                device,
+                status.names()
+            );
+        }
+        None => com2_println!(
+            "PCI {} {} {} reports a correctable error.",
+            source,
+            vendor,
+            device
+        ),
+    }
+    clear_device_status(source);
+}
+
+fn report_uncorrectable(source: FunctionAddress, fatal: bool) {
+    let (vendor, device): (lspci::Vendor, lspci::Device) = lspci::name(source);
+    let severity: &str = if fatal { "fatal" } else { "non-fatal" };
+    match source.extended_capability(extended_capability::Id::AdvancedErrorReporting) {
+        Some(advanced_error_reporting) => {
+            let register: u16 = advanced_error_reporting + UNCORRECTABLE_ERROR_STATUS;
+            let status: u32 = source.read(register);
+            let severities: u32 =
+                source.read(advanced_error_reporting + UNCORRECTABLE_ERROR_SEVERITY);
+            let header_log: Vec<u32> = (0..4)
+                .map(|index| source.read(advanced_error_reporting + HEADER_LOG + 4 * index))
+                .collect();
+            source.write(register, status);
+            let fatal_errors: advanced_error_reporting::UncorrectableError =
+                (status & severities).into();
+            let non_fatal_errors: advanced_error_reporting::UncorrectableError =
+                (status & !severities).into();
+            com2_println!(
+                "PCI {} {} {} reports a {} error. fatal = {:?}, non-fatal = {:?}, header log = {:08x?}",
+                source,
+                vendor,
+                device,
+                severity,
+                fatal_errors.names(),
+                non_fatal_errors.names(),
+                header_log
+            );
+        }
+        None => com2_println!(
+            "PCI {} {} {} reports a {} error.",
+            source,
+            vendor,
+            device,
+            severity
+        ),
+    }
+    clear_device_status(source);
+}
+
+/// # Enable or disable sending error messages in the PCI Express Device Control register
+fn set_error_reporting(function: FunctionAddress, enable: bool) {
+    if let Some(pci_express) = function.capability(capability::Id::PciExpress) {
+        let register: u16 = pci_express + DEVICE_CONTROL_AND_STATUS;
+        let device_control: device::control::Register = (function.read(register) as u16).into();
+        function.write(
+            register,
+            u16::from(device_control.with_error_reporting(enable)) as u32,
+        );
+    }
+}
+
+/// # Write a Message Control register keeping the capability header
+fn write_message_control(
+    function: FunctionAddress,
+    capability: u16,
+    message_control_and_header: u32,
+    message_control: u16,
+) {
+    function.write(
+        capability,
+        (message_control_and_header & 0xffff) | ((message_control as u32) << u16::BITS),
+    );
+}
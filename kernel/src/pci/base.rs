@@ -15,6 +15,13 @@ impl Index2Address {
         let Self(index2address) = self;
         index2address.get(&index)
     }
+
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &Address)> {
+        let Self(index2address) = self;
+        index2address
+            .iter()
+            .map(|(index, address)| (*index, address))
+    }
 }
 
 impl From<&[u32]> for Index2Address {
@@ -154,6 +161,32 @@ impl Address {
         read
     }
 
+    /// # Write a value
+    /// A memory is written at once because some registers like an MSI-X table don't accept byte accesses.
+    pub fn write<T>(&self, value: T) {
+        match self {
+            Self::Io { address } => {
+                let reader: *const T = &value as *const T;
+                let reader: *const u8 = reader as *const u8;
+                let size: usize = mem::size_of::<T>();
+                let reader: &[u8] = unsafe { slice::from_raw_parts(reader, size) };
+                reader.iter().enumerate().for_each(|(index, byte)| {
+                    let address: u16 = *address as u16;
+                    let index: u16 = index as u16;
+                    x64::port::outb(address + index, *byte);
+                });
+            }
+            Self::Memory {
+                address,
+                prefetchable: _,
+            } => {
+                let address: usize = *address as usize;
+                let address: *mut T = address as *mut T;
+                unsafe { address.write_volatile(value) };
+            }
+        }
+    }
+
     pub fn read_vector<T>(&self, length: usize) -> Vec<T>
     where
         T: Default,
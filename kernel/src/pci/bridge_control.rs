@@ -20,3 +20,13 @@ pub struct Register {
     #[bits(4)]
     __: u8,
 }
+
+impl Register {
+    pub fn assert_secondary_bus_reset(self) -> Self {
+        self.with_secondary_bus_reset(true)
+    }
+
+    pub fn deassert_secondary_bus_reset(self) -> Self {
+        self.with_secondary_bus_reset(false)
+    }
+}
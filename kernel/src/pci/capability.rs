@@ -70,15 +70,8 @@ impl Headers<'_> {
     }
 
     fn next_header(&self) -> Option<&Header> {
-        self.next_pointer().map(|next_pointer| {
-            let function: &Function = self.function_with_address.function();
-            let function: *const Function = function as *const Function;
-            let function: usize = function as usize;
-            let next_pointer: usize = next_pointer as usize;
-            let next_header: usize = function + next_pointer;
-            let next_header: *const Header = next_header as *const Header;
-            unsafe { &*next_header }
-        })
+        self.next_pointer()
+            .map(|next_pointer| header(self.function_with_address.function(), next_pointer))
     }
 }
 
@@ -124,7 +117,7 @@ impl<'a> Iterator for Headers<'a> {
 /// # Capability IDs
 /// ## References
 /// * [PCI Local Bus Specification Revision 3.0](https://lekensteyn.nl/files/docs/PCI_SPEV_V3_0.pdf) H. Capability IDs
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum Id {
     Reserved(u8),
     PciPowerManagementInterface,
@@ -171,6 +164,33 @@ impl From<u8> for Id {
     }
 }
 
+impl fmt::Display for Id {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Reserved(id) => write!(formatter, "Capability ID {:#04x}", id),
+            Self::PciPowerManagementInterface => write!(formatter, "Power Management"),
+            Self::Agp => write!(formatter, "AGP"),
+            Self::Vpd => write!(formatter, "Vital Product Data"),
+            Self::SlotIdentification => write!(formatter, "Slot ID"),
+            Self::Msi => write!(formatter, "MSI"),
+            Self::CompactPciHotSwap => write!(formatter, "CompactPCI Hot Swap"),
+            Self::PciX => write!(formatter, "PCI-X"),
+            Self::HyperTransport => write!(formatter, "HyperTransport"),
+            Self::VendorSpecific => write!(formatter, "Vendor Specific Information"),
+            Self::DebugPort => write!(formatter, "Debug Port"),
+            Self::CompactPciCentralResourceControl => {
+                write!(formatter, "CompactPCI Central Resource Control")
+            }
+            Self::PciHotPlug => write!(formatter, "PCI Hot-Plug"),
+            Self::PciBridgeSubsystemVendor => write!(formatter, "Subsystem"),
+            Self::Agp8x => write!(formatter, "AGP 8x"),
+            Self::SecureDevice => write!(formatter, "Secure Device"),
+            Self::PciExpress => write!(formatter, "Express"),
+            Self::MsiX => write!(formatter, "MSI-X"),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub enum Structure<'a> {
     Agp(&'a agp::Space),
@@ -181,21 +201,19 @@ pub enum Structure<'a> {
     PciPowerManagementInterface(&'a pci_power_management::Registers),
     PciX(&'a pci_x::Item),
     Reserved(u8),
+    /// A capability which this module does not decode.
+    Other(Id),
     SlotIdentification(&'a slot_identification::Register),
     VendorSpecific(vendor_specific::StructureInFunction<'a>),
     Vpd(vpd::StructureWithFunctionWithAddress<'a>),
 }
 
 impl<'a> Structure<'a> {
-    fn new(function_with_address: &'a FunctionWithAddress<'a>, next_pointer: u8) -> Self {
+    pub fn new(function_with_address: &'a FunctionWithAddress<'a>, next_pointer: u8) -> Self {
         let function: &Function = function_with_address.function();
-        let function_address: *const Function = function as *const Function;
-        let function_address: usize = function_address as usize;
-        let next_pointer_usize: usize = next_pointer as usize;
-        let structure: usize = function_address + next_pointer_usize;
-        let header: usize = structure;
-        let header: *const Header = header as *const Header;
-        let header: &Header = unsafe { &*header };
+        let header: &Header = header(function, next_pointer);
+        let structure: *const Header = header as *const Header;
+        let structure: usize = structure as usize;
         match header.capability_id().into() {
             Id::Agp => {
                 let space: *const agp::Space = structure as *const agp::Space;
@@ -246,7 +264,17 @@ impl<'a> Structure<'a> {
                 function_with_address,
                 next_pointer,
             )),
-            id => unimplemented!("id = {:#x?}", id),
+            id => Self::Other(id),
         }
     }
 }
+
+/// # The capability header at a pointer in the configuration space
+pub fn header(function: &Function, pointer: u8) -> &Header {
+    let function: *const Function = function as *const Function;
+    let function: usize = function as usize;
+    let pointer: usize = pointer as usize;
+    let header: usize = function + pointer;
+    let header: *const Header = header as *const Header;
+    unsafe { &*header }
+}
@@ -16,6 +16,52 @@ pub struct Structure {
     pending_bits: u32,
 }
 
+impl Structure {
+    pub fn message_control(&self) -> message::Control {
+        self.message_control
+    }
+}
+
+/// # The message and the mask like `lspci -vv`
+/// A function without 64-bit addresses has the message data right after the lower 32 bits of the address.
+impl fmt::Display for Structure {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message_control: message::Control = self.message_control;
+        let message_address: u64 = self.message_address;
+        let message_data: u16 = self.message_data;
+        let reserved: u16 = self.__;
+        let mask_bits: u32 = self.mask_bits;
+        let pending_bits: u32 = self.pending_bits;
+        let (mask_bits, pending_bits): (u32, u32) = if message_control.is_64bit() {
+            writeln!(
+                formatter,
+                "Address: {:016x}  Data: {:04x}",
+                message_address, message_data
+            )?;
+            (mask_bits, pending_bits)
+        } else {
+            writeln!(
+                formatter,
+                "Address: {:08x}  Data: {:04x}",
+                message_address as u32,
+                (message_address >> u32::BITS) as u16
+            )?;
+            (
+                (message_data as u32) | ((reserved as u32) << u16::BITS),
+                mask_bits,
+            )
+        };
+        if message_control.is_maskable() {
+            writeln!(
+                formatter,
+                "Masking: {:08x}  Pending: {:08x}",
+                mask_bits, pending_bits
+            )?;
+        }
+        Ok(())
+    }
+}
+
 impl fmt::Debug for Structure {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
         let header: Header = self.header.clone();
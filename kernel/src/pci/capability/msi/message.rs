@@ -15,3 +15,47 @@ pub struct Control {
     #[bits(7)]
     __: u8,
 }
+
+impl Control {
+    pub fn is_64bit(&self) -> bool {
+        self.bit64_address_capable()
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.msi_enable()
+    }
+
+    pub fn is_maskable(&self) -> bool {
+        self.per_vector_masking_capable()
+    }
+
+    /// # The number of vectors the function requests
+    pub fn capable_vectors(&self) -> usize {
+        1 << self.multiple_message_capable()
+    }
+
+    /// # The number of vectors the software allocated
+    pub fn enabled_vectors(&self) -> usize {
+        1 << self.multiple_message_enable()
+    }
+
+    /// # Enable or disable MSI with a single vector
+    pub fn with_enabled(self, enable: bool) -> Self {
+        self.with_msi_enable(enable).with_multiple_message_enable(0)
+    }
+}
+
+/// # Message Address for a fixed interrupt to a local APIC
+/// ## References
+/// * [Intel 64 and IA-32 Architectures Software Developer's Manual December 2023](https://www.intel.com/content/www/us/en/developer/articles/technical/intel-sdm.html) Vol.3A 11.11.1 Message Address Register Format
+pub fn address(local_apic_id: u8) -> u64 {
+    let local_apic_id: u64 = local_apic_id as u64;
+    0xfee00000 | (local_apic_id << 12)
+}
+
+/// # Message Data for an edge triggered fixed interrupt
+/// ## References
+/// * [Intel 64 and IA-32 Architectures Software Developer's Manual December 2023](https://www.intel.com/content/www/us/en/developer/articles/technical/intel-sdm.html) Vol.3A 11.11.2 Message Data Register Format
+pub fn data(interrupt_number: u8) -> u32 {
+    interrupt_number as u32
+}
@@ -23,6 +23,10 @@ pub struct Structure {
 }
 
 impl Structure {
+    pub fn message_control(&self) -> MessageControl {
+        self.message_control
+    }
+
     pub fn read_pba(&self, function: &Function) -> Vec<pba::PendingBits> {
         let pba: pba::Register = self.pba;
         let index2address: base::Index2Address = function.header().index2address();
@@ -37,6 +41,12 @@ impl Structure {
         table.read(&index2address, table_length)
     }
 
+    pub fn write_table(&self, function: &Function, index: usize, entry: table::Entry) {
+        let table: table::Register = self.table;
+        let index2address: base::Index2Address = function.header().index2address();
+        table.write(&index2address, index, entry)
+    }
+
     pub fn table_length(&self) -> usize {
         let message_control: MessageControl = self.message_control;
         let table_size: usize = message_control.table_size() as usize;
@@ -44,6 +54,16 @@ impl Structure {
     }
 }
 
+/// # The locations of the table and the PBA like `lspci -vv`
+impl fmt::Display for Structure {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let table: table::Register = self.table;
+        let pba: pba::Register = self.pba;
+        writeln!(formatter, "Vector table: {}", table)?;
+        writeln!(formatter, "PBA: {}", pba)
+    }
+}
+
 impl fmt::Debug for Structure {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
         let header: Header = self.header.clone();
@@ -73,7 +93,7 @@ impl<'a> StructureInFunction<'a> {
         }
     }
 
-    fn structure(&'a self) -> &'a Structure {
+    pub fn structure(&'a self) -> &'a Structure {
         let Self {
             function,
             structure_offset,
@@ -122,3 +142,18 @@ pub struct MessageControl {
     function_mask: bool,
     msi_x_enable: bool,
 }
+
+impl MessageControl {
+    pub fn is_enabled(&self) -> bool {
+        self.msi_x_enable()
+    }
+
+    pub fn is_masked(&self) -> bool {
+        self.function_mask()
+    }
+
+    /// # Enable or disable MSI-X without masking the function
+    pub fn with_enabled(self, enable: bool) -> Self {
+        self.with_msi_x_enable(enable).with_function_mask(false)
+    }
+}
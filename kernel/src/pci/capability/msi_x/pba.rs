@@ -1,4 +1,9 @@
-use {super::super::super::base, alloc::vec::Vec, bitfield_struct::bitfield, core::mem};
+use {
+    super::super::super::base,
+    alloc::vec::Vec,
+    bitfield_struct::bitfield,
+    core::{fmt, mem},
+};
 
 /// # PBA Offset/PBA BIR for MSI-X
 /// ## References
@@ -30,6 +35,18 @@ impl Register {
     }
 }
 
+/// # The BAR and the offset like `lspci -vv`
+impl fmt::Display for Register {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "BAR={} offset={:08x}",
+            self.bir(),
+            self.offset() << Self::OFFSET_OFFSET
+        )
+    }
+}
+
 /// # Pending Bits
 /// ## References
 /// * [PCI Local Bus Specification Revision 3.0](https://lekensteyn.nl/files/docs/PCI_SPEV_V3_0.pdf) 6.8.2.10. Pending Bits for MSI-X PBA-Entries
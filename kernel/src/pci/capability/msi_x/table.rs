@@ -1,4 +1,9 @@
-use {super::super::super::base, alloc::vec::Vec, bitfield_struct::bitfield};
+use {
+    super::super::super::base,
+    alloc::vec::Vec,
+    bitfield_struct::bitfield,
+    core::{fmt, mem},
+};
 
 /// # Table Offset/Table BIR for MSI-X
 /// ## References
@@ -22,6 +27,31 @@ impl Register {
             .offset(offset)
             .read_vector(table_length)
     }
+
+    /// # Write an entry of the table
+    pub fn write(&self, index2address: &base::Index2Address, index: usize, entry: Entry) {
+        let bir: usize = self.bir() as usize;
+        let offset: u32 = self.offset() << Self::OFFSET_OFFSET;
+        let offset: usize = offset as usize + index * mem::size_of::<Entry>();
+        let entry: [u32; 4] = entry.into();
+        let address: base::Address = index2address.get(bir).unwrap().offset(offset);
+        entry
+            .into_iter()
+            .enumerate()
+            .for_each(|(index, dword)| address.offset(index * mem::size_of::<u32>()).write(dword));
+    }
+}
+
+/// # The BAR and the offset like `lspci -vv`
+impl fmt::Display for Register {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "BAR={} offset={:08x}",
+            self.bir(),
+            self.offset() << Self::OFFSET_OFFSET
+        )
+    }
 }
 
 /// # Table Entry
@@ -38,6 +68,33 @@ pub struct Entry {
     vector_control: VectorControl,
 }
 
+impl Entry {
+    /// # An unmasked entry
+    pub fn new(msg_addr: u64, msg_data: u32) -> Self {
+        let vector_control = VectorControl::new();
+        Self {
+            msg_addr,
+            msg_data,
+            vector_control,
+        }
+    }
+}
+
+/// The vector control is written at last so that the entry is unmasked after the message is written.
+impl From<Entry> for [u32; 4] {
+    fn from(entry: Entry) -> Self {
+        let msg_addr: u64 = entry.msg_addr;
+        let msg_data: u32 = entry.msg_data;
+        let vector_control: VectorControl = entry.vector_control;
+        [
+            msg_addr as u32,
+            (msg_addr >> u32::BITS) as u32,
+            msg_data,
+            vector_control.into(),
+        ]
+    }
+}
+
 /// # Vector Control
 /// ## References
 /// * [PCI Local Bus Specification Revision 3.0](https://lekensteyn.nl/files/docs/PCI_SPEV_V3_0.pdf) 6.8.2.9. Vector Control for MSI-X Table Entries
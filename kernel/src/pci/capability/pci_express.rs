@@ -33,6 +33,34 @@ pub struct Structure {
     link_status_2: link::status_2::Register,
 }
 
+impl Structure {
+    pub fn capability(&self) -> capability::Register {
+        self.capability
+    }
+}
+
+/// # The device and link registers like `lspci -vv`
+impl fmt::Display for Structure {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let capability: capability::Register = self.capability;
+        let device_capabilities: device::capabilities::Register = self.device_capabilities;
+        let device_control: device::control::Register = self.device_control;
+        let device_status: device::status::Register = self.device_status;
+        writeln!(formatter, "{}", device_capabilities)?;
+        writeln!(formatter, "{}", device_control)?;
+        writeln!(formatter, "{}", device_status)?;
+        if capability.has_link() {
+            let link_capabilities: link::capabilities::Register = self.link_capabilities;
+            let link_control: link::control::Register = self.link_control;
+            let link_status: link::status::Register = self.link_status;
+            writeln!(formatter, "{}", link_capabilities)?;
+            writeln!(formatter, "{}", link_control)?;
+            writeln!(formatter, "{}", link_status)?;
+        }
+        Ok(())
+    }
+}
+
 impl fmt::Debug for Structure {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
         let header: Header = self.header.clone();
@@ -1,4 +1,4 @@
-use bitfield_struct::bitfield;
+use {super::super::super::lspci::Flag, bitfield_struct::bitfield, core::fmt};
 
 /// # PCI Express Capability Register - 0x080
 /// ## Referneces
@@ -16,3 +16,58 @@ pub struct Register {
     #[bits(2)]
     __: u8,
 }
+
+impl Register {
+    /// Device/Port Type of a Root Port of PCI Express Root Complex
+    const ROOT_PORT: u8 = 0b0100;
+    /// Device/Port Type of a PCI Express to PCI/PCI-X Bridge
+    const PCI_EXPRESS_TO_PCI_BRIDGE: u8 = 0b0111;
+    /// Device/Port Type of a PCI/PCI-X to PCI Express Bridge
+    const PCI_TO_PCI_EXPRESS_BRIDGE: u8 = 0b1000;
+
+    pub fn is_root_port(&self) -> bool {
+        self.device_type() == Self::ROOT_PORT
+    }
+
+    pub fn is_pci_express_to_pci_bridge(&self) -> bool {
+        self.device_type() == Self::PCI_EXPRESS_TO_PCI_BRIDGE
+    }
+
+    pub fn is_pci_to_pci_express_bridge(&self) -> bool {
+        self.device_type() == Self::PCI_TO_PCI_EXPRESS_BRIDGE
+    }
+
+    /// # Whether the function has a link
+    /// Root complex integrated endpoints and event collectors have no link.
+    pub fn has_link(&self) -> bool {
+        !matches!(self.device_type(), 0b1001 | 0b1010)
+    }
+}
+
+/// # The version, the type and the interrupt message number like `lspci -vv`
+impl fmt::Display for Register {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "(v{}) ", self.capability_version())?;
+        match self.device_type() {
+            0b0000 => write!(formatter, "Endpoint"),
+            0b0001 => write!(formatter, "Legacy Endpoint"),
+            Self::ROOT_PORT => write!(
+                formatter,
+                "Root Port (Slot{})",
+                Flag(self.slot_implemented())
+            ),
+            0b0101 => write!(formatter, "Upstream Port"),
+            0b0110 => write!(
+                formatter,
+                "Downstream Port (Slot{})",
+                Flag(self.slot_implemented())
+            ),
+            Self::PCI_EXPRESS_TO_PCI_BRIDGE => write!(formatter, "PCI-Express to PCI/PCI-X Bridge"),
+            Self::PCI_TO_PCI_EXPRESS_BRIDGE => write!(formatter, "PCI/PCI-X to PCI-Express Bridge"),
+            0b1001 => write!(formatter, "Root Complex Integrated Endpoint"),
+            0b1010 => write!(formatter, "Root Complex Event Collector"),
+            device_type => write!(formatter, "Unknown type {}", device_type),
+        }?;
+        write!(formatter, ", MSI {:02x}", self.interrupt_message_number())
+    }
+}
@@ -1,4 +1,4 @@
-use bitfield_struct::bitfield;
+use {super::super::super::super::lspci::Flag, bitfield_struct::bitfield, core::fmt};
 
 /// # PCI Express Device Capabilities Register - 0x084
 /// ## Referneces
@@ -27,3 +27,53 @@ pub struct Register {
     #[bits(3)]
     __: u8,
 }
+
+impl Register {
+    /// Acceptable L0s latency for each encoding
+    const L0S_LATENCIES: [&'static str; 8] = [
+        "<64ns",
+        "<128ns",
+        "<256ns",
+        "<512ns",
+        "<1us",
+        "<2us",
+        "<4us",
+        "unlimited",
+    ];
+    /// Acceptable L1 latency for each encoding
+    const L1_LATENCIES: [&'static str; 8] = [
+        "<1us",
+        "<2us",
+        "<4us",
+        "<8us",
+        "<16us",
+        "<32us",
+        "<64us",
+        "unlimited",
+    ];
+
+    pub fn is_flr_capable(&self) -> bool {
+        self.flr_capable()
+    }
+}
+
+/// # The capabilities like `lspci -vv`
+impl fmt::Display for Register {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            formatter,
+            "DevCap:\tMaxPayload {} bytes, PhantFunc {}, Latency L0s {}, L1 {}",
+            128 << self.maximum_payload_size_supported_by_the_function(),
+            self.phantom_functions_supported(),
+            Self::L0S_LATENCIES[self.acceptable_l0s_latency() as usize],
+            Self::L1_LATENCIES[self.acceptable_l1_latency() as usize]
+        )?;
+        write!(
+            formatter,
+            "\tExtTag{} RBE{} FLReset{}",
+            Flag(self.extended_tag_supported()),
+            Flag(self.role_based_error_reporting_supported()),
+            Flag(self.flr_capable())
+        )
+    }
+}
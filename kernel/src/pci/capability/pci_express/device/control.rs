@@ -1,4 +1,4 @@
-use bitfield_struct::bitfield;
+use {super::super::super::super::lspci::Flag, bitfield_struct::bitfield, core::fmt};
 
 /// # PCI Express Device Control and Status Register - 0x088
 /// ## Referneces
@@ -21,3 +21,48 @@ pub struct Register {
     maximum_read_request_size: u8,
     function_level_reset_or_bridge_config_retry_enable: bool,
 }
+
+impl Register {
+    /// # Enable or disable sending correctable, non-fatal, fatal and unsupported request error messages
+    pub fn with_error_reporting(self, enable: bool) -> Self {
+        self.with_enable_correctable_error_reporting(enable)
+            .with_enable_non_fatal_error_reporting(enable)
+            .with_enable_fatal_error_reporting(enable)
+            .with_enable_unsupported_request_reporting(enable)
+    }
+
+    /// # Initiate Function Level Reset
+    /// The bit means Bridge Configuration Retry Enable on a bridge.
+    pub fn initiate_function_level_reset(self) -> Self {
+        self.with_function_level_reset_or_bridge_config_retry_enable(true)
+    }
+}
+
+/// # The control like `lspci -vv`
+impl fmt::Display for Register {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            formatter,
+            "DevCtl:\tCorrErr{} NonFatalErr{} FatalErr{} UnsupReq{}",
+            Flag(self.enable_correctable_error_reporting()),
+            Flag(self.enable_non_fatal_error_reporting()),
+            Flag(self.enable_fatal_error_reporting()),
+            Flag(self.enable_unsupported_request_reporting())
+        )?;
+        writeln!(
+            formatter,
+            "\tRlxdOrd{} ExtTag{} PhantFunc{} AuxPwr{} NoSnoop{}",
+            Flag(self.enable_relaxed_ordering()),
+            Flag(self.extended_tag_field_enable()),
+            Flag(self.phantom_functions_enable()),
+            Flag(self.aux_power_enable()),
+            Flag(self.enable_no_snoop())
+        )?;
+        write!(
+            formatter,
+            "\tMaxPayload {} bytes, MaxReadReq {} bytes",
+            128 << self.maximum_payload_size(),
+            128 << self.maximum_read_request_size()
+        )
+    }
+}
@@ -1,4 +1,4 @@
-use bitfield_struct::bitfield;
+use {super::super::super::super::lspci::Flag, bitfield_struct::bitfield, core::fmt};
 
 /// # PCI Express Device Control and Status Register - 0x088
 /// ## Referneces
@@ -15,3 +15,25 @@ pub struct Register {
     #[bits(10)]
     __: u16,
 }
+
+impl Register {
+    pub fn is_transaction_pending(&self) -> bool {
+        self.transaction_pending()
+    }
+}
+
+/// # The status like `lspci -vv`
+impl fmt::Display for Register {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "DevSta:\tCorrErr{} NonFatalErr{} FatalErr{} UnsupReq{} AuxPwr{} TransPend{}",
+            Flag(self.correctable_error_detected()),
+            Flag(self.non_fatal_error_detected()),
+            Flag(self.fatal_error_detected()),
+            Flag(self.unsupported_request_detected()),
+            Flag(self.aux_power_detected()),
+            Flag(self.transaction_pending())
+        )
+    }
+}
@@ -4,3 +4,23 @@ pub mod control;
 pub mod control_2;
 pub mod status;
 pub mod status_2;
+
+use core::fmt;
+
+/// # A link speed like `lspci -vv`
+pub struct Speed(pub u8);
+
+impl fmt::Display for Speed {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self(speed) = *self;
+        match speed {
+            1 => write!(formatter, "2.5GT/s"),
+            2 => write!(formatter, "5GT/s"),
+            3 => write!(formatter, "8GT/s"),
+            4 => write!(formatter, "16GT/s"),
+            5 => write!(formatter, "32GT/s"),
+            6 => write!(formatter, "64GT/s"),
+            _ => write!(formatter, "unknown"),
+        }
+    }
+}
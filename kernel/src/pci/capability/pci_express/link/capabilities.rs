@@ -1,4 +1,8 @@
-use bitfield_struct::bitfield;
+use {
+    super::{super::super::super::lspci::Flag, Speed},
+    bitfield_struct::bitfield,
+    core::fmt,
+};
 
 /// # Link Capabilities Register - 0x08C
 /// ## References
@@ -24,3 +28,47 @@ pub struct Register {
     __: bool,
     port_number: u8,
 }
+
+impl Register {
+    /// ASPM support for each encoding
+    const ASPM_SUPPORTS: [&'static str; 4] = ["not supported", "L0s", "L1", "L0s L1"];
+    /// L0s exit latency for each encoding
+    const L0S_EXIT_LATENCIES: [&'static str; 8] = [
+        "<64ns", "<128ns", "<256ns", "<512ns", "<1us", "<2us", "<4us", ">4us",
+    ];
+    /// L1 exit latency for each encoding
+    const L1_EXIT_LATENCIES: [&'static str; 8] = [
+        "<1us", "<2us", "<4us", "<8us", "<16us", "<32us", "<64us", ">64us",
+    ];
+}
+
+/// # The capabilities like `lspci -vv`
+impl fmt::Display for Register {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let aspm_support: usize = (self.aspm_support_for_l0s_state() as usize)
+            | ((self.aspm_support_for_l1_state() as usize) << 1);
+        writeln!(
+            formatter,
+            "LnkCap:\tPort #{}, Speed {}, Width x{}, ASPM {}",
+            self.port_number(),
+            Speed(self.maximum_link_speed()),
+            self.maximum_link_width(),
+            Self::ASPM_SUPPORTS[aspm_support]
+        )?;
+        writeln!(
+            formatter,
+            "\tExit Latency L0s {}, L1 {}",
+            Self::L0S_EXIT_LATENCIES[self.l0s_exit_latency() as usize],
+            Self::L1_EXIT_LATENCIES[self.l1_exit_latency() as usize]
+        )?;
+        write!(
+            formatter,
+            "\tClockPM{} Surprise{} LLActRep{} BwNot{} ASPMOptComp{}",
+            Flag(self.clock_power_management()),
+            Flag(self.surprise_down_error_reporting_capable()),
+            Flag(self.data_link_layer_active_reporting_capable()),
+            Flag(self.link_bandwidth_notification_capability()),
+            Flag(self.aspm_optionally_compliance())
+        )
+    }
+}
@@ -1,4 +1,4 @@
-use bitfield_struct::bitfield;
+use {super::super::super::super::lspci::Flag, bitfield_struct::bitfield, core::fmt};
 
 /// # Link Control and Status Register - 0x090
 /// ## References
@@ -18,3 +18,33 @@ pub struct Register {
     #[bits(7)]
     __: u8,
 }
+
+impl Register {
+    /// ASPM control for each encoding
+    const ASPM_CONTROLS: [&'static str; 4] =
+        ["Disabled", "L0s Enabled", "L1 Enabled", "L0s L1 Enabled"];
+}
+
+/// # The control like `lspci -vv`
+impl fmt::Display for Register {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            formatter,
+            "LnkCtl:\tASPM {}; RCB {} bytes, Disabled{} CommClk{}",
+            Self::ASPM_CONTROLS[self.aspm_control() as usize],
+            if self.read_completion_boundary() {
+                128
+            } else {
+                64
+            },
+            Flag(self.link_disable()),
+            Flag(self.common_clock_configuration())
+        )?;
+        write!(
+            formatter,
+            "\tExtSynch{} ClockPM{}",
+            Flag(self.extended_synch()),
+            Flag(self.enable_clock_power_management())
+        )
+    }
+}
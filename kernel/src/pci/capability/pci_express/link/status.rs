@@ -1,4 +1,8 @@
-use bitfield_struct::bitfield;
+use {
+    super::{super::super::super::lspci::Flag, Speed},
+    bitfield_struct::bitfield,
+    core::fmt,
+};
 
 /// # Link Control and Status Register - 0x090
 /// ## References
@@ -17,3 +21,22 @@ pub struct Register {
     #[bits(2)]
     __: u8,
 }
+
+/// # The status like `lspci -vv`
+impl fmt::Display for Register {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            formatter,
+            "LnkSta:\tSpeed {}, Width x{}",
+            Speed(self.negotiated_link_speed()),
+            self.negotiated_link_width()
+        )?;
+        write!(
+            formatter,
+            "\tTrain{} SlotClk{} DLActive{}",
+            Flag(self.link_training()),
+            Flag(self.slot_clock_configuration()),
+            Flag(self.data_link_layer_active())
+        )
+    }
+}
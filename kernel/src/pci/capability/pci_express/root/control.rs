@@ -13,3 +13,12 @@ pub struct Register {
     #[bits(11)]
     __: u16,
 }
+
+impl Register {
+    /// # Enable or disable the System Error on error messages
+    pub fn with_system_errors(self, enable: bool) -> Self {
+        self.with_correctable_serr_enable(enable)
+            .with_non_fatal_serr_enable(enable)
+            .with_fatal_serr_enable(enable)
+    }
+}
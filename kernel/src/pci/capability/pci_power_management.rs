@@ -16,6 +16,22 @@ pub struct Registers {
     data: u8,
 }
 
+impl Registers {
+    pub fn pmc(&self) -> pmc::Register {
+        self.pmc
+    }
+}
+
+/// # The capabilities and the status like `lspci -vv`
+impl fmt::Display for Registers {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let pmc: pmc::Register = self.pmc;
+        let pmcsr: pmcsr::Register = self.pmcsr;
+        writeln!(formatter, "{}", pmc)?;
+        writeln!(formatter, "{}", pmcsr)
+    }
+}
+
 impl fmt::Debug for Registers {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
         let header: Header = self.header.clone();
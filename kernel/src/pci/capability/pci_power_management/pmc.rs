@@ -1,4 +1,4 @@
-use bitfield_struct::bitfield;
+use {super::super::super::lspci::Flag, bitfield_struct::bitfield, core::fmt};
 
 /// # PMC - Power Management Capabilities (Offset = 2)
 /// ## References
@@ -17,3 +17,41 @@ pub struct Register {
     #[bits(5)]
     pme_support: u8,
 }
+
+impl Register {
+    /// Auxiliary current in mA for each encoding
+    const AUX_CURRENTS: [u16; 8] = [0, 55, 100, 160, 220, 270, 320, 375];
+
+    pub fn supports_d1(&self) -> bool {
+        self.d1_support()
+    }
+
+    pub fn supports_d2(&self) -> bool {
+        self.d2_support()
+    }
+
+    pub fn specification_version(&self) -> u8 {
+        self.version()
+    }
+}
+
+/// # The flags like `lspci -vv`
+impl fmt::Display for Register {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let pme_support: u8 = self.pme_support();
+        write!(
+            formatter,
+            "Flags: PMEClk{} DSI{} D1{} D2{} AuxCurrent={}mA PME(D0{},D1{},D2{},D3hot{},D3cold{})",
+            Flag(self.pme_clock()),
+            Flag(self.dsi()),
+            Flag(self.d1_support()),
+            Flag(self.d2_support()),
+            Self::AUX_CURRENTS[self.aux_current() as usize],
+            Flag(pme_support & 0x01 != 0),
+            Flag(pme_support & 0x02 != 0),
+            Flag(pme_support & 0x04 != 0),
+            Flag(pme_support & 0x08 != 0),
+            Flag(pme_support & 0x10 != 0)
+        )
+    }
+}
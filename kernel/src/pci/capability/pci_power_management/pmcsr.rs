@@ -1,4 +1,4 @@
-use bitfield_struct::bitfield;
+use {super::super::super::lspci::Flag, bitfield_struct::bitfield, core::fmt};
 
 /// # PMCSR - Power Management Control/Status (Offset = 4)
 /// ## References
@@ -18,3 +18,74 @@ pub struct Register {
     data_scale: u8,
     pme_status: bool,
 }
+
+impl Register {
+    /// # Whether the function keeps its configuration from D3hot to D0
+    pub fn is_no_soft_reset(&self) -> bool {
+        self.no_soft_reset()
+    }
+
+    pub fn state(&self) -> PowerState {
+        self.power_state().into()
+    }
+
+    /// # Request a power state
+    pub fn with_state(self, state: PowerState) -> Self {
+        self.with_power_state(state.into())
+    }
+
+    /// # Mask PME_Status out of a value to write back
+    /// Writing 1 to PME_Status clears the status.
+    pub fn without_pme_status(self) -> Self {
+        self.with_pme_status(false)
+    }
+}
+
+/// # The status like `lspci -vv`
+impl fmt::Display for Register {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "Status: D{} NoSoftRst{} PME-Enable{} DSel={} DScale={} PME{}",
+            self.power_state(),
+            Flag(self.no_soft_reset()),
+            Flag(self.pme_en()),
+            self.data_select(),
+            self.data_scale(),
+            Flag(self.pme_status())
+        )
+    }
+}
+
+/// # Power State
+/// ## References
+/// * [PCI Power Management Interface Specification Revision 1.2](https://lekensteyn.nl/files/docs/PCI_Power_Management_12.pdf) 5.4. PCI Function Power Management States
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum PowerState {
+    D0,
+    D1,
+    D2,
+    D3Hot,
+}
+
+impl From<u8> for PowerState {
+    fn from(power_state: u8) -> Self {
+        match power_state {
+            0 => Self::D0,
+            1 => Self::D1,
+            2 => Self::D2,
+            _ => Self::D3Hot,
+        }
+    }
+}
+
+impl From<PowerState> for u8 {
+    fn from(power_state: PowerState) -> Self {
+        match power_state {
+            PowerState::D0 => 0,
+            PowerState::D1 => 1,
+            PowerState::D2 => 2,
+            PowerState::D3Hot => 3,
+        }
+    }
+}
@@ -1,8 +1,5 @@
 use {
-    super::{
-        super::{Address, FunctionWithAddress},
-        Header,
-    },
+    super::{super::FunctionWithAddress, Header},
     crate::x64,
     core::{fmt, mem},
 };
@@ -78,24 +75,12 @@ impl<'a> StructureWithFunctionWithAddress<'a> {
             .resource_data_iterator()
     }
 
-    fn address_address(&self) -> Address {
-        let Self {
-            function_with_address,
-            structure_offset,
-        } = self;
-        let bus_number: u8 = function_with_address.bus_number();
-        let device_number: u8 = function_with_address.device_number();
-        let function_number: u8 = function_with_address.function_number();
-        Address::create(
-            bus_number,
-            device_number,
-            function_number,
-            *structure_offset,
-        )
+    fn address_register(&self) -> u16 {
+        self.structure_offset as u16
     }
 
-    fn data_address(&self) -> Address {
-        self.address_address().add(mem::size_of::<Structure>())
+    fn data_register(&self) -> u16 {
+        self.address_register() + (mem::size_of::<Structure>() as u16)
     }
 
     fn dword_iterator(&'a self) -> DwordIterator<'a> {
@@ -110,17 +95,24 @@ impl<'a> StructureWithFunctionWithAddress<'a> {
         while !self.read_structure().can_read_data() {
             x64::pause();
         }
-        self.data_address().read()
+        self.function_with_address
+            .address()
+            .read(self.data_register())
     }
 
     fn read_structure(&self) -> Structure {
-        let structure: u32 = self.address_address().read();
+        let structure: u32 = self
+            .function_with_address
+            .address()
+            .read(self.address_register());
         structure.into()
     }
 
     fn write_structure(&self, structure: Structure) {
         let structure: u32 = structure.into();
-        self.address_address().write(structure);
+        self.function_with_address
+            .address()
+            .write(self.address_register(), structure);
     }
 }
 
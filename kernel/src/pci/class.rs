@@ -1,3 +1,5 @@
+use core::fmt;
+
 /// # Class Code Register
 /// ## References
 /// * [PCI Express Base Specification Revision 5.0 Version 1.0](https://picture.iczhiku.com/resource/eetop/SYkDTqhOLhpUTnMx.pdf) 7.5.1.1.6 Class Code Register
@@ -9,6 +11,20 @@ pub struct Register {
     base_class: u8,
 }
 
+impl Register {
+    pub fn base_class(&self) -> u8 {
+        self.base_class
+    }
+
+    pub fn programming_interface(&self) -> u8 {
+        self.programming_interface
+    }
+
+    pub fn sub_class(&self) -> u8 {
+        self.sub_class
+    }
+}
+
 /// # Class Code
 /// ## References
 /// * [PCI Code and ID Assignment Specification Revision 1.11](https://pcisig.com/sites/default/files/files/PCI_Code-ID_r_1_11__v24_Jan_2019.pdf)
@@ -390,3 +406,254 @@ impl From<Register> for Code {
         }
     }
 }
+
+impl fmt::Display for Code {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AllCurrentlyImplemented => write!(formatter, "Non-VGA unclassified device"),
+            Self::VgaCompatibleDevice => write!(formatter, "VGA compatible unclassified device"),
+            Self::Scsi {
+                programming_interface,
+            } => write!(
+                formatter,
+                "SCSI storage controller (prog-if {:02x})",
+                programming_interface
+            ),
+            Self::Ide {
+                programming_interface,
+            } => write!(
+                formatter,
+                "IDE interface (prog-if {:02x})",
+                programming_interface
+            ),
+            Self::FloppyDisk => write!(formatter, "Floppy disk controller"),
+            Self::IpiBus => write!(formatter, "IPI bus controller"),
+            Self::Raid => write!(formatter, "RAID bus controller"),
+            Self::AtaSingleStepping => write!(formatter, "ATA controller (single stepping)"),
+            Self::AtaContinuousOperation => {
+                write!(formatter, "ATA controller (continuous operation)")
+            }
+            Self::SerialAtaVendorSpecific => write!(formatter, "SATA controller (vendor specific)"),
+            Self::SerialAtaAhci => write!(formatter, "SATA controller (AHCI)"),
+            Self::SerialStorage => write!(formatter, "SATA controller (serial storage bus)"),
+            Self::SerialAttachedScsi => write!(formatter, "Serial Attached SCSI controller"),
+            Self::Obsolete => write!(formatter, "Serial storage bus controller (obsolete)"),
+            Self::NoneVolatileMemorySubsystemVendorSpecific => write!(
+                formatter,
+                "Non-Volatile memory controller (vendor specific)"
+            ),
+            Self::NoneVolatileMemorySubsystemNvmHci => {
+                write!(formatter, "Non-Volatile memory controller (NVMHCI)")
+            }
+            Self::NvmExpressIo => write!(formatter, "Non-Volatile memory controller (NVM Express)"),
+            Self::NvmExpressAdministrative => write!(
+                formatter,
+                "Non-Volatile memory controller (NVM Express administrative)"
+            ),
+            Self::UniversalFlashStorageVendorSpecific => write!(
+                formatter,
+                "Universal Flash Storage controller (vendor specific)"
+            ),
+            Self::UniversalFlashStorageHostController => {
+                write!(formatter, "Universal Flash Storage controller (UFSHCI)")
+            }
+            Self::OtherMassStorage => write!(formatter, "Mass storage controller"),
+            Self::Ethernet => write!(formatter, "Ethernet controller"),
+            Self::TokenRing => write!(formatter, "Token ring network controller"),
+            Self::Fddi => write!(formatter, "FDDI network controller"),
+            Self::Atm => write!(formatter, "ATM network controller"),
+            Self::Isdn => write!(formatter, "ISDN controller"),
+            Self::WorldFip => write!(formatter, "WorldFip controller"),
+            Self::Picmg {
+                programming_interface,
+            } => write!(
+                formatter,
+                "PICMG controller (prog-if {:02x})",
+                programming_interface
+            ),
+            Self::InfiniBand => write!(formatter, "Infiniband controller"),
+            Self::HostFabric => write!(formatter, "Fabric controller"),
+            Self::OtherNetwork => write!(formatter, "Network controller"),
+            Self::VgaCompatibleController => write!(formatter, "VGA compatible controller"),
+            Self::Display8514Compatible => write!(formatter, "8514 compatible controller"),
+            Self::Xga => write!(formatter, "XGA compatible controller"),
+            Self::Display3D => write!(formatter, "3D controller"),
+            Self::OtherDisplay => write!(formatter, "Display controller"),
+            Self::Video => write!(formatter, "Multimedia video controller"),
+            Self::AudioDevice => write!(formatter, "Multimedia audio controller"),
+            Self::ComputerTelephony => write!(formatter, "Computer telephony device"),
+            Self::HighDefinitionAudio => write!(formatter, "Audio device (HD Audio)"),
+            Self::HighDefinitionAudioVendorSpecific => {
+                write!(formatter, "Audio device (HD Audio vendor specific)")
+            }
+            Self::OtherMultimedia => write!(formatter, "Multimedia controller"),
+            Self::Ram => write!(formatter, "RAM memory"),
+            Self::Flash => write!(formatter, "FLASH memory"),
+            Self::OtherMemory => write!(formatter, "Memory controller"),
+            Self::HostBridge => write!(formatter, "Host bridge"),
+            Self::IsaBridge => write!(formatter, "ISA bridge"),
+            Self::EisaBridge => write!(formatter, "EISA bridge"),
+            Self::McaBridge => write!(formatter, "MicroChannel bridge"),
+            Self::Pci2PciBridge => write!(formatter, "PCI bridge"),
+            Self::SubtractiveDecodePci2PciBridge => {
+                write!(formatter, "PCI bridge (subtractive decode)")
+            }
+            Self::PcmciaBridge => write!(formatter, "PCMCIA bridge"),
+            Self::NuBusBridge => write!(formatter, "NuBus bridge"),
+            Self::CardBusBridge => write!(formatter, "CardBus bridge"),
+            Self::RaceWayBridge {
+                programming_interface,
+            } => write!(
+                formatter,
+                "RACEway bridge (prog-if {:02x})",
+                programming_interface
+            ),
+            Self::SemiTransparentPci2PciBridgePrimary => {
+                write!(formatter, "Semi-transparent PCI-to-PCI bridge (primary)")
+            }
+            Self::SemiTransparentPci2PciBridgeSecondary => {
+                write!(formatter, "Semi-transparent PCI-to-PCI bridge (secondary)")
+            }
+            Self::InfiniBand2PciHostBridge => write!(formatter, "InfiniBand to PCI host bridge"),
+            Self::AdvancedSwitching2PciHostBridgeCustom => {
+                write!(formatter, "Advanced Switching to PCI host bridge (custom)")
+            }
+            Self::AdvancedSwitching2PciHostBridgeASISIG => {
+                write!(formatter, "Advanced Switching to PCI host bridge (ASI-SIG)")
+            }
+            Self::OtherBridge => write!(formatter, "Bridge"),
+            Self::SerialGenericXTCompatible => write!(formatter, "Serial controller (8250)"),
+            Self::Serial16450Compatible => write!(formatter, "Serial controller (16450)"),
+            Self::Serial16550Compatible => write!(formatter, "Serial controller (16550)"),
+            Self::Serial16650Compatible => write!(formatter, "Serial controller (16650)"),
+            Self::Serial16750Compatible => write!(formatter, "Serial controller (16750)"),
+            Self::Serial16850Compatible => write!(formatter, "Serial controller (16850)"),
+            Self::Serial16950Compatible => write!(formatter, "Serial controller (16950)"),
+            Self::ParallelPort => write!(formatter, "Parallel controller (SPP)"),
+            Self::BidirectionalParallelPort => write!(formatter, "Parallel controller (BiDir)"),
+            Self::Ecp1xCompliantParallelPort => write!(formatter, "Parallel controller (ECP)"),
+            Self::Ieee1284Controller => write!(formatter, "Parallel controller (IEEE1284)"),
+            Self::Ieee1284TargetDevice => {
+                write!(formatter, "Parallel controller (IEEE1284 target)")
+            }
+            Self::MultiportSerial => write!(formatter, "Multiport serial controller"),
+            Self::GenericModem => write!(formatter, "Modem"),
+            Self::HayesCompatibleModem16450Compatible => write!(formatter, "Modem (Hayes/16450)"),
+            Self::HayesCompatibleModem16550Compatible => write!(formatter, "Modem (Hayes/16550)"),
+            Self::HayesCompatibleModem16650Compatible => write!(formatter, "Modem (Hayes/16650)"),
+            Self::HayesCompatibleModem16750Compatible => write!(formatter, "Modem (Hayes/16750)"),
+            Self::Gpib => write!(formatter, "GPIB controller"),
+            Self::SmartCard => write!(formatter, "Smart Card controller"),
+            Self::OtherCommunication => write!(formatter, "Communication controller"),
+            Self::Generic8259Pic => write!(formatter, "PIC (8259)"),
+            Self::IsaPic => write!(formatter, "PIC (ISA PIC)"),
+            Self::EisaPic => write!(formatter, "PIC (EISA PIC)"),
+            Self::IoApicInterrupt => write!(formatter, "PIC (IO-APIC)"),
+            Self::IoxApicInterrupt => write!(formatter, "PIC (IO(X)-APIC)"),
+            Self::Generic8237Dma => write!(formatter, "DMA controller (8237)"),
+            Self::IsaDma => write!(formatter, "DMA controller (ISA DMA)"),
+            Self::EisaDma => write!(formatter, "DMA controller (EISA DMA)"),
+            Self::Generic8254SystemTimer => write!(formatter, "Timer (8254)"),
+            Self::IsaSystemTimer => write!(formatter, "Timer (ISA timer)"),
+            Self::EisaSystemTimer => write!(formatter, "Timer (EISA timers)"),
+            Self::HighPerformanceEventTimer => write!(formatter, "Timer (HPET)"),
+            Self::GenericRtc => write!(formatter, "RTC"),
+            Self::IsaRtc => write!(formatter, "RTC (ISA RTC)"),
+            Self::GenericPciHotPlug => write!(formatter, "PCI Hot-plug controller"),
+            Self::SdHost => write!(formatter, "SD Host controller"),
+            Self::Iommu => write!(formatter, "IOMMU"),
+            Self::RootComplexEvent => write!(formatter, "Root Complex Event Collector"),
+            Self::OtherSystemPeripheral => write!(formatter, "System peripheral"),
+            Self::Kerboard => write!(formatter, "Keyboard controller"),
+            Self::Digitizer => write!(formatter, "Digitizer Pen"),
+            Self::Mouse => write!(formatter, "Mouse controller"),
+            Self::Scanner => write!(formatter, "Scanner controller"),
+            Self::GenericGameport => write!(formatter, "Gameport controller"),
+            Self::Gameport => write!(formatter, "Gameport controller (extended)"),
+            Self::OtherInput => write!(formatter, "Input device controller"),
+            Self::GenericDockingStation => write!(formatter, "Generic Docking Station"),
+            Self::OtherDockingStation => write!(formatter, "Docking Station"),
+            Self::Processor386 => write!(formatter, "386"),
+            Self::Processor486 => write!(formatter, "486"),
+            Self::Pentium => write!(formatter, "Pentium"),
+            Self::Alpha => write!(formatter, "Alpha"),
+            Self::PowerPC => write!(formatter, "Power PC"),
+            Self::Mips => write!(formatter, "MIPS"),
+            Self::Coprocessor => write!(formatter, "Co-processor"),
+            Self::OtherProcessor => write!(formatter, "Processor"),
+            Self::Ieee1394 => write!(formatter, "FireWire (IEEE 1394)"),
+            Self::Ieee1394OpenHci => write!(formatter, "FireWire (IEEE 1394) (OHCI)"),
+            Self::AccessBus => write!(formatter, "ACCESS Bus"),
+            Self::Ssa => write!(formatter, "SSA"),
+            Self::UsbUhc => write!(formatter, "USB controller (UHCI)"),
+            Self::UsbOhc => write!(formatter, "USB controller (OHCI)"),
+            Self::UsbEhc => write!(formatter, "USB controller (EHCI)"),
+            Self::UsbXhc => write!(formatter, "USB controller (XHCI)"),
+            Self::UsbNoSpecificProgrammingInterface => {
+                write!(formatter, "USB controller (unspecified)")
+            }
+            Self::UsbNoHostController => write!(formatter, "USB device"),
+            Self::FibreChannel => write!(formatter, "Fibre Channel"),
+            Self::SmBus => write!(formatter, "SMBus"),
+            Self::InfiniBandDeprecated => write!(formatter, "InfiniBand"),
+            Self::IpmiSmic => write!(formatter, "IPMI Interface (SMIC)"),
+            Self::IpmiKeyboardControllerStyle => write!(formatter, "IPMI Interface (KCS)"),
+            Self::IpmiBlockTransfer => write!(formatter, "IPMI Interface (BT)"),
+            Self::Sercos => write!(formatter, "SERCOS interface"),
+            Self::CanBus => write!(formatter, "CANBUS"),
+            Self::MipiI3c => write!(formatter, "MIPI I3C"),
+            Self::OtherSerialBus => write!(formatter, "Serial bus controller"),
+            Self::Irda => write!(formatter, "IRDA controller"),
+            Self::ConsumerIR => write!(formatter, "Consumer IR controller"),
+            Self::UwbRadio => write!(formatter, "UWB radio controller"),
+            Self::Rf => write!(formatter, "RF controller"),
+            Self::Bluetooth => write!(formatter, "Bluetooth"),
+            Self::Broadband => write!(formatter, "Broadband"),
+            Self::Ethernet80211a => write!(formatter, "802.1a controller"),
+            Self::Ethernet20811b => write!(formatter, "802.1b controller"),
+            Self::Cellular => write!(formatter, "Cellular controller"),
+            Self::CellularPlusEthernet => write!(formatter, "Cellular controller with Ethernet"),
+            Self::OtherWireless => write!(formatter, "Wireless controller"),
+            Self::IntelligentIo {
+                programming_interface,
+            } => write!(formatter, "I2O (prog-if {:02x})", programming_interface),
+            Self::MessageFifo => write!(formatter, "I2O (message FIFO)"),
+            Self::Tv => write!(formatter, "Satellite TV controller"),
+            Self::Audio => write!(formatter, "Satellite audio communication controller"),
+            Self::Voice => write!(formatter, "Satellite voice communication controller"),
+            Self::Data => write!(formatter, "Satellite data communication controller"),
+            Self::OtherSatelliteCommunication => {
+                write!(formatter, "Satellite communications controller")
+            }
+            Self::NetworkAndComputingEncryptionAndDecryption => {
+                write!(formatter, "Network and computing encryption device")
+            }
+            Self::EntertainmentEncryptionAndDecryption => {
+                write!(formatter, "Entertainment encryption device")
+            }
+            Self::OtherEncryptionAndDecryption => write!(formatter, "Encryption controller"),
+            Self::Dpio => write!(formatter, "DPIO module"),
+            Self::PerformanceCounter => write!(formatter, "Performance counters"),
+            Self::CommunicationSynchronizationPlusTime => {
+                write!(formatter, "Communication synchronizer")
+            }
+            Self::ManagementCard => write!(formatter, "Signal processing management"),
+            Self::OtherDataAcquisitionAndSignalProcessing => {
+                write!(formatter, "Signal processing controller")
+            }
+            Self::ProcessingAccelerator => write!(formatter, "Processing accelerators"),
+            Self::NonEssentialInstrumentationFunction => {
+                write!(formatter, "Non-Essential Instrumentation")
+            }
+            Self::Other {
+                base_class,
+                sub_class,
+                programming_interface,
+            } => write!(
+                formatter,
+                "Class {:02x}{:02x} (prog-if {:02x})",
+                base_class, sub_class, programming_interface
+            ),
+        }
+    }
+}
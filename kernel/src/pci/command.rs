@@ -19,3 +19,37 @@ pub struct Register {
     #[bits(5)]
     __: u8,
 }
+
+impl Register {
+    pub fn decodes_io(&self) -> bool {
+        self.io_space_enable()
+    }
+
+    pub fn decodes_memory(&self) -> bool {
+        self.memory_space_enable()
+    }
+
+    pub fn is_bus_master(&self) -> bool {
+        self.bus_master_enable()
+    }
+
+    pub fn is_interrupt_disabled(&self) -> bool {
+        self.interrupt_disable()
+    }
+
+    /// # Allow or forbid the function to issue memory requests including MSI
+    pub fn with_bus_master(self, enable: bool) -> Self {
+        self.with_bus_master_enable(enable)
+    }
+
+    /// # Allow or forbid the function to assert INTx
+    pub fn with_interrupt(self, enable: bool) -> Self {
+        self.with_interrupt_disable(!enable)
+    }
+
+    /// # Enable or disable decoding of the I/O and memory spaces
+    pub fn with_decoding(self, io: bool, memory: bool) -> Self {
+        self.with_io_space_enable(io)
+            .with_memory_space_enable(memory)
+    }
+}
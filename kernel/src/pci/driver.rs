@@ -0,0 +1,181 @@
+//! # PCI Device Drivers
+//! Functions are bound to drivers whose match tables have an ID matching the function.
+//! A driver may decline a function in `probe`, and then the next matching driver is tried.
+//! Drivers are added to `DRIVERS` without changing the enumeration.
+
+mod aer;
+mod xhc;
+
+use {
+    super::{class, power, Configuration, FunctionAddress, FunctionWithAddress},
+    crate::{com2_println, sync::spin},
+    alloc::vec::Vec,
+};
+
+static BINDINGS: spin::Lock<Vec<Binding>> = spin::Lock::new(Vec::new());
+static DRIVERS: [&dyn Driver; 2] = [&aer::Aer, &xhc::Xhc];
+
+pub trait Driver: Sync {
+    /// # Match table
+    fn ids(&self) -> &[Id];
+    fn name(&self) -> &str;
+    /// # Start handling a function
+    /// Returns whether the driver handles the function.
+    fn probe(&self, function: &FunctionWithAddress) -> bool;
+    /// # Stop handling a function
+    fn remove(&self, function: &FunctionWithAddress);
+    /// # Restore a function after the system wakes
+    fn resume(&self, _function: &FunctionWithAddress) {}
+    /// # Quiesce a function before the system sleeps
+    fn suspend(&self, _function: &FunctionWithAddress) {}
+}
+
+/// # An entry of a match table
+/// A field of `None` matches any value.
+#[derive(Debug)]
+pub struct Id {
+    vendor_id: Option<u16>,
+    device_id: Option<u16>,
+    subsystem_vendor_id: Option<u16>,
+    subsystem_id: Option<u16>,
+    class_code: Option<class::Code>,
+}
+
+impl Id {
+    /// # Match a class code
+    pub const fn class(class_code: class::Code) -> Self {
+        Self {
+            vendor_id: None,
+            device_id: None,
+            subsystem_vendor_id: None,
+            subsystem_id: None,
+            class_code: Some(class_code),
+        }
+    }
+
+    /// # Match a vendor ID and a device ID
+    #[allow(dead_code)]
+    pub const fn device(vendor_id: u16, device_id: u16) -> Self {
+        Self {
+            vendor_id: Some(vendor_id),
+            device_id: Some(device_id),
+            subsystem_vendor_id: None,
+            subsystem_id: None,
+            class_code: None,
+        }
+    }
+
+    /// # Match a subsystem vendor ID and a subsystem ID in addition
+    #[allow(dead_code)]
+    pub const fn with_subsystem(self, subsystem_vendor_id: u16, subsystem_id: u16) -> Self {
+        let Self {
+            vendor_id,
+            device_id,
+            subsystem_vendor_id: _,
+            subsystem_id: _,
+            class_code,
+        } = self;
+        Self {
+            vendor_id,
+            device_id,
+            subsystem_vendor_id: Some(subsystem_vendor_id),
+            subsystem_id: Some(subsystem_id),
+            class_code,
+        }
+    }
+
+    fn matches(&self, function: &FunctionWithAddress) -> bool {
+        let header = function.function().header();
+        self.vendor_id
+            .map_or(true, |vendor_id| vendor_id == header.vendor_id())
+            && self
+                .device_id
+                .map_or(true, |device_id| device_id == header.device_id())
+            && self
+                .subsystem_vendor_id
+                .map_or(true, |subsystem_vendor_id| {
+                    header.subsystem_vendor_id() == Some(subsystem_vendor_id)
+                })
+            && self.subsystem_id.map_or(true, |subsystem_id| {
+                header.subsystem_id() == Some(subsystem_id)
+            })
+            && self
+                .class_code
+                .as_ref()
+                .map_or(true, |class_code| *class_code == header.class_code())
+    }
+}
+
+/// # Bind drivers to the enumerated functions
+pub fn initialize(configuration: &Configuration) {
+    let bindings: Vec<Binding> = configuration
+        .functions()
+        .filter_map(|function| {
+            DRIVERS
+                .iter()
+                .filter(|driver| driver.ids().iter().any(|id| id.matches(&function)))
+                .find(|driver| driver.probe(&function))
+                .map(|driver| {
+                    com2_println!("{} binds {:x?}", driver.name(), function.address());
+                    Binding {
+                        address: function.address(),
+                        driver: *driver,
+                    }
+                })
+        })
+        .collect();
+    *BINDINGS.lock() = bindings;
+}
+
+/// # Unbind all drivers
+pub fn remove_all(configuration: &Configuration) {
+    let bindings: Vec<Binding> = BINDINGS.lock().drain(..).collect();
+    bindings.into_iter().rev().for_each(|binding| {
+        if let Some(function) = configuration.function(binding.address) {
+            binding.driver.remove(&function);
+        }
+    });
+}
+
+/// # Resume bound functions in the order of binding
+/// A function is brought back to D0 before its driver resumes it, and a function which doesn't return to D0 is reset.
+pub fn resume(configuration: &Configuration) {
+    BINDINGS.lock().iter().for_each(|binding| {
+        if let Some(function) = configuration.function(binding.address) {
+            set_power_state(binding.address, power::PowerState::D0);
+            match power::power_state(binding.address) {
+                Some(power::PowerState::D0) | None => {}
+                Some(state) => {
+                    com2_println!("PCI {} stays in {:?}.", binding.address, state);
+                    if power::reset(configuration, binding.address).is_none() {
+                        com2_println!("PCI {} can't be reset.", binding.address);
+                    }
+                }
+            }
+            binding.driver.resume(&function);
+        }
+    });
+}
+
+/// # Suspend bound functions in the reverse order of binding
+/// A function is put into D3hot after its driver suspends it.
+pub fn suspend(configuration: &Configuration) {
+    BINDINGS.lock().iter().rev().for_each(|binding| {
+        if let Some(function) = configuration.function(binding.address) {
+            binding.driver.suspend(&function);
+            set_power_state(binding.address, power::PowerState::D3Hot);
+        }
+    });
+}
+
+fn set_power_state(address: FunctionAddress, state: power::PowerState) {
+    match power::set_power_state(address, state) {
+        Ok(()) | Err(power::Error::NoPowerManagement) => {}
+        Err(error) => com2_println!("PCI {} can't enter {:?}. {:?}", address, state, error),
+    }
+}
+
+struct Binding {
+    address: FunctionAddress,
+    driver: &'static dyn Driver,
+}
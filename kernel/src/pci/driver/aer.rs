@@ -0,0 +1,75 @@
+//! # AER Driver
+//! A PCI Express root port with the Advanced Error Reporting capability reports the errors under it to the processor which binds the driver.
+//! ## References
+//! * [PCI Express Base Specification Revision 5.0 Version 1.0](https://picture.iczhiku.com/resource/eetop/SYkDTqhOLhpUTnMx.pdf) 6.2.4.1 Error Messages
+
+use {
+    super::{
+        super::{
+            aer,
+            capability::{self, pci_express},
+            class, FunctionAddress, FunctionWithAddress,
+        },
+        Driver, Id,
+    },
+    crate::{com2_println, x64},
+};
+
+pub struct Aer;
+
+impl Aer {
+    fn enable(function: &FunctionWithAddress) -> bool {
+        let local_apic_id: u8 = x64::msr::ia32::ApicBase::get()
+            .unwrap()
+            .registers()
+            .apic_id();
+        match aer::enable(function, local_apic_id) {
+            Ok(()) => true,
+            Err(aer::Error::NoAdvancedErrorReporting) => false,
+            Err(error) => {
+                com2_println!(
+                    "PCI {} can't report errors. {:?}",
+                    function.address(),
+                    error
+                );
+                false
+            }
+        }
+    }
+}
+
+impl Driver for Aer {
+    fn ids(&self) -> &[Id] {
+        const IDS: [Id; 1] = [Id::class(class::Code::Pci2PciBridge)];
+        &IDS
+    }
+
+    fn name(&self) -> &str {
+        "AER"
+    }
+
+    fn probe(&self, function: &FunctionWithAddress) -> bool {
+        let address: FunctionAddress = function.address();
+        let is_root_port: bool = address
+            .capability(capability::Id::PciExpress)
+            .map(|pci_express| {
+                let capability: pci_express::capability::Register =
+                    ((address.read(pci_express) >> u16::BITS) as u16).into();
+                capability.is_root_port()
+            })
+            .unwrap_or(false);
+        is_root_port && Self::enable(function)
+    }
+
+    fn remove(&self, function: &FunctionWithAddress) {
+        aer::disable(function.address());
+    }
+
+    fn resume(&self, function: &FunctionWithAddress) {
+        Self::enable(function);
+    }
+
+    fn suspend(&self, function: &FunctionWithAddress) {
+        aer::disable(function.address());
+    }
+}
@@ -0,0 +1,131 @@
+//! # xHCI Driver
+//! The host controller is reset so that it doesn't keep the state left by the firmware.
+//! The Device Context Base Address Array is mapped for DMA of the controller within its DMA mask, and it is pointed again after the system wakes.
+//! Removing the driver halts and resets the controller and then disables its bus mastering so that it stops DMA before the array is unmapped.
+//! ## References
+//! * [eXtensible Host Controller Interface for Universal Serial Bus (xHCI)](https://www.intel.com/content/dam/www/public/us/en/documents/technical-specifications/extensible-host-controler-interface-usb-xhci.pdf) 4.2 Host Controller Initialization
+//! * [eXtensible Host Controller Interface for Universal Serial Bus (xHCI)](https://www.intel.com/content/dam/www/public/us/en/documents/technical-specifications/extensible-host-controler-interface-usb-xhci.pdf) 6.1 Device Context Base Address Array
+
+use {
+    super::{
+        super::{class, command, xhc, FunctionAddress, FunctionWithAddress},
+        Driver, Id,
+    },
+    crate::{com2_println, iommu, sync::spin, Argument},
+    alloc::{boxed::Box, collections::BTreeMap},
+    core::mem,
+};
+
+const COMMAND: u16 = 0x04;
+
+static DEVICE_CONTEXT_BASE_ADDRESS_ARRAYS: spin::Lock<
+    BTreeMap<FunctionAddress, DeviceContextBaseAddressArray>,
+> = spin::Lock::new(BTreeMap::new());
+
+pub struct Xhc;
+
+impl Driver for Xhc {
+    fn ids(&self) -> &[Id] {
+        const IDS: [Id; 1] = [Id::class(class::Code::UsbXhc)];
+        &IDS
+    }
+
+    fn name(&self) -> &str {
+        "xHCI"
+    }
+
+    fn probe(&self, function: &FunctionWithAddress) -> bool {
+        let xhc: Result<xhc::Registers, ()> = function.function().try_into();
+        let Ok(mut xhc) = xhc else {
+            return false;
+        };
+        xhc.reset();
+        let address: FunctionAddress = function.address();
+        match DeviceContextBaseAddressArray::new(address, &xhc) {
+            Ok(device_context_base_address_array) => {
+                xhc.set_device_context_base_address_array(device_context_base_address_array.iova);
+                DEVICE_CONTEXT_BASE_ADDRESS_ARRAYS
+                    .lock()
+                    .insert(address, device_context_base_address_array);
+                true
+            }
+            Err(error) => {
+                com2_println!(
+                    "PCI {} can't access the Device Context Base Address Array. {:?}",
+                    address,
+                    error
+                );
+                false
+            }
+        }
+    }
+
+    fn remove(&self, function: &FunctionWithAddress) {
+        let xhc: Result<xhc::Registers, ()> = function.function().try_into();
+        if let Ok(mut xhc) = xhc {
+            xhc.halt();
+            xhc.reset();
+        }
+        let address: FunctionAddress = function.address();
+        let command: command::Register = (address.read(COMMAND) as u16).into();
+        address.write(COMMAND, u16::from(command.with_bus_master(false)) as u32);
+        if let Some(device_context_base_address_array) =
+            DEVICE_CONTEXT_BASE_ADDRESS_ARRAYS.lock().remove(&address)
+        {
+            iommu::dma_unmap(
+                address,
+                device_context_base_address_array.iova,
+                mem::size_of::<Entries>(),
+            );
+        }
+    }
+
+    fn resume(&self, function: &FunctionWithAddress) {
+        let xhc: Result<xhc::Registers, ()> = function.function().try_into();
+        if let (Ok(mut xhc), Some(device_context_base_address_array)) = (
+            xhc,
+            DEVICE_CONTEXT_BASE_ADDRESS_ARRAYS
+                .lock()
+                .get(&function.address()),
+        ) {
+            xhc.reset();
+            xhc.set_device_context_base_address_array(device_context_base_address_array.iova);
+        }
+    }
+}
+
+/// # Device Context Base Address Array
+/// The entry 0 is for the Scratchpad Buffer Array, and the entries from 1 are for the Device Slots.
+struct DeviceContextBaseAddressArray {
+    /// The entries are kept while the controller may access them.
+    #[allow(dead_code)]
+    entries: Box<Entries>,
+    iova: u64,
+}
+
+impl DeviceContextBaseAddressArray {
+    fn new(function: FunctionAddress, xhc: &xhc::Registers) -> Result<Self, iommu::Error> {
+        assert!(xhc.number_of_slots() < Entries::LENGTH);
+        let entries: Box<Entries> = Box::default();
+        let paddr: usize = Argument::get()
+            .paging()
+            .vaddr2paddr(entries.as_ref())
+            .unwrap();
+        let iova: u64 = iommu::dma_map(function, paddr, mem::size_of::<Entries>(), xhc.dma_mask())?;
+        Ok(Self { entries, iova })
+    }
+}
+
+/// The array doesn't cross a page boundary.
+#[repr(align(4096))]
+struct Entries([u64; Entries::LENGTH]);
+
+impl Entries {
+    const LENGTH: usize = 0x100;
+}
+
+impl Default for Entries {
+    fn default() -> Self {
+        Self([0; Self::LENGTH])
+    }
+}
@@ -15,3 +15,13 @@ pub struct Register {
     #[bits(21)]
     base_address: u32,
 }
+
+impl Register {
+    pub fn address(&self) -> u32 {
+        self.base_address() << Self::BASE_ADDRESS_OFFSET
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enable()
+    }
+}
@@ -0,0 +1,206 @@
+//! # PCI Express Extended Capabilities
+//! Extended capabilities are linked from offset 0x100 of the configuration space, which is only reachable through ECAM.
+//! They are read on demand, and a structure refers to the ECAM window directly.
+//! ## References
+//! * [PCI Express Base Specification Revision 5.0 Version 1.0](https://picture.iczhiku.com/resource/eetop/SYkDTqhOLhpUTnMx.pdf) 7.6 PCI Express Extended Capabilities
+
+pub mod access_control_services;
+pub mod advanced_error_reporting;
+pub mod device_serial_number;
+pub mod downstream_port_containment;
+pub mod latency_tolerance_reporting;
+pub mod single_root_io_virtualization;
+
+use {
+    super::FunctionAddress,
+    bitfield_struct::bitfield,
+    core::{fmt, mem},
+};
+
+/// The first extended capability is at the beginning of the extended configuration space.
+pub const FIRST_POINTER: u16 = 0x100;
+/// Size of the configuration space of a function in bytes
+const CONFIGURATION_SPACE_SIZE: usize = 0x1000;
+
+/// # PCI Express Extended Capability Header
+/// ## References
+/// * [PCI Express Base Specification Revision 5.0 Version 1.0](https://picture.iczhiku.com/resource/eetop/SYkDTqhOLhpUTnMx.pdf) 7.6.3 PCI Express Extended Capability Header
+#[bitfield(u32)]
+pub struct Header {
+    capability_id: u16,
+    #[bits(4)]
+    capability_version: u8,
+    #[bits(12)]
+    next_capability_offset: u16,
+}
+
+impl Header {
+    pub fn id(&self) -> Id {
+        self.capability_id().into()
+    }
+
+    pub fn version(&self) -> u8 {
+        self.capability_version()
+    }
+
+    /// # The offset of the next capability
+    /// The lowest 2 bits are reserved and masked.
+    pub fn next_pointer(&self) -> u16 {
+        self.next_capability_offset() & !0x3
+    }
+
+    fn is_valid(&self) -> bool {
+        let header: u32 = (*self).into();
+        header != 0 && header != u32::MAX
+    }
+}
+
+#[derive(Clone)]
+pub struct Headers {
+    address: FunctionAddress,
+    next_pointer: u16,
+}
+
+impl Headers {
+    fn next_header(&self) -> Option<Header> {
+        (FIRST_POINTER <= self.next_pointer)
+            .then(|| header(self.address, self.next_pointer))
+            .filter(|header| header.is_valid())
+    }
+}
+
+impl fmt::Debug for Headers {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let address: FunctionAddress = self.address;
+        formatter
+            .debug_list()
+            .entries(
+                self.clone()
+                    .filter_map(|next_pointer| Structure::new(address, next_pointer)),
+            )
+            .finish()
+    }
+}
+
+impl From<FunctionAddress> for Headers {
+    fn from(address: FunctionAddress) -> Self {
+        let next_pointer: u16 = FIRST_POINTER;
+        Self {
+            address,
+            next_pointer,
+        }
+    }
+}
+
+impl Iterator for Headers {
+    type Item = u16;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_header().map(|next_header| {
+            let pointer: u16 = self.next_pointer;
+            // A capability never points backward, which stops a broken list.
+            self.next_pointer = Some(next_header.next_pointer())
+                .filter(|next_pointer| pointer < *next_pointer)
+                .unwrap_or(0);
+            pointer
+        })
+    }
+}
+
+/// # PCI Express Extended Capability IDs
+/// ## References
+/// * [PCI Code and ID Assignment Specification Revision 1.11](https://pcisig.com/sites/default/files/files/PCI_Code-ID_r_1_11__v24_Jan_2019.pdf) 3. Extended Capability ID Assignments
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Id {
+    AdvancedErrorReporting,
+    DeviceSerialNumber,
+    AccessControlServices,
+    SingleRootIoVirtualization,
+    LatencyToleranceReporting,
+    DownstreamPortContainment,
+    Other(u16),
+}
+
+impl From<u16> for Id {
+    fn from(id: u16) -> Self {
+        match id {
+            0x0001 => Self::AdvancedErrorReporting,
+            0x0003 => Self::DeviceSerialNumber,
+            0x000d => Self::AccessControlServices,
+            0x0010 => Self::SingleRootIoVirtualization,
+            0x0018 => Self::LatencyToleranceReporting,
+            0x001d => Self::DownstreamPortContainment,
+            id => Self::Other(id),
+        }
+    }
+}
+
+impl fmt::Display for Id {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AdvancedErrorReporting => write!(formatter, "Advanced Error Reporting"),
+            Self::DeviceSerialNumber => write!(formatter, "Device Serial Number"),
+            Self::AccessControlServices => write!(formatter, "Access Control Services"),
+            Self::SingleRootIoVirtualization => {
+                write!(formatter, "Single Root I/O Virtualization (SR-IOV)")
+            }
+            Self::LatencyToleranceReporting => write!(formatter, "Latency Tolerance Reporting"),
+            Self::DownstreamPortContainment => write!(formatter, "Downstream Port Containment"),
+            Self::Other(id) => write!(formatter, "Extended Capability ID {:#06x}", id),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Structure {
+    AccessControlServices(&'static access_control_services::Structure),
+    AdvancedErrorReporting(&'static advanced_error_reporting::Structure),
+    DeviceSerialNumber(&'static device_serial_number::Structure),
+    DownstreamPortContainment(&'static downstream_port_containment::Structure),
+    LatencyToleranceReporting(&'static latency_tolerance_reporting::Structure),
+    Other { id: u16, version: u8 },
+    SingleRootIoVirtualization(&'static single_root_io_virtualization::Structure),
+}
+
+impl Structure {
+    /// # The extended capability at a pointer
+    /// It is `None` if the function has no ECAM window or the structure runs over the configuration space.
+    pub fn new(address: FunctionAddress, pointer: u16) -> Option<Self> {
+        let header: Header = header(address, pointer);
+        Some(match header.id() {
+            Id::AccessControlServices => Self::AccessControlServices(structure(address, pointer)?),
+            Id::AdvancedErrorReporting => {
+                Self::AdvancedErrorReporting(structure(address, pointer)?)
+            }
+            Id::DeviceSerialNumber => Self::DeviceSerialNumber(structure(address, pointer)?),
+            Id::DownstreamPortContainment => {
+                Self::DownstreamPortContainment(structure(address, pointer)?)
+            }
+            Id::LatencyToleranceReporting => {
+                Self::LatencyToleranceReporting(structure(address, pointer)?)
+            }
+            Id::SingleRootIoVirtualization => {
+                Self::SingleRootIoVirtualization(structure(address, pointer)?)
+            }
+            Id::Other(id) => Self::Other {
+                id,
+                version: header.version(),
+            },
+        })
+    }
+}
+
+/// # The extended capability header at a pointer in the configuration space
+pub fn header(address: FunctionAddress, pointer: u16) -> Header {
+    address.read(pointer).into()
+}
+
+/// # A structure at a pointer in the ECAM window of a function
+fn structure<T>(address: FunctionAddress, pointer: u16) -> Option<&'static T> {
+    let ecam: usize = address.ecam()?;
+    ((pointer as usize) + mem::size_of::<T>() <= CONFIGURATION_SPACE_SIZE).then(|| {
+        let structure: usize = ecam + (pointer as usize);
+        let structure: *const T = structure as *const T;
+        unsafe { &*structure }
+    })
+}
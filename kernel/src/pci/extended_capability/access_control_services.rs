@@ -0,0 +1,63 @@
+use {super::Header, bitfield_struct::bitfield, core::fmt};
+
+/// # ACS Extended Capability
+/// ## References
+/// * [PCI Express Base Specification Revision 5.0 Version 1.0](https://picture.iczhiku.com/resource/eetop/SYkDTqhOLhpUTnMx.pdf) 7.7.8 ACS Extended Capability
+#[repr(packed)]
+pub struct Structure {
+    header: Header,
+    capability: Capability,
+    control: Control,
+}
+
+impl fmt::Debug for Structure {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let header: Header = self.header;
+        let capability: Capability = self.capability;
+        let control: Control = self.control;
+        formatter
+            .debug_struct("Structure")
+            .field("header", &header)
+            .field("capability", &capability)
+            .field("control", &control)
+            .finish()
+    }
+}
+
+/// # ACS Capability Register
+/// ## References
+/// * [PCI Express Base Specification Revision 5.0 Version 1.0](https://picture.iczhiku.com/resource/eetop/SYkDTqhOLhpUTnMx.pdf) 7.7.8.2 ACS Capability Register
+#[bitfield(u16)]
+pub struct Capability {
+    source_validation: bool,
+    translation_blocking: bool,
+    p2p_request_redirect: bool,
+    p2p_completion_redirect: bool,
+    upstream_forwarding: bool,
+    p2p_egress_control: bool,
+    direct_translated_p2p: bool,
+    enhanced_capability: bool,
+    egress_control_vector_size: u8,
+}
+
+/// # ACS Control Register
+/// ## References
+/// * [PCI Express Base Specification Revision 5.0 Version 1.0](https://picture.iczhiku.com/resource/eetop/SYkDTqhOLhpUTnMx.pdf) 7.7.8.3 ACS Control Register
+#[bitfield(u16)]
+pub struct Control {
+    source_validation_enable: bool,
+    translation_blocking_enable: bool,
+    p2p_request_redirect_enable: bool,
+    p2p_completion_redirect_enable: bool,
+    upstream_forwarding_enable: bool,
+    p2p_egress_control_enable: bool,
+    direct_translated_p2p_enable: bool,
+    io_request_blocking_enable: bool,
+    #[bits(2)]
+    dsp_memory_target_access_control: u8,
+    #[bits(2)]
+    usp_memory_target_access_control: u8,
+    unclaimed_request_redirect_control: bool,
+    #[bits(3)]
+    __: u8,
+}
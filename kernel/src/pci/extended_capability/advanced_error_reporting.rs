@@ -0,0 +1,325 @@
+use {
+    super::{super::lspci::Flag, Header},
+    alloc::vec::Vec,
+    bitfield_struct::bitfield,
+    core::fmt,
+};
+
+/// # Advanced Error Reporting Extended Capability
+/// The root error registers are only meaningful in root ports and root complex event collectors.
+/// ## References
+/// * [PCI Express Base Specification Revision 5.0 Version 1.0](https://picture.iczhiku.com/resource/eetop/SYkDTqhOLhpUTnMx.pdf) 7.8.4 Advanced Error Reporting Extended Capability
+#[repr(packed)]
+pub struct Structure {
+    header: Header,
+    uncorrectable_error_status: UncorrectableError,
+    uncorrectable_error_mask: UncorrectableError,
+    uncorrectable_error_severity: UncorrectableError,
+    correctable_error_status: CorrectableError,
+    correctable_error_mask: CorrectableError,
+    capabilities_and_control: CapabilitiesAndControl,
+    header_log: [u32; 4],
+    #[allow(dead_code)]
+    root_error_command: RootErrorCommand,
+    #[allow(dead_code)]
+    root_error_status: RootErrorStatus,
+    #[allow(dead_code)]
+    correctable_error_source_identification: u16,
+    #[allow(dead_code)]
+    error_source_identification: u16,
+    #[allow(dead_code)]
+    tlp_prefix_log: [u32; 4],
+}
+
+/// # The error registers like `lspci -vv`
+impl fmt::Display for Structure {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let uncorrectable_error_status: UncorrectableError = self.uncorrectable_error_status;
+        let uncorrectable_error_mask: UncorrectableError = self.uncorrectable_error_mask;
+        let uncorrectable_error_severity: UncorrectableError = self.uncorrectable_error_severity;
+        let correctable_error_status: CorrectableError = self.correctable_error_status;
+        let correctable_error_mask: CorrectableError = self.correctable_error_mask;
+        let capabilities_and_control: CapabilitiesAndControl = self.capabilities_and_control;
+        let header_log: [u32; 4] = self.header_log;
+        writeln!(formatter, "UESta:\t{}", uncorrectable_error_status)?;
+        writeln!(formatter, "UEMsk:\t{}", uncorrectable_error_mask)?;
+        writeln!(formatter, "UESvrt:\t{}", uncorrectable_error_severity)?;
+        writeln!(formatter, "CESta:\t{}", correctable_error_status)?;
+        writeln!(formatter, "CEMsk:\t{}", correctable_error_mask)?;
+        writeln!(formatter, "AERCap:\t{}", capabilities_and_control)?;
+        writeln!(
+            formatter,
+            "HeaderLog: {:08x} {:08x} {:08x} {:08x}",
+            header_log[0], header_log[1], header_log[2], header_log[3]
+        )
+    }
+}
+
+impl fmt::Debug for Structure {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let header: Header = self.header;
+        let uncorrectable_error_status: UncorrectableError = self.uncorrectable_error_status;
+        let uncorrectable_error_mask: UncorrectableError = self.uncorrectable_error_mask;
+        let uncorrectable_error_severity: UncorrectableError = self.uncorrectable_error_severity;
+        let correctable_error_status: CorrectableError = self.correctable_error_status;
+        let correctable_error_mask: CorrectableError = self.correctable_error_mask;
+        let capabilities_and_control: CapabilitiesAndControl = self.capabilities_and_control;
+        let header_log: [u32; 4] = self.header_log;
+        formatter
+            .debug_struct("Structure")
+            .field("header", &header)
+            .field("uncorrectable_error_status", &uncorrectable_error_status)
+            .field("uncorrectable_error_mask", &uncorrectable_error_mask)
+            .field(
+                "uncorrectable_error_severity",
+                &uncorrectable_error_severity,
+            )
+            .field("correctable_error_status", &correctable_error_status)
+            .field("correctable_error_mask", &correctable_error_mask)
+            .field("capabilities_and_control", &capabilities_and_control)
+            .field("header_log", &header_log)
+            .finish()
+    }
+}
+
+/// # Uncorrectable Error Status, Mask and Severity Registers
+/// ## References
+/// * [PCI Express Base Specification Revision 5.0 Version 1.0](https://picture.iczhiku.com/resource/eetop/SYkDTqhOLhpUTnMx.pdf) 7.8.4.2 Uncorrectable Error Status Register
+#[bitfield(u32)]
+pub struct UncorrectableError {
+    __: bool,
+    #[bits(3)]
+    __: u8,
+    data_link_protocol_error: bool,
+    surprise_down_error: bool,
+    #[bits(6)]
+    __: u8,
+    poisoned_tlp_received: bool,
+    flow_control_protocol_error: bool,
+    completion_timeout: bool,
+    completer_abort: bool,
+    unexpected_completion: bool,
+    receiver_overflow: bool,
+    malformed_tlp: bool,
+    ecrc_error: bool,
+    unsupported_request_error: bool,
+    acs_violation: bool,
+    uncorrectable_internal_error: bool,
+    mc_blocked_tlp: bool,
+    atomic_op_egress_blocked: bool,
+    tlp_prefix_blocked_error: bool,
+    poisoned_tlp_egress_blocked: bool,
+    #[bits(5)]
+    __: u8,
+}
+
+impl UncorrectableError {
+    const NAMES: [(u32, &'static str); 17] = [
+        (4, "Data Link Protocol Error"),
+        (5, "Surprise Down Error"),
+        (12, "Poisoned TLP Received"),
+        (13, "Flow Control Protocol Error"),
+        (14, "Completion Timeout"),
+        (15, "Completer Abort"),
+        (16, "Unexpected Completion"),
+        (17, "Receiver Overflow"),
+        (18, "Malformed TLP"),
+        (19, "ECRC Error"),
+        (20, "Unsupported Request Error"),
+        (21, "ACS Violation"),
+        (22, "Uncorrectable Internal Error"),
+        (23, "MC Blocked TLP"),
+        (24, "AtomicOp Egress Blocked"),
+        (25, "TLP Prefix Blocked Error"),
+        (26, "Poisoned TLP Egress Blocked"),
+    ];
+
+    /// # The names of the errors whose bits are set
+    pub fn names(&self) -> Vec<&'static str> {
+        names(u32::from(*self), &Self::NAMES)
+    }
+}
+
+impl fmt::Display for UncorrectableError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "DLP{} SDES{} TLP{} FCP{} CmpltTO{} CmpltAbrt{} UnxCmplt{} RxOF{} MalfTLP{} ECRC{} UnsupReq{} ACSViol{}",
+            Flag(self.data_link_protocol_error()),
+            Flag(self.surprise_down_error()),
+            Flag(self.poisoned_tlp_received()),
+            Flag(self.flow_control_protocol_error()),
+            Flag(self.completion_timeout()),
+            Flag(self.completer_abort()),
+            Flag(self.unexpected_completion()),
+            Flag(self.receiver_overflow()),
+            Flag(self.malformed_tlp()),
+            Flag(self.ecrc_error()),
+            Flag(self.unsupported_request_error()),
+            Flag(self.acs_violation())
+        )
+    }
+}
+
+/// # Correctable Error Status and Mask Registers
+/// ## References
+/// * [PCI Express Base Specification Revision 5.0 Version 1.0](https://picture.iczhiku.com/resource/eetop/SYkDTqhOLhpUTnMx.pdf) 7.8.4.5 Correctable Error Status Register
+#[bitfield(u32)]
+pub struct CorrectableError {
+    receiver_error: bool,
+    #[bits(5)]
+    __: u8,
+    bad_tlp: bool,
+    bad_dllp: bool,
+    replay_num_rollover: bool,
+    #[bits(3)]
+    __: u8,
+    replay_timer_timeout: bool,
+    advisory_non_fatal_error: bool,
+    corrected_internal_error: bool,
+    header_log_overflow: bool,
+    __: u16,
+}
+
+impl CorrectableError {
+    const NAMES: [(u32, &'static str); 8] = [
+        (0, "Receiver Error"),
+        (6, "Bad TLP"),
+        (7, "Bad DLLP"),
+        (8, "REPLAY_NUM Rollover"),
+        (12, "Replay Timer Timeout"),
+        (13, "Advisory Non-Fatal Error"),
+        (14, "Corrected Internal Error"),
+        (15, "Header Log Overflow"),
+    ];
+
+    /// # The names of the errors whose bits are set
+    pub fn names(&self) -> Vec<&'static str> {
+        names(u32::from(*self), &Self::NAMES)
+    }
+}
+
+impl fmt::Display for CorrectableError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "RxErr{} BadTLP{} BadDLLP{} Rollover{} Timeout{} AdvNonFatalErr{}",
+            Flag(self.receiver_error()),
+            Flag(self.bad_tlp()),
+            Flag(self.bad_dllp()),
+            Flag(self.replay_num_rollover()),
+            Flag(self.replay_timer_timeout()),
+            Flag(self.advisory_non_fatal_error())
+        )
+    }
+}
+
+/// # Advanced Error Capabilities and Control Register
+/// ## References
+/// * [PCI Express Base Specification Revision 5.0 Version 1.0](https://picture.iczhiku.com/resource/eetop/SYkDTqhOLhpUTnMx.pdf) 7.8.4.7 Advanced Error Capabilities and Control Register
+#[bitfield(u32)]
+pub struct CapabilitiesAndControl {
+    #[bits(5)]
+    first_error_pointer: u8,
+    ecrc_generation_capable: bool,
+    ecrc_generation_enable: bool,
+    ecrc_check_capable: bool,
+    ecrc_check_enable: bool,
+    multiple_header_recording_capable: bool,
+    multiple_header_recording_enable: bool,
+    tlp_prefix_log_present: bool,
+    completion_timeout_prefix_header_log_capable: bool,
+    #[bits(19)]
+    __: u32,
+}
+
+impl fmt::Display for CapabilitiesAndControl {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            formatter,
+            "First Error Pointer: {:02x}, ECRCGenCap{} ECRCGenEn{} ECRCChkCap{} ECRCChkEn{}",
+            self.first_error_pointer(),
+            Flag(self.ecrc_generation_capable()),
+            Flag(self.ecrc_generation_enable()),
+            Flag(self.ecrc_check_capable()),
+            Flag(self.ecrc_check_enable())
+        )?;
+        write!(
+            formatter,
+            "\tMultHdrRecCap{} MultHdrRecEn{} TLPPfxPres{} HdrLogCap{}",
+            Flag(self.multiple_header_recording_capable()),
+            Flag(self.multiple_header_recording_enable()),
+            Flag(self.tlp_prefix_log_present()),
+            Flag(self.completion_timeout_prefix_header_log_capable())
+        )
+    }
+}
+
+/// # Root Error Command Register
+/// ## References
+/// * [PCI Express Base Specification Revision 5.0 Version 1.0](https://picture.iczhiku.com/resource/eetop/SYkDTqhOLhpUTnMx.pdf) 7.8.4.9 Root Error Command Register
+#[bitfield(u32)]
+pub struct RootErrorCommand {
+    correctable_error_reporting_enable: bool,
+    non_fatal_error_reporting_enable: bool,
+    fatal_error_reporting_enable: bool,
+    #[bits(29)]
+    __: u32,
+}
+
+impl RootErrorCommand {
+    /// # Enable or disable the interrupt on every error message
+    pub fn with_reporting(self, enable: bool) -> Self {
+        self.with_correctable_error_reporting_enable(enable)
+            .with_non_fatal_error_reporting_enable(enable)
+            .with_fatal_error_reporting_enable(enable)
+    }
+}
+
+/// # Root Error Status Register
+/// ## References
+/// * [PCI Express Base Specification Revision 5.0 Version 1.0](https://picture.iczhiku.com/resource/eetop/SYkDTqhOLhpUTnMx.pdf) 7.8.4.10 Root Error Status Register
+#[bitfield(u32)]
+pub struct RootErrorStatus {
+    err_cor_received: bool,
+    multiple_err_cor_received: bool,
+    err_fatal_nonfatal_received: bool,
+    multiple_err_fatal_nonfatal_received: bool,
+    first_uncorrectable_fatal: bool,
+    non_fatal_error_messages_received: bool,
+    fatal_error_messages_received: bool,
+    #[bits(20)]
+    __: u32,
+    #[bits(5)]
+    advanced_error_interrupt_message_number: u8,
+}
+
+impl RootErrorStatus {
+    pub fn is_correctable_received(&self) -> bool {
+        self.err_cor_received()
+    }
+
+    pub fn is_fatal_received(&self) -> bool {
+        self.fatal_error_messages_received()
+    }
+
+    pub fn is_multiple_correctable_received(&self) -> bool {
+        self.multiple_err_cor_received()
+    }
+
+    pub fn is_multiple_uncorrectable_received(&self) -> bool {
+        self.multiple_err_fatal_nonfatal_received()
+    }
+
+    pub fn is_uncorrectable_received(&self) -> bool {
+        self.err_fatal_nonfatal_received()
+    }
+}
+
+fn names(bits: u32, names: &[(u32, &'static str)]) -> Vec<&'static str> {
+    names
+        .iter()
+        .filter(|(bit, _name)| bits & (1 << bit) != 0)
+        .map(|(_bit, name)| *name)
+        .collect()
+}
@@ -0,0 +1,32 @@
+use {super::Header, core::fmt};
+
+/// # Device Serial Number Extended Capability
+/// ## References
+/// * [PCI Express Base Specification Revision 5.0 Version 1.0](https://picture.iczhiku.com/resource/eetop/SYkDTqhOLhpUTnMx.pdf) 7.9.3 Device Serial Number Extended Capability
+#[repr(packed)]
+pub struct Structure {
+    header: Header,
+    serial_number_lower: u32,
+    serial_number_upper: u32,
+}
+
+impl Structure {
+    /// # The IEEE defined 64-bit extended unique identifier (EUI-64)
+    pub fn serial_number(&self) -> u64 {
+        let serial_number_lower: u32 = self.serial_number_lower;
+        let serial_number_upper: u32 = self.serial_number_upper;
+        ((serial_number_upper as u64) << u32::BITS) | (serial_number_lower as u64)
+    }
+}
+
+impl fmt::Debug for Structure {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let header: Header = self.header;
+        let serial_number: u64 = self.serial_number();
+        formatter
+            .debug_struct("Structure")
+            .field("header", &header)
+            .field("serial_number", &serial_number)
+            .finish()
+    }
+}
@@ -0,0 +1,83 @@
+use {super::Header, bitfield_struct::bitfield, core::fmt};
+
+/// # Downstream Port Containment Extended Capability
+/// ## References
+/// * [PCI Express Base Specification Revision 5.0 Version 1.0](https://picture.iczhiku.com/resource/eetop/SYkDTqhOLhpUTnMx.pdf) 7.9.15 Downstream Port Containment Extended Capability
+#[repr(packed)]
+pub struct Structure {
+    header: Header,
+    capability: Capability,
+    control: Control,
+    status: Status,
+    error_source_id: u16,
+}
+
+impl fmt::Debug for Structure {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let header: Header = self.header;
+        let capability: Capability = self.capability;
+        let control: Control = self.control;
+        let status: Status = self.status;
+        let error_source_id: u16 = self.error_source_id;
+        formatter
+            .debug_struct("Structure")
+            .field("header", &header)
+            .field("capability", &capability)
+            .field("control", &control)
+            .field("status", &status)
+            .field("error_source_id", &error_source_id)
+            .finish()
+    }
+}
+
+/// # DPC Capability Register
+/// ## References
+/// * [PCI Express Base Specification Revision 5.0 Version 1.0](https://picture.iczhiku.com/resource/eetop/SYkDTqhOLhpUTnMx.pdf) 7.9.15.2 DPC Capability Register
+#[bitfield(u16)]
+pub struct Capability {
+    #[bits(5)]
+    dpc_interrupt_message_number: u8,
+    rp_extensions_for_dpc: bool,
+    poisoned_tlp_egress_blocking_supported: bool,
+    dpc_software_triggering_supported: bool,
+    #[bits(4)]
+    rp_pio_log_size: u8,
+    dl_active_err_cor_signaling_supported: bool,
+    #[bits(3)]
+    __: u8,
+}
+
+/// # DPC Control Register
+/// ## References
+/// * [PCI Express Base Specification Revision 5.0 Version 1.0](https://picture.iczhiku.com/resource/eetop/SYkDTqhOLhpUTnMx.pdf) 7.9.15.3 DPC Control Register
+#[bitfield(u16)]
+pub struct Control {
+    #[bits(2)]
+    dpc_trigger_enable: u8,
+    dpc_completion_control: bool,
+    dpc_interrupt_enable: bool,
+    dpc_err_cor_enable: bool,
+    poisoned_tlp_egress_blocking_enable: bool,
+    dpc_software_trigger: bool,
+    dl_active_err_cor_enable: bool,
+    __: u8,
+}
+
+/// # DPC Status Register
+/// ## References
+/// * [PCI Express Base Specification Revision 5.0 Version 1.0](https://picture.iczhiku.com/resource/eetop/SYkDTqhOLhpUTnMx.pdf) 7.9.15.4 DPC Status Register
+#[bitfield(u16)]
+pub struct Status {
+    dpc_trigger_status: bool,
+    #[bits(2)]
+    dpc_trigger_reason: u8,
+    dpc_interrupt_status: bool,
+    dpc_rp_busy: bool,
+    #[bits(2)]
+    dpc_trigger_reason_extension: u8,
+    __: bool,
+    #[bits(5)]
+    rp_pio_first_error_pointer: u8,
+    #[bits(3)]
+    __: u8,
+}
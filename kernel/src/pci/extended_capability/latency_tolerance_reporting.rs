@@ -0,0 +1,39 @@
+use {super::Header, bitfield_struct::bitfield, core::fmt};
+
+/// # LTR Extended Capability
+/// ## References
+/// * [PCI Express Base Specification Revision 5.0 Version 1.0](https://picture.iczhiku.com/resource/eetop/SYkDTqhOLhpUTnMx.pdf) 7.8.2 Latency Tolerance Reporting (LTR) Extended Capability
+#[repr(packed)]
+pub struct Structure {
+    header: Header,
+    max_snoop_latency: Latency,
+    max_no_snoop_latency: Latency,
+}
+
+impl fmt::Debug for Structure {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let header: Header = self.header;
+        let max_snoop_latency: Latency = self.max_snoop_latency;
+        let max_no_snoop_latency: Latency = self.max_no_snoop_latency;
+        formatter
+            .debug_struct("Structure")
+            .field("header", &header)
+            .field("max_snoop_latency", &max_snoop_latency)
+            .field("max_no_snoop_latency", &max_no_snoop_latency)
+            .finish()
+    }
+}
+
+/// # Max Snoop Latency and Max No-Snoop Latency Registers
+/// The latency in nanoseconds is the value multiplied by 32 to the power of the scale.
+/// ## References
+/// * [PCI Express Base Specification Revision 5.0 Version 1.0](https://picture.iczhiku.com/resource/eetop/SYkDTqhOLhpUTnMx.pdf) 7.8.2.2 Max Snoop Latency Register
+#[bitfield(u16)]
+pub struct Latency {
+    #[bits(10)]
+    value: u16,
+    #[bits(3)]
+    scale: u8,
+    #[bits(3)]
+    __: u8,
+}
@@ -0,0 +1,95 @@
+use {super::Header, bitfield_struct::bitfield, core::fmt};
+
+/// # SR-IOV Extended Capability
+/// ## References
+/// * [PCI Express Base Specification Revision 5.0 Version 1.0](https://picture.iczhiku.com/resource/eetop/SYkDTqhOLhpUTnMx.pdf) 9.3.3 SR-IOV Extended Capability
+#[repr(packed)]
+pub struct Structure {
+    header: Header,
+    capabilities: Capabilities,
+    control: Control,
+    status: u16,
+    initial_vfs: u16,
+    total_vfs: u16,
+    num_vfs: u16,
+    function_dependency_link: u8,
+    _0: u8,
+    first_vf_offset: u16,
+    vf_stride: u16,
+    _1: u16,
+    vf_device_id: u16,
+    supported_page_sizes: u32,
+    system_page_size: u32,
+    vf_base_address_registers: [u32; 6],
+    vf_migration_state_array_offset: u32,
+}
+
+impl fmt::Debug for Structure {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let header: Header = self.header;
+        let capabilities: Capabilities = self.capabilities;
+        let control: Control = self.control;
+        let status: u16 = self.status;
+        let initial_vfs: u16 = self.initial_vfs;
+        let total_vfs: u16 = self.total_vfs;
+        let num_vfs: u16 = self.num_vfs;
+        let function_dependency_link: u8 = self.function_dependency_link;
+        let first_vf_offset: u16 = self.first_vf_offset;
+        let vf_stride: u16 = self.vf_stride;
+        let vf_device_id: u16 = self.vf_device_id;
+        let supported_page_sizes: u32 = self.supported_page_sizes;
+        let system_page_size: u32 = self.system_page_size;
+        let vf_base_address_registers: [u32; 6] = self.vf_base_address_registers;
+        let vf_migration_state_array_offset: u32 = self.vf_migration_state_array_offset;
+        formatter
+            .debug_struct("Structure")
+            .field("header", &header)
+            .field("capabilities", &capabilities)
+            .field("control", &control)
+            .field("status", &status)
+            .field("initial_vfs", &initial_vfs)
+            .field("total_vfs", &total_vfs)
+            .field("num_vfs", &num_vfs)
+            .field("function_dependency_link", &function_dependency_link)
+            .field("first_vf_offset", &first_vf_offset)
+            .field("vf_stride", &vf_stride)
+            .field("vf_device_id", &vf_device_id)
+            .field("supported_page_sizes", &supported_page_sizes)
+            .field("system_page_size", &system_page_size)
+            .field("vf_base_address_registers", &vf_base_address_registers)
+            .field(
+                "vf_migration_state_array_offset",
+                &vf_migration_state_array_offset,
+            )
+            .finish()
+    }
+}
+
+/// # SR-IOV Capabilities Register
+/// ## References
+/// * [PCI Express Base Specification Revision 5.0 Version 1.0](https://picture.iczhiku.com/resource/eetop/SYkDTqhOLhpUTnMx.pdf) 9.3.3.2 SR-IOV Capabilities Register
+#[bitfield(u32)]
+pub struct Capabilities {
+    vf_migration_capable: bool,
+    ari_capable_hierarchy_preserved: bool,
+    vf_10bit_tag_requester_supported: bool,
+    #[bits(18)]
+    __: u32,
+    #[bits(11)]
+    vf_migration_interrupt_message_number: u16,
+}
+
+/// # SR-IOV Control Register
+/// ## References
+/// * [PCI Express Base Specification Revision 5.0 Version 1.0](https://picture.iczhiku.com/resource/eetop/SYkDTqhOLhpUTnMx.pdf) 9.3.3.3 SR-IOV Control Register
+#[bitfield(u16)]
+pub struct Control {
+    vf_enable: bool,
+    vf_migration_enable: bool,
+    vf_migration_interrupt_enable: bool,
+    vf_mse: bool,
+    ari_capable_hierarchy: bool,
+    vf_10bit_tag_requester_enable: bool,
+    #[bits(10)]
+    __: u16,
+}
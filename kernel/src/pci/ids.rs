@@ -0,0 +1,39 @@
+//! # PCI ID Database
+//! The names of vendors and devices in `pci.ids`, which the build script converts into tables sorted by ID.
+//! The file is a subset of the PCI ID Repository covering the devices emulated by virtual machines and common host controllers.
+//! ## References
+//! * [The PCI ID Repository](https://pci-ids.ucw.cz/)
+
+include!(concat!(env!("OUT_DIR"), "/pci_ids.rs"));
+
+/// # The name of a device
+pub fn device(vendor_id: u16, device_id: u16) -> Option<&'static str> {
+    let devices: &[Device] = vendor_entry(vendor_id)?.devices;
+    devices
+        .binary_search_by_key(&device_id, |device| device.id)
+        .ok()
+        .map(|index| devices[index].name)
+}
+
+/// # The name of a vendor
+pub fn vendor(vendor_id: u16) -> Option<&'static str> {
+    vendor_entry(vendor_id).map(|vendor| vendor.name)
+}
+
+fn vendor_entry(vendor_id: u16) -> Option<&'static Vendor> {
+    VENDORS
+        .binary_search_by_key(&vendor_id, |vendor| vendor.id)
+        .ok()
+        .map(|index| &VENDORS[index])
+}
+
+struct Device {
+    id: u16,
+    name: &'static str,
+}
+
+struct Vendor {
+    id: u16,
+    name: &'static str,
+    devices: &'static [Device],
+}
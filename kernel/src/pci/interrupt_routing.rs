@@ -0,0 +1,268 @@
+//! # PCI Interrupt Routing
+//! INTx interrupts of a function are routed by `_PRT` of the nearest bridge which has it.
+//! A bridge without `_PRT` swizzles the pin by the device number and forwards it to its own upstream bridge.
+//! ## References
+//! * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 6.2.13 _PRT (PCI Routing Table)
+//! * [PCI-to-PCI Bridge Architecture Specification Revision 1.2](https://picture.iczhiku.com/resource/eetop/wYkDdSdtJRuwWnvX.pdf) 9.1 Interrupt Routing
+
+use {
+    super::{Configuration, FunctionAddress, FunctionWithAddress},
+    crate::{
+        acpi::{
+            machine_language::{self, device, interrupt_routing},
+            multiple_apic_description,
+        },
+        com2_println, interrupt,
+        sync::spin,
+        Argument,
+    },
+    alloc::{collections::BTreeMap, vec::Vec},
+    core::cell::RefCell,
+};
+
+pub const PCI_EXPRESS_ROOT_BRIDGE: &str = "PNP0A08";
+pub const PCI_ROOT_BRIDGE: &str = "PNP0A03";
+
+/// # Routes of the functions which have an interrupt pin
+/// Routing evaluates `_PRT` and may configure interrupt link devices, so every function is routed once by `initialize`.
+static ROUTES: spin::Lock<BTreeMap<FunctionAddress, Route>> = spin::Lock::new(BTreeMap::new());
+
+/// # Switch the firmware to the APIC interrupt model and route the INTx pins of the functions
+/// `_PRT` may return routes to the 8259 PIC until `_PIC(1)` is evaluated.
+pub fn initialize(configuration: &Configuration) {
+    let root: &machine_language::reference::Node = machine_language::root();
+    if !interrupt_routing::set_interrupt_model(root, interrupt_routing::InterruptModel::Apic) {
+        com2_println!("The firmware has no _PIC.");
+    }
+    let devices = device::Node::enumerate(root);
+    let root_bridges: Vec<(u16, u8, &device::Node)> = devices
+        .devices()
+        .into_iter()
+        .filter(|device| {
+            device.is_compatible(PCI_EXPRESS_ROOT_BRIDGE) || device.is_compatible(PCI_ROOT_BRIDGE)
+        })
+        .map(|root_bridge| {
+            let seg: machine_language::name::Path = root_bridge.path().clone() + "_SEG".into();
+            let segment_number: u16 = root
+                .evaluate(&seg, Vec::new())
+                .and_then(|seg| seg.get_integer())
+                .map_or(0, |seg| seg as u16);
+            let bbn: machine_language::name::Path = root_bridge.path().clone() + "_BBN".into();
+            let bus_number: u8 = root
+                .evaluate(&bbn, Vec::new())
+                .and_then(|bbn| bbn.get_integer())
+                .map_or(0, |bbn| bbn as u8);
+            (segment_number, bus_number, root_bridge)
+        })
+        .collect();
+    let router = Router {
+        configuration,
+        root,
+        root_bridges,
+        tables: RefCell::new(BTreeMap::new()),
+    };
+    let madt: &multiple_apic_description::Table =
+        Argument::get().efi_system_table().rsdp().xsdt().madt();
+    let routes: BTreeMap<FunctionAddress, Route> = configuration
+        .functions()
+        .filter_map(|function| {
+            let pin: Pin = function
+                .function()
+                .header()
+                .interrupt_pin()
+                .try_into()
+                .ok()?;
+            let route: Route = router
+                .route(
+                    function.segment_number(),
+                    function.bus_number(),
+                    function.device_number(),
+                    pin.into(),
+                )
+                .map(Route::from)?
+                .overridden(madt);
+            Some((function.address(), route))
+        })
+        .collect();
+    *ROUTES.lock() = routes;
+}
+
+/// # The global system interrupt which an INTx pin of a function is routed to
+pub fn route(function: FunctionAddress) -> Option<Route> {
+    ROUTES.lock().get(&function).copied()
+}
+
+/// # Interrupt Pin
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Pin {
+    IntA,
+    IntB,
+    IntC,
+    IntD,
+}
+
+impl From<Pin> for u8 {
+    /// `_PRT` counts pins from 0.
+    fn from(pin: Pin) -> Self {
+        match pin {
+            Pin::IntA => 0,
+            Pin::IntB => 1,
+            Pin::IntC => 2,
+            Pin::IntD => 3,
+        }
+    }
+}
+
+impl TryFrom<u8> for Pin {
+    type Error = ();
+
+    /// The interrupt pin register counts pins from 1.
+    fn try_from(interrupt_pin: u8) -> Result<Self, Self::Error> {
+        match interrupt_pin {
+            1 => Ok(Self::IntA),
+            2 => Ok(Self::IntB),
+            3 => Ok(Self::IntC),
+            4 => Ok(Self::IntD),
+            _ => Err(()),
+        }
+    }
+}
+
+/// # A routed PCI interrupt
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Route {
+    pub global_system_interrupt: u32,
+    pub polarity: interrupt::apic::Polarity,
+    pub trigger_mode: interrupt::apic::TriggerMode,
+}
+
+impl Route {
+    /// # Redirect the interrupt to a local APIC through the I/O APIC which has the global system interrupt
+    /// Returns false if no I/O APIC has it.
+    pub fn redirect(&self, local_apic_id: u8, interrupt_number: u8) -> bool {
+        let Some(io_apic) = Argument::get()
+            .efi_system_table_mut()
+            .rsdp_mut()
+            .xsdt_mut()
+            .madt_mut()
+            .io_apic_mut_for(self.global_system_interrupt)
+        else {
+            return false;
+        };
+        let irq: u32 = self.global_system_interrupt - io_apic.global_system_interrupt_base();
+        io_apic.registers_mut().redirect_with_signal(
+            irq as u8,
+            local_apic_id,
+            interrupt_number,
+            self.polarity,
+            self.trigger_mode,
+        );
+        true
+    }
+
+    /// # Apply the Interrupt Source Override of the MADT
+    /// An interrupt link device may report an ISA IRQ, which is overridden like the other ISA IRQs.
+    fn overridden(self, madt: &multiple_apic_description::Table) -> Self {
+        u8::try_from(self.global_system_interrupt)
+            .ok()
+            .and_then(|irq| madt.interrupt_source_override(irq))
+            .map_or(self, |interrupt_source_override| Self {
+                global_system_interrupt: interrupt_source_override.global_system_interrupt(),
+                polarity: interrupt_source_override
+                    .polarity()
+                    .unwrap_or(self.polarity),
+                trigger_mode: interrupt_source_override
+                    .trigger_mode()
+                    .unwrap_or(self.trigger_mode),
+            })
+    }
+}
+
+impl From<interrupt_routing::Interrupt> for Route {
+    fn from(interrupt: interrupt_routing::Interrupt) -> Self {
+        let interrupt_routing::Interrupt {
+            global_system_interrupt,
+            flags,
+        } = interrupt;
+        let polarity: interrupt::apic::Polarity = flags.active_low.into();
+        let trigger_mode: interrupt::apic::TriggerMode = (!flags.edge_triggered).into();
+        Self {
+            global_system_interrupt,
+            polarity,
+            trigger_mode,
+        }
+    }
+}
+
+struct Router<'a> {
+    configuration: &'a Configuration,
+    root: &'a machine_language::reference::Node<'static>,
+    root_bridges: Vec<(u16, u8, &'a device::Node)>,
+    /// `_PRT` of the bridges which have been read
+    tables: RefCell<BTreeMap<machine_language::name::Path, Option<Vec<interrupt_routing::Entry>>>>,
+}
+
+impl Router<'_> {
+    /// # Find the namespace device of the bridge whose secondary bus is the bus
+    fn bridge(&self, segment_number: u16, bus_number: u8) -> Option<&device::Node> {
+        self.root_bridges
+            .iter()
+            .find(|(root_segment_number, root_bus_number, _root_bridge)| {
+                (*root_segment_number, *root_bus_number) == (segment_number, bus_number)
+            })
+            .map(|(_root_segment_number, _root_bus_number, root_bridge)| *root_bridge)
+            .or_else(|| {
+                let bridge: FunctionWithAddress = self
+                    .configuration
+                    .upstream_bridge(segment_number, bus_number)?;
+                let adr: u64 = ((bridge.device_number() as u64) << u16::BITS)
+                    | bridge.function_number() as u64;
+                self.bridge(segment_number, bridge.bus_number())?
+                    .children()
+                    .iter()
+                    .find(|child| child.adr() == Some(adr))
+            })
+    }
+
+    fn route(
+        &self,
+        segment_number: u16,
+        bus_number: u8,
+        device_number: u8,
+        pin: u8,
+    ) -> Option<interrupt_routing::Interrupt> {
+        let table: Option<Vec<interrupt_routing::Entry>> = self
+            .bridge(segment_number, bus_number)
+            .and_then(|bridge| self.table(bridge.path()));
+        match table {
+            Some(table) => table
+                .iter()
+                .find(|entry| entry.device == device_number as u16 && entry.pin == pin)
+                .and_then(|entry| entry.interrupt(self.root)),
+            None => {
+                let bridge: FunctionWithAddress = self
+                    .configuration
+                    .upstream_bridge(segment_number, bus_number)?;
+                let pin: u8 = (pin + device_number) % 4;
+                self.route(
+                    segment_number,
+                    bridge.bus_number(),
+                    bridge.device_number(),
+                    pin,
+                )
+            }
+        }
+    }
+
+    /// # Read `_PRT` of a bridge once
+    fn table(
+        &self,
+        bridge: &machine_language::name::Path,
+    ) -> Option<Vec<interrupt_routing::Entry>> {
+        self.tables
+            .borrow_mut()
+            .entry(bridge.clone())
+            .or_insert_with(|| interrupt_routing::table(self.root, bridge))
+            .clone()
+    }
+}
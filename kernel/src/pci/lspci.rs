@@ -0,0 +1,386 @@
+//! # lspci-like Listing
+//! A function is printed in one line with its address, class, vendor and device like `lspci -nn`.
+//! The verbose mode adds the regions with their sizes, the interrupt routed by `_PRT`, the MSI and MSI-X state and the list of capabilities, and the very verbose mode decodes MSI, MSI-X, Power Management, PCI Express and Advanced Error Reporting like `lspci -vv`.
+//! ## References
+//! * [lspci(8)](https://man7.org/linux/man-pages/man8/lspci.8.html)
+
+use {
+    super::{
+        base, capability, class, command, expansion_rom_base_address, extended_capability, ids,
+        interrupt_routing, resource, Configuration, FunctionAddress, FunctionWithAddress, Header,
+    },
+    alloc::{format, string::String},
+    core::fmt,
+};
+
+const BASE_ADDRESS_REGISTERS: u16 = 0x10;
+const TYPE0_EXPANSION_ROM_BASE_ADDRESS: u16 = 0x30;
+const TYPE1_EXPANSION_ROM_BASE_ADDRESS: u16 = 0x38;
+
+/// # How much is printed for each function
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Verbosity {
+    /// The address, class, vendor and device like `lspci -nn`
+    Normal,
+    /// The regions, interrupts and the capability list like `lspci -v`
+    Verbose,
+    /// The decoded capabilities like `lspci -vv`
+    VeryVerbose,
+}
+
+/// # All functions in a configuration
+pub struct Listing<'a> {
+    configuration: &'a Configuration,
+    verbosity: Verbosity,
+}
+
+impl<'a> Listing<'a> {
+    pub fn new(configuration: &'a Configuration, verbosity: Verbosity) -> Self {
+        Self {
+            configuration,
+            verbosity,
+        }
+    }
+}
+
+impl fmt::Display for Listing<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.configuration.functions().try_for_each(|function| {
+            let entry = Entry::new(&function, self.verbosity);
+            write!(formatter, "{}", entry)
+        })
+    }
+}
+
+/// # A function
+pub struct Entry<'a> {
+    function: &'a FunctionWithAddress<'a>,
+    verbosity: Verbosity,
+}
+
+impl<'a> Entry<'a> {
+    pub fn new(function: &'a FunctionWithAddress<'a>, verbosity: Verbosity) -> Self {
+        Self {
+            function,
+            verbosity,
+        }
+    }
+
+    fn write_capabilities(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let function: &FunctionWithAddress = self.function;
+        function.capabilities().try_for_each(|pointer| {
+            let id: capability::Id = capability::header(function.function(), pointer)
+                .capability_id()
+                .into();
+            write!(formatter, "\tCapabilities: [{:02x}] {}", pointer, id)?;
+            let structure = capability::Structure::new(function, pointer);
+            match &structure {
+                capability::Structure::Msi(msi) => {
+                    let control = msi.message_control();
+                    write!(
+                        formatter,
+                        ": Enable{} Count={}/{} Maskable{} 64bit{}",
+                        Flag(control.is_enabled()),
+                        control.enabled_vectors(),
+                        control.capable_vectors(),
+                        Flag(control.is_maskable()),
+                        Flag(control.is_64bit()),
+                    )?;
+                }
+                capability::Structure::MsiX(msi_x) => {
+                    let msi_x = msi_x.structure();
+                    let control = msi_x.message_control();
+                    write!(
+                        formatter,
+                        ": Enable{} Count={} Masked{}",
+                        Flag(control.is_enabled()),
+                        msi_x.table_length(),
+                        Flag(control.is_masked()),
+                    )?;
+                }
+                capability::Structure::PciExpress(pci_express) => {
+                    write!(formatter, " {}", pci_express.capability())?;
+                }
+                capability::Structure::PciPowerManagementInterface(power_management) => {
+                    write!(
+                        formatter,
+                        " version {}",
+                        power_management.pmc().specification_version()
+                    )?;
+                }
+                _ => {}
+            }
+            writeln!(formatter)?;
+            if self.verbosity == Verbosity::VeryVerbose {
+                match &structure {
+                    capability::Structure::Msi(msi) => write_decoded(formatter, msi)?,
+                    capability::Structure::MsiX(msi_x) => {
+                        write_decoded(formatter, msi_x.structure())?
+                    }
+                    capability::Structure::PciExpress(pci_express) => {
+                        write_decoded(formatter, pci_express)?
+                    }
+                    capability::Structure::PciPowerManagementInterface(power_management) => {
+                        write_decoded(formatter, power_management)?
+                    }
+                    _ => {}
+                }
+            }
+            Ok(())
+        })?;
+        function.extended_capabilities().try_for_each(|pointer| {
+            let header: extended_capability::Header =
+                extended_capability::header(function.address(), pointer);
+            write!(
+                formatter,
+                "\tCapabilities: [{:03x} v{}] {}",
+                pointer,
+                header.version(),
+                header.id()
+            )?;
+            let structure: Option<extended_capability::Structure> =
+                extended_capability::Structure::new(function.address(), pointer);
+            if let Some(extended_capability::Structure::DeviceSerialNumber(device_serial_number)) =
+                &structure
+            {
+                let serial_number: [u8; 8] = device_serial_number.serial_number().to_be_bytes();
+                serial_number
+                    .iter()
+                    .enumerate()
+                    .try_for_each(|(index, byte)| {
+                        let separator: char = if index == 0 { ' ' } else { '-' };
+                        write!(formatter, "{}{:02x}", separator, byte)
+                    })?;
+            }
+            writeln!(formatter)?;
+            if self.verbosity == Verbosity::VeryVerbose {
+                if let Some(extended_capability::Structure::AdvancedErrorReporting(
+                    advanced_error_reporting,
+                )) = &structure
+                {
+                    write_decoded(formatter, advanced_error_reporting)?;
+                }
+            }
+            Ok(())
+        })
+    }
+
+    fn write_regions(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let function: &FunctionWithAddress = self.function;
+        let header: Header = function.function().header();
+        let command: command::Register = header.command();
+        let index2address: base::Index2Address = header.index2address();
+        index2address.iter().try_for_each(|(index, address)| {
+            let register: u16 = BASE_ADDRESS_REGISTERS + 4 * (index as u16);
+            let size: Option<u64> = resource::size(function.address(), register);
+            match *address {
+                base::Address::Io { address } if size.is_some() || address != 0 => {
+                    write!(
+                        formatter,
+                        "\tRegion {}: I/O ports at {:04x}",
+                        index, address
+                    )?;
+                    if !command.decodes_io() {
+                        write!(formatter, " [disabled]")?;
+                    }
+                    write_size(formatter, size)
+                }
+                base::Address::Memory {
+                    address,
+                    prefetchable,
+                } if size.is_some() || address != 0 => {
+                    write!(
+                        formatter,
+                        "\tRegion {}: Memory at {:08x} ({})",
+                        index,
+                        address,
+                        if prefetchable {
+                            "prefetchable"
+                        } else {
+                            "non-prefetchable"
+                        }
+                    )?;
+                    if !command.decodes_memory() {
+                        write!(formatter, " [disabled]")?;
+                    }
+                    write_size(formatter, size)
+                }
+                _ => Ok(()),
+            }
+        })?;
+        let expansion_rom: expansion_rom_base_address::Register =
+            header.expansion_rom_base_address();
+        let register: u16 = if header.bus_numbers().is_some() {
+            TYPE1_EXPANSION_ROM_BASE_ADDRESS
+        } else {
+            TYPE0_EXPANSION_ROM_BASE_ADDRESS
+        };
+        let size: Option<u64> = resource::size(function.address(), register);
+        if size.is_some() || expansion_rom.address() != 0 {
+            write!(
+                formatter,
+                "\tExpansion ROM at {:08x}",
+                expansion_rom.address()
+            )?;
+            if !expansion_rom.is_enabled() {
+                write!(formatter, " [disabled]")?;
+            }
+            write_size(formatter, size)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Entry<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let function: &FunctionWithAddress = self.function;
+        let header: Header = function.function().header();
+        let class_register: class::Register = header.class_register();
+        let class_code: class::Code = class_register.clone().into();
+        let vendor_id: u16 = header.vendor_id();
+        let device_id: u16 = header.device_id();
+        writeln!(
+            formatter,
+            "{} {} [{:02x}{:02x}]: {} {} [{:04x}:{:04x}] (rev {:02x})",
+            function.address(),
+            class_code,
+            class_register.base_class(),
+            class_register.sub_class(),
+            Vendor(vendor_id),
+            Device(vendor_id, device_id),
+            vendor_id,
+            device_id,
+            header.revision_id()
+        )?;
+        if self.verbosity == Verbosity::Normal {
+            return Ok(());
+        }
+        if let Some((subsystem_vendor_id, subsystem_id)) = header
+            .subsystem_vendor_id()
+            .zip(header.subsystem_id())
+            .filter(|(subsystem_vendor_id, _)| *subsystem_vendor_id != 0)
+        {
+            writeln!(
+                formatter,
+                "\tSubsystem: {} Device [{:04x}:{:04x}]",
+                Vendor(subsystem_vendor_id),
+                subsystem_vendor_id,
+                subsystem_id
+            )?;
+        }
+        if let Some((primary, secondary, subordinate)) = header.bus_numbers() {
+            writeln!(
+                formatter,
+                "\tBus: primary={:02x}, secondary={:02x}, subordinate={:02x}",
+                primary, secondary, subordinate
+            )?;
+        }
+        let command: command::Register = header.command();
+        writeln!(
+            formatter,
+            "\tControl: I/O{} Mem{} BusMaster{} DisINTx{}",
+            Flag(command.decodes_io()),
+            Flag(command.decodes_memory()),
+            Flag(command.is_bus_master()),
+            Flag(command.is_interrupt_disabled())
+        )?;
+        let interrupt_pin: u8 = header.interrupt_pin();
+        if (1..=4).contains(&interrupt_pin) {
+            write!(
+                formatter,
+                "\tInterrupt: pin {}",
+                char::from(b'A' + interrupt_pin - 1)
+            )?;
+            match interrupt_routing::route(function.address()) {
+                Some(route) => writeln!(
+                    formatter,
+                    " routed to IRQ {}",
+                    route.global_system_interrupt
+                ),
+                None => writeln!(formatter, " not routed"),
+            }?;
+        }
+        self.write_regions(formatter)?;
+        self.write_capabilities(formatter)
+    }
+}
+
+/// # A device name or its ID if the database doesn't know it
+pub struct Device(pub u16, pub u16);
+
+impl fmt::Display for Device {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self(vendor_id, device_id) = *self;
+        match ids::device(vendor_id, device_id) {
+            Some(device) => write!(formatter, "{}", device),
+            None => write!(formatter, "Device {:04x}", device_id),
+        }
+    }
+}
+
+/// # `+` or `-` for an enabled or disabled feature
+pub struct Flag(pub bool);
+
+impl fmt::Display for Flag {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self(flag) = self;
+        write!(formatter, "{}", if *flag { '+' } else { '-' })
+    }
+}
+
+/// # A size with a binary unit like `[size=16K]`
+struct Size(u64);
+
+impl fmt::Display for Size {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self(size) = *self;
+        let (size, unit): (u64, &str) = ["K", "M", "G", "T"]
+            .into_iter()
+            .try_fold((size, ""), |(size, unit), larger_unit| {
+                if size != 0 && size % 1024 == 0 {
+                    Ok((size / 1024, larger_unit))
+                } else {
+                    Err((size, unit))
+                }
+            })
+            .unwrap_or_else(|size_and_unit| size_and_unit);
+        write!(formatter, "{}{}", size, unit)
+    }
+}
+
+/// # A vendor name or its ID if the database doesn't know it
+pub struct Vendor(pub u16);
+
+impl fmt::Display for Vendor {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self(vendor_id) = *self;
+        match ids::vendor(vendor_id) {
+            Some(vendor) => write!(formatter, "{}", vendor),
+            None => write!(formatter, "Vendor {:04x}", vendor_id),
+        }
+    }
+}
+
+/// # The vendor and device names of a function in the current configuration space
+pub fn name(function: FunctionAddress) -> (Vendor, Device) {
+    let ids: u32 = function.read(0);
+    let vendor_id: u16 = ids as u16;
+    let device_id: u16 = (ids >> u16::BITS) as u16;
+    (Vendor(vendor_id), Device(vendor_id, device_id))
+}
+
+/// # Write the decoded registers of a capability indented under its line
+fn write_decoded(formatter: &mut fmt::Formatter<'_>, structure: &dyn fmt::Display) -> fmt::Result {
+    let decoded: String = format!("{}", structure);
+    decoded
+        .lines()
+        .try_for_each(|line| writeln!(formatter, "\t\t{}", line))
+}
+
+fn write_size(formatter: &mut fmt::Formatter<'_>, size: Option<u64>) -> fmt::Result {
+    match size {
+        Some(size) => writeln!(formatter, " [size={}]", Size(size)),
+        None => writeln!(formatter),
+    }
+}
@@ -0,0 +1,332 @@
+//! # PCI Power Management and Resets
+//! A function is moved between D0 and the low power states through the PCI Power Management capability.
+//! The configuration is saved when the function leaves D0 and written back when it returns to D0, because a soft reset on D3hot to D0 or a system sleep clears it.
+//! The configurations of all functions, including bridges and functions without drivers, are saved before the system sleeps and written back after it wakes.
+//! A function is reset by Function Level Reset, by D3hot to D0 transitions or by Secondary Bus Reset of the upstream bridge, and its configuration is restored after the reset.
+//! ## References
+//! * [PCI Power Management Interface Specification Revision 1.2](https://lekensteyn.nl/files/docs/PCI_Power_Management_12.pdf) 5.6.1. Power State Transitions
+//! * [PCI Express Base Specification Revision 5.0 Version 1.0](https://picture.iczhiku.com/resource/eetop/SYkDTqhOLhpUTnMx.pdf) 6.6 PCI Express Reset - Rules
+//! * [PCI Express Base Specification Revision 5.0 Version 1.0](https://picture.iczhiku.com/resource/eetop/SYkDTqhOLhpUTnMx.pdf) 6.6.2 Function Level Reset (FLR)
+//! * [PCI Express Base Specification Revision 5.0 Version 1.0](https://picture.iczhiku.com/resource/eetop/SYkDTqhOLhpUTnMx.pdf) 7.5.1.3.13 Bridge Control Register (Offset 3Eh)
+
+use {
+    super::{
+        bridge_control,
+        capability::{
+            self,
+            pci_express::device,
+            pci_power_management::{pmc, pmcsr},
+        },
+        header_type, Configuration, FunctionAddress,
+    },
+    crate::{com2_println, sync::spin, timer},
+    alloc::{collections::btree_map::BTreeMap, vec::Vec},
+};
+
+pub use pmcsr::PowerState;
+
+const COMMAND_AND_STATUS: u16 = 0x04;
+const BUS_NUMBERS: u16 = 0x18;
+const SECONDARY_STATUS: u16 = 0x1c;
+const BRIDGE_CONTROL: u16 = 0x3c;
+const HEADER_LENGTH: u16 = 0x40;
+
+/// Delay after a transition from or to D3hot
+const D3HOT_DELAY_MILLISECONDS: usize = 10;
+/// Delay after a transition from or to D2
+const D2_DELAY_MICROSECONDS: usize = 200;
+/// Delay after a reset before the first configuration request
+const RESET_DELAY_MILLISECONDS: usize = 100;
+/// Duration to hold Secondary Bus Reset
+const SECONDARY_BUS_RESET_MILLISECONDS: usize = 2;
+/// A function may keep returning Configuration Request Retry Status until this time after a reset.
+const READY_TIMEOUT_MILLISECONDS: usize = 1000;
+
+static STATES: spin::Lock<BTreeMap<FunctionAddress, State>> = spin::Lock::new(BTreeMap::new());
+
+/// # Read the power state of a function
+pub fn power_state(function: FunctionAddress) -> Option<PowerState> {
+    let power_management: u16 = function.capability(capability::Id::PciPowerManagementInterface)?;
+    Some(read_pmcsr(function, power_management).state())
+}
+
+/// # Reset a function by the first method which it supports
+/// Returns the method which reset the function.
+pub fn reset(configuration: &Configuration, function: FunctionAddress) -> Option<Method> {
+    Method::ALL
+        .into_iter()
+        .find(|method| match reset_by(configuration, function, *method) {
+            Ok(()) => true,
+            Err(error) => {
+                com2_println!("PCI {} {:?} failed. {:?}", function, method, error);
+                false
+            }
+        })
+}
+
+/// # Reset a function by a method
+/// Secondary Bus Reset resets all functions under the upstream bridge, and their configurations are restored too.
+pub fn reset_by(
+    configuration: &Configuration,
+    function: FunctionAddress,
+    method: Method,
+) -> Result<(), Error> {
+    match method {
+        Method::FunctionLevel => {
+            let pci_express: u16 = function
+                .capability(capability::Id::PciExpress)
+                .ok_or(Error::NoPciExpress)?;
+            let device_capabilities: device::capabilities::Register =
+                function.read(pci_express + 4).into();
+            if !device_capabilities.is_flr_capable() {
+                return Err(Error::Unsupported);
+            }
+            let state = State::save(function);
+            let device_status = || -> device::status::Register {
+                ((function.read(pci_express + 8) >> u16::BITS) as u16).into()
+            };
+            if (0..10).all(|_| {
+                let pending: bool = device_status().is_transaction_pending();
+                if pending {
+                    timer::acpi::wait_milliseconds(10);
+                }
+                pending
+            }) {
+                com2_println!("PCI {} resets with pending transactions.", function);
+            }
+            let device_control: device::control::Register =
+                (function.read(pci_express + 8) as u16).into();
+            function.write(
+                pci_express + 8,
+                u16::from(device_control.initiate_function_level_reset()) as u32,
+            );
+            wait_ready([function])?;
+            state.restore(function);
+        }
+        Method::PowerManagement => {
+            let power_management: u16 = function
+                .capability(capability::Id::PciPowerManagementInterface)
+                .ok_or(Error::NoPowerManagement)?;
+            let pmcsr: pmcsr::Register = read_pmcsr(function, power_management);
+            if pmcsr.is_no_soft_reset() || pmcsr.state() != PowerState::D0 {
+                return Err(Error::Unsupported);
+            }
+            set_power_state(function, PowerState::D3Hot)?;
+            set_power_state(function, PowerState::D0)?;
+        }
+        Method::SecondaryBus => {
+            let bridge: FunctionAddress = configuration
+                .upstream_bridge(function.segment, function.bus)
+                .ok_or(Error::NoUpstreamBridge)?
+                .address();
+            reset_secondary_bus(bridge)?;
+        }
+    }
+    com2_println!("PCI {} is reset by {:?}.", function, method);
+    Ok(())
+}
+
+/// # Reset the secondary bus of a bridge
+/// The link under the bridge is retrained, and the configurations of the functions under the bridge are saved before the reset and restored after it.
+pub fn reset_secondary_bus(bridge: FunctionAddress) -> Result<(), Error> {
+    let bus_numbers: u32 = bridge.read(BUS_NUMBERS);
+    let secondary_bus_number: u8 = (bus_numbers >> 8) as u8;
+    let subordinate_bus_number: u8 = (bus_numbers >> 16) as u8;
+    // The buses are numbered depth first, so upstream bridges are restored before the functions under them.
+    let states: BTreeMap<FunctionAddress, State> = (secondary_bus_number..=subordinate_bus_number)
+        .flat_map(|bus| FunctionAddress::bus(bridge.segment, bus))
+        .map(|function| (function, State::save(function)))
+        .collect();
+    let interrupt_and_bridge_control: u32 = bridge.read(BRIDGE_CONTROL);
+    let bridge_control: bridge_control::Register =
+        ((interrupt_and_bridge_control >> u16::BITS) as u16).into();
+    let write_bridge_control = |bridge_control: bridge_control::Register| {
+        bridge.write(
+            BRIDGE_CONTROL,
+            (interrupt_and_bridge_control & 0xffff)
+                | ((u16::from(bridge_control) as u32) << u16::BITS),
+        )
+    };
+    write_bridge_control(bridge_control.assert_secondary_bus_reset());
+    timer::acpi::wait_milliseconds(SECONDARY_BUS_RESET_MILLISECONDS);
+    write_bridge_control(bridge_control.deassert_secondary_bus_reset());
+    wait_ready(states.keys().copied())?;
+    states
+        .iter()
+        .for_each(|(function, state)| state.restore(*function));
+    Ok(())
+}
+
+/// # Restore the configurations saved by `save_all` after the system wakes
+pub fn restore_all(states: &BTreeMap<FunctionAddress, State>) {
+    states
+        .iter()
+        .for_each(|(function, state)| state.restore(*function));
+}
+
+/// # Save the configurations of all functions before the system sleeps
+/// The buses are numbered depth first, so restoring them in the order of their addresses brings bridges back with their bus numbers and windows before the functions under them.
+pub fn save_all(configuration: &Configuration) -> BTreeMap<FunctionAddress, State> {
+    configuration
+        .functions()
+        .map(|function| (function.address(), State::save(function.address())))
+        .collect()
+}
+
+/// # Move a function to a power state
+/// The configuration is saved when the function leaves D0 and restored when it returns to D0.
+/// A function in D1, D2 or D3hot can only move to a deeper state or to D0.
+pub fn set_power_state(function: FunctionAddress, state: PowerState) -> Result<(), Error> {
+    let power_management: u16 = function
+        .capability(capability::Id::PciPowerManagementInterface)
+        .ok_or(Error::NoPowerManagement)?;
+    let pmc: pmc::Register = ((function.read(power_management) >> u16::BITS) as u16).into();
+    let pmcsr: pmcsr::Register = read_pmcsr(function, power_management);
+    let current: PowerState = pmcsr.state();
+    match state {
+        PowerState::D1 if !pmc.supports_d1() => return Err(Error::Unsupported),
+        PowerState::D2 if !pmc.supports_d2() => return Err(Error::Unsupported),
+        PowerState::D0 => {}
+        state if state < current => return Err(Error::InvalidTransition { current, state }),
+        _ => {}
+    }
+    if current != state {
+        if current == PowerState::D0 {
+            STATES.lock().insert(function, State::save(function));
+        }
+        function.write(
+            power_management + 4,
+            u16::from(pmcsr.without_pme_status().with_state(state)) as u32,
+        );
+        if current == PowerState::D3Hot || state == PowerState::D3Hot {
+            timer::acpi::wait_milliseconds(D3HOT_DELAY_MILLISECONDS);
+        } else if current == PowerState::D2 || state == PowerState::D2 {
+            timer::acpi::wait_microseconds(D2_DELAY_MICROSECONDS);
+        }
+    }
+    if state == PowerState::D0 {
+        let saved: Option<State> = STATES.lock().remove(&function);
+        if let Some(saved) = saved {
+            saved.restore(function);
+        }
+    }
+    Ok(())
+}
+
+#[derive(Debug)]
+pub enum Error {
+    InvalidTransition {
+        current: PowerState,
+        state: PowerState,
+    },
+    NoPciExpress,
+    NoPowerManagement,
+    NoUpstreamBridge,
+    /// The function doesn't respond after a reset.
+    NotReady,
+    Unsupported,
+}
+
+/// # Reset Methods
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Method {
+    /// Initiate Function Level Reset in the PCI Express Device Control register
+    FunctionLevel,
+    /// D0 to D3hot and back to D0 on a function without No_Soft_Reset
+    PowerManagement,
+    /// Secondary Bus Reset in the Bridge Control register of the upstream bridge
+    SecondaryBus,
+}
+
+impl Method {
+    /// From the narrowest effect
+    const ALL: [Self; 3] = [
+        Self::FunctionLevel,
+        Self::PowerManagement,
+        Self::SecondaryBus,
+    ];
+}
+
+/// # Saved configuration registers of a function
+/// It holds the header, the control registers of the PCI Express capability and the MSI and MSI-X capabilities in the order to write them back.
+#[derive(Debug)]
+pub struct State {
+    registers: Vec<(u16, u32)>,
+}
+
+impl State {
+    pub fn save(function: FunctionAddress) -> Self {
+        let mut registers: Vec<(u16, u32)> = Vec::new();
+        // Device, Link, Slot, Root, Device 2 and Link 2 controls without their status
+        if let Some(pci_express) = function.capability(capability::Id::PciExpress) {
+            registers.extend(
+                [0x08, 0x10, 0x18, 0x1c, 0x28, 0x30]
+                    .into_iter()
+                    .map(|offset| pci_express + offset)
+                    .map(|register| (register, function.read(register) & 0xffff)),
+            );
+        }
+        // The header from its end so that the command register is written after the base addresses.
+        let is_bridge: bool = matches!(function.header_type().into(), header_type::Type::One);
+        registers.extend(
+            (COMMAND_AND_STATUS..HEADER_LENGTH)
+                .step_by(4)
+                .rev()
+                .map(|register| {
+                    let value: u32 = function.read(register);
+                    let value: u32 = match register {
+                        COMMAND_AND_STATUS => value & 0xffff,
+                        SECONDARY_STATUS if is_bridge => value & 0xffff,
+                        _ => value,
+                    };
+                    (register, value)
+                }),
+        );
+        // MSI with its control at last so that it is enabled after the message is written.
+        if let Some(msi) = function.capability(capability::Id::Msi) {
+            let message_control: u32 = function.read(msi) >> u16::BITS;
+            let is_64bit: bool = message_control & 0x0080 != 0;
+            let per_vector_masking: bool = message_control & 0x0100 != 0;
+            let length: u16 =
+                0x0c + if is_64bit { 4 } else { 0 } + if per_vector_masking { 8 } else { 0 };
+            registers.extend(
+                (msi + 4..msi + length)
+                    .step_by(4)
+                    .chain([msi])
+                    .map(|register| (register, function.read(register))),
+            );
+        }
+        if let Some(msi_x) = function.capability(capability::Id::MsiX) {
+            registers.push((msi_x, function.read(msi_x)));
+        }
+        Self { registers }
+    }
+
+    pub fn restore(&self, function: FunctionAddress) {
+        self.registers
+            .iter()
+            .for_each(|(register, value)| function.write(*register, *value));
+    }
+}
+
+fn read_pmcsr(function: FunctionAddress, power_management: u16) -> pmcsr::Register {
+    (function.read(power_management + 4) as u16).into()
+}
+
+/// # Wait for functions to respond after a reset
+fn wait_ready(functions: impl IntoIterator<Item = FunctionAddress>) -> Result<(), Error> {
+    timer::acpi::wait_milliseconds(RESET_DELAY_MILLISECONDS);
+    functions.into_iter().try_for_each(|function| {
+        (0..READY_TIMEOUT_MILLISECONDS / 10)
+            .any(|_| {
+                let vendor_id: u16 = function.read(0) as u16;
+                let ready: bool = vendor_id != 0xffff && vendor_id != 0x0001;
+                if !ready {
+                    timer::acpi::wait_milliseconds(10);
+                }
+                ready
+            })
+            .then_some(())
+            .ok_or(Error::NotReady)
+    })
+}
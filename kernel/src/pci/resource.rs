@@ -0,0 +1,1074 @@
+//! # PCI Resource Allocation
+//! Every BAR and expansion ROM is sized by writing all ones and reading it back while the function decodes neither I/O nor memory.
+//! Bus numbers given to a PCI to PCI bridge by the firmware are kept if they are under the upstream bus and overlap no other bridge, and the other bridges are numbered depth first from the free bus numbers.
+//! An address assigned by the firmware is kept if it is in a window of the upstream bridge and overlaps nothing else, and the other resources and the closed bridge windows are assigned from the windows.
+//! The windows of a root bridge are the address space descriptors in its `_CRS`, and a memory aperture above 4 GiB holds the 64-bit prefetchable resources.
+//! The programmed registers are kept and written again after the system wakes, before the saved configurations of the functions are restored.
+//! ## References
+//! * [PCI Express Base Specification Revision 5.0 Version 1.0](https://picture.iczhiku.com/resource/eetop/SYkDTqhOLhpUTnMx.pdf) 7.5.1.2.1 Base Address Registers (Offset 10h - 24h)
+//! * [PCI Express Base Specification Revision 5.0 Version 1.0](https://picture.iczhiku.com/resource/eetop/SYkDTqhOLhpUTnMx.pdf) 7.5.1.2.4 Expansion ROM Base Address Register (Offset 30h)
+//! * [PCI Express Base Specification Revision 5.0 Version 1.0](https://picture.iczhiku.com/resource/eetop/SYkDTqhOLhpUTnMx.pdf) 7.5.1.3 Type 1 Configuration Space Header
+//! * [PCI-to-PCI Bridge Architecture Specification Revision 1.2](https://picture.iczhiku.com/resource/eetop/wYkDdSdtJRuwWnvX.pdf) 4.1 Configuration
+//! * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 6.4.3.5 Address Space Resource Descriptors
+
+use {
+    super::{command, header_type, interrupt_routing, FunctionAddress},
+    crate::{
+        acpi::machine_language::{self, device, resource},
+        com2_println,
+        sync::spin,
+    },
+    alloc::{collections::btree_map::BTreeMap, vec::Vec},
+    core::{
+        cmp, iter,
+        ops::{Range, RangeInclusive},
+    },
+};
+
+const COMMAND: u16 = 0x04;
+const BASE_ADDRESS_REGISTERS: u16 = 0x10;
+const TYPE0_EXPANSION_ROM_BASE_ADDRESS: u16 = 0x30;
+const BUS_NUMBERS: u16 = 0x18;
+const IO_BASE_AND_LIMIT: u16 = 0x1c;
+const MEMORY_BASE_AND_LIMIT: u16 = 0x20;
+const PREFETCHABLE_MEMORY_BASE_AND_LIMIT: u16 = 0x24;
+const PREFETCHABLE_MEMORY_BASE_UPPER_32BITS: u16 = 0x28;
+const PREFETCHABLE_MEMORY_LIMIT_UPPER_32BITS: u16 = 0x2c;
+const IO_BASE_AND_LIMIT_UPPER_16BITS: u16 = 0x30;
+const TYPE1_EXPANSION_ROM_BASE_ADDRESS: u16 = 0x38;
+
+/// I/O ports below it are left to legacy devices.
+const IO_MINIMUM: u64 = 0x1000;
+
+/// The sizes of the BARs and expansion ROMs keyed by function and register
+static SIZES: spin::Lock<BTreeMap<(FunctionAddress, u16), u64>> = spin::Lock::new(BTreeMap::new());
+
+/// The registers programmed by `assign` keyed by function
+static ASSIGNMENTS: spin::Lock<BTreeMap<FunctionAddress, Assignment>> =
+    spin::Lock::new(BTreeMap::new());
+
+/// # Assign bus numbers and resources under every root bridge
+/// Returns the conflicts found on the way.
+pub fn assign() -> Vec<Conflict> {
+    let root_bridges: Vec<RootBridge> = RootBridge::enumerate();
+    if root_bridges.is_empty() {
+        com2_println!("There is no PCI root bridge in the namespace.");
+    }
+    let mut conflicts: Vec<Conflict> = Vec::new();
+    root_bridges
+        .into_iter()
+        .for_each(|root_bridge| root_bridge.assign(&mut conflicts));
+    conflicts
+}
+
+/// # Program the assigned bus numbers and resources again after the system wakes
+/// A secondary bus number is always above the bus of its bridge, so bridges get their bus numbers and windows before the functions under them are reached.
+pub fn restore() {
+    ASSIGNMENTS
+        .lock()
+        .iter()
+        .for_each(|(function, assignment)| assignment.write(*function));
+}
+
+/// # The size of a BAR or an expansion ROM
+/// The size is known only for a resource sized by `assign`.
+pub fn size(function: FunctionAddress, register: u16) -> Option<u64> {
+    SIZES.lock().get(&(function, register)).copied()
+}
+
+/// # A resource which can't be kept or assigned
+#[allow(dead_code)]
+#[derive(Debug)]
+pub enum Conflict {
+    /// No bus number is left for the secondary bus of a bridge.
+    BusNumber { bridge: FunctionAddress },
+    /// No space is left in the windows of the upstream bridge.
+    Exhausted {
+        function: FunctionAddress,
+        register: u16,
+        size: u64,
+    },
+    /// The range assigned by the firmware is out of the windows of the upstream bridge.
+    OutsideWindow {
+        function: FunctionAddress,
+        register: u16,
+        range: Range<u64>,
+    },
+    /// The range assigned by the firmware overlaps another resource.
+    Overlap {
+        function: FunctionAddress,
+        register: u16,
+        range: Range<u64>,
+    },
+}
+
+impl Conflict {
+    fn new(
+        collision: Collision,
+        function: FunctionAddress,
+        register: u16,
+        range: Range<u64>,
+    ) -> Self {
+        match collision {
+            Collision::OutsideWindow => Self::OutsideWindow {
+                function,
+                register,
+                range,
+            },
+            Collision::Overlap => Self::Overlap {
+                function,
+                register,
+                range,
+            },
+        }
+    }
+}
+
+/// # Address space of a resource
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Kind {
+    Io,
+    Memory,
+    /// 64-bit prefetchable memory
+    PrefetchableMemory,
+}
+
+impl Kind {
+    const ALL: [Self; 3] = [Self::Io, Self::Memory, Self::PrefetchableMemory];
+
+    /// # Granularity of a bridge window
+    fn granularity(self) -> u64 {
+        match self {
+            Self::Io => 0x1000,
+            Self::Memory | Self::PrefetchableMemory => 0x100000,
+        }
+    }
+
+    fn index(self) -> usize {
+        match self {
+            Self::Io => 0,
+            Self::Memory => 1,
+            Self::PrefetchableMemory => 2,
+        }
+    }
+
+    /// # The register which holds a bridge window
+    fn window_register(self) -> u16 {
+        match self {
+            Self::Io => IO_BASE_AND_LIMIT,
+            Self::Memory => MEMORY_BASE_AND_LIMIT,
+            Self::PrefetchableMemory => PREFETCHABLE_MEMORY_BASE_AND_LIMIT,
+        }
+    }
+}
+
+/// # A BAR or an expansion ROM
+#[derive(Debug)]
+struct Resource {
+    kind: Kind,
+    register: u16,
+    size: u64,
+    is_64bit: bool,
+    expansion_rom: bool,
+    base: Option<u64>,
+}
+
+impl Resource {
+    const ROM_ADDRESS_MASK: u32 = 0xfffff800;
+
+    /// # Size the BARs of a function
+    fn base_address_registers(function: FunctionAddress, count: u16) -> Vec<Self> {
+        let mut resources: Vec<Self> = Vec::new();
+        let mut index: u16 = 0;
+        while index < count {
+            let register: u16 = BASE_ADDRESS_REGISTERS + index * 4;
+            let (original, probed): (u32, u32) = probe(function, register, u32::MAX);
+            let resource: Option<Self> = if original & 0x1 != 0 {
+                let mask: u32 = probed & !0x3;
+                let mask: u32 = if mask >> u16::BITS == 0 {
+                    mask | 0xffff0000
+                } else {
+                    mask
+                };
+                (probed & !0x3 != 0).then(|| Self {
+                    kind: Kind::Io,
+                    register,
+                    size: (!mask).wrapping_add(1) as u64,
+                    is_64bit: false,
+                    expansion_rom: false,
+                    base: Some((original & !0x3) as u64).filter(|base| *base != 0),
+                })
+            } else {
+                let is_64bit: bool = (original >> 1) & 0x3 == 0x2;
+                let prefetchable: bool = original & 0x8 != 0;
+                let (original_high, probed_high): (u32, u32) = if is_64bit {
+                    index += 1;
+                    probe(function, register + 4, u32::MAX)
+                } else {
+                    (0, u32::MAX)
+                };
+                let mask: u64 = ((probed_high as u64) << u32::BITS) | (probed & !0xf) as u64;
+                let base: u64 = ((original_high as u64) << u32::BITS) | (original & !0xf) as u64;
+                let is_implemented: bool = probed & !0xf != 0 || (is_64bit && probed_high != 0);
+                is_implemented.then(|| Self {
+                    kind: if prefetchable && is_64bit {
+                        Kind::PrefetchableMemory
+                    } else {
+                        Kind::Memory
+                    },
+                    register,
+                    size: (!mask).wrapping_add(1),
+                    is_64bit,
+                    expansion_rom: false,
+                    base: Some(base).filter(|base| *base != 0),
+                })
+            };
+            resources.extend(resource);
+            index += 1;
+        }
+        resources
+    }
+
+    /// # Size the expansion ROM of a function
+    fn expansion_rom(function: FunctionAddress, register: u16) -> Option<Self> {
+        let (original, probed): (u32, u32) = probe(function, register, Self::ROM_ADDRESS_MASK);
+        let mask: u32 = probed & Self::ROM_ADDRESS_MASK;
+        (mask != 0).then(|| Self {
+            kind: Kind::Memory,
+            register,
+            size: (!mask).wrapping_add(1) as u64,
+            is_64bit: false,
+            expansion_rom: true,
+            base: Some((original & Self::ROM_ADDRESS_MASK) as u64).filter(|base| *base != 0),
+        })
+    }
+
+    fn range(&self) -> Option<Range<u64>> {
+        self.base.map(|base| base..base + self.size)
+    }
+
+    /// # Write the base address
+    /// The enable bit of an expansion ROM is kept.
+    fn write(&self, function: FunctionAddress) {
+        if let Some(base) = self.base {
+            let low: u32 = if self.expansion_rom {
+                base as u32 | (function.read(self.register) & 0x1)
+            } else {
+                base as u32
+            };
+            function.write(self.register, low);
+            if self.is_64bit {
+                function.write(self.register + 4, (base >> u32::BITS) as u32);
+            }
+        }
+    }
+}
+
+/// # The values of the registers programmed for a function
+#[derive(Debug)]
+struct Assignment {
+    registers: Vec<(u16, u32)>,
+    command: command::Register,
+}
+
+impl Assignment {
+    fn read(function: FunctionAddress, registers: Vec<u16>, command: command::Register) -> Self {
+        let registers: Vec<(u16, u32)> = registers
+            .into_iter()
+            .map(|register| (register, function.read(register)))
+            .collect();
+        Self { registers, command }
+    }
+
+    /// # Write the registers while the function decodes neither I/O nor memory
+    fn write(&self, function: FunctionAddress) {
+        function.write(
+            COMMAND,
+            u16::from(self.command.with_decoding(false, false)) as u32,
+        );
+        self.registers
+            .iter()
+            .for_each(|(register, value)| function.write(*register, *value));
+        function.write(COMMAND, u16::from(self.command) as u32);
+    }
+}
+
+/// # Free ranges in the windows of a bus
+#[derive(Debug, Default)]
+struct Space {
+    windows: Vec<Range<u64>>,
+    free: Vec<Range<u64>>,
+    /// Allocation starts from it.
+    minimum: u64,
+}
+
+impl Space {
+    fn new(windows: Vec<Range<u64>>, minimum: u64) -> Self {
+        let free: Vec<Range<u64>> = windows.clone();
+        Self {
+            windows,
+            free,
+            minimum,
+        }
+    }
+
+    /// # Take the first free range aligned to the alignment
+    fn allocate(&mut self, size: u64, alignment: u64) -> Option<Range<u64>> {
+        let range: Range<u64> = self.free.iter().find_map(|free| {
+            let start: u64 = free.start.max(self.minimum).next_multiple_of(alignment);
+            let end: u64 = start.checked_add(size)?;
+            (end <= free.end).then_some(start..end)
+        })?;
+        self.reserve(range.clone()).ok()?;
+        Some(range)
+    }
+
+    /// # Free bus numbers in a range
+    fn bus_numbers(bus_numbers: Range<u64>) -> Self {
+        Self::new(iter::once(bus_numbers).collect(), 0)
+    }
+
+    /// # The free range starting at an address
+    fn free_from(&self, start: u64) -> Option<Range<u64>> {
+        self.free.iter().find(|free| free.start == start).cloned()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.windows.is_empty()
+    }
+
+    /// # Take a range
+    fn reserve(&mut self, range: Range<u64>) -> Result<(), Collision> {
+        let index: usize = self
+            .free
+            .iter()
+            .position(|free| free.start <= range.start && range.end <= free.end)
+            .ok_or_else(|| {
+                if self
+                    .windows
+                    .iter()
+                    .any(|window| window.start <= range.start && range.end <= window.end)
+                {
+                    Collision::Overlap
+                } else {
+                    Collision::OutsideWindow
+                }
+            })?;
+        let free: Range<u64> = self.free.remove(index);
+        [range.end..free.end, free.start..range.start]
+            .into_iter()
+            .filter(|rest| !rest.is_empty())
+            .for_each(|rest| self.free.insert(index, rest));
+        Ok(())
+    }
+}
+
+enum Collision {
+    OutsideWindow,
+    Overlap,
+}
+
+/// # Spaces of a bus for each kind
+#[derive(Debug, Default)]
+struct Spaces([Space; 3]);
+
+impl Spaces {
+    /// # The space to allocate a resource of the kind from
+    /// Prefetchable resources fall back to the non-prefetchable memory if the bus has no prefetchable window.
+    fn get_mut(&mut self, kind: Kind) -> &mut Space {
+        let Self(spaces) = self;
+        match kind {
+            Kind::PrefetchableMemory if spaces[kind.index()].is_empty() => {
+                &mut spaces[Kind::Memory.index()]
+            }
+            kind => &mut spaces[kind.index()],
+        }
+    }
+}
+
+struct Bus {
+    number: u8,
+    functions: Vec<Function>,
+}
+
+impl Bus {
+    /// # Scan a bus and give bus numbers to the bridges on it
+    /// The bus numbers given by the firmware are reserved from the free bus numbers first.
+    /// The bus numbers of the other bridges on the bus are cleared so that none of them claims the numbers given to another one.
+    fn scan(
+        segment_number: u16,
+        number: u8,
+        bus_numbers: &mut Space,
+        conflicts: &mut Vec<Conflict>,
+    ) -> Self {
+        let addresses: Vec<FunctionAddress> = FunctionAddress::bus(segment_number, number);
+        // Keep bus numbers given by the firmware.
+        let firmware_bus_numbers: Vec<Option<RangeInclusive<u8>>> = addresses
+            .iter()
+            .map(|function| {
+                let firmware_bus_numbers: RangeInclusive<u8> = is_bridge(*function)
+                    .then(|| firmware_bus_numbers(*function, number))
+                    .flatten()?;
+                let range: Range<u64> =
+                    *firmware_bus_numbers.start() as u64..*firmware_bus_numbers.end() as u64 + 1;
+                match bus_numbers.reserve(range.clone()) {
+                    Ok(()) => Some(firmware_bus_numbers),
+                    Err(collision) => {
+                        conflicts.push(Conflict::new(collision, *function, BUS_NUMBERS, range));
+                        None
+                    }
+                }
+            })
+            .collect();
+        addresses
+            .iter()
+            .zip(firmware_bus_numbers.iter())
+            .filter(|(function, firmware_bus_numbers)| {
+                is_bridge(**function) && firmware_bus_numbers.is_none()
+            })
+            .for_each(|(bridge, _)| {
+                let latency_timer: u32 = bridge.read(BUS_NUMBERS) & 0xff000000;
+                bridge.write(BUS_NUMBERS, latency_timer);
+            });
+        let functions: Vec<Function> = addresses
+            .into_iter()
+            .zip(firmware_bus_numbers)
+            .map(|(function, firmware_bus_numbers)| {
+                Function::scan(
+                    function,
+                    number,
+                    firmware_bus_numbers,
+                    bus_numbers,
+                    conflicts,
+                )
+            })
+            .collect();
+        Self { number, functions }
+    }
+
+    /// # The highest bus number on and under the bus
+    fn last_bus_number(&self) -> u8 {
+        self.functions
+            .iter()
+            .filter_map(|function| function.bridge.as_ref())
+            .map(|bridge| *bridge.bus_numbers.end())
+            .max()
+            .unwrap_or(self.number)
+    }
+
+    /// # Keep or assign the resources on the bus and under its bridges
+    fn assign(&mut self, spaces: &mut Spaces, conflicts: &mut Vec<Conflict>) {
+        // Keep ranges assigned by the firmware.
+        self.functions.iter_mut().for_each(|function| {
+            let address: FunctionAddress = function.address;
+            function.resources.iter_mut().for_each(|resource| {
+                if let Some(range) = resource.range() {
+                    if let Err(collision) = spaces.get_mut(resource.kind).reserve(range.clone()) {
+                        conflicts.push(Conflict::new(collision, address, resource.register, range));
+                        resource.base = None;
+                    }
+                }
+            });
+            if let Some(bridge) = function.bridge.as_mut() {
+                Kind::ALL.into_iter().for_each(|kind| {
+                    if let Some(window) = bridge.windows[kind.index()].clone() {
+                        if let Err(collision) =
+                            bridge.parent_space(spaces, kind).reserve(window.clone())
+                        {
+                            conflicts.push(Conflict::new(
+                                collision,
+                                address,
+                                kind.window_register(),
+                                window,
+                            ));
+                            bridge.windows[kind.index()] = None;
+                        }
+                    }
+                });
+            }
+        });
+        // Assign the other ranges from the largest alignment.
+        let mut requests: Vec<(Request, u64, u64)> = self
+            .functions
+            .iter()
+            .enumerate()
+            .flat_map(|(function_index, function)| {
+                let resources = function
+                    .resources
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, resource)| resource.base.is_none())
+                    .map(move |(resource_index, resource)| {
+                        let request = Request::Resource {
+                            function: function_index,
+                            resource: resource_index,
+                        };
+                        (request, resource.size, resource.size)
+                    });
+                let windows = function.bridge.iter().flat_map(move |bridge| {
+                    Kind::ALL
+                        .into_iter()
+                        .filter(|kind| bridge.windows[kind.index()].is_none())
+                        .filter_map(move |kind| {
+                            let (size, alignment): (u64, u64) = bridge.requirement(kind)?;
+                            let request = Request::Window {
+                                function: function_index,
+                                kind,
+                            };
+                            Some((request, size, alignment))
+                        })
+                });
+                resources.chain(windows)
+            })
+            .collect();
+        requests.sort_by_key(|(_, _, alignment)| cmp::Reverse(*alignment));
+        requests
+            .into_iter()
+            .for_each(|(request, size, alignment)| match request {
+                Request::Resource { function, resource } => {
+                    let address: FunctionAddress = self.functions[function].address;
+                    let resource: &mut Resource = &mut self.functions[function].resources[resource];
+                    resource.base = spaces
+                        .get_mut(resource.kind)
+                        .allocate(size, alignment)
+                        .map(|range| range.start);
+                    match resource.base {
+                        Some(base) => com2_println!(
+                            "PCI {} {:#x} is assigned {:#x?}.",
+                            address,
+                            resource.register,
+                            base..base + size
+                        ),
+                        None => conflicts.push(Conflict::Exhausted {
+                            function: address,
+                            register: resource.register,
+                            size,
+                        }),
+                    }
+                }
+                Request::Window { function, kind } => {
+                    let address: FunctionAddress = self.functions[function].address;
+                    if let Some(bridge) = self.functions[function].bridge.as_mut() {
+                        let window: Option<Range<u64>> =
+                            bridge.parent_space(spaces, kind).allocate(size, alignment);
+                        match window.as_ref() {
+                            Some(window) => com2_println!(
+                                "PCI {} window {:#x?} is assigned {:#x?}.",
+                                address,
+                                kind,
+                                window
+                            ),
+                            None => conflicts.push(Conflict::Exhausted {
+                                function: address,
+                                register: kind.window_register(),
+                                size,
+                            }),
+                        }
+                        bridge.windows[kind.index()] = window;
+                    }
+                }
+            });
+        self.functions
+            .iter_mut()
+            .for_each(|function| function.program(conflicts));
+    }
+
+    /// # Sizes and alignments of the resources of the kind on the bus
+    fn requests(&self, kind: Kind) -> Vec<(u64, u64)> {
+        self.functions
+            .iter()
+            .flat_map(|function| {
+                let resources = function
+                    .resources
+                    .iter()
+                    .filter(move |resource| resource.kind == kind)
+                    .map(|resource| (resource.size, resource.size));
+                let windows = function
+                    .bridge
+                    .iter()
+                    .filter_map(move |bridge| bridge.requirement(kind));
+                resources.chain(windows)
+            })
+            .collect()
+    }
+}
+
+enum Request {
+    Resource { function: usize, resource: usize },
+    Window { function: usize, kind: Kind },
+}
+
+struct Function {
+    address: FunctionAddress,
+    command: command::Register,
+    resources: Vec<Resource>,
+    bridge: Option<Bridge>,
+}
+
+impl Function {
+    /// # Size the resources of a function and scan its secondary bus if it is a bridge
+    fn scan(
+        address: FunctionAddress,
+        bus_number: u8,
+        firmware_bus_numbers: Option<RangeInclusive<u8>>,
+        bus_numbers: &mut Space,
+        conflicts: &mut Vec<Conflict>,
+    ) -> Self {
+        let command: command::Register = (address.read(COMMAND) as u16).into();
+        address.write(
+            COMMAND,
+            u16::from(command.with_decoding(false, false)) as u32,
+        );
+        let is_bridge: bool = is_bridge(address);
+        let (count, expansion_rom): (u16, u16) = if is_bridge {
+            (2, TYPE1_EXPANSION_ROM_BASE_ADDRESS)
+        } else {
+            (6, TYPE0_EXPANSION_ROM_BASE_ADDRESS)
+        };
+        let mut resources: Vec<Resource> = Resource::base_address_registers(address, count);
+        resources.extend(Resource::expansion_rom(address, expansion_rom));
+        address.write(COMMAND, u16::from(command) as u32);
+        resources.iter().for_each(|resource| {
+            SIZES
+                .lock()
+                .insert((address, resource.register), resource.size);
+        });
+        let bridge: Option<Bridge> = is_bridge
+            .then(|| {
+                Bridge::scan(
+                    address,
+                    bus_number,
+                    firmware_bus_numbers,
+                    bus_numbers,
+                    conflicts,
+                )
+            })
+            .flatten();
+        Self {
+            address,
+            command,
+            resources,
+            bridge,
+        }
+    }
+
+    /// # Write the assigned resources and enable decoding of them
+    fn program(&mut self, conflicts: &mut Vec<Conflict>) {
+        let address: FunctionAddress = self.address;
+        address.write(
+            COMMAND,
+            u16::from(self.command.with_decoding(false, false)) as u32,
+        );
+        self.resources
+            .iter()
+            .for_each(|resource| resource.write(address));
+        let decodes = |kind: Kind| {
+            self.resources.iter().any(|resource| {
+                !resource.expansion_rom && resource.kind == kind && resource.base.is_some()
+            })
+        };
+        let mut io: bool = decodes(Kind::Io);
+        let mut memory: bool = decodes(Kind::Memory) || decodes(Kind::PrefetchableMemory);
+        let mut registers: Vec<u16> = self
+            .resources
+            .iter()
+            .flat_map(|resource| {
+                let upper: Option<u16> = resource.is_64bit.then_some(resource.register + 4);
+                [Some(resource.register), upper]
+            })
+            .flatten()
+            .collect();
+        if let Some(bridge) = self.bridge.as_mut() {
+            bridge.write_windows(address);
+            registers.extend(bridge.registers());
+            io |= bridge.windows[Kind::Io.index()].is_some();
+            memory |= bridge.windows[Kind::Memory.index()].is_some()
+                || bridge.windows[Kind::PrefetchableMemory.index()].is_some();
+            let mut spaces: Spaces = bridge.spaces();
+            bridge.secondary.assign(&mut spaces, conflicts);
+        }
+        let command: command::Register = self.command.with_decoding(
+            self.command.decodes_io() || io,
+            self.command.decodes_memory() || memory,
+        );
+        address.write(COMMAND, u16::from(command) as u32);
+        ASSIGNMENTS
+            .lock()
+            .insert(address, Assignment::read(address, registers, command));
+    }
+}
+
+struct Bridge {
+    /// Whether the bridge has an I/O window
+    io: bool,
+    /// Whether the bridge has a prefetchable memory window
+    prefetchable: bool,
+    /// Whether the prefetchable memory window can be above 4 GiB
+    prefetchable_64bit: bool,
+    /// Open windows for each kind
+    windows: [Option<Range<u64>>; 3],
+    /// The secondary and subordinate bus numbers
+    bus_numbers: RangeInclusive<u8>,
+    secondary: Bus,
+}
+
+impl Bridge {
+    /// # Give bus numbers to a bridge and scan its secondary bus
+    /// The bus numbers given by the firmware are kept, and the buses under the bridge are numbered within them.
+    /// Otherwise, the first free bus number is the secondary one.
+    /// Then the subordinate bus number is the last of the free ones following it while the secondary bus is scanned, and it is narrowed to the buses found under the bridge.
+    fn scan(
+        address: FunctionAddress,
+        bus_number: u8,
+        firmware_bus_numbers: Option<RangeInclusive<u8>>,
+        bus_numbers: &mut Space,
+        conflicts: &mut Vec<Conflict>,
+    ) -> Option<Self> {
+        let (bus_numbers, secondary): (RangeInclusive<u8>, Bus) = match firmware_bus_numbers {
+            Some(firmware_bus_numbers) => {
+                let secondary_bus_number: u8 = *firmware_bus_numbers.start();
+                let mut bus_numbers = Space::bus_numbers(
+                    secondary_bus_number as u64 + 1..*firmware_bus_numbers.end() as u64 + 1,
+                );
+                let secondary: Bus = Bus::scan(
+                    address.segment,
+                    secondary_bus_number,
+                    &mut bus_numbers,
+                    conflicts,
+                );
+                (firmware_bus_numbers, secondary)
+            }
+            None => {
+                let Some(secondary_bus_number) = bus_numbers.allocate(1, 1) else {
+                    conflicts.push(Conflict::BusNumber { bridge: address });
+                    return None;
+                };
+                let secondary_bus_number: u8 = secondary_bus_number.start as u8;
+                let first_following: u64 = secondary_bus_number as u64 + 1;
+                let following: Range<u64> = bus_numbers
+                    .free_from(first_following)
+                    .unwrap_or(first_following..first_following);
+                let latency_timer: u32 = address.read(BUS_NUMBERS) & 0xff000000;
+                let write_bus_numbers = |subordinate_bus_number: u8| {
+                    address.write(
+                        BUS_NUMBERS,
+                        latency_timer
+                            | ((subordinate_bus_number as u32) << 16)
+                            | ((secondary_bus_number as u32) << 8)
+                            | bus_number as u32,
+                    )
+                };
+                write_bus_numbers((following.end - 1) as u8);
+                let mut following_bus_numbers = Space::bus_numbers(following);
+                let secondary: Bus = Bus::scan(
+                    address.segment,
+                    secondary_bus_number,
+                    &mut following_bus_numbers,
+                    conflicts,
+                );
+                let subordinate_bus_number: u8 = secondary.last_bus_number();
+                // The buses under the bridge are in the free range following the secondary bus.
+                if secondary_bus_number < subordinate_bus_number {
+                    bus_numbers
+                        .reserve(first_following..subordinate_bus_number as u64 + 1)
+                        .ok();
+                }
+                write_bus_numbers(subordinate_bus_number);
+                (secondary_bus_number..=subordinate_bus_number, secondary)
+            }
+        };
+        let io: bool = address.read(IO_BASE_AND_LIMIT) & 0xffff != 0
+            || probe(address, IO_BASE_AND_LIMIT, 0xf0f0).1 & 0xffff != 0;
+        let prefetchable_base_and_limit: u32 = address.read(PREFETCHABLE_MEMORY_BASE_AND_LIMIT);
+        let prefetchable: bool = prefetchable_base_and_limit != 0
+            || probe(address, PREFETCHABLE_MEMORY_BASE_AND_LIMIT, 0xfff0fff0).1 != 0;
+        let prefetchable_64bit: bool = prefetchable_base_and_limit & 0xf == 0x1;
+        let windows: [Option<Range<u64>>; 3] = [
+            io.then(|| io_window(address)).flatten(),
+            memory_window(address),
+            prefetchable
+                .then(|| prefetchable_memory_window(address, prefetchable_64bit))
+                .flatten(),
+        ];
+        Some(Self {
+            io,
+            prefetchable,
+            prefetchable_64bit,
+            windows,
+            bus_numbers,
+            secondary,
+        })
+    }
+
+    /// # The space of the upstream bus to take a window of the kind from
+    fn parent_space<'a>(&self, spaces: &'a mut Spaces, kind: Kind) -> &'a mut Space {
+        match kind {
+            Kind::PrefetchableMemory if !self.prefetchable_64bit => spaces.get_mut(Kind::Memory),
+            kind => spaces.get_mut(kind),
+        }
+    }
+
+    /// # Size and alignment of the window of the kind for the resources under the bridge
+    /// The prefetchable resources are put in the memory window if the bridge has no prefetchable memory window.
+    fn requirement(&self, kind: Kind) -> Option<(u64, u64)> {
+        if !self.supports(kind) {
+            return None;
+        }
+        let mut requests: Vec<(u64, u64)> = self.secondary.requests(kind);
+        if kind == Kind::Memory && !self.supports(Kind::PrefetchableMemory) {
+            requests.extend(self.secondary.requests(Kind::PrefetchableMemory));
+        }
+        requests.sort_by_key(|(_size, alignment)| cmp::Reverse(*alignment));
+        let alignment: u64 = requests
+            .iter()
+            .map(|(_size, alignment)| *alignment)
+            .max()?
+            .max(kind.granularity());
+        let size: u64 = requests
+            .iter()
+            .fold(0u64, |end, (size, alignment)| {
+                end.next_multiple_of(*alignment) + size
+            })
+            .next_multiple_of(kind.granularity());
+        Some((size, alignment))
+    }
+
+    /// # Spaces of the secondary bus
+    fn spaces(&self) -> Spaces {
+        Spaces(
+            Kind::ALL
+                .map(|kind| Space::new(self.windows[kind.index()].iter().cloned().collect(), 0)),
+        )
+    }
+
+    fn supports(&self, kind: Kind) -> bool {
+        match kind {
+            Kind::Io => self.io,
+            Kind::Memory => true,
+            Kind::PrefetchableMemory => self.prefetchable,
+        }
+    }
+
+    /// # The bus number and window registers
+    fn registers(&self) -> Vec<u16> {
+        let mut registers: Vec<u16> = Vec::from([BUS_NUMBERS]);
+        if self.io {
+            registers.extend([IO_BASE_AND_LIMIT, IO_BASE_AND_LIMIT_UPPER_16BITS]);
+        }
+        registers.push(MEMORY_BASE_AND_LIMIT);
+        if self.prefetchable {
+            registers.push(PREFETCHABLE_MEMORY_BASE_AND_LIMIT);
+        }
+        if self.prefetchable && self.prefetchable_64bit {
+            registers.extend([
+                PREFETCHABLE_MEMORY_BASE_UPPER_32BITS,
+                PREFETCHABLE_MEMORY_LIMIT_UPPER_32BITS,
+            ]);
+        }
+        registers
+    }
+
+    /// # Write the windows
+    /// A window without a range is closed by a base above its limit.
+    fn write_windows(&self, address: FunctionAddress) {
+        let [io, memory, prefetchable] = &self.windows;
+        if self.io {
+            let (base, limit): (u64, u64) =
+                io.as_ref().map_or((0xf000, 0), |io| (io.start, io.end - 1));
+            address.write(
+                IO_BASE_AND_LIMIT,
+                (((limit >> 8) as u32 & 0xf0) << 8) | ((base >> 8) as u32 & 0xf0),
+            );
+            address.write(
+                IO_BASE_AND_LIMIT_UPPER_16BITS,
+                (((limit >> 16) as u32) << 16) | (base >> 16) as u32 & 0xffff,
+            );
+        }
+        let (base, limit): (u64, u64) = memory
+            .as_ref()
+            .map_or((0xfff00000, 0), |memory| (memory.start, memory.end - 1));
+        address.write(
+            MEMORY_BASE_AND_LIMIT,
+            (((limit >> 16) as u32 & 0xfff0) << 16) | ((base >> 16) as u32 & 0xfff0),
+        );
+        if self.prefetchable {
+            let (base, limit): (u64, u64) = prefetchable
+                .as_ref()
+                .map_or((0xfffffffffff00000, 0), |prefetchable| {
+                    (prefetchable.start, prefetchable.end - 1)
+                });
+            address.write(
+                PREFETCHABLE_MEMORY_BASE_AND_LIMIT,
+                (((limit >> 16) as u32 & 0xfff0) << 16) | ((base >> 16) as u32 & 0xfff0),
+            );
+            if self.prefetchable_64bit {
+                address.write(
+                    PREFETCHABLE_MEMORY_BASE_UPPER_32BITS,
+                    (base >> u32::BITS) as u32,
+                );
+                address.write(
+                    PREFETCHABLE_MEMORY_LIMIT_UPPER_32BITS,
+                    (limit >> u32::BITS) as u32,
+                );
+            }
+        }
+    }
+}
+
+/// # PCI Host Bridge in the namespace
+struct RootBridge {
+    segment_number: u16,
+    bus_numbers: RangeInclusive<u8>,
+    spaces: Spaces,
+}
+
+impl RootBridge {
+    /// # Read `_SEG`, `_BBN` and `_CRS` of the root bridges
+    fn enumerate() -> Vec<Self> {
+        let root: &machine_language::reference::Node = machine_language::root();
+        let devices = device::Node::enumerate(root);
+        devices
+            .devices()
+            .into_iter()
+            .filter(|device| {
+                device.is_compatible(interrupt_routing::PCI_EXPRESS_ROOT_BRIDGE)
+                    || device.is_compatible(interrupt_routing::PCI_ROOT_BRIDGE)
+            })
+            .map(|root_bridge| {
+                let path: &machine_language::name::Path = root_bridge.path();
+                let evaluate = |name: &str| {
+                    let path: machine_language::name::Path = path.clone() + name.into();
+                    root.evaluate(&path, Vec::new())
+                        .and_then(|value| value.get_integer())
+                };
+                let segment_number: u16 = evaluate("_SEG").map_or(0, |seg| seg as u16);
+                let bus_number: u8 = evaluate("_BBN").map_or(0, |bbn| bbn as u8);
+                let descriptors: Vec<resource::Descriptor> =
+                    resource::current(root, path).unwrap_or_default();
+                let bus_numbers: RangeInclusive<u8> = descriptors
+                    .iter()
+                    .find_map(|descriptor| match descriptor {
+                        resource::Descriptor::Address {
+                            resource_type: resource::ResourceType::BusNumber,
+                            minimum,
+                            maximum,
+                            ..
+                        } => Some(*minimum as u8..=*maximum as u8),
+                        _ => None,
+                    })
+                    .unwrap_or(bus_number..=u8::MAX);
+                let apertures: Vec<Range<u64>> = descriptors
+                    .iter()
+                    .filter(|descriptor| matches!(descriptor, resource::Descriptor::Address { .. }))
+                    .filter_map(|descriptor| descriptor.memory())
+                    .filter(|aperture| !aperture.is_empty())
+                    .collect();
+                let (memory, prefetchable): (Vec<Range<u64>>, Vec<Range<u64>>) = apertures
+                    .into_iter()
+                    .partition(|aperture| aperture.start < 1 << u32::BITS);
+                let io: Vec<Range<u64>> = descriptors
+                    .iter()
+                    .filter(|descriptor| matches!(descriptor, resource::Descriptor::Address { .. }))
+                    .filter_map(|descriptor| descriptor.io())
+                    .filter(|aperture| !aperture.is_empty())
+                    .collect();
+                let spaces = Spaces([
+                    Space::new(io, IO_MINIMUM),
+                    Space::new(memory, 0),
+                    Space::new(prefetchable, 0),
+                ]);
+                com2_println!(
+                    "PCI root bridge {:?} segment {:#x} buses {:#x?} spaces {:#x?}",
+                    path,
+                    segment_number,
+                    bus_numbers,
+                    spaces
+                );
+                Self {
+                    segment_number,
+                    bus_numbers,
+                    spaces,
+                }
+            })
+            .collect()
+    }
+
+    fn assign(self, conflicts: &mut Vec<Conflict>) {
+        let Self {
+            segment_number,
+            bus_numbers,
+            mut spaces,
+        } = self;
+        let bus_number: u8 = *bus_numbers.start();
+        let mut bus_numbers =
+            Space::bus_numbers(bus_number as u64 + 1..*bus_numbers.end() as u64 + 1);
+        let mut bus: Bus = Bus::scan(segment_number, bus_number, &mut bus_numbers, conflicts);
+        bus.assign(&mut spaces, conflicts);
+    }
+}
+
+/// # Secondary and subordinate bus numbers given to a bridge by the firmware
+/// They are consistent if the primary bus number is the bus of the bridge and the secondary bus number is above it and not above the subordinate one.
+fn firmware_bus_numbers(bridge: FunctionAddress, bus_number: u8) -> Option<RangeInclusive<u8>> {
+    let bus_numbers: u32 = bridge.read(BUS_NUMBERS);
+    let primary_bus_number: u8 = bus_numbers as u8;
+    let secondary_bus_number: u8 = (bus_numbers >> 8) as u8;
+    let subordinate_bus_number: u8 = (bus_numbers >> 16) as u8;
+    (primary_bus_number == bus_number
+        && bus_number < secondary_bus_number
+        && secondary_bus_number <= subordinate_bus_number)
+        .then_some(secondary_bus_number..=subordinate_bus_number)
+}
+
+fn is_bridge(function: FunctionAddress) -> bool {
+    matches!(function.header_type().into(), header_type::Type::One)
+}
+
+/// # Write a value to a register, read it back and restore the original value
+/// Returns the original value and the read one.
+fn probe(function: FunctionAddress, register: u16, value: u32) -> (u32, u32) {
+    let original: u32 = function.read(register);
+    function.write(register, value);
+    let probed: u32 = function.read(register);
+    function.write(register, original);
+    (original, probed)
+}
+
+/// # Read the I/O window of a bridge
+/// A window at 0 is regarded as closed because the firmware leaves the registers cleared rather than assigning it.
+fn io_window(bridge: FunctionAddress) -> Option<Range<u64>> {
+    let base_and_limit: u32 = bridge.read(IO_BASE_AND_LIMIT);
+    let upper: u32 = bridge.read(IO_BASE_AND_LIMIT_UPPER_16BITS);
+    let is_32bit: bool = base_and_limit & 0xf == 0x1;
+    let (base_upper, limit_upper): (u64, u64) = if is_32bit {
+        ((upper & 0xffff) as u64, (upper >> 16) as u64)
+    } else {
+        (0, 0)
+    };
+    let base: u64 = (base_upper << 16) | (((base_and_limit & 0xf0) as u64) << 8);
+    let limit: u64 = (limit_upper << 16) | (((base_and_limit >> 8) & 0xf0) as u64) << 8 | 0xfff;
+    (base != 0 && base <= limit).then_some(base..limit + 1)
+}
+
+fn memory_window(bridge: FunctionAddress) -> Option<Range<u64>> {
+    let base_and_limit: u32 = bridge.read(MEMORY_BASE_AND_LIMIT);
+    let base: u64 = ((base_and_limit & 0xfff0) as u64) << 16;
+    let limit: u64 = (((base_and_limit >> 16) & 0xfff0) as u64) << 16 | 0xfffff;
+    (base != 0 && base <= limit).then_some(base..limit + 1)
+}
+
+fn prefetchable_memory_window(bridge: FunctionAddress, is_64bit: bool) -> Option<Range<u64>> {
+    let base_and_limit: u32 = bridge.read(PREFETCHABLE_MEMORY_BASE_AND_LIMIT);
+    let (base_upper, limit_upper): (u64, u64) = if is_64bit {
+        (
+            bridge.read(PREFETCHABLE_MEMORY_BASE_UPPER_32BITS) as u64,
+            bridge.read(PREFETCHABLE_MEMORY_LIMIT_UPPER_32BITS) as u64,
+        )
+    } else {
+        (0, 0)
+    };
+    let base: u64 = (base_upper << u32::BITS) | ((base_and_limit & 0xfff0) as u64) << 16;
+    let limit: u64 =
+        (limit_upper << u32::BITS) | (((base_and_limit >> 16) & 0xfff0) as u64) << 16 | 0xfffff;
+    (base != 0 && base <= limit).then_some(base..limit + 1)
+}
@@ -53,10 +53,37 @@ impl Registers {
             .collect()
     }
 
+    /// # The DMA mask of the controller
+    /// The controller which doesn't support 64-bit addressing accesses only the memory below 4 GiB.
+    pub fn dma_mask(&self) -> u64 {
+        if self.capability_registers().supports_64bit_addressing() {
+            u64::MAX
+        } else {
+            u32::MAX as u64
+        }
+    }
+
+    pub fn halt(&mut self) {
+        self.capability_registers_mut().halt()
+    }
+
+    pub fn number_of_slots(&self) -> usize {
+        self.capability_registers().number_of_slots()
+    }
+
     pub fn reset(&mut self) {
         self.capability_registers_mut().reset()
     }
 
+    /// # Set the Device Context Base Address Array Pointer
+    pub fn set_device_context_base_address_array(&mut self, address: u64) {
+        self.capability_registers_mut()
+            .operational_registers_mut()
+            .set_dcbaap(host_controller::operational::dcbaap::Register::create(
+                address,
+            ));
+    }
+
     fn runtime_registers(&self) -> &host_controller::runtime::Registers {
         self.capability_registers().runtime_registers()
     }
@@ -30,7 +30,6 @@ pub struct Registers {
     hcsparams2: hcsparams2::Register,
     #[allow(dead_code)]
     hcsparams3: hcsparams3::Register,
-    #[allow(dead_code)]
     hccparams1: hccparams1::Register,
     dboff: dboff::Register,
     rtsoff: rtsoff::Register,
@@ -53,6 +52,11 @@ impl Registers {
         unsafe { &*doorbell_register }
     }
 
+    pub fn supports_64bit_addressing(&self) -> bool {
+        let hccparams1: hccparams1::Register = self.hccparams1;
+        hccparams1.supports_64bit_addressing()
+    }
+
     pub fn number_of_ports(&self) -> usize {
         let hcsparams1: hcsparams1::Register = self.hcsparams1;
         hcsparams1.number_of_ports()
@@ -85,6 +89,10 @@ impl Registers {
         unsafe { &mut *operational_registers }
     }
 
+    pub fn halt(&mut self) {
+        self.operational_registers_mut().halt();
+    }
+
     pub fn reset(&mut self) {
         self.operational_registers_mut().reset();
     }
@@ -21,3 +21,10 @@ pub struct Register {
     max_psa_size: u8,
     xecp: u16,
 }
+
+impl Register {
+    /// # Whether the controller uses 64-bit addresses for its data structures
+    pub fn supports_64bit_addressing(&self) -> bool {
+        self.ac64()
+    }
+}
@@ -25,13 +25,20 @@ pub struct Registers {
     #[allow(dead_code)]
     crcr: crcr::Register,
     _1: u128,
-    #[allow(dead_code)]
     dcbaap: dcbaap::Register,
     #[allow(dead_code)]
     config: config::Register,
 }
 
 impl Registers {
+    /// # Clear Run/Stop and wait for the controller to halt
+    pub fn halt(&mut self) {
+        self.usbcmd = self.usbcmd().halt();
+        while !self.usbsts().is_halted() {
+            x64::pause();
+        }
+    }
+
     pub fn port_registers(&self, port: usize) -> &port::Registers {
         assert!(1 <= port);
         let address: *const Self = self as *const Self;
@@ -42,6 +49,10 @@ impl Registers {
         unsafe { &*port }
     }
 
+    pub fn set_dcbaap(&mut self, dcbaap: dcbaap::Register) {
+        self.dcbaap = dcbaap;
+    }
+
     pub fn reset(&mut self) {
         while !self.usbsts().is_halted() {
             x64::pause();
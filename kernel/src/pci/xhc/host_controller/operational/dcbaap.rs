@@ -10,3 +10,11 @@ pub struct Register {
     #[bits(58)]
     dcbaap: u64,
 }
+
+impl Register {
+    /// # Point a Device Context Base Address Array which is aligned to 64 bytes
+    pub fn create(address: u64) -> Self {
+        assert_eq!(address % (1 << 6), 0);
+        Self::new().with_dcbaap(address >> 6)
+    }
+}
@@ -26,6 +26,10 @@ pub struct Register {
 }
 
 impl Register {
+    pub fn halt(self) -> Self {
+        self.with_rs(false)
+    }
+
     pub fn is_reset(&self) -> bool {
         !self.hcrst()
     }
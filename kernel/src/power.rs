@@ -0,0 +1,114 @@
+//! # Shutdown and Reboot
+//! Both unbind the PCI drivers and stop the application processors and the timers before leaving the kernel.
+
+use {
+    crate::{acpi, com2_println, efi, interrupt, pci, processor, task, timer, x64, Argument},
+    core::arch::asm,
+};
+
+const RESET_TIMEOUT_MILLISECONDS: usize = 0x100;
+
+/// # Reboot Kind
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Kind {
+    Cold,
+    Warm,
+}
+
+impl From<Kind> for efi::ResetType {
+    fn from(kind: Kind) -> Self {
+        match kind {
+            Kind::Cold => Self::Cold,
+            Kind::Warm => Self::Warm,
+        }
+    }
+}
+
+/// # Reboot the system
+/// The FADT reset register, EFI ResetSystem, the 8042 keyboard controller and a triple fault are tried in this order.
+pub fn reboot(configuration: &pci::Configuration, kind: Kind) -> ! {
+    tear_down(configuration);
+    com2_println!("Rebooting.");
+    if Argument::get()
+        .efi_system_table()
+        .rsdp()
+        .xsdt()
+        .fadt()
+        .reset()
+    {
+        timer::acpi::wait_milliseconds(RESET_TIMEOUT_MILLISECONDS);
+        com2_println!("RESET_REG doesn't reset the system.");
+    }
+    Argument::get().efi_system_table().reset(kind.into());
+    com2_println!("ResetSystem doesn't reset the system.");
+    pulse_reset_line();
+    timer::acpi::wait_milliseconds(RESET_TIMEOUT_MILLISECONDS);
+    com2_println!("The keyboard controller doesn't reset the system.");
+    triple_fault()
+}
+
+/// # Power off the system
+pub fn shutdown(configuration: &pci::Configuration) -> ! {
+    tear_down(configuration);
+    com2_println!("Shutting down.");
+    Argument::get()
+        .efi_system_table_mut()
+        .rsdp_mut()
+        .xsdt_mut()
+        .fadt_mut()
+        .shutdown();
+    unreachable!();
+}
+
+/// # Pulse the reset line through the 8042 keyboard controller
+/// ## References
+/// * [Keyboard Controller](https://wiki.osdev.org/%228042%22_PS/2_Controller) PS/2 Controller Commands
+fn pulse_reset_line() {
+    const COMMAND_PORT: u16 = 0x0064;
+    const STATUS_PORT: u16 = 0x0064;
+    const INPUT_BUFFER_FULL: u8 = 0x02;
+    const PULSE_RESET_LINE: u8 = 0xfe;
+    (0..RESET_TIMEOUT_MILLISECONDS)
+        .take_while(|_| x64::port::inb(STATUS_PORT) & INPUT_BUFFER_FULL != 0)
+        .for_each(|_| timer::acpi::wait_milliseconds(1));
+    x64::port::outb(COMMAND_PORT, PULSE_RESET_LINE);
+}
+
+/// # Unbind the PCI drivers and stop the application processors, the watchdog, the timers and interruptions
+fn tear_down(configuration: &pci::Configuration) {
+    // Unbind PCI drivers.
+    pci::driver::remove_all(configuration);
+    // Print AP log.
+    processor::Manager::finalize();
+    // Park APs.
+    let hpet: &timer::hpet::Registers = Argument::get()
+        .efi_system_table()
+        .rsdp()
+        .xsdt()
+        .hpet()
+        .registers();
+    let mut ia32_apic_base = x64::msr::ia32::ApicBase::get().unwrap();
+    processor::Manager::park(ia32_apic_base.registers_mut(), hpet);
+    // Stop the watchdog because nothing resets its countdown once HPET stops.
+    acpi::watchdog::stop();
+    // Stop ACPI events.
+    acpi::event::finalize();
+    // Stop RTC interruptions.
+    timer::rtc::disable_periodic_interrupt();
+    // Stop HPET.
+    timer::hpet::Registers::finalize();
+    // Stop APIC interruptions.
+    ia32_apic_base.registers_mut().disable_periodic_interrupt();
+    // Disable all interruptions.
+    task::Controller::get_current_mut().unwrap().cli();
+}
+
+/// # Reset the processor by a triple fault
+/// An exception with an empty IDT causes a double fault and then a triple fault.
+fn triple_fault() -> ! {
+    interrupt::descriptor::table::Register::default().set();
+    unsafe {
+        asm!("int3");
+    }
+    unreachable!();
+}
@@ -1,5 +1,7 @@
 pub mod boot;
+pub mod idle;
 pub mod message;
+pub mod performance;
 
 use {
     crate::{acpi, com2_println, elf, interrupt, memory, sync, timer, x64, Argument},
@@ -17,6 +19,8 @@ static mut MANAGER: OnceCell<Manager> = OnceCell::new();
 pub struct Controller {
     boot_completed: AtomicBool,
     heap: Vec<MaybeUninit<u8>>,
+    /// The last residencies reported by the application processor
+    idle_governor: Option<idle::Governor>,
     initialized: bool,
     kernel_entry: usize,
     #[allow(dead_code)]
@@ -75,8 +79,15 @@ impl Controller {
         &self.heap
     }
 
+    pub fn idle_governor(&self) -> Option<&idle::Governor> {
+        self.idle_governor.as_ref()
+    }
+
+    /// # Send the idle states to the initialized application processor
     pub fn initialized(&mut self) {
         self.initialized = true;
+        let states: idle::States = idle::states(self.local_apic_structure.acpi_processor_uid());
+        self.send(message::Content::IdleStates(states));
     }
 
     pub fn kernel_entry(&self) -> usize {
@@ -106,6 +117,7 @@ impl Controller {
         heap: Vec<MaybeUninit<u8>>,
     ) -> Self {
         let boot_completed: AtomicBool = AtomicBool::new(false);
+        let idle_governor: Option<idle::Governor> = None;
         let initialized: bool = false;
         let kernel_writable_pages: Vec<memory::Page> = kernel.deploy_writable_segments(&mut paging);
         let kernel_stack_pages: usize = 0x10;
@@ -123,6 +135,7 @@ impl Controller {
         Self {
             boot_completed,
             heap,
+            idle_governor,
             initialized,
             kernel_entry,
             kernel_stack,
@@ -166,6 +179,21 @@ impl Controller {
             .send_interrupt(self.local_apic_id(), interrupt::INTERPROCESSOR_INTERRUPT);
     }
 
+    /// # Reset the controller to boot the application processor again
+    /// The writable segments of the kernel are deployed again so that the kernel starts from its initial state.
+    fn reset(&mut self, kernel: &elf::File) {
+        self.boot_completed.store(false, Ordering::Release);
+        self.idle_governor = None;
+        self.initialized = false;
+        self.kernel_writable_pages = kernel.deploy_writable_segments(&mut self.paging);
+        *self.receiver.lock() = None;
+        *self.sender.lock() = None;
+    }
+
+    pub fn set_idle_governor(&mut self, governor: idle::Governor) {
+        self.idle_governor = Some(governor);
+    }
+
     pub fn sender(&self) -> &sync::spin::Lock<Option<message::Content>> {
         &self.sender
     }
@@ -178,7 +206,6 @@ impl Controller {
 #[derive(Debug)]
 pub struct Manager {
     controllers: Vec<Controller>,
-    #[allow(dead_code)]
     kernel: elf::File,
     #[allow(dead_code)]
     kernel_read_only_pages: Vec<memory::Page>,
@@ -246,6 +273,42 @@ impl Manager {
         });
     }
 
+    /// # Put the application processors into the wait-for-SIPI state
+    pub fn park(
+        local_apic_registers: &mut interrupt::apic::local::Registers,
+        hpet: &timer::hpet::Registers,
+    ) {
+        Controller::get_all().for_each(|processor| {
+            local_apic_registers.send_init(processor.local_apic_id(), hpet);
+        });
+        idle::park();
+    }
+
+    /// # Boot the application processors again after waking
+    pub fn resume(
+        local_apic_id: u8,
+        local_apic_registers: &mut interrupt::apic::local::Registers,
+        hpet: &timer::hpet::Registers,
+    ) {
+        let Self {
+            controllers,
+            kernel,
+            ..
+        } = unsafe { MANAGER.get_mut() }.unwrap();
+        controllers
+            .iter_mut()
+            .for_each(|controller| controller.reset(kernel));
+        Controller::get_all().for_each(|processor| {
+            processor.boot(
+                Argument::get().processor_boot_loader_mut(),
+                local_apic_registers,
+                hpet,
+                local_apic_id,
+                Argument::get().heap_start(),
+            )
+        });
+    }
+
     pub fn finalize() {
         let local_apic_id2log: BTreeMap<u8, &str> = Controller::get_all()
             .map(|processor| (processor.local_apic_id(), processor.log()))
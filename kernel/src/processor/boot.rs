@@ -1,6 +1,6 @@
 use {
     super::{message, Controller},
-    crate::{com2_println, memory, sync},
+    crate::{com2_println, memory, sync, Argument},
     alloc::{string::String, vec::Vec},
     core::{
         fmt,
@@ -30,7 +30,21 @@ impl Loader {
     ) {
         self.initialize_stack();
         self.set_arguments(controller, bsp_heap_start, bsp_local_apic_id);
-        self.set_temporary_pml4_table(controller);
+        self.set_temporary_pml4_table(controller.paging());
+    }
+
+    /// # Make the loader bring the bootstrap processor waking from S3 to the long mode
+    /// The loader calls the entry with the stack floor after setting CR3.
+    pub fn initialize_for_wake(
+        &mut self,
+        cr3: u64,
+        entry: usize,
+        stack_floor: usize,
+        bsp_local_apic_id: u8,
+    ) {
+        self.initialize_stack();
+        *self.arguments_mut() = Arguments::wake(self, cr3, entry, stack_floor, bsp_local_apic_id);
+        self.set_temporary_pml4_table(Argument::get().paging());
     }
 
     pub fn log(&self) -> String {
@@ -78,9 +92,9 @@ impl Loader {
         *self.arguments_mut() = Arguments::new(self, controller, bsp_heap_start, bsp_local_apic_id);
     }
 
-    fn set_temporary_pml4_table(&mut self, controller: &Controller) {
+    fn set_temporary_pml4_table(&mut self, paging: &memory::Paging) {
         self.temporary_pml4_table_mut()
-            .copy_from_slice(controller.paging().table())
+            .copy_from_slice(paging.table())
     }
 
     fn stack_mut(&mut self) -> &mut [u8] {
@@ -176,4 +190,27 @@ impl Arguments {
             bsp_local_apic_id,
         }
     }
+
+    /// # Arguments to bring the bootstrap processor waking from S3 to the long mode
+    pub fn wake(
+        loader: &Loader,
+        cr3: u64,
+        kernel_entry: usize,
+        kernel_stack_floor: usize,
+        bsp_local_apic_id: u8,
+    ) -> Self {
+        let ss: u16 = loader.ss();
+        Self {
+            cr3,
+            kernel_entry,
+            kernel_stack_floor,
+            bsp_heap_start: 0,
+            heap_start: 0,
+            heap_size: 0,
+            receiver: 0,
+            sender: 0,
+            ss,
+            bsp_local_apic_id,
+        }
+    }
 }
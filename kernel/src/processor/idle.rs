@@ -0,0 +1,451 @@
+//! # Processor Idle States
+//! The states come from `_CST` of the processor object, or native C states in the LPIT if there is no `_CST`.
+//! The governor predicts the next idle duration from the past ones and enters the deepest state whose target residency fits in the prediction.
+//! C3 and deeper states are used only if the local APIC timer keeps running in them and BM_STS is readable.
+//! Such a state is demoted while bus masters are active, and bus master arbitration is disabled while every processor is in one.
+//! The application processors receive their states in a message and report their residencies on HPET events.
+//! ## References
+//! * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 8.1 Processor Power States
+//! * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 8.1.4 Processor Power State C3
+//! * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 8.4.1.1 _CST (C States)
+//! * [Intel Low Power S0 Idle](https://uefi.org/sites/default/files/resources/Intel_ACPI_Low_Power_S0_Idle.pdf) 2.2.1. Native C-State instruction based LPI structure type
+//! * [Intel Processor Vendor-Specific ACPI](https://www.intel.com/content/dam/www/public/us/en/documents/product-specifications/processor-vendor-specific-acpi-specification.pdf) 2.2 Functional Fixed Hardware
+
+use {
+    crate::{
+        acpi::{self, generic_address, machine_language},
+        com2_println,
+        sync::spin,
+        timer, x64, Argument,
+    },
+    alloc::vec::Vec,
+    core::sync::atomic::AtomicU64,
+};
+
+pub const MAX_STATES: usize = 8;
+/// Target residency of a `_CST` state relative to its latency
+const LATENCY_FACTOR: u32 = 2;
+/// Vendor of a functional fixed hardware register
+const INTEL: u8 = 0x01;
+/// Class of a functional fixed hardware register to enter C1 by `hlt`
+const C1_HALT: u8 = 0x01;
+/// Class of a functional fixed hardware register to enter a C state by MWAIT
+const NATIVE_C_STATE_INSTRUCTION: u8 = 0x02;
+
+static GOVERNOR: spin::Lock<Governor> = spin::Lock::new(Governor::new(States::halt()));
+static MONITORED: AtomicU64 = AtomicU64::new(0);
+/// Number of processors in C3 or deeper states, shared with the application processors
+static PROCESSORS_IN_C3: spin::Lock<usize> = spin::Lock::new(0);
+
+/// # The governor with its residency statistics
+pub fn governor() -> Governor {
+    GOVERNOR.lock().clone()
+}
+
+/// # Idle until an interruption
+/// The governor is unlocked while the processor sleeps.
+pub fn idle() {
+    let governor: Governor = GOVERNOR.lock().clone();
+    let index: usize = governor.select();
+    let start: u64 = x64::rdtsc();
+    let index: usize = governor.enter(index);
+    let duration: u64 =
+        x64::rdtsc().wrapping_sub(start) / governor.states.ticks_per_microsecond.max(1);
+    GOVERNOR.lock().account(index, duration);
+}
+
+/// # Total idle duration in microseconds since the idle states were set
+pub fn idle_time() -> u64 {
+    GOVERNOR.lock().idle_time()
+}
+
+/// # Set the idle states of the bootstrap processor
+pub fn initialize(local_apic_id: u8) {
+    if let Some(local_apic) = Argument::get()
+        .efi_system_table()
+        .rsdp()
+        .xsdt()
+        .madt()
+        .processor_local_apic_structures()
+        .into_iter()
+        .find(|local_apic| local_apic.apic_id() == local_apic_id)
+    {
+        set(states(local_apic.acpi_processor_uid()));
+    }
+}
+
+/// # Forget the application processors counted in C3
+/// Called after putting them into the wait-for-SIPI state.
+pub fn park() {
+    *PROCESSORS_IN_C3.lock() = 0;
+}
+
+/// # Replace the idle states
+/// The residency statistics are reset.
+pub fn set(states: States) {
+    *GOVERNOR.lock() = Governor::new(states);
+}
+
+/// # Read the idle states of a processor
+/// C1 by `hlt` is always the shallowest state, and MWAIT based states are ignored if MONITOR/MWAIT is not supported.
+/// C3 and deeper states are ignored if the local APIC timer stops in them or BM_STS is unavailable, and ones entered by I/O are ignored without ARB_DIS as well.
+pub fn states(acpi_processor_uid: u8) -> States {
+    let root: &machine_language::reference::Node = machine_language::root();
+    let supports_mwait: bool = Argument::get().cpuid().supports_monitor();
+    let supports_arat: bool = Argument::get().cpuid().supports_always_running_apic_timer();
+    let fadt: &acpi::fixed_acpi_description::Table =
+        Argument::get().efi_system_table().rsdp().xsdt().fadt();
+    let processors: usize = Argument::get()
+        .efi_system_table()
+        .rsdp()
+        .xsdt()
+        .madt()
+        .processor_local_apic_structures()
+        .into_iter()
+        .filter(|local_apic| local_apic.is_enabled())
+        .count();
+    let bus_master: Option<BusMaster> =
+        fadt.bus_master_status_port().map(|status_port| BusMaster {
+            status_port,
+            control_port: fadt.arbiter_port(),
+            processors_in_c3: &PROCESSORS_IN_C3,
+            processors,
+        });
+    let states: Vec<State> = machine_language::processor::Processor::enumerate(root)
+        .into_iter()
+        .find(|processor| processor.uid() == acpi_processor_uid as u32)
+        .and_then(|processor| processor.c_states(root))
+        .map(|c_states| {
+            c_states
+                .iter()
+                .filter_map(|c_state| State::from_c_state(c_state, supports_mwait))
+                .collect()
+        })
+        .or_else(|| {
+            Argument::get()
+                .efi_system_table()
+                .rsdp()
+                .xsdt()
+                .lpit()
+                .map(|lpit| {
+                    lpit.native_c_states()
+                        .into_iter()
+                        .filter_map(|structure| State::from_lpi(structure, supports_mwait))
+                        .collect()
+                })
+        })
+        .unwrap_or_default();
+    let states: Vec<State> = states
+        .into_iter()
+        .filter(|state| {
+            !state.deep
+                || supports_arat
+                    && bus_master.is_some_and(|bus_master| {
+                        !matches!(state.entry, Entry::Io { port: _ })
+                            || bus_master.control_port.is_some()
+                    })
+        })
+        .collect();
+    let states = States::new(
+        &states,
+        timer::tsc::calibrated_frequency() / 1000000,
+        bus_master,
+    );
+    com2_println!(
+        "Idle states of processor {:#x?} = {:#x?}",
+        acpi_processor_uid,
+        states.states()
+    );
+    states
+}
+
+/// # The way to enter an idle state
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Entry {
+    Halt,
+    Mwait { hint: u32 },
+    Io { port: u16 },
+}
+
+impl Entry {
+    /// # Decode a register to enter a C state
+    fn new(
+        space_id: generic_address::SpaceId,
+        bit_width: u8,
+        bit_offset: u8,
+        address: u64,
+        supports_mwait: bool,
+    ) -> Option<Self> {
+        match space_id {
+            generic_address::SpaceId::SystemIoSpace => Some(Self::Io {
+                port: address as u16,
+            }),
+            generic_address::SpaceId::FunctionalFixedHardware => match (bit_width, bit_offset) {
+                (INTEL, C1_HALT) => Some(Self::Halt),
+                (INTEL, NATIVE_C_STATE_INSTRUCTION) if supports_mwait => Some(Self::Mwait {
+                    hint: address as u32,
+                }),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    fn enter(&self) {
+        match self {
+            Self::Halt => x64::hlt(),
+            Self::Mwait { hint } => {
+                x64::monitor(&MONITORED as *const AtomicU64 as usize);
+                x64::mwait(*hint);
+            }
+            Self::Io { port } => {
+                x64::port::inb(*port);
+            }
+        }
+    }
+}
+
+/// # Bus master control for C3 and deeper states
+#[derive(Clone, Copy, Debug)]
+pub struct BusMaster {
+    /// PM1a_STS
+    status_port: u16,
+    /// PM2_CNT
+    control_port: Option<u16>,
+    processors_in_c3: &'static spin::Lock<usize>,
+    processors: usize,
+}
+
+impl BusMaster {
+    const ARB_DIS: u8 = 1 << 0;
+    const BM_STS: u16 = 1 << 4;
+
+    /// # Whether bus masters have been active since the last check
+    /// BM_STS is cleared.
+    fn is_active(&self) -> bool {
+        let active: bool = x64::port::inw(self.status_port) & Self::BM_STS != 0;
+        if active {
+            x64::port::outw(self.status_port, Self::BM_STS);
+        }
+        active
+    }
+
+    /// # Count a processor entering C3 and disable bus master arbitration if it is the last one
+    fn enter(&self) {
+        let mut processors_in_c3 = self.processors_in_c3.lock();
+        *processors_in_c3 += 1;
+        if *processors_in_c3 == self.processors {
+            self.set_arbitration_disable(true);
+        }
+    }
+
+    /// # Count a processor leaving C3 and enable bus master arbitration if it was disabled
+    fn exit(&self) {
+        let mut processors_in_c3 = self.processors_in_c3.lock();
+        if *processors_in_c3 == self.processors {
+            self.set_arbitration_disable(false);
+        }
+        *processors_in_c3 -= 1;
+    }
+
+    fn set_arbitration_disable(&self, disable: bool) {
+        if let Some(control_port) = self.control_port {
+            let control: u8 = x64::port::inb(control_port);
+            let control: u8 = if disable {
+                control | Self::ARB_DIS
+            } else {
+                control & !Self::ARB_DIS
+            };
+            x64::port::outb(control_port, control);
+        }
+    }
+}
+
+/// # An idle state
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct State {
+    entry: Entry,
+    /// Worst case exit latency in microseconds
+    latency: u32,
+    /// Minimum idle duration in microseconds to save power
+    residency: u32,
+    /// C3 or deeper, where caches don't snoop bus masters and the local APIC timer may stop
+    deep: bool,
+}
+
+impl State {
+    pub const fn new(entry: Entry, latency: u32, residency: u32, deep: bool) -> Self {
+        Self {
+            entry,
+            latency,
+            residency,
+            deep,
+        }
+    }
+
+    pub const fn halt() -> Self {
+        Self::new(Entry::Halt, 0, 0, false)
+    }
+
+    fn from_c_state(
+        c_state: &machine_language::processor::CState,
+        supports_mwait: bool,
+    ) -> Option<Self> {
+        let register: &machine_language::processor::Register = &c_state.register;
+        let entry: Entry = Entry::new(
+            register.address_space.into(),
+            register.bit_width,
+            register.bit_offset,
+            register.address,
+            supports_mwait,
+        )?;
+        let entry: Entry = if c_state.state_type == 1 {
+            Entry::Halt
+        } else {
+            entry
+        };
+        let latency: u32 = c_state.latency as u32;
+        let deep: bool = 3 <= c_state.state_type;
+        Some(Self::new(entry, latency, LATENCY_FACTOR * latency, deep))
+    }
+
+    fn from_lpi(
+        structure: &acpi::low_power_idle::native_c_state_instruction::Structure,
+        supports_mwait: bool,
+    ) -> Option<Self> {
+        let entry_trigger: generic_address::Structure = structure.entry_trigger();
+        let entry: Entry = Entry::new(
+            entry_trigger.space_id(),
+            entry_trigger.register_bit_width(),
+            entry_trigger.register_bit_offset(),
+            entry_trigger.address() as u64,
+            supports_mwait,
+        )?;
+        Some(Self::new(
+            entry,
+            structure.latency(),
+            structure.residency(),
+            true,
+        ))
+    }
+}
+
+/// # Idle states of a processor sorted from the shallowest
+#[derive(Clone, Debug)]
+pub struct States {
+    states: [State; MAX_STATES],
+    length: usize,
+    ticks_per_microsecond: u64,
+    bus_master: Option<BusMaster>,
+}
+
+impl States {
+    /// # Sort idle states from the shallowest after C1 by `hlt`
+    /// States which don't fit in `MAX_STATES` are dropped.
+    pub fn new(
+        states: &[State],
+        ticks_per_microsecond: u64,
+        bus_master: Option<BusMaster>,
+    ) -> Self {
+        let mut sorted: Vec<State> = states
+            .iter()
+            .filter(|state| state.entry != Entry::Halt)
+            .copied()
+            .collect();
+        sorted.sort_by_key(|state| state.latency);
+        let halt: State = states
+            .iter()
+            .find(|state| state.entry == Entry::Halt)
+            .copied()
+            .unwrap_or(State::halt());
+        let mut states: [State; MAX_STATES] = [State::halt(); MAX_STATES];
+        let length: usize = sorted.len().min(MAX_STATES - 1) + 1;
+        states[0] = halt;
+        states[1..length].copy_from_slice(&sorted[..length - 1]);
+        Self {
+            states,
+            length,
+            ticks_per_microsecond,
+            bus_master,
+        }
+    }
+
+    /// # Idle states with only `hlt`
+    pub const fn halt() -> Self {
+        Self {
+            states: [State::halt(); MAX_STATES],
+            length: 1,
+            ticks_per_microsecond: 1,
+            bus_master: None,
+        }
+    }
+
+    pub fn states(&self) -> &[State] {
+        &self.states[..self.length]
+    }
+}
+
+/// # Idle governor
+/// The residencies are in microseconds.
+#[derive(Clone, Debug)]
+pub struct Governor {
+    states: States,
+    /// Exponential moving average of idle durations in microseconds
+    predicted: u64,
+    residencies: [u64; MAX_STATES],
+    usages: [u64; MAX_STATES],
+}
+
+impl Governor {
+    const fn new(states: States) -> Self {
+        Self {
+            states,
+            predicted: 0,
+            residencies: [0; MAX_STATES],
+            usages: [0; MAX_STATES],
+        }
+    }
+
+    /// # Record an idle duration in microseconds
+    fn account(&mut self, index: usize, duration: u64) {
+        self.residencies[index] += duration;
+        self.usages[index] += 1;
+        self.predicted = (7 * self.predicted + duration) / 8;
+    }
+
+    /// # Enter a state and return the index of the entered one
+    /// A C3 or deeper state is demoted to the deepest shallower state while bus masters are active.
+    fn enter(&self, index: usize) -> usize {
+        let states: &[State] = self.states.states();
+        let index: usize = match self.states.bus_master {
+            Some(bus_master) if states[index].deep && bus_master.is_active() => states[..index]
+                .iter()
+                .rposition(|state| !state.deep)
+                .unwrap_or(0),
+            _ => index,
+        };
+        let state: State = states[index];
+        match self.states.bus_master.filter(|_| state.deep) {
+            Some(bus_master) => {
+                bus_master.enter();
+                state.entry.enter();
+                bus_master.exit();
+            }
+            None => state.entry.enter(),
+        }
+        index
+    }
+
+    pub fn idle_time(&self) -> u64 {
+        self.residencies.iter().sum()
+    }
+
+    /// # Select the deepest state whose target residency fits in the predicted idle duration
+    fn select(&self) -> usize {
+        self.states
+            .states()
+            .iter()
+            .rposition(|state| (state.residency as u64) <= self.predicted)
+            .unwrap_or(0)
+    }
+}
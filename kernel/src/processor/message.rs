@@ -1,11 +1,14 @@
-use super::Controller;
+use super::{idle, Controller};
 
 #[derive(Clone, Debug)]
 pub enum Content {
     BootCompleted,
     Char(char),
     HpetInterrupt,
+    IdleGovernor(idle::Governor),
+    IdleStates(idle::States),
     Initialized,
+    PerformanceControl(u64),
     PitInterrupt,
     RtcInterrupt,
 }
@@ -20,7 +23,10 @@ impl Content {
             Self::BootCompleted => controller.boot_complete(),
             Self::Char(character) => controller.receive_character(character),
             Self::HpetInterrupt => unimplemented!(),
+            Self::IdleGovernor(governor) => controller.set_idle_governor(governor),
+            Self::IdleStates(_states) => unimplemented!(),
             Self::Initialized => controller.initialized(),
+            Self::PerformanceControl(_control) => unimplemented!(),
             Self::PitInterrupt => unimplemented!(),
             Self::RtcInterrupt => unimplemented!(),
         }
@@ -0,0 +1,311 @@
+//! # Processor Performance States
+//! The states come from `_PSS` of the processor object, and a state is requested by writing its control value to the register in `_PCT`.
+//! `_PPC` limits the highest available state and is read again when the processor is notified.
+//! The on-demand governor follows the utilization of each processor, which is the time spent out of idle states.
+//! The idle time of an application processor comes from the residencies it reports on HPET events.
+//! ## References
+//! * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 8.4.5 Processor Performance Control
+//! * [Intel Processor Vendor-Specific ACPI](https://www.intel.com/content/dam/www/public/us/en/documents/product-specifications/processor-vendor-specific-acpi-specification.pdf) 2.2 Functional Fixed Hardware
+
+use {
+    super::{idle, message, Controller},
+    crate::{
+        acpi::{generic_address, machine_language},
+        com2_println,
+        sync::spin,
+        timer, x64, Argument,
+    },
+    alloc::vec::Vec,
+};
+
+/// Utilization in percent above which the on-demand governor requests the highest available state
+const UP_THRESHOLD: u64 = 80;
+
+static POLICY: spin::Lock<Policy> = spin::Lock::new(Policy::new());
+
+/// # The current governor
+#[allow(dead_code)]
+pub fn governor() -> Governor {
+    POLICY.lock().governor
+}
+
+/// # Read the performance states of the processors
+pub fn initialize(local_apic_id: u8) {
+    let root: &machine_language::reference::Node = machine_language::root();
+    let processors: Vec<machine_language::processor::Processor> =
+        machine_language::processor::Processor::enumerate(root);
+    let processors: Vec<Processor> = Argument::get()
+        .efi_system_table()
+        .rsdp()
+        .xsdt()
+        .madt()
+        .processor_local_apic_structures()
+        .into_iter()
+        .filter(|local_apic| local_apic.is_enabled())
+        .filter_map(|local_apic| {
+            let processor: machine_language::processor::Processor = processors
+                .iter()
+                .find(|processor| processor.uid() == local_apic.acpi_processor_uid() as u32)?
+                .clone();
+            Processor::new(processor, local_apic.apic_id())
+        })
+        .collect();
+    processors.iter().for_each(|processor| {
+        machine_language::notification::register(processor.processor.path(), notify)
+    });
+    com2_println!("performance states = {:#x?}", processors);
+    let mut policy = POLICY.lock();
+    policy.bootstrap_local_apic_id = local_apic_id;
+    policy.processors = processors;
+    policy.measure();
+    policy.update();
+}
+
+/// # Replace the governor and request the states it selects
+#[allow(dead_code)]
+pub fn set_governor(governor: Governor) {
+    let mut policy = POLICY.lock();
+    policy.governor = governor;
+    policy.update();
+}
+
+/// # Measure the utilization and request the states the governor selects
+/// Called every HPET event.
+pub fn tick() {
+    let mut policy = POLICY.lock();
+    policy.measure();
+    policy.update();
+}
+
+/// # Performance governor
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Governor {
+    /// The highest available state
+    Performance,
+    /// The lowest state
+    Powersave,
+    /// The lowest state whose frequency covers the utilization
+    OnDemand,
+}
+
+#[derive(Debug)]
+struct Policy {
+    governor: Governor,
+    processors: Vec<Processor>,
+    bootstrap_local_apic_id: u8,
+}
+
+impl Policy {
+    const fn new() -> Self {
+        Self {
+            governor: Governor::OnDemand,
+            processors: Vec::new(),
+            bootstrap_local_apic_id: 0,
+        }
+    }
+
+    /// # Measure the utilization of each processor since the last measurement
+    fn measure(&mut self) {
+        let bootstrap_local_apic_id: u8 = self.bootstrap_local_apic_id;
+        self.processors
+            .iter_mut()
+            .for_each(|processor| processor.measure(bootstrap_local_apic_id));
+    }
+
+    fn update(&mut self) {
+        let governor: Governor = self.governor;
+        let bootstrap_local_apic_id: u8 = self.bootstrap_local_apic_id;
+        self.processors.iter_mut().for_each(|processor| {
+            let index: usize = processor.select(governor);
+            processor.request(index, bootstrap_local_apic_id);
+        });
+    }
+}
+
+/// # The register to request a performance state
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Control {
+    /// IA32_PERF_CTL
+    FunctionalFixedHardware,
+    SystemIo {
+        port: u16,
+        bit_width: u8,
+    },
+}
+
+impl Control {
+    fn new(register: &machine_language::processor::Register) -> Option<Self> {
+        match register.address_space.into() {
+            generic_address::SpaceId::FunctionalFixedHardware => Argument::get()
+                .cpuid()
+                .supports_enhanced_intel_speedstep()
+                .then_some(Self::FunctionalFixedHardware),
+            generic_address::SpaceId::SystemIoSpace => Some(Self::SystemIo {
+                port: register.address as u16,
+                bit_width: register.bit_width,
+            }),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Processor {
+    processor: machine_language::processor::Processor,
+    local_apic_id: u8,
+    control: Control,
+    /// From the highest performance
+    states: Vec<machine_language::processor::PState>,
+    /// The index of the highest available state
+    limit: usize,
+    /// The index of the requested state
+    current: Option<usize>,
+    /// Total idle duration in microseconds at the last measurement
+    idle_time: Option<u64>,
+    /// TSC at the last measurement
+    timestamp: u64,
+    /// In percent
+    utilization: u64,
+}
+
+impl Processor {
+    fn new(processor: machine_language::processor::Processor, local_apic_id: u8) -> Option<Self> {
+        let root: &machine_language::reference::Node = machine_language::root();
+        let (control, _status): (
+            machine_language::processor::Register,
+            machine_language::processor::Register,
+        ) = processor.performance_control(root)?;
+        let control: Control = Control::new(&control)?;
+        let states: Vec<machine_language::processor::PState> =
+            processor
+                .performance_states(root)
+                .filter(|states| !states.is_empty())?;
+        let mut processor = Self {
+            processor,
+            local_apic_id,
+            control,
+            states,
+            limit: 0,
+            current: None,
+            idle_time: None,
+            timestamp: 0,
+            utilization: 100,
+        };
+        processor.read_limit();
+        Some(processor)
+    }
+
+    /// # The total idle duration of the processor in microseconds
+    /// An application processor which hasn't reported its residencies yet has none.
+    fn idle_time(&self, bootstrap_local_apic_id: u8) -> Option<u64> {
+        if self.local_apic_id == bootstrap_local_apic_id {
+            Some(idle::idle_time())
+        } else {
+            Controller::get_all()
+                .find(|controller| controller.local_apic_id() == self.local_apic_id)
+                .and_then(|controller| controller.idle_governor())
+                .map(|governor| governor.idle_time())
+        }
+    }
+
+    /// # Measure the utilization since the last measurement
+    fn measure(&mut self, bootstrap_local_apic_id: u8) {
+        let timestamp: u64 = x64::rdtsc();
+        let idle_time: Option<u64> = self.idle_time(bootstrap_local_apic_id);
+        let elapsed: u64 = timestamp.wrapping_sub(self.timestamp)
+            / (timer::tsc::calibrated_frequency() / 1000000).max(1);
+        match (self.idle_time, idle_time) {
+            (Some(previous), Some(current)) if elapsed != 0 => {
+                let idle: u64 = current.saturating_sub(previous).min(elapsed);
+                self.utilization = 100 - 100 * idle / elapsed;
+            }
+            _ => self.utilization = 100,
+        }
+        self.timestamp = timestamp;
+        self.idle_time = idle_time;
+    }
+
+    /// # Read `_PPC`
+    fn read_limit(&mut self) {
+        let root: &machine_language::reference::Node = machine_language::root();
+        self.limit = self
+            .processor
+            .performance_present_capabilities(root)
+            .unwrap_or(0)
+            .min(self.states.len() - 1);
+    }
+
+    /// # Write the control value of a state
+    /// An application processor writes IA32_PERF_CTL by itself on a message, so the request is retried on the next tick until the processor is initialized.
+    fn request(&mut self, index: usize, bootstrap_local_apic_id: u8) {
+        if self.current == Some(index) {
+            return;
+        }
+        let control: u64 = self.states[index].control;
+        match self.control {
+            Control::FunctionalFixedHardware if self.local_apic_id == bootstrap_local_apic_id => {
+                x64::msr::ia32::PerfCtl::request(Argument::get().cpuid(), control)
+            }
+            Control::FunctionalFixedHardware => {
+                match Controller::get_mut_all().find(|controller| {
+                    controller.is_initialized() && controller.local_apic_id() == self.local_apic_id
+                }) {
+                    Some(controller) => {
+                        controller.send(message::Content::PerformanceControl(control))
+                    }
+                    None => return,
+                }
+            }
+            Control::SystemIo { port, bit_width } => match bit_width {
+                8 => x64::port::outb(port, control as u8),
+                16 => x64::port::outw(port, control as u16),
+                _ => x64::port::outl(port, control as u32),
+            },
+        }
+        com2_println!(
+            "Processor {:#x?} requests P{} ({} MHz).",
+            self.local_apic_id,
+            index,
+            self.states[index].core_frequency
+        );
+        self.current = Some(index);
+    }
+
+    fn select(&self, governor: Governor) -> usize {
+        let utilization: u64 = self.utilization;
+        match governor {
+            Governor::Performance => self.limit,
+            Governor::Powersave => self.states.len() - 1,
+            Governor::OnDemand if UP_THRESHOLD <= utilization => self.limit,
+            Governor::OnDemand => {
+                let target: u64 =
+                    self.states[self.limit].core_frequency as u64 * utilization / UP_THRESHOLD;
+                self.states[self.limit..]
+                    .iter()
+                    .rposition(|state| target <= state.core_frequency as u64)
+                    .map_or(self.limit, |index| self.limit + index)
+            }
+        }
+    }
+}
+
+fn notify(path: &machine_language::name::Path, value: u8) {
+    let mut policy = POLICY.lock();
+    let governor: Governor = policy.governor;
+    let bootstrap_local_apic_id: u8 = policy.bootstrap_local_apic_id;
+    if let Some(processor) = policy
+        .processors
+        .iter_mut()
+        .find(|processor| processor.processor.path() == path)
+    {
+        match value {
+            machine_language::processor::PERFORMANCE_PRESENT_CAPABILITIES_CHANGED => {
+                processor.read_limit();
+                let index: usize = processor.select(governor);
+                processor.request(index, bootstrap_local_apic_id);
+            }
+            value => com2_println!("Unknown processor notification {:#x?}.", value),
+        }
+    }
+}
@@ -10,6 +10,9 @@ use {
     core::{fmt, slice},
 };
 
+/// Period of HPET interrupts in milliseconds
+pub const PERIOD_MILLISECONDS: usize = 1000;
+
 /// # Register Overview
 /// ## References
 /// * [IA-PC HPET (High Precision Event Timers Specification)](https://www.intel.com/content/dam/www/public/us/en/documents/technical-specifications/software-developers-hpet-spec-1-0a.pdf) 2.3.1 Register Overview Table 2 Memory-Mapped Registers
@@ -74,8 +77,7 @@ impl Registers {
             .hpet_mut()
             .registers_mut();
         hpet.enable_legacy_replacement_route();
-        let hpet_interrupt_period_milliseconds: usize = 1000;
-        let hpet_irq: u8 = hpet.enable_periodic_interrupt(hpet_interrupt_period_milliseconds);
+        let hpet_irq: u8 = hpet.enable_periodic_interrupt(PERIOD_MILLISECONDS);
         com2_println!("hpet_irq = {:#x?}", hpet_irq);
         Argument::get()
             .efi_system_table_mut()
@@ -11,6 +11,44 @@ use {
     core::fmt,
 };
 
+pub fn disable_alarm() {
+    task::Controller::get_current_mut().unwrap().cli();
+    interrupt::non_maskable::disable();
+    status_register::B::read().disable_alarm_interrupt().write();
+    interrupt::non_maskable::enable();
+    task::Controller::get_current_mut().unwrap().sti();
+}
+
+/// # Set the alarm to fire the given seconds later
+/// The alarm raises the RTC fixed event of ACPI as well as IRQ 8, so it wakes the system from S3.
+pub fn set_alarm(seconds: u32) {
+    const SECONDS_PER_DAY: u32 = 24 * 60 * 60;
+    assert!(seconds < SECONDS_PER_DAY);
+    task::Controller::get_current_mut().unwrap().cli();
+    interrupt::non_maskable::disable();
+    let time = Time::get();
+    let alarm: u32 =
+        ((time.hour as u32) * 60 * 60 + (time.minute as u32) * 60 + (time.second as u32) + seconds)
+            % SECONDS_PER_DAY;
+    let status_register_b = status_register::B::read();
+    x64::cmos::write_u8(
+        Time::SECOND_ALARM_ADDRESS,
+        status_register_b.encode((alarm % 60) as u8),
+    );
+    x64::cmos::write_u8(
+        Time::MINUTE_ALARM_ADDRESS,
+        status_register_b.encode((alarm / 60 % 60) as u8),
+    );
+    x64::cmos::write_u8(
+        Time::HOUR_ALARM_ADDRESS,
+        status_register_b.encode_hour((alarm / 60 / 60) as u8),
+    );
+    status_register_b.enable_alarm_interrupt().write();
+    end_interruption();
+    interrupt::non_maskable::enable();
+    task::Controller::get_current_mut().unwrap().sti();
+}
+
 pub fn disable_periodic_interrupt() {
     task::Controller::get_current_mut().unwrap().cli();
     interrupt::non_maskable::disable();
@@ -66,8 +104,11 @@ pub struct Time {
 
 impl Time {
     const SECOND_ADDRESS: u8 = 0x00;
+    const SECOND_ALARM_ADDRESS: u8 = 0x01;
     const MINUTE_ADDRESS: u8 = 0x02;
+    const MINUTE_ALARM_ADDRESS: u8 = 0x03;
     const HOUR_ADDRESS: u8 = 0x04;
+    const HOUR_ALARM_ADDRESS: u8 = 0x05;
     const DAY_ADDRESS: u8 = 0x07;
     const MONTH_ADDRESS: u8 = 0x08;
     const YEAR_ADDRESS: u8 = 0x09;
@@ -62,6 +62,32 @@ impl B {
         }
     }
 
+    /// # Convert a binary value into the format of the RTC
+    pub fn encode(&self, value: u8) -> u8 {
+        assert!(value < 100);
+        if self.dm() {
+            value
+        } else {
+            0x10 * (value / 10) + value % 10
+        }
+    }
+
+    /// # Convert an hour in 24 hours into the format of the RTC
+    pub fn encode_hour(&self, hour: u8) -> u8 {
+        assert!(hour < 24);
+        if self.hour_mode_24() {
+            self.encode(hour)
+        } else {
+            const PM: u8 = 0x80;
+            let pm: u8 = if 12 <= hour { PM } else { 0 };
+            let hour: u8 = match hour % 12 {
+                0 => 12,
+                hour => hour,
+            };
+            self.encode(hour) | pm
+        }
+    }
+
     pub fn correct_hour(&self, hour: u8) -> u8 {
         if self.hour_mode_24() {
             self.binarize(hour)
@@ -75,6 +101,14 @@ impl B {
         }
     }
 
+    pub fn disable_alarm_interrupt(self) -> Self {
+        self.with_aie(false)
+    }
+
+    pub fn enable_alarm_interrupt(self) -> Self {
+        self.with_aie(true)
+    }
+
     pub fn disable_periodic_interrupt(self) -> Self {
         self.with_pie(false)
     }
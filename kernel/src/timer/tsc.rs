@@ -2,14 +2,36 @@
 //! ## References
 //! * [TSC](https://wiki.osdev.org/TSC)
 
-use crate::{x64, Argument};
+use {
+    super::acpi,
+    crate::{x64, Argument},
+    core::sync::atomic::{AtomicU64, Ordering},
+};
+
+const CALIBRATION_MILLISECONDS: usize = 10;
+
+static CALIBRATED_FREQUENCY: AtomicU64 = AtomicU64::new(0);
+
+/// # Frequency in Hz
+/// The frequency is measured by the ACPI PM timer once if CPUID doesn't report it.
+pub fn calibrated_frequency() -> u64 {
+    frequency().unwrap_or_else(|| match CALIBRATED_FREQUENCY.load(Ordering::Relaxed) {
+        0 => {
+            let start: u64 = counter_value();
+            acpi::wait_milliseconds(CALIBRATION_MILLISECONDS);
+            let ticks: u64 = counter_value() - start;
+            let frequency: u64 = ticks * 1000 / (CALIBRATION_MILLISECONDS as u64);
+            CALIBRATED_FREQUENCY.store(frequency, Ordering::Relaxed);
+            frequency
+        }
+        frequency => frequency,
+    })
+}
 
-#[allow(dead_code)]
 pub fn counter_value() -> u64 {
     x64::rdtsc()
 }
 
-#[allow(dead_code)]
 pub fn frequency() -> Option<u64> {
     Argument::get().cpuid().tsc_frequency()
 }
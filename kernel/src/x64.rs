@@ -15,6 +15,19 @@ pub use {cpuid::Cpuid, rflags::Rflags};
 
 use {crate::memory, core::arch::asm};
 
+/// # Flush Cache Line
+/// ## References
+/// * [Intel 64 and IA-32 Architectures Software Developer's Manual December 2023](https://www.intel.com/content/www/us/en/developer/articles/technical/intel-sdm.html) Vol.2A 3-146
+#[inline(never)]
+pub fn clflush(address: usize) {
+    unsafe {
+        asm!(
+            "clflush [{}]",
+            in(reg) address,
+        );
+    }
+}
+
 /// # Clear Interrupt Flag
 /// ## References
 /// * [Intel 64 and IA-32 Architectures Software Developer's Manual December 2023](https://www.intel.com/content/www/us/en/developer/articles/technical/intel-sdm.html) Vol.2A 3-166
@@ -36,6 +49,45 @@ pub fn hlt() {
     }
 }
 
+/// # Memory Fence
+/// ## References
+/// * [Intel 64 and IA-32 Architectures Software Developer's Manual December 2023](https://www.intel.com/content/www/us/en/developer/articles/technical/intel-sdm.html) Vol.2B 4-22
+#[inline(never)]
+pub fn mfence() {
+    unsafe {
+        asm!("mfence");
+    }
+}
+
+/// # Set Up Monitor Address
+/// ## References
+/// * [Intel 64 and IA-32 Architectures Software Developer's Manual December 2023](https://www.intel.com/content/www/us/en/developer/articles/technical/intel-sdm.html) Vol.2B MONITOR-Set Up Monitor Address
+#[inline(never)]
+pub fn monitor(address: usize) {
+    unsafe {
+        asm!(
+            "monitor",
+            in("rax") address,
+            in("ecx") 0,
+            in("edx") 0,
+        );
+    }
+}
+
+/// # Monitor Wait
+/// ## References
+/// * [Intel 64 and IA-32 Architectures Software Developer's Manual December 2023](https://www.intel.com/content/www/us/en/developer/articles/technical/intel-sdm.html) Vol.2B MWAIT-Monitor Wait
+#[inline(never)]
+pub fn mwait(hint: u32) {
+    unsafe {
+        asm!(
+            "mwait",
+            in("eax") hint,
+            in("ecx") 0,
+        );
+    }
+}
+
 /// # Pause
 /// ## References
 /// * [Intel 64 and IA-32 Architectures Software Developer's Manual December 2023](https://www.intel.com/content/www/us/en/developer/articles/technical/intel-sdm.html) Vol.2B 4-230
@@ -49,7 +101,6 @@ pub fn pause() {
 /// # Read Timer Stamp Counter
 /// ## References
 /// * [Intel 64 and IA-32 Architectures Software Developer's Manual December 2023](https://www.intel.com/content/www/us/en/developer/articles/technical/intel-sdm.html) Vol.2B 4-550
-#[allow(dead_code)]
 #[inline(never)]
 pub fn rdtsc() -> u64 {
     let eax: u32;
@@ -75,6 +126,16 @@ pub fn sti() {
     assert!(Rflags::get().interrupt_is_enabled());
 }
 
+/// # Write Back and Invalidate Cache
+/// ## References
+/// * [Intel 64 and IA-32 Architectures Software Developer's Manual December 2023](https://www.intel.com/content/www/us/en/developer/articles/technical/intel-sdm.html) Vol.2D 6-3
+#[inline(never)]
+pub fn wbinvd() {
+    unsafe {
+        asm!("wbinvd");
+    }
+}
+
 pub fn set_segment_registers(
     code_segment_selector: &memory::segment::Selector,
     data_segment_selector: &memory::segment::Selector,
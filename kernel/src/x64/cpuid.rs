@@ -69,7 +69,6 @@ pub struct Cpuid {
     eax0x00000004: Option<Eax0x00000004>,
     #[allow(dead_code)]
     eax0x00000005: Option<Eax0x00000005>,
-    #[allow(dead_code)]
     eax0x00000006: Option<Eax0x00000006>,
     #[allow(dead_code)]
     eax0x00000007: Option<Eax0x00000007>,
@@ -224,6 +223,24 @@ impl Cpuid {
             .map_or(false, |eax0x00000001| eax0x00000001.supports_apic())
     }
 
+    /// # Whether the local APIC timer keeps running in deep C states
+    /// ## References
+    /// * [Intel 64 and IA-32 Architectures Software Developer's Manual December 2023](https://www.intel.com/content/www/us/en/developer/articles/technical/intel-sdm.html) Vol. 2A 3-217 Table 3-8. Information Returned by CPUID Instruction
+    pub fn supports_always_running_apic_timer(&self) -> bool {
+        self.eax0x00000006
+            .as_ref()
+            .map_or(false, |eax0x00000006| eax0x00000006.supports_arat())
+    }
+
+    /// # Get Enhanced Intel SpeedStep Technology availability.
+    /// ## References
+    /// * [Intel 64 and IA-32 Architectures Software Developer's Manual December 2023](https://www.intel.com/content/www/us/en/developer/articles/technical/intel-sdm.html) Vol. 2A 3-243 Figure 3-7. Feature Information Returned in the ECX Register
+    pub fn supports_enhanced_intel_speedstep(&self) -> bool {
+        self.eax0x00000001.as_ref().map_or(false, |eax0x00000001| {
+            eax0x00000001.supports_enhanced_intel_speedstep()
+        })
+    }
+
     pub fn supports_execute_disable_bit(&self) -> bool {
         self.eax0x80000001.as_ref().map_or(false, |eax0x80000001| {
             eax0x80000001.supports_execute_disable_bit()
@@ -248,6 +265,15 @@ impl Cpuid {
         })
     }
 
+    /// # Get MONITOR/MWAIT availability.
+    /// ## References
+    /// * [Intel 64 and IA-32 Architectures Software Developer's Manual December 2023](https://www.intel.com/content/www/us/en/developer/articles/technical/intel-sdm.html) Vol. 2A 3-243 Figure 3-7. Feature Information Returned in the ECX Register
+    pub fn supports_monitor(&self) -> bool {
+        self.eax0x00000001
+            .as_ref()
+            .map_or(false, |eax0x00000001| eax0x00000001.supports_monitor())
+    }
+
     pub fn tsc_frequency(&self) -> Option<u64> {
         self.eax0x00000015
             .as_ref()
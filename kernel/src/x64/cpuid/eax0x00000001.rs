@@ -39,6 +39,14 @@ impl Eax0x00000001 {
     pub fn supports_apic(&self) -> bool {
         self.edx.apic()
     }
+
+    pub fn supports_enhanced_intel_speedstep(&self) -> bool {
+        self.ecx.eist()
+    }
+
+    pub fn supports_monitor(&self) -> bool {
+        self.ecx.monitor()
+    }
 }
 
 #[bitfield(u32)]
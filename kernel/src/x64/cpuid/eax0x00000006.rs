@@ -9,7 +9,6 @@ use {
 
 #[derive(Debug)]
 pub struct Eax0x00000006 {
-    #[allow(dead_code)]
     eax: Eax,
     #[allow(dead_code)]
     ebx: Ebx,
@@ -32,6 +31,10 @@ impl Eax0x00000006 {
             Self { eax, ebx, ecx, edx }
         })
     }
+
+    pub fn supports_arat(&self) -> bool {
+        self.eax.arat()
+    }
 }
 
 #[bitfield(u32)]
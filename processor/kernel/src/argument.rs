@@ -94,6 +94,18 @@ impl Argument<'_> {
         }
     }
 
+    /// # Report the residencies of the idle states to the bootstrap processor
+    pub fn send_idle_governor(&mut self, governor: processor::idle::Governor) {
+        while self.sender.lock().is_some() {
+            x64::pause();
+        }
+        *self.sender.lock() = Some(processor::message::Content::IdleGovernor(governor));
+        let mut ia32_apic_base: x64::msr::ia32::ApicBase = self.ia32_apic_base;
+        ia32_apic_base
+            .registers_mut()
+            .send_interrupt(self.bsp_local_apic_id, interrupt::INTERPROCESSOR_INTERRUPT);
+    }
+
     pub fn send_char(&mut self, character: char) {
         while self.sender.lock().is_some() {
             x64::pause();
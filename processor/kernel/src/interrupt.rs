@@ -10,6 +10,7 @@ use {
 
 static mut EVENTS: VecDeque<Event> = VecDeque::new();
 
+#[allow(clippy::large_enum_variant)]
 pub enum Event {
     #[allow(dead_code)]
     ApicTimer,
@@ -40,7 +41,10 @@ impl Event {
     pub fn process(self) {
         match self {
             Self::ApicTimer => bsp_println!("APIC timer event."),
-            Self::Hpet => bsp_println!("HPET event."),
+            Self::Hpet => {
+                bsp_println!("HPET event.");
+                Argument::get_mut().send_idle_governor(processor::idle::governor());
+            }
             Self::Interprocessor {
                 sender_local_apic_id,
                 message,
@@ -62,7 +62,7 @@ fn main(argument: &'static Argument<'static>) {
     loop {
         match interrupt::Event::pop() {
             Some(event) => event.process(),
-            None => x64::hlt(),
+            None => processor::idle::idle(),
         }
     }
 }
@@ -0,0 +1,238 @@
+//! # Processor Idle States
+//! The governor predicts the next idle duration from the past ones and enters the deepest state whose target residency fits in the prediction.
+//! A C3 or deeper state is demoted while bus masters are active, and bus master arbitration is disabled while every processor is in one.
+//! ## References
+//! * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 8.1 Processor Power States
+//! * [Advanced Configuration and Power Interface (ACPI) Specification](https://uefi.org/sites/default/files/resources/ACPI_Spec_6_5_Aug29.pdf) 8.1.4 Processor Power State C3
+
+use {
+    crate::{sync::spin, x64},
+    core::sync::atomic::AtomicU64,
+};
+
+pub const MAX_STATES: usize = 8;
+
+static GOVERNOR: spin::Lock<Governor> = spin::Lock::new(Governor::new(States::halt()));
+static MONITORED: AtomicU64 = AtomicU64::new(0);
+
+/// # The governor with its residency statistics
+pub fn governor() -> Governor {
+    GOVERNOR.lock().clone()
+}
+
+/// # Idle until an interruption
+/// The governor is unlocked while the processor sleeps.
+pub fn idle() {
+    let governor: Governor = GOVERNOR.lock().clone();
+    let index: usize = governor.select();
+    let start: u64 = x64::rdtsc();
+    let index: usize = governor.enter(index);
+    let duration: u64 =
+        x64::rdtsc().wrapping_sub(start) / governor.states.ticks_per_microsecond.max(1);
+    GOVERNOR.lock().account(index, duration);
+}
+
+/// # Replace the idle states
+/// The residency statistics are reset.
+pub fn set(states: States) {
+    *GOVERNOR.lock() = Governor::new(states);
+}
+
+/// # The way to enter an idle state
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Entry {
+    Halt,
+    #[allow(dead_code)]
+    Mwait {
+        hint: u32,
+    },
+    #[allow(dead_code)]
+    Io {
+        port: u16,
+    },
+}
+
+impl Entry {
+    fn enter(&self) {
+        match self {
+            Self::Halt => x64::hlt(),
+            Self::Mwait { hint } => {
+                x64::monitor(&MONITORED as *const AtomicU64 as usize);
+                x64::mwait(*hint);
+            }
+            Self::Io { port } => {
+                x64::port::inb(*port);
+            }
+        }
+    }
+}
+
+/// # Bus master control for C3 and deeper states
+/// The counter of processors in C3 belongs to the bootstrap processor.
+#[derive(Clone, Copy, Debug)]
+pub struct BusMaster {
+    /// PM1a_STS
+    status_port: u16,
+    /// PM2_CNT
+    control_port: Option<u16>,
+    processors_in_c3: &'static spin::Lock<usize>,
+    processors: usize,
+}
+
+impl BusMaster {
+    const ARB_DIS: u8 = 1 << 0;
+    const BM_STS: u16 = 1 << 4;
+
+    /// # Whether bus masters have been active since the last check
+    /// BM_STS is cleared.
+    fn is_active(&self) -> bool {
+        let active: bool = x64::port::inw(self.status_port) & Self::BM_STS != 0;
+        if active {
+            x64::port::outw(self.status_port, Self::BM_STS);
+        }
+        active
+    }
+
+    /// # Count a processor entering C3 and disable bus master arbitration if it is the last one
+    fn enter(&self) {
+        let mut processors_in_c3 = self.processors_in_c3.lock();
+        *processors_in_c3 += 1;
+        if *processors_in_c3 == self.processors {
+            self.set_arbitration_disable(true);
+        }
+    }
+
+    /// # Count a processor leaving C3 and enable bus master arbitration if it was disabled
+    fn exit(&self) {
+        let mut processors_in_c3 = self.processors_in_c3.lock();
+        if *processors_in_c3 == self.processors {
+            self.set_arbitration_disable(false);
+        }
+        *processors_in_c3 -= 1;
+    }
+
+    fn set_arbitration_disable(&self, disable: bool) {
+        if let Some(control_port) = self.control_port {
+            let control: u8 = x64::port::inb(control_port);
+            let control: u8 = if disable {
+                control | Self::ARB_DIS
+            } else {
+                control & !Self::ARB_DIS
+            };
+            x64::port::outb(control_port, control);
+        }
+    }
+}
+
+/// # An idle state
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct State {
+    entry: Entry,
+    /// Worst case exit latency in microseconds
+    latency: u32,
+    /// Minimum idle duration in microseconds to save power
+    residency: u32,
+    /// C3 or deeper, where caches don't snoop bus masters and the local APIC timer may stop
+    deep: bool,
+}
+
+impl State {
+    pub const fn new(entry: Entry, latency: u32, residency: u32, deep: bool) -> Self {
+        Self {
+            entry,
+            latency,
+            residency,
+            deep,
+        }
+    }
+
+    pub const fn halt() -> Self {
+        Self::new(Entry::Halt, 0, 0, false)
+    }
+}
+
+/// # Idle states of a processor sorted from the shallowest
+#[derive(Clone, Debug)]
+pub struct States {
+    states: [State; MAX_STATES],
+    length: usize,
+    ticks_per_microsecond: u64,
+    bus_master: Option<BusMaster>,
+}
+
+impl States {
+    /// # Idle states with only `hlt`
+    pub const fn halt() -> Self {
+        Self {
+            states: [State::halt(); MAX_STATES],
+            length: 1,
+            ticks_per_microsecond: 1,
+            bus_master: None,
+        }
+    }
+
+    pub fn states(&self) -> &[State] {
+        &self.states[..self.length]
+    }
+}
+
+/// # Idle governor
+/// The residencies are in microseconds.
+#[derive(Clone, Debug)]
+pub struct Governor {
+    states: States,
+    /// Exponential moving average of idle durations in microseconds
+    predicted: u64,
+    residencies: [u64; MAX_STATES],
+    usages: [u64; MAX_STATES],
+}
+
+impl Governor {
+    const fn new(states: States) -> Self {
+        Self {
+            states,
+            predicted: 0,
+            residencies: [0; MAX_STATES],
+            usages: [0; MAX_STATES],
+        }
+    }
+
+    /// # Record an idle duration in microseconds
+    fn account(&mut self, index: usize, duration: u64) {
+        self.residencies[index] += duration;
+        self.usages[index] += 1;
+        self.predicted = (7 * self.predicted + duration) / 8;
+    }
+
+    /// # Enter a state and return the index of the entered one
+    /// A C3 or deeper state is demoted to the deepest shallower state while bus masters are active.
+    fn enter(&self, index: usize) -> usize {
+        let states: &[State] = self.states.states();
+        let index: usize = match self.states.bus_master {
+            Some(bus_master) if states[index].deep && bus_master.is_active() => states[..index]
+                .iter()
+                .rposition(|state| !state.deep)
+                .unwrap_or(0),
+            _ => index,
+        };
+        let state: State = states[index];
+        match self.states.bus_master.filter(|_| state.deep) {
+            Some(bus_master) => {
+                bus_master.enter();
+                state.entry.enter();
+                bus_master.exit();
+            }
+            None => state.entry.enter(),
+        }
+        index
+    }
+
+    /// # Select the deepest state whose target residency fits in the predicted idle duration
+    fn select(&self) -> usize {
+        self.states
+            .states()
+            .iter()
+            .rposition(|state| (state.residency as u64) <= self.predicted)
+            .unwrap_or(0)
+    }
+}
@@ -1,4 +1,7 @@
-use crate::interrupt;
+use {
+    super::idle,
+    crate::{interrupt, x64},
+};
 
 #[derive(Clone, Debug)]
 pub enum Content {
@@ -6,8 +9,13 @@ pub enum Content {
     Char(char),
     #[allow(dead_code)]
     HpetInterrupt,
+    IdleGovernor(idle::Governor),
+    #[allow(dead_code)]
+    IdleStates(idle::States),
     Initialized,
     #[allow(dead_code)]
+    PerformanceControl(u64),
+    #[allow(dead_code)]
     PitInterrupt,
     #[allow(dead_code)]
     RtcInterrupt,
@@ -31,7 +39,12 @@ impl Content {
             Self::BootCompleted => unimplemented!(),
             Self::Char(_character) => unimplemented!(),
             Self::HpetInterrupt => interrupt::Event::push(interrupt::Event::Hpet),
+            Self::IdleGovernor(_governor) => unimplemented!(),
+            Self::IdleStates(states) => idle::set(states),
             Self::Initialized => unimplemented!(),
+            Self::PerformanceControl(control) => {
+                x64::msr::ia32::PerfCtl::request(x64::Cpuid::get(), control)
+            }
             Self::PitInterrupt => interrupt::Event::push(interrupt::Event::Pit),
             Self::RtcInterrupt => interrupt::Event::push(interrupt::Event::Rtc),
         }
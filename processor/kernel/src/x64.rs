@@ -6,6 +6,7 @@ pub mod control;
 pub mod cpuid;
 pub mod descriptor;
 pub mod msr;
+pub mod port;
 pub mod rflags;
 pub mod task;
 
@@ -34,6 +35,35 @@ pub fn hlt() {
     }
 }
 
+/// # Set Up Monitor Address
+/// ## References
+/// * [Intel 64 and IA-32 Architectures Software Developer's Manual December 2023](https://www.intel.com/content/www/us/en/developer/articles/technical/intel-sdm.html) Vol.2B MONITOR-Set Up Monitor Address
+#[inline(never)]
+pub fn monitor(address: usize) {
+    unsafe {
+        asm!(
+            "monitor",
+            in("rax") address,
+            in("ecx") 0,
+            in("edx") 0,
+        );
+    }
+}
+
+/// # Monitor Wait
+/// ## References
+/// * [Intel 64 and IA-32 Architectures Software Developer's Manual December 2023](https://www.intel.com/content/www/us/en/developer/articles/technical/intel-sdm.html) Vol.2B MWAIT-Monitor Wait
+#[inline(never)]
+pub fn mwait(hint: u32) {
+    unsafe {
+        asm!(
+            "mwait",
+            in("eax") hint,
+            in("ecx") 0,
+        );
+    }
+}
+
 /// # Pause
 /// ## References
 /// * [Intel 64 and IA-32 Architectures Software Developer's Manual December 2023](https://www.intel.com/content/www/us/en/developer/articles/technical/intel-sdm.html) Vol.2B 4-230
@@ -44,6 +74,23 @@ pub fn pause() {
     }
 }
 
+/// # Read Timer Stamp Counter
+/// ## References
+/// * [Intel 64 and IA-32 Architectures Software Developer's Manual December 2023](https://www.intel.com/content/www/us/en/developer/articles/technical/intel-sdm.html) Vol.2B 4-550
+#[inline(never)]
+pub fn rdtsc() -> u64 {
+    let eax: u32;
+    let edx: u32;
+    unsafe {
+        asm!(
+            "rdtsc",
+            out("eax") eax,
+            out("edx") edx,
+        );
+    }
+    ((edx as u64) << u32::BITS) + (eax as u64)
+}
+
 /// # Set Interrupt Flag
 /// ## References
 /// * [Intel 64 and IA-32 Architectures Software Developer's Manual December 2023](https://www.intel.com/content/www/us/en/developer/articles/technical/intel-sdm.html) Vol.2B 4-662
@@ -234,6 +234,15 @@ impl Cpuid {
             .map_or(false, |eax0x00000001| eax0x00000001.supports_apic())
     }
 
+    /// # Get Enhanced Intel SpeedStep Technology availability.
+    /// ## References
+    /// * [Intel 64 and IA-32 Architectures Software Developer's Manual December 2023](https://www.intel.com/content/www/us/en/developer/articles/technical/intel-sdm.html) Vol. 2A 3-243 Figure 3-7. Feature Information Returned in the ECX Register
+    pub fn supports_enhanced_intel_speedstep(&self) -> bool {
+        self.eax0x00000001.as_ref().map_or(false, |eax0x00000001| {
+            eax0x00000001.supports_enhanced_intel_speedstep()
+        })
+    }
+
     pub fn supports_execute_disable_bit(&self) -> bool {
         self.eax0x80000001.as_ref().map_or(false, |eax0x80000001| {
             eax0x80000001.supports_execute_disable_bit()
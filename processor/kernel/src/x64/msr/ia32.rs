@@ -6,6 +6,9 @@ mod apic_base;
 mod efer;
 mod fmask;
 mod lstar;
+mod perf_ctl;
 mod star;
 
-pub use {apic_base::ApicBase, efer::Efer, fmask::Fmask, lstar::Lstar, star::Star};
+pub use {
+    apic_base::ApicBase, efer::Efer, fmask::Fmask, lstar::Lstar, perf_ctl::PerfCtl, star::Star,
+};
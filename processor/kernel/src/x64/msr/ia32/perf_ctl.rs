@@ -0,0 +1,43 @@
+use {
+    super::super::{super::Cpuid, rdmsr, wrmsr},
+    bitfield_struct::bitfield,
+};
+
+/// # IA32_PERF_CTL
+/// ## References
+/// * [Intel 64 and IA-32 Architectures Software Developer's Manual December 2023](https://www.intel.com/content/www/us/en/developer/articles/technical/intel-sdm.html) Vol.3 15.1.1 Enhanced Intel SpeedStep Technology
+/// * [Intel 64 and IA-32 Architectures Software Developer's Manual December 2023](https://www.intel.com/content/www/us/en/developer/articles/technical/intel-sdm.html) Vol.4 2-20
+#[bitfield(u64)]
+pub struct PerfCtl {
+    target_performance_state_value: u16,
+    #[bits(16)]
+    __: u16,
+    ida_engage: bool,
+    #[bits(31)]
+    __: u32,
+}
+
+impl PerfCtl {
+    const ECX: u32 = 0x00000199;
+
+    pub fn get(cpuid: &Cpuid) -> Option<Self> {
+        cpuid
+            .supports_enhanced_intel_speedstep()
+            .then(|| rdmsr(Self::ECX).into())
+    }
+
+    /// # Request a performance state
+    /// The control value comes from `_PSS`.
+    pub fn request(cpuid: &Cpuid, control: u64) {
+        if let Some(perf_ctl) = Self::get(cpuid) {
+            perf_ctl
+                .with_target_performance_state_value(control as u16)
+                .set();
+        }
+    }
+
+    pub fn set(self) {
+        let perf_ctl: u64 = self.into();
+        wrmsr(Self::ECX, perf_ctl);
+    }
+}